@@ -1,6 +1,6 @@
 import { useState, useMemo, useCallback, useEffect, useRef } from 'react'
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query'
-import { getCuration, getDataPreview, acceptDecision, rejectDecision, resetDecision, saveCuration, batchAccept, batchReject, getLlmStatus } from './api/client'
+import { getCuration, getDataPreview, acceptDecision, rejectDecision, resetDecision, saveCuration, batchAccept, batchReject, getLlmStatus, applyCuration, downloadUrl } from './api/client'
 import type { BatchRequest } from './api/client'
 import { SuggestionCard, SuggestionGroup, StatusBar, Button, DataPreview, AskQuestionDialog, useToast } from './components'
 import type { DecisionInfo, SuggestionInfo, ObservationInfo, DataPreviewResponse } from './types'
@@ -180,6 +180,17 @@ export default function App() {
     },
   })
 
+  const applyMutation = useMutation({
+    mutationFn: () => applyCuration(),
+    onSuccess: (data) => {
+      addToast(`Applied ${data.operations_applied} transformations (${data.rows_modified} values changed)`, 'success')
+      window.location.href = downloadUrl('tsv')
+    },
+    onError: (error: Error) => {
+      addToast(`Failed to apply: ${error.message}`, 'error')
+    },
+  })
+
   // Initialize lastSavedAt from curation data
   useEffect(() => {
     if (curation?.updated_at && !lastSavedAt) {
@@ -639,9 +650,17 @@ export default function App() {
               <div className="mb-4 rounded-lg border border-success/50 bg-success/5 p-4 text-center">
                 <p className="font-medium text-success">Review complete!</p>
                 <p className="mt-1 text-sm text-muted-foreground">
-                  Run <code className="rounded bg-muted px-1">crucible apply</code> to
-                  generate your curated dataset.
+                  Download your curated dataset, or run{' '}
+                  <code className="rounded bg-muted px-1">crucible apply</code> to generate it.
                 </p>
+                <Button
+                  className="mt-3"
+                  size="sm"
+                  onClick={() => applyMutation.mutate()}
+                  disabled={applyMutation.isPending}
+                >
+                  {applyMutation.isPending ? 'Applying...' : 'Apply & Download'}
+                </Button>
               </div>
             )}
 
@@ -126,6 +126,36 @@ export async function batchReject(request: BatchRequest): Promise<BatchResponse>
   })
 }
 
+// Apply accepted decisions and download the curated data
+export interface AppliedChange {
+  description: string
+  column: string
+  values_changed: number
+}
+
+export interface ApplyResponse {
+  operations_applied: number
+  rows_modified: number
+  row_count: number
+  changes: AppliedChange[]
+  audit: unknown
+}
+
+export async function applyCuration(force = false): Promise<ApplyResponse> {
+  return fetchApi<ApplyResponse>('/apply', {
+    method: 'POST',
+    body: JSON.stringify({ force }),
+  })
+}
+
+export function downloadUrl(format: 'tsv' | 'csv' | 'json' = 'tsv'): string {
+  return `${API_BASE}/download?format=${format}`
+}
+
+export function auditUrl(): string {
+  return `${API_BASE}/download/audit`
+}
+
 // Interactive explanation APIs
 export async function askQuestion(
   request: AskQuestionRequest
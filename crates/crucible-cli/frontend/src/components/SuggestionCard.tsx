@@ -17,6 +17,8 @@ interface SuggestionCardProps {
 const actionColors: Record<string, 'default' | 'warning' | 'destructive' | 'success'> = {
   Standardize: 'default',
   Flag: 'warning',
+  Clamp: 'warning',
+  ConvertUnit: 'default',
   Derive: 'success',
   Split: 'default',
   Merge: 'default',
@@ -7,9 +7,12 @@ export interface SourceInfo {
   column_count: number
 }
 
+// Plain types serialize as their name, list columns with their delimiter
+export type ColumnType = string | { list: { item_delimiter: string } }
+
 export interface ColumnInfo {
   name: string
-  inferred_type: string
+  inferred_type: ColumnType
   semantic_role: string
   nullable: boolean
   unique: boolean
@@ -21,6 +24,7 @@ export interface SchemaInfo {
 
 export interface ObservationEvidence {
   sample_rows?: number[]
+  rows?: number[]
   occurrences?: number
   percentage?: number
   value_counts?: Record<string, Record<string, number>>
@@ -52,10 +56,11 @@ export interface SuggestionInfo {
 export interface DecisionInfo {
   id: string
   suggestion_id: string
-  status: 'pending' | 'accepted' | 'rejected' | 'modified'
+  status: 'pending' | 'accepted' | 'rejected' | 'modified' | 'deferred'
   decided_by: string | null
   decided_at: string | null
   notes: string | null
+  deferred_until?: string | null
 }
 
 export interface ObservationCounts {
@@ -7,6 +7,7 @@ use std::path::PathBuf;
 #[derive(Parser)]
 #[command(name = "crucible")]
 #[command(version, about, long_about = None)]
+#[command(after_help = EXIT_CODES_HELP)]
 #[command(propagate_version = true)]
 pub struct Cli {
     #[command(subcommand)]
@@ -17,18 +18,33 @@ pub struct Cli {
     pub verbose: bool,
 }
 
+/// Exit codes, listed in `--help`.
+const EXIT_CODES_HELP: &str = "\
+Exit codes:
+  0  Success, with no observations at the --fail-on severity
+  1  The command failed (unreadable input, analysis error, ...)
+  2  Error observations were found (with --fail-on error or warning)
+  3  Warning observations were found (with --fail-on warning)";
+
 #[derive(Subcommand)]
 pub enum Commands {
     /// Analyze a data file and create a curation layer
     Analyze {
-        /// Path to the data file (CSV/TSV)
+        /// Path to the data file (CSV/TSV), a directory of files, or - to
+        /// read from stdin (requires --output)
         #[arg(value_name = "FILE")]
         file: PathBuf,
 
-        /// Output path for curation file (default: <file>.curation.json)
+        /// Output path for curation file (default: <file>.curation.json);
+        /// an output directory when analyzing a directory
         #[arg(short, long)]
         output: Option<PathBuf>,
 
+        /// File name to record for data read from stdin (e.g. cohort.tsv);
+        /// a .jsonl or .gz extension selects that format
+        #[arg(long, value_name = "NAME", default_value = "stdin")]
+        stdin_name: String,
+
         /// Domain context for analysis (e.g., "biomedical", "genomics")
         #[arg(short, long)]
         domain: Option<String>,
@@ -44,6 +60,79 @@ pub enum Commands {
         /// MIxS environmental package for bioinformatics validation
         #[arg(long)]
         mixs_package: Option<MixsPackageChoice>,
+
+        /// Treat all warnings as errors (fails if any are found)
+        #[arg(long)]
+        strict: bool,
+
+        /// Validate against a frozen schema contract (JSON)
+        #[arg(long, value_name = "SCHEMA")]
+        against: Option<PathBuf>,
+
+        /// Analyze only the first N rows (for very large files)
+        #[arg(long, value_name = "N")]
+        sample: Option<usize>,
+
+        /// Always call the LLM instead of reusing cached responses
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Re-request cached LLM responses older than this many hours
+        #[arg(long, value_name = "HOURS")]
+        cache_ttl: Option<u64>,
+
+        /// Only analyze directory entries matching this pattern (e.g. "*.tsv")
+        #[arg(long, value_name = "PATTERN")]
+        glob: Option<String>,
+
+        /// Number of files to analyze concurrently in a directory
+        #[arg(long, value_name = "N", default_value = "1")]
+        parallel: usize,
+
+        /// Validators to skip, by detector name (e.g. typo_validator,semantic_equivalence_validator)
+        #[arg(long, value_name = "NAMES", value_delimiter = ',')]
+        disable: Vec<String>,
+
+        /// Analyze only these columns (e.g. sample_id,disease,age)
+        #[arg(long, value_name = "NAMES", value_delimiter = ',')]
+        columns: Vec<String>,
+
+        /// Report findings of the same issue on a column once, with combined confidence
+        #[arg(long)]
+        merge_duplicates: bool,
+
+        /// Find values with the same meaning using the LLM provider's embeddings
+        /// (Ollama or OpenAI), besides the built-in synonym table
+        #[arg(long)]
+        semantic_embeddings: bool,
+
+        /// Strictness preset: lenient, standard, or strict (strict implies --strict)
+        #[arg(long, default_value = "standard")]
+        profile: crucible::Profile,
+
+        /// Number and date conventions of the data: en-US (1,234.56 and
+        /// MM/DD/YYYY, the default) or de-DE (1.234,56 and DD.MM.YYYY)
+        #[arg(long)]
+        locale: Option<crucible::Locale>,
+
+        /// Ask which role ambiguous columns play instead of letting the LLM guess
+        #[arg(long)]
+        interactive: bool,
+
+        /// Show how each column's type was inferred: candidate types with
+        /// their votes, each source's weight and confidence, and why
+        #[arg(long)]
+        explain_inference: bool,
+
+        /// Exit non-zero when observations of this severity or worse are found:
+        /// error, warning, or none (default: error with --strict, else none)
+        #[arg(long, value_name = "SEVERITY")]
+        fail_on: Option<FailOn>,
+
+        /// Analyze every worksheet of an Excel workbook into one curation
+        /// file, with IDs prefixed by sheet name (needs the xlsx feature)
+        #[arg(long, conflicts_with_all = ["against", "mixs_package", "interactive", "explain_inference"])]
+        all_sheets: bool,
     },
 
     /// Open web UI for interactive curation review
@@ -64,8 +153,8 @@ pub enum Commands {
     /// Apply accepted decisions and export curated data
     Apply {
         /// Path to curation file
-        #[arg(value_name = "CURATION_FILE")]
-        file: PathBuf,
+        #[arg(value_name = "CURATION_FILE", required_unless_present = "revert")]
+        file: Option<PathBuf>,
 
         /// Output path for curated data
         #[arg(short, long)]
@@ -75,9 +164,43 @@ pub enum Commands {
         #[arg(short, long, default_value = "tsv")]
         format: OutputFormat,
 
-        /// Include audit metadata columns
+        /// Include audit metadata columns and write an `<output>.audit.json` trail
         #[arg(long)]
         with_audit: bool,
+
+        /// Write duplicate or empty headers as they were in the source file
+        #[arg(long)]
+        restore_headers: bool,
+
+        /// Preview the changes without writing any output file
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Maximum number of sample changes to show with --dry-run
+        #[arg(long, default_value = "10", requires = "dry_run")]
+        limit: usize,
+
+        /// Let column renames replace an existing column of the same name
+        #[arg(long)]
+        force: bool,
+
+        /// Restore original values in curated data using its `.audit.json` trail
+        #[arg(
+            long,
+            value_name = "AUDIT_FILE",
+            conflicts_with_all = ["file", "with_audit", "restore_headers", "dry_run", "force"]
+        )]
+        revert: Option<PathBuf>,
+
+        /// Record accept, reject, or defer decisions from a review sheet
+        /// (e.g. made with `crucible status --format csv`) before applying
+        #[arg(long, value_name = "SHEET", conflicts_with = "revert")]
+        decisions: Option<PathBuf>,
+
+        /// Table of a multi-table curation file to apply, such as a workbook
+        /// sheet analyzed with `analyze --all-sheets`
+        #[arg(long, value_name = "NAME", conflicts_with = "revert")]
+        table: Option<String>,
     },
 
     /// Show curation progress and summary
@@ -89,13 +212,57 @@ pub enum Commands {
         /// Output as JSON
         #[arg(long)]
         json: bool,
+
+        /// Output format: text, json, sarif (SARIF 2.1.0 observations), or
+        /// csv (one row per observation, for triage in a spreadsheet)
+        #[arg(long, default_value = "text", conflicts_with = "json")]
+        format: StatusFormat,
+
+        /// Print the dataset fingerprint for change detection (with
+        /// `--format sarif`, added to the run's properties)
+        #[arg(long)]
+        fingerprint: bool,
+
+        /// Show how observation confidences are distributed per observation type
+        #[arg(long)]
+        confidence: bool,
+
+        /// Profile every column: types, missing values, distinct and top
+        /// values, quartiles and string lengths
+        #[arg(long, conflicts_with = "confidence")]
+        profile: bool,
+
+        /// Exit non-zero when the curation has observations of this severity
+        /// or worse: error, warning, or none
+        #[arg(long, value_name = "SEVERITY", default_value = "none")]
+        fail_on: FailOn,
+    },
+
+    /// Show one observation with its full evidence and the rows it cites
+    Explain {
+        /// Path to curation file or data file
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+
+        /// Observation ID (e.g. obs_012)
+        #[arg(value_name = "OBSERVATION_ID")]
+        observation_id: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
     },
 
-    /// Preview changes that would be applied
+    /// Preview changes that would be applied, or compare two curation layers
     Diff {
         /// Path to curation file
-        #[arg(value_name = "CURATION_FILE")]
-        file: PathBuf,
+        #[arg(value_name = "CURATION_FILE", required_unless_present = "layers")]
+        file: Option<PathBuf>,
+
+        /// Compare two curation layers of the same dataset (e.g. before and
+        /// after re-analysis) instead of previewing changes
+        #[arg(long, num_args = 2, value_names = ["OLD", "NEW"], conflicts_with = "file")]
+        layers: Option<Vec<PathBuf>>,
 
         /// Number of context lines around changes
         #[arg(short = 'C', long, default_value = "3")]
@@ -106,6 +273,45 @@ pub enum Commands {
         changed_only: bool,
     },
 
+    /// Export the inferred schema of a data or curation file
+    Schema {
+        /// Path to data file or curation file
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+
+        /// Output format: crucible (usable with analyze --against) or json-schema
+        #[arg(short, long, default_value = "crucible")]
+        format: SchemaFormat,
+
+        /// Output file (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Check NCBI BioSample submission readiness and MIxS compliance
+    Biosample {
+        /// Path to data file
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+
+        /// MIxS environmental package to check against (auto-detected by default)
+        #[arg(long, alias = "mixs-package", default_value = "auto")]
+        package: MixsPackageChoice,
+
+        /// Save the issues and their suggested fixes to a curation file,
+        /// to review and apply like any other suggestions
+        #[arg(long)]
+        emit_suggestions: bool,
+
+        /// Curation file to save with --emit-suggestions (default: <file>.curation.json)
+        #[arg(short, long, requires = "emit_suggestions")]
+        output: Option<PathBuf>,
+
+        /// Output the readiness report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
     /// Batch accept or reject suggestions by type
     Batch {
         /// Path to curation file
@@ -132,7 +338,7 @@ pub enum Commands {
         #[arg(long)]
         all: bool,
 
-        /// User name for the decision
+        /// User name recorded on each decision and its history
         #[arg(long, default_value = "batch")]
         user: String,
     },
@@ -144,6 +350,8 @@ pub enum OutputFormat {
     Tsv,
     Csv,
     Json,
+    /// Same column layout as a fixed-width source
+    FixedWidth,
     #[cfg(feature = "parquet")]
     Parquet,
 }
@@ -156,11 +364,12 @@ impl std::str::FromStr for OutputFormat {
             "tsv" => Ok(OutputFormat::Tsv),
             "csv" => Ok(OutputFormat::Csv),
             "json" => Ok(OutputFormat::Json),
+            "fixed-width" | "fwf" => Ok(OutputFormat::FixedWidth),
             #[cfg(feature = "parquet")]
             "parquet" => Ok(OutputFormat::Parquet),
             #[cfg(not(feature = "parquet"))]
             "parquet" => Err("Parquet support not enabled. Rebuild with --features parquet".to_string()),
-            _ => Err(format!("Unknown format: {}. Use tsv, csv, or json.", s)),
+            _ => Err(format!("Unknown format: {}. Use tsv, csv, json, or fixed-width.", s)),
         }
     }
 }
@@ -171,12 +380,97 @@ impl std::fmt::Display for OutputFormat {
             OutputFormat::Tsv => write!(f, "tsv"),
             OutputFormat::Csv => write!(f, "csv"),
             OutputFormat::Json => write!(f, "json"),
+            OutputFormat::FixedWidth => write!(f, "fixed-width"),
             #[cfg(feature = "parquet")]
             OutputFormat::Parquet => write!(f, "parquet"),
         }
     }
 }
 
+/// Output format for exported schemas
+#[derive(Clone, Debug, Default)]
+pub enum SchemaFormat {
+    /// Crucible's native schema format
+    #[default]
+    Crucible,
+    /// JSON Schema (draft 2020-12)
+    JsonSchema,
+}
+
+impl std::str::FromStr for SchemaFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "crucible" => Ok(SchemaFormat::Crucible),
+            "json-schema" | "jsonschema" => Ok(SchemaFormat::JsonSchema),
+            _ => Err(format!(
+                "Unknown schema format: {}. Use crucible or json-schema.",
+                s
+            )),
+        }
+    }
+}
+
+/// Output format for the status command
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum StatusFormat {
+    /// Human-readable summary
+    #[default]
+    Text,
+    /// JSON summary
+    Json,
+    /// SARIF 2.1.0 log of observations
+    Sarif,
+    /// CSV triage sheet of observations
+    Csv,
+}
+
+impl std::str::FromStr for StatusFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(StatusFormat::Text),
+            "json" => Ok(StatusFormat::Json),
+            "sarif" => Ok(StatusFormat::Sarif),
+            "csv" => Ok(StatusFormat::Csv),
+            _ => Err(format!(
+                "Unknown status format: {}. Use text, json, sarif, or csv.",
+                s
+            )),
+        }
+    }
+}
+
+/// Observation severity that makes a run exit non-zero
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum FailOn {
+    /// Fail on error observations (exit code 2)
+    Error,
+    /// Fail on error (exit code 2) or warning (exit code 3) observations
+    Warning,
+    /// Never fail on observations
+    #[default]
+    None,
+}
+
+impl std::str::FromStr for FailOn {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "error" | "errors" => Ok(FailOn::Error),
+            "warning" | "warnings" => Ok(FailOn::Warning),
+            "none" => Ok(FailOn::None),
+            _ => Err(format!(
+                "Unknown severity: {}. Use error, warning, or none.",
+                s
+            )),
+        }
+    }
+}
+
 /// LLM provider choice for analysis
 #[derive(Clone, Debug, Default)]
 pub enum LlmProviderChoice {
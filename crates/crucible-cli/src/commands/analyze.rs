@@ -1,77 +1,354 @@
 //! Analyze command - analyze a data file and create curation layer.
+//!
+//! Given a directory, every matching file is analyzed, optionally in
+//! parallel, and a summary table is printed.
 
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::io::{BufRead, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
 
 use colored::Colorize;
 use crucible::{
-    bio::{BioSampleValidator, BioValidator, MixsComplianceValidator, MixsPackage},
-    AnthropicProvider, ContextHints, Crucible, CurationContext, CurationLayer, LlmConfig,
-    MockProvider, OllamaProvider, OpenAIProvider, Parser, Severity,
+    bio::{
+        BioSampleValidator, BioValidator, CollectionDateValidator, MixsComplianceValidator,
+        MixsPackage, NcbiReadiness,
+    },
+    curation::{curation_path, llm_cache_path},
+    AnalysisResult, AnthropicProvider, ContextHints, Crucible, CurationContext, CurationLayer,
+    DataTable, LlmConfig, LlmProvider, MockProvider, Observation, ObservationCounts, ObservationType,
+    Locale, OllamaProvider, OpenAIProvider, Parser, Profile, ProgressEvent, SemanticRole, Severity,
+    SourceMetadata, TableSchema,
+    validation::ValidationEngine,
 };
 
 use crate::cli::{LlmProviderChoice, MixsPackageChoice};
 
+/// Extensions analyzed in a directory when no `--glob` is given.
+const DATA_EXTENSIONS: &[&str] = &["csv", "tsv", "txt", "psv", "gz", "xlsx", "jsonl", "ndjson"];
+
+/// Error type for work done on batch worker threads.
+type BatchError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Analysis settings shared by every file in a run.
+struct AnalyzeSettings {
+    domain: Option<String>,
+    mixs_package: Option<MixsPackageChoice>,
+    strict: bool,
+    against: Option<PathBuf>,
+    sample: Option<usize>,
+    no_cache: bool,
+    cache_ttl: Option<u64>,
+    disabled_validators: Vec<String>,
+    columns: Vec<String>,
+    merge_duplicates: bool,
+    semantic_embeddings: bool,
+    profile: Profile,
+    locale: Option<Locale>,
+    interactive: bool,
+}
+
+impl AnalyzeSettings {
+    /// Build a Crucible instance for one file, sharing the LLM provider.
+    ///
+    /// Column roles chosen earlier are pinned, so they aren't asked about again.
+    fn crucible(
+        &self,
+        file: &Path,
+        provider: Option<&Arc<dyn LlmProvider>>,
+        roles: &HashMap<String, SemanticRole>,
+    ) -> Crucible {
+        let mut crucible = Crucible::new()
+            .with_profile(self.profile)
+            .with_strict(self.strict)
+            .with_merged_duplicates(self.merge_duplicates)
+            .with_semantic_embeddings(self.semantic_embeddings)
+            .with_auto_answer(!self.interactive);
+        if !self.columns.is_empty() {
+            crucible = crucible.with_columns(&self.columns);
+        }
+        if let Some(rows) = self.sample {
+            crucible = crucible.with_sample(rows);
+        }
+
+        if let Some(provider) = provider {
+            crucible = crucible.with_shared_llm(Arc::clone(provider));
+
+            // Reuse LLM responses from earlier runs unless disabled
+            if !self.no_cache {
+                crucible = crucible.with_llm_cache(llm_cache_path(file));
+                if let Some(hours) = self.cache_ttl {
+                    crucible = crucible.with_llm_cache_ttl(Duration::from_secs(hours * 3600));
+                }
+            }
+        }
+
+        let hints = self.hints(roles);
+        if !hints.is_empty() {
+            crucible = crucible.with_context(hints);
+        }
+        crucible
+    }
+
+    /// Context hints from the domain, locale, disabled validators, and the
+    /// column roles chosen.
+    fn hints(&self, roles: &HashMap<String, SemanticRole>) -> ContextHints {
+        let mut hints = ContextHints::new().with_disabled_validators(&self.disabled_validators);
+        if let Some(ref d) = self.domain {
+            hints = hints.with_domain(d);
+        }
+        if let Some(locale) = self.locale {
+            hints = hints.with_locale(locale);
+        }
+        for (column, role) in roles {
+            hints = hints.with_column_role(column, *role);
+        }
+        hints
+    }
+
+    /// Whether any warning fails the run, as with `--strict` or the strict profile.
+    fn fails_on_warnings(&self) -> bool {
+        self.strict || self.profile.scores_warnings_as_errors()
+    }
+
+    /// Observation counts deciding the exit code, with warnings counted as
+    /// errors when they fail the run.
+    fn exit_counts(&self, counts: ObservationCounts) -> ObservationCounts {
+        if self.fails_on_warnings() {
+            counts.escalated()
+        } else {
+            counts
+        }
+    }
+
+    /// Context recorded in the curation layer, including the column roles chosen.
+    fn context(&self, roles: &HashMap<String, SemanticRole>) -> CurationContext {
+        let mut context = CurationContext::new();
+        if let Some(ref d) = self.domain {
+            context = context.with_domain(d.clone());
+        }
+        context.hints.column_roles = roles.clone();
+        context
+    }
+}
+
+/// Overwrite the status line on stderr with the analysis stage reached.
+fn show_progress_event(event: ProgressEvent) {
+    let mut stderr = std::io::stderr().lock();
+    let _ = write!(stderr, "\r\x1b[2K  {}", event.label().dimmed());
+    let _ = stderr.flush();
+}
+
+/// MIxS compliance and NCBI BioSample results for one file.
+pub struct BioReport {
+    /// MIxS and collection date observations.
+    pub observations: Vec<Observation>,
+    /// Observations for BioSample readiness issues.
+    pub readiness_observations: Vec<Observation>,
+    /// Package validated against: the one chosen, or the detected one when
+    /// auto. `None` when auto-detection found no package.
+    pub package: Option<MixsPackage>,
+    /// MIxS compliance score (0.0-1.0).
+    pub compliance_score: f64,
+    /// NCBI BioSample submission readiness.
+    pub readiness: NcbiReadiness,
+}
+
+/// Analyze a data file, or each data file in a directory, returning the
+/// observation counts that decide the exit code.
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     file: PathBuf,
     output: Option<PathBuf>,
+    stdin_name: String,
     domain: Option<String>,
     llm: LlmProviderChoice,
     model: Option<String>,
     mixs_package: Option<MixsPackageChoice>,
+    strict: bool,
+    against: Option<PathBuf>,
+    sample: Option<usize>,
+    no_cache: bool,
+    cache_ttl: Option<u64>,
+    glob: Option<String>,
+    parallel: usize,
+    disable: Vec<String>,
+    columns: Vec<String>,
+    merge_duplicates: bool,
+    semantic_embeddings: bool,
+    profile: Profile,
+    locale: Option<Locale>,
+    interactive: bool,
+    explain_inference: bool,
+    all_sheets: bool,
     verbose: bool,
-) -> Result<(), Box<dyn std::error::Error>> {
-    // Validate input file exists
-    if !file.exists() {
+) -> Result<ObservationCounts, Box<dyn std::error::Error>> {
+    // Read the data from stdin when the file is "-"
+    let stdin_data = if file.as_os_str() == "-" {
+        if output.is_none() {
+            return Err("Reading from stdin (-) needs --output for the curation file".into());
+        }
+        if interactive {
+            return Err("--interactive can't be used when reading data from stdin".into());
+        }
+        let mut data = Vec::new();
+        std::io::stdin().read_to_end(&mut data)?;
+        Some(data)
+    } else if !file.exists() {
         return Err(format!("File not found: {}", file.display()).into());
+    } else {
+        None
+    };
+    ValidationEngine::check_validator_names(&disable)?;
+
+    let settings = AnalyzeSettings {
+        domain,
+        mixs_package,
+        strict,
+        against,
+        sample,
+        no_cache,
+        cache_ttl,
+        disabled_validators: disable,
+        columns,
+        merge_duplicates,
+        semantic_embeddings,
+        profile,
+        locale,
+        interactive,
+    };
+
+    if stdin_data.is_none() && file.is_dir() {
+        if interactive {
+            return Err("--interactive can only be used when analyzing a single file".into());
+        }
+        if explain_inference {
+            return Err("--explain-inference can only be used when analyzing a single file".into());
+        }
+        return run_batch(&file, glob.as_deref(), parallel, output, llm, model, settings, verbose);
+    }
+    if glob.is_some() {
+        return Err("--glob can only be used when analyzing a directory".into());
+    }
+    if all_sheets {
+        if stdin_data.is_some() {
+            return Err("--all-sheets needs a workbook file; workbooks can't be read from stdin".into());
+        }
+        return run_workbook(&file, output, llm, model, settings, verbose);
     }
 
+    let output_path = output.unwrap_or_else(|| curation_path(&file));
+    let source = SourceMetadata::stream(&stdin_name);
+    // Stdin data is treated as a file named after it, beside the curation file
+    let data_path = match stdin_data {
+        Some(_) => output_path.with_file_name(&stdin_name),
+        None => file.clone(),
+    };
+
     println!(
         "{} {}",
         "Analyzing".cyan().bold(),
-        file.display().to_string().white()
+        match stdin_data {
+            Some(_) => format!("{} (stdin)", stdin_name),
+            None => file.display().to_string(),
+        }
+        .white()
     );
 
     // Build Crucible instance with selected LLM provider
-    let crucible = create_crucible_with_provider(llm, model, verbose)?;
+    let provider = create_provider(llm, model, verbose)?;
+    if verbose && provider.is_some() && !settings.no_cache {
+        println!("  {} {}", "LLM cache:".dimmed(), llm_cache_path(&data_path).display());
+    }
+    if settings.semantic_embeddings && provider.is_none() {
+        println!(
+            "{} --semantic-embeddings needs an LLM provider; using the built-in synonym table",
+            "Warning:".yellow().bold()
+        );
+    }
+    let mut roles = prior_roles(&output_path);
 
-    // Add domain context if provided
-    let crucible = if let Some(ref d) = domain {
-        crucible.with_context(ContextHints::new().with_domain(d))
-    } else {
-        crucible
+    // Run analysis, against a frozen schema contract if given
+    let contract = settings.against.as_ref().map(TableSchema::load).transpose()?;
+    let show_progress = std::io::stderr().is_terminal();
+    let analyze = |roles: &HashMap<String, SemanticRole>| {
+        let mut crucible = settings.crucible(&data_path, provider.as_ref(), roles);
+        if show_progress {
+            crucible = crucible.with_progress(show_progress_event);
+        }
+        let result = match (&stdin_data, contract.as_ref()) {
+            (Some(data), Some(contract)) => {
+                crucible.analyze_reader_against(data.as_slice(), source.clone(), contract)
+            }
+            (Some(data), None) => crucible.analyze_reader(data.as_slice(), source.clone()),
+            (None, Some(contract)) => crucible.analyze_against(&file, contract),
+            (None, None) => crucible.analyze(&file),
+        };
+        if show_progress {
+            eprint!("\r\x1b[2K");
+        }
+        result
     };
+    let mut result = analyze(&roles)?;
+    if !result.source.missing_columns.is_empty() {
+        println!(
+            "{} column(s) not found: {}",
+            "Warning:".yellow().bold(),
+            result.source.missing_columns.join(", ")
+        );
+    }
 
-    // Run analysis
-    let mut result = crucible.analyze(&file)?;
-
-    // Run MIxS compliance validation if requested
-    if let Some(ref pkg) = mixs_package {
-        let is_auto = matches!(pkg, MixsPackageChoice::Auto);
-        let mixs_pkg = convert_mixs_package(pkg);
-
-        // Parse the file to get data for bio validation
-        let parser = Parser::new();
-        let (table, _) = parser.parse_file(&file)?;
+    // Ask about ambiguous column roles, then re-analyze with the answers
+    if settings.interactive && result.open_questions().next().is_some() {
+        ask_questions(&result, &mut roles)?;
+        result = analyze(&roles)?;
+    }
 
-        let mut validator = MixsComplianceValidator::new();
-        if !is_auto {
-            validator = validator.with_package(mixs_pkg);
+    if let Some(ref schema_path) = settings.against {
+        let violations = result
+            .observations
+            .iter()
+            .filter(|o| o.observation_type == ObservationType::ContractViolation)
+            .count();
+        if violations == 0 {
+            println!("{} {}", "Contract satisfied:".green().bold(), schema_path.display());
+        } else {
+            println!(
+                "{} {} deviations from {}",
+                "Contract violated:".red().bold(),
+                violations,
+                schema_path.display()
+            );
         }
+    }
 
-        let bio_observations = validator.validate(&table, &result.schema);
+    // Run MIxS compliance validation if requested
+    if let Some(ref pkg) = settings.mixs_package {
+        let (table, _) = match stdin_data {
+            Some(ref data) => Parser::new().parse_reader(data.as_slice(), source.clone())?,
+            None => Parser::new().parse_file(&file)?,
+        };
+        let hints = settings.hints(&roles);
+        let bio = validate_bio(&table, pkg, &result.schema, settings.profile, &hints)?;
 
         if verbose {
             println!();
             println!("{}", "MIxS Compliance:".yellow().bold());
-            let detected = validator.detect_package(&table, &result.schema);
-            println!("  Package: {:?}", detected.unwrap_or(mixs_pkg));
-            let score = validator.compliance_score(&table, &result.schema);
-            println!("  Compliance score: {:.0}%", score * 100.0);
+            match bio.package {
+                Some(package) => {
+                    println!("  Package: {:?}", package);
+                    println!("  Compliance score: {:.0}%", bio.compliance_score * 100.0);
+                }
+                None => println!("  No MIxS package detected"),
+            }
         }
 
         // Count bio observations by severity
-        let bio_errors = bio_observations.iter().filter(|o| o.severity == Severity::Error).count();
-        let bio_warnings = bio_observations.iter().filter(|o| o.severity == Severity::Warning).count();
+        let bio_errors = bio.observations.iter().filter(|o| o.severity == Severity::Error).count();
+        let bio_warnings = bio.observations.iter().filter(|o| o.severity == Severity::Warning).count();
 
         if bio_errors > 0 || bio_warnings > 0 {
             println!(
@@ -83,17 +360,9 @@ pub fn run(
         }
 
         // Merge bio observations into result
-        result.observations.extend(bio_observations);
-
-        // Run NCBI BioSample pre-validation
-        let biosample_validator = BioSampleValidator::new();
-        let detected_pkg = if is_auto {
-            validator.detect_package(&table, &result.schema)
-        } else {
-            Some(mixs_pkg)
-        };
-        let readiness = biosample_validator.check_readiness(&table, &result.schema, detected_pkg);
+        result.observations.extend(bio.observations);
 
+        let readiness = &bio.readiness;
         println!();
         let ready_status = if readiness.is_ready {
             "READY".green().bold()
@@ -131,8 +400,7 @@ pub fn run(
         }
 
         // Add BioSample observations to result
-        let biosample_observations = biosample_validator.to_observations(&readiness);
-        result.observations.extend(biosample_observations);
+        result.observations.extend(bio.readiness_observations);
     }
 
     if verbose {
@@ -147,25 +415,57 @@ pub fn run(
             );
         }
         println!();
+
+        let violated: Vec<_> = result.violated_constraints().collect();
+        if !violated.is_empty() {
+            println!("{}", "Inferred constraints already violated:".yellow().bold());
+            for report in violated {
+                println!(
+                    "  {:20} {:?}: {}/{} values ({:.1}%)",
+                    report.column,
+                    report.constraint,
+                    report.violations,
+                    report.checked,
+                    report.violation_rate * 100.0
+                );
+            }
+            println!();
+        }
+    }
+
+    if explain_inference {
+        print_explanations(&result.schema);
     }
 
     // Count by severity
-    let error_count = result
-        .observations
-        .iter()
-        .filter(|o| o.severity == Severity::Error)
-        .count();
-    let warning_count = result
-        .observations
-        .iter()
-        .filter(|o| o.severity == Severity::Warning)
-        .count();
+    let (error_count, warning_count) = severity_counts(&result);
     let info_count = result
         .observations
         .iter()
         .filter(|o| o.severity == Severity::Info)
         .count();
 
+    if let (Some(sheet), Some(count)) = (&result.source.sheet, result.source.sheet_count) {
+        println!("{} {} ({} in workbook)", "Worksheet:".dimmed(), sheet, count);
+    }
+    if let Some(sampled) = result.source.sampled_rows {
+        println!(
+            "{} {} of {} rows",
+            "Sampled:".dimmed(),
+            sampled,
+            result.source.row_count
+        );
+    }
+    if let Some(delimiter) = result.source.detected_delimiter {
+        let name = match delimiter {
+            '\t' => "tab".to_string(),
+            ',' => "comma".to_string(),
+            ';' => "semicolon".to_string(),
+            '|' => "pipe".to_string(),
+            other => format!("'{}'", other),
+        };
+        println!("{} {}", "Detected delimiter:".dimmed(), name);
+    }
     println!(
         "Found {} observations ({} errors, {} warnings, {} info)",
         result.observations.len().to_string().white().bold(),
@@ -177,21 +477,18 @@ pub fn run(
         "Generated {} suggestions",
         result.suggestions.len().to_string().white().bold()
     );
+    let open_questions = result.open_questions().count();
+    if open_questions > 0 {
+        println!(
+            "{} {} ambiguous column role(s) guessed; rerun with {} to choose",
+            "Note:".dimmed(),
+            open_questions,
+            "--interactive".cyan()
+        );
+    }
 
     // Create curation layer
-    let mut context = CurationContext::new();
-    if let Some(d) = domain {
-        context = context.with_domain(d);
-    }
-    let curation = CurationLayer::from_analysis(result, context);
-
-    // Determine output path
-    let output_path = output.unwrap_or_else(|| {
-        let mut p = file.clone();
-        let stem = p.file_stem().unwrap_or_default().to_string_lossy();
-        p.set_file_name(format!("{}.curation.json", stem));
-        p
-    });
+    let curation = CurationLayer::from_analysis(result, settings.context(&roles));
 
     // Save curation layer
     curation.save(&output_path)?;
@@ -210,34 +507,122 @@ pub fn run(
         curation.summary.data_quality_score * 100.0
     );
 
+    // Apply and review look for stdin data beside the curation file
+    if stdin_data.is_some() && !data_path.exists() {
+        println!(
+            "{} The data came from stdin; save it as {} to apply the curation",
+            "Note:".yellow().bold(),
+            data_path.display().to_string().cyan()
+        );
+    }
+
     if curation.suggestions.is_empty() {
         println!("{}", "No issues found - data looks clean!".green());
     } else {
         println!(
             "Run {} to review suggestions",
-            format!("crucible review {}", file.display())
+            format!("crucible review {}", match stdin_data {
+                Some(_) => output_path.display(),
+                None => file.display(),
+            })
                 .cyan()
                 .bold()
         );
     }
 
+    // In strict mode, any warning fails the run just like an error
+    Ok(settings.exit_counts(ObservationCounts {
+        error: error_count,
+        warning: warning_count,
+        info: info_count,
+    }))
+}
+
+/// Print how each column's type was inferred.
+fn print_explanations(schema: &TableSchema) {
+    println!("{}", "Type inference:".yellow().bold());
+    for col in &schema.columns {
+        println!(
+            "  {} {}",
+            col.name.white().bold(),
+            format!("{:?}", col.inferred_type).cyan()
+        );
+        let Some(explanation) = &col.explanation else {
+            println!("    {}", "No explanation recorded".dimmed());
+            continue;
+        };
+        for candidate in &explanation.candidates {
+            println!(
+                "    {:10} {:>6} values ({:.0}%)",
+                format!("{:?}", candidate.column_type),
+                candidate.votes,
+                candidate.share * 100.0
+            );
+        }
+        for source in &explanation.sources {
+            let marker = if source.used { "✓".green() } else { "·".dimmed() };
+            println!(
+                "    {} {:12} weight {:.2}, confidence {:.2}: {}",
+                marker, source.source, source.weight, source.confidence, source.detail
+            );
+        }
+        println!("    {}", explanation.rationale.dimmed());
+    }
+    println!();
+}
+
+/// Column roles chosen in an earlier run, kept in its curation layer.
+fn prior_roles(curation_file: &Path) -> HashMap<String, SemanticRole> {
+    CurationLayer::load(curation_file)
+        .map(|layer| layer.context.hints.column_roles)
+        .unwrap_or_default()
+}
+
+/// Ask which role each ambiguous column plays, recording the answers.
+///
+/// An empty answer (or end of input) keeps the role inference ranked first.
+fn ask_questions(
+    result: &AnalysisResult,
+    roles: &mut HashMap<String, SemanticRole>,
+) -> std::io::Result<()> {
+    let stdin = std::io::stdin();
+    for question in result.open_questions() {
+        println!();
+        println!("{} {}", "?".cyan().bold(), question.prompt);
+        for (i, role) in question.options.iter().enumerate() {
+            let default = if i == 0 { " (default)" } else { "" };
+            println!("  {}) {}{}", i + 1, role.label(), default.dimmed());
+        }
+
+        let role = loop {
+            print!("Choice [1]: ");
+            std::io::stdout().flush()?;
+            let mut line = String::new();
+            if stdin.lock().read_line(&mut line)? == 0 || line.trim().is_empty() {
+                break question.role_or_default();
+            }
+            match question.resolve(&line) {
+                Some(role) => break role,
+                None => println!("  {}", "Enter a number or one of the roles listed".yellow()),
+            }
+        };
+        roles.insert(question.column.clone(), role);
+    }
     Ok(())
 }
 
-/// Create a Crucible instance with the selected LLM provider.
-fn create_crucible_with_provider(
+/// Create the selected LLM provider, shared by every file in a run.
+fn create_provider(
     provider: LlmProviderChoice,
     model: Option<String>,
     verbose: bool,
-) -> Result<Crucible, Box<dyn std::error::Error>> {
-    let crucible = Crucible::new();
-
+) -> Result<Option<Arc<dyn LlmProvider>>, Box<dyn std::error::Error>> {
     match provider {
         LlmProviderChoice::None => {
             if verbose {
                 println!("  {} rule-based analysis (no LLM)", "Using".dimmed());
             }
-            Ok(crucible)
+            Ok(None)
         }
         LlmProviderChoice::Anthropic => {
             if verbose {
@@ -252,7 +637,7 @@ fn create_crucible_with_provider(
                     config,
                 )?;
             }
-            Ok(crucible.with_llm(provider))
+            Ok(Some(Arc::new(provider)))
         }
         LlmProviderChoice::OpenAI => {
             if verbose {
@@ -260,14 +645,9 @@ fn create_crucible_with_provider(
             }
             let mut provider = OpenAIProvider::from_env()?;
             if let Some(m) = model {
-                let mut config = LlmConfig::default();
-                config.model = m;
-                provider = OpenAIProvider::with_config(
-                    std::env::var("OPENAI_API_KEY")?,
-                    config,
-                )?;
+                provider = provider.with_model(m);
             }
-            Ok(crucible.with_llm(provider))
+            Ok(Some(Arc::new(provider)))
         }
         LlmProviderChoice::Ollama => {
             let model_name = model.as_deref().unwrap_or("llama3.2");
@@ -275,19 +655,402 @@ fn create_crucible_with_provider(
                 println!("  {} Ollama local model: {}", "Using".dimmed(), model_name);
             }
             let provider = OllamaProvider::with_model(model_name)?;
-            Ok(crucible.with_llm(provider))
+            Ok(Some(Arc::new(provider)))
         }
         LlmProviderChoice::Mock => {
             if verbose {
                 println!("  {} mock LLM (for testing)", "Using".dimmed());
             }
-            Ok(crucible.with_llm(MockProvider::new()))
+            Ok(Some(Arc::new(MockProvider::new())))
+        }
+    }
+}
+
+/// Run MIxS compliance and NCBI BioSample validation on a file.
+///
+/// Collection dates are read with the study window and locale of the
+/// context.
+pub fn validate_bio(
+    table: &DataTable,
+    pkg: &MixsPackageChoice,
+    schema: &TableSchema,
+    profile: Profile,
+    context: &ContextHints,
+) -> crucible::Result<BioReport> {
+    let is_auto = matches!(pkg, MixsPackageChoice::Auto);
+    let mixs_pkg = convert_mixs_package(pkg);
+
+    let mut validator = MixsComplianceValidator::new().with_blocking(profile.mixs_blocking());
+    if !is_auto {
+        validator = validator.with_package(mixs_pkg);
+    }
+
+    let mut observations = validator.validate(table, schema);
+
+    // Semantic date sanity, reported separately from the format check
+    observations.extend(CollectionDateValidator::new().with_context(context).validate(table, schema));
+
+    // Run NCBI BioSample pre-validation
+    let detected = validator.detect_package(table, schema);
+    let biosample_validator = BioSampleValidator::new();
+    let readiness_pkg = if is_auto { detected } else { Some(mixs_pkg) };
+    let readiness = biosample_validator.check_readiness(table, schema, readiness_pkg);
+
+    Ok(BioReport {
+        observations,
+        readiness_observations: biosample_validator.to_observations(&readiness),
+        package: readiness_pkg,
+        compliance_score: validator.compliance_score(table, schema),
+        readiness,
+    })
+}
+
+/// Count error and warning observations in an analysis.
+fn severity_counts(result: &AnalysisResult) -> (usize, usize) {
+    let count = |severity| {
+        result
+            .observations
+            .iter()
+            .filter(|o| o.severity == severity)
+            .count()
+    };
+    (count(Severity::Error), count(Severity::Warning))
+}
+
+/// Summary of one file analyzed in a batch.
+struct BatchRow {
+    columns: usize,
+    errors: usize,
+    warnings: usize,
+    /// NCBI readiness score and status, when MIxS validation ran.
+    readiness: Option<(u8, bool)>,
+}
+
+/// Analyze every matching file in a directory.
+#[allow(clippy::too_many_arguments)]
+fn run_batch(
+    dir: &Path,
+    glob: Option<&str>,
+    parallel: usize,
+    output: Option<PathBuf>,
+    llm: LlmProviderChoice,
+    model: Option<String>,
+    settings: AnalyzeSettings,
+    verbose: bool,
+) -> Result<ObservationCounts, Box<dyn std::error::Error>> {
+    let files = batch_files(dir, glob)?;
+    if files.is_empty() {
+        return Err(format!(
+            "No files matching '{}' in {}",
+            glob.unwrap_or("*"),
+            dir.display()
+        )
+        .into());
+    }
+    check_stem_collisions(&files)?;
+
+    if let Some(ref out_dir) = output {
+        std::fs::create_dir_all(out_dir)?;
+    }
+
+    let workers = parallel.clamp(1, files.len());
+    println!(
+        "{} {} files in {} ({} {})",
+        "Analyzing".cyan().bold(),
+        files.len(),
+        dir.display().to_string().white(),
+        workers,
+        if workers == 1 { "worker" } else { "workers" }
+    );
+
+    // One provider for the whole batch, so clients and rate limits are shared
+    let provider = create_provider(llm, model, verbose)?;
+
+    // Workers pull the next unclaimed file until none remain
+    let next = AtomicUsize::new(0);
+    let outcomes: Mutex<Vec<(usize, Result<BatchRow, String>)>> = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(file) = files.get(index) else {
+                        break;
+                    };
+
+                    let outcome =
+                        analyze_batch_file(file, output.as_deref(), provider.as_ref(), &settings)
+                            .map_err(|e| e.to_string());
+                    match outcome {
+                        Ok(_) => println!("  {} {}", "✓".green(), file.display()),
+                        Err(ref e) => println!("  {} {}: {}", "✗".red(), file.display(), e),
+                    }
+                    outcomes.lock().unwrap().push((index, outcome));
+                }
+            });
+        }
+    });
+
+    let mut outcomes = outcomes.into_inner().unwrap();
+    outcomes.sort_by_key(|(index, _)| *index);
+
+    let show_readiness = settings.mixs_package.is_some();
+    println!();
+    print!(
+        "{:<40} {:>7} {:>7} {:>8}",
+        "File".bold(),
+        "Columns".bold(),
+        "Errors".bold(),
+        "Warnings".bold()
+    );
+    if show_readiness {
+        print!("  {}", "NCBI Readiness".bold());
+    }
+    println!();
+
+    let mut failures = Vec::new();
+    let mut counts = ObservationCounts::default();
+    for (index, outcome) in outcomes {
+        let name = display_name(dir, &files[index]);
+        let row = match outcome {
+            Ok(row) => row,
+            Err(e) => {
+                println!("{:<40} {:>7}", name, "failed".red());
+                failures.push((name, e));
+                continue;
+            }
+        };
+        counts.error += row.errors;
+        counts.warning += row.warnings;
+
+        print!(
+            "{:<40} {:>7} {:>7} {:>8}",
+            name,
+            row.columns,
+            row.errors.to_string().red(),
+            row.warnings.to_string().yellow()
+        );
+        if let Some((score, is_ready)) = row.readiness {
+            let status = if is_ready {
+                "READY".green()
+            } else {
+                "NOT READY".red()
+            };
+            print!("  {}% ({})", score, status);
+        }
+        println!();
+    }
+
+    if !failures.is_empty() {
+        println!();
+        println!("{}", "Failed:".red().bold());
+        for (name, e) in &failures {
+            println!("  {} {}: {}", "✗".red(), name, e);
         }
+        return Err(format!("{} of {} files failed", failures.len(), files.len()).into());
     }
+
+    // In strict mode, any warning fails the run just like an error
+    Ok(settings.exit_counts(counts))
+}
+
+/// Analyze every sheet of a workbook into one curation layer.
+///
+/// Observation and suggestion IDs are prefixed with the sheet name; apply
+/// the layer one sheet at a time with `apply --table`.
+fn run_workbook(
+    file: &Path,
+    output: Option<PathBuf>,
+    llm: LlmProviderChoice,
+    model: Option<String>,
+    settings: AnalyzeSettings,
+    verbose: bool,
+) -> Result<ObservationCounts, Box<dyn std::error::Error>> {
+    let output_path = output.unwrap_or_else(|| curation_path(file));
+    println!(
+        "{} {} (all sheets)",
+        "Analyzing".cyan().bold(),
+        file.display().to_string().white()
+    );
+
+    // Roles recorded for one sheet's columns don't carry over to the others
+    let provider = create_provider(llm, model, verbose)?;
+    let no_roles = HashMap::new();
+    let results = settings
+        .crucible(file, provider.as_ref(), &no_roles)
+        .analyze_workbook(file)?;
+
+    let mut counts = ObservationCounts::default();
+    println!();
+    println!(
+        "{:<30} {:>7} {:>7} {:>8}",
+        "Sheet".bold(),
+        "Columns".bold(),
+        "Errors".bold(),
+        "Warnings".bold()
+    );
+    for (name, result) in &results {
+        let (errors, warnings) = severity_counts(result);
+        counts.error += errors;
+        counts.warning += warnings;
+        println!(
+            "{:<30} {:>7} {:>7} {:>8}",
+            name,
+            result.schema.columns.len(),
+            errors.to_string().red(),
+            warnings.to_string().yellow()
+        );
+    }
+
+    let curation = CurationLayer::from_analyses(results, settings.context(&no_roles))?;
+    curation.save(&output_path)?;
+    println!();
+    println!(
+        "{} {}",
+        "Curation layer saved to".green(),
+        output_path.display().to_string().white().bold()
+    );
+    for name in curation.table_names() {
+        println!(
+            "  Apply with {}",
+            format!("crucible apply {} --table {}", output_path.display(), name).cyan()
+        );
+    }
+
+    Ok(settings.exit_counts(counts))
+}
+
+/// Analyze one file of a batch and save its curation layer.
+fn analyze_batch_file(
+    file: &Path,
+    out_dir: Option<&Path>,
+    provider: Option<&Arc<dyn LlmProvider>>,
+    settings: &AnalyzeSettings,
+) -> Result<BatchRow, BatchError> {
+    let output_path = match out_dir {
+        Some(dir) => dir.join(curation_path(file).file_name().unwrap_or_default()),
+        None => curation_path(file),
+    };
+    let roles = prior_roles(&output_path);
+
+    let crucible = settings.crucible(file, provider, &roles);
+    let mut result = match settings.against {
+        Some(ref schema_path) => crucible.analyze_against(file, &TableSchema::load(schema_path)?)?,
+        None => crucible.analyze(file)?,
+    };
+
+    let mut readiness = None;
+    if let Some(ref pkg) = settings.mixs_package {
+        let (table, _) = Parser::new().parse_file(file)?;
+        let hints = settings.hints(&roles);
+        let bio = validate_bio(&table, pkg, &result.schema, settings.profile, &hints)?;
+        readiness = Some((bio.readiness.score, bio.readiness.is_ready));
+        result.observations.extend(bio.observations);
+        result.observations.extend(bio.readiness_observations);
+    }
+
+    let (errors, warnings) = severity_counts(&result);
+    let columns = result.schema.columns.len();
+
+    let curation = CurationLayer::from_analysis(result, settings.context(&roles));
+    curation.save(&output_path)?;
+
+    Ok(BatchRow {
+        columns,
+        errors,
+        warnings,
+        readiness,
+    })
+}
+
+/// List the data files in a directory matching a glob, in name order.
+///
+/// Without a glob, files with a known data extension are analyzed. Curation
+/// and LLM cache files written by earlier runs are always skipped.
+fn batch_files(dir: &Path, glob: Option<&str>) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_file() {
+            continue;
+        }
+
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if name.ends_with(".curation.json") || name.ends_with(".llm-cache.json") {
+            continue;
+        }
+
+        let matches = match glob {
+            Some(pattern) => glob_match(pattern, &name),
+            None => path
+                .extension()
+                .is_some_and(|ext| DATA_EXTENSIONS.iter().any(|e| ext.eq_ignore_ascii_case(e))),
+        };
+        if matches {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// Fail when two files of a batch share a stem, as `x.csv` and `x.tsv` do,
+/// since their curation and LLM cache files would overwrite each other.
+fn check_stem_collisions(files: &[PathBuf]) -> Result<(), String> {
+    let mut seen: HashMap<PathBuf, &PathBuf> = HashMap::new();
+    for file in files {
+        let output = curation_path(file);
+        if let Some(other) = seen.insert(output.clone(), file) {
+            return Err(format!(
+                "{} and {} would both be saved to {}; rename one or narrow the batch with --glob",
+                other.display(),
+                file.display(),
+                output.display()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Match a file name against a glob supporting `*` and `?`.
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    // Position of the last `*` and the name position it was tried at
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = star {
+            // Let the last `*` absorb one more character
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, n));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// File path relative to the batch directory, for the summary table.
+fn display_name(dir: &Path, file: &Path) -> String {
+    file.strip_prefix(dir)
+        .unwrap_or(file)
+        .display()
+        .to_string()
 }
 
 /// Convert CLI MixsPackageChoice to library MixsPackage.
-fn convert_mixs_package(choice: &MixsPackageChoice) -> MixsPackage {
+pub fn convert_mixs_package(choice: &MixsPackageChoice) -> MixsPackage {
     match choice {
         MixsPackageChoice::HumanGut => MixsPackage::HumanGut,
         MixsPackageChoice::HumanOral => MixsPackage::HumanOral,
@@ -307,3 +1070,112 @@ fn convert_mixs_package(choice: &MixsPackageChoice) -> MixsPackage {
         MixsPackageChoice::Auto => MixsPackage::HumanGut, // Default for auto-detect start
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*.csv", "samples.csv"));
+        assert!(glob_match("*.csv", ".csv"));
+        assert!(!glob_match("*.csv", "samples.csv.gz"));
+        assert!(glob_match("run_??.tsv", "run_01.tsv"));
+        assert!(!glob_match("run_??.tsv", "run_1.tsv"));
+        assert!(glob_match("*_2024*", "plate_2024_03.csv"));
+        assert!(glob_match("a*b*c", "aXbYbZc"));
+        assert!(!glob_match("a*b*c", "aXbYbZ"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("", "x"));
+    }
+
+    #[test]
+    fn test_batch_files() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in [
+            "b.csv",
+            "a.TSV",
+            "c.jsonl",
+            "d.ndjson",
+            "e.csv.gz",
+            "notes.md",
+            "a.curation.json",
+            "a.llm-cache.json",
+        ] {
+            std::fs::write(dir.path().join(name), "id\n1\n").unwrap();
+        }
+        std::fs::create_dir(dir.path().join("nested.csv")).unwrap();
+
+        let names = |files: Vec<PathBuf>| -> Vec<String> {
+            files
+                .iter()
+                .map(|f| f.file_name().unwrap().to_string_lossy().into_owned())
+                .collect()
+        };
+
+        let files = batch_files(dir.path(), None).unwrap();
+        assert_eq!(
+            names(files),
+            vec!["a.TSV", "b.csv", "c.jsonl", "d.ndjson", "e.csv.gz"]
+        );
+
+        // A glob replaces the extension filter, but never picks up sidecars
+        let files = batch_files(dir.path(), Some("a*")).unwrap();
+        assert_eq!(names(files), vec!["a.TSV"]);
+    }
+
+    #[test]
+    fn test_stem_collisions() {
+        let dir = tempfile::tempdir().unwrap();
+        let files = vec![dir.path().join("x.csv"), dir.path().join("y.csv")];
+        assert!(check_stem_collisions(&files).is_ok());
+
+        let files = vec![dir.path().join("x.csv"), dir.path().join("x.tsv")];
+        let err = check_stem_collisions(&files).unwrap_err();
+        assert!(err.contains("x.curation.json"));
+    }
+
+    #[test]
+    fn test_validate_bio_reports_explicit_package() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("samples.csv");
+        std::fs::write(
+            &path,
+            "sample_id,host,body_site,collection_date\n\
+             S1,Homo sapiens,feces,2024-01-05\n\
+             S2,Homo sapiens,feces,2024-01-06\n",
+        )
+        .unwrap();
+        let result = Crucible::new().analyze(&path).unwrap();
+        let (table, _) = Parser::new().parse_file(&path).unwrap();
+
+        let bio = validate_bio(
+            &table,
+            &MixsPackageChoice::Soil,
+            &result.schema,
+            Profile::Standard,
+            &ContextHints::new(),
+        )
+        .unwrap();
+        assert_eq!(bio.package, Some(MixsPackage::Soil));
+    }
+
+    #[test]
+    fn test_validate_bio_auto_without_package() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("items.csv");
+        std::fs::write(&path, "item,price\nwidget,3\ngadget,5\n").unwrap();
+        let result = Crucible::new().analyze(&path).unwrap();
+        let (table, _) = Parser::new().parse_file(&path).unwrap();
+
+        let bio = validate_bio(
+            &table,
+            &MixsPackageChoice::Auto,
+            &result.schema,
+            Profile::Standard,
+            &ContextHints::new(),
+        )
+        .unwrap();
+        assert_eq!(bio.package, None);
+    }
+}
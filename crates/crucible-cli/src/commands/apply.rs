@@ -1,18 +1,35 @@
 //! Apply command - apply accepted decisions and export curated data.
 
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
 
 use colored::Colorize;
-use crucible::{CurationLayer, DecisionStatus, Parser, TransformEngine, TransformResult};
+use crucible::{
+    curation::data_stem, input::ParserConfig, CurationLayer, DecisionStatus, Parser, SourceMetadata,
+    TransformAudit, TransformEngine, TransformResult,
+};
 
 use crate::cli::OutputFormat;
 
+/// Apply accepted decisions to the source data and write the result.
+///
+/// With `preview` set, the transforms run as usual but nothing is written;
+/// instead a summary and up to `preview` sample changes are printed.
+/// Decisions from a triage sheet are recorded in the curation file first
+/// (only in memory when previewing). With `force` set, a column rename may
+/// replace an existing column of the same name. A multi-table curation file
+/// is applied one `table` at a time.
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     file: PathBuf,
     output: Option<PathBuf>,
     format: OutputFormat,
     with_audit: bool,
+    restore_headers: bool,
+    preview: Option<usize>,
+    decisions: Option<PathBuf>,
+    table: Option<String>,
+    force: bool,
     verbose: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
     if !file.exists() {
@@ -20,7 +37,59 @@ pub fn run(
     }
 
     // Load curation layer
-    let curation = CurationLayer::load(&file)?;
+    let mut curation = CurationLayer::load(&file)?;
+
+    if let Some(sheet) = decisions {
+        let report = curation.ingest_decisions_csv(&sheet)?;
+        println!(
+            "{} {} decision(s) from {} ({} unchanged)",
+            "Recorded".cyan().bold(),
+            report.recorded.len().to_string().white().bold(),
+            sheet.display(),
+            report.unchanged.len()
+        );
+        if !report.unmatched.is_empty() {
+            println!(
+                "{} IDs not in the curation layer: {}",
+                "Warning:".yellow().bold(),
+                report.unmatched.join(", ")
+            );
+        }
+        if !report.without_suggestions.is_empty() {
+            println!(
+                "{} No suggestions to decide for {}",
+                "Note:".yellow(),
+                report.without_suggestions.join(", ")
+            );
+        }
+        if preview.is_none() && !report.recorded.is_empty() {
+            curation.save(&file)?;
+        }
+    }
+
+    // A multi-table layer, such as a workbook's sheets, is applied per table
+    match table {
+        Some(ref name) => {
+            curation = curation.table(name).ok_or_else(|| {
+                format!(
+                    "Table '{}' not found in {} (tables: {})",
+                    name,
+                    file.display(),
+                    curation.table_names().join(", ")
+                )
+            })?;
+        }
+        None if !curation.tables.is_empty() => {
+            return Err(format!(
+                "{} covers {} tables ({}); pick one with --table",
+                file.display(),
+                curation.tables.len(),
+                curation.table_names().join(", ")
+            )
+            .into());
+        }
+        None => {}
+    }
 
     // Count approved decisions
     let approved: Vec<_> = curation
@@ -58,9 +127,9 @@ pub fn run(
         );
     }
 
-    // Parse the source data
-    let parser = Parser::new();
-    let (mut data, _source_metadata) = parser.parse_file(&source_path)?;
+    // Parse the source data the way it was analyzed
+    let parser = Parser::with_config(ParserConfig::for_source(&curation.source));
+    let (mut data, source_metadata) = parser.parse_file(&source_path)?;
 
     if verbose {
         println!(
@@ -72,7 +141,7 @@ pub fn run(
     }
 
     // Apply transformations
-    let engine = TransformEngine::new();
+    let engine = TransformEngine::new().with_force_renames(force);
     let result = engine.apply(&curation, &mut data)?;
 
     // Report changes
@@ -85,7 +154,7 @@ pub fn run(
         );
 
         for change in &result.changes {
-            if change.values_changed > 0 || verbose {
+            if change.values_changed > 0 || !change.columns_renamed.is_empty() || verbose {
                 println!(
                     "  {} {} ({} values)",
                     "•".dimmed(),
@@ -102,41 +171,40 @@ pub fn run(
         );
     }
 
+    if let Some(limit) = preview {
+        print_preview(&result, limit);
+        return Ok(());
+    }
+
     // Add audit columns if requested
     if with_audit {
         add_audit_columns(&mut data, &result, verbose)?;
     }
 
+    // Put back header names the parser made unique
+    if restore_headers && !source_metadata.header_renames.is_empty() {
+        data.restore_headers(&source_metadata.header_renames);
+        if verbose {
+            println!(
+                "  {} {} header(s)",
+                "Restored:".dimmed(),
+                source_metadata.header_renames.len().to_string().dimmed()
+            );
+        }
+    }
+
     // Determine output path
     let output_path = output.unwrap_or_else(|| {
         let source_file = PathBuf::from(&curation.source.file);
-        let stem = source_file.file_stem().unwrap_or_default().to_string_lossy();
-        let ext = match format {
-            OutputFormat::Tsv => "tsv",
-            OutputFormat::Csv => "csv",
-            OutputFormat::Json => "json",
-            #[cfg(feature = "parquet")]
-            OutputFormat::Parquet => "parquet",
+        let stem = match table {
+            Some(ref name) => format!("{}_{}", data_stem(&source_file), name),
+            None => data_stem(&source_file),
         };
-        file.with_file_name(format!("{}_curated.{}", stem, ext))
+        file.with_file_name(format!("{}_curated.{}", stem, extension(&format)))
     });
 
     // Write the transformed data
-    match format {
-        OutputFormat::Json => {
-            data.write_to_json(&output_path)?;
-        }
-        OutputFormat::Tsv => {
-            data.write_to_file(&output_path, b'\t')?;
-        }
-        OutputFormat::Csv => {
-            data.write_to_file(&output_path, b',')?;
-        }
-        #[cfg(feature = "parquet")]
-        OutputFormat::Parquet => {
-            write_parquet(&data, &output_path)?;
-        }
-    }
+    write_output(&data, &output_path, &format, Some(&source_metadata))?;
 
     println!();
     println!(
@@ -145,6 +213,50 @@ pub fn run(
         output_path.display().to_string().cyan()
     );
 
+    // The output's schema, with roles and constraints following renames
+    let schema_path = schema_path(&output_path);
+    result.output_schema(&curation.schema).save(&schema_path)?;
+    println!(
+        "{} {}",
+        "Schema:".green().bold(),
+        schema_path.display().to_string().cyan()
+    );
+
+    // Pseudonym mappings go to a separate keyfile, never the output
+    if !result.pseudonyms.is_empty() {
+        let keyfile_path = keyfile_path(&output_path);
+        write_private(&keyfile_path, &serde_json::to_string_pretty(&result.pseudonyms)?)?;
+        println!(
+            "{} {} (re-links pseudonyms; keep it private)",
+            "Keyfile:".green().bold(),
+            keyfile_path.display().to_string().cyan()
+        );
+    }
+
+    // Write the audit trail next to the output
+    if with_audit {
+        let audit = TransformAudit::new(&curation.schema, &result);
+        let audit_path = audit_path(&output_path);
+
+        let previous = std::fs::read_to_string(&audit_path)
+            .ok()
+            .and_then(|text| serde_json::from_str::<TransformAudit>(&text).ok());
+        if previous.is_some_and(|previous| previous.same_operations(&audit)) {
+            println!(
+                "{} These operations were already applied to {}",
+                "Note:".yellow(),
+                output_path.display()
+            );
+        }
+
+        std::fs::write(&audit_path, serde_json::to_string_pretty(&audit)?)?;
+        println!(
+            "{} {}",
+            "Audit:".green().bold(),
+            audit_path.display().to_string().cyan()
+        );
+    }
+
     // Show summary
     println!();
     println!("{}", "Summary:".white().bold());
@@ -166,6 +278,242 @@ pub fn run(
     Ok(())
 }
 
+/// Print what a dry run would change: cells per column, rows affected and
+/// the first `limit` before/after values.
+fn print_preview(result: &TransformResult, limit: usize) {
+    let audits: Vec<_> = result
+        .changes
+        .iter()
+        .flat_map(|change| &change.row_audits)
+        .filter(|audit| audit.original_value != audit.new_value)
+        .collect();
+
+    let mut by_column: BTreeMap<&str, usize> = BTreeMap::new();
+    for audit in &audits {
+        *by_column.entry(audit.column.as_str()).or_default() += 1;
+    }
+    let rows: BTreeSet<usize> = audits.iter().map(|audit| audit.row).collect();
+
+    println!();
+    println!(
+        "{} No files were written.",
+        "Dry run:".yellow().bold()
+    );
+
+    if audits.is_empty() {
+        return;
+    }
+
+    println!();
+    println!("{}", "Cells changed by column:".white().bold());
+    for (column, count) in &by_column {
+        println!("  {} {} ({})", "•".dimmed(), column, count.to_string().cyan());
+    }
+    println!("  {} rows affected", rows.len().to_string().cyan());
+
+    if limit == 0 {
+        return;
+    }
+
+    println!();
+    println!("{}", "Sample changes:".white().bold());
+    for audit in audits.iter().take(limit) {
+        println!(
+            "  row {} {}: {} → {}",
+            audit.row + 1,
+            audit.column.cyan(),
+            format!("{:?}", audit.original_value).red(),
+            format!("{:?}", audit.new_value).green()
+        );
+    }
+    if audits.len() > limit {
+        println!(
+            "  {}",
+            format!("... and {} more", audits.len() - limit).dimmed()
+        );
+    }
+}
+
+/// Undo an applied curation, restoring original values from its audit trail.
+///
+/// The curated data is read from next to the audit file (the audit path
+/// without `.audit.json`), and audit columns added by `--with-audit` are
+/// dropped.
+pub fn revert(
+    audit_file: PathBuf,
+    output: Option<PathBuf>,
+    format: OutputFormat,
+    verbose: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !audit_file.exists() {
+        return Err(format!("Audit file not found: {}", audit_file.display()).into());
+    }
+    let audit: TransformAudit = serde_json::from_str(&std::fs::read_to_string(&audit_file)?)?;
+
+    let data_path = audit_file
+        .to_str()
+        .and_then(|path| path.strip_suffix(".audit.json"))
+        .map(PathBuf::from)
+        .filter(|path| path.exists())
+        .ok_or_else(|| {
+            format!(
+                "Could not find the curated data for {}. Expected it next to the audit file.",
+                audit_file.display()
+            )
+        })?;
+
+    println!(
+        "{} {}",
+        "Reverting".cyan().bold(),
+        data_path.display().to_string().white().bold()
+    );
+
+    let (mut data, _) = Parser::new().parse_file(&data_path)?;
+    for column in AUDIT_COLUMNS {
+        if let Some(idx) = data.headers.iter().position(|h| h == column) {
+            data.remove_column(idx);
+        }
+    }
+
+    let result = TransformEngine::new().revert(&audit, &mut data)?;
+
+    let conflicts: Vec<_> = result
+        .changes
+        .iter()
+        .flat_map(|change| &change.row_audits)
+        .filter(|audit| audit.transform_type == "revert_conflict")
+        .collect();
+
+    println!();
+    for change in &result.changes {
+        println!("  {} {}", "•".dimmed(), change.description);
+    }
+    if !conflicts.is_empty() {
+        println!(
+            "{} {} value(s) changed since the curation was applied and were left as is",
+            "Warning:".yellow().bold(),
+            conflicts.len()
+        );
+        if verbose {
+            for conflict in &conflicts {
+                println!(
+                    "  row {} {}: {:?}",
+                    conflict.row + 1,
+                    conflict.column,
+                    conflict.original_value
+                );
+            }
+        }
+    }
+
+    let output_path = output.unwrap_or_else(|| {
+        let stem = data_stem(&data_path);
+        data_path.with_file_name(format!("{}_reverted.{}", stem, extension(&format)))
+    });
+    write_output(&data, &output_path, &format, None)?;
+
+    println!();
+    println!(
+        "{} {}",
+        "Saved:".green().bold(),
+        output_path.display().to_string().cyan()
+    );
+    println!(
+        "  {} values restored",
+        result.rows_modified.to_string().cyan()
+    );
+
+    Ok(())
+}
+
+/// Columns added by `--with-audit`.
+const AUDIT_COLUMNS: [&str; 3] = ["_crucible_modified", "_crucible_original", "_crucible_reason"];
+
+/// File extension for an output format.
+fn extension(format: &OutputFormat) -> &'static str {
+    match format {
+        OutputFormat::Tsv => "tsv",
+        OutputFormat::Csv => "csv",
+        OutputFormat::Json => "json",
+        OutputFormat::FixedWidth => "txt",
+        #[cfg(feature = "parquet")]
+        OutputFormat::Parquet => "parquet",
+    }
+}
+
+/// Write a table in the given output format.
+///
+/// Fixed-width output needs the source's column layout.
+fn write_output(
+    data: &crucible::DataTable,
+    path: &Path,
+    format: &OutputFormat,
+    source: Option<&SourceMetadata>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match format {
+        OutputFormat::Json => {
+            data.write_to_json(path)?;
+        }
+        OutputFormat::Tsv => {
+            data.write_to_file(path, b'\t')?;
+        }
+        OutputFormat::Csv => {
+            data.write_to_file(path, b',')?;
+        }
+        OutputFormat::FixedWidth => {
+            let (layout, has_header) = source
+                .and_then(|s| Some((s.fixed_width.as_deref()?, s.has_header)))
+                .ok_or("Fixed-width output needs a source parsed with a fixed-width layout")?;
+            data.write_fixed_width(path, layout, has_header)?;
+        }
+        #[cfg(feature = "parquet")]
+        OutputFormat::Parquet => {
+            write_parquet(data, path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Path of the audit trail for an output file: `<output>.audit.json`.
+fn audit_path(output: &Path) -> PathBuf {
+    let mut name = output.as_os_str().to_owned();
+    name.push(".audit.json");
+    PathBuf::from(name)
+}
+
+/// Path of the schema of an output file: `<output>.schema.json`.
+fn schema_path(output: &Path) -> PathBuf {
+    let mut name = output.as_os_str().to_owned();
+    name.push(".schema.json");
+    PathBuf::from(name)
+}
+
+/// Path of the pseudonym keyfile for an output file: `<output>.keys.json`.
+fn keyfile_path(output: &Path) -> PathBuf {
+    let mut name = output.as_os_str().to_owned();
+    name.push(".keys.json");
+    PathBuf::from(name)
+}
+
+/// Write a file only its owner can read or write (mode 0600 on Unix),
+/// tightening the permissions of a file already there.
+fn write_private(path: &Path, contents: &str) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        let file = options.open(path)?;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        (&file).write_all(contents.as_bytes())
+    }
+    #[cfg(not(unix))]
+    options.open(path)?.write_all(contents.as_bytes())
+}
+
 /// Add audit columns to track what was changed and why.
 fn add_audit_columns(
     data: &mut crucible::DataTable,
@@ -195,13 +543,12 @@ fn add_audit_columns(
     }
 
     // Add audit columns
-    data.add_column("_crucible_modified".to_string(), String::new());
-    data.add_column("_crucible_original".to_string(), String::new());
-    data.add_column("_crucible_reason".to_string(), String::new());
+    for column in AUDIT_COLUMNS {
+        data.add_column(column.to_string(), String::new());
+    }
 
-    let mod_col = data.column_index("_crucible_modified").unwrap();
-    let orig_col = data.column_index("_crucible_original").unwrap();
-    let reason_col = data.column_index("_crucible_reason").unwrap();
+    let [mod_col, orig_col, reason_col] =
+        AUDIT_COLUMNS.map(|column| data.column_index(column).unwrap());
 
     // Populate audit columns
     for row_idx in 0..data.row_count() {
@@ -228,7 +575,7 @@ fn add_audit_columns(
 }
 
 /// Resolve the source data file path from the curation layer.
-fn resolve_source_path(
+pub(crate) fn resolve_source_path(
     curation_file: &PathBuf,
     curation: &CurationLayer,
 ) -> Result<PathBuf, Box<dyn std::error::Error>> {
@@ -255,13 +602,22 @@ fn resolve_source_path(
 
     // If the curation file is "foo.curation.json", try "foo.tsv", "foo.csv"
     let data_stem = stem.trim_end_matches(".curation");
-    for ext in &["tsv", "csv", "txt"] {
+    for ext in &["tsv", "csv", "txt", "tsv.gz", "csv.gz"] {
         let data_file = curation_dir.join(format!("{}.{}", data_stem, ext));
         if data_file.exists() {
             return Ok(data_file);
         }
     }
 
+    // Data analyzed from stdin has no path; it is expected beside the curation file
+    if curation.source.is_stream() {
+        return Err(format!(
+            "The source data was read from stdin when analyzed. Save it as {} to continue.",
+            relative_path.display()
+        )
+        .into());
+    }
+
     Err(format!(
         "Could not find source data file. Tried:\n  - {}\n  - {}\nPlease ensure the source file exists.",
         stored_path.display(),
@@ -318,3 +674,85 @@ fn write_parquet(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible::{Crucible, CurationContext, SourceMetadata};
+
+    #[test]
+    fn test_resolve_stdin_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = "sample_id,mass\nS1,1.5\nS2,2.5\n";
+        let result = Crucible::new()
+            .analyze_reader(data.as_bytes(), SourceMetadata::stream("samples.csv"))
+            .unwrap();
+        let curation = CurationLayer::from_analysis(result, CurationContext::new());
+        let curation_file = dir.path().join("samples.curation.json");
+
+        // Until the data is saved beside the curation file, say where it goes
+        let err = resolve_source_path(&curation_file, &curation).unwrap_err();
+        assert!(err.to_string().contains("read from stdin"));
+        assert!(err.to_string().contains("samples.csv"));
+
+        let data_path = dir.path().join("samples.csv");
+        std::fs::write(&data_path, data).unwrap();
+        assert_eq!(resolve_source_path(&curation_file, &curation).unwrap(), data_path);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_keyfile_is_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.tsv.keys.json");
+        std::fs::write(&path, "old").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_private(&path, "{}").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "{}");
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn test_apply_one_table() {
+        let dir = tempfile::tempdir().unwrap();
+        let results = ["visits", "labs"]
+            .into_iter()
+            .map(|name| {
+                let path = dir.path().join(format!("{}.csv", name));
+                std::fs::write(&path, "id,site\n1,gut\n2,Gut\n3,gut\n4,gut\n5,GUT\n").unwrap();
+                (name.to_string(), Crucible::new().analyze(&path).unwrap())
+            })
+            .collect();
+        let mut curation = CurationLayer::from_analyses(results, CurationContext::new()).unwrap();
+        let id = curation.suggestions[0].id.clone();
+        curation.accept(&id).unwrap();
+        let curation_file = dir.path().join("book.curation.json");
+        curation.save(&curation_file).unwrap();
+
+        let apply = |table: Option<&str>| {
+            run(
+                curation_file.clone(),
+                None,
+                OutputFormat::Tsv,
+                false,
+                false,
+                None,
+                None,
+                table.map(str::to_string),
+                false,
+                false,
+            )
+        };
+        let err = apply(None).unwrap_err();
+        assert!(err.to_string().contains("pick one with --table"));
+        assert!(apply(Some("missing")).unwrap_err().to_string().contains("visits, labs"));
+
+        let table = id.split("::").next().unwrap();
+        apply(Some(table)).unwrap();
+        assert!(dir.path().join(format!("{}_{}_curated.tsv", table, table)).exists());
+    }
+}
@@ -56,11 +56,14 @@ pub fn run(
                         crucible::SuggestionAction::Coerce => "coerce",
                         crucible::SuggestionAction::ConvertDate => "convert_date",
                         crucible::SuggestionAction::Flag => "flag",
+                        crucible::SuggestionAction::Clamp => "clamp",
+                        crucible::SuggestionAction::ConvertUnit => "convert_unit",
                         crucible::SuggestionAction::Remove => "remove",
                         crucible::SuggestionAction::Merge => "merge",
                         crucible::SuggestionAction::Rename => "rename",
                         crucible::SuggestionAction::Split => "split",
                         crucible::SuggestionAction::Derive => "derive",
+                        crucible::SuggestionAction::Redact => "redact",
                     };
                     if !filter_lower.contains(snake) && snake != filter_lower {
                         return false;
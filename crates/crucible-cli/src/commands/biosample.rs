@@ -0,0 +1,143 @@
+//! Biosample command - check NCBI BioSample submission readiness.
+
+use std::path::PathBuf;
+
+use colored::Colorize;
+use crucible::bio::{IssueCategory, ReadinessIssue};
+use crucible::curation::curation_path;
+use crucible::input::ParserConfig;
+use crucible::{ContextHints, Crucible, CurationContext, CurationLayer, Parser, Profile, Severity, Suggestion};
+
+use crate::cli::MixsPackageChoice;
+use crate::commands::analyze::validate_bio;
+
+pub fn run(
+    file: PathBuf,
+    package: MixsPackageChoice,
+    emit_suggestions: bool,
+    output: Option<PathBuf>,
+    json_output: bool,
+    verbose: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !file.exists() {
+        return Err(format!("File not found: {}", file.display()).into());
+    }
+    if verbose {
+        eprintln!("{} {}", "Analyzing".cyan().bold(), file.display());
+    }
+
+    // Readiness is checked against the inferred schema and the data as parsed
+    let mut result = Crucible::new().analyze(&file)?;
+    let parser = Parser::with_config(ParserConfig::for_source(&result.source));
+    let (table, _) = parser.parse_file(&file)?;
+
+    let hints = ContextHints::new();
+    let bio = validate_bio(&table, &package, &result.schema, Profile::Standard, &hints)?;
+    let readiness = &bio.readiness;
+    let (readiness_observations, suggestions) = readiness.to_observations_with_suggestions();
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(readiness)?);
+    } else {
+        let ready_status = if readiness.is_ready {
+            "READY".green().bold()
+        } else {
+            "NOT READY".red().bold()
+        };
+        println!(
+            "{} {}% ({})",
+            "NCBI Readiness:".yellow().bold(),
+            readiness.score,
+            ready_status
+        );
+
+        let mixs_errors = bio.observations.iter().filter(|o| o.severity == Severity::Error).count();
+        let mixs_warnings =
+            bio.observations.iter().filter(|o| o.severity == Severity::Warning).count();
+        match bio.package {
+            Some(package) => println!(
+                "{} {:.0}% for {:?} ({} errors, {} warnings)",
+                "MIxS compliance:".yellow().bold(),
+                bio.compliance_score * 100.0,
+                package,
+                mixs_errors.to_string().red(),
+                mixs_warnings.to_string().yellow()
+            ),
+            None => println!(
+                "{} no MIxS package detected ({} errors, {} warnings)",
+                "MIxS compliance:".yellow().bold(),
+                mixs_errors.to_string().red(),
+                mixs_warnings.to_string().yellow()
+            ),
+        }
+        println!(
+            "{} {} samples, {} with issues",
+            "Samples:".dimmed(),
+            readiness.stats.total_samples,
+            readiness.stats.samples_with_issues
+        );
+
+        // Suggestions follow the issues in order: blocking first
+        let (blocking, warnings) = suggestions.split_at(readiness.blocking_issues.len());
+        print_issues(
+            &"Blocking issues (must fix):".red().to_string(),
+            &"✗".red().to_string(),
+            &readiness.blocking_issues,
+            blocking,
+        );
+        print_issues(
+            &"Warnings (should fix):".yellow().to_string(),
+            &"⚠".yellow().to_string(),
+            &readiness.warning_issues,
+            warnings,
+        );
+    }
+
+    if emit_suggestions {
+        let output_path = output.unwrap_or_else(|| curation_path(&file));
+        let count = suggestions.len();
+        result.observations.extend(bio.observations);
+        result.observations.extend(readiness_observations);
+        result.suggestions.extend(suggestions);
+        CurationLayer::from_analysis(result, CurationContext::new()).save(&output_path)?;
+
+        eprintln!();
+        eprintln!(
+            "{} {} suggested fixes to {}",
+            "Saved".green().bold(),
+            count,
+            output_path.display().to_string().white()
+        );
+        eprintln!(
+            "  Next: crucible review {} or crucible batch {} --accept --all",
+            output_path.display(),
+            output_path.display()
+        );
+    }
+
+    Ok(())
+}
+
+/// Print issues grouped by category, with the kind of fix suggested for each.
+fn print_issues(heading: &str, marker: &str, issues: &[ReadinessIssue], fixes: &[Suggestion]) {
+    if issues.is_empty() {
+        return;
+    }
+    let mut by_category: Vec<(IssueCategory, Vec<(&ReadinessIssue, &Suggestion)>)> = Vec::new();
+    for (issue, fix) in issues.iter().zip(fixes) {
+        match by_category.iter_mut().find(|(category, _)| *category == issue.category) {
+            Some((_, entries)) => entries.push((issue, fix)),
+            None => by_category.push((issue.category, vec![(issue, fix)])),
+        }
+    }
+
+    println!();
+    println!("{}", heading);
+    for (category, entries) in by_category {
+        println!("  {} ({})", category.label().bold(), entries.len());
+        for (issue, fix) in entries {
+            println!("    {} {}", marker, issue.description);
+            println!("      {} {}", "→".dimmed(), fix.action.label().dimmed());
+        }
+    }
+}
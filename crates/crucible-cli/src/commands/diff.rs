@@ -1,9 +1,11 @@
-//! Diff command - preview changes that would be applied.
+//! Diff command - preview changes that would be applied, or compare two
+//! curation layers of the same dataset.
 
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 use colored::Colorize;
-use crucible::{CurationLayer, DecisionStatus};
+use crucible::{CurationLayer, DecisionStatus, Observation, Suggestion};
 
 pub fn run(
     file: PathBuf,
@@ -57,7 +59,7 @@ pub fn run(
                     observation.description
                 );
 
-                if let Some(notes) = &decision.notes {
+                if let Some(notes) = decision.reason() {
                     println!("    Notes: {}", notes.dimmed());
                 }
             }
@@ -74,3 +76,278 @@ pub fn run(
 
     Ok(())
 }
+
+/// One line of a layer comparison, shown under its column.
+#[derive(Debug, PartialEq)]
+enum LayerChange {
+    ObservationAdded(String),
+    ObservationRemoved(String),
+    SuggestionAdded(String),
+    SuggestionRemoved(String),
+    DecisionChanged {
+        suggestion: String,
+        from: DecisionStatus,
+        to: DecisionStatus,
+    },
+}
+
+/// Compare two curation layers, e.g. before and after re-analysis.
+///
+/// IDs differ between analysis runs, so observations are matched by column,
+/// type, and description, and suggestions by their observation and action.
+pub fn run_layers(old: &Path, new: &Path, verbose: bool) -> Result<(), Box<dyn std::error::Error>> {
+    for path in [old, new] {
+        if !path.exists() {
+            return Err(format!("Curation file not found: {}", path.display()).into());
+        }
+    }
+
+    let old_layer = CurationLayer::load(old)?;
+    let new_layer = CurationLayer::load(new)?;
+
+    println!(
+        "{} {} → {}",
+        "Comparing".cyan().bold(),
+        old.display().to_string().white(),
+        new.display().to_string().white()
+    );
+    if old_layer.fingerprint() != new_layer.fingerprint() {
+        println!(
+            "{} the layers were created from different data",
+            "Warning:".yellow().bold()
+        );
+    }
+    if verbose {
+        println!(
+            "  {} {} → {}",
+            "Crucible version:".dimmed(),
+            old_layer.crucible_version,
+            new_layer.crucible_version
+        );
+    }
+    println!();
+
+    let by_column = layer_changes(&old_layer, &new_layer);
+    if by_column.is_empty() {
+        println!("{}", "No differences between the layers.".green());
+        return Ok(());
+    }
+
+    let (mut added, mut removed, mut changed) = (0, 0, 0);
+    for (column, changes) in &by_column {
+        println!("{}", column.white().bold());
+        for change in changes {
+            match change {
+                LayerChange::ObservationAdded(text) => {
+                    added += 1;
+                    println!("  {} {}", "+".green(), text);
+                }
+                LayerChange::ObservationRemoved(text) => {
+                    removed += 1;
+                    println!("  {} {}", "-".red(), text);
+                }
+                LayerChange::SuggestionAdded(text) => {
+                    added += 1;
+                    println!("  {} {} {}", "+".green(), "suggestion".dimmed(), text);
+                }
+                LayerChange::SuggestionRemoved(text) => {
+                    removed += 1;
+                    println!("  {} {} {}", "-".red(), "suggestion".dimmed(), text);
+                }
+                LayerChange::DecisionChanged {
+                    suggestion,
+                    from,
+                    to,
+                } => {
+                    changed += 1;
+                    println!(
+                        "  {} {} {}: {} → {}",
+                        "~".yellow(),
+                        "decision".dimmed(),
+                        suggestion,
+                        from.label(),
+                        to.label()
+                    );
+                }
+            }
+        }
+        println!();
+    }
+
+    println!(
+        "{} added, {} removed, {} decisions changed",
+        added.to_string().green(),
+        removed.to_string().red(),
+        changed.to_string().yellow()
+    );
+
+    Ok(())
+}
+
+/// Changes from one layer to the next, grouped by column.
+fn layer_changes(
+    old_layer: &CurationLayer,
+    new_layer: &CurationLayer,
+) -> BTreeMap<String, Vec<LayerChange>> {
+    let mut by_column: BTreeMap<String, Vec<LayerChange>> = BTreeMap::new();
+
+    // Observations: unmatched old ones disappeared, unmatched new ones appeared
+    let mut unmatched: Vec<&Observation> = old_layer.observations.iter().collect();
+    for obs in &new_layer.observations {
+        match unmatched.iter().position(|o| observation_key(o) == observation_key(obs)) {
+            Some(index) => {
+                unmatched.remove(index);
+            }
+            None => by_column
+                .entry(obs.column.clone())
+                .or_default()
+                .push(LayerChange::ObservationAdded(describe_observation(obs))),
+        }
+    }
+    for obs in unmatched {
+        by_column
+            .entry(obs.column.clone())
+            .or_default()
+            .push(LayerChange::ObservationRemoved(describe_observation(obs)));
+    }
+
+    // Suggestions, and decision status changes for those in both layers
+    let mut unmatched: Vec<(&Suggestion, String)> = old_layer
+        .suggestions
+        .iter()
+        .map(|s| (s, suggestion_key(old_layer, s)))
+        .collect();
+    for suggestion in &new_layer.suggestions {
+        let key = suggestion_key(new_layer, suggestion);
+        let column = suggestion_column(new_layer, suggestion);
+        match unmatched.iter().position(|(_, k)| *k == key) {
+            Some(index) => {
+                let (old_suggestion, _) = unmatched.remove(index);
+                let from = decision_status(old_layer, old_suggestion);
+                let to = decision_status(new_layer, suggestion);
+                if from != to {
+                    by_column.entry(column).or_default().push(LayerChange::DecisionChanged {
+                        suggestion: describe_suggestion(suggestion),
+                        from,
+                        to,
+                    });
+                }
+            }
+            None => by_column
+                .entry(column)
+                .or_default()
+                .push(LayerChange::SuggestionAdded(describe_suggestion(suggestion))),
+        }
+    }
+    for (suggestion, _) in unmatched {
+        by_column
+            .entry(suggestion_column(old_layer, suggestion))
+            .or_default()
+            .push(LayerChange::SuggestionRemoved(describe_suggestion(suggestion)));
+    }
+
+    by_column
+}
+
+/// Identity of an observation across analysis runs.
+fn observation_key(obs: &Observation) -> (&str, &str, &str) {
+    (&obs.column, obs.observation_type.label(), &obs.description)
+}
+
+/// Identity of a suggestion across analysis runs.
+fn suggestion_key(layer: &CurationLayer, suggestion: &Suggestion) -> String {
+    let observation = layer
+        .observation(&suggestion.observation_id)
+        .map(|o| format!("{:?}", observation_key(o)))
+        .unwrap_or_default();
+    format!("{}|{}", observation, suggestion.action.label())
+}
+
+/// Column a suggestion applies to, via its observation.
+fn suggestion_column(layer: &CurationLayer, suggestion: &Suggestion) -> String {
+    layer
+        .observation(&suggestion.observation_id)
+        .map(|o| o.column.clone())
+        .unwrap_or_default()
+}
+
+fn decision_status(layer: &CurationLayer, suggestion: &Suggestion) -> DecisionStatus {
+    layer
+        .decision_for(&suggestion.id)
+        .map(|d| d.status.clone())
+        .unwrap_or(DecisionStatus::Pending)
+}
+
+fn describe_observation(obs: &Observation) -> String {
+    format!("[{}] {}", obs.observation_type.label(), obs.description)
+}
+
+fn describe_suggestion(suggestion: &Suggestion) -> String {
+    format!(
+        "[{}] {}",
+        suggestion.action.label().to_uppercase(),
+        suggestion.rationale
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible::{Crucible, CurationContext};
+
+    fn layer(dir: &Path) -> CurationLayer {
+        let data = dir.join("samples.csv");
+        std::fs::write(
+            &data,
+            "sample_id,site,mass\nS1,gut,1.5\nS2,Gut,missing\nS3,GUT,2.5\nS4,gut,missing\nS5,gut,3.5\n",
+        )
+        .unwrap();
+        let result = Crucible::new().analyze(&data).unwrap();
+        CurationLayer::from_analysis(result, CurationContext::new())
+    }
+
+    #[test]
+    fn test_layer_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let old = layer(dir.path());
+        assert!(layer_changes(&old, &old).is_empty());
+
+        // Re-analysis gives new IDs, which don't count as changes
+        let mut new = layer(dir.path());
+        assert!(layer_changes(&old, &new).is_empty());
+
+        let suggestion = new.suggestions[0].clone();
+        new.accept(&suggestion.id).unwrap();
+        let column = suggestion_column(&new, &suggestion);
+        let removed = new
+            .observations
+            .iter()
+            .position(|o| o.id != suggestion.observation_id)
+            .map(|i| new.observations.remove(i))
+            .unwrap();
+
+        let changes = layer_changes(&old, &new);
+        assert!(changes[&column].contains(&LayerChange::DecisionChanged {
+            suggestion: describe_suggestion(&suggestion),
+            from: DecisionStatus::Pending,
+            to: DecisionStatus::Accepted,
+        }));
+        assert!(changes[&removed.column]
+            .contains(&LayerChange::ObservationRemoved(describe_observation(&removed))));
+
+        // Going back, the observation reappears
+        let changes = layer_changes(&new, &old);
+        assert!(changes[&removed.column]
+            .contains(&LayerChange::ObservationAdded(describe_observation(&removed))));
+    }
+
+    #[test]
+    fn test_run_layers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("samples.curation.json");
+        layer(dir.path()).save(&path).unwrap();
+
+        assert!(run_layers(&path, &path, true).is_ok());
+        assert!(run_layers(&path, &dir.path().join("missing.json"), false).is_err());
+    }
+}
@@ -0,0 +1,340 @@
+//! Explain command - show one observation with its full evidence.
+
+use std::path::PathBuf;
+
+use colored::Colorize;
+use crucible::input::ParserConfig;
+use crucible::{CurationLayer, DataTable, Observation, Parser, Severity};
+use serde_json::Value;
+
+use crate::commands::apply::resolve_source_path;
+
+pub fn run(
+    file: PathBuf,
+    observation_id: String,
+    json_output: bool,
+    verbose: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let is_curation_file = file.extension().map(|e| e == "json").unwrap_or(false);
+    let curation_path = if is_curation_file {
+        file.clone()
+    } else {
+        crucible::curation::curation_path(&file)
+    };
+
+    if !curation_path.exists() {
+        return Err(format!(
+            "Curation file not found: {}\nRun 'crucible analyze {}' first.",
+            curation_path.display(),
+            file.display()
+        )
+        .into());
+    }
+
+    let curation = CurationLayer::load(&curation_path)?;
+    let observation = curation.observation(&observation_id).ok_or_else(|| {
+        format!(
+            "Observation '{}' not found in {} ({} observations)",
+            observation_id,
+            curation_path.display(),
+            curation.observations.len()
+        )
+    })?;
+
+    // Pull the sample rows from the source data when it can be found
+    let data = match resolve_source_path(&curation_path, &curation) {
+        Ok(path) => {
+            let parser = Parser::with_config(ParserConfig::for_source(&curation.source));
+            let (table, source) = parser.parse_file(&path)?;
+            if source.hash != curation.source.hash {
+                eprintln!(
+                    "{} {} has changed since it was analyzed; sample rows may not match the observation",
+                    "Warning:".yellow().bold(),
+                    path.display()
+                );
+            }
+            Some(table)
+        }
+        Err(e) => {
+            if verbose {
+                eprintln!("{} {}", "Note:".yellow().bold(), e);
+            }
+            None
+        }
+    };
+    let rows: Vec<SampleRow> = observation
+        .evidence
+        .sample_rows
+        .iter()
+        .map(|&row| SampleRow::resolve(row, observation, data.as_ref()))
+        .collect();
+
+    if json_output {
+        let rows: Vec<Value> = rows.iter().map(SampleRow::to_json).collect();
+        let suggestions: Vec<_> = curation
+            .suggestions
+            .iter()
+            .filter(|s| s.observation_id == observation.id)
+            .collect();
+        let explained = serde_json::json!({
+            "observation": observation,
+            "sample_rows": rows,
+            "suggestions": suggestions,
+        });
+        println!("{}", serde_json::to_string_pretty(&explained)?);
+        return Ok(());
+    }
+
+    print_observation(observation);
+    print_evidence(observation, &rows, data.is_some());
+
+    if let Some(ref explanation) = observation.llm_explanation {
+        println!();
+        println!("{}", "LLM explanation:".yellow().bold());
+        println!("  {}", explanation);
+    }
+
+    let suggestions: Vec<_> = curation
+        .suggestions
+        .iter()
+        .filter(|s| s.observation_id == observation.id)
+        .collect();
+    if !suggestions.is_empty() {
+        println!();
+        println!("{}", "Suggestions:".yellow().bold());
+        for suggestion in suggestions {
+            let status = curation
+                .decision_for(&suggestion.id)
+                .map(|d| d.status.label().to_lowercase())
+                .unwrap_or_else(|| "pending".to_string());
+            println!(
+                "  {} [{}] {} ({})",
+                suggestion.id.dimmed(),
+                suggestion.action.label().cyan(),
+                suggestion.rationale,
+                status
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// A sample row of the evidence, resolved against the source data.
+struct SampleRow {
+    /// Zero-based data row index, as stored in the evidence.
+    row: usize,
+    /// Line in the source file, for line-oriented formats.
+    line: Option<usize>,
+    /// Value of the observation's column in this row.
+    value: Option<String>,
+    /// The whole row, by column.
+    record: Vec<(String, String)>,
+}
+
+impl SampleRow {
+    fn resolve(
+        row: usize,
+        observation: &Observation,
+        table: Option<&DataTable>,
+    ) -> Self {
+        let Some(table) = table else {
+            return Self {
+                row,
+                line: None,
+                value: None,
+                record: Vec::new(),
+            };
+        };
+
+        let record: Vec<(String, String)> = table
+            .headers
+            .iter()
+            .enumerate()
+            .map(|(col, header)| {
+                let value = table.get(row, col).unwrap_or_default().to_string();
+                (header.clone(), value)
+            })
+            .collect();
+        let value = table
+            .column_index(&observation.column)
+            .and_then(|col| table.get(row, col))
+            .map(str::to_string);
+
+        Self {
+            row,
+            line: table.row_line(row),
+            value,
+            record,
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let record: serde_json::Map<String, Value> = self
+            .record
+            .iter()
+            .map(|(header, value)| (header.clone(), Value::String(value.clone())))
+            .collect();
+        serde_json::json!({
+            "row": self.row,
+            "line": self.line,
+            "value": self.value,
+            "record": record,
+        })
+    }
+}
+
+fn print_observation(observation: &Observation) {
+    let severity = match observation.severity {
+        Severity::Error => "ERROR".red().bold(),
+        Severity::Warning => "WARNING".yellow().bold(),
+        Severity::Info => "INFO".blue().bold(),
+    };
+
+    println!(
+        "{} {} {}",
+        severity,
+        observation.id.white().bold(),
+        observation.observation_type.label().cyan()
+    );
+    println!("  {}", observation.description);
+    println!();
+    println!("  {:<11} {}", "Column:".dimmed(), observation.column);
+    println!("  {:<11} {}", "Detector:".dimmed(), observation.detector);
+    println!(
+        "  {:<11} {:.2}",
+        "Confidence:".dimmed(),
+        observation.confidence
+    );
+    println!(
+        "  {:<11} {}",
+        "Detected:".dimmed(),
+        observation.detected_at.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+}
+
+fn print_evidence(observation: &Observation, rows: &[SampleRow], has_data: bool) {
+    let evidence = &observation.evidence;
+    println!();
+    println!("{}", "Evidence:".yellow().bold());
+
+    if let Some(occurrences) = evidence.occurrences {
+        match evidence.percentage {
+            Some(pct) => println!("  Occurrences: {} ({:.1}%)", occurrences, pct),
+            None => println!("  Occurrences: {}", occurrences),
+        }
+    }
+    if let Some(ref pattern) = evidence.pattern {
+        println!("  Pattern:     {}", pattern);
+    }
+    if let Some(z) = evidence.z_score {
+        println!("  Z-score:     {:.2}", z);
+    }
+    if let Some(ref value) = evidence.value {
+        println!("  Value:       {}", compact(value));
+    }
+    if let Some(ref expected) = evidence.expected {
+        println!("  Expected:    {}", compact(expected));
+    }
+    if let Some(ref counts) = evidence.value_counts {
+        println!("  Value counts:");
+        match counts.as_object() {
+            Some(counts) => {
+                for (value, count) in counts {
+                    println!("    {:?}: {}", value, compact(count));
+                }
+            }
+            None => println!("    {}", compact(counts)),
+        }
+    }
+
+    if rows.is_empty() {
+        return;
+    }
+    println!();
+    println!("{}", "Sample rows:".yellow().bold());
+    if !has_data {
+        let indices: Vec<String> = rows.iter().map(|r| (r.row + 1).to_string()).collect();
+        println!("  rows {} (source data not found)", indices.join(", "));
+        return;
+    }
+    for row in rows {
+        let location = match row.line {
+            Some(line) => format!("row {} (line {})", row.row + 1, line),
+            None => format!("row {}", row.row + 1),
+        };
+        let value = row.value.as_deref().unwrap_or_default();
+        println!("  {} {}: {:?}", location.white().bold(), observation.column.cyan(), value);
+        let record: Vec<String> = row
+            .record
+            .iter()
+            .map(|(header, value)| format!("{}={:?}", header, value))
+            .collect();
+        println!("    {}", record.join(" ").dimmed());
+    }
+}
+
+/// Render a JSON value on one line, without quotes around plain strings.
+fn compact(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible::{Crucible, CurationContext, ObservationType};
+
+    #[test]
+    fn test_sample_row_lines_follow_parser() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.csv");
+        std::fs::write(
+            &path,
+            "# exported 2024-01-01\nid,note\n1,\"two\nlines\"\n2,plain\n",
+        )
+        .unwrap();
+        let config = ParserConfig::default().with_comment_prefix('#');
+        let (table, _) = Parser::with_config(config).parse_file(&path).unwrap();
+        let observation = Observation::new(
+            ObservationType::Inconsistency,
+            Severity::Warning,
+            "NOTE",
+            "Odd note",
+        );
+
+        // The comment and the multi-line field come before row 2
+        let row = SampleRow::resolve(1, &observation, Some(&table));
+        assert_eq!(row.line, Some(5));
+        assert_eq!(row.value.as_deref(), Some("plain"));
+        assert_eq!(row.to_json()["record"]["id"], "2");
+
+        let row = SampleRow::resolve(1, &observation, None);
+        assert_eq!(row.line, None);
+        assert!(row.record.is_empty());
+    }
+
+    #[test]
+    fn test_explain_observation() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("samples.csv");
+        std::fs::write(
+            &data,
+            "sample_id,site\nS1,gut\nS2,Gut\nS3,GUT\nS4,gut\nS5,gut\n",
+        )
+        .unwrap();
+        let result = Crucible::new().analyze(&data).unwrap();
+        let curation = CurationLayer::from_analysis(result, CurationContext::new());
+        let curation_path = crucible::curation::curation_path(&data);
+        curation.save(&curation_path).unwrap();
+
+        let id = curation.observations[0].id.clone();
+        assert!(run(data.clone(), id.clone(), true, false).is_ok());
+        assert!(run(curation_path, id, false, false).is_ok());
+
+        let err = run(data, "obs_missing".to_string(), false, false).unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+}
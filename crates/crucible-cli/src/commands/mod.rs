@@ -3,6 +3,9 @@
 pub mod analyze;
 pub mod apply;
 pub mod batch;
+pub mod biosample;
 pub mod diff;
+pub mod explain;
 pub mod review;
+pub mod schema;
 pub mod status;
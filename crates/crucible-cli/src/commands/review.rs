@@ -68,9 +68,7 @@ pub fn run(
         (data_path, file.clone())
     } else {
         // It's a data file, derive curation path
-        let stem = file.file_stem().unwrap_or_default().to_string_lossy();
-        let curation_path = file.with_file_name(format!("{}.curation.json", stem));
-        (file.clone(), curation_path)
+        (file.clone(), crucible::curation::curation_path(&file))
     };
 
     // Detect LLM provider from environment
@@ -103,6 +101,21 @@ pub fn run(
         curation
     };
 
+    // Data analyzed from stdin is expected beside the curation file
+    let data_path = if curation.source.is_stream() {
+        let path = curation_path.with_file_name(&curation.source.file);
+        if !path.exists() {
+            println!(
+                "{} The data was read from stdin when analyzed; save it as {} before applying",
+                "Note:".yellow(),
+                path.display()
+            );
+        }
+        path
+    } else {
+        data_path
+    };
+
     // Create app state with or without LLM
     let state = if let Some(provider) = llm_provider {
         AppState::with_llm(curation, curation_path.clone(), data_path.clone(), provider)
@@ -0,0 +1,46 @@
+//! Schema command - export the inferred schema of a file.
+
+use std::path::PathBuf;
+
+use colored::Colorize;
+use crucible::{Crucible, CurationLayer};
+
+use crate::cli::SchemaFormat;
+
+pub fn run(
+    file: PathBuf,
+    format: SchemaFormat,
+    output: Option<PathBuf>,
+    verbose: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let is_curation_file = file.extension().map(|e| e == "json").unwrap_or(false);
+
+    // Reuse the schema from a curation file, or infer it from the data
+    let schema = if is_curation_file {
+        CurationLayer::load(&file)?.schema
+    } else {
+        if verbose {
+            eprintln!("{} {}", "Analyzing".cyan().bold(), file.display());
+        }
+        Crucible::new().analyze(&file)?.schema
+    };
+
+    let json = match format {
+        SchemaFormat::Crucible => serde_json::to_string_pretty(&schema)?,
+        SchemaFormat::JsonSchema => serde_json::to_string_pretty(&schema.to_json_schema())?,
+    };
+
+    match output {
+        Some(path) => {
+            std::fs::write(&path, json)?;
+            eprintln!(
+                "{} {}",
+                "Saved schema to".green().bold(),
+                path.display().to_string().white()
+            );
+        }
+        None => println!("{}", json),
+    }
+
+    Ok(())
+}
@@ -3,22 +3,47 @@
 use std::path::PathBuf;
 
 use colored::Colorize;
-use crucible::{CurationLayer, DecisionStatus};
+use crucible::validation::CONFIDENCE_BUCKETS;
+use crucible::schema::ColumnSchema;
+use crucible::{ConfidenceHistogram, CurationLayer, DecisionStatus, ObservationCounts, Parser};
+
+use crate::cli::StatusFormat;
+use crate::{sarif, triage};
 
 pub fn run(
     file: PathBuf,
-    json_output: bool,
+    format: StatusFormat,
+    fingerprint: bool,
+    confidence: bool,
+    profile: bool,
     _verbose: bool,
-) -> Result<(), Box<dyn std::error::Error>> {
+) -> Result<ObservationCounts, Box<dyn std::error::Error>> {
+    let json_output = format == StatusFormat::Json;
+    let is_curation_file = file.extension().map(|e| e == "json").unwrap_or(false);
+
+    // A data file can be fingerprinted directly, without a curation layer,
+    // unless the fingerprint goes in a SARIF log of its observations
+    if fingerprint && !is_curation_file && format != StatusFormat::Sarif {
+        let (table, source) = Parser::new().parse_file(&file)?;
+        let fp = source.fingerprint(&table.headers);
+        if json_output {
+            let status = serde_json::json!({
+                "file": source.file,
+                "fingerprint": fp,
+            });
+            println!("{}", serde_json::to_string_pretty(&status)?);
+        } else {
+            println!("{}", fp);
+        }
+        return Ok(ObservationCounts::default());
+    }
+
     // Determine curation file path
-    let curation_path = if file.extension().map(|e| e == "json").unwrap_or(false) {
+    let curation_path = if is_curation_file {
         file.clone()
     } else {
         // Assume it's a data file, look for .curation.json
-        let mut p = file.clone();
-        let stem = p.file_stem().unwrap_or_default().to_string_lossy();
-        p.set_file_name(format!("{}.curation.json", stem));
-        p
+        crucible::curation::curation_path(&file)
     };
 
     if !curation_path.exists() {
@@ -32,10 +57,49 @@ pub fn run(
 
     // Load curation layer
     let curation = CurationLayer::load(&curation_path)?;
+    let counts = curation.summary.observations_by_severity.clone();
+
+    if format == StatusFormat::Sarif {
+        println!("{}", serde_json::to_string_pretty(&sarif::to_sarif(&curation, fingerprint))?);
+        return Ok(counts);
+    }
+
+    if format == StatusFormat::Csv {
+        triage::write_csv(&curation, std::io::stdout().lock())?;
+        return Ok(counts);
+    }
+
+    if fingerprint && !json_output {
+        println!("{}", curation.fingerprint());
+        return Ok(counts);
+    }
+
+    if confidence {
+        let histogram = curation.confidence_histogram();
+        if json_output {
+            println!("{}", serde_json::to_string_pretty(&histogram)?);
+        } else {
+            print_confidence_histogram(&histogram);
+        }
+        return Ok(counts);
+    }
+
+    if profile {
+        if json_output {
+            let profiles = serde_json::json!({
+                "file": curation.source.file,
+                "columns": curation.schema.columns.iter().map(ColumnSchema::profile).collect::<Vec<_>>(),
+            });
+            println!("{}", serde_json::to_string_pretty(&profiles)?);
+        } else {
+            print_profiles(&curation.schema.columns);
+        }
+        return Ok(counts);
+    }
 
     if json_output {
         // JSON output
-        let status = serde_json::json!({
+        let mut status = serde_json::json!({
             "file": curation.source.file,
             "progress": curation.progress(),
             "total_suggestions": curation.suggestions.len(),
@@ -45,14 +109,20 @@ pub fn run(
                 "modified": curation.decisions.iter().filter(|d| d.status == DecisionStatus::Modified).count(),
                 "rejected": curation.decisions.iter().filter(|d| d.status == DecisionStatus::Rejected).count(),
                 "applied": curation.decisions.iter().filter(|d| d.status == DecisionStatus::Applied).count(),
+                "deferred": curation.deferred_suggestions().len(),
             },
             "observations": {
                 "total": curation.observations.len(),
                 "by_severity": curation.summary.observations_by_severity,
             },
             "data_quality_score": curation.summary.data_quality_score,
+            "profile": curation.profile,
+            "locale": curation.locale.to_string(),
             "is_complete": curation.is_complete(),
         });
+        if fingerprint {
+            status["fingerprint"] = serde_json::json!(curation.fingerprint());
+        }
         println!("{}", serde_json::to_string_pretty(&status)?);
     } else {
         // Human-readable output
@@ -61,6 +131,8 @@ pub fn run(
             "Curation status for".cyan().bold(),
             curation.source.file.white()
         );
+        println!("Profile: {}", curation.profile);
+        println!("Locale: {}", curation.locale);
         println!();
 
         // Progress bar
@@ -111,6 +183,10 @@ pub fn run(
         if applied > 0 {
             println!("  Applied:  {}", applied.to_string().magenta());
         }
+        let deferred = curation.deferred_suggestions().len();
+        if deferred > 0 {
+            println!("  Deferred: {}", deferred.to_string().dimmed());
+        }
         println!();
 
         // Observation summary
@@ -178,5 +254,86 @@ pub fn run(
         }
     }
 
-    Ok(())
+    Ok(counts)
+}
+
+/// Print observation counts per confidence decile, one row per observation type.
+fn print_confidence_histogram(histogram: &ConfidenceHistogram) {
+    println!("{}", "Observation confidence by type:".yellow().bold());
+    if histogram.total == 0 {
+        println!("  No observations.");
+        return;
+    }
+
+    let buckets: Vec<String> = (0..CONFIDENCE_BUCKETS)
+        .map(|b| format!("{:>5.1}", b as f64 / CONFIDENCE_BUCKETS as f64))
+        .collect();
+    println!("  {:<26} {}   mean", "", buckets.join(""));
+
+    let row = |counts: &[usize]| -> String {
+        counts
+            .iter()
+            .map(|&c| {
+                if c == 0 {
+                    format!("{:>5}", "·")
+                } else {
+                    format!("{:>5}", c)
+                }
+            })
+            .collect()
+    };
+
+    for entry in &histogram.by_type {
+        println!(
+            "  {:<26} {}   {:.2}",
+            entry.observation_type.label(),
+            row(&entry.counts),
+            entry.mean_confidence
+        );
+    }
+    println!(
+        "  {:<26} {}",
+        "All observations".bold(),
+        row(&histogram.counts)
+    );
+}
+
+fn print_profiles(columns: &[ColumnSchema]) {
+    println!("{}", "Column profiles:".yellow().bold());
+    for column in columns {
+        let profile = column.profile();
+        println!();
+        println!(
+            "  {} {} ({}, {})",
+            column.name.white().bold(),
+            profile["type"].as_str().unwrap_or_default().cyan(),
+            profile["semantic_type"].as_str().unwrap_or_default(),
+            profile["semantic_role"].as_str().unwrap_or_default()
+        );
+        println!(
+            "    Missing: {:.1}%   Distinct: {}",
+            column.null_percentage(),
+            column.statistics.unique_count
+        );
+
+        if let Some(top) = profile["top_values"].as_array().filter(|t| !t.is_empty()) {
+            let top: Vec<String> = top
+                .iter()
+                .map(|t| format!("{} ({})", t["value"].as_str().unwrap_or_default(), t["count"]))
+                .collect();
+            println!("    Top:     {}", top.join(", "));
+        }
+        if let Some(n) = &column.statistics.numeric {
+            println!(
+                "    Range:   {} | {} | {} | {} | {}  (min | q1 | median | q3 | max)",
+                n.min, n.q1, n.median, n.q3, n.max
+            );
+        }
+        if let Some(s) = &column.statistics.string {
+            println!(
+                "    Length:  {}-{} (mean {:.1})",
+                s.min_length, s.max_length, s.avg_length
+            );
+        }
+    }
 }
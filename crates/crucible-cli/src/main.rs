@@ -2,45 +2,197 @@
 
 mod cli;
 mod commands;
+mod sarif;
 mod server;
+mod triage;
 mod web;
 
 use clap::Parser;
-use cli::{Cli, Commands};
+use cli::{Cli, Commands, FailOn, StatusFormat};
+use crucible::ObservationCounts;
+
+/// The command failed: unreadable input, an analysis error, and so on.
+const EXIT_FAILURE: i32 = 1;
+
+/// Error observations were found, failing with `--fail-on error` or `warning`.
+const EXIT_ERRORS: i32 = 2;
+
+/// Warning observations were found, failing with `--fail-on warning`.
+const EXIT_WARNINGS: i32 = 3;
+
+/// Exit code for a command's observations: 0 unless some reach the
+/// `--fail-on` severity, with errors taking precedence over warnings.
+fn exit_code(fail_on: FailOn, counts: &ObservationCounts) -> i32 {
+    match fail_on {
+        FailOn::None => 0,
+        _ if counts.error > 0 => EXIT_ERRORS,
+        FailOn::Warning if counts.warning > 0 => EXIT_WARNINGS,
+        _ => 0,
+    }
+}
 
 fn main() {
-    let cli = Cli::parse();
+    // Usage errors exit with 1 rather than clap's 2, which means error observations
+    let cli = Cli::try_parse().unwrap_or_else(|e| {
+        let _ = e.print();
+        std::process::exit(if e.use_stderr() { EXIT_FAILURE } else { 0 });
+    });
 
+    // Commands reporting observations return their counts and the severity
+    // that fails them; the others have nothing to fail on
+    let no_findings = |()| (FailOn::None, ObservationCounts::default());
     let result = match cli.command {
         Commands::Analyze {
             file,
             output,
+            stdin_name,
             domain,
             llm,
             model,
             mixs_package,
-        } => commands::analyze::run(file, output, domain, llm, model, mixs_package, cli.verbose),
+            strict,
+            against,
+            sample,
+            no_cache,
+            cache_ttl,
+            glob,
+            parallel,
+            disable,
+            columns,
+            merge_duplicates,
+            semantic_embeddings,
+            profile,
+            locale,
+            interactive,
+            explain_inference,
+            fail_on,
+            all_sheets,
+        } => {
+            // Strict runs count warnings as errors, and fail on them by default
+            let fail_on = fail_on.unwrap_or(if strict || profile.scores_warnings_as_errors() {
+                FailOn::Error
+            } else {
+                FailOn::None
+            });
+            commands::analyze::run(
+                file,
+                output,
+                stdin_name,
+                domain,
+                llm,
+                model,
+                mixs_package,
+                strict,
+                against,
+                sample,
+                no_cache,
+                cache_ttl,
+                glob,
+                parallel,
+                disable,
+                columns,
+                merge_duplicates,
+                semantic_embeddings,
+                profile,
+                locale,
+                interactive,
+                explain_inference,
+                all_sheets,
+                cli.verbose,
+            )
+            .map(|counts| (fail_on, counts))
+        }
 
         Commands::Review {
             file,
             port,
             no_open,
-        } => commands::review::run(file, port, no_open, cli.verbose),
+        } => commands::review::run(file, port, no_open, cli.verbose).map(no_findings),
 
         Commands::Apply {
             file,
             output,
             format,
             with_audit,
-        } => commands::apply::run(file, output, format, with_audit, cli.verbose),
+            restore_headers,
+            dry_run,
+            limit,
+            force,
+            revert,
+            decisions,
+            table,
+        } => match (revert, file) {
+            (Some(audit), _) => {
+                commands::apply::revert(audit, output, format, cli.verbose).map(no_findings)
+            }
+            (None, Some(file)) => {
+                let preview = dry_run.then_some(limit);
+                commands::apply::run(
+                    file,
+                    output,
+                    format,
+                    with_audit,
+                    restore_headers,
+                    preview,
+                    decisions,
+                    table,
+                    force,
+                    cli.verbose,
+                )
+                .map(no_findings)
+            }
+            (None, None) => unreachable!("clap requires a curation file or --revert"),
+        },
+
+        Commands::Status {
+            file,
+            json,
+            format,
+            fingerprint,
+            confidence,
+            profile,
+            fail_on,
+        } => {
+            let format = if json { StatusFormat::Json } else { format };
+            commands::status::run(file, format, fingerprint, confidence, profile, cli.verbose)
+                .map(|counts| (fail_on, counts))
+        }
 
-        Commands::Status { file, json } => commands::status::run(file, json, cli.verbose),
+        Commands::Explain {
+            file,
+            observation_id,
+            json,
+        } => commands::explain::run(file, observation_id, json, cli.verbose).map(no_findings),
 
         Commands::Diff {
             file,
+            layers,
             context,
             changed_only,
-        } => commands::diff::run(file, context, changed_only, cli.verbose),
+        } => match (layers, file) {
+            (Some(layers), _) => {
+                commands::diff::run_layers(&layers[0], &layers[1], cli.verbose).map(no_findings)
+            }
+            (None, Some(file)) => {
+                commands::diff::run(file, context, changed_only, cli.verbose).map(no_findings)
+            }
+            (None, None) => unreachable!("clap requires a curation file or --layers"),
+        },
+
+        Commands::Schema {
+            file,
+            format,
+            output,
+        } => commands::schema::run(file, format, output, cli.verbose).map(no_findings),
+
+        Commands::Biosample {
+            file,
+            package,
+            emit_suggestions,
+            output,
+            json,
+        } => commands::biosample::run(file, package, emit_suggestions, output, json, cli.verbose)
+            .map(no_findings),
 
         Commands::Batch {
             file,
@@ -50,11 +202,59 @@ fn main() {
             column,
             all,
             user,
-        } => commands::batch::run(file, accept, reject, action_type, column, all, user, cli.verbose),
+        } => commands::batch::run(file, accept, reject, action_type, column, all, user, cli.verbose)
+            .map(no_findings),
     };
 
-    if let Err(e) = result {
-        eprintln!("Error: {}", e);
-        std::process::exit(1);
+    match result {
+        Ok((fail_on, counts)) => {
+            let code = exit_code(fail_on, &counts);
+            if code != 0 {
+                eprintln!(
+                    "Failing: {} error and {} warning observation(s) found",
+                    counts.error, counts.warning
+                );
+                std::process::exit(code);
+            }
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(EXIT_FAILURE);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(error: usize, warning: usize) -> ObservationCounts {
+        ObservationCounts {
+            error,
+            warning,
+            info: 5,
+        }
+    }
+
+    #[test]
+    fn test_exit_code() {
+        assert_eq!(exit_code(FailOn::None, &counts(3, 3)), 0);
+
+        assert_eq!(exit_code(FailOn::Error, &counts(0, 0)), 0);
+        assert_eq!(exit_code(FailOn::Error, &counts(0, 4)), 0);
+        assert_eq!(exit_code(FailOn::Error, &counts(1, 0)), EXIT_ERRORS);
+
+        assert_eq!(exit_code(FailOn::Warning, &counts(0, 0)), 0);
+        assert_eq!(exit_code(FailOn::Warning, &counts(0, 2)), EXIT_WARNINGS);
+        // Errors take precedence over warnings
+        assert_eq!(exit_code(FailOn::Warning, &counts(1, 2)), EXIT_ERRORS);
+    }
+
+    #[test]
+    fn test_fail_on_parsing() {
+        assert_eq!("Warnings".parse::<FailOn>(), Ok(FailOn::Warning));
+        assert_eq!("error".parse::<FailOn>(), Ok(FailOn::Error));
+        assert_eq!("none".parse::<FailOn>(), Ok(FailOn::None));
+        assert!("info".parse::<FailOn>().is_err());
     }
 }
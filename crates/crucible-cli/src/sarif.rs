@@ -0,0 +1,267 @@
+//! SARIF 2.1.0 export of curation observations.
+//!
+//! Lets CI systems that already ingest SARIF gate on data quality. Each
+//! observation becomes a result whose rule is the detector that produced it;
+//! the data file and each of its columns are listed as artifacts. The
+//! dataset fingerprint can be attached as a run property.
+
+use std::collections::BTreeMap;
+
+use crucible::{CurationLayer, Observation, Severity};
+use serde_json::{Value, json};
+
+/// JSON schema for SARIF 2.1.0 documents.
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Rule id for observations without a detector.
+const DEFAULT_RULE: &str = "crucible";
+
+/// Build a SARIF log for the observations in a curation layer, with the
+/// dataset fingerprint in the run's properties when `fingerprint` is set.
+pub fn to_sarif(curation: &CurationLayer, fingerprint: bool) -> Value {
+    let file = &curation.source.file;
+
+    // Artifact 0 is the data file; each column is a child artifact
+    let mut columns: Vec<&str> = curation
+        .observations
+        .iter()
+        .map(|o| o.column.as_str())
+        .collect();
+    columns.sort_unstable();
+    columns.dedup();
+
+    let mut artifacts = vec![json!({
+        "location": { "uri": percent_encode(file, true) },
+        "roles": ["analysisTarget"],
+    })];
+    let mut column_index = BTreeMap::new();
+    for column in columns {
+        column_index.insert(column, artifacts.len());
+        artifacts.push(json!({
+            "location": { "uri": column_uri(file, column) },
+            "parentIndex": 0,
+            "description": { "text": format!("Column '{}'", column) },
+        }));
+    }
+
+    let mut rules: BTreeMap<&str, &Observation> = BTreeMap::new();
+    for obs in &curation.observations {
+        rules.entry(rule_id(obs)).or_insert(obs);
+    }
+    let rules: Vec<Value> = rules
+        .iter()
+        .map(|(id, obs)| {
+            json!({
+                "id": id,
+                "shortDescription": { "text": obs.observation_type.label() },
+            })
+        })
+        .collect();
+
+    let results: Vec<Value> = curation
+        .observations
+        .iter()
+        .map(|obs| to_result(obs, file, column_index[obs.column.as_str()]))
+        .collect();
+
+    let mut run = json!({
+        "tool": {
+            "driver": {
+                "name": "crucible",
+                "version": env!("CARGO_PKG_VERSION"),
+                "rules": rules,
+            },
+        },
+        "artifacts": artifacts,
+        "results": results,
+    });
+    if fingerprint {
+        run["properties"] = json!({ "fingerprint": curation.fingerprint() });
+    }
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [run],
+    })
+}
+
+/// Convert one observation to a SARIF result.
+fn to_result(obs: &Observation, file: &str, artifact_index: usize) -> Value {
+    let artifact_location = json!({
+        "uri": column_uri(file, &obs.column),
+        "index": artifact_index,
+    });
+    let logical_locations = json!([{ "name": obs.column, "kind": "member" }]);
+
+    // Sample rows are 0-based data rows; line 1 is the header
+    let locations: Vec<Value> = if obs.evidence.sample_rows.is_empty() {
+        vec![json!({
+            "physicalLocation": { "artifactLocation": artifact_location },
+            "logicalLocations": logical_locations,
+        })]
+    } else {
+        obs.evidence
+            .sample_rows
+            .iter()
+            .map(|row| {
+                json!({
+                    "physicalLocation": {
+                        "artifactLocation": artifact_location,
+                        "region": { "startLine": row + 2 },
+                    },
+                    "logicalLocations": logical_locations,
+                })
+            })
+            .collect()
+    };
+
+    json!({
+        "ruleId": rule_id(obs),
+        "level": level(obs.severity),
+        "message": { "text": obs.description },
+        "locations": locations,
+        "properties": {
+            "observationId": obs.id,
+            "observationType": obs.observation_type,
+            "confidence": obs.confidence,
+        },
+    })
+}
+
+/// The rule an observation is reported under.
+fn rule_id(obs: &Observation) -> &str {
+    if obs.detector.is_empty() {
+        DEFAULT_RULE
+    } else {
+        &obs.detector
+    }
+}
+
+/// Map a severity to a SARIF result level.
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+/// URI of a column within the data file.
+fn column_uri(file: &str, column: &str) -> String {
+    format!("{}#column={}", percent_encode(file, true), percent_encode(column, false))
+}
+
+/// Percent-encode text for a URI, keeping unreserved characters and, for
+/// paths, `/` separators.
+fn percent_encode(text: &str, path: bool) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => encoded.push(byte as char),
+            b'/' if path => encoded.push('/'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible::validation::Evidence;
+    use crucible::{Crucible, CurationContext, ObservationType};
+
+    fn curation(observations: Vec<Observation>) -> CurationLayer {
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("samples.csv");
+        std::fs::write(&data, "sample_id,age\nS1,34\nS2,41\n").unwrap();
+        let result = Crucible::new().analyze(&data).unwrap();
+        let mut curation = CurationLayer::from_analysis(result, CurationContext::new());
+        curation.observations = observations;
+        curation
+    }
+
+    #[test]
+    fn test_sarif_shape() {
+        let outlier = Observation::new(ObservationType::Outlier, Severity::Error, "age", "Age 340 is implausible")
+            .with_evidence(Evidence::new().with_sample_rows(vec![0, 4]))
+            .with_detector("range_validator");
+        let mut undetected = Observation::new(ObservationType::Completeness, Severity::Info, "sample_id", "Sparse");
+        undetected.detector = String::new();
+
+        let sarif = to_sarif(&curation(vec![outlier, undetected]), false);
+        assert_eq!(sarif["version"], "2.1.0");
+        assert_eq!(sarif["$schema"], SARIF_SCHEMA);
+
+        let run = &sarif["runs"][0];
+        let rules: Vec<&str> = run["tool"]["driver"]["rules"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|r| r["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(rules, vec![DEFAULT_RULE, "range_validator"]);
+
+        // The file, then its columns in name order
+        let artifacts = run["artifacts"].as_array().unwrap();
+        assert_eq!(artifacts.len(), 3);
+        assert_eq!(artifacts[0]["location"]["uri"], "samples.csv");
+        assert_eq!(artifacts[1]["location"]["uri"], "samples.csv#column=age");
+        assert_eq!(artifacts[1]["parentIndex"], 0);
+        assert_eq!(artifacts[2]["location"]["uri"], "samples.csv#column=sample_id");
+
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "range_validator");
+        assert_eq!(result["level"], "error");
+        assert_eq!(result["message"]["text"], "Age 340 is implausible");
+        let locations = result["locations"].as_array().unwrap();
+        assert_eq!(locations.len(), 2);
+        assert_eq!(locations[0]["physicalLocation"]["artifactLocation"]["index"], 1);
+        assert_eq!(locations[0]["physicalLocation"]["region"]["startLine"], 2);
+        assert_eq!(locations[1]["physicalLocation"]["region"]["startLine"], 6);
+        assert_eq!(locations[0]["logicalLocations"][0]["name"], "age");
+
+        // Without sample rows there is one location and no region
+        let result = &run["results"][1];
+        assert_eq!(result["ruleId"], DEFAULT_RULE);
+        assert_eq!(result["level"], "note");
+        let locations = result["locations"].as_array().unwrap();
+        assert_eq!(locations.len(), 1);
+        assert!(locations[0]["physicalLocation"].get("region").is_none());
+        assert_eq!(locations[0]["physicalLocation"]["artifactLocation"]["index"], 2);
+        assert!(run.get("properties").is_none());
+    }
+
+    #[test]
+    fn test_sarif_encodes_uris() {
+        let obs = Observation::new(ObservationType::Outlier, Severity::Warning, "Weight (kg)#1", "Heavy");
+        let mut curation = curation(vec![obs]);
+        curation.source.file = "field data/run 1.csv".to_string();
+
+        let sarif = to_sarif(&curation, false);
+        let artifacts = sarif["runs"][0]["artifacts"].as_array().unwrap();
+        assert_eq!(artifacts[0]["location"]["uri"], "field%20data/run%201.csv");
+        assert_eq!(
+            artifacts[1]["location"]["uri"],
+            "field%20data/run%201.csv#column=Weight%20%28kg%29%231"
+        );
+        assert_eq!(percent_encode("día", false), "d%C3%ADa");
+    }
+
+    #[test]
+    fn test_sarif_fingerprint() {
+        let curation = curation(Vec::new());
+        let sarif = to_sarif(&curation, true);
+        assert_eq!(sarif["runs"][0]["properties"]["fingerprint"], curation.fingerprint());
+    }
+
+    #[test]
+    fn test_sarif_without_observations() {
+        let sarif = to_sarif(&curation(Vec::new()), false);
+        let run = &sarif["runs"][0];
+        assert_eq!(run["artifacts"].as_array().unwrap().len(), 1);
+        assert!(run["results"].as_array().unwrap().is_empty());
+        assert!(run["tool"]["driver"]["rules"].as_array().unwrap().is_empty());
+    }
+}
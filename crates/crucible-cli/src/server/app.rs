@@ -25,6 +25,8 @@ pub fn create_router(state: AppState) -> Router {
         .route("/save", post(handlers::save_curation))
         // Data preview
         .route("/data", get(handlers::get_data_preview))
+        // Inferred schema
+        .route("/schema", get(handlers::get_schema))
         // Decisions
         .route("/decisions/:id/accept", post(handlers::accept_decision))
         .route("/decisions/:id/reject", post(handlers::reject_decision))
@@ -33,6 +35,11 @@ pub fn create_router(state: AppState) -> Router {
         // Batch operations
         .route("/batch/accept", post(handlers::batch_accept))
         .route("/batch/reject", post(handlers::batch_reject))
+        // Apply accepted decisions and download the result
+        .route("/apply", post(handlers::apply_curation))
+        .route("/download", get(handlers::download_curated))
+        .route("/download/audit", get(handlers::download_audit))
+        .route("/download/schema", get(handlers::download_schema))
         // Interactive explanations (LLM-powered)
         .route("/explain/ask", post(handlers::ask_question))
         .route("/explain/calibrate", post(handlers::calibrate_confidence))
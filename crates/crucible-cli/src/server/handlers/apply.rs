@@ -0,0 +1,292 @@
+//! Apply and download handlers.
+//!
+//! Applying runs the transforms for accepted decisions over a fresh parse of
+//! the source file and keeps the result in memory; the source file is never
+//! modified. The result can then be downloaded in a chosen format, with the
+//! audit trail and the output schema available as companion JSON.
+
+use axum::{
+    extract::{Query, State},
+    http::header,
+    response::IntoResponse,
+    Json,
+};
+use crucible::curation::data_stem;
+use crucible::input::ParserConfig;
+use crucible::{Parser, TableSchema, TransformAudit, TransformEngine};
+use serde::{Deserialize, Serialize};
+
+use crate::server::error::ApiError;
+use crate::server::state::{AppState, AppliedCuration};
+
+/// Request body for applying decisions.
+#[derive(Deserialize, Default)]
+pub struct ApplyRequest {
+    /// Let a column rename replace an existing column of the same name.
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// Summary of one applied transform.
+#[derive(Serialize)]
+pub struct AppliedChange {
+    pub description: String,
+    pub column: String,
+    pub values_changed: usize,
+}
+
+/// Response after applying decisions.
+#[derive(Serialize)]
+pub struct ApplyResponse {
+    pub operations_applied: usize,
+    pub rows_modified: usize,
+    pub row_count: usize,
+    pub changes: Vec<AppliedChange>,
+    pub audit: TransformAudit,
+}
+
+/// POST /api/apply
+///
+/// Runs the transforms for accepted and modified decisions and keeps the
+/// result for `GET /api/download`.
+pub async fn apply_curation(
+    State(state): State<AppState>,
+    request: Option<Json<ApplyRequest>>,
+) -> Result<Json<ApplyResponse>, ApiError> {
+    let Json(request) = request.unwrap_or_default();
+    let curation = state.curation.read().await;
+
+    if curation.accepted_decisions().is_empty() {
+        return Err(ApiError::BadRequest(
+            "No accepted decisions to apply".to_string(),
+        ));
+    }
+
+    // Parse the source data the way it was analyzed
+    let parser = Parser::with_config(ParserConfig::for_source(&curation.source));
+    let (mut data, _metadata) = parser
+        .parse_file(&state.data_path)
+        .map_err(|e| ApiError::Internal(format!("Failed to load data: {}", e)))?;
+
+    let result = TransformEngine::new()
+        .with_force_renames(request.force)
+        .apply(&curation, &mut data)?;
+    let audit = TransformAudit::new(&curation.schema, &result);
+    let schema = result.output_schema(&curation.schema);
+    drop(curation);
+
+    let response = ApplyResponse {
+        operations_applied: result.operations_applied,
+        rows_modified: result.rows_modified,
+        row_count: data.row_count(),
+        changes: result
+            .changes
+            .iter()
+            .map(|change| AppliedChange {
+                description: change.description.clone(),
+                column: change.column.clone(),
+                values_changed: change.values_changed,
+            })
+            .collect(),
+        audit: audit.clone(),
+    };
+
+    *state.applied.write().await = Some(AppliedCuration { data, audit, schema });
+
+    Ok(Json(response))
+}
+
+/// Query parameters for the download endpoint.
+#[derive(Deserialize)]
+pub struct DownloadQuery {
+    /// Output format: tsv (default), csv, or json.
+    #[serde(default = "default_format")]
+    pub format: String,
+}
+
+fn default_format() -> String {
+    "tsv".to_string()
+}
+
+/// GET /api/download
+///
+/// Returns the data from the last apply as an attachment.
+pub async fn download_curated(
+    State(state): State<AppState>,
+    Query(params): Query<DownloadQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (content_type, delimiter) = match params.format.to_lowercase().as_str() {
+        "tsv" => ("text/tab-separated-values", Some(b'\t')),
+        "csv" => ("text/csv", Some(b',')),
+        "json" => ("application/json", None),
+        other => {
+            return Err(ApiError::BadRequest(format!(
+                "Unsupported download format '{}' (use tsv, csv, or json)",
+                other
+            )))
+        }
+    };
+
+    let applied = state.applied.read().await;
+    let applied = applied.as_ref().ok_or_else(not_applied)?;
+
+    let body = match delimiter {
+        Some(delimiter) => {
+            let mut body = Vec::new();
+            applied
+                .data
+                .write_delimited(&mut body, delimiter)
+                .map_err(|e| ApiError::Internal(format!("Failed to write data: {}", e)))?;
+            body
+        }
+        None => serde_json::to_vec_pretty(&applied.data.to_json_value())
+            .map_err(|e| ApiError::Internal(format!("Failed to write data: {}", e)))?,
+    };
+
+    let source_file = std::path::PathBuf::from(&state.curation.read().await.source.file);
+    let filename = format!(
+        "{}_curated.{}",
+        data_stem(&source_file),
+        params.format.to_lowercase()
+    );
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", filename),
+            ),
+        ],
+        body,
+    ))
+}
+
+/// GET /api/download/audit
+///
+/// Returns the audit trail of the last apply.
+pub async fn download_audit(
+    State(state): State<AppState>,
+) -> Result<Json<TransformAudit>, ApiError> {
+    let applied = state.applied.read().await;
+    let applied = applied.as_ref().ok_or_else(not_applied)?;
+    Ok(Json(applied.audit.clone()))
+}
+
+/// GET /api/download/schema
+///
+/// Returns the schema of the data from the last apply, with roles and
+/// constraints following renamed columns.
+pub async fn download_schema(
+    State(state): State<AppState>,
+) -> Result<Json<TableSchema>, ApiError> {
+    let applied = state.applied.read().await;
+    let applied = applied.as_ref().ok_or_else(not_applied)?;
+    Ok(Json(applied.schema.clone()))
+}
+
+fn not_applied() -> ApiError {
+    ApiError::NotFound("Nothing applied yet; POST /api/apply first".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::extract::Path;
+    use crucible::{Crucible, CurationContext, CurationLayer, Suggestion, SuggestionAction};
+
+    /// Server state for an analyzed file, with its first suggestion accepted.
+    fn accepted_state() -> (AppState, tempfile::TempDir) {
+        let mut contents = String::from("sample_id,site,mass\n");
+        for i in 1..=20 {
+            let mass = if i % 5 == 0 { "NA".to_string() } else { format!("{}.5", i) };
+            contents.push_str(&format!("S{},\"Smith, J\",{}\n", i, mass));
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let data_path = dir.path().join("samples.csv");
+        std::fs::write(&data_path, contents).unwrap();
+
+        let result = Crucible::new().analyze(&data_path).unwrap();
+        let mut curation = CurationLayer::from_analysis(result, CurationContext::new());
+        let id = curation.suggestions[0].id.clone();
+        curation.accept(&id).unwrap();
+
+        let mut state = AppState::new(curation, dir.path().join("samples.curation.json"), data_path);
+        state.auto_save = false;
+        (state, dir)
+    }
+
+    async fn download(state: &AppState, format: &str) -> Result<String, ApiError> {
+        let query = DownloadQuery {
+            format: format.to_string(),
+        };
+        let response = download_curated(State(state.clone()), Query(query))
+            .await?
+            .into_response();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        Ok(String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_download_csv_quotes_values() {
+        let (state, _dir) = accepted_state();
+        let Json(applied) = apply_curation(State(state.clone()), None).await.unwrap();
+        assert_eq!(applied.operations_applied, 1);
+
+        let csv = download(&state, "csv").await.unwrap();
+        assert!(csv.starts_with("sample_id,site,mass"));
+        assert!(csv.contains("S1,\"Smith, J\","));
+
+        let tsv = download(&state, "tsv").await.unwrap();
+        assert!(tsv.contains("S1\tSmith, J\t"));
+    }
+
+    #[tokio::test]
+    async fn test_download_schema_follows_renames() {
+        let (state, _dir) = accepted_state();
+        assert!(download_schema(State(state.clone())).await.is_err());
+
+        {
+            let mut curation = state.curation.write().await;
+            let obs_id = curation.observations[0].id.clone();
+            let rename = Suggestion::new(obs_id, SuggestionAction::Rename, "Rename")
+                .with_parameters(serde_json::json!({"column": "site", "to": "collection_site"}));
+            let id = rename.id.clone();
+            curation.suggestions.push(rename);
+            curation.accept(&id).unwrap();
+        }
+        let _ = apply_curation(State(state.clone()), None).await.unwrap();
+
+        let Json(schema) = download_schema(State(state)).await.unwrap();
+        assert!(schema.get_column("collection_site").is_some());
+        assert!(schema.get_column("site").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_apply_reports_unreadable_data_as_internal() {
+        let (state, _dir) = accepted_state();
+        std::fs::remove_file(&state.data_path).unwrap();
+
+        let result = apply_curation(State(state), None).await;
+        assert!(matches!(result, Err(ApiError::Internal(_))));
+    }
+
+    #[tokio::test]
+    async fn test_decision_change_clears_applied() {
+        let (state, _dir) = accepted_state();
+        let _ = apply_curation(State(state.clone()), None).await.unwrap();
+        assert!(download(&state, "json").await.is_ok());
+
+        let id = state.curation.read().await.suggestions[0].id.clone();
+        let Json(reset) = crate::server::handlers::reset_decision(State(state.clone()), Path(id))
+            .await
+            .unwrap();
+        assert!(reset.was_reset);
+
+        assert!(state.applied.read().await.is_none());
+        assert!(matches!(download(&state, "csv").await, Err(ApiError::NotFound(_))));
+    }
+}
@@ -75,6 +75,8 @@ pub struct DecisionInfo {
     pub decided_by: Option<String>,
     pub decided_at: Option<String>,
     pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deferred_until: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -155,10 +157,14 @@ pub async fn get_curation(State(state): State<AppState>) -> Result<Json<Curation
             .map(|d| DecisionInfo {
                 id: d.id.clone(),
                 suggestion_id: d.suggestion_id.clone(),
-                status: format!("{:?}", d.status).to_lowercase(),
+                status: d.status.label().to_lowercase(),
                 decided_by: d.decided_by.clone(),
                 decided_at: d.decided_at.map(|dt| dt.to_rfc3339()),
-                notes: d.notes.clone(),
+                notes: d.reason().map(str::to_string),
+                deferred_until: match &d.status {
+                    DecisionStatus::Deferred { until, .. } => until.clone(),
+                    _ => None,
+                },
             })
             .collect(),
         summary: SummaryInfo {
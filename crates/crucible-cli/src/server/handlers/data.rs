@@ -4,6 +4,7 @@ use axum::{
     extract::{Query, State},
     Json,
 };
+use crucible::input::ParserConfig;
 use crucible::Parser;
 use serde::{Deserialize, Serialize};
 
@@ -60,8 +61,9 @@ pub async fn get_data_preview(
     let limit = params.limit.min(MAX_LIMIT);
     let offset = params.offset;
 
-    // Load and parse the source data
-    let parser = Parser::new();
+    // Load and parse the source data the way it was analyzed
+    let config = ParserConfig::for_source(&state.curation.read().await.source);
+    let parser = Parser::with_config(config);
     let (data, _metadata) = parser
         .parse_file(&state.data_path)
         .map_err(|e| ApiError::NotFound(format!("Failed to load data: {}", e)))?;
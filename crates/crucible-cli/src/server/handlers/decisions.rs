@@ -100,15 +100,18 @@ pub async fn accept_decision(
     let response = DecisionResponse {
         id: decision.id.clone(),
         suggestion_id: decision.suggestion_id.clone(),
-        status: format!("{:?}", decision.status).to_lowercase(),
+        status: decision.status.label().to_lowercase(),
         decided_by: decision.decided_by.clone(),
         decided_at: decision.decided_at.map(|dt| dt.to_rfc3339()),
         notes: decision.notes.clone(),
     };
 
+    // A previous apply no longer reflects the decisions
+    drop(curation);
+    state.clear_applied().await;
+
     // Auto-save if enabled
     if state.auto_save {
-        drop(curation); // Release the lock before saving
         state.save().await?;
     }
 
@@ -149,15 +152,18 @@ pub async fn reject_decision(
     let response = DecisionResponse {
         id: decision.id.clone(),
         suggestion_id: decision.suggestion_id.clone(),
-        status: format!("{:?}", decision.status).to_lowercase(),
+        status: decision.status.label().to_lowercase(),
         decided_by: decision.decided_by.clone(),
         decided_at: decision.decided_at.map(|dt| dt.to_rfc3339()),
         notes: decision.notes.clone(),
     };
 
+    // A previous apply no longer reflects the decisions
+    drop(curation);
+    state.clear_applied().await;
+
     // Auto-save if enabled
     if state.auto_save {
-        drop(curation);
         state.save().await?;
     }
 
@@ -198,15 +204,18 @@ pub async fn modify_decision(
     let response = DecisionResponse {
         id: decision.id.clone(),
         suggestion_id: decision.suggestion_id.clone(),
-        status: format!("{:?}", decision.status).to_lowercase(),
+        status: decision.status.label().to_lowercase(),
         decided_by: decision.decided_by.clone(),
         decided_at: decision.decided_at.map(|dt| dt.to_rfc3339()),
         notes: decision.notes.clone(),
     };
 
+    // A previous apply no longer reflects the decisions
+    drop(curation);
+    state.clear_applied().await;
+
     // Auto-save if enabled
     if state.auto_save {
-        drop(curation);
         state.save().await?;
     }
 
@@ -242,13 +251,18 @@ pub async fn reset_decision(
     let response = ResetResponse {
         suggestion_id: suggestion_id.clone(),
         was_reset: removed.is_some(),
-        previous_status: removed.map(|d| format!("{:?}", d.status).to_lowercase()),
+        previous_status: removed.map(|d| d.status.label().to_lowercase()),
     };
 
-    // Auto-save if enabled
-    if state.auto_save && response.was_reset {
+    if response.was_reset {
+        // A previous apply no longer reflects the decisions
         drop(curation);
-        state.save().await?;
+        state.clear_applied().await;
+
+        // Auto-save if enabled
+        if state.auto_save {
+            state.save().await?;
+        }
     }
 
     Ok(Json(response))
@@ -308,11 +322,14 @@ async fn batch_operation(
                     crucible::SuggestionAction::Coerce => "coerce",
                     crucible::SuggestionAction::ConvertDate => "convert_date",
                     crucible::SuggestionAction::Flag => "flag",
+                    crucible::SuggestionAction::Clamp => "clamp",
+                    crucible::SuggestionAction::ConvertUnit => "convert_unit",
                     crucible::SuggestionAction::Remove => "remove",
                     crucible::SuggestionAction::Merge => "merge",
                     crucible::SuggestionAction::Rename => "rename",
                     crucible::SuggestionAction::Split => "split",
                     crucible::SuggestionAction::Derive => "derive",
+                    crucible::SuggestionAction::Redact => "redact",
                 };
                 if !action_str.contains(&filter_lower)
                     && !filter_lower.contains(&action_str)
@@ -367,7 +384,7 @@ async fn batch_operation(
         decisions.push(DecisionResponse {
             id: decision.id.clone(),
             suggestion_id: decision.suggestion_id.clone(),
-            status: format!("{:?}", decision.status).to_lowercase(),
+            status: decision.status.label().to_lowercase(),
             decided_by: decision.decided_by.clone(),
             decided_at: decision.decided_at.map(|dt| dt.to_rfc3339()),
             notes: decision.notes.clone(),
@@ -380,9 +397,12 @@ async fn batch_operation(
         .filter(|s| !curation.decisions.iter().any(|d| d.suggestion_id == s.id))
         .count();
 
+    // A previous apply no longer reflects the decisions
+    drop(curation);
+    state.clear_applied().await;
+
     // Auto-save if enabled
     if state.auto_save {
-        drop(curation);
         state.save().await?;
     }
 
@@ -1,11 +1,15 @@
 //! API request handlers.
 
+mod apply;
 mod curation;
 mod data;
 mod decisions;
 mod explain;
+mod schema;
 
+pub use apply::*;
 pub use curation::*;
 pub use data::*;
 pub use decisions::*;
 pub use explain::*;
+pub use schema::*;
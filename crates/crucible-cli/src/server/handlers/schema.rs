@@ -0,0 +1,122 @@
+//! Schema handler.
+
+use axum::{
+    extract::{Query, State},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Deserialize;
+
+use crate::server::error::ApiError;
+use crate::server::state::AppState;
+
+/// Query parameters for the schema endpoint.
+#[derive(Deserialize, Default)]
+pub struct SchemaQuery {
+    /// Return only this column.
+    pub column: Option<String>,
+    /// Table to read the schema of, for a multi-table curation layer.
+    pub table: Option<String>,
+}
+
+/// GET /api/schema
+///
+/// Returns the inferred schema with per-column statistics, semantic roles,
+/// constraints, inference explanations, and LLM insights. With `?column=name`, returns just that
+/// column.
+pub async fn get_schema(
+    State(state): State<AppState>,
+    Query(params): Query<SchemaQuery>,
+) -> Result<Response, ApiError> {
+    let curation = state.curation.read().await;
+
+    let schema = match params.table.as_deref() {
+        Some(name) => {
+            &curation
+                .tables
+                .get(name)
+                .ok_or_else(|| ApiError::NotFound(format!("Table not found: {}", name)))?
+                .schema
+        }
+        None => &curation.schema,
+    };
+
+    match params.column.as_deref() {
+        Some(name) => {
+            let column = schema
+                .get_column(name)
+                .ok_or_else(|| ApiError::NotFound(format!("Column not found: {}", name)))?;
+            Ok(Json(column).into_response())
+        }
+        None => Ok(Json(schema).into_response()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible::{Crucible, CurationContext, CurationLayer};
+
+    /// Server state for a layer covering two sheets, `samples` and `assays`.
+    fn state() -> (AppState, tempfile::TempDir) {
+        let dir = tempfile::tempdir().unwrap();
+        let analyze = |name: &str, contents: &str| {
+            let path = dir.path().join(format!("{}.csv", name));
+            std::fs::write(&path, contents).unwrap();
+            (name.to_string(), Crucible::new().analyze(&path).unwrap())
+        };
+        let results = [
+            analyze("samples", "sample_id,age\nS1,34\nS2,41\n"),
+            analyze("assays", "assay_id,reading\nA1,0.5\nA2,0.7\n"),
+        ];
+        let curation =
+            CurationLayer::from_analyses(results.into_iter().collect(), CurationContext::new()).unwrap();
+        let state = AppState::new(
+            curation,
+            dir.path().join("study.curation.json"),
+            dir.path().join("samples.csv"),
+        );
+        (state, dir)
+    }
+
+    async fn schema(state: &AppState, column: Option<&str>, table: Option<&str>) -> Result<serde_json::Value, ApiError> {
+        let query = SchemaQuery {
+            column: column.map(str::to_string),
+            table: table.map(str::to_string),
+        };
+        let response = get_schema(State(state.clone()), Query(query)).await?;
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        Ok(serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_get_schema() {
+        let (state, _dir) = state();
+
+        let column = schema(&state, Some("age"), None).await.unwrap();
+        assert_eq!(column["name"], "age");
+        assert!(column["explanation"]["rationale"].is_string());
+
+        let table = schema(&state, None, Some("assays")).await.unwrap();
+        assert_eq!(table["columns"][0]["name"], "assay_id");
+        let column = schema(&state, Some("reading"), Some("assays")).await.unwrap();
+        assert_eq!(column["name"], "reading");
+    }
+
+    #[tokio::test]
+    async fn test_get_schema_not_found() {
+        let (state, _dir) = state();
+
+        let missing_column = schema(&state, Some("weight"), None).await;
+        assert!(matches!(missing_column, Err(ApiError::NotFound(m)) if m.contains("weight")));
+
+        let missing_table = schema(&state, None, Some("plates")).await;
+        assert!(matches!(missing_table, Err(ApiError::NotFound(m)) if m.contains("plates")));
+
+        // Columns are looked up in the requested table only
+        let other_table = schema(&state, Some("age"), Some("assays")).await;
+        assert!(matches!(other_table, Err(ApiError::NotFound(_))));
+    }
+}
@@ -4,7 +4,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-use crucible::{CurationLayer, LlmProvider};
+use crucible::{CurationLayer, DataTable, LlmProvider, TableSchema, TransformAudit};
 
 /// Shared application state.
 #[derive(Clone)]
@@ -22,6 +22,20 @@ pub struct AppState {
     pub llm_provider: Option<Arc<dyn LlmProvider>>,
     /// Name of the configured LLM provider (for display).
     pub llm_provider_name: Option<String>,
+    /// Result of the last `POST /api/apply`, kept in memory for download.
+    pub applied: Arc<RwLock<Option<AppliedCuration>>>,
+}
+
+/// Transformed data from applying the accepted decisions.
+///
+/// Held in memory only; the source file on disk is never modified.
+pub struct AppliedCuration {
+    /// The transformed table.
+    pub data: DataTable,
+    /// Audit trail of the transforms that produced it.
+    pub audit: TransformAudit,
+    /// Schema of the transformed table, following any column renames.
+    pub schema: TableSchema,
 }
 
 impl AppState {
@@ -34,6 +48,7 @@ impl AppState {
             auto_save: true,
             llm_provider: None,
             llm_provider_name: None,
+            applied: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -52,9 +67,15 @@ impl AppState {
             auto_save: true,
             llm_provider: Some(provider),
             llm_provider_name: Some(name),
+            applied: Arc::new(RwLock::new(None)),
         }
     }
 
+    /// Drop the result of the last apply, once decisions have changed.
+    pub async fn clear_applied(&self) {
+        *self.applied.write().await = None;
+    }
+
     /// Save the curation layer to disk.
     pub async fn save(&self) -> Result<(), crucible::CrucibleError> {
         let curation = self.curation.read().await;
@@ -0,0 +1,198 @@
+//! CSV export of observations for triage in a spreadsheet.
+//!
+//! Each observation becomes one row, errors first. The `decision` and
+//! `reason` columns are left for the reviewer; filled in with accept,
+//! reject, or defer, the sheet can be read back with
+//! [`CurationLayer::ingest_decisions_csv`] to decide every suggestion made
+//! for that observation.
+
+use std::io::Write;
+
+use crucible::{CurationLayer, DecisionStatus, Observation};
+
+/// Columns of the triage sheet.
+const HEADERS: [&str; 12] = [
+    "id",
+    "column",
+    "type",
+    "severity",
+    "confidence",
+    "detector",
+    "description",
+    "occurrences",
+    "percentage",
+    "first_sample_row",
+    "decision",
+    "reason",
+];
+
+/// Write one CSV row per observation, ordered by severity (errors first),
+/// then column and ID.
+///
+/// The decision column is pre-filled when every suggestion for an
+/// observation has the same decision.
+pub fn write_csv(
+    curation: &CurationLayer,
+    writer: impl Write,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut observations: Vec<&Observation> = curation.observations.iter().collect();
+    observations.sort_by(|a, b| {
+        b.severity
+            .cmp(&a.severity)
+            .then_with(|| a.column.cmp(&b.column))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    let mut csv = csv::Writer::from_writer(writer);
+    csv.write_record(HEADERS)?;
+    for obs in observations {
+        let (decision, reason) = current_decision(curation, &obs.id);
+        csv.write_record([
+            obs.id.clone(),
+            obs.column.clone(),
+            obs.observation_type.label().to_string(),
+            obs.severity.label().to_string(),
+            format!("{:.2}", obs.confidence),
+            obs.detector.clone(),
+            obs.description.clone(),
+            obs.evidence
+                .occurrences
+                .map(|n| n.to_string())
+                .unwrap_or_default(),
+            obs.evidence
+                .percentage
+                .map(|p| format!("{:.1}", p))
+                .unwrap_or_default(),
+            // 1-based data row, as in observation descriptions
+            obs.evidence
+                .sample_rows
+                .first()
+                .map(|row| (row + 1).to_string())
+                .unwrap_or_default(),
+            decision,
+            reason,
+        ])?;
+    }
+    csv.flush()?;
+    Ok(())
+}
+
+/// The shared decision and its reason on an observation's suggestions, if any.
+fn current_decision(curation: &CurationLayer, observation_id: &str) -> (String, String) {
+    let mut decisions = curation
+        .suggestions
+        .iter()
+        .filter(|s| s.observation_id == observation_id)
+        .map(|s| curation.decision_for(&s.id));
+
+    let Some(Some(first)) = decisions.next() else {
+        return Default::default();
+    };
+    if !decisions.all(|d| d.is_some_and(|d| d.status.label() == first.status.label())) {
+        return Default::default();
+    }
+    let decision = match &first.status {
+        status if status.is_approved() => "accept",
+        DecisionStatus::Rejected => "reject",
+        DecisionStatus::Deferred { .. } => "defer",
+        _ => return Default::default(),
+    };
+    (decision.to_string(), first.reason().unwrap_or_default().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible::{
+        Crucible, CurationContext, ObservationType, Severity, Suggestion, SuggestionAction,
+    };
+
+    /// A layer holding only the given observations, each with one suggestion.
+    fn layer(observations: Vec<Observation>) -> CurationLayer {
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("samples.csv");
+        std::fs::write(&data, "sample_id,mass\nS1,1.5\nS2,2.5\n").unwrap();
+        let result = Crucible::new().analyze(&data).unwrap();
+        let mut curation = CurationLayer::from_analysis(result, CurationContext::new());
+        curation.decisions.clear();
+        curation.suggestions = observations
+            .iter()
+            .map(|obs| Suggestion::new(&obs.id, SuggestionAction::Flag, "Review"))
+            .collect();
+        curation.observations = observations;
+        curation
+    }
+
+    fn rows(curation: &CurationLayer) -> Vec<csv::StringRecord> {
+        let mut out = Vec::new();
+        write_csv(curation, &mut out).unwrap();
+        let mut reader = csv::Reader::from_reader(out.as_slice());
+        assert_eq!(reader.headers().unwrap(), &csv::StringRecord::from(HEADERS.to_vec()));
+        reader.records().map(|r| r.unwrap()).collect()
+    }
+
+    fn observation(severity: Severity, column: &str, description: &str) -> Observation {
+        Observation::new(ObservationType::Inconsistency, severity, column, description)
+    }
+
+    #[test]
+    fn test_rows_ordered_errors_first_then_column() {
+        let curation = layer(vec![
+            observation(Severity::Info, "a", "info"),
+            observation(Severity::Warning, "b", "warning on b"),
+            observation(Severity::Error, "z", "error"),
+            observation(Severity::Warning, "a", "warning on a"),
+        ]);
+
+        let descriptions: Vec<String> = rows(&curation).iter().map(|r| r[6].to_string()).collect();
+        assert_eq!(descriptions, vec!["error", "warning on a", "warning on b", "info"]);
+    }
+
+    #[test]
+    fn test_fields_are_quoted() {
+        let description = "Values \"N/A\", \"-\"\nand blanks";
+        let curation = layer(vec![
+            observation(Severity::Warning, "site, region", description)
+                .with_evidence(crucible::validation::Evidence::new().with_sample_rows(vec![4])),
+        ]);
+
+        let rows = rows(&curation);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(&rows[0][1], "site, region");
+        assert_eq!(&rows[0][6], description);
+        // Sample rows are reported 1-based
+        assert_eq!(&rows[0][9], "5");
+    }
+
+    #[test]
+    fn test_decision_prefilled() {
+        let mut curation = layer(vec![
+            observation(Severity::Error, "a", "accepted"),
+            observation(Severity::Error, "b", "rejected"),
+            observation(Severity::Error, "c", "undecided"),
+        ]);
+        let ids: Vec<String> = curation.suggestions.iter().map(|s| s.id.clone()).collect();
+        curation.accept(&ids[0]).unwrap();
+        curation.reject(&ids[1], "Expected, values, \"as is\"").unwrap();
+
+        let rows = rows(&curation);
+        assert_eq!((&rows[0][10], &rows[0][11]), ("accept", ""));
+        assert_eq!((&rows[1][10], &rows[1][11]), ("reject", "Expected, values, \"as is\""));
+        assert_eq!((&rows[2][10], &rows[2][11]), ("", ""));
+    }
+
+    #[test]
+    fn test_mixed_decisions_left_blank() {
+        let mut curation = layer(vec![observation(Severity::Error, "a", "two fixes")]);
+        let obs_id = curation.observations[0].id.clone();
+        curation
+            .suggestions
+            .push(Suggestion::new(&obs_id, SuggestionAction::Flag, "Also review"));
+        let ids: Vec<String> = curation.suggestions.iter().map(|s| s.id.clone()).collect();
+        curation.accept(&ids[0]).unwrap();
+        curation.reject(&ids[1], "No").unwrap();
+
+        let rows = rows(&curation);
+        assert_eq!(&rows[0][10], "");
+    }
+}
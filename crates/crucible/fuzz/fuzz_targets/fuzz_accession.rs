@@ -24,6 +24,7 @@ fuzz_target!(|data: &[u8]| {
         let _ = result.normalized;
         let _ = result.error;
         let _ = result.archive;
+        let _ = result.malformed_type();
 
         // Test URL generation
         let _ = validator.get_url(input);
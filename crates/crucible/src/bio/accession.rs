@@ -124,6 +124,25 @@ pub struct AccessionValidationResult {
     pub error: Option<String>,
     /// Archive prefix (NCBI, EBI, DDBJ).
     pub archive: Option<String>,
+    /// Whether the input was already in canonical form (uppercase, no
+    /// surrounding whitespace).
+    pub is_canonical: bool,
+    /// The type an invalid accession appears to be, judged by its prefix.
+    pub expected_type: Option<AccessionType>,
+}
+
+impl AccessionValidationResult {
+    /// The type this accession was meant to be, if it is plausible but malformed.
+    ///
+    /// Covers invalid values with a recognizable prefix (`SAMN123`) and valid
+    /// accessions written in non-canonical form (`srr1234567`).
+    pub fn malformed_type(&self) -> Option<AccessionType> {
+        if self.is_valid {
+            if self.is_canonical { None } else { self.accession_type }
+        } else {
+            self.expected_type
+        }
+    }
 }
 
 /// Statistics about accession validation.
@@ -135,6 +154,53 @@ pub struct AccessionStats {
     pub valid_count: usize,
     /// Total invalid accessions.
     pub invalid_count: usize,
+    /// Count of plausible but malformed accessions by the type they resemble.
+    #[serde(default)]
+    pub malformed_by_type: HashMap<AccessionType, usize>,
+}
+
+impl AccessionStats {
+    /// Add a validation result to the counts.
+    ///
+    /// Valid accessions in non-canonical form count as malformed, not valid.
+    pub fn record(&mut self, result: &AccessionValidationResult) {
+        if result.input.trim().is_empty() {
+            return;
+        }
+        if let Some(acc_type) = result.malformed_type() {
+            self.invalid_count += 1;
+            *self.malformed_by_type.entry(acc_type).or_insert(0) += 1;
+        } else if result.is_valid {
+            self.valid_count += 1;
+            if let Some(acc_type) = result.accession_type {
+                *self.by_type.entry(acc_type).or_insert(0) += 1;
+            }
+        } else {
+            self.invalid_count += 1;
+        }
+    }
+
+    /// Human-readable breakdown, e.g. "12 valid SRA Run, 3 malformed NCBI BioSample".
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        let mut push_counts = |counts: &HashMap<AccessionType, usize>, label: &str| {
+            let mut entries: Vec<_> = counts.iter().collect();
+            entries.sort_by(|a, b| b.1.cmp(a.1).then(a.0.database().cmp(b.0.database())));
+            for (acc_type, count) in entries {
+                parts.push(format!("{} {} {}", count, label, acc_type.database()));
+            }
+        };
+        push_counts(&self.by_type, "valid");
+        push_counts(&self.malformed_by_type, "malformed");
+
+        let unrecognized = self
+            .invalid_count
+            .saturating_sub(self.malformed_by_type.values().sum::<usize>());
+        if unrecognized > 0 {
+            parts.push(format!("{} unrecognized", unrecognized));
+        }
+        parts.join(", ")
+    }
 }
 
 /// Validates biological database accession numbers.
@@ -244,6 +310,8 @@ impl AccessionValidator {
                 normalized: None,
                 error: Some("Empty accession".to_string()),
                 archive: None,
+                is_canonical: false,
+                expected_type: None,
             };
         }
 
@@ -271,43 +339,66 @@ impl AccessionValidator {
                         input,
                         is_valid: true,
                         accession_type: Some(acc_type),
+                        is_canonical: accession == normalized,
                         normalized: Some(normalized),
                         error: None,
                         archive,
+                        expected_type: None,
                     };
                 }
             }
         }
 
         // Check if it looks like an accession but doesn't match patterns
-        let error = if normalized.starts_with("SAM") {
-            Some("Invalid BioSample format. Expected: SAMN/SAME/SAMD followed by digits".to_string())
-        } else if normalized.starts_with("SRR")
-            || normalized.starts_with("ERR")
-            || normalized.starts_with("DRR")
-        {
-            Some("Invalid SRA Run format. Expected: SRR/ERR/DRR followed by 6-9 digits".to_string())
-        } else if normalized.starts_with("PRJ") {
-            Some(
-                "Invalid BioProject format. Expected: PRJNA/PRJEB/PRJDB followed by digits"
-                    .to_string(),
-            )
-        } else if normalized.contains('_') && normalized.len() > 3 {
-            Some("Invalid RefSeq format. Expected: NM_/XM_/etc. followed by digits".to_string())
-        } else {
-            Some("Unrecognized accession format".to_string())
+        let expected_type = Self::plausible_type(&normalized);
+        let error = match expected_type {
+            Some(acc_type) => format!(
+                "Invalid {} format. Expected: {}",
+                acc_type.database(),
+                acc_type.format_description()
+            ),
+            None => "Unrecognized accession format".to_string(),
         };
 
         AccessionValidationResult {
             input,
             is_valid: false,
             accession_type: None,
+            is_canonical: accession == normalized,
             normalized: Some(normalized),
-            error,
+            error: Some(error),
             archive: None,
+            expected_type,
         }
     }
 
+    /// Guess the intended type of an accession that failed validation from
+    /// its prefix.
+    fn plausible_type(normalized: &str) -> Option<AccessionType> {
+        if normalized.starts_with("SAM") {
+            return Some(AccessionType::BioSample);
+        }
+        if normalized.starts_with("PRJ") {
+            return Some(AccessionType::BioProject);
+        }
+
+        let prefix = normalized.as_bytes();
+        if prefix.len() >= 3 && matches!(prefix[0], b'S' | b'E' | b'D') && prefix[1] == b'R' {
+            match prefix[2] {
+                b'R' => return Some(AccessionType::SraRun),
+                b'X' => return Some(AccessionType::SraExperiment),
+                b'S' => return Some(AccessionType::SraSample),
+                b'P' => return Some(AccessionType::SraStudy),
+                _ => {}
+            }
+        }
+
+        if normalized.contains('_') && normalized.len() > 3 {
+            return Some(AccessionType::RefSeq);
+        }
+        None
+    }
+
     /// Detect the archive (NCBI, EBI, DDBJ) from an accession.
     fn detect_archive(&self, normalized: &str, acc_type: AccessionType) -> Option<String> {
         match acc_type {
@@ -433,14 +524,7 @@ impl AccessionValidator {
 
         for value in values {
             let result = self.validate(value);
-            if result.is_valid {
-                stats.valid_count += 1;
-                if let Some(acc_type) = result.accession_type {
-                    *stats.by_type.entry(acc_type).or_insert(0) += 1;
-                }
-            } else if !value.trim().is_empty() {
-                stats.invalid_count += 1;
-            }
+            stats.record(&result);
             results.push(result);
         }
 
@@ -706,4 +790,56 @@ mod tests {
         assert_eq!(stats.by_type.get(&AccessionType::BioSample), Some(&3));
         assert_eq!(results.len(), 4);
     }
+
+    #[test]
+    fn test_malformed_accessions() {
+        let validator = AccessionValidator::new();
+
+        // Too few digits for an SRA run
+        let result = validator.validate("SRR123");
+        assert!(!result.is_valid);
+        assert_eq!(result.expected_type, Some(AccessionType::SraRun));
+        assert!(result.error.unwrap().contains("SRR/ERR/DRR"));
+
+        // Too few digits for a BioProject
+        let result = validator.validate("PRJNA12");
+        assert_eq!(result.malformed_type(), Some(AccessionType::BioProject));
+
+        // Lowercase is resolvable but not canonical
+        let result = validator.validate("samn12345678");
+        assert!(result.is_valid);
+        assert!(!result.is_canonical);
+        assert_eq!(result.malformed_type(), Some(AccessionType::BioSample));
+
+        let result = validator.validate("SAMN12345678");
+        assert!(result.is_canonical);
+        assert_eq!(result.malformed_type(), None);
+
+        let result = validator.validate("hello");
+        assert_eq!(result.expected_type, None);
+    }
+
+    #[test]
+    fn test_column_stats_breakdown() {
+        let validator = AccessionValidator::new();
+
+        let values = vec![
+            "SRR1234567",
+            "ERR123456",
+            "SAMN123",
+            "samn12345678",
+            "SAMX",
+            "???",
+            "",
+        ];
+        let (_, stats) = validator.validate_column(&values);
+
+        assert_eq!(stats.valid_count, 2);
+        assert_eq!(stats.invalid_count, 4);
+        assert_eq!(stats.malformed_by_type.get(&AccessionType::BioSample), Some(&3));
+        assert_eq!(
+            stats.summary(),
+            "2 valid SRA Run, 3 malformed NCBI BioSample, 1 unrecognized"
+        );
+    }
 }
@@ -27,11 +27,12 @@ use crate::bio::mixs::MixsPackage;
 use crate::bio::taxonomy::TaxonomyValidator;
 use crate::input::DataTable;
 use crate::schema::TableSchema;
+use crate::suggestion::{Suggestion, SuggestionAction};
 use crate::validation::{Evidence, Observation, ObservationType, Severity};
 
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Null value strings that NCBI accepts.
 const VALID_NULL_VALUES: &[&str] = &[
@@ -85,6 +86,13 @@ pub struct ReadinessIssue {
     pub column: Option<String>,
     /// Suggested fix.
     pub suggestion: Option<String>,
+    /// Distinct offending values as written (e.g. a non-standard null).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<String>,
+    /// Known corrections by value as written (e.g. an abbreviated
+    /// organism's full name).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub replacements: BTreeMap<String, String>,
 }
 
 /// Categories of readiness issues.
@@ -108,6 +116,22 @@ pub enum IssueCategory {
     InvalidFormat,
 }
 
+impl IssueCategory {
+    /// Get a human-readable label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            IssueCategory::MissingField => "Missing field",
+            IssueCategory::InvalidOrganism => "Invalid organism",
+            IssueCategory::OrganismPackageMismatch => "Organism/package mismatch",
+            IssueCategory::DuplicateSamples => "Duplicate samples",
+            IssueCategory::InvalidDate => "Invalid date",
+            IssueCategory::InvalidCoordinates => "Invalid coordinates",
+            IssueCategory::InvalidNullValue => "Invalid null value",
+            IssueCategory::InvalidFormat => "Invalid format",
+        }
+    }
+}
+
 /// Summary statistics for readiness.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ReadinessStats {
@@ -125,6 +149,136 @@ pub struct ReadinessStats {
     pub unique_samples: usize,
 }
 
+impl NcbiReadiness {
+    /// Blocking issues, then warnings: the order
+    /// [`BioSampleValidator::to_observations`] reports them in.
+    pub fn issues(&self) -> impl Iterator<Item = &ReadinessIssue> {
+        self.blocking_issues.iter().chain(&self.warning_issues)
+    }
+
+    /// Observations for the issues, as [`BioSampleValidator::to_observations`]
+    /// reports them, each with a suggested fix for the curation layer.
+    ///
+    /// Each suggestion refers to the observation built with it, in the same
+    /// order. Non-standard nulls are replaced with NCBI's 'missing' term,
+    /// abbreviated organisms are expanded, and non-ISO dates converted;
+    /// issues without an automatic fix are flagged for review.
+    pub fn to_observations_with_suggestions(&self) -> (Vec<Observation>, Vec<Suggestion>) {
+        let blocking = self.blocking_issues.len();
+        self.issues()
+            .enumerate()
+            .map(|(idx, issue)| {
+                let observation = issue.to_observation(idx < blocking);
+                let suggestion = issue.to_suggestion(&observation.id, idx < blocking);
+                (observation, suggestion)
+            })
+            .unzip()
+    }
+}
+
+impl ReadinessIssue {
+    /// The observation reporting this issue for the curation layer.
+    fn to_observation(&self, blocking: bool) -> Observation {
+        let (severity, confidence) = if blocking {
+            (Severity::Error, 0.95)
+        } else {
+            (Severity::Warning, 0.85)
+        };
+        let obs_type = match (blocking, self.category) {
+            (true, IssueCategory::DuplicateSamples) => ObservationType::Inconsistency,
+            (true, IssueCategory::MissingField) => ObservationType::Completeness,
+            (false, IssueCategory::InvalidDate | IssueCategory::InvalidCoordinates) => {
+                ObservationType::PatternViolation
+            }
+            (false, IssueCategory::InvalidNullValue) => ObservationType::Inconsistency,
+            _ => ObservationType::ConstraintViolation,
+        };
+
+        let column = self.column.as_deref().unwrap_or("_metadata");
+
+        // Build evidence
+        let mut evidence = Evidence::new();
+        if let Some(rows) = &self.affected_rows {
+            evidence = evidence
+                .with_occurrences(rows.len())
+                .with_sample_rows(rows.iter().take(5).copied().collect());
+        }
+        if let Some(suggestion) = &self.suggestion {
+            evidence = evidence.with_expected(json!({ "suggestion": suggestion }));
+        }
+
+        Observation::new(obs_type, severity, column, &self.description)
+            .with_confidence(confidence)
+            .with_detector("BioSampleValidator")
+            .with_evidence(evidence)
+    }
+
+    /// The suggested fix for this issue, for the observation reporting it.
+    fn to_suggestion(&self, observation_id: &str, blocking: bool) -> Suggestion {
+        let rows = self.affected_rows.clone().unwrap_or_default();
+        let (action, params, rationale) = match (&self.column, self.category) {
+            (Some(column), IssueCategory::InvalidNullValue) if !self.values.is_empty() => {
+                let mapping: BTreeMap<&str, &str> =
+                    self.values.iter().map(|value| (value.as_str(), "missing")).collect();
+                (
+                    SuggestionAction::Standardize,
+                    json!({"column": column, "mapping": mapping}),
+                    format!(
+                        "Replace non-standard null value(s) {:?} in column '{}' with 'missing'. NCBI rejects blank values and only accepts its own null terms, such as 'missing' or 'not collected'.",
+                        self.values, column
+                    ),
+                )
+            }
+            (Some(column), IssueCategory::InvalidOrganism) if !self.replacements.is_empty() => (
+                SuggestionAction::Standardize,
+                json!({"column": column, "mapping": self.replacements}),
+                format!(
+                    "Expand abbreviated organism names in column '{}' to full scientific names ({}).",
+                    column,
+                    self.replacements
+                        .iter()
+                        .map(|(from, to)| format!("'{}' → '{}'", from.trim(), to))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            ),
+            (Some(column), IssueCategory::InvalidDate) => (
+                SuggestionAction::ConvertDate,
+                json!({"column": column, "target_format": "ISO (YYYY-MM-DD)"}),
+                format!(
+                    "Standardize dates in column '{}' to ISO format (YYYY-MM-DD), as NCBI requires.",
+                    column
+                ),
+            ),
+            (column, _) => {
+                let flag_column = match column {
+                    Some(column) => format!("{}_ncbi_review", column),
+                    None => "ncbi_review".to_string(),
+                };
+                (
+                    SuggestionAction::Flag,
+                    json!({
+                        "column": column,
+                        "flag_column": flag_column,
+                        "rows": rows,
+                    }),
+                    format!(
+                        "Flag for review before NCBI submission: {}",
+                        self.suggestion.as_deref().unwrap_or(&self.description)
+                    ),
+                )
+            }
+        };
+
+        Suggestion::new(observation_id, action, rationale)
+            .with_parameters(params)
+            .with_affected_rows(rows.len())
+            .with_confidence(if blocking { 0.95 } else { 0.85 })
+            .with_priority(if blocking { 1 } else { 3 })
+            .with_suggester("BioSampleValidator")
+    }
+}
+
 /// Validates metadata for NCBI BioSample submission.
 pub struct BioSampleValidator {
     /// Taxonomy validator for organism checking.
@@ -274,6 +428,8 @@ impl BioSampleValidator {
                         "Add differentiating attributes like collection_date, sample_name, or replicate number"
                             .to_string(),
                     ),
+                    values: Vec::new(),
+                    replacements: BTreeMap::new(),
                 });
             }
         }
@@ -327,6 +483,8 @@ impl BioSampleValidator {
                         "Use ISO 8601 format: YYYY-MM-DD, YYYY-MM, or YYYY. Or use 'missing', 'not collected'."
                             .to_string(),
                     ),
+                    values: Vec::new(),
+                    replacements: BTreeMap::new(),
                 });
             }
         }
@@ -386,6 +544,8 @@ impl BioSampleValidator {
                         "Use decimal degrees: 'DD.DDDD DD.DDDD' or 'DD.DDDDН DD.DDDDW'. Or use 'missing', 'not collected'."
                             .to_string(),
                     ),
+                    values: Vec::new(),
+                    replacements: BTreeMap::new(),
                 });
             }
         }
@@ -444,6 +604,8 @@ impl BioSampleValidator {
                         "Use NCBI-accepted values: 'missing', 'not collected', 'not applicable', 'not provided', or 'restricted access'"
                             .to_string(),
                     ),
+                    values: vec![value],
+                    replacements: BTreeMap::new(),
                 });
             }
         }
@@ -492,11 +654,10 @@ impl BioSampleValidator {
                 match result {
                     crate::bio::taxonomy::TaxonomyValidationResult::Valid { .. } => {}
                     crate::bio::taxonomy::TaxonomyValidationResult::Abbreviation {
-                        input,
-                        expanded,
-                        ..
+                        expanded, ..
                     } => {
-                        abbreviated_organisms.push((row_idx, input, expanded));
+                        // Kept as written, so the name can be replaced as is
+                        abbreviated_organisms.push((row_idx, value.clone(), expanded));
                     }
                     crate::bio::taxonomy::TaxonomyValidationResult::CaseError { input, .. } => {
                         invalid_organisms.push((row_idx, input));
@@ -530,8 +691,13 @@ impl BioSampleValidator {
                 column: Some(col.name.clone()),
                 suggestion: Some(format!(
                     "Expand '{}' to '{}'. Use full binomial nomenclature.",
-                    sample.1, sample.2
+                    sample.1.trim(), sample.2
                 )),
+                values: Vec::new(),
+                replacements: abbreviated_organisms
+                    .iter()
+                    .map(|(_, name, expanded)| (name.clone(), expanded.clone()))
+                    .collect(),
             });
         }
 
@@ -551,6 +717,8 @@ impl BioSampleValidator {
                     "Verify organism names against NCBI Taxonomy. Use the scientific name with proper capitalization."
                         .to_string(),
                 ),
+                values: Vec::new(),
+                replacements: BTreeMap::new(),
             });
         }
 
@@ -612,6 +780,8 @@ impl BioSampleValidator {
                             "Verify organism is appropriate for {} package, or choose a different MIxS package.",
                             pkg.name()
                         )),
+                        values: Vec::new(),
+                        replacements: BTreeMap::new(),
                     });
                 }
             }
@@ -768,74 +938,16 @@ impl BioSampleValidator {
     }
 
     /// Convert readiness check to observations for the curation layer.
+    ///
+    /// Blocking issues are errors and the rest warnings. Use
+    /// [`NcbiReadiness::to_observations_with_suggestions`] to get fixes too.
     pub fn to_observations(&self, readiness: &NcbiReadiness) -> Vec<Observation> {
-        let mut observations = Vec::new();
-
-        // Convert blocking issues
-        for issue in &readiness.blocking_issues {
-            let severity = Severity::Error;
-            let obs_type = match issue.category {
-                IssueCategory::DuplicateSamples => ObservationType::Inconsistency,
-                IssueCategory::InvalidOrganism => ObservationType::ConstraintViolation,
-                IssueCategory::MissingField => ObservationType::Completeness,
-                _ => ObservationType::ConstraintViolation,
-            };
-
-            let column = issue.column.as_deref().unwrap_or("_metadata");
-
-            // Build evidence
-            let mut evidence = Evidence::new();
-            if let Some(rows) = &issue.affected_rows {
-                evidence = evidence
-                    .with_occurrences(rows.len())
-                    .with_sample_rows(rows.iter().take(5).copied().collect());
-            }
-            if let Some(suggestion) = &issue.suggestion {
-                evidence = evidence.with_expected(json!({ "suggestion": suggestion }));
-            }
-
-            let obs = Observation::new(obs_type, severity, column, &issue.description)
-                .with_confidence(0.95)
-                .with_detector("BioSampleValidator")
-                .with_evidence(evidence);
-
-            observations.push(obs);
-        }
-
-        // Convert warning issues
-        for issue in &readiness.warning_issues {
-            let severity = Severity::Warning;
-            let obs_type = match issue.category {
-                IssueCategory::InvalidDate | IssueCategory::InvalidCoordinates => {
-                    ObservationType::PatternViolation
-                }
-                IssueCategory::InvalidNullValue => ObservationType::Inconsistency,
-                IssueCategory::OrganismPackageMismatch => ObservationType::ConstraintViolation,
-                _ => ObservationType::ConstraintViolation,
-            };
-
-            let column = issue.column.as_deref().unwrap_or("_metadata");
-
-            // Build evidence
-            let mut evidence = Evidence::new();
-            if let Some(rows) = &issue.affected_rows {
-                evidence = evidence
-                    .with_occurrences(rows.len())
-                    .with_sample_rows(rows.iter().take(5).copied().collect());
-            }
-            if let Some(suggestion) = &issue.suggestion {
-                evidence = evidence.with_expected(json!({ "suggestion": suggestion }));
-            }
-
-            let obs = Observation::new(obs_type, severity, column, &issue.description)
-                .with_confidence(0.85)
-                .with_detector("BioSampleValidator")
-                .with_evidence(evidence);
-
-            observations.push(obs);
-        }
-
-        observations
+        let blocking = readiness.blocking_issues.len();
+        readiness
+            .issues()
+            .enumerate()
+            .map(|(idx, issue)| issue.to_observation(idx < blocking))
+            .collect()
     }
 }
 
@@ -883,6 +995,8 @@ mod tests {
                 ],
             ],
             delimiter: b'\t',
+            total_rows: None,
+            row_lines: Vec::new(),
         };
 
         let mut schema = TableSchema::new();
@@ -961,6 +1075,8 @@ mod tests {
                 vec!["S004".to_string(), "not collected".to_string()], // valid
             ],
             delimiter: b'\t',
+            total_rows: None,
+            row_lines: Vec::new(),
         };
 
         let mut schema = TableSchema::new();
@@ -1012,4 +1128,38 @@ mod tests {
         assert!(!validator.is_valid_coordinates_or_null("invalid"));
         assert!(!validator.is_valid_coordinates_or_null("somewhere"));
     }
+
+    #[test]
+    fn test_readiness_suggestions() {
+        let (mut data, schema) = create_test_data();
+        data.rows[2][4] = "N/A".to_string();
+        let validator = BioSampleValidator::new();
+        let readiness = validator.check_readiness(&data, &schema, Some(MixsPackage::HumanGut));
+        let (observations, suggestions) = readiness.to_observations_with_suggestions();
+        assert_eq!(suggestions.len(), readiness.issues().count());
+        for (suggestion, obs) in suggestions.iter().zip(&observations) {
+            assert_eq!(suggestion.observation_id, obs.id);
+        }
+
+        let by_column = |column: &str| {
+            suggestions
+                .iter()
+                .filter(|s| s.column() == Some(column))
+                .map(|s| s.action)
+                .collect::<Vec<_>>()
+        };
+        assert!(by_column("organism").contains(&SuggestionAction::Standardize));
+        assert_eq!(by_column("collection_date"), vec![SuggestionAction::ConvertDate]);
+        assert_eq!(by_column("lat_lon"), vec![SuggestionAction::Flag]);
+        assert_eq!(by_column("treatment"), vec![SuggestionAction::Standardize]);
+        let missing = suggestions.iter().find(|s| s.column() == Some("treatment")).unwrap();
+        assert_eq!(missing.parameters["mapping"]["N/A"], "missing");
+
+        let expand = suggestions
+            .iter()
+            .find(|s| s.column() == Some("organism") && s.action == SuggestionAction::Standardize)
+            .unwrap();
+        assert_eq!(expand.parameters["mapping"]["E. coli"], "Escherichia coli");
+        assert_eq!(expand.priority, 1);
+    }
 }
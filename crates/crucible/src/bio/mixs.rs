@@ -448,6 +448,48 @@ impl MixsSchema {
             ],
         );
 
+        // Built environment specific fields
+        self.package_fields.insert(
+            MixsPackage::BuiltEnvironment,
+            vec![
+                MixsField::new("build_occup_type", MixsFieldRequirement::Mandatory)
+                    .with_label("Building Occupancy Type")
+                    .with_description("The primary function for which a building is used")
+                    .with_example("office")
+                    .with_aliases(vec!["building_type", "occupancy_type"]),
+                MixsField::new("surf_material", MixsFieldRequirement::Mandatory)
+                    .with_label("Surface Material")
+                    .with_description("Surface material from which the sample was collected")
+                    .with_example("stainless steel")
+                    .with_aliases(vec!["surface_material"]),
+                MixsField::new("ventilation_type", MixsFieldRequirement::Mandatory)
+                    .with_label("Ventilation Type")
+                    .with_description("Ventilation system used in the sampled premises")
+                    .with_example("mechanical")
+                    .with_aliases(vec!["ventilation"]),
+                MixsField::new("building_setting", MixsFieldRequirement::Mandatory)
+                    .with_label("Building Setting")
+                    .with_description("Location of the building (urban, suburban, rural)")
+                    .with_example("urban"),
+                MixsField::new("room_type", MixsFieldRequirement::Recommended)
+                    .with_label("Room Type")
+                    .with_description("Main purpose of the sampled room")
+                    .with_example("bathroom")
+                    .with_aliases(vec!["room"]),
+                MixsField::new("rel_air_humidity", MixsFieldRequirement::Recommended)
+                    .with_label("Relative Air Humidity")
+                    .with_description("Relative humidity of the indoor air")
+                    .with_format("number unit")
+                    .with_example("45 %")
+                    .with_aliases(vec!["humidity", "rel_humidity"]),
+                MixsField::new("indoor_surf", MixsFieldRequirement::Recommended)
+                    .with_label("Indoor Surface")
+                    .with_description("Type of indoor surface sampled")
+                    .with_example("countertop")
+                    .with_aliases(vec!["surface", "surface_type"]),
+            ],
+        );
+
         // Add empty entries for other packages (to be expanded)
         for package in MixsPackage::all() {
             self.package_fields.entry(*package).or_insert_with(Vec::new);
@@ -573,6 +615,27 @@ mod tests {
         assert!(has_subject_id);
     }
 
+    #[test]
+    fn test_built_environment_fields() {
+        let schema = MixsSchema::new();
+        let mandatory: Vec<_> = schema
+            .mandatory_fields_for_package(MixsPackage::BuiltEnvironment)
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect();
+
+        assert!(mandatory.contains(&"build_occup_type"));
+        assert!(mandatory.contains(&"surf_material"));
+        assert!(mandatory.contains(&"ventilation_type"));
+        assert!(!MixsPackage::BuiltEnvironment.is_human_package());
+        assert_eq!(MixsPackage::BuiltEnvironment.name(), "built environment");
+
+        // A bare "material" column is the environmental medium, not the surface
+        let package = Some(MixsPackage::BuiltEnvironment);
+        assert_eq!(schema.find_field("material", package).unwrap().name, "env_medium");
+        assert_eq!(schema.find_field("surface_material", package).unwrap().name, "surf_material");
+    }
+
     #[test]
     fn test_find_field_with_alias() {
         let schema = MixsSchema::new();
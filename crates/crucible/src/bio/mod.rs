@@ -42,4 +42,5 @@ pub use ontology::{
     OntologyValidator,
 };
 pub use taxonomy::{TaxonomyEntry, TaxonomyStats, TaxonomyValidationResult, TaxonomyValidator};
-pub use validators::{BioValidator, MixsComplianceValidator};
+pub use validators::{BioValidator, CollectionDateValidator, MixsComplianceValidator};
+pub(crate) use validators::BioValidatorAdapter;
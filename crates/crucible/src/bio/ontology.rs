@@ -24,11 +24,13 @@
 //! ```
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 
+use crate::validation::levenshtein_distance;
+
 /// Supported ontology types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum OntologyType {
@@ -133,6 +135,23 @@ impl OntologyTerm {
         self.definition = Some(definition.into());
         self
     }
+
+    /// Add a parent term ID.
+    pub fn with_parent(mut self, parent_id: impl Into<String>) -> Self {
+        self.parent_ids.push(parent_id.into());
+        self
+    }
+}
+
+/// Default edit distance allowed for fuzzy matches.
+const DEFAULT_MAX_FUZZY_DISTANCE: usize = 2;
+
+/// Furthest an ancestor suggestion may be from the input text.
+const MAX_ANCESTOR_HOPS: usize = 4;
+
+/// Confidence of an ancestor suggestion `hops` levels above the input text.
+fn ancestor_confidence(hops: usize) -> f64 {
+    0.8 - 0.1 * (hops - 1) as f64
 }
 
 /// Statistics about loaded ontology data.
@@ -157,6 +176,10 @@ pub struct OntologyValidator {
     terms_by_label: HashMap<String, Vec<OntologyTerm>>,
     /// Terms indexed by lowercase synonym.
     terms_by_synonym: HashMap<String, Vec<OntologyTerm>>,
+    /// Lowercase labels and synonyms by first character, for fuzzy matching.
+    names_by_initial: HashMap<char, HashSet<String>>,
+    /// Maximum edit distance for fuzzy matches.
+    max_fuzzy_distance: usize,
     /// Statistics about loaded data.
     stats: OntologyStats,
 }
@@ -168,6 +191,8 @@ impl OntologyValidator {
             terms_by_id: HashMap::new(),
             terms_by_label: HashMap::new(),
             terms_by_synonym: HashMap::new(),
+            names_by_initial: HashMap::new(),
+            max_fuzzy_distance: DEFAULT_MAX_FUZZY_DISTANCE,
             stats: OntologyStats {
                 source: "built-in".to_string(),
                 ..Default::default()
@@ -180,6 +205,12 @@ impl OntologyValidator {
         validator
     }
 
+    /// Set the maximum edit distance for fuzzy matches (0 disables them).
+    pub fn with_max_fuzzy_distance(mut self, max_distance: usize) -> Self {
+        self.max_fuzzy_distance = max_distance;
+        self
+    }
+
     /// Load terms from an OBO format file.
     pub fn load_obo_file(&mut self, path: impl AsRef<Path>) -> Result<usize, std::io::Error> {
         let file = File::open(path)?;
@@ -248,6 +279,7 @@ impl OntologyValidator {
 
         // Index by label
         let label_lower = term.label.to_lowercase();
+        self.index_initial(&label_lower);
         self.terms_by_label
             .entry(label_lower)
             .or_default()
@@ -256,6 +288,7 @@ impl OntologyValidator {
         // Index by synonyms
         for synonym in &term.synonyms {
             let syn_lower = synonym.to_lowercase();
+            self.index_initial(&syn_lower);
             self.terms_by_synonym
                 .entry(syn_lower)
                 .or_default()
@@ -263,6 +296,16 @@ impl OntologyValidator {
         }
     }
 
+    /// Add a lowercase name to its first-character bucket.
+    fn index_initial(&mut self, name: &str) {
+        if let Some(initial) = name.chars().next() {
+            self.names_by_initial
+                .entry(initial)
+                .or_default()
+                .insert(name.to_string());
+        }
+    }
+
     /// Update statistics.
     fn update_stats(&mut self) {
         self.stats.total_terms = self.terms_by_id.len();
@@ -407,18 +450,136 @@ impl OntologyValidator {
                     }
                 }
             }
+
+            // Broader terms for text naming a narrower, unindexed concept
+            mappings.extend(self.ancestor_mappings(text, &text_lower, ontology_filter));
+
+            // Labels and synonyms a typo away
+            mappings.extend(self.fuzzy_mappings(text, &text_lower, ontology_filter));
         }
 
         // Sort by confidence
         mappings.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap());
 
         // Deduplicate by term ID
-        let mut seen = std::collections::HashSet::new();
+        let mut seen = HashSet::new();
         mappings.retain(|m| seen.insert(m.term_id.clone()));
 
         mappings
     }
 
+    /// Suggest terms whose label or synonym is within a small edit distance.
+    ///
+    /// Only names sharing the text's first character are compared. The
+    /// allowed distance is capped at a quarter of the text's length so short
+    /// terms don't match unrelated ones, and confidence falls with the
+    /// distance relative to the longer string.
+    fn fuzzy_mappings(
+        &self,
+        text: &str,
+        text_lower: &str,
+        ontology_filter: Option<OntologyType>,
+    ) -> Vec<OntologyMapping> {
+        let text_len = text_lower.chars().count();
+        let max_distance = self.max_fuzzy_distance.min(text_len / 4);
+        let Some(names) = text_lower
+            .chars()
+            .next()
+            .filter(|_| max_distance > 0)
+            .and_then(|initial| self.names_by_initial.get(&initial))
+        else {
+            return Vec::new();
+        };
+
+        let mut mappings = Vec::new();
+        for name in names {
+            let name_len = name.chars().count();
+            if name_len.abs_diff(text_len) > max_distance {
+                continue;
+            }
+            let distance = levenshtein_distance(text_lower, name);
+            if distance == 0 || distance > max_distance {
+                continue;
+            }
+
+            let confidence = 0.8 * (1.0 - distance as f64 / text_len.max(name_len) as f64);
+            let terms = self.terms_by_label.get(name).into_iter().flatten();
+            let synonym_terms = self.terms_by_synonym.get(name).into_iter().flatten();
+            for term in terms.chain(synonym_terms) {
+                if ontology_filter.is_none() || ontology_filter == Some(term.ontology) {
+                    mappings.push(OntologyMapping {
+                        input: text.to_string(),
+                        term_id: term.id.clone(),
+                        term_label: term.label.clone(),
+                        ontology: term.ontology,
+                        match_type: MatchType::Fuzzy,
+                        confidence,
+                    });
+                }
+            }
+        }
+
+        mappings
+    }
+
+    /// Suggest the nearest indexed ancestors of free text with no direct match.
+    ///
+    /// The longest trailing phrase of the text that names a term (e.g. "forest
+    /// soil" in "boreal forest soil") is taken as the text's parent, one hop
+    /// away. Its `parent_ids` are then climbed breadth-first, so a term outside
+    /// the ontology filter is skipped in favour of its nearest ancestor inside
+    /// it. Confidence drops with each hop.
+    fn ancestor_mappings(
+        &self,
+        text: &str,
+        text_lower: &str,
+        ontology_filter: Option<OntologyType>,
+    ) -> Vec<OntologyMapping> {
+        let words: Vec<&str> = text_lower.split_whitespace().collect();
+        let nearest = (1..words.len()).find_map(|start| {
+            let phrase = words[start..].join(" ");
+            self.terms_by_label
+                .get(&phrase)
+                .or_else(|| self.terms_by_synonym.get(&phrase))
+        });
+        let Some(nearest) = nearest else {
+            return Vec::new();
+        };
+
+        let mut mappings = Vec::new();
+        let mut seen = HashSet::new();
+        let mut level: Vec<&OntologyTerm> = nearest.iter().collect();
+        for hops in 1..=MAX_ANCESTOR_HOPS {
+            let mut parents = Vec::new();
+            for term in level {
+                if !seen.insert(term.id.to_uppercase()) {
+                    continue;
+                }
+                if ontology_filter.is_none() || ontology_filter == Some(term.ontology) {
+                    mappings.push(OntologyMapping {
+                        input: text.to_string(),
+                        term_id: term.id.clone(),
+                        term_label: term.label.clone(),
+                        ontology: term.ontology,
+                        match_type: MatchType::Ancestor(hops),
+                        confidence: ancestor_confidence(hops),
+                    });
+                }
+                parents.extend(
+                    term.parent_ids
+                        .iter()
+                        .filter_map(|id| self.terms_by_id.get(&id.to_uppercase())),
+                );
+            }
+            if parents.is_empty() {
+                break;
+            }
+            level = parents;
+        }
+
+        mappings
+    }
+
     /// Load common ENVO terms for environmental metadata.
     fn load_common_envo_terms(&mut self) {
         let terms = [
@@ -482,9 +643,32 @@ impl OntologyValidator {
             ("ENVO:00002018", "clay", &[]),
         ];
 
+        // is_a links between the terms above
+        let parents = [
+            ("ENVO:01000174", "ENVO:00000446"),
+            ("ENVO:01000175", "ENVO:00000446"),
+            ("ENVO:01000176", "ENVO:00000446"),
+            ("ENVO:01000177", "ENVO:00000446"),
+            ("ENVO:01000178", "ENVO:00000446"),
+            ("ENVO:01000179", "ENVO:00000446"),
+            ("ENVO:01000180", "ENVO:00000446"),
+            ("ENVO:01000219", "ENVO:00000446"),
+            ("ENVO:00002150", "ENVO:00002151"),
+            ("ENVO:00002151", "ENVO:00002001"),
+            ("ENVO:00002016", "ENVO:00002001"),
+            ("ENVO:00002042", "ENVO:00002001"),
+            ("ENVO:00002044", "ENVO:00002001"),
+            ("ENVO:00002045", "ENVO:00002001"),
+            ("ENVO:00002261", "ENVO:00001998"),
+            ("ENVO:00002262", "ENVO:00001998"),
+        ];
+
         for (id, label, synonyms) in terms {
-            let term = OntologyTerm::new(id, label, OntologyType::Envo)
+            let mut term = OntologyTerm::new(id, label, OntologyType::Envo)
                 .with_synonyms(synonyms.iter().map(|s| s.to_string()).collect());
+            for (_, parent_id) in parents.iter().filter(|(child, _)| *child == id) {
+                term = term.with_parent(*parent_id);
+            }
             self.add_term(term);
         }
     }
@@ -687,6 +871,9 @@ pub enum MatchType {
     Partial,
     /// Fuzzy match.
     Fuzzy,
+    /// Matched a broader term this many hops above the concept the text
+    /// names, for text with no direct match.
+    Ancestor(usize),
 }
 
 #[cfg(test)]
@@ -778,6 +965,55 @@ mod tests {
         assert!(mappings.iter().any(|m| m.term_id == "MONDO:0005011"));
     }
 
+    #[test]
+    fn test_suggest_ancestor_mappings() {
+        let validator = OntologyValidator::new();
+
+        // No direct match: "forest soil" is one hop up, "soil" two
+        let mappings = validator.suggest_mappings("boreal forest soil", Some(OntologyType::Envo));
+        assert_eq!(mappings[0].term_id, "ENVO:00002261");
+        assert!(matches!(mappings[0].match_type, MatchType::Ancestor(1)));
+        let soil = mappings.iter().find(|m| m.term_id == "ENVO:00001998").unwrap();
+        assert!(matches!(soil.match_type, MatchType::Ancestor(2)));
+        assert!(soil.confidence < mappings[0].confidence);
+
+        // Terms missing from the index are climbed past
+        let mut validator = OntologyValidator::new();
+        validator.add_term(
+            OntologyTerm::new("ENVO:99999999", "peat soil", OntologyType::Envo)
+                .with_parent("ENVO:00009999")
+                .with_parent("ENVO:00001998"),
+        );
+        let mappings = validator.suggest_mappings("alpine peat soil", None);
+        assert!(matches!(mappings[0].match_type, MatchType::Ancestor(1)));
+        assert!(mappings.iter().any(|m| m.term_id == "ENVO:00001998"
+            && matches!(m.match_type, MatchType::Ancestor(2))));
+
+        // Exact matches are never replaced by ancestors
+        let mappings = validator.suggest_mappings("forest soil", None);
+        assert!(mappings.iter().all(|m| !matches!(m.match_type, MatchType::Ancestor(_))));
+    }
+
+    #[test]
+    fn test_suggest_fuzzy_mappings() {
+        let validator = OntologyValidator::new();
+
+        let mappings = validator.suggest_mappings("faeces", Some(OntologyType::Uberon));
+        assert_eq!(mappings[0].term_id, "UBERON:0001988");
+        assert!(matches!(mappings[0].match_type, MatchType::Fuzzy));
+        assert!(mappings[0].confidence < 0.95);
+
+        let mappings = validator.suggest_mappings("intestin", Some(OntologyType::Uberon));
+        assert_eq!(mappings[0].term_id, "UBERON:0000160");
+
+        // Short terms allow no edits
+        let mappings = validator.suggest_mappings("gat", None);
+        assert!(mappings.iter().all(|m| !matches!(m.match_type, MatchType::Fuzzy)));
+
+        let validator = OntologyValidator::new().with_max_fuzzy_distance(0);
+        assert!(validator.suggest_mappings("faeces", Some(OntologyType::Uberon)).is_empty());
+    }
+
     #[test]
     fn test_filter_by_ontology() {
         let validator = OntologyValidator::new();
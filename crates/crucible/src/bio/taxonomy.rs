@@ -18,6 +18,10 @@
 //!
 //! // Or load from NCBI dump files
 //! let validator = TaxonomyValidator::from_ncbi_dump("taxdump/names.dmp", "taxdump/nodes.dmp")?;
+//!
+//! // Or extend the built-in database with names.dmp
+//! let mut validator = TaxonomyValidator::new();
+//! validator.load_names_dmp("taxdump/names.dmp")?;
 //! ```
 
 use serde::{Deserialize, Serialize};
@@ -37,6 +41,9 @@ pub struct TaxonomyEntry {
     pub rank: String,
     /// Common names (if any).
     pub common_names: Vec<String>,
+    /// Synonyms and equivalent names (if any).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub synonyms: Vec<String>,
     /// Parent taxonomy ID (for lineage lookups).
     #[serde(default)]
     pub parent_taxid: Option<u32>,
@@ -62,8 +69,8 @@ pub struct TaxonomyStats {
 pub struct TaxonomyValidator {
     /// Common abbreviations and their expansions.
     abbreviations: HashMap<String, String>,
-    /// Organism lookup by lowercase name.
-    known_organisms: HashMap<String, TaxonomyEntry>,
+    /// Taxid lookup by lowercase scientific, common, or synonym name.
+    known_organisms: HashMap<String, u32>,
     /// Taxid to entry lookup.
     taxid_index: HashMap<u32, TaxonomyEntry>,
     /// Statistics about loaded data.
@@ -109,15 +116,18 @@ impl TaxonomyValidator {
             },
         };
 
-        // Load ranks from nodes.dmp if provided
-        let ranks: HashMap<u32, (String, u32)> = if let Some(nodes) = nodes_path {
-            validator.parse_nodes_dmp(nodes)?
-        } else {
-            HashMap::new()
-        };
-
         // Load names from names.dmp
-        validator.parse_names_dmp(names_path, &ranks)?;
+        validator.load_names_dmp(names_path)?;
+
+        // Fill in ranks and parents from nodes.dmp if provided
+        if let Some(nodes) = nodes_path {
+            for (taxid, (rank, parent_taxid)) in validator.parse_nodes_dmp(nodes)? {
+                if let Some(entry) = validator.taxid_index.get_mut(&taxid) {
+                    entry.rank = rank;
+                    entry.parent_taxid = (parent_taxid > 0).then_some(parent_taxid);
+                }
+            }
+        }
 
         // Load abbreviations
         validator.load_common_abbreviations();
@@ -150,74 +160,83 @@ impl TaxonomyValidator {
         Ok(ranks)
     }
 
-    /// Parse names.dmp file to load taxonomy entries.
-    fn parse_names_dmp(
-        &mut self,
-        path: impl AsRef<Path>,
-        ranks: &HashMap<u32, (String, u32)>,
-    ) -> Result<(), std::io::Error> {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-
-        // Temporary storage for building entries
-        let mut scientific_names: HashMap<u32, String> = HashMap::new();
-        let mut common_names: HashMap<u32, Vec<String>> = HashMap::new();
-
-        for line in reader.lines() {
-            let line = line?;
-            let parts: Vec<&str> = line.split("\t|\t").collect();
-            if parts.len() >= 4 {
-                if let Ok(taxid) = parts[0].trim_end_matches("\t|").parse::<u32>() {
-                    let name = parts[1].trim().to_string();
-                    let name_class = parts[3].trim_end_matches("\t|").trim();
-
-                    match name_class {
-                        "scientific name" => {
-                            scientific_names.insert(taxid, name);
-                        }
-                        "common name" | "genbank common name" => {
-                            common_names.entry(taxid).or_default().push(name);
-                        }
-                        _ => {}
-                    }
-                }
+    /// Load names from an NCBI names.dmp file.
+    ///
+    /// Scientific names, common names, and synonyms are added to the lookup
+    /// index alongside any organisms already known. The file is streamed line
+    /// by line, so the full NCBI dump can be loaded without holding it in
+    /// memory. Taxa not already known get rank "no rank"; use
+    /// [`from_ncbi_dump`](Self::from_ncbi_dump) with nodes.dmp for ranks.
+    ///
+    /// Returns the number of taxa with a scientific name in the file.
+    pub fn load_names_dmp(&mut self, path: impl AsRef<Path>) -> Result<usize, std::io::Error> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut line = String::new();
+        let mut taxa_loaded = 0;
+
+        while reader.read_line(&mut line)? > 0 {
+            // Format: taxid\t|\tname\t|\tunique name\t|\tname class\t|
+            let mut fields = line.trim_end_matches(['\n', '\r']).split("\t|");
+            let taxid = fields.next().and_then(|f| f.trim().parse::<u32>().ok());
+            let name = fields.next().map(str::trim);
+            let name_class = fields.nth(1).map(str::trim);
+
+            if let (Some(taxid), Some(name), Some(name_class)) = (taxid, name, name_class)
+                && !name.is_empty()
+                && self.add_name(taxid, name, name_class)
+            {
+                taxa_loaded += 1;
             }
+            line.clear();
         }
 
-        // Build entries from collected data
-        for (taxid, scientific_name) in scientific_names {
-            let (rank, parent_taxid) = ranks
-                .get(&taxid)
-                .cloned()
-                .unwrap_or(("no rank".to_string(), 0));
-
-            let entry = TaxonomyEntry {
-                taxid,
-                scientific_name: scientific_name.clone(),
-                rank,
-                common_names: common_names.remove(&taxid).unwrap_or_default(),
-                parent_taxid: if parent_taxid > 0 {
-                    Some(parent_taxid)
-                } else {
-                    None
-                },
-            };
+        // Names without a scientific name for their taxon can't be resolved
+        self.taxid_index
+            .retain(|_, entry| !entry.scientific_name.is_empty());
+        if self.stats.source == "built-in" {
+            self.stats.source = "built-in + NCBI names.dmp".to_string();
+        }
+        self.update_stats();
 
-            // Index by scientific name
-            self.known_organisms
-                .insert(scientific_name.to_lowercase(), entry.clone());
+        Ok(taxa_loaded)
+    }
 
-            // Index by common names
-            for common in &entry.common_names {
-                self.known_organisms
-                    .insert(common.to_lowercase(), entry.clone());
+    /// Add one names.dmp record. Returns true for a scientific name.
+    fn add_name(&mut self, taxid: u32, name: &str, name_class: &str) -> bool {
+        let entry = self
+            .taxid_index
+            .entry(taxid)
+            .or_insert_with(|| TaxonomyEntry {
+                taxid,
+                scientific_name: String::new(),
+                rank: "no rank".to_string(),
+                common_names: Vec::new(),
+                synonyms: Vec::new(),
+                parent_taxid: None,
+            });
+
+        let key = name.to_lowercase();
+        match name_class {
+            "scientific name" => {
+                entry.scientific_name = name.to_string();
+                // Scientific names take precedence over other taxa's synonyms
+                self.known_organisms.insert(key, taxid);
+                return true;
             }
-
-            // Index by taxid
-            self.taxid_index.insert(taxid, entry);
+            "common name" | "genbank common name" => {
+                if !entry.common_names.iter().any(|n| n == name) {
+                    entry.common_names.push(name.to_string());
+                }
+            }
+            "synonym" | "equivalent name" | "genbank synonym" => {
+                if !entry.synonyms.iter().any(|n| n == name) {
+                    entry.synonyms.push(name.to_string());
+                }
+            }
+            _ => return false,
         }
-
-        Ok(())
+        self.known_organisms.entry(key).or_insert(taxid);
+        false
     }
 
     /// Update statistics after loading data.
@@ -471,17 +490,17 @@ impl TaxonomyValidator {
                 scientific_name: name.to_string(),
                 rank: rank.to_string(),
                 common_names: common.iter().map(|s| s.to_string()).collect(),
+                synonyms: Vec::new(),
                 parent_taxid: None, // Could add lineage later
             };
 
             // Index by lowercase scientific name
-            self.known_organisms
-                .insert(name.to_lowercase(), entry.clone());
+            self.known_organisms.insert(name.to_lowercase(), *taxid);
 
             // Also index by common names
             for common_name in &entry.common_names {
                 self.known_organisms
-                    .insert(common_name.to_lowercase(), entry.clone());
+                    .insert(common_name.to_lowercase(), *taxid);
             }
 
             // Index by taxid
@@ -499,16 +518,14 @@ impl TaxonomyValidator {
         let name_lower = name.to_lowercase().trim().to_string();
 
         // Direct lookup
-        if let Some(entry) = self.known_organisms.get(&name_lower) {
-            return Some(entry);
+        if let Some(taxid) = self.known_organisms.get(&name_lower) {
+            return self.taxid_index.get(taxid);
         }
 
         // Try expanding abbreviation
-        if let Some(expanded) = self.expand_abbreviation(&name_lower) {
-            return self.known_organisms.get(&expanded.to_lowercase());
-        }
-
-        None
+        let expanded = self.expand_abbreviation(&name_lower)?;
+        let taxid = self.known_organisms.get(&expanded.to_lowercase())?;
+        self.taxid_index.get(taxid)
     }
 
     /// Validate an organism name and return suggestions.
@@ -527,7 +544,7 @@ impl TaxonomyValidator {
 
         // Check if it's a known abbreviation
         if let Some(expanded) = self.expand_abbreviation(name_trimmed) {
-            if let Some(entry) = self.known_organisms.get(&expanded.to_lowercase()) {
+            if let Some(entry) = self.lookup(expanded) {
                 return TaxonomyValidationResult::Abbreviation {
                     input: name_trimmed.to_string(),
                     expanded: entry.scientific_name.clone(),
@@ -575,7 +592,7 @@ impl TaxonomyValidator {
         let name_lower = name.to_lowercase();
         let mut best_match: Option<(&TaxonomyEntry, usize)> = None;
 
-        for entry in self.known_organisms.values() {
+        for entry in self.taxid_index.values() {
             let distance = levenshtein(&name_lower, &entry.scientific_name.to_lowercase());
             if distance <= 3 {
                 match &best_match {
@@ -707,6 +724,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_load_names_dmp() {
+        use std::io::Write;
+
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(
+            file,
+            "562\t|\tEscherichia coli\t|\t\t|\tscientific name\t|\n\
+             562\t|\tBacillus coli\t|\t\t|\tsynonym\t|\n\
+             1423\t|\tBacillus subtilis\t|\t\t|\tscientific name\t|\n\
+             1423\t|\thay bacillus\t|\t\t|\tgenbank common name\t|\n\
+             1423\t|\tVibrio subtilis\t|\t\t|\tsynonym\t|\n"
+        )
+        .unwrap();
+
+        let mut validator = TaxonomyValidator::new();
+        let loaded = validator.load_names_dmp(file.path()).unwrap();
+        assert_eq!(loaded, 2);
+
+        match validator.validate("Bacillus subtilis") {
+            TaxonomyValidationResult::Valid { taxid, .. } => assert_eq!(taxid, 1423),
+            other => panic!("Expected Valid, got {:?}", other),
+        }
+
+        let entry = validator.lookup("Vibrio subtilis").unwrap();
+        assert_eq!(entry.taxid, 1423);
+        assert_eq!(entry.scientific_name, "Bacillus subtilis");
+        assert_eq!(validator.lookup("hay bacillus").unwrap().taxid, 1423);
+        assert_eq!(validator.lookup("Bacillus coli").unwrap().taxid, 562);
+
+        // Built-in organisms are kept
+        assert_eq!(validator.lookup("human").unwrap().taxid, 9606);
+    }
+
     #[test]
     fn test_validate_abbreviation() {
         let validator = TaxonomyValidator::new();
@@ -3,26 +3,96 @@
 //! This module provides validators for biological metadata including
 //! MIxS compliance checking, taxonomy validation, and ontology term mapping.
 
-use crate::bio::accession::AccessionValidator;
-use crate::bio::mixs::{MixsPackage, MixsSchema};
+use crate::bio::accession::{AccessionStats, AccessionValidator};
+use crate::bio::mixs::{MixsFieldRequirement, MixsPackage, MixsSchema};
 use crate::bio::ontology::{OntologyType, OntologyValidator};
 use crate::bio::taxonomy::{TaxonomyValidationResult, TaxonomyValidator};
-use crate::input::DataTable;
+use crate::input::{ContextHints, DataTable};
 use crate::schema::TableSchema;
-use crate::validation::{Evidence, Observation, ObservationType, Severity};
+use crate::transform::{DateOrder, parse_date};
+use crate::validation::{Evidence, Observation, ObservationType, Severity, Validator};
 
+use chrono::{Datelike, NaiveDate, Utc};
 use serde_json::json;
 use std::collections::HashSet;
+use std::sync::Arc;
 
 /// Trait for bioinformatics validators.
+///
+/// This is the stable extension point for domain rules that don't belong
+/// in Crucible itself, such as an institution's specimen ID format.
+/// Implementations registered with [`Crucible::with_bio_validator`] run
+/// alongside the built-in validators during analysis, and their
+/// observations are reported in the same [`AnalysisResult`], with the
+/// same severity overrides and ordering.
+///
+/// Validators may run on several threads at once, hence the `Send + Sync`
+/// bound; `validate` only reads the table and schema.
+///
+/// ```
+/// use crucible::bio::BioValidator;
+/// use crucible::validation::{Observation, ObservationType, Severity};
+/// use crucible::{Crucible, DataTable, TableSchema};
+///
+/// struct SpecimenIdValidator;
+///
+/// impl BioValidator for SpecimenIdValidator {
+///     fn validate(&self, data: &DataTable, _schema: &TableSchema) -> Vec<Observation> {
+///         let Some(col) = data.column_index("specimen_id") else {
+///             return Vec::new();
+///         };
+///         data.column_values(col)
+///             .filter(|id| !id.starts_with("SP-"))
+///             .map(|id| {
+///                 Observation::new(
+///                     ObservationType::PatternViolation,
+///                     Severity::Error,
+///                     "specimen_id",
+///                     format!("'{}' is not an SP- specimen ID", id),
+///                 )
+///             })
+///             .collect()
+///     }
+///
+///     fn name(&self) -> &'static str {
+///         "specimen_id_validator"
+///     }
+/// }
+///
+/// let crucible = Crucible::new().with_bio_validator(Box::new(SpecimenIdValidator));
+/// ```
+///
+/// [`Crucible::with_bio_validator`]: crate::Crucible::with_bio_validator
+/// [`AnalysisResult`]: crate::AnalysisResult
 pub trait BioValidator: Send + Sync {
     /// Validate the data and return observations.
     fn validate(&self, data: &DataTable, schema: &TableSchema) -> Vec<Observation>;
 
     /// Get the validator name.
+    ///
+    /// Used as the `detector` of observations that don't set their own.
     fn name(&self) -> &'static str;
 }
 
+/// Runs a [`BioValidator`] as one of the validation engine's validators.
+pub(crate) struct BioValidatorAdapter(pub(crate) Arc<dyn BioValidator>);
+
+impl Validator for BioValidatorAdapter {
+    fn name(&self) -> &'static str {
+        self.0.name()
+    }
+
+    fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
+        let mut observations = self.0.validate(table, schema);
+        for obs in &mut observations {
+            if obs.detector.is_empty() {
+                obs.detector = self.0.name().to_string();
+            }
+        }
+        observations
+    }
+}
+
 /// Validates metadata against MIxS (Minimum Information about any (x) Sequence) standards.
 pub struct MixsComplianceValidator {
     /// The MIxS schema.
@@ -35,6 +105,8 @@ pub struct MixsComplianceValidator {
     ontology_validator: OntologyValidator,
     /// Accession validator for database identifiers.
     accession_validator: AccessionValidator,
+    /// Report missing recommended fields, and warnings as errors.
+    blocking: bool,
 }
 
 impl MixsComplianceValidator {
@@ -46,9 +118,19 @@ impl MixsComplianceValidator {
             taxonomy_validator: TaxonomyValidator::new(),
             ontology_validator: OntologyValidator::new(),
             accession_validator: AccessionValidator::new(),
+            blocking: false,
         }
     }
 
+    /// Treat every MIxS finding as blocking submission.
+    ///
+    /// Missing recommended fields are reported too, and warnings (such as
+    /// malformed values) are reported as errors. Used by the strict profile.
+    pub fn with_blocking(mut self, blocking: bool) -> Self {
+        self.blocking = blocking;
+        self
+    }
+
     /// Set the MIxS package to validate against.
     pub fn with_package(mut self, package: MixsPackage) -> Self {
         self.package = Some(package);
@@ -106,6 +188,24 @@ impl MixsComplianceValidator {
             return Some(MixsPackage::HumanAssociated);
         }
 
+        // Check for built environment indicators
+        const BUILT_ENVIRONMENT_COLUMNS: &[&str] = &[
+            "surf_material",
+            "surface_material",
+            "build_occup_type",
+            "building_setting",
+            "ventilation_type",
+            "indoor_surf",
+            "room",
+            "room_type",
+        ];
+        if BUILT_ENVIRONMENT_COLUMNS
+            .iter()
+            .any(|c| column_names.contains(*c))
+        {
+            return Some(MixsPackage::BuiltEnvironment);
+        }
+
         // Check for environmental indicators
         if column_names.contains("depth") && column_names.contains("salinity") {
             return Some(MixsPackage::Water);
@@ -241,21 +341,32 @@ impl BioValidator for MixsComplianceValidator {
             }
         };
 
-        // Get fields for this package
-        let mandatory_fields = self.schema.mandatory_fields_for_package(package);
+        // Get fields for this package; blocking mode also requires recommended ones
+        let required_fields: Vec<_> = self
+            .schema
+            .fields_for_package(package)
+            .into_iter()
+            .filter(|f| match f.requirement {
+                MixsFieldRequirement::Mandatory => true,
+                MixsFieldRequirement::Recommended => self.blocking,
+                _ => false,
+            })
+            .collect();
 
-        // Check for missing mandatory fields
-        for field in &mandatory_fields {
+        // Check for missing required fields
+        for field in &required_fields {
             let column_exists = schema.columns.iter().any(|c| field.matches_column(&c.name));
 
             if !column_exists {
+                let requirement = field.requirement.label().to_lowercase();
                 observations.push(
                     Observation::new(
                         ObservationType::Completeness,
                         Severity::Error,
                         &field.name,
                         format!(
-                            "Missing mandatory MIxS field '{}' ({}) for {} package",
+                            "Missing {} MIxS field '{}' ({}) for {} package",
+                            requirement,
                             field.name,
                             field.label,
                             package.name()
@@ -265,7 +376,7 @@ impl BioValidator for MixsComplianceValidator {
                         Evidence::new()
                             .with_expected(json!({
                                 "field": field.name,
-                                "requirement": "mandatory",
+                                "requirement": requirement,
                                 "package": package.name(),
                                 "description": field.description,
                                 "format": field.format,
@@ -650,7 +761,7 @@ impl BioValidator for MixsComplianceValidator {
             // Check for accession columns
             if let Some(expected_type) = self.accession_validator.detect_accession_column(&col.name) {
                 let mut invalid_accessions: Vec<(usize, String, String)> = Vec::new();
-                let mut _valid_count = 0;
+                let mut stats = AccessionStats::default();
 
                 for (row_idx, row) in data.rows.iter().enumerate() {
                     if let Some(value) = row.get(col_idx) {
@@ -663,8 +774,17 @@ impl BioValidator for MixsComplianceValidator {
                         }
 
                         let result = self.accession_validator.validate(value);
-                        if result.is_valid {
-                            _valid_count += 1;
+                        stats.record(&result);
+                        if result.is_valid && !result.is_canonical {
+                            invalid_accessions.push((
+                                row_idx,
+                                value.clone(),
+                                format!(
+                                    "Not in canonical form. Expected: {}",
+                                    result.normalized.as_deref().unwrap_or_default()
+                                ),
+                            ));
+                        } else if result.is_valid {
                             // Check if type matches expected
                             if let Some(actual_type) = result.accession_type {
                                 if actual_type != expected_type {
@@ -708,7 +828,9 @@ impl BioValidator for MixsComplianceValidator {
                                 .with_value(json!({
                                     "example": sample.1,
                                     "error": sample.2,
+                                    "summary": stats.summary(),
                                 }))
+                                .with_expected(expected_type.format_description())
                                 .with_occurrences(invalid_accessions.len())
                                 .with_sample_rows(
                                     invalid_accessions.iter().take(5).map(|t| t.0).collect(),
@@ -721,6 +843,12 @@ impl BioValidator for MixsComplianceValidator {
             }
         }
 
+        if self.blocking {
+            for obs in observations.iter_mut().filter(|o| o.severity == Severity::Warning) {
+                obs.severity = Severity::Error;
+            }
+        }
+
         observations
     }
 
@@ -729,6 +857,229 @@ impl BioValidator for MixsComplianceValidator {
     }
 }
 
+/// Checks that `collection_date` values are plausible, not just well-formed.
+///
+/// Complements the MIxS format check: flags dates in the future, dates before
+/// a plausible epoch, and dates outside the study window when one is known.
+/// Dates are read as ISO 8601, and in other formats too once a day/month
+/// order is known (e.g. from the locale). Values that can't be parsed are
+/// left to the format check.
+pub struct CollectionDateValidator {
+    schema: MixsSchema,
+    /// Earliest plausible collection date.
+    earliest: NaiveDate,
+    /// Study window, if known.
+    study_start: Option<NaiveDate>,
+    study_end: Option<NaiveDate>,
+    /// Reference date for the future check (defaults to today).
+    today: NaiveDate,
+    /// Order for reading non-ISO numeric dates, if known.
+    date_order: Option<DateOrder>,
+}
+
+impl CollectionDateValidator {
+    /// Create a validator with a 1900-01-01 epoch and no study window.
+    pub fn new() -> Self {
+        Self {
+            schema: MixsSchema::new(),
+            earliest: NaiveDate::from_ymd_opt(1900, 1, 1).unwrap(),
+            study_start: None,
+            study_end: None,
+            today: Utc::now().date_naive(),
+            date_order: None,
+        }
+    }
+
+    /// Set the earliest plausible collection date.
+    pub fn with_earliest(mut self, earliest: NaiveDate) -> Self {
+        self.earliest = earliest;
+        self
+    }
+
+    /// Set the study window that collection dates should fall within.
+    pub fn with_study_window(mut self, start: NaiveDate, end: NaiveDate) -> Self {
+        self.study_start = Some(start);
+        self.study_end = Some(end);
+        self
+    }
+
+    /// Also read dates like `03/04/2024` or `Mar 4 2024`, with numeric ones
+    /// in this day/month order.
+    pub fn with_date_order(mut self, date_order: DateOrder) -> Self {
+        self.date_order = Some(date_order);
+        self
+    }
+
+    /// Take the study window and, when a locale is set, the day/month order
+    /// from context hints.
+    pub fn with_context(mut self, context: &ContextHints) -> Self {
+        self.study_start = context.study_start.or(self.study_start);
+        self.study_end = context.study_end.or(self.study_end);
+        if context.locale.is_some() {
+            self.date_order = Some(context.date_order());
+        }
+        self
+    }
+
+    /// Set the reference date used for the future check.
+    pub fn with_today(mut self, today: NaiveDate) -> Self {
+        self.today = today;
+        self
+    }
+
+    /// Parse a date into the range of days it covers: an ISO 8601 date (full,
+    /// year-month, or year), or a full date in another format when the
+    /// day/month order is known.
+    fn parse_date_range(&self, value: &str) -> Option<(NaiveDate, NaiveDate)> {
+        Self::parse_iso_range(value).or_else(|| {
+            let date = parse_date(value, self.date_order?)?;
+            let date = NaiveDate::parse_from_str(&date.iso, "%Y-%m-%d").ok()?;
+            Some((date, date))
+        })
+    }
+
+    /// Parse an ISO 8601 date (full, year-month, or year) into the range of
+    /// days it covers.
+    fn parse_iso_range(value: &str) -> Option<(NaiveDate, NaiveDate)> {
+        let value = value.trim();
+        // Drop any time component (e.g. "2024-01-15T10:30:00Z")
+        let date_part = value.split(['T', ' ']).next().unwrap_or(value);
+        let parts: Vec<&str> = date_part.split('-').collect();
+
+        match parts.as_slice() {
+            [y, m, d] if y.len() == 4 => {
+                let date = NaiveDate::from_ymd_opt(y.parse().ok()?, m.parse().ok()?, d.parse().ok()?)?;
+                Some((date, date))
+            }
+            [y, m] if y.len() == 4 => {
+                let start = NaiveDate::from_ymd_opt(y.parse().ok()?, m.parse().ok()?, 1)?;
+                let next = if start.month() == 12 {
+                    NaiveDate::from_ymd_opt(start.year() + 1, 1, 1)?
+                } else {
+                    NaiveDate::from_ymd_opt(start.year(), start.month() + 1, 1)?
+                };
+                Some((start, next.pred_opt()?))
+            }
+            [y] if y.len() == 4 => {
+                let year = y.parse().ok()?;
+                Some((
+                    NaiveDate::from_ymd_opt(year, 1, 1)?,
+                    NaiveDate::from_ymd_opt(year, 12, 31)?,
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    fn date_observation(
+        &self,
+        column: &str,
+        severity: Severity,
+        description: String,
+        rows: &[(usize, String)],
+        expected: serde_json::Value,
+    ) -> Observation {
+        Observation::new(ObservationType::ConstraintViolation, severity, column, description)
+            .with_evidence(
+                Evidence::new()
+                    .with_value(rows[0].1.clone())
+                    .with_occurrences(rows.len())
+                    .with_sample_rows(rows.iter().take(5).map(|(r, _)| *r).collect())
+                    .with_expected(expected),
+            )
+            .with_confidence(0.9)
+            .with_detector("CollectionDateValidator")
+    }
+}
+
+impl Default for CollectionDateValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BioValidator for CollectionDateValidator {
+    fn validate(&self, data: &DataTable, schema: &TableSchema) -> Vec<Observation> {
+        let mut observations = Vec::new();
+
+        for col in &schema.columns {
+            let is_collection_date = self
+                .schema
+                .find_field(&col.name, None)
+                .is_some_and(|f| f.name == "collection_date");
+            if !is_collection_date {
+                continue;
+            }
+
+            let mut future = Vec::new();
+            let mut too_early = Vec::new();
+            let mut outside_study = Vec::new();
+
+            for (row_idx, value) in data.column_values(col.position).enumerate() {
+                let Some((first_day, last_day)) = self.parse_date_range(value) else {
+                    continue;
+                };
+
+                if first_day > self.today {
+                    future.push((row_idx, value.to_string()));
+                } else if last_day < self.earliest {
+                    too_early.push((row_idx, value.to_string()));
+                } else if self.study_start.is_some_and(|start| last_day < start)
+                    || self.study_end.is_some_and(|end| first_day > end)
+                {
+                    outside_study.push((row_idx, value.to_string()));
+                }
+            }
+
+            if !future.is_empty() {
+                observations.push(self.date_observation(
+                    &col.name,
+                    Severity::Error,
+                    format!("{} collection dates are in the future", future.len()),
+                    &future,
+                    json!({ "max": self.today.to_string() }),
+                ));
+            }
+
+            if !too_early.is_empty() {
+                observations.push(self.date_observation(
+                    &col.name,
+                    Severity::Warning,
+                    format!(
+                        "{} collection dates are before {}, which is implausible",
+                        too_early.len(),
+                        self.earliest
+                    ),
+                    &too_early,
+                    json!({ "min": self.earliest.to_string() }),
+                ));
+            }
+
+            if !outside_study.is_empty() {
+                observations.push(self.date_observation(
+                    &col.name,
+                    Severity::Warning,
+                    format!(
+                        "{} collection dates fall outside the study window",
+                        outside_study.len()
+                    ),
+                    &outside_study,
+                    json!({
+                        "min": self.study_start.map(|d| d.to_string()),
+                        "max": self.study_end.map(|d| d.to_string()),
+                    }),
+                ));
+            }
+        }
+
+        observations
+    }
+
+    fn name(&self) -> &'static str {
+        "CollectionDateValidator"
+    }
+}
+
 /// Ontology column classification for validation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum OntologyColumnType {
@@ -866,6 +1217,7 @@ fn is_taxonomy_column(col_name: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input::Locale;
     use crate::schema::ColumnSchema;
 
     fn create_test_data() -> (DataTable, TableSchema) {
@@ -891,6 +1243,8 @@ mod tests {
                 ],
             ],
             delimiter: b'\t',
+            total_rows: None,
+            row_lines: Vec::new(),
         };
 
         let mut schema = TableSchema::new();
@@ -926,6 +1280,44 @@ mod tests {
         assert!(has_env_field);
     }
 
+    #[test]
+    fn test_mixs_validator_blocking() {
+        let (data, schema) = create_test_data();
+        let validator = MixsComplianceValidator::new().with_package(MixsPackage::HumanGut);
+        let missing_recommended = |observations: &[Observation]| {
+            observations
+                .iter()
+                .any(|o| o.description.starts_with("Missing recommended MIxS field 'seq_meth'"))
+        };
+
+        let observations = validator.validate(&data, &schema);
+        assert!(!missing_recommended(&observations));
+        assert!(observations.iter().any(|o| o.severity == Severity::Warning));
+
+        let observations = validator.with_blocking(true).validate(&data, &schema);
+        assert!(missing_recommended(&observations));
+        assert!(observations.iter().all(|o| o.severity != Severity::Warning));
+    }
+
+    #[test]
+    fn test_detect_built_environment_package() {
+        let (data, mut schema) = create_test_data();
+        let validator = MixsComplianceValidator::new();
+        assert_eq!(validator.detect_package(&data, &schema), None);
+
+        schema.columns.push(ColumnSchema::new("surf_material", 4));
+        assert_eq!(
+            validator.detect_package(&data, &schema),
+            Some(MixsPackage::BuiltEnvironment)
+        );
+
+        // Missing mandatory built environment fields are reported
+        let observations = validator.validate(&data, &schema);
+        assert!(observations
+            .iter()
+            .any(|o| o.description.contains("build_occup_type")));
+    }
+
     #[test]
     fn test_mixs_validator_detects_taxonomy_issues() {
         let (data, schema) = create_test_data();
@@ -966,6 +1358,60 @@ mod tests {
         assert!(!validator.validate_date_format("15/01/2024"));
     }
 
+    #[test]
+    fn test_collection_date_plausibility() {
+        let data = DataTable::new(
+            vec!["sample_id".to_string(), "collection_date".to_string()],
+            vec![
+                vec!["S001".to_string(), "2024-01-15".to_string()],
+                vec!["S002".to_string(), "2031-06".to_string()],
+                vec!["S003".to_string(), "1850".to_string()],
+                vec!["S004".to_string(), "2019-03-02".to_string()],
+                vec!["S005".to_string(), "Jan 15, 2024".to_string()],
+            ],
+            b'\t',
+        );
+        let mut schema = TableSchema::new();
+        schema.columns = vec![
+            ColumnSchema::new("sample_id", 0),
+            ColumnSchema::new("collection_date", 1),
+        ];
+
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let validator = CollectionDateValidator::new()
+            .with_today(date(2025, 1, 1))
+            .with_context(
+                &ContextHints::new().with_study_period(date(2023, 1, 1), date(2024, 12, 31)),
+            );
+
+        let observations = validator.validate(&data, &schema);
+        assert_eq!(observations.len(), 3);
+
+        let future = &observations[0];
+        assert_eq!(future.severity, Severity::Error);
+        assert_eq!(future.evidence.sample_rows, vec![1]);
+        assert_eq!(observations[1].evidence.sample_rows, vec![2]);
+        assert_eq!(observations[2].evidence.sample_rows, vec![3]);
+        assert!(observations.iter().all(|o| o.observation_type == ObservationType::ConstraintViolation));
+
+        // With a locale, dates in its format are checked too
+        let data = DataTable::new(
+            vec!["sample_id".to_string(), "collection_date".to_string()],
+            vec![
+                vec!["S001".to_string(), "15.06.2024".to_string()],
+                vec!["S002".to_string(), "03.04.2026".to_string()],
+            ],
+            b'\t',
+        );
+        let validator = CollectionDateValidator::new()
+            .with_today(date(2025, 1, 1))
+            .with_context(&ContextHints::new().with_locale(Locale::EuDe));
+        let observations = validator.validate(&data, &schema);
+        assert_eq!(observations.len(), 1);
+        assert_eq!(observations[0].evidence.sample_rows, vec![1]);
+        assert!(CollectionDateValidator::new().validate(&data, &schema).is_empty());
+    }
+
     #[test]
     fn test_compliance_score() {
         let (data, schema) = create_test_data();
@@ -1090,6 +1536,8 @@ mod tests {
                 ],
             ],
             delimiter: b'\t',
+            total_rows: None,
+            row_lines: Vec::new(),
         };
 
         let mut schema = TableSchema::new();
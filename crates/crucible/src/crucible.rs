@@ -1,17 +1,25 @@
 //! Main Crucible struct and public API.
 
-use std::path::Path;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 
+use crate::bio::{BioValidator, BioValidatorAdapter};
+use crate::curation::{CarryForwardReport, CurationLayer};
 use crate::error::Result;
-use crate::inference::{FusionConfig, InferenceFusion};
-use crate::input::{ContextHints, Parser, ParserConfig, SourceMetadata};
-use crate::llm::LlmProvider;
+use crate::inference::{ConstraintReport, FusionConfig, InferenceFusion, Question};
+use crate::input::{ContextHints, DataTable, Locale, Parser, ParserConfig, SourceMetadata};
+use crate::llm::{CachingProvider, InsightCache, LlmProvider, LlmUsage, observation_cost, question_cost};
+use crate::progress::{ProgressCallback, ProgressEvent};
 use crate::schema::TableSchema;
 use crate::suggestion::{Suggestion, SuggestionEngine};
-use crate::validation::{Observation, ValidationEngine};
+use crate::validation::{
+    CohortMember, CohortReport, ConfidenceHistogram, ContractValidator, CrossColumnValidator, Observation,
+    Parallelism, Profile, ValidationEngine, Validator, header_rename_observations, unmatched_vocabulary_observations,
+};
 
 /// Configuration for Crucible analysis.
 #[derive(Debug, Clone)]
@@ -24,6 +32,21 @@ pub struct CrucibleConfig {
     pub max_rows: Option<usize>,
     /// Context hints for LLM enhancement.
     pub context: ContextHints,
+    /// Treat warnings as errors when scoring (stored severities are unchanged).
+    pub strict: bool,
+    /// Threads to run validators on; `Sequential` for single-threaded runs.
+    pub parallelism: Parallelism,
+    /// Merge duplicate observations (see [`Crucible::with_merged_duplicates`]).
+    pub merge_duplicates: bool,
+    /// Strictness preset for validator thresholds and checks.
+    pub profile: Profile,
+    /// Let the LLM, when configured, answer questions about ambiguous roles.
+    pub auto_answer: bool,
+    /// Columns to analyze (empty = all).
+    pub columns: Vec<String>,
+    /// Group values with the same meaning by LLM embeddings, when the
+    /// provider supports them.
+    pub semantic_embeddings: bool,
 }
 
 impl Default for CrucibleConfig {
@@ -33,10 +56,28 @@ impl Default for CrucibleConfig {
             fusion: FusionConfig::default(),
             max_rows: None,
             context: ContextHints::default(),
+            strict: false,
+            parallelism: Parallelism::default(),
+            merge_duplicates: false,
+            profile: Profile::default(),
+            auto_answer: true,
+            columns: Vec::new(),
+            semantic_embeddings: false,
         }
     }
 }
 
+impl CrucibleConfig {
+    /// Use a strictness preset for validator thresholds and checks.
+    ///
+    /// See [`Profile`] for what each preset changes. `Strict` also scores
+    /// warnings as errors.
+    pub fn with_profile(mut self, profile: Profile) -> Self {
+        self.profile = profile;
+        self
+    }
+}
+
 /// Result of analyzing a data file.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalysisResult {
@@ -48,10 +89,76 @@ pub struct AnalysisResult {
     pub observations: Vec<Observation>,
     /// Suggested fixes for observations (LLM-generated if enabled).
     pub suggestions: Vec<Suggestion>,
+    /// Violation rate of each inferred constraint against the current data.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub constraint_report: Vec<ConstraintReport>,
+    /// How many observations were sent to the LLM under its token budget.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llm_usage: Option<LlmUsage>,
+    /// Strictness profile the findings were generated under.
+    #[serde(default)]
+    pub profile: Profile,
+    /// Locale numbers and dates were read in.
+    #[serde(default)]
+    pub locale: Locale,
+    /// Questions about near-tied column roles, answered or still open.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub questions: Vec<Question>,
     /// Summary statistics.
     pub summary: AnalysisSummary,
 }
 
+impl AnalysisResult {
+    /// Questions nobody has answered yet.
+    pub fn open_questions(&self) -> impl Iterator<Item = &Question> {
+        self.questions.iter().filter(|q| q.answer.is_none())
+    }
+
+    /// Inferred constraints that some current values already violate.
+    pub fn violated_constraints(&self) -> impl Iterator<Item = &ConstraintReport> {
+        self.constraint_report.iter().filter(|r| r.is_violated())
+    }
+
+    /// Stable fingerprint of the analyzed dataset.
+    ///
+    /// See [`SourceMetadata::fingerprint`] for what is included. Comparing
+    /// fingerprints across deliveries shows whether anything changed.
+    pub fn fingerprint(&self) -> String {
+        self.source.fingerprint(&self.schema.column_names())
+    }
+
+    /// Observation confidences bucketed into deciles, overall and per type.
+    pub fn confidence_histogram(&self) -> ConfidenceHistogram {
+        ConfidenceHistogram::from_observations(&self.observations)
+    }
+}
+
+/// Result of validating rows appended to a previously analyzed dataset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppendValidation {
+    /// Number of new rows validated.
+    pub rows_checked: usize,
+    /// Observations for the new rows (row indices are relative to the new rows).
+    pub observations: Vec<Observation>,
+    /// Schema columns missing from the new rows.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing_columns: Vec<String>,
+    /// Columns in the new rows that aren't in the schema.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unexpected_columns: Vec<String>,
+    /// Checks that need the full table and were not run.
+    pub skipped_checks: Vec<String>,
+}
+
+/// Checks that can't be performed on appended rows alone.
+const NON_INCREMENTAL_CHECKS: &[&str] = &[
+    "uniqueness (requires the full table)",
+    "duplicate identifiers (requires the full table)",
+    "duplicate rows (requires the full table)",
+    "statistical outliers (requires full-table statistics)",
+    "case variants, typos, and semantic equivalents (require the full value distribution)",
+];
+
 /// Summary of the analysis results.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalysisSummary {
@@ -71,6 +178,51 @@ pub struct AnalysisSummary {
     pub recommendation: String,
 }
 
+impl AnalysisSummary {
+    /// Combine the summaries of several tables.
+    ///
+    /// Counts are summed, the quality score is averaged weighted by column
+    /// count, and the recommendation of the lowest-scoring table is kept.
+    pub fn combine<'a>(summaries: impl IntoIterator<Item = &'a AnalysisSummary>) -> Self {
+        let mut combined = AnalysisSummary {
+            total_columns: 0,
+            columns_with_issues: 0,
+            total_observations: 0,
+            observations_by_severity: ObservationCounts::default(),
+            observations_by_type: std::collections::HashMap::new(),
+            data_quality_score: 1.0,
+            recommendation: String::new(),
+        };
+        let mut weighted_score = 0.0;
+        let mut lowest_score = f64::INFINITY;
+
+        for summary in summaries {
+            combined.total_columns += summary.total_columns;
+            combined.columns_with_issues += summary.columns_with_issues;
+            combined.total_observations += summary.total_observations;
+            combined.observations_by_severity.error += summary.observations_by_severity.error;
+            combined.observations_by_severity.warning += summary.observations_by_severity.warning;
+            combined.observations_by_severity.info += summary.observations_by_severity.info;
+            for (kind, count) in &summary.observations_by_type {
+                *combined
+                    .observations_by_type
+                    .entry(kind.clone())
+                    .or_insert(0) += count;
+            }
+            weighted_score += summary.data_quality_score * summary.total_columns as f64;
+            if summary.data_quality_score < lowest_score {
+                lowest_score = summary.data_quality_score;
+                combined.recommendation = summary.recommendation.clone();
+            }
+        }
+
+        if combined.total_columns > 0 {
+            combined.data_quality_score = weighted_score / combined.total_columns as f64;
+        }
+        combined
+    }
+}
+
 /// Counts of observations by severity.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ObservationCounts {
@@ -79,6 +231,17 @@ pub struct ObservationCounts {
     pub info: usize,
 }
 
+impl ObservationCounts {
+    /// Counts with every warning escalated to an error (strict mode).
+    pub fn escalated(&self) -> Self {
+        Self {
+            error: self.error + self.warning,
+            warning: 0,
+            info: self.info,
+        }
+    }
+}
+
 /// The main Crucible analysis engine.
 pub struct Crucible {
     config: CrucibleConfig,
@@ -86,6 +249,11 @@ pub struct Crucible {
     inference: InferenceFusion,
     validation: ValidationEngine,
     llm_provider: Option<Arc<dyn LlmProvider>>,
+    insight_cache: Option<PathBuf>,
+    llm_cache: Option<PathBuf>,
+    llm_cache_ttl: Option<Duration>,
+    progress: Option<ProgressCallback>,
+    bio_validators: Vec<Arc<dyn BioValidator>>,
 }
 
 impl Crucible {
@@ -96,9 +264,13 @@ impl Crucible {
 
     /// Create a Crucible instance with custom configuration.
     pub fn with_config(config: CrucibleConfig) -> Self {
-        let parser = Parser::with_config(config.parser.clone());
-        let inference = InferenceFusion::with_config(config.fusion.clone());
-        let validation = ValidationEngine::new();
+        let parser_config = match config.max_rows {
+            Some(rows) => config.parser.clone().with_sample(rows),
+            None => config.parser.clone(),
+        };
+        let parser = Parser::with_config(parser_config);
+        let inference = Self::inference_fusion(&config);
+        let validation = Self::validation_engine(&config, &[], None);
 
         Self {
             config,
@@ -106,6 +278,11 @@ impl Crucible {
             inference,
             validation,
             llm_provider: None,
+            insight_cache: None,
+            llm_cache: None,
+            llm_cache_ttl: None,
+            progress: None,
+            bio_validators: Vec::new(),
         }
     }
 
@@ -120,36 +297,417 @@ impl Crucible {
         self
     }
 
+    /// Use an LLM provider that is shared with other Crucible instances.
+    ///
+    /// Useful when analyzing many files concurrently against one API client.
+    pub fn with_shared_llm(mut self, provider: Arc<dyn LlmProvider>) -> Self {
+        self.llm_provider = Some(provider);
+        self
+    }
+
     /// Set context hints for LLM enhancement.
     ///
     /// Context hints help the LLM provide more relevant insights
     /// by understanding the domain and purpose of the data.
+    ///
+    /// Missing-value codes in the context are also used by validation, and
+    /// pinned column types by inference.
     pub fn with_context(mut self, context: ContextHints) -> Self {
         self.config.context = context;
+        self.inference = Self::inference_fusion(&self.config);
+        self.validation = Self::validation_engine(&self.config, &self.bio_validators, self.progress.as_ref());
+        self
+    }
+
+    /// Set how many threads validators run on.
+    ///
+    /// Results are identical in every mode; use [`Parallelism::Sequential`]
+    /// to keep analysis on the calling thread.
+    pub fn with_parallelism(mut self, parallelism: Parallelism) -> Self {
+        self.config.parallelism = parallelism;
+        self.validation = Self::validation_engine(&self.config, &self.bio_validators, self.progress.as_ref());
         self
     }
 
+    /// Collapse observations of the same issue on a column into one.
+    ///
+    /// When several validators flag the same values (case variants, typos,
+    /// semantic equivalents), they are reported once with a combined
+    /// confidence; the individual detectors are kept in the evidence. See
+    /// [`ValidationEngine::merge_duplicate_observations`].
+    pub fn with_merged_duplicates(mut self, merge: bool) -> Self {
+        self.config.merge_duplicates = merge;
+        self.validation = Self::validation_engine(&self.config, &self.bio_validators, self.progress.as_ref());
+        self
+    }
+
+    /// Build the inference engine for a configuration.
+    fn inference_fusion(config: &CrucibleConfig) -> InferenceFusion {
+        InferenceFusion::with_config(config.fusion.clone())
+            .with_column_types(config.context.column_types.clone())
+            .with_column_roles(config.context.column_roles.clone())
+            .with_decimal_mark(config.context.decimal_mark())
+    }
+
+    /// Build the validation engine for a configuration, with custom
+    /// validators after the built-in ones.
+    fn validation_engine(
+        config: &CrucibleConfig,
+        bio_validators: &[Arc<dyn BioValidator>],
+        progress: Option<&ProgressCallback>,
+    ) -> ValidationEngine {
+        let mut engine = ValidationEngine::with_profile(&config.context, config.profile)
+            .with_parallelism(config.parallelism)
+            .with_merged_duplicates(config.merge_duplicates);
+        for validator in bio_validators {
+            engine = engine.with_validator(Box::new(BioValidatorAdapter(Arc::clone(validator))));
+        }
+        match progress {
+            Some(progress) => engine.with_progress(Arc::clone(progress)),
+            None => engine,
+        }
+    }
+
+    /// Use a strictness preset for validator thresholds and checks.
+    ///
+    /// See [`CrucibleConfig::with_profile`].
+    pub fn with_profile(mut self, profile: Profile) -> Self {
+        self.config.profile = profile;
+        self.validation = Self::validation_engine(&self.config, &self.bio_validators, self.progress.as_ref());
+        self
+    }
+
+    /// Let a configured LLM answer questions about near-tied column roles.
+    ///
+    /// On by default; turn it off when a person will answer them instead.
+    /// Answered questions are kept in [`AnalysisResult::questions`].
+    pub fn with_auto_answer(mut self, auto_answer: bool) -> Self {
+        self.config.auto_answer = auto_answer;
+        self
+    }
+
+    /// Enable strict mode, treating every warning as an error.
+    ///
+    /// Only the quality score and recommendation are affected; observation
+    /// severities in the result are left as detected.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.config.strict = strict;
+        self
+    }
+
+    /// Analyze only the first `rows` rows of each file.
+    ///
+    /// The true row count is still recorded, and checks that need the whole
+    /// table (uniqueness, duplicates) are reported as skipped.
+    pub fn with_sample(mut self, rows: usize) -> Self {
+        self.config.max_rows = Some(rows);
+        self.parser = Parser::with_config(self.config.parser.clone().with_sample(rows));
+        self
+    }
+
+    /// Analyze only the named columns.
+    ///
+    /// The other columns are dropped after parsing, except those a
+    /// cross-column check relates to a selected one (an end date for a
+    /// selected start date), so inference and validation see only these.
+    /// A schema contract is checked for the kept columns only. Names are
+    /// matched ignoring case; those matching no column are listed in
+    /// [`SourceMetadata::missing_columns`].
+    pub fn with_columns<S: Into<String>>(mut self, columns: impl IntoIterator<Item = S>) -> Self {
+        self.config.columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Find values with the same meaning by embedding them with the LLM
+    /// provider, besides the built-in synonym table.
+    ///
+    /// Needs a provider that supports embeddings (Ollama, OpenAI); without
+    /// one only the synonym table is used. Embeddings go through the LLM
+    /// cache when one is set. See [`SemanticEquivalenceValidator::with_embeddings`](crate::validation::SemanticEquivalenceValidator::with_embeddings).
+    pub fn with_semantic_embeddings(mut self, enabled: bool) -> Self {
+        self.config.semantic_embeddings = enabled;
+        self
+    }
+
+    /// Reuse column insights from a cache file instead of re-calling the LLM.
+    ///
+    /// Insights are keyed by column name and a hash of the column's values,
+    /// so only new or changed columns are sent to the LLM. The cache file is
+    /// created if missing and updated after each analysis.
+    pub fn with_insight_cache(mut self, path: impl Into<PathBuf>) -> Self {
+        self.insight_cache = Some(path.into());
+        self
+    }
+
+    /// Cache LLM responses in a JSON file instead of re-sending identical prompts.
+    ///
+    /// Responses are keyed by a hash of the prompt, model, and temperature.
+    /// New responses are kept in memory during a run and written to the
+    /// cache file, creating it if missing, once at the end of each analysis.
+    pub fn with_llm_cache(mut self, path: impl Into<PathBuf>) -> Self {
+        self.llm_cache = Some(path.into());
+        self
+    }
+
+    /// Re-request cached LLM responses older than `ttl`.
+    pub fn with_llm_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.llm_cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Report progress through analysis to a callback.
+    ///
+    /// The callback is told when parsing, inference, and each validator
+    /// start, and after each LLM request; see [`ProgressEvent`]. Validators
+    /// running in parallel call it from their own threads.
+    pub fn with_progress(mut self, callback: impl Fn(ProgressEvent) + Send + Sync + 'static) -> Self {
+        self.progress = Some(Arc::new(callback));
+        self.validation = Self::validation_engine(&self.config, &self.bio_validators, self.progress.as_ref());
+        self
+    }
+
+    /// Run a custom bio validator alongside the built-in validators.
+    ///
+    /// Its observations are part of the analysis result like any other
+    /// validator's; see [`BioValidator`] for an example. Like the built-in
+    /// validators, it may run on its own thread (see
+    /// [`Crucible::with_parallelism`]).
+    pub fn with_bio_validator(mut self, validator: Box<dyn BioValidator>) -> Self {
+        self.bio_validators.push(Arc::from(validator));
+        self.validation =
+            Self::validation_engine(&self.config, &self.bio_validators, self.progress.as_ref());
+        self
+    }
+
+    /// Send a progress event to the callback, if one is set.
+    fn report(&self, event: ProgressEvent) {
+        if let Some(ref progress) = self.progress {
+            progress(event);
+        }
+    }
+
     /// Analyze a data file and produce observations.
     pub fn analyze(&self, path: impl AsRef<Path>) -> Result<AnalysisResult> {
+        self.run_analysis(path.as_ref(), None)
+    }
+
+    /// Analyze every worksheet of an Excel workbook, keyed by sheet name.
+    ///
+    /// Pass the results to [`CurationLayer::from_analyses`] for one curation
+    /// layer covering the whole workbook. Reading workbooks needs the `xlsx`
+    /// feature.
+    pub fn analyze_workbook(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<indexmap::IndexMap<String, AnalysisResult>> {
         let path = path.as_ref();
+        let mut results = indexmap::IndexMap::new();
+        for name in self.parser.sheet_names(path)? {
+            self.report(ProgressEvent::Parsing);
+            let (table, source) = self.parser.parse_sheet(path, &name)?;
+            results.insert(name, self.analyze_parsed(table, source, None)?);
+        }
+        Ok(results)
+    }
+
+    /// Analyze a data file and validate it against a frozen schema contract.
+    ///
+    /// In addition to the usual data-quality observations, every deviation
+    /// from the contract (missing or extra columns, changed types or roles,
+    /// violated constraints) is reported as a contract violation.
+    pub fn analyze_against(
+        &self,
+        path: impl AsRef<Path>,
+        contract: &TableSchema,
+    ) -> Result<AnalysisResult> {
+        self.run_analysis(path.as_ref(), Some(contract))
+    }
+
+    /// Analyze data from any reader, such as stdin or an in-memory buffer.
+    ///
+    /// `source` names the input, since there's no path to take it from; see
+    /// [`SourceMetadata::stream`]. Its file name decides the format as a
+    /// path's extension would, and a delimiter set with
+    /// [`SourceMetadata::with_delimiter`] is used instead of detection. The
+    /// result's source has an empty path, so a curation file for it needs an
+    /// explicit name rather than [`curation_path`](crate::curation::curation_path).
+    pub fn analyze_reader(&self, reader: impl Read, source: SourceMetadata) -> Result<AnalysisResult> {
+        self.report(ProgressEvent::Parsing);
+        let (table, source) = self.parser.parse_reader(reader, source)?;
+        self.analyze_parsed(table, source, None)
+    }
+
+    /// Analyze data from a reader and validate it against a frozen schema
+    /// contract, as [`analyze_against`](Self::analyze_against) does for files.
+    pub fn analyze_reader_against(
+        &self,
+        reader: impl Read,
+        source: SourceMetadata,
+        contract: &TableSchema,
+    ) -> Result<AnalysisResult> {
+        self.report(ProgressEvent::Parsing);
+        let (table, source) = self.parser.parse_reader(reader, source)?;
+        self.analyze_parsed(table, source, Some(contract))
+    }
+
+    /// Re-analyze a data file, keeping the decisions of a prior curation layer.
+    ///
+    /// Useful after rows are appended: inference and validation run on the
+    /// whole file again, and decisions on suggestions that are still made
+    /// (same column, action, and target) are carried over. New suggestions
+    /// are left pending. See [`CurationLayer::carry_forward`].
+    pub fn analyze_with_prior(
+        &self,
+        path: impl AsRef<Path>,
+        prior: &CurationLayer,
+    ) -> Result<(CurationLayer, CarryForwardReport)> {
+        let result = self.analyze(path)?;
+        Ok(CurationLayer::carry_forward(result, prior))
+    }
+
+    /// Check the files of a multi-timepoint cohort for consistency.
+    ///
+    /// Each file is parsed and its schema inferred as in [`analyze`](Self::analyze);
+    /// the timepoints are then compared for subjects missing from some visits,
+    /// columns whose inferred type drifts, and categorical value-set drift.
+    /// Timepoints are labeled by file name.
+    pub fn analyze_cohort<P: AsRef<Path>>(&self, files: &[P]) -> Result<CohortReport> {
+        let mut parsed = Vec::with_capacity(files.len());
+        for path in files {
+            let path = path.as_ref();
+            let (table, _) = self.parser.parse_file(path)?;
+            let schema = self.inference.analyze_table(&table);
+            let label = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.display().to_string());
+            parsed.push((label, table, schema));
+        }
+
+        let members: Vec<CohortMember<'_>> = parsed
+            .iter()
+            .map(|(label, table, schema)| CohortMember {
+                label: label.clone(),
+                table,
+                schema,
+            })
+            .collect();
+        Ok(CohortReport::compare(&members))
+    }
 
+    fn run_analysis(&self, path: &Path, contract: Option<&TableSchema>) -> Result<AnalysisResult> {
         // Parse the file
+        self.report(ProgressEvent::Parsing);
         let (table, source) = self.parser.parse_file(path)?;
+        self.analyze_parsed(table, source, contract)
+    }
+
+    /// Analyze a parsed table, from a file or a stream.
+    fn analyze_parsed(
+        &self,
+        mut table: DataTable,
+        mut source: SourceMetadata,
+        contract: Option<&TableSchema>,
+    ) -> Result<AnalysisResult> {
+        let mut header_observations = header_rename_observations(&table, &source.header_renames);
+
+        // Restrict analysis to the selected columns, keeping the columns
+        // cross-column checks relate them to
+        let file_headers = table.headers.clone();
+        let mut selection = self.config.columns.clone();
+        if !selection.is_empty() {
+            selection.extend(CrossColumnValidator::related_columns(&table, &self.config.columns));
+            source.missing_columns = table.retain_columns(&selection);
+            source.selected_columns = table.headers.clone();
+            header_observations.retain(|obs| table.column_index(&obs.column).is_some());
+        }
 
-        // Run inference to get schema
-        let mut schema = self.inference.analyze_table(&table);
+        // Route LLM calls through the response cache if one is configured
+        let response_cache = match (&self.llm_provider, &self.llm_cache) {
+            (Some(provider), Some(cache_path)) => {
+                let mut provider = CachingProvider::new(Arc::clone(provider), cache_path)?;
+                if let Some(ttl) = self.llm_cache_ttl {
+                    provider = provider.with_ttl(ttl);
+                }
+                Some(Arc::new(provider))
+            }
+            _ => None,
+        };
+        let shared_llm: Option<Arc<dyn LlmProvider>> = match (&response_cache, &self.llm_provider) {
+            (Some(cache), _) => Some(Arc::clone(cache) as Arc<dyn LlmProvider>),
+            (None, Some(provider)) => Some(Arc::clone(provider)),
+            (None, None) => None,
+        };
+        let llm = shared_llm.as_deref();
+
+        // Run inference to get schema, then apply the project's vocabularies
+        self.report(ProgressEvent::Inference);
+        let (mut schema, mut questions) = self.inference.analyze_table_with_questions(&table);
+        let mut unmatched_vocabulary = self.config.context.apply_vocabularies(&mut schema)?;
+        // Entries for columns left out of the selection still match the file
+        unmatched_vocabulary.retain(|name| !file_headers.iter().any(|h| h.eq_ignore_ascii_case(name)));
+
+        // Enhance schema with LLM if available, letting it settle ambiguous roles
+        // within the token budget shared with observation requests
+        let mut usage = LlmUsage {
+            token_budget: llm.and_then(|llm| llm.config().token_budget),
+            ..Default::default()
+        };
+        if let Some(llm) = llm {
+            if self.config.auto_answer {
+                self.answer_questions(&mut questions, &mut schema, &table, llm, &mut usage);
+            }
+            self.enhance_schema(&mut schema, &table, llm)?;
+        }
+
+        // Run validation to get observations, starting with renamed headers
+        let mut observations = header_observations;
+        observations.extend(unmatched_vocabulary_observations(&table, &unmatched_vocabulary));
+        match shared_llm {
+            Some(ref provider) if self.config.semantic_embeddings => {
+                let validation = Self::validation_engine(&self.config, &self.bio_validators, self.progress.as_ref())
+                    .with_semantic_embeddings(&self.config.context, Arc::clone(provider));
+                observations.extend(validation.validate(&table, &schema));
+            }
+            _ => observations.extend(self.validation.validate(&table, &schema)),
+        }
 
-        // Enhance schema with LLM if available
-        if let Some(ref llm) = self.llm_provider {
-            self.enhance_schema(&mut schema, &table, llm.as_ref());
+        // Check against the schema contract, errors first
+        if let Some(contract) = contract {
+            let mut contract = contract.clone();
+            // Contract columns left out of the selection aren't missing
+            if !selection.is_empty() {
+                contract
+                    .columns
+                    .retain(|c| selection.iter().any(|name| name.eq_ignore_ascii_case(&c.name)));
+            }
+            let contract_observations = ContractValidator::new(contract).validate(&table, &schema);
+            observations.extend(contract_observations);
+            observations.sort_by_key(|o| std::cmp::Reverse(o.severity));
         }
 
-        // Run validation to get observations
-        let mut observations = self.validation.validate(&table, &schema);
+        // Record how well the data satisfies each inferred constraint
+        let constraint_report = ConstraintReport::for_table(&table, &schema);
+
+        // Pick the observations to send to the LLM, within its token budget
+        let (selected, llm_usage) = match llm {
+            Some(llm) => self.plan_llm_requests(&observations, &schema, llm, usage),
+            None => (Vec::new(), None),
+        };
+
+        // Count the explanation and suggestion requests for progress events
+        let mut llm_progress = LlmProgress::new(
+            self,
+            llm.map_or(0, |llm| {
+                let config = llm.config();
+                selected.len()
+                    * (config.explain_observations as usize + config.generate_suggestions as usize)
+            }),
+        );
 
         // Enhance observations with LLM explanations
-        if let Some(ref llm) = self.llm_provider {
-            self.enhance_observations(&mut observations, &schema, llm.as_ref());
+        if let Some(llm) = llm {
+            self.enhance_observations(&mut observations, &selected, &schema, llm, &mut llm_progress);
         }
 
         // Generate suggestions
@@ -157,8 +715,9 @@ impl Crucible {
         let mut suggestions = SuggestionEngine::generate(&observations);
 
         // If LLM is available, enhance or add LLM-generated suggestions
-        if let Some(ref llm) = self.llm_provider {
-            let llm_suggestions = self.generate_llm_suggestions(&observations, &schema, llm.as_ref());
+        if let Some(llm) = llm {
+            let llm_suggestions =
+                self.generate_llm_suggestions(&observations, &selected, &schema, llm, &mut llm_progress);
             // Merge LLM suggestions with rule-based ones
             // LLM suggestions can provide better rationale for existing suggestions
             // or add new suggestions that rules didn't catch
@@ -175,6 +734,11 @@ impl Crucible {
             }
         }
 
+        // Write the run's new LLM responses to the cache once
+        if let Some(cache) = &response_cache {
+            cache.flush()?;
+        }
+
         // Compute summary
         let summary = self.compute_summary(&schema, &observations);
 
@@ -183,22 +747,102 @@ impl Crucible {
             schema,
             observations,
             suggestions,
+            constraint_report,
+            llm_usage,
+            profile: self.config.profile,
+            locale: self.config.context.locale.unwrap_or_default(),
+            questions,
             summary,
         })
     }
 
+    /// Validate rows appended to a dataset against its previously inferred schema.
+    ///
+    /// Only checks that work on new rows in isolation are run (type, range,
+    /// set membership, format); the rest are listed in `skipped_checks`.
+    /// Columns are matched by name, so the new rows may use a different order.
+    pub fn validate_appended(
+        &self,
+        prev_schema: &TableSchema,
+        new_rows: &DataTable,
+    ) -> AppendValidation {
+        // Re-point schema positions at the new table's columns
+        let mut schema = prev_schema.clone();
+        let mut missing_columns = Vec::new();
+        schema.columns.retain_mut(|col| match new_rows.column_index(&col.name) {
+            Some(index) => {
+                col.position = index;
+                true
+            }
+            None => {
+                missing_columns.push(col.name.clone());
+                false
+            }
+        });
+
+        let unexpected_columns = new_rows
+            .headers
+            .iter()
+            .filter(|h| prev_schema.get_column(h).is_none())
+            .cloned()
+            .collect();
+
+        let observations = if new_rows.row_count() == 0 {
+            Vec::new()
+        } else {
+            // Read numbers as the schema was inferred
+            let decimal_mark = prev_schema
+                .inference
+                .as_ref()
+                .map_or(self.config.context.decimal_mark(), |settings| settings.decimal_mark);
+            ValidationEngine::incremental(decimal_mark)
+                .with_parallelism(self.config.parallelism)
+                .with_severity_overrides(self.config.context.severity_overrides.clone())
+                .without_validators(&self.config.context.disabled_validators)
+                .validate(new_rows, &schema)
+        };
+
+        AppendValidation {
+            rows_checked: new_rows.row_count(),
+            observations,
+            missing_columns,
+            unexpected_columns,
+            skipped_checks: NON_INCREMENTAL_CHECKS.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
     /// Enhance column schemas with LLM-generated insights.
     fn enhance_schema(
         &self,
         schema: &mut TableSchema,
-        table: &crate::input::DataTable,
+        table: &DataTable,
         llm: &dyn LlmProvider,
-    ) {
+    ) -> Result<()> {
         if !llm.config().enhance_schema {
-            return;
+            return Ok(());
         }
 
+        let mut cache = match self.insight_cache {
+            Some(ref path) => Some(InsightCache::load(path)?),
+            None => None,
+        };
+
+        let mut progress = LlmProgress::new(self, schema.columns.len());
         for column in &mut schema.columns {
+            // Reuse the cached insight if the column is unchanged
+            let profile_hash = cache
+                .as_ref()
+                .map(|_| InsightCache::profile_hash(column, table));
+            let cached = match (&cache, &profile_hash) {
+                (Some(cache), Some(hash)) => cache.get(&column.name, hash),
+                _ => None,
+            };
+            if let Some(insight) = cached {
+                column.llm_insight = Some(insight.to_string());
+                progress.step();
+                continue;
+            }
+
             // Get sample values for this column
             let samples: Vec<String> = table
                 .column_values(column.position)
@@ -209,30 +853,132 @@ impl Crucible {
             // Get LLM enhancement
             if let Ok(enhancement) = llm.enhance_schema(column, &samples, &self.config.context) {
                 if !enhancement.insight.is_empty() {
+                    if let (Some(cache), Some(hash)) = (&mut cache, profile_hash) {
+                        cache.insert(&column.name, hash, &enhancement.insight);
+                    }
                     column.llm_insight = Some(enhancement.insight);
                 }
             }
+            progress.step();
+        }
+
+        if let (Some(cache), Some(path)) = (&cache, &self.insight_cache) {
+            cache.save(path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Ask the LLM to answer questions about near-tied roles.
+    ///
+    /// Each answer is applied to the schema before validation. Questions the
+    /// LLM fails to answer, answers with none of the options, or that don't
+    /// fit in the token budget stay open.
+    fn answer_questions(
+        &self,
+        questions: &mut [Question],
+        schema: &mut TableSchema,
+        table: &DataTable,
+        llm: &dyn LlmProvider,
+        usage: &mut LlmUsage,
+    ) {
+        for question in questions.iter_mut() {
+            let Some(column) = schema.columns.iter_mut().find(|c| c.name == question.column) else {
+                continue;
+            };
+            let samples: Vec<String> = table
+                .column_values(column.position)
+                .filter(|v| !DataTable::is_null_value(v))
+                .take(10)
+                .map(|s| s.to_string())
+                .collect();
+
+            let context = question.to_question_context(Some(column), samples);
+            let cost = question_cost(llm.config(), &context, &self.config.context);
+            if usage
+                .token_budget
+                .is_some_and(|budget| usage.estimated_tokens + cost > budget)
+            {
+                break;
+            }
+            usage.estimated_tokens += cost;
+            usage.questions += 1;
+
+            let answer = llm
+                .answer_question(&context, &self.config.context)
+                .ok()
+                .and_then(|response| question.resolve(&response.answer));
+            if let Some(role) = answer {
+                question.record_answer(role, llm.name());
+                column.semantic_role = role;
+            }
         }
     }
 
+    /// Choose which observations to send to the LLM.
+    ///
+    /// Observations are taken highest severity first until the estimated
+    /// token budget, less what `usage` already spent on questions, would be
+    /// exceeded; the rest keep rule-based suggestions only. Returns the
+    /// chosen indices in their original order.
+    fn plan_llm_requests(
+        &self,
+        observations: &[Observation],
+        schema: &TableSchema,
+        llm: &dyn LlmProvider,
+        mut usage: LlmUsage,
+    ) -> (Vec<usize>, Option<LlmUsage>) {
+        let config = llm.config();
+        if !config.explain_observations && !config.generate_suggestions {
+            return (Vec::new(), (usage.questions > 0).then_some(usage));
+        }
+
+        let mut order: Vec<usize> = (0..observations.len()).collect();
+        order.sort_by_key(|&i| std::cmp::Reverse(observations[i].severity));
+
+        let mut selected = Vec::new();
+        for (n, &i) in order.iter().enumerate() {
+            let obs = &observations[i];
+            let column = schema.columns.iter().find(|c| c.name == obs.column);
+            let cost = observation_cost(config, obs, column, &self.config.context);
+            if usage
+                .token_budget
+                .is_some_and(|budget| usage.estimated_tokens + cost > budget)
+            {
+                usage.skipped = order.len() - n;
+                break;
+            }
+            usage.estimated_tokens += cost;
+            selected.push(i);
+        }
+        usage.enhanced = selected.len();
+        selected.sort_unstable();
+
+        (selected, Some(usage))
+    }
+
     /// Enhance observations with LLM-generated explanations.
     fn enhance_observations(
         &self,
         observations: &mut [Observation],
+        selected: &[usize],
         schema: &TableSchema,
         llm: &dyn LlmProvider,
+        progress: &mut LlmProgress<'_>,
     ) {
         if !llm.config().explain_observations {
             return;
         }
 
-        for obs in observations {
+        for &i in selected {
+            let obs = &mut observations[i];
             let column = schema.columns.iter().find(|c| c.name == obs.column);
             if let Ok(explanation) = llm.explain_observation(obs, column, &self.config.context) {
                 if !explanation.is_empty() {
                     obs.llm_explanation = Some(explanation);
                 }
             }
+            progress.step();
         }
     }
 
@@ -240,21 +986,24 @@ impl Crucible {
     fn generate_llm_suggestions(
         &self,
         observations: &[Observation],
+        selected: &[usize],
         schema: &TableSchema,
         llm: &dyn LlmProvider,
+        progress: &mut LlmProgress<'_>,
     ) -> Vec<Suggestion> {
         if !llm.config().generate_suggestions {
             return Vec::new();
         }
 
         let mut suggestions = Vec::new();
-        for obs in observations {
+        for obs in selected.iter().map(|&i| &observations[i]) {
             let column = schema.columns.iter().find(|c| c.name == obs.column);
             if let Ok(Some(suggestion)) =
                 llm.generate_suggestion(obs, column, &self.config.context)
             {
                 suggestions.push(suggestion);
             }
+            progress.step();
         }
         suggestions
     }
@@ -295,16 +1044,23 @@ impl Crucible {
                 .or_insert(0) += 1;
         }
 
+        // Strict mode scores warnings as errors without touching the stored counts
+        let scored_severity = if self.config.strict || self.config.profile.scores_warnings_as_errors() {
+            observations_by_severity.escalated()
+        } else {
+            observations_by_severity.clone()
+        };
+
         // Calculate quality score
         let data_quality_score = self.calculate_quality_score(
             total_columns,
             columns_with_issues,
-            &observations_by_severity,
+            &scored_severity,
         );
 
         // Generate recommendation
         let recommendation = self.generate_recommendation(
-            &observations_by_severity,
+            &scored_severity,
             data_quality_score,
         );
 
@@ -376,6 +1132,32 @@ impl Default for Crucible {
     }
 }
 
+/// Counts the LLM requests of one pass, reporting each as progress.
+struct LlmProgress<'a> {
+    crucible: &'a Crucible,
+    done: usize,
+    total: usize,
+}
+
+impl<'a> LlmProgress<'a> {
+    fn new(crucible: &'a Crucible, total: usize) -> Self {
+        Self {
+            crucible,
+            done: 0,
+            total,
+        }
+    }
+
+    /// Record one finished request.
+    fn step(&mut self) {
+        self.done += 1;
+        self.crucible.report(ProgressEvent::LlmEnhancement {
+            done: self.done,
+            total: self.total,
+        });
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -417,6 +1199,551 @@ mod tests {
         assert!(missing_obs);
     }
 
+    #[test]
+    fn test_fingerprint_ignores_row_order() {
+        let a = create_test_file("id,value\nS1,10\nS2,20\n");
+        let b = create_test_file("id,value\nS2,20\nS1,10\n");
+        let c = create_test_file("id,value\nS1,10\nS2,21\n");
+
+        let crucible = Crucible::new();
+        let fp_a = crucible.analyze(a.path()).unwrap().fingerprint();
+        let fp_b = crucible.analyze(b.path()).unwrap().fingerprint();
+        let fp_c = crucible.analyze(c.path()).unwrap().fingerprint();
+
+        assert!(fp_a.starts_with("sha256:"));
+        assert_eq!(fp_a, fp_b);
+        assert_ne!(fp_a, fp_c);
+    }
+
+    #[test]
+    fn test_insight_cache_reuses_unchanged_columns() {
+        use crate::llm::MockProvider;
+
+        let data = create_test_file("sample_id,age\nS1,30\nS2,40\n");
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("insights.json");
+
+        let crucible = Crucible::new()
+            .with_llm(MockProvider::new())
+            .with_insight_cache(&cache_path);
+        crucible.analyze(data.path()).unwrap();
+
+        // Overwrite a cached insight; a cache hit must return it verbatim
+        let mut cache = InsightCache::load(&cache_path).unwrap();
+        assert_eq!(cache.entries.len(), 2);
+        let hash = cache.entries["age"].profile_hash.clone();
+        cache.insert("age", hash, "cached age insight");
+        cache.save(&cache_path).unwrap();
+
+        let result = crucible.analyze(data.path()).unwrap();
+        let age = result.schema.get_column("age").unwrap();
+        assert_eq!(age.llm_insight.as_deref(), Some("cached age insight"));
+    }
+
+    #[test]
+    fn test_llm_cache_is_written_after_analysis() {
+        use crate::llm::MockProvider;
+
+        let data = create_test_file("sample_id,age\nS1,30\nS2,40\n");
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("llm-cache.json");
+
+        let crucible = Crucible::new()
+            .with_llm(MockProvider::new())
+            .with_llm_cache(&cache_path);
+        let first = crucible.analyze(data.path()).unwrap();
+        assert!(cache_path.exists());
+
+        let second = crucible.analyze(data.path()).unwrap();
+        assert_eq!(
+            first.schema.get_column("age").unwrap().llm_insight,
+            second.schema.get_column("age").unwrap().llm_insight
+        );
+    }
+
+    #[test]
+    fn test_progress_events() {
+        use crate::llm::MockProvider;
+        use std::sync::Mutex;
+
+        let file = create_test_file("sample_id,age\nS1,30\nS2,40\nS3,missing\n");
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let crucible = Crucible::new()
+            .with_llm(MockProvider::new())
+            .with_progress(move |event| sink.lock().unwrap().push(event));
+        let result = crucible.analyze(file.path()).unwrap();
+
+        let events = events.lock().unwrap();
+        assert_eq!(events[..2], [ProgressEvent::Parsing, ProgressEvent::Inference]);
+        let validators: Vec<&str> = events
+            .iter()
+            .filter_map(|e| match e {
+                ProgressEvent::Validation { validator_name } => Some(*validator_name),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(validators.len(), ValidationEngine::validator_names().len());
+
+        // Column insights first, then one pass over the observations
+        let llm: Vec<(usize, usize)> = events
+            .iter()
+            .filter_map(|e| match e {
+                ProgressEvent::LlmEnhancement { done, total } => Some((*done, *total)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(llm[..2], [(1, 2), (2, 2)]);
+        let observation_requests = llm.len() - 2;
+        assert!(observation_requests > 0);
+        assert_eq!(
+            llm.last(),
+            Some(&(observation_requests, observation_requests))
+        );
+        assert!(!result.observations.is_empty());
+    }
+
+    #[test]
+    fn test_semantic_embeddings() {
+        use crate::llm::MockProvider;
+
+        let file = create_test_file(
+            "id,site\nS1,upper respiratory tract\nS2,upper respiratory tract\nS3,upper respiratory tracts\nS4,skin\n",
+        );
+        let groups = |crucible: Crucible| {
+            crucible
+                .analyze(file.path())
+                .unwrap()
+                .observations
+                .into_iter()
+                .filter(|o| o.detector == "semantic_equivalence_validator")
+                .count()
+        };
+
+        assert_eq!(groups(Crucible::new().with_llm(MockProvider::new())), 0);
+        assert_eq!(
+            groups(Crucible::new().with_llm(MockProvider::new()).with_semantic_embeddings(true)),
+            1
+        );
+        // Without a provider the synonym table alone is used
+        assert_eq!(groups(Crucible::new().with_semantic_embeddings(true)), 0);
+    }
+
+    #[test]
+    fn test_llm_token_budget() {
+        use crate::llm::{LlmConfig, MockProvider};
+
+        let content = "id,age,status\n1,30,active\n1,40,missing\n3,-5,Active\n4,35,missing\n";
+        let file = create_test_file(content);
+
+        let unlimited = Crucible::new()
+            .with_llm(MockProvider::new())
+            .analyze(file.path())
+            .unwrap();
+        let usage = unlimited.llm_usage.clone().unwrap();
+        assert!(unlimited.observations.len() > 1);
+        assert_eq!(usage.enhanced, unlimited.observations.len());
+        assert_eq!(usage.skipped, 0);
+
+        // Just short of what every observation needs
+        let config = LlmConfig::default().with_token_budget(usage.estimated_tokens - 1);
+        let result = Crucible::new()
+            .with_llm(MockProvider::with_config(config))
+            .analyze(file.path())
+            .unwrap();
+        let usage = result.llm_usage.clone().unwrap();
+        assert!(usage.skipped >= 1);
+        assert_eq!(usage.enhanced + usage.skipped, result.observations.len());
+
+        // Enhanced observations are at least as severe as skipped ones
+        let (enhanced, skipped): (Vec<_>, Vec<_>) = result
+            .observations
+            .iter()
+            .partition(|o| o.llm_explanation.is_some());
+        assert_eq!(enhanced.len(), usage.enhanced);
+        let least_enhanced = enhanced.iter().map(|o| o.severity).min();
+        let most_skipped = skipped.iter().map(|o| o.severity).max().unwrap();
+        assert!(least_enhanced.is_none_or(|s| s >= most_skipped));
+
+        // Skipped observations keep their rule-based suggestions
+        let baseline = Crucible::new().analyze(file.path()).unwrap();
+        // (observation IDs differ between runs, so match by description)
+        for obs in skipped {
+            let base = baseline
+                .observations
+                .iter()
+                .find(|o| o.column == obs.column && o.description == obs.description)
+                .unwrap();
+            let has_rule_based = baseline.suggestions.iter().any(|s| s.observation_id == base.id);
+            let has_suggestion = result.suggestions.iter().any(|s| s.observation_id == obs.id);
+            assert_eq!(has_rule_based, has_suggestion);
+        }
+    }
+
+    #[test]
+    fn test_strict_mode_scores_warnings_as_errors() {
+        let content = "sample_id,age,height,weight,city,status\n\
+                       S1,25,170,60,NYC,active\n\
+                       S2,30,165,70,LA,missing\n\
+                       S3,35,180,80,SF,active\n\
+                       S4,40,175,90,SEA,missing\n";
+        let file = create_test_file(content);
+
+        let lenient = Crucible::new().analyze(file.path()).unwrap();
+        let strict = Crucible::new().with_strict(true).analyze(file.path()).unwrap();
+
+        // Stored severities are unchanged
+        assert_eq!(
+            lenient.summary.observations_by_severity.warning,
+            strict.summary.observations_by_severity.warning
+        );
+        assert!(strict.summary.observations_by_severity.warning > 0);
+        assert!(strict.summary.data_quality_score < lenient.summary.data_quality_score);
+        assert!(strict.summary.recommendation.contains("error-level"));
+    }
+
+    #[test]
+    fn test_custom_bio_validator() {
+        struct SpecimenIdValidator;
+
+        impl BioValidator for SpecimenIdValidator {
+            fn validate(&self, data: &DataTable, _schema: &TableSchema) -> Vec<Observation> {
+                let Some(col) = data.column_index("specimen_id") else {
+                    return Vec::new();
+                };
+                data.column_values(col)
+                    .filter(|id| !id.starts_with("SP-"))
+                    .map(|id| {
+                        Observation::new(
+                            crate::ObservationType::PatternViolation,
+                            crate::Severity::Error,
+                            "specimen_id",
+                            format!("'{}' is not an SP- specimen ID", id),
+                        )
+                    })
+                    .collect()
+            }
+
+            fn name(&self) -> &'static str {
+                "specimen_id_validator"
+            }
+        }
+
+        let file = create_test_file("specimen_id,age\nSP-001,34\nSP-002,41\nX-003,29\n");
+        let crucible = Crucible::new()
+            .with_parallelism(Parallelism::Threads(2))
+            .with_bio_validator(Box::new(SpecimenIdValidator))
+            .with_profile(Profile::Strict);
+        let result = crucible.analyze(file.path()).unwrap();
+
+        let custom: Vec<_> = result
+            .observations
+            .iter()
+            .filter(|o| o.detector == "specimen_id_validator")
+            .collect();
+        assert_eq!(custom.len(), 1);
+        assert!(custom[0].description.contains("X-003"));
+        assert_eq!(result.summary.total_observations, result.observations.len());
+    }
+
+    #[test]
+    fn test_profiles() {
+        let mut content = String::from("sample_id,score\n");
+        for i in 1..=10 {
+            let score = if i == 3 { "NA".to_string() } else { (i * 10).to_string() };
+            content.push_str(&format!("S{},{}\n", i, score));
+        }
+        let file = create_test_file(&content);
+
+        let completeness = |profile: Profile| {
+            let result = Crucible::with_config(CrucibleConfig::default().with_profile(profile))
+                .analyze(file.path())
+                .unwrap();
+            assert_eq!(result.profile, profile);
+            result
+                .observations
+                .iter()
+                .find(|o| {
+                    o.column == "score"
+                        && o.observation_type == crate::ObservationType::Completeness
+                })
+                .map(|o| o.severity)
+        };
+
+        // 10% missing
+        assert_eq!(completeness(Profile::Lenient), None);
+        assert_eq!(completeness(Profile::Standard), Some(crate::Severity::Warning));
+        assert_eq!(completeness(Profile::Strict), Some(crate::Severity::Error));
+
+        let lenient = ValidationEngine::with_profile(&ContextHints::new(), Profile::Lenient);
+        assert!(!lenient.names().contains(&"typo_validator"));
+
+        // The profile is kept with the curation layer
+        let result = Crucible::new().with_profile(Profile::Strict).analyze(file.path()).unwrap();
+        let layer = CurationLayer::from_analysis(result, Default::default());
+        let json = serde_json::to_string(&layer).unwrap();
+        let restored: CurationLayer = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.profile, Profile::Strict);
+    }
+
+    #[test]
+    fn test_analyze_selected_columns() {
+        let content = "sample_id,start_date,end_date,age\n\
+                       S1,2024-03-01,2024-01-01,30\n\
+                       S2,2024-01-01,2024-02-01,40\n\
+                       S3,2024-01-05,2024-02-01,35\n";
+        let file = create_test_file(content);
+
+        let all = Crucible::new().analyze(file.path()).unwrap();
+        assert!(all.observations.iter().any(|o| o.column == "start_date vs end_date"));
+
+        let result = Crucible::new()
+            .with_columns(["sample_id", "START_DATE", "age", "depth"])
+            .analyze(file.path())
+            .unwrap();
+
+        // The end date is kept so the start date can be checked against it
+        let names: Vec<&str> = result.schema.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["sample_id", "start_date", "end_date", "age"]);
+        assert_eq!(result.source.selected_columns, names);
+        assert_eq!(result.source.missing_columns, vec!["depth"]);
+        assert_eq!(result.source.column_count, 4);
+        assert!(result.observations.iter().any(|o| o.column == "start_date vs end_date"));
+
+        // Neither date is selected, so they're dropped
+        let result = Crucible::new().with_columns(["sample_id", "age"]).analyze(file.path()).unwrap();
+        let names: Vec<&str> = result.schema.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["sample_id", "age"]);
+    }
+
+    #[test]
+    fn test_validate_appended_rows() {
+        let content = "sample_id,age,group\nS1,30,A\nS2,40,B\nS3,50,A\nS4,45,B\n";
+        let file = create_test_file(content);
+
+        let crucible = Crucible::new();
+        let result = crucible.analyze(file.path()).unwrap();
+
+        // Columns reordered, one bad age, one missing ID
+        let new_rows = DataTable::new(
+            vec!["group".to_string(), "sample_id".to_string(), "age".to_string()],
+            vec![
+                vec!["A".to_string(), "S5".to_string(), "33".to_string()],
+                vec!["B".to_string(), "".to_string(), "old".to_string()],
+            ],
+            b',',
+        );
+
+        let appended = crucible.validate_appended(&result.schema, &new_rows);
+        assert_eq!(appended.rows_checked, 2);
+        assert!(appended.missing_columns.is_empty());
+        assert!(!appended.skipped_checks.is_empty());
+        assert!(appended
+            .observations
+            .iter()
+            .any(|o| o.column == "age" && o.evidence.sample_rows == vec![1]));
+    }
+
+    #[test]
+    fn test_validate_appended_grouped_numbers() {
+        let content = "sample_id,read_count\nS1,1.234.567\nS2,980\nS3,12.000\nS4,45.312\n";
+        let file = create_test_file(content);
+
+        let crucible = Crucible::new().with_context(ContextHints::new().with_locale(Locale::EuDe));
+        let result = crucible.analyze(file.path()).unwrap();
+        let column = result.schema.get_column("read_count").unwrap();
+        assert_eq!(column.inferred_type, crate::schema::ColumnType::Integer);
+
+        let new_rows = DataTable::new(
+            vec!["sample_id".to_string(), "read_count".to_string()],
+            vec![vec!["S5".to_string(), "2.345.678".to_string()]],
+            b',',
+        );
+        let appended = crucible.validate_appended(&result.schema, &new_rows);
+        assert!(appended
+            .observations
+            .iter()
+            .all(|o| o.observation_type != crate::ObservationType::TypeMismatch));
+    }
+
+    #[test]
+    fn test_analyze_sample() {
+        let mut content = "sample_id,value\n".to_string();
+        for i in 0..50 {
+            content.push_str(&format!("S{},{}\n", i, i));
+        }
+        let file = create_test_file(&content);
+
+        let result = Crucible::new().with_sample(10).analyze(file.path()).unwrap();
+        assert_eq!(result.source.row_count, 50);
+        assert_eq!(result.source.sampled_rows, Some(10));
+        assert!(result
+            .observations
+            .iter()
+            .any(|o| o.observation_type == crate::ObservationType::SkippedCheck));
+    }
+
+    #[test]
+    fn test_role_questions() {
+        use crate::curation::CurationContext;
+        use crate::llm::MockProvider;
+        use crate::schema::SemanticRole;
+
+        let file = create_test_file("sample_id,age_group,value\nS1,young,1.5\nS2,old,2.5\nS3,young,3.5\n");
+        let role = |result: &AnalysisResult| {
+            result.schema.get_column("age_group").unwrap().semantic_role
+        };
+
+        // Without an LLM the question stays open and the first role is used
+        let result = Crucible::new().analyze(file.path()).unwrap();
+        let open: Vec<_> = result.open_questions().collect();
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].column, "age_group");
+        assert_eq!(role(&result), SemanticRole::Grouping);
+
+        // An LLM answers it, and the answer is kept in the curation layer
+        let result = Crucible::new().with_llm(MockProvider::new()).analyze(file.path()).unwrap();
+        assert_eq!(result.open_questions().count(), 0);
+        assert_eq!(result.questions[0].answered_by.as_deref(), Some("mock"));
+        let usage = result.llm_usage.clone().unwrap();
+        assert_eq!(usage.questions, 1);
+        assert!(usage.estimated_tokens > 0);
+
+        // Answering counts against the token budget
+        let config = crate::llm::LlmConfig::default().with_token_budget(1);
+        let budgeted = Crucible::new()
+            .with_llm(MockProvider::with_config(config))
+            .analyze(file.path())
+            .unwrap();
+        assert_eq!(budgeted.open_questions().count(), 1);
+        assert_eq!(budgeted.llm_usage.unwrap().questions, 0);
+
+        let layer = CurationLayer::from_analysis(result, CurationContext::new());
+        assert_eq!(layer.context.hints.column_roles["age_group"], SemanticRole::Grouping);
+
+        // so re-analysis with the layer's hints doesn't ask again
+        let result = Crucible::new()
+            .with_context(layer.context.to_context_hints())
+            .analyze(file.path())
+            .unwrap();
+        assert!(result.questions.is_empty());
+
+        // A pinned role is used as given
+        let context = ContextHints::new().with_column_role("Age_Group", SemanticRole::Covariate);
+        let result = Crucible::new().with_context(context).analyze(file.path()).unwrap();
+        assert!(result.questions.is_empty());
+        assert_eq!(role(&result), SemanticRole::Covariate);
+    }
+
+    #[test]
+    fn test_context_missing_patterns() {
+        let file = create_test_file("sample_id,result\nS1,12.1\nS2,ND\nS3,ND\nS4,9.8\n");
+
+        let has_nd = |crucible: Crucible| {
+            crucible
+                .analyze(file.path())
+                .unwrap()
+                .observations
+                .iter()
+                .any(|o| o.observation_type == crate::ObservationType::MissingPattern)
+        };
+
+        assert!(!has_nd(Crucible::new()));
+        assert!(has_nd(Crucible::new().with_context(
+            ContextHints::new().with_missing_patterns(vec!["ND".to_string()])
+        )));
+    }
+
+    #[test]
+    fn test_context_column_types() {
+        let file = create_test_file(
+            "sample_id,zip,visit\nS1,02134,1\nS2,10001,2\nS3,94110,two\nS4,60601,4\n",
+        );
+        let column = |result: &AnalysisResult, name: &str| {
+            result.schema.columns.iter().find(|c| c.name == name).unwrap().clone()
+        };
+
+        let inferred = Crucible::new().analyze(file.path()).unwrap();
+        assert!(column(&inferred, "zip").inferred_type.is_numeric());
+
+        let result = Crucible::new()
+            .with_context(
+                ContextHints::new()
+                    .with_column_type("ZIP", crate::ColumnType::String)
+                    .with_column_type("visit", crate::ColumnType::Integer),
+            )
+            .analyze(file.path())
+            .unwrap();
+
+        let zip = column(&result, "zip");
+        assert_eq!(zip.inferred_type, crate::ColumnType::String);
+        assert_eq!(zip.inference_sources, vec!["user_hint"]);
+        assert_eq!(zip.confidence, 1.0);
+        assert!(zip.expected_range.is_none());
+        assert!(zip.statistics.string.is_some());
+
+        // A pinned type is still validated
+        assert_eq!(column(&result, "visit").inferred_type, crate::ColumnType::Integer);
+        assert!(result.observations.iter().any(|o| {
+            o.column == "visit" && o.observation_type == crate::ObservationType::TypeMismatch
+        }));
+    }
+
+    #[test]
+    fn test_context_vocabulary() {
+        let file = create_test_file("sample_id,Diagnosis\nS1,CD\nS2,UC\nS3,Crohns\nS4,CD\n");
+
+        let result = Crucible::new()
+            .with_context(
+                ContextHints::new()
+                    .with_vocabulary("diagnosis", ["CD", "UC", "IBDU"])
+                    .with_vocabulary("diagnsis", ["CD"]),
+            )
+            .analyze(file.path())
+            .unwrap();
+
+        assert!(result.observations.iter().any(|o| o.column == "Diagnosis"
+            && o.detector == "set_validator"
+            && o.description.contains("Crohns")));
+        assert!(result
+            .observations
+            .iter()
+            .any(|o| o.column == "diagnsis" && o.detector == "vocabulary"));
+    }
+
+    #[test]
+    fn test_analyze_against_contract() {
+        let delivery_1 = create_test_file("sample_id,age\nS1,30\nS2,40\n");
+        let delivery_2 = create_test_file("sample_id,weight\nS3,70.5\nS4,80.1\n");
+
+        let crucible = Crucible::new();
+        let contract = crucible.analyze(delivery_1.path()).unwrap().schema;
+
+        let dir = tempfile::tempdir().unwrap();
+        let contract_path = dir.path().join("schema.json");
+        contract.save(&contract_path).unwrap();
+        let contract = TableSchema::load(&contract_path).unwrap();
+
+        let result = crucible.analyze_against(delivery_2.path(), &contract).unwrap();
+        let violations: Vec<_> = result
+            .observations
+            .iter()
+            .filter(|o| o.observation_type == crate::ObservationType::ContractViolation)
+            .collect();
+
+        assert!(violations
+            .iter()
+            .any(|o| o.column == "age" && o.severity == crate::Severity::Error));
+        assert!(violations.iter().any(|o| o.column == "weight"));
+
+        // Contract columns outside the selection aren't reported missing
+        let result = Crucible::new()
+            .with_columns(["sample_id"])
+            .analyze_against(delivery_2.path(), &contract)
+            .unwrap();
+        assert!(result.observations.iter().all(|o| o.column != "age"));
+    }
+
     #[test]
     fn test_quality_score() {
         let crucible = Crucible::new();
@@ -0,0 +1,91 @@
+//! Carrying review decisions forward when a dataset is re-analyzed.
+
+use std::collections::{HashMap, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use crate::crucible::AnalysisResult;
+use crate::suggestion::{Suggestion, SuggestionAction};
+
+use super::decision::Decision;
+use super::layer::CurationLayer;
+
+/// Outcome of carrying a prior layer's decisions into a re-analysis.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CarryForwardReport {
+    /// New suggestions that took over a prior decision.
+    pub carried_over: Vec<String>,
+    /// Prior suggestions whose decision was dropped because the issue is gone.
+    pub invalidated: Vec<String>,
+    /// New suggestions with no prior counterpart, left pending.
+    pub added: Vec<String>,
+}
+
+/// Identity of a suggestion across analyses: column, action, and target.
+type SuggestionKey = (String, SuggestionAction, Option<String>);
+
+impl CurationLayer {
+    /// Create a curation layer from a re-analysis, keeping prior decisions.
+    ///
+    /// Suggestion IDs change between analyses, so suggestions are matched by
+    /// column, action, and [target](Suggestion::target). A matched decision
+    /// is moved to the new suggestion unchanged; prior decisions without a
+    /// match are dropped and reported as invalidated. The prior context and
+    /// creation time are kept.
+    pub fn carry_forward(
+        result: AnalysisResult,
+        prior: &CurationLayer,
+    ) -> (Self, CarryForwardReport) {
+        let mut layer = Self::from_analysis(result, prior.context.clone());
+        layer.created_at = prior.created_at;
+
+        // Prior suggestions by identity, in order, with their decisions
+        let mut prior_by_key: HashMap<SuggestionKey, VecDeque<(&Suggestion, Option<&Decision>)>> =
+            HashMap::new();
+        for suggestion in &prior.suggestions {
+            prior_by_key
+                .entry(prior.suggestion_key(suggestion))
+                .or_default()
+                .push_back((suggestion, prior.decision_for(&suggestion.id)));
+        }
+
+        let mut report = CarryForwardReport::default();
+        let mut decisions = Vec::new();
+        for suggestion in &layer.suggestions {
+            let key = layer.suggestion_key(suggestion);
+            match prior_by_key.get_mut(&key).and_then(VecDeque::pop_front) {
+                Some((_, Some(decision))) => {
+                    let mut decision = decision.clone();
+                    decision.suggestion_id = suggestion.id.clone();
+                    decisions.push(decision);
+                    report.carried_over.push(suggestion.id.clone());
+                }
+                Some((_, None)) => {}
+                None => report.added.push(suggestion.id.clone()),
+            }
+        }
+
+        // Whatever is left unmatched no longer applies to the data
+        for (suggestion, decision) in prior_by_key.into_values().flatten() {
+            if decision.is_some() {
+                report.invalidated.push(suggestion.id.clone());
+            }
+        }
+        report.invalidated.sort();
+
+        layer.decisions = decisions;
+        layer.touch();
+
+        (layer, report)
+    }
+
+    /// Column, action, and target of a suggestion in this layer.
+    fn suggestion_key(&self, suggestion: &Suggestion) -> SuggestionKey {
+        let column = suggestion
+            .column()
+            .or_else(|| self.observation(&suggestion.observation_id).map(|o| o.column.as_str()))
+            .unwrap_or_default()
+            .to_string();
+        (column, suggestion.action, suggestion.target())
+    }
+}
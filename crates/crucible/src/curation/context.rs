@@ -8,6 +8,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 
 use crate::input::ContextHints;
+use crate::schema::SemanticRole;
 
 /// Context hints provided by the user.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -35,6 +36,10 @@ pub struct UserHints {
     /// Custom key-value hints.
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub custom: HashMap<String, String>,
+
+    /// Semantic roles chosen for columns, e.g. in answer to inference questions.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub column_roles: HashMap<String, SemanticRole>,
 }
 
 impl UserHints {
@@ -66,6 +71,12 @@ impl UserHints {
         self.identifier_column = Some(column.into());
         self
     }
+
+    /// Set the semantic role of a column.
+    pub fn with_column_role(mut self, column: impl Into<String>, role: SemanticRole) -> Self {
+        self.column_roles.insert(column.into(), role);
+        self
+    }
 }
 
 /// File-derived context information.
@@ -191,6 +202,7 @@ impl CurationContext {
                 identifier_column: hints.identifier_column.clone(),
                 column_hints: hints.column_hints.clone(),
                 custom: hints.custom.clone(),
+                column_roles: hints.column_roles.clone(),
             },
             file_context: FileContext {
                 directory: None,
@@ -260,6 +272,20 @@ impl CurationContext {
             custom: self.hints.custom.clone(),
             related_files: self.file_context.related_files.clone(),
             data_source: self.file_context.extraction_source.clone(),
+            study_start: None,
+            study_end: None,
+            missing_patterns: Vec::new(),
+            units: HashMap::new(),
+            references: HashMap::new(),
+            clean_encoding: false,
+            severity_overrides: HashMap::new(),
+            disabled_validators: Vec::new(),
+            sensitive_patterns: HashMap::new(),
+            column_types: HashMap::new(),
+            column_roles: self.hints.column_roles.clone(),
+            vocabularies: HashMap::new(),
+            canonical_label: None,
+            locale: None,
         }
     }
 }
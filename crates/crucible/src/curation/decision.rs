@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 /// Status of a decision on a suggestion.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum DecisionStatus {
     /// Not yet reviewed.
@@ -20,6 +20,15 @@ pub enum DecisionStatus {
     Rejected,
     /// Accepted and exported/applied.
     Applied,
+    /// Postponed for later review.
+    Deferred {
+        /// Why review was postponed.
+        #[serde(default)]
+        reason: String,
+        /// When to revisit the suggestion (e.g., "2024-07-01"), if set.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        until: Option<String>,
+    },
 }
 
 impl DecisionStatus {
@@ -31,12 +40,18 @@ impl DecisionStatus {
             DecisionStatus::Modified => "Modified",
             DecisionStatus::Rejected => "Rejected",
             DecisionStatus::Applied => "Applied",
+            DecisionStatus::Deferred { .. } => "Deferred",
         }
     }
 
-    /// Check if this is a terminal decision (not pending).
+    /// Check if this is a terminal decision (not pending or deferred).
     pub fn is_decided(&self) -> bool {
-        !matches!(self, DecisionStatus::Pending)
+        !matches!(self, DecisionStatus::Pending | DecisionStatus::Deferred { .. })
+    }
+
+    /// Check if review was postponed.
+    pub fn is_deferred(&self) -> bool {
+        matches!(self, DecisionStatus::Deferred { .. })
     }
 
     /// Check if this is an approval (accepted, modified, or applied).
@@ -48,6 +63,40 @@ impl DecisionStatus {
     }
 }
 
+/// A change in the status of a suggestion's decision.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecisionEvent {
+    /// When the change was made.
+    pub timestamp: DateTime<Utc>,
+
+    /// Who made the change.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+
+    /// Status before the change.
+    pub from_status: DecisionStatus,
+
+    /// Status after the change.
+    pub to_status: DecisionStatus,
+
+    /// Why the change was made.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl DecisionEvent {
+    /// Create an event for a status change made now.
+    pub fn new(from_status: DecisionStatus, to_status: DecisionStatus) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            user: None,
+            from_status,
+            to_status,
+            reason: None,
+        }
+    }
+}
+
 /// A decision made on a suggestion.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Decision {
@@ -75,6 +124,11 @@ pub struct Decision {
     /// Optional notes explaining the decision.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<String>,
+
+    /// Every status change of this suggestion, oldest first, including
+    /// decisions that were later deferred, reset, or reversed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub history: Vec<DecisionEvent>,
 }
 
 impl Decision {
@@ -88,6 +142,7 @@ impl Decision {
             decided_at: None,
             modifications: None,
             notes: None,
+            history: Vec::new(),
         }
     }
 
@@ -101,6 +156,7 @@ impl Decision {
             decided_at: Some(Utc::now()),
             modifications: None,
             notes: None,
+            history: Vec::new(),
         }
     }
 
@@ -114,6 +170,7 @@ impl Decision {
             decided_at: Some(Utc::now()),
             modifications: None,
             notes: Some(notes.into()),
+            history: Vec::new(),
         }
     }
 
@@ -131,6 +188,24 @@ impl Decision {
             decided_at: Some(Utc::now()),
             modifications: Some(modifications),
             notes: Some(notes.into()),
+            history: Vec::new(),
+        }
+    }
+
+    /// Create a deferral, postponing review of a suggestion.
+    pub fn defer(suggestion_id: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            id: generate_decision_id(),
+            suggestion_id: suggestion_id.into(),
+            status: DecisionStatus::Deferred {
+                reason: reason.into(),
+                until: None,
+            },
+            decided_by: None,
+            decided_at: Some(Utc::now()),
+            modifications: None,
+            notes: None,
+            history: Vec::new(),
         }
     }
 
@@ -146,10 +221,38 @@ impl Decision {
         self
     }
 
+    /// Set when a deferred suggestion should be revisited (e.g., "2024-07-01").
+    ///
+    /// Has no effect on decisions that aren't deferrals.
+    pub fn with_deferred_until(mut self, until: impl Into<String>) -> Self {
+        if let DecisionStatus::Deferred { until: slot, .. } = &mut self.status {
+            *slot = Some(until.into());
+        }
+        self
+    }
+
+    /// Why the decision was made: the deferral reason, or else the notes.
+    pub fn reason(&self) -> Option<&str> {
+        match &self.status {
+            DecisionStatus::Deferred { reason, .. } => Some(reason),
+            _ => self.notes.as_deref(),
+        }
+    }
+
+    /// Append an event recording the change from `from` to the current
+    /// status, attributed to the decision's author and reason.
+    pub fn push_event(&mut self, from: DecisionStatus) {
+        let mut event = DecisionEvent::new(from, self.status.clone());
+        event.user = self.decided_by.clone();
+        event.reason = self.reason().map(str::to_string);
+        self.history.push(event);
+    }
+
     /// Mark as applied (after export).
     pub fn mark_applied(&mut self) {
         if self.status == DecisionStatus::Accepted || self.status == DecisionStatus::Modified {
-            self.status = DecisionStatus::Applied;
+            let from = std::mem::replace(&mut self.status, DecisionStatus::Applied);
+            self.push_event(from);
         }
     }
 }
@@ -205,11 +308,46 @@ mod tests {
         assert!(!DecisionStatus::Pending.is_decided());
         assert!(DecisionStatus::Accepted.is_decided());
         assert!(DecisionStatus::Rejected.is_decided());
+        assert!(!Decision::defer("sug_004", "Later").status.is_decided());
 
         assert!(DecisionStatus::Accepted.is_approved());
         assert!(DecisionStatus::Modified.is_approved());
         assert!(!DecisionStatus::Rejected.is_approved());
         assert!(!DecisionStatus::Pending.is_approved());
+        assert!(!Decision::defer("sug_004", "Later").status.is_approved());
+    }
+
+    #[test]
+    fn test_defer_decision_round_trip() {
+        let dec = Decision::defer("sug_004", "Waiting on lab confirmation")
+            .with_deferred_until("2024-07-01");
+
+        let json = serde_json::to_value(&dec).unwrap();
+        assert_eq!(json["status"]["deferred"]["reason"], "Waiting on lab confirmation");
+        assert_eq!(json["status"]["deferred"]["until"], "2024-07-01");
+
+        let restored: Decision = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            restored.status,
+            DecisionStatus::Deferred {
+                reason: "Waiting on lab confirmation".to_string(),
+                until: Some("2024-07-01".to_string()),
+            }
+        );
+        assert_eq!(restored.reason(), Some("Waiting on lab confirmation"));
+        assert!(restored.notes.is_none());
+    }
+
+    #[test]
+    fn test_deferred_fields_default_when_absent() {
+        let status: DecisionStatus = serde_json::from_str(r#"{"deferred": {}}"#).unwrap();
+        assert_eq!(
+            status,
+            DecisionStatus::Deferred {
+                reason: String::new(),
+                until: None,
+            }
+        );
     }
 
     #[test]
@@ -217,10 +355,14 @@ mod tests {
         let mut dec = Decision::accept("sug_001");
         dec.mark_applied();
         assert_eq!(dec.status, DecisionStatus::Applied);
+        assert_eq!(dec.history.len(), 1);
+        assert_eq!(dec.history[0].from_status, DecisionStatus::Accepted);
+        assert_eq!(dec.history[0].to_status, DecisionStatus::Applied);
 
         // Rejected decisions should not become applied
         let mut rejected = Decision::reject("sug_002", "No");
         rejected.mark_applied();
         assert_eq!(rejected.status, DecisionStatus::Rejected);
+        assert!(rejected.history.is_empty());
     }
 }
@@ -3,22 +3,27 @@
 use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::crucible::{AnalysisResult, AnalysisSummary, ObservationCounts};
 use crate::error::{CrucibleError, Result};
-use crate::input::SourceMetadata;
+use crate::input::{Locale, SourceMetadata};
 use crate::schema::TableSchema;
 use crate::suggestion::Suggestion;
-use crate::validation::Observation;
+use crate::validation::{ConfidenceHistogram, Observation, Profile};
 
 use super::context::CurationContext;
-use super::decision::{Decision, DecisionStatus};
+use super::decision::{Decision, DecisionEvent, DecisionStatus};
 
 /// Current version of the crucible curation format.
 pub const CRUCIBLE_VERSION: &str = "1.0.0";
 
+/// Separator between a table name and an ID in a multi-table layer
+/// (e.g. `sheet1::sug_001`).
+pub const TABLE_ID_SEPARATOR: &str = "::";
+
 /// Counts of suggestions by decision status.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SuggestionCounts {
@@ -32,6 +37,9 @@ pub struct SuggestionCounts {
     pub rejected: usize,
     /// Applied to output.
     pub applied: usize,
+    /// Postponed for later review.
+    #[serde(default)]
+    pub deferred: usize,
 }
 
 impl SuggestionCounts {
@@ -42,10 +50,10 @@ impl SuggestionCounts {
 
     /// Total number of suggestions.
     pub fn total(&self) -> usize {
-        self.pending + self.accepted + self.modified + self.rejected + self.applied
+        self.pending + self.accepted + self.modified + self.rejected + self.applied + self.deferred
     }
 
-    /// Number of decided suggestions (not pending).
+    /// Number of decided suggestions (not pending or deferred).
     pub fn decided(&self) -> usize {
         self.accepted + self.modified + self.rejected + self.applied
     }
@@ -126,6 +134,11 @@ impl CurationSummary {
                         suggestions_by_status.pending.saturating_sub(1);
                     suggestions_by_status.applied += 1;
                 }
+                DecisionStatus::Deferred { .. } => {
+                    suggestions_by_status.pending =
+                        suggestions_by_status.pending.saturating_sub(1);
+                    suggestions_by_status.deferred += 1;
+                }
             }
         }
 
@@ -144,8 +157,25 @@ impl CurationSummary {
     }
 }
 
+/// Source and schema of one table in a multi-table curation layer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CuratedTable {
+    /// Metadata about the table's source (e.g. the worksheet read).
+    pub source: SourceMetadata,
+
+    /// Inferred schema.
+    pub schema: TableSchema,
+
+    /// Summary of the table's own analysis.
+    pub summary: AnalysisSummary,
+}
+
 /// The curation layer - captures all inferences, observations, suggestions,
 /// and decisions for a dataset.
+///
+/// A layer may cover several tables, such as the sheets of a workbook (see
+/// [`CurationLayer::from_analyses`]). Their observations, suggestions, and
+/// decisions are kept together, with IDs prefixed by the table name.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CurationLayer {
     /// Version of the crucible format.
@@ -166,6 +196,14 @@ pub struct CurationLayer {
     /// Inferred schema.
     pub schema: TableSchema,
 
+    /// Strictness profile the observations were generated under.
+    #[serde(default)]
+    pub profile: Profile,
+
+    /// Locale numbers and dates were read in.
+    #[serde(default)]
+    pub locale: Locale,
+
     /// Detected observations/issues.
     pub observations: Vec<Observation>,
 
@@ -177,13 +215,35 @@ pub struct CurationLayer {
 
     /// Summary statistics.
     pub summary: CurationSummary,
+
+    /// Source and schema of each table, by name, for a multi-table layer.
+    ///
+    /// Empty for a single table. Otherwise `source` and `schema` above are
+    /// those of the first table.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub tables: IndexMap<String, CuratedTable>,
+
+    /// History of reset decisions, by suggestion ID, carried into the next
+    /// decision on the same suggestion.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub reset_history: IndexMap<String, Vec<DecisionEvent>>,
 }
 
 impl CurationLayer {
     /// Create a new curation layer from analysis results.
-    pub fn from_analysis(result: AnalysisResult, context: CurationContext) -> Self {
+    ///
+    /// Roles chosen in answer to the analysis's questions are added to the
+    /// context's hints.
+    pub fn from_analysis(result: AnalysisResult, mut context: CurationContext) -> Self {
         let now = Utc::now();
 
+        // Keep answered questions so re-analysis doesn't ask them again
+        for question in &result.questions {
+            if let Some(role) = question.answer {
+                context.hints.column_roles.insert(question.column.clone(), role);
+            }
+        }
+
         // Create initial summary with no decisions
         let summary = CurationSummary::from_analysis(&result.summary, &result.suggestions, &[]);
 
@@ -194,23 +254,157 @@ impl CurationLayer {
             source: result.source,
             context,
             schema: result.schema,
+            profile: result.profile,
+            locale: result.locale,
             observations: result.observations,
             suggestions: result.suggestions,
             decisions: Vec::new(),
             summary,
+            tables: IndexMap::new(),
+            reset_history: IndexMap::new(),
         }
     }
 
+    /// Create one curation layer for several named tables, such as the
+    /// sheets of a workbook.
+    ///
+    /// Observation and suggestion IDs are prefixed with the table name
+    /// (`sheet1::sug_001`); those are the IDs to accept, reject, or look up.
+    /// Roles answered for a table's columns are kept under the same prefix,
+    /// so sheets sharing a column name don't overwrite each other's.
+    /// Table names must be non-empty and not contain `::`.
+    pub fn from_analyses(
+        results: IndexMap<String, AnalysisResult>,
+        context: CurationContext,
+    ) -> Result<Self> {
+        if results.is_empty() {
+            return Err(CrucibleError::Validation(
+                "A curation layer needs at least one table".to_string(),
+            ));
+        }
+        if let Some(name) = results
+            .keys()
+            .find(|name| name.is_empty() || name.contains(TABLE_ID_SEPARATOR))
+        {
+            return Err(CrucibleError::Validation(format!(
+                "Invalid table name '{}': names must be non-empty and not contain '{}'",
+                name, TABLE_ID_SEPARATOR
+            )));
+        }
+
+        let mut tables = IndexMap::new();
+        let mut observations = Vec::new();
+        let mut suggestions = Vec::new();
+        let mut questions = Vec::new();
+        let mut profile = Profile::default();
+        let mut locale = Locale::default();
+
+        for (name, result) in results {
+            let namespaced = |id: &str| format!("{}{}{}", name, TABLE_ID_SEPARATOR, id);
+            observations.extend(result.observations.into_iter().map(|mut obs| {
+                obs.id = namespaced(&obs.id);
+                obs
+            }));
+            suggestions.extend(result.suggestions.into_iter().map(|mut sug| {
+                sug.id = namespaced(&sug.id);
+                sug.observation_id = namespaced(&sug.observation_id);
+                sug
+            }));
+            questions.extend(result.questions.into_iter().map(|mut question| {
+                question.column = namespaced(&question.column);
+                question
+            }));
+            if tables.is_empty() {
+                profile = result.profile;
+                locale = result.locale;
+            }
+            tables.insert(
+                name,
+                CuratedTable {
+                    source: result.source,
+                    schema: result.schema,
+                    summary: result.summary,
+                },
+            );
+        }
+
+        let (_, first) = tables.first().expect("at least one table");
+        let combined = AnalysisResult {
+            source: first.source.clone(),
+            schema: first.schema.clone(),
+            observations,
+            suggestions,
+            constraint_report: Vec::new(),
+            llm_usage: None,
+            profile,
+            locale,
+            questions,
+            summary: AnalysisSummary::combine(tables.values().map(|t| &t.summary)),
+        };
+
+        let mut layer = Self::from_analysis(combined, context);
+        layer.tables = tables;
+        Ok(layer)
+    }
+
+    /// Names of the tables in a multi-table layer, in order.
+    ///
+    /// Empty for a single-table layer.
+    pub fn table_names(&self) -> Vec<&str> {
+        self.tables.keys().map(String::as_str).collect()
+    }
+
+    /// Split a namespaced ID into its table name and the ID within the table.
+    ///
+    /// IDs without a table prefix (from single-table layers) have no table.
+    pub fn split_table_id(id: &str) -> (Option<&str>, &str) {
+        match id.split_once(TABLE_ID_SEPARATOR) {
+            Some((table, id)) => (Some(table), id),
+            None => (None, id),
+        }
+    }
+
+    /// A single-table layer holding one table of a multi-table layer.
+    ///
+    /// The table's observations, suggestions, and decisions keep their
+    /// namespaced IDs, so decisions made on the view can be copied back.
+    /// Column roles answered for the table lose their prefix, and those of
+    /// other tables are dropped. Returns `None` for an unknown table name.
+    pub fn table(&self, name: &str) -> Option<CurationLayer> {
+        let table = self.tables.get(name)?;
+        let in_table = |id: &str| Self::split_table_id(id).0 == Some(name);
+
+        let mut layer = self.clone();
+        layer.source = table.source.clone();
+        layer.schema = table.schema.clone();
+        layer.tables = IndexMap::new();
+        layer.observations.retain(|o| in_table(&o.id));
+        layer.suggestions.retain(|s| in_table(&s.id));
+        layer.decisions.retain(|d| in_table(&d.suggestion_id));
+        layer.reset_history.retain(|id, _| in_table(id));
+        layer.context.hints.column_roles = self
+            .context
+            .hints
+            .column_roles
+            .iter()
+            .filter_map(|(column, role)| match Self::split_table_id(column) {
+                (Some(table), column) if table == name => Some((column.to_string(), *role)),
+                (Some(_), _) => None,
+                (None, column) => Some((column.to_string(), *role)),
+            })
+            .collect();
+        layer.summary =
+            CurationSummary::from_analysis(&table.summary, &layer.suggestions, &layer.decisions);
+        Some(layer)
+    }
+
     /// Accept a suggestion as-is.
     pub fn accept(&mut self, suggestion_id: &str) -> Result<&Decision> {
         self.validate_suggestion_exists(suggestion_id)?;
         self.validate_no_existing_decision(suggestion_id)?;
 
         let decision = Decision::accept(suggestion_id);
-        self.decisions.push(decision);
-        self.touch();
-
-        Ok(self.decisions.last().unwrap())
+        Ok(self.record(decision))
     }
 
     /// Accept a suggestion with a user identifier.
@@ -219,10 +413,7 @@ impl CurationLayer {
         self.validate_no_existing_decision(suggestion_id)?;
 
         let decision = Decision::accept(suggestion_id).with_decided_by(user);
-        self.decisions.push(decision);
-        self.touch();
-
-        Ok(self.decisions.last().unwrap())
+        Ok(self.record(decision))
     }
 
     /// Reject a suggestion with notes.
@@ -231,10 +422,7 @@ impl CurationLayer {
         self.validate_no_existing_decision(suggestion_id)?;
 
         let decision = Decision::reject(suggestion_id, notes);
-        self.decisions.push(decision);
-        self.touch();
-
-        Ok(self.decisions.last().unwrap())
+        Ok(self.record(decision))
     }
 
     /// Reject a suggestion with user and notes.
@@ -243,10 +431,7 @@ impl CurationLayer {
         self.validate_no_existing_decision(suggestion_id)?;
 
         let decision = Decision::reject(suggestion_id, notes).with_decided_by(user);
-        self.decisions.push(decision);
-        self.touch();
-
-        Ok(self.decisions.last().unwrap())
+        Ok(self.record(decision))
     }
 
     /// Modify a suggestion with changes and notes.
@@ -260,10 +445,7 @@ impl CurationLayer {
         self.validate_no_existing_decision(suggestion_id)?;
 
         let decision = Decision::modify(suggestion_id, modifications, notes);
-        self.decisions.push(decision);
-        self.touch();
-
-        Ok(self.decisions.last().unwrap())
+        Ok(self.record(decision))
     }
 
     /// Modify a suggestion with user, changes, and notes.
@@ -278,13 +460,37 @@ impl CurationLayer {
         self.validate_no_existing_decision(suggestion_id)?;
 
         let decision = Decision::modify(suggestion_id, modifications, notes).with_decided_by(user);
-        self.decisions.push(decision);
-        self.touch();
+        Ok(self.record(decision))
+    }
 
-        Ok(self.decisions.last().unwrap())
+    /// Defer a suggestion, postponing review with a reason.
+    ///
+    /// A deferred suggestion is no longer pending and can later be accepted,
+    /// modified, or rejected directly.
+    pub fn defer(&mut self, suggestion_id: &str, reason: &str) -> Result<&Decision> {
+        self.defer_decision(Decision::defer(suggestion_id, reason))
+    }
+
+    /// Defer a suggestion until a given date or milestone.
+    pub fn defer_until(
+        &mut self,
+        suggestion_id: &str,
+        reason: &str,
+        until: &str,
+    ) -> Result<&Decision> {
+        self.defer_decision(Decision::defer(suggestion_id, reason).with_deferred_until(until))
+    }
+
+    fn defer_decision(&mut self, decision: Decision) -> Result<&Decision> {
+        self.validate_suggestion_exists(&decision.suggestion_id)?;
+        self.validate_no_existing_decision(&decision.suggestion_id)?;
+
+        Ok(self.record(decision))
     }
 
     /// Get all pending (undecided) suggestions.
+    ///
+    /// Deferred suggestions are not pending; see [`Self::deferred_suggestions`].
     pub fn pending_suggestions(&self) -> Vec<&Suggestion> {
         let decided_ids: std::collections::HashSet<_> =
             self.decisions.iter().map(|d| &d.suggestion_id).collect();
@@ -295,6 +501,21 @@ impl CurationLayer {
             .collect()
     }
 
+    /// Get all deferred suggestions.
+    pub fn deferred_suggestions(&self) -> Vec<&Suggestion> {
+        let deferred_ids: std::collections::HashSet<_> = self
+            .decisions
+            .iter()
+            .filter(|d| d.status.is_deferred())
+            .map(|d| &d.suggestion_id)
+            .collect();
+
+        self.suggestions
+            .iter()
+            .filter(|s| deferred_ids.contains(&s.id))
+            .collect()
+    }
+
     /// Get all accepted decisions.
     pub fn accepted_decisions(&self) -> Vec<&Decision> {
         self.decisions
@@ -318,8 +539,25 @@ impl CurationLayer {
             .find(|d| d.suggestion_id == suggestion_id)
     }
 
+    /// Get every status change of a suggestion's decision, oldest first.
+    ///
+    /// Includes decisions that were deferred, reset, or reversed; empty if
+    /// the suggestion was never decided.
+    pub fn decision_history(&self, suggestion_id: &str) -> &[DecisionEvent] {
+        match self.decision_for(suggestion_id) {
+            Some(decision) => &decision.history,
+            None => self
+                .reset_history
+                .get(suggestion_id)
+                .map_or(&[], Vec::as_slice),
+        }
+    }
+
     /// Reset a decision, removing it and returning the suggestion to pending status.
     /// Returns the removed decision, or None if no decision existed.
+    ///
+    /// The decision's history, plus the reset itself, is kept and carried
+    /// into the next decision on the suggestion.
     pub fn reset(&mut self, suggestion_id: &str) -> Result<Option<Decision>> {
         self.validate_suggestion_exists(suggestion_id)?;
 
@@ -330,7 +568,10 @@ impl CurationLayer {
 
         let removed = position.map(|pos| self.decisions.remove(pos));
 
-        if removed.is_some() {
+        if let Some(decision) = &removed {
+            let mut history = decision.history.clone();
+            history.push(DecisionEvent::new(decision.status.clone(), DecisionStatus::Pending));
+            self.reset_history.insert(suggestion_id.to_string(), history);
             self.touch();
         }
 
@@ -347,9 +588,19 @@ impl CurationLayer {
         self.observations.iter().find(|o| o.id == observation_id)
     }
 
+    /// Stable fingerprint of the curated dataset (see [`AnalysisResult::fingerprint`]).
+    pub fn fingerprint(&self) -> String {
+        self.source.fingerprint(&self.schema.column_names())
+    }
+
+    /// Observation confidences bucketed into deciles, overall and per type.
+    pub fn confidence_histogram(&self) -> ConfidenceHistogram {
+        ConfidenceHistogram::from_observations(&self.observations)
+    }
+
     /// Check if all suggestions have been decided.
     pub fn is_complete(&self) -> bool {
-        self.pending_suggestions().is_empty()
+        self.pending_suggestions().is_empty() && self.deferred_suggestions().is_empty()
     }
 
     /// Get progress as a fraction (0.0 to 1.0).
@@ -357,7 +608,8 @@ impl CurationLayer {
         if self.suggestions.is_empty() {
             return 1.0;
         }
-        self.decisions.len() as f64 / self.suggestions.len() as f64
+        let decided = self.decisions.iter().filter(|d| d.status.is_decided()).count();
+        decided as f64 / self.suggestions.len() as f64
     }
 
     // Helper methods
@@ -372,8 +624,13 @@ impl CurationLayer {
         Ok(())
     }
 
+    /// A deferred suggestion may still be decided; any other decision is final
+    /// until reset.
     fn validate_no_existing_decision(&self, suggestion_id: &str) -> Result<()> {
-        if self.decision_for(suggestion_id).is_some() {
+        let existing = self
+            .decision_for(suggestion_id)
+            .filter(|d| !d.status.is_deferred());
+        if existing.is_some() {
             return Err(CrucibleError::Validation(format!(
                 "Decision already exists for suggestion '{}'",
                 suggestion_id
@@ -382,7 +639,30 @@ impl CurationLayer {
         Ok(())
     }
 
-    fn touch(&mut self) {
+    /// Store a decision, replacing any deferral of the same suggestion and
+    /// appending the status change to the suggestion's history.
+    pub(super) fn record(&mut self, mut decision: Decision) -> &Decision {
+        let deferral = self.decisions.iter().position(|d| {
+            d.suggestion_id == decision.suggestion_id && d.status.is_deferred()
+        });
+        let (from, history) = match deferral.map(|pos| self.decisions.remove(pos)) {
+            Some(deferred) => (deferred.status, deferred.history),
+            None => (
+                DecisionStatus::Pending,
+                self.reset_history
+                    .shift_remove(&decision.suggestion_id)
+                    .unwrap_or_default(),
+            ),
+        };
+        decision.history = history;
+        decision.push_event(from);
+        self.decisions.push(decision);
+        self.touch();
+
+        self.decisions.last().unwrap()
+    }
+
+    pub(super) fn touch(&mut self) {
         self.updated_at = Utc::now();
         self.update_summary();
     }
@@ -414,6 +694,11 @@ impl CurationLayer {
                         self.summary.suggestions_by_status.pending.saturating_sub(1);
                     self.summary.suggestions_by_status.applied += 1;
                 }
+                DecisionStatus::Deferred { .. } => {
+                    self.summary.suggestions_by_status.pending =
+                        self.summary.suggestions_by_status.pending.saturating_sub(1);
+                    self.summary.suggestions_by_status.deferred += 1;
+                }
             }
         }
     }
@@ -0,0 +1,113 @@
+//! Merging curation layers from parallel review sessions.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{CrucibleError, Result};
+
+use super::decision::Decision;
+use super::layer::CurationLayer;
+
+/// Two reviewers made different decisions on the same suggestion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeConflict {
+    /// ID of the suggestion both layers decided.
+    pub suggestion_id: String,
+    /// Decision from the layer being merged into.
+    pub ours: Decision,
+    /// Decision from the other layer.
+    pub theirs: Decision,
+    /// Whether the other layer's (more recent) decision was kept.
+    pub kept_theirs: bool,
+}
+
+/// Outcome of merging another curation layer's decisions.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MergeReport {
+    /// Suggestions decided only in the other layer, now added.
+    pub added: Vec<String>,
+    /// Suggestions both layers decided the same way.
+    pub unchanged: Vec<String>,
+    /// Suggestions decided differently, resolved by keeping the newer decision.
+    pub conflicts: Vec<MergeConflict>,
+    /// Decisions for suggestions this layer does not have, which were skipped.
+    pub unknown: Vec<String>,
+}
+
+impl MergeReport {
+    /// Check if any decisions conflicted.
+    pub fn has_conflicts(&self) -> bool {
+        !self.conflicts.is_empty()
+    }
+}
+
+impl CurationLayer {
+    /// Merge decisions from another review of the same dataset.
+    ///
+    /// Decisions are matched by suggestion ID. Suggestions decided only in
+    /// `other` are added; where both layers decided a suggestion differently,
+    /// the more recent decision is kept and the conflict is listed in the
+    /// report for a human to confirm.
+    ///
+    /// Both layers must have been created from the same source data, as
+    /// identified by their [fingerprint](Self::fingerprint).
+    pub fn merge(&mut self, other: &CurationLayer) -> Result<MergeReport> {
+        if self.fingerprint() != other.fingerprint() {
+            return Err(CrucibleError::Validation(format!(
+                "Cannot merge curation layers for different data: '{}' and '{}'",
+                self.source.file, other.source.file
+            )));
+        }
+
+        let mut report = MergeReport::default();
+        let ours: HashMap<&str, usize> = self
+            .decisions
+            .iter()
+            .enumerate()
+            .map(|(i, d)| (d.suggestion_id.as_str(), i))
+            .collect();
+
+        let mut replacements = Vec::new();
+        let mut additions = Vec::new();
+        for theirs in &other.decisions {
+            let id = &theirs.suggestion_id;
+            if self.suggestion(id).is_none() {
+                report.unknown.push(id.clone());
+                continue;
+            }
+
+            let Some(&index) = ours.get(id.as_str()) else {
+                report.added.push(id.clone());
+                additions.push(theirs.clone());
+                continue;
+            };
+
+            let ours = &self.decisions[index];
+            if ours.status == theirs.status {
+                report.unchanged.push(id.clone());
+                continue;
+            }
+
+            // Undated decisions count as oldest; ties keep ours
+            let kept_theirs = theirs.decided_at > ours.decided_at;
+            if kept_theirs {
+                replacements.push((index, theirs.clone()));
+            }
+            report.conflicts.push(MergeConflict {
+                suggestion_id: id.clone(),
+                ours: ours.clone(),
+                theirs: theirs.clone(),
+                kept_theirs,
+            });
+        }
+
+        for (index, decision) in replacements {
+            self.decisions[index] = decision;
+        }
+        self.decisions.extend(additions);
+        self.touch();
+
+        Ok(report)
+    }
+}
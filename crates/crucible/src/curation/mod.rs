@@ -39,12 +39,21 @@
 //! println!("Pending: {}", curation.pending_suggestions().len());
 //! ```
 
+mod carry_forward;
 mod context;
 mod decision;
 mod layer;
+mod merge;
 mod persistence;
+mod review_sheet;
 
+pub use carry_forward::CarryForwardReport;
 pub use context::{CurationContext, FileContext, InferenceConfig, UserHints};
-pub use decision::{Decision, DecisionStatus};
-pub use layer::{CurationLayer, CurationSummary, SuggestionCounts, CRUCIBLE_VERSION};
-pub use persistence::{crucible_curation_path, curation_path};
+pub use decision::{Decision, DecisionEvent, DecisionStatus};
+pub use layer::{
+    CuratedTable, CurationLayer, CurationSummary, SuggestionCounts, CRUCIBLE_VERSION,
+    TABLE_ID_SEPARATOR,
+};
+pub use merge::{MergeConflict, MergeReport};
+pub use persistence::{crucible_curation_path, curation_path, data_stem, llm_cache_path};
+pub use review_sheet::{IngestReport, SheetDecision};
@@ -188,6 +188,30 @@ fn history_directory(path: &Path) -> PathBuf {
     parent.join(format!("{}.history", stem))
 }
 
+/// Get the base name of a data file, without its format or `.gz` extension.
+///
+/// # Example
+///
+/// ```
+/// use crucible::curation::data_stem;
+///
+/// assert_eq!(data_stem("data/metadata.tsv"), "metadata");
+/// assert_eq!(data_stem("data/metadata.tsv.gz"), "metadata");
+/// ```
+pub fn data_stem(data_path: impl AsRef<Path>) -> String {
+    let data_path = data_path.as_ref();
+    let is_gz = data_path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("gz"));
+
+    let name = if is_gz {
+        data_path.with_extension("")
+    } else {
+        data_path.to_path_buf()
+    };
+    name.file_stem().unwrap_or_default().to_string_lossy().into_owned()
+}
+
 /// Generate a curation file path for a data file.
 ///
 /// # Example
@@ -200,12 +224,32 @@ fn history_directory(path: &Path) -> PathBuf {
 /// ```
 pub fn curation_path(data_path: impl AsRef<Path>) -> PathBuf {
     let data_path = data_path.as_ref();
-    let stem = data_path.file_stem().unwrap_or_default().to_string_lossy();
+    let stem = data_stem(data_path);
     let parent = data_path.parent().unwrap_or(Path::new("."));
 
     parent.join(format!("{}.curation.json", stem))
 }
 
+/// Generate an LLM response cache path for a data file.
+///
+/// The cache sits next to the default curation file.
+///
+/// # Example
+///
+/// ```
+/// use crucible::curation::llm_cache_path;
+///
+/// let path = llm_cache_path("data/metadata.tsv");
+/// assert_eq!(path.to_string_lossy(), "data/metadata.llm-cache.json");
+/// ```
+pub fn llm_cache_path(data_path: impl AsRef<Path>) -> PathBuf {
+    let data_path = data_path.as_ref();
+    let stem = data_stem(data_path);
+    let parent = data_path.parent().unwrap_or(Path::new("."));
+
+    parent.join(format!("{}.llm-cache.json", stem))
+}
+
 /// Generate a curation file path in a .crucible subdirectory.
 ///
 /// # Example
@@ -218,7 +262,7 @@ pub fn curation_path(data_path: impl AsRef<Path>) -> PathBuf {
 /// ```
 pub fn crucible_curation_path(data_path: impl AsRef<Path>) -> PathBuf {
     let data_path = data_path.as_ref();
-    let stem = data_path.file_stem().unwrap_or_default().to_string_lossy();
+    let stem = data_stem(data_path);
     let parent = data_path.parent().unwrap_or(Path::new("."));
 
     parent
@@ -240,6 +284,10 @@ mod tests {
             curation_path("test.csv").to_string_lossy(),
             "test.curation.json"
         );
+        assert_eq!(
+            curation_path("data/metadata.tsv.gz").to_string_lossy(),
+            "data/metadata.curation.json"
+        );
     }
 
     #[test]
@@ -0,0 +1,216 @@
+//! Ingesting decisions made offline in a review spreadsheet.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{CrucibleError, Result};
+
+use super::decision::{Decision, DecisionStatus};
+use super::layer::CurationLayer;
+
+/// Note for rejections made in a sheet without a reason.
+const DEFAULT_REJECT_REASON: &str = "Rejected in review sheet";
+
+/// Note for deferrals made in a sheet without a reason.
+const DEFAULT_DEFER_REASON: &str = "Deferred in review sheet";
+
+/// A decision written in a review sheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SheetDecision {
+    /// Accept the suggestion as-is.
+    Accept,
+    /// Reject the suggestion.
+    Reject,
+    /// Postpone the decision.
+    Defer,
+}
+
+impl SheetDecision {
+    /// Parse a decision cell (case-insensitive). Blank and "pending" cells
+    /// have no decision.
+    pub fn parse(cell: &str) -> std::result::Result<Option<Self>, String> {
+        match cell.trim().to_lowercase().as_str() {
+            "" | "pending" => Ok(None),
+            "accept" | "accepted" => Ok(Some(SheetDecision::Accept)),
+            "reject" | "rejected" => Ok(Some(SheetDecision::Reject)),
+            "defer" | "deferred" => Ok(Some(SheetDecision::Defer)),
+            other => Err(format!(
+                "unknown decision '{}' (use accept, reject, defer, or leave blank)",
+                other
+            )),
+        }
+    }
+
+    /// Whether an existing decision already says the same.
+    fn matches(self, status: &DecisionStatus) -> bool {
+        match self {
+            SheetDecision::Accept => status.is_approved(),
+            SheetDecision::Reject => *status == DecisionStatus::Rejected,
+            SheetDecision::Defer => status.is_deferred(),
+        }
+    }
+}
+
+/// Outcome of ingesting a review sheet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IngestReport {
+    /// Suggestions newly decided, or whose decision was changed.
+    pub recorded: Vec<String>,
+    /// Suggestions whose decision already matched the sheet, or was
+    /// already applied.
+    pub unchanged: Vec<String>,
+    /// IDs in the sheet matching no suggestion or observation.
+    pub unmatched: Vec<String>,
+    /// Observations with a decision but no suggestion to apply it to.
+    pub without_suggestions: Vec<String>,
+}
+
+impl CurationLayer {
+    /// Ingest decisions from a review sheet in CSV format.
+    ///
+    /// See [`Self::ingest_decisions`].
+    pub fn ingest_decisions_csv(&mut self, path: impl AsRef<Path>) -> Result<IngestReport> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| {
+            CrucibleError::Persistence(format!(
+                "Failed to open review sheet '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+        self.ingest_decisions(file)
+    }
+
+    /// Ingest decisions made offline in a review sheet (CSV).
+    ///
+    /// The sheet needs an `id` column, holding a suggestion ID or an
+    /// observation ID, and a `decision` column of accept, reject, or defer
+    /// (blank or "pending" rows are skipped). A `reason` (or `notes`)
+    /// column is kept as the decision notes, and a `reviewer` column as who
+    /// decided. An observation ID decides every suggestion made for it.
+    ///
+    /// Changed decisions replace earlier ones; applied decisions are kept.
+    /// IDs matching nothing are listed in the report. Malformed rows are an
+    /// error listing every one of them, and nothing is recorded.
+    pub fn ingest_decisions<R: Read>(&mut self, reader: R) -> Result<IngestReport> {
+        let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(reader);
+        let headers = reader.headers()?.clone();
+        let column = |names: &[&str]| {
+            headers
+                .iter()
+                .position(|h| names.iter().any(|n| h.trim().eq_ignore_ascii_case(n)))
+        };
+        let id_col = column(&["id", "suggestion_id", "observation_id"]);
+        let decision_col = column(&["decision"]);
+        let (Some(id_col), Some(decision_col)) = (id_col, decision_col) else {
+            return Err(CrucibleError::Validation(
+                "Review sheet needs 'id' and 'decision' columns".to_string(),
+            ));
+        };
+        let reason_col = column(&["reason", "notes"]);
+        let reviewer_col = column(&["reviewer"]);
+
+        // Read the whole sheet before recording anything. Lines are
+        // numbered as in a spreadsheet, with the header on line 1.
+        let mut rows = Vec::new();
+        let mut malformed = Vec::new();
+        for (i, record) in reader.records().enumerate() {
+            let line = i + 2;
+            let record = match record {
+                Ok(record) => record,
+                Err(e) => {
+                    malformed.push(format!("line {}: {}", line, e));
+                    continue;
+                }
+            };
+            let cell = |col: Option<usize>| {
+                col.and_then(|c| record.get(c))
+                    .map(str::trim)
+                    .unwrap_or_default()
+                    .to_string()
+            };
+
+            let decision = match SheetDecision::parse(&cell(Some(decision_col))) {
+                Ok(Some(decision)) => decision,
+                Ok(None) => continue,
+                Err(problem) => {
+                    malformed.push(format!("line {}: {}", line, problem));
+                    continue;
+                }
+            };
+            let id = cell(Some(id_col));
+            if id.is_empty() {
+                malformed.push(format!("line {}: decision without an id", line));
+                continue;
+            }
+            rows.push((id, decision, cell(reason_col), cell(reviewer_col)));
+        }
+
+        if !malformed.is_empty() {
+            return Err(CrucibleError::Validation(format!(
+                "Review sheet has {} malformed row(s): {}",
+                malformed.len(),
+                malformed.join("; ")
+            )));
+        }
+
+        let mut report = IngestReport::default();
+        for (id, decision, reason, reviewer) in rows {
+            let suggestion_ids: Vec<String> = if self.suggestion(&id).is_some() {
+                vec![id.clone()]
+            } else if self.observation(&id).is_some() {
+                self.suggestions
+                    .iter()
+                    .filter(|s| s.observation_id == id)
+                    .map(|s| s.id.clone())
+                    .collect()
+            } else {
+                report.unmatched.push(id);
+                continue;
+            };
+            if suggestion_ids.is_empty() {
+                report.without_suggestions.push(id);
+                continue;
+            }
+
+            for suggestion_id in suggestion_ids {
+                match self.decision_for(&suggestion_id).map(|d| &d.status) {
+                    Some(status) if decision.matches(status) || *status == DecisionStatus::Applied => {
+                        report.unchanged.push(suggestion_id);
+                        continue;
+                    }
+                    Some(_) => {
+                        self.reset(&suggestion_id)?;
+                    }
+                    None => {}
+                }
+
+                let mut entry = match decision {
+                    SheetDecision::Accept => Decision::accept(&suggestion_id),
+                    SheetDecision::Reject => Decision::reject(
+                        &suggestion_id,
+                        if reason.is_empty() { DEFAULT_REJECT_REASON } else { &reason },
+                    ),
+                    SheetDecision::Defer => Decision::defer(
+                        &suggestion_id,
+                        if reason.is_empty() { DEFAULT_DEFER_REASON } else { &reason },
+                    ),
+                };
+                if decision == SheetDecision::Accept && !reason.is_empty() {
+                    entry = entry.with_notes(&reason);
+                }
+                if !reviewer.is_empty() {
+                    entry = entry.with_decided_by(&reviewer);
+                }
+                self.record(entry);
+                report.recorded.push(suggestion_id);
+            }
+        }
+
+        Ok(report)
+    }
+}
@@ -46,6 +46,10 @@ pub enum CrucibleError {
     #[error("Validation error: {0}")]
     Validation(String),
 
+    /// An LLM API kept rejecting requests as rate limited or overloaded.
+    #[error("Rate limited after {retries} retries: {message}")]
+    RateLimited { retries: u32, message: String },
+
     /// Persistence error (save/load).
     #[error("Persistence error: {0}")]
     Persistence(String),
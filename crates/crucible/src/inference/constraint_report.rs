@@ -0,0 +1,185 @@
+//! Violation rates for inferred constraints.
+//!
+//! Inference may adopt a constraint even when some values don't satisfy it
+//! (e.g. a pattern matched by 98% of values). This report makes that tension
+//! explicit so users can judge whether the constraint or the violators are wrong.
+
+use std::collections::HashMap;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::input::DataTable;
+use crate::schema::{ColumnSchema, Constraint, TableSchema};
+
+/// How well the current data satisfies one inferred constraint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConstraintReport {
+    /// Column the constraint applies to.
+    pub column: String,
+    /// The inferred constraint.
+    pub constraint: Constraint,
+    /// Number of values the constraint was checked against.
+    pub checked: usize,
+    /// Number of values violating the constraint.
+    pub violations: usize,
+    /// Fraction of checked values that violate the constraint (0.0-1.0).
+    pub violation_rate: f64,
+    /// Sample row indices of violating values.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sample_rows: Vec<usize>,
+}
+
+impl ConstraintReport {
+    /// Check every inferred constraint in the schema against the table.
+    pub fn for_table(table: &DataTable, schema: &TableSchema) -> Vec<Self> {
+        schema
+            .columns
+            .iter()
+            .flat_map(|col| {
+                col.constraints
+                    .iter()
+                    .filter_map(move |constraint| Self::check(table, col, constraint))
+            })
+            .collect()
+    }
+
+    /// Whether any current value violates the constraint.
+    pub fn is_violated(&self) -> bool {
+        self.violations > 0
+    }
+
+    /// Check a single constraint against a column's values.
+    fn check(table: &DataTable, col: &ColumnSchema, constraint: &Constraint) -> Option<Self> {
+        let values: Vec<(usize, &str)> = table.column_values(col.position).enumerate().collect();
+        let non_null = || {
+            values
+                .iter()
+                .copied()
+                .filter(|(_, v)| !DataTable::is_null_value(v))
+        };
+
+        let (checked, violating): (usize, Vec<usize>) = match constraint {
+            Constraint::Pattern { value: pattern, .. } | Constraint::Regex { pattern } => {
+                let regex = Regex::new(pattern).ok()?;
+                let checked = non_null().count();
+                let violating = non_null()
+                    .filter(|(_, v)| !regex.is_match(v.trim()))
+                    .map(|(row, _)| row)
+                    .collect();
+                (checked, violating)
+            }
+            // Each item of a list must be allowed
+            Constraint::SetMembership { values: allowed, .. } => {
+                let checked = non_null().count();
+                let violating = non_null()
+                    .filter(|(_, v)| {
+                        col.inferred_type
+                            .items(v)
+                            .iter()
+                            .any(|item| !allowed.iter().any(|a| a == item))
+                    })
+                    .map(|(row, _)| row)
+                    .collect();
+                (checked, violating)
+            }
+            Constraint::Range { min, max, .. } => {
+                let numeric: Vec<(usize, f64)> = non_null()
+                    .filter_map(|(row, v)| v.trim().parse::<f64>().ok().map(|n| (row, n)))
+                    .collect();
+                let violating = numeric
+                    .iter()
+                    .filter(|(_, n)| min.is_some_and(|m| *n < m) || max.is_some_and(|m| *n > m))
+                    .map(|(row, _)| *row)
+                    .collect();
+                (numeric.len(), violating)
+            }
+            Constraint::Length { min, max, .. } => {
+                let checked = non_null().count();
+                let violating = non_null()
+                    .filter(|(_, v)| {
+                        let len = v.trim().chars().count();
+                        min.is_some_and(|m| len < m) || max.is_some_and(|m| len > m)
+                    })
+                    .map(|(row, _)| row)
+                    .collect();
+                (checked, violating)
+            }
+            Constraint::Unique { .. } => {
+                let mut seen: HashMap<&str, usize> = HashMap::new();
+                let mut violating = Vec::new();
+                let mut checked = 0;
+                for (row, v) in non_null() {
+                    checked += 1;
+                    let count = seen.entry(v.trim()).or_insert(0);
+                    *count += 1;
+                    if *count > 1 {
+                        violating.push(row);
+                    }
+                }
+                (checked, violating)
+            }
+            Constraint::NotNull { .. } => {
+                let checked = table.row_count();
+                let violating = values
+                    .iter()
+                    .filter(|(_, v)| DataTable::is_null_value(v))
+                    .map(|(row, _)| *row)
+                    .collect();
+                (checked, violating)
+            }
+        };
+
+        let violations = violating.len();
+        Some(Self {
+            column: col.name.clone(),
+            constraint: constraint.clone(),
+            checked,
+            violations,
+            violation_rate: if checked == 0 {
+                0.0
+            } else {
+                violations as f64 / checked as f64
+            },
+            sample_rows: violating.into_iter().take(5).collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern_violation_rate() {
+        let table = DataTable::new(
+            vec!["sample_id".to_string()],
+            vec![
+                vec!["S001".to_string()],
+                vec!["S002".to_string()],
+                vec!["S003".to_string()],
+                vec!["bad".to_string()],
+            ],
+            b',',
+        );
+        let mut col = ColumnSchema::new("sample_id", 0);
+        col.constraints = vec![
+            Constraint::Pattern {
+                value: r"^S\d{3}$".to_string(),
+                confidence: 0.75,
+            },
+            Constraint::NotNull { confidence: 0.9 },
+        ];
+        let schema = TableSchema::with_columns(vec![col]);
+
+        let report = ConstraintReport::for_table(&table, &schema);
+        assert_eq!(report.len(), 2);
+
+        assert!(report[0].is_violated());
+        assert_eq!(report[0].checked, 4);
+        assert_eq!(report[0].sample_rows, vec![3]);
+        assert!((report[0].violation_rate - 0.25).abs() < 1e-9);
+
+        assert!(!report[1].is_violated());
+    }
+}
@@ -1,10 +1,20 @@
 //! Inference fusion - combining statistical and semantic analysis.
 
+use std::collections::HashMap;
+
 use crate::input::DataTable;
-use crate::schema::{ColumnSchema, Constraint, SemanticRole, TableSchema};
+use crate::validation::DecimalMark;
+use crate::schema::{
+    ColumnSchema, ColumnType, Constraint, InferenceExplanation, InferenceSettings, SemanticRole,
+    SourceContribution, TableSchema, TypeCandidate,
+};
 
+use super::question::Question;
 use super::semantic::{SemanticAnalysis, SemanticAnalyzer};
-use super::statistical::{StatisticalAnalysis, StatisticalAnalyzer};
+use super::statistical::{
+    DEFAULT_CATEGORICAL_MAX_RATIO, DEFAULT_CATEGORICAL_THRESHOLD, DEFAULT_MAX_TRACKED_VALUES,
+    DEFAULT_TOP_K_VALUES, StatisticalAnalysis, StatisticalAnalyzer,
+};
 
 /// Combined inference result for a column.
 #[derive(Debug)]
@@ -26,6 +36,16 @@ pub struct FusionConfig {
     pub semantic_weight: f64,
     /// Minimum confidence threshold for constraints.
     pub constraint_threshold: f64,
+    /// Number of most frequent values kept in each column's statistics.
+    pub top_k_values: usize,
+    /// Distinct values counted per column before value tracking stops.
+    pub max_tracked_values: usize,
+    /// Most distinct values a text column can have and still be categorical
+    /// (given `expected_values`).
+    pub categorical_max_distinct: usize,
+    /// Distinct-to-value ratio above which a text column is treated as free
+    /// text or an identifier rather than categorical.
+    pub categorical_max_ratio: f64,
 }
 
 impl Default for FusionConfig {
@@ -34,6 +54,10 @@ impl Default for FusionConfig {
             statistical_weight: 0.6,
             semantic_weight: 0.4,
             constraint_threshold: 0.7,
+            top_k_values: DEFAULT_TOP_K_VALUES,
+            max_tracked_values: DEFAULT_MAX_TRACKED_VALUES,
+            categorical_max_distinct: DEFAULT_CATEGORICAL_THRESHOLD,
+            categorical_max_ratio: DEFAULT_CATEGORICAL_MAX_RATIO,
         }
     }
 }
@@ -43,6 +67,12 @@ pub struct InferenceFusion {
     statistical_analyzer: StatisticalAnalyzer,
     semantic_analyzer: SemanticAnalyzer,
     config: FusionConfig,
+    /// Types pinned by the user, by lowercased column name.
+    column_types: HashMap<String, ColumnType>,
+    /// Roles pinned by the user, by lowercased column name.
+    column_roles: HashMap<String, SemanticRole>,
+    /// Decimal mark numbers are written with.
+    decimal_mark: DecimalMark,
 }
 
 impl InferenceFusion {
@@ -52,31 +82,89 @@ impl InferenceFusion {
             statistical_analyzer: StatisticalAnalyzer::new(),
             semantic_analyzer: SemanticAnalyzer::new(),
             config: FusionConfig::default(),
+            column_types: HashMap::new(),
+            column_roles: HashMap::new(),
+            decimal_mark: DecimalMark::Point,
         }
     }
 
     /// Create with custom configuration.
     pub fn with_config(config: FusionConfig) -> Self {
         Self {
-            statistical_analyzer: StatisticalAnalyzer::new(),
+            statistical_analyzer: StatisticalAnalyzer::new()
+                .with_top_k(config.top_k_values)
+                .with_max_tracked_values(config.max_tracked_values)
+                .with_categorical_threshold(config.categorical_max_distinct)
+                .with_categorical_max_ratio(config.categorical_max_ratio),
             semantic_analyzer: SemanticAnalyzer::new(),
             config,
+            column_types: HashMap::new(),
+            column_roles: HashMap::new(),
+            decimal_mark: DecimalMark::Point,
         }
     }
 
+    /// Pin column types by name instead of inferring them.
+    ///
+    /// Names are matched case-insensitively. A pinned column is analyzed as
+    /// its given type, with full confidence and `inference_sources` of
+    /// `["user_hint"]`; validators still check its values against that type.
+    pub fn with_column_types(mut self, column_types: HashMap<String, ColumnType>) -> Self {
+        self.column_types = column_types
+            .into_iter()
+            .map(|(name, column_type)| (name.to_lowercase(), column_type))
+            .collect();
+        self
+    }
+
+    /// Pin semantic roles by name instead of inferring them.
+    ///
+    /// Names are matched case-insensitively. No question is asked about a
+    /// pinned column's role.
+    pub fn with_column_roles(mut self, column_roles: HashMap<String, SemanticRole>) -> Self {
+        self.column_roles = column_roles
+            .into_iter()
+            .map(|(name, role)| (name.to_lowercase(), role))
+            .collect();
+        self
+    }
+
+    /// Read numbers as written with this decimal mark (see
+    /// [`StatisticalAnalyzer::with_decimal_mark`]).
+    pub fn with_decimal_mark(mut self, decimal_mark: DecimalMark) -> Self {
+        self.statistical_analyzer = self.statistical_analyzer.with_decimal_mark(decimal_mark);
+        self.decimal_mark = decimal_mark;
+        self
+    }
+
     /// Analyze a table and produce a fused schema.
     pub fn analyze_table(&self, table: &DataTable) -> TableSchema {
+        self.analyze_table_with_questions(table).0
+    }
+
+    /// Analyze a table, also returning questions about near-tied roles.
+    ///
+    /// Until a question is answered, its column keeps the role ranked first.
+    pub fn analyze_table_with_questions(&self, table: &DataTable) -> (TableSchema, Vec<Question>) {
+        let mut questions = Vec::new();
         let columns: Vec<ColumnSchema> = table
             .headers
             .iter()
             .enumerate()
             .map(|(idx, name)| {
                 let fused = self.analyze_column(table, idx, name);
+                questions.extend(fused.semantic.question);
                 fused.schema
             })
             .collect();
 
-        TableSchema::with_columns(columns)
+        let mut schema = TableSchema::with_columns(columns);
+        schema.inference = Some(InferenceSettings {
+            categorical_max_distinct: self.config.categorical_max_distinct,
+            categorical_max_ratio: self.config.categorical_max_ratio,
+            decimal_mark: self.decimal_mark,
+        });
+        (schema, questions)
     }
 
     /// Analyze a single column with both analyzers and fuse results.
@@ -86,12 +174,49 @@ impl InferenceFusion {
         col_index: usize,
         col_name: &str,
     ) -> FusedInference {
-        // Run both analyzers
-        let statistical = self.statistical_analyzer.analyze_column(table, col_index);
-        let semantic = self.semantic_analyzer.analyze_column(table, col_index, col_name);
+        // Run both analyzers, taking a pinned type as given
+        let pinned = self.column_types.get(&col_name.to_lowercase()).copied();
+        let statistical = self
+            .statistical_analyzer
+            .analyze_column_as(table, col_index, pinned);
+        let mut semantic = self.semantic_analyzer.analyze_column(table, col_index, col_name);
+        let pinned_role = self.column_roles.get(&col_name.to_lowercase()).copied();
+        if pinned_role.is_some() {
+            semantic.question = None;
+        }
 
         // Fuse results into final schema
-        let schema = self.fuse_results(col_name, col_index, &statistical, &semantic);
+        let mut schema = self.fuse_results(col_name, col_index, &statistical, &semantic);
+        if pinned.is_some() {
+            schema.confidence = 1.0;
+            schema.inference_sources = vec!["user_hint".to_string()];
+        }
+        if let Some(role) = pinned_role {
+            schema.semantic_role = role;
+        }
+        if pinned.is_some() || pinned_role.is_some() {
+            let mut given = Vec::new();
+            if let Some(column_type) = pinned {
+                given.push(format!("type {:?}", column_type));
+            }
+            if let Some(role) = pinned_role {
+                given.push(format!("role {:?}", role));
+            }
+            if let Some(explanation) = schema.explanation.as_mut() {
+                if pinned.is_some() {
+                    for source in &mut explanation.sources {
+                        source.used = false;
+                    }
+                }
+                explanation.sources.push(SourceContribution {
+                    source: "user_hint".to_string(),
+                    weight: 1.0,
+                    confidence: 1.0,
+                    used: true,
+                    detail: format!("Given {}", given.join(" and ")),
+                });
+            }
+        }
 
         FusedInference {
             statistical,
@@ -118,8 +243,12 @@ impl InferenceFusion {
         let semantic_role = if semantic.confidence > 0.6 {
             semantic.semantic_role
         } else {
-            // Infer from statistical uniqueness patterns
-            if statistical.unique && !statistical.nullable {
+            // Infer from statistical uniqueness patterns; unique coordinates
+            // are still coordinates, not row identifiers
+            if statistical.unique
+                && !statistical.nullable
+                && semantic.semantic_role != SemanticRole::Coordinate
+            {
                 SemanticRole::Identifier
             } else {
                 semantic.semantic_role
@@ -161,6 +290,8 @@ impl InferenceFusion {
             inference_sources.push("semantic".to_string());
         }
 
+        let explanation = self.explain(statistical, semantic, semantic_role);
+
         ColumnSchema {
             name: col_name.to_string(),
             position: col_index,
@@ -173,9 +304,62 @@ impl InferenceFusion {
             expected_range: statistical.expected_range,
             constraints,
             statistics: statistical.statistics.clone(),
+            detected_null_tokens: statistical.detected_null_tokens.clone(),
             confidence,
             inference_sources,
             llm_insight: None, // No LLM in Phase 1
+            explanation: Some(explanation),
+        }
+    }
+
+    /// Explain how the analyzers' results were weighed for a column.
+    fn explain(
+        &self,
+        statistical: &StatisticalAnalysis,
+        semantic: &SemanticAnalysis,
+        semantic_role: SemanticRole,
+    ) -> InferenceExplanation {
+        let total: usize = statistical.type_votes.iter().map(|(_, votes)| votes).sum();
+        let candidates = statistical
+            .type_votes
+            .iter()
+            .map(|&(column_type, votes)| TypeCandidate {
+                column_type,
+                votes,
+                share: votes as f64 / total.max(1) as f64,
+            })
+            .collect();
+
+        let mut semantic_detail = format!("Role {:?}", semantic.semantic_role);
+        if !semantic.name_hints.is_empty() {
+            semantic_detail.push_str(&format!(" (name hints: {})", semantic.name_hints.join(", ")));
+        }
+        if semantic_role != semantic.semantic_role {
+            semantic_detail.push_str(&format!(
+                "; not confident enough to set the role, {:?} used from uniqueness",
+                semantic_role
+            ));
+        }
+
+        InferenceExplanation {
+            candidates,
+            sources: vec![
+                SourceContribution {
+                    source: "statistical".to_string(),
+                    weight: self.config.statistical_weight,
+                    confidence: statistical.confidence,
+                    used: true,
+                    detail: format!("Type {:?}", statistical.inferred_type),
+                },
+                SourceContribution {
+                    source: "semantic".to_string(),
+                    weight: self.config.semantic_weight,
+                    confidence: semantic.confidence,
+                    used: semantic.confidence > 0.3,
+                    detail: semantic_detail,
+                },
+            ],
+            rationale: statistical.type_rationale.clone(),
         }
     }
 
@@ -188,6 +372,7 @@ impl InferenceFusion {
             Constraint::Length { .. } => "length".to_string(),
             Constraint::Unique { .. } => "unique".to_string(),
             Constraint::NotNull { .. } => "not_null".to_string(),
+            Constraint::Regex { .. } => "regex".to_string(),
         }
     }
 
@@ -252,6 +437,67 @@ mod tests {
         assert!(!result.schema.nullable);
     }
 
+    #[test]
+    fn test_explanation() {
+        let table = make_table(
+            vec!["weight"],
+            vec![vec!["70"], vec!["82"], vec!["65.5"], vec!["90"]],
+        );
+
+        let fusion = InferenceFusion::new();
+        let result = fusion.analyze_column(&table, 0, "weight");
+        let explanation = result.schema.explanation.as_ref().unwrap();
+
+        assert_eq!(result.schema.inferred_type, ColumnType::Float);
+        assert_eq!(explanation.candidates[0].column_type, ColumnType::Integer);
+        assert_eq!(explanation.candidates[0].votes, 3);
+        assert_eq!(explanation.candidates[1].column_type, ColumnType::Float);
+        assert_eq!(explanation.candidates[1].share, 0.25);
+        assert!(explanation.rationale.contains("Float"));
+        assert_eq!(explanation.sources[0].source, "statistical");
+        assert_eq!(explanation.sources[0].weight, 0.6);
+
+        // A pinned type overrides the analyzers
+        let fusion = InferenceFusion::new()
+            .with_column_types(HashMap::from([("weight".to_string(), ColumnType::String)]));
+        let result = fusion.analyze_column(&table, 0, "weight");
+        let explanation = result.schema.explanation.as_ref().unwrap();
+        assert!(explanation.sources.iter().all(|s| s.used == (s.source == "user_hint")));
+        assert!(explanation.rationale.contains("given by the user"));
+    }
+
+    #[test]
+    fn test_categorical_thresholds() {
+        // 12 values over 6 sites: categorical by default
+        let sites = ["A", "B", "C", "D", "E", "F"];
+        let rows: Vec<Vec<&str>> = sites.iter().chain(&sites).map(|s| vec![*s]).collect();
+        let table = make_table(vec!["site"], rows);
+
+        let schema = InferenceFusion::new().analyze_table(&table);
+        assert_eq!(schema.columns[0].expected_values.as_ref().map(Vec::len), Some(6));
+        assert_eq!(
+            schema.inference.as_ref().unwrap().categorical_max_distinct,
+            DEFAULT_CATEGORICAL_THRESHOLD
+        );
+
+        // Fewer distinct values allowed
+        let config = FusionConfig {
+            categorical_max_distinct: 5,
+            ..FusionConfig::default()
+        };
+        let schema = InferenceFusion::with_config(config).analyze_table(&table);
+        assert!(schema.columns[0].expected_values.is_none());
+        assert_eq!(schema.inference.unwrap().categorical_max_distinct, 5);
+
+        // Half the values distinct is too many
+        let config = FusionConfig {
+            categorical_max_ratio: 0.4,
+            ..FusionConfig::default()
+        };
+        let schema = InferenceFusion::with_config(config).analyze_table(&table);
+        assert!(schema.columns[0].expected_values.is_none());
+    }
+
     #[test]
     fn test_fuse_categorical_column() {
         let table = make_table(
@@ -1,9 +1,16 @@
 //! Inference engine for schema detection and analysis.
 
+mod constraint_report;
 mod fusion;
+mod question;
 mod semantic;
 mod statistical;
 
+pub use constraint_report::ConstraintReport;
 pub use fusion::{FusedInference, FusionConfig, InferenceFusion};
+pub use question::Question;
 pub use semantic::{SemanticAnalysis, SemanticAnalyzer};
-pub use statistical::{StatisticalAnalysis, StatisticalAnalyzer};
+pub use statistical::{
+    DEFAULT_CATEGORICAL_MAX_RATIO, DEFAULT_CATEGORICAL_THRESHOLD, StatisticalAnalysis, StatisticalAnalyzer,
+    exceeds_distinct_ratio, is_high_cardinality,
+};
@@ -0,0 +1,126 @@
+//! Clarification questions for ambiguous inferences.
+
+use serde::{Deserialize, Serialize};
+
+use crate::llm::QuestionContext;
+use crate::schema::{ColumnSchema, SemanticRole};
+
+/// A question asked when inference can't decide between near-tied roles.
+///
+/// The role ranked first is used until the question is answered. Answers
+/// are pinned through [`ContextHints::with_column_role`](crate::ContextHints::with_column_role)
+/// and kept in the curation layer, so re-analysis doesn't ask again.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Question {
+    /// Column the question is about.
+    pub column: String,
+
+    /// The question to show.
+    pub prompt: String,
+
+    /// Candidate roles, the one used by default first.
+    pub options: Vec<SemanticRole>,
+
+    /// The chosen role, once answered.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer: Option<SemanticRole>,
+
+    /// Who answered (`"user"` or an LLM provider's name).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answered_by: Option<String>,
+}
+
+impl Question {
+    /// Ask which of several roles a column plays.
+    pub fn role(column: impl Into<String>, options: Vec<SemanticRole>) -> Self {
+        let column = column.into();
+        let labels: Vec<&str> = options.iter().map(SemanticRole::label).collect();
+        let choices = match labels.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+            _ => labels.join(""),
+        };
+        let prompt = format!("Which role does '{}' play: {}?", column, choices);
+
+        Self {
+            column,
+            prompt,
+            options,
+            answer: None,
+            answered_by: None,
+        }
+    }
+
+    /// The answered role, or the default when unanswered.
+    pub fn role_or_default(&self) -> SemanticRole {
+        self.answer
+            .or_else(|| self.options.first().copied())
+            .unwrap_or_default()
+    }
+
+    /// Match an answer to one of the options.
+    ///
+    /// Accepts a 1-based option number or an option's role label, ignoring
+    /// case, surrounding quotes and a trailing full stop. Anything else,
+    /// such as a sentence mentioning a role, matches nothing.
+    pub fn resolve(&self, answer: &str) -> Option<SemanticRole> {
+        let answer = answer
+            .trim()
+            .trim_end_matches('.')
+            .trim_matches(|c| matches!(c, '"' | '\'' | '`' | '*'))
+            .trim()
+            .to_lowercase();
+        if let Ok(n) = answer.parse::<usize>() {
+            return n.checked_sub(1).and_then(|i| self.options.get(i)).copied();
+        }
+
+        self.options.iter().find(|role| role.label() == answer).copied()
+    }
+
+    /// Record an answer.
+    pub fn record_answer(&mut self, role: SemanticRole, answered_by: impl Into<String>) {
+        self.answer = Some(role);
+        self.answered_by = Some(answered_by.into());
+    }
+
+    /// Build the context for asking an LLM instead of a person.
+    pub fn to_question_context(
+        &self,
+        column: Option<&ColumnSchema>,
+        samples: Vec<String>,
+    ) -> QuestionContext {
+        let labels: Vec<&str> = self.options.iter().map(SemanticRole::label).collect();
+        let mut context = QuestionContext::new(format!(
+            "{} Answer with only one of: {}.",
+            self.prompt,
+            labels.join(", ")
+        ))
+        .with_samples(samples);
+        if let Some(column) = column {
+            context = context.with_column(column.clone());
+        }
+        context
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_answer() {
+        let question = Question::role(
+            "event_date",
+            vec![SemanticRole::Outcome, SemanticRole::Metadata],
+        );
+        assert_eq!(question.prompt, "Which role does 'event_date' play: outcome or metadata?");
+        assert_eq!(question.resolve("2"), Some(SemanticRole::Metadata));
+        assert_eq!(question.resolve("3"), None);
+        assert_eq!(question.resolve(" Metadata."), Some(SemanticRole::Metadata));
+        assert_eq!(question.resolve("\"outcome\""), Some(SemanticRole::Outcome));
+        // A sentence naming roles is ambiguous, not a choice
+        assert_eq!(question.resolve("It is metadata, not an outcome"), None);
+        assert_eq!(question.resolve("not an outcome"), None);
+        assert_eq!(question.resolve("no idea"), None);
+        assert_eq!(question.role_or_default(), SemanticRole::Outcome);
+    }
+}
@@ -8,6 +8,8 @@ use regex::Regex;
 use crate::input::DataTable;
 use crate::schema::{Constraint, SemanticRole};
 
+use super::question::Question;
+
 // =============================================================================
 // LAZY STATIC PATTERNS
 // =============================================================================
@@ -17,6 +19,10 @@ use crate::schema::{Constraint, SemanticRole};
 /// Patterns for identifying column roles by name.
 static ROLE_PATTERNS: Lazy<Vec<(Regex, SemanticRole)>> = Lazy::new(|| {
     vec![
+        // Coordinate patterns (before identifiers, so "site_lat" isn't caught elsewhere)
+        (Regex::new(r"(?i)^(lat|lon|lng|latitude|longitude|lat_lon|latlon|coordinates)$").unwrap(), SemanticRole::Coordinate),
+        (Regex::new(r"(?i)(_lat|_lon|_lng|_latitude|_longitude|lat_lon)$").unwrap(), SemanticRole::Coordinate),
+
         // Identifier patterns
         (Regex::new(r"(?i)^(id|_id$|identifier|key|uuid|guid)").unwrap(), SemanticRole::Identifier),
         (Regex::new(r"(?i)(sample[_\s]?id|patient[_\s]?id|subject[_\s]?id|record[_\s]?id)").unwrap(), SemanticRole::Identifier),
@@ -77,11 +83,20 @@ static FORMAT_PATTERNS: Lazy<Vec<(Regex, &'static str)>> = Lazy::new(|| {
         // Gene symbols
         (Regex::new(r"^[A-Z][A-Z0-9]{1,5}$").unwrap(), "gene_symbol_candidate"),
 
+        // Decimal-degree lat/lon pair (e.g., "38.98 -77.11", "38.98N, 77.11W")
+        (Regex::new(r"^-?\d{1,3}\.\d+\s*[NSns]?\s*[,;\s]\s*-?\d{1,3}\.\d+\s*[EWew]?$").unwrap(), "lat_lon"),
+
         // Boolean-like
         (Regex::new(r"(?i)^(true|false|yes|no|y|n|t|f)$").unwrap(), "boolean"),
     ]
 });
 
+/// Confidence of a role matched from the column name.
+const NAME_ROLE_CONFIDENCE: f64 = 0.85;
+
+/// Candidate roles this close to the best one are near-tied, and asked about.
+const ROLE_TIE_MARGIN: f64 = 0.1;
+
 /// Results from semantic analysis of a column.
 #[derive(Debug, Clone)]
 pub struct SemanticAnalysis {
@@ -97,6 +112,8 @@ pub struct SemanticAnalysis {
     pub confidence: f64,
     /// Hints extracted from column name.
     pub name_hints: Vec<String>,
+    /// Question to ask when other roles are near-tied with the inferred one.
+    pub question: Option<Question>,
 }
 
 /// Performs semantic analysis on column names and values.
@@ -126,6 +143,7 @@ impl SemanticAnalyzer {
 
         // Analyze column name
         let (role_from_name, name_confidence) = self.infer_role_from_name(col_name);
+        let name_roles = self.infer_roles_from_name(col_name);
         let name_hints = self.extract_name_hints(col_name);
 
         // Analyze value patterns
@@ -133,18 +151,35 @@ impl SemanticAnalyzer {
         let detected_format = self.detect_value_format(&values);
 
         // Combine role inference
-        let (semantic_role, role_confidence) = if name_confidence > 0.7 {
-            (role_from_name, name_confidence)
-        } else {
-            // Try to infer from values
-            let role_from_values = self.infer_role_from_values(&values, &detected_format);
-            if role_from_values != SemanticRole::Unknown {
-                (role_from_values, 0.6)
-            } else {
-                (role_from_name, name_confidence)
-            }
+        let role_from_values = match self.infer_role_from_values(&values, &detected_format) {
+            SemanticRole::Unknown => None,
+            // Lat/lon pairs are unambiguous, so trust them like a name match
+            SemanticRole::Coordinate => Some((SemanticRole::Coordinate, NAME_ROLE_CONFIDENCE)),
+            role => Some((role, 0.6)),
+        };
+        let (semantic_role, role_confidence) = match role_from_values {
+            Some(from_values) if name_confidence <= 0.7 => from_values,
+            _ => (role_from_name, name_confidence),
         };
 
+        // Ask when another candidate role is about as likely
+        let mut candidates: Vec<(SemanticRole, f64)> = name_roles
+            .iter()
+            .map(|role| (*role, NAME_ROLE_CONFIDENCE))
+            .collect();
+        candidates.extend(role_from_values.filter(|(role, _)| !name_roles.contains(role)));
+        let tied: Vec<SemanticRole> = candidates
+            .iter()
+            .filter(|(role, confidence)| {
+                *role != semantic_role && role_confidence - confidence <= ROLE_TIE_MARGIN
+            })
+            .map(|(role, _)| *role)
+            .collect();
+        let question = (!tied.is_empty()).then(|| {
+            let options = std::iter::once(semantic_role).chain(tied).collect();
+            Question::role(col_name, options)
+        });
+
         // Build constraints from patterns
         let mut constraints = Vec::new();
         if let Some(ref pattern) = value_pattern {
@@ -152,6 +187,12 @@ impl SemanticAnalyzer {
                 value: pattern.clone(),
                 confidence: pattern_confidence,
             });
+            // An identifier format every value follows is worth enforcing
+            if semantic_role == SemanticRole::Identifier && pattern_confidence >= 1.0 {
+                constraints.push(Constraint::Regex {
+                    pattern: pattern.clone(),
+                });
+            }
         }
 
         // Overall confidence
@@ -164,17 +205,27 @@ impl SemanticAnalyzer {
             constraints,
             confidence,
             name_hints,
+            question,
         }
     }
 
     /// Infer semantic role from column name.
     fn infer_role_from_name(&self, name: &str) -> (SemanticRole, f64) {
+        match self.infer_roles_from_name(name).first() {
+            Some(role) => (*role, NAME_ROLE_CONFIDENCE),
+            None => (SemanticRole::Unknown, 0.0),
+        }
+    }
+
+    /// Every role whose name patterns match, in pattern order.
+    fn infer_roles_from_name(&self, name: &str) -> Vec<SemanticRole> {
+        let mut roles = Vec::new();
         for (pattern, role) in ROLE_PATTERNS.iter() {
-            if pattern.is_match(name) {
-                return (*role, 0.85);
+            if !roles.contains(role) && pattern.is_match(name) {
+                roles.push(*role);
             }
         }
-        (SemanticRole::Unknown, 0.0)
+        roles
     }
 
     /// Extract semantic hints from column name.
@@ -378,6 +429,9 @@ impl SemanticAnalyzer {
                 "boolean" => {
                     return SemanticRole::Grouping;
                 }
+                "lat_lon" => {
+                    return SemanticRole::Coordinate;
+                }
                 _ => {}
             }
         }
@@ -460,4 +514,65 @@ mod tests {
         assert_eq!(result.detected_format, Some("iso_date".to_string()));
         assert_eq!(result.semantic_role, SemanticRole::Metadata);
     }
+
+    #[test]
+    fn test_identifier_format_becomes_regex_constraint() {
+        let table = make_table(
+            vec!["sample_id"],
+            vec![vec!["IBD0001"], vec!["IBD0002"], vec!["IBD0003"]],
+        );
+        let analyzer = SemanticAnalyzer::new();
+        let result = analyzer.analyze_column(&table, 0, "sample_id");
+
+        let pattern = result.value_pattern.clone().unwrap();
+        assert!(result.constraints.contains(&Constraint::Regex { pattern }));
+
+        // Non-identifier columns only get the heuristic pattern
+        let result = analyzer.analyze_column(&table, 0, "notes");
+        assert!(!result
+            .constraints
+            .iter()
+            .any(|c| matches!(c, Constraint::Regex { .. })));
+    }
+
+    #[test]
+    fn test_coordinate_role_from_values() {
+        let table = make_table(
+            vec!["site_location"],
+            vec![
+                vec!["38.98 -77.11"],
+                vec!["39.01 -76.95"],
+                vec!["38.90, -77.04"],
+            ],
+        );
+        let analyzer = SemanticAnalyzer::new();
+        let result = analyzer.analyze_column(&table, 0, "site_location");
+
+        assert_eq!(result.detected_format, Some("lat_lon".to_string()));
+        assert_eq!(result.semantic_role, SemanticRole::Coordinate);
+    }
+
+    #[test]
+    fn test_near_tied_roles_ask_question() {
+        let table = make_table(vec!["age_group"], vec![vec!["young"], vec!["old"]]);
+        let analyzer = SemanticAnalyzer::new();
+
+        let result = analyzer.analyze_column(&table, 0, "age_group");
+        assert_eq!(result.semantic_role, SemanticRole::Grouping);
+        let question = result.question.unwrap();
+        assert_eq!(question.column, "age_group");
+        assert_eq!(question.options, vec![SemanticRole::Grouping, SemanticRole::Covariate]);
+
+        // A single matching role is not asked about
+        let result = analyzer.analyze_column(&table, 0, "diagnosis");
+        assert!(result.question.is_none());
+    }
+
+    #[test]
+    fn test_coordinate_role_from_name() {
+        let analyzer = SemanticAnalyzer::new();
+        assert_eq!(analyzer.infer_role_from_name("latitude").0, SemanticRole::Coordinate);
+        assert_eq!(analyzer.infer_role_from_name("site_lon").0, SemanticRole::Coordinate);
+        assert_eq!(analyzer.infer_role_from_name("lat_lon").0, SemanticRole::Coordinate);
+    }
 }
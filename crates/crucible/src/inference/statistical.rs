@@ -1,6 +1,6 @@
 //! Statistical analysis for column type and distribution inference.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use indexmap::IndexMap;
 use once_cell::sync::Lazy;
@@ -11,6 +11,7 @@ use crate::schema::{
     ColumnStatistics, ColumnType, Constraint, NumericStatistics,
     SemanticType, StringStatistics,
 };
+use crate::validation::{DecimalMark, parse_currency, parse_grouped_number, parse_percentage};
 
 // =============================================================================
 // LAZY STATIC PATTERNS
@@ -23,9 +24,25 @@ static DATE_PATTERNS: Lazy<Vec<Regex>> = Lazy::new(|| {
         Regex::new(r"^\d{2}/\d{2}/\d{4}").unwrap(),  // US date
         Regex::new(r"^\d{2}-\d{2}-\d{4}").unwrap(),  // European date
         Regex::new(r"^\d{4}/\d{2}/\d{2}").unwrap(),  // Alt ISO
+        Regex::new(r"^\d{2}\.\d{2}\.\d{4}").unwrap(), // European date with dots
     ]
 });
 
+/// A time of day following a date: `T09:30` or ` 09:30`.
+static TIME_COMPONENT: Lazy<Regex> = Lazy::new(|| Regex::new(r"[T ]\d{1,2}:\d{2}").unwrap());
+
+/// Minimum fraction of values that must be formatted amounts (or plain
+/// numbers) for a text column to be read as currency or percentages.
+const FORMATTED_NUMBER_FIT: f64 = 0.9;
+
+/// Values that commonly stand for missing data but aren't caught by
+/// [`DataTable::is_null_value`] (matched case-insensitively).
+const MISSING_VALUE_TOKENS: &[&str] = &[
+    "missing", "unknown", "not available", "not recorded",
+    "n.a.", "n.a", "na.", "#n/a", "#null", "undefined",
+    "-999", "-9999", "999", "9999", "-1",
+];
+
 // =============================================================================
 // STREAMING STATISTICS
 // =============================================================================
@@ -167,34 +184,145 @@ pub struct StatisticalAnalysis {
     pub statistics: ColumnStatistics,
     /// Confidence in the analysis.
     pub confidence: f64,
+    /// Non-null values detected as each type, most first.
+    pub type_votes: Vec<(ColumnType, usize)>,
+    /// Why `inferred_type` was chosen.
+    pub type_rationale: String,
     /// Detected outliers (row indices).
     pub outliers: Vec<usize>,
     /// Detected missing value patterns.
     pub missing_patterns: Vec<String>,
+    /// Values this column uses for missing data, as spelled in the data.
+    pub detected_null_tokens: Vec<String>,
+}
+
+/// Default number of most frequent values kept per column.
+pub const DEFAULT_TOP_K_VALUES: usize = 20;
+
+/// Default number of distinct values counted per column before value
+/// tracking stops.
+pub const DEFAULT_MAX_TRACKED_VALUES: usize = 10_000;
+
+/// Characters tried as item delimiters in list columns, preferred in order.
+const LIST_DELIMITERS: &[char] = &[';', '|', ','];
+
+/// Fraction of non-null values that must contain the item delimiter for a
+/// column to be a list column.
+const LIST_DELIMITER_FIT: f64 = 0.6;
+
+/// Fewest non-null values to detect a list column from.
+const MIN_LIST_VALUES: usize = 5;
+
+/// Most words an item can have; longer items are prose, not a list.
+const MAX_LIST_ITEM_WORDS: usize = 4;
+
+/// Most distinct values a text column can have and still be categorical.
+pub const DEFAULT_CATEGORICAL_THRESHOLD: usize = 20;
+
+/// Distinct-to-value ratio above which a text column is free text or an
+/// identifier rather than categorical.
+pub const DEFAULT_CATEGORICAL_MAX_RATIO: f64 = 0.9;
+
+/// Fewest values for the distinct ratio to count; a handful of values is
+/// mostly distinct anyway.
+const MIN_HIGH_CARDINALITY_VALUES: usize = 10;
+
+/// Whether nearly every one of `value_count` non-null values is distinct
+/// (more than 90%), as in free text or identifiers.
+pub fn is_high_cardinality(unique_count: usize, value_count: usize) -> bool {
+    exceeds_distinct_ratio(unique_count, value_count, DEFAULT_CATEGORICAL_MAX_RATIO)
+}
+
+/// Whether more than `ratio` of `value_count` non-null values are distinct.
+pub fn exceeds_distinct_ratio(unique_count: usize, value_count: usize, ratio: f64) -> bool {
+    value_count >= MIN_HIGH_CARDINALITY_VALUES && unique_count as f64 / value_count as f64 > ratio
 }
 
 /// Performs statistical analysis on data columns.
 pub struct StatisticalAnalyzer {
     /// Maximum unique values to consider "categorical".
     categorical_threshold: usize,
+    /// Distinct-to-value ratio above which a text column isn't categorical.
+    categorical_max_ratio: f64,
+    /// Number of most frequent values kept in the column statistics.
+    top_k: usize,
+    /// Distinct values counted before tracking stops; columns with more
+    /// keep no value counts.
+    max_tracked_values: usize,
     /// Outlier detection multiplier for IQR method.
     iqr_multiplier: f64,
     /// Z-score threshold for outlier detection.
     z_score_threshold: f64,
+    /// Decimal mark numbers are written with.
+    decimal_mark: DecimalMark,
 }
 
 impl StatisticalAnalyzer {
     /// Create a new statistical analyzer with default settings.
     pub fn new() -> Self {
         Self {
-            categorical_threshold: 20,
+            categorical_threshold: DEFAULT_CATEGORICAL_THRESHOLD,
+            categorical_max_ratio: DEFAULT_CATEGORICAL_MAX_RATIO,
+            top_k: DEFAULT_TOP_K_VALUES,
+            max_tracked_values: DEFAULT_MAX_TRACKED_VALUES,
             iqr_multiplier: 1.5,
             z_score_threshold: 3.0,
+            decimal_mark: DecimalMark::Point,
         }
     }
 
+    /// Treat text columns with at most `max_distinct` distinct values as
+    /// categorical.
+    pub fn with_categorical_threshold(mut self, max_distinct: usize) -> Self {
+        self.categorical_threshold = max_distinct;
+        self
+    }
+
+    /// Don't treat a text column as categorical when more than `ratio` of its
+    /// values are distinct (only checked for columns of ten or more values).
+    pub fn with_categorical_max_ratio(mut self, ratio: f64) -> Self {
+        self.categorical_max_ratio = ratio;
+        self
+    }
+
+    /// Keep the `k` most frequent values of each column.
+    pub fn with_top_k(mut self, k: usize) -> Self {
+        self.top_k = k;
+        self
+    }
+
+    /// Stop counting values once a column has more than `limit` distinct
+    /// values. Distinct values are still counted, but no frequencies are kept.
+    pub fn with_max_tracked_values(mut self, limit: usize) -> Self {
+        self.max_tracked_values = limit;
+        self
+    }
+
+    /// Read numbers as written with this decimal mark.
+    ///
+    /// Numbers with thousands separators (`1,234,567`, or `1.234.567` with
+    /// a decimal comma) count as Integer or Float values.
+    pub fn with_decimal_mark(mut self, decimal_mark: DecimalMark) -> Self {
+        self.decimal_mark = decimal_mark;
+        self
+    }
+
     /// Analyze a column and return statistical analysis.
     pub fn analyze_column(&self, table: &DataTable, col_index: usize) -> StatisticalAnalysis {
+        self.analyze_column_as(table, col_index, None)
+    }
+
+    /// Analyze a column, taking its type as given when `column_type` is set.
+    ///
+    /// Statistics and constraints are computed for the given type, so a
+    /// digit-only column pinned to `String` gets length statistics rather
+    /// than a numeric range. The type confidence is then 1.0.
+    pub fn analyze_column_as(
+        &self,
+        table: &DataTable,
+        col_index: usize,
+        column_type: Option<ColumnType>,
+    ) -> StatisticalAnalysis {
         let values: Vec<&str> = table.column_values(col_index).collect();
         let total_count = values.len();
 
@@ -204,21 +332,68 @@ impl StatisticalAnalyzer {
             .partition(|v| DataTable::is_null_value(v));
 
         let null_count = null_values.len();
-        let nullable = null_count > 0;
 
         // Detect missing value patterns (non-standard NA representations)
         let missing_patterns = self.detect_missing_patterns(&values);
+        let null_tokens = self.detect_null_tokens(&non_null_values);
+        let null_token_count: usize = null_tokens.iter().map(|(_, count)| count).sum();
+        let nullable = null_count + null_token_count > 0;
 
-        // Get unique non-null values
+        // Count non-null values; past the tracking limit only distinct values
+        // are counted, without copying them
         let mut value_counts: IndexMap<String, usize> = IndexMap::new();
+        let mut untracked: HashSet<&str> = HashSet::new();
         for v in &non_null_values {
-            *value_counts.entry(v.to_string()).or_insert(0) += 1;
+            if let Some(count) = value_counts.get_mut(**v) {
+                *count += 1;
+            } else if value_counts.len() < self.max_tracked_values {
+                value_counts.insert(v.to_string(), 1);
+            } else {
+                untracked.insert(**v);
+            }
         }
-        let unique_count = value_counts.len();
+        let unique_count = value_counts.len() + untracked.len();
         let unique = unique_count == non_null_values.len() && !non_null_values.is_empty();
 
-        // Infer type
-        let (inferred_type, type_confidence) = self.infer_type(&non_null_values);
+        // Infer type, unless it was given
+        let type_votes = self.type_votes(&non_null_values);
+        let (inferred_type, type_confidence, type_rationale) = match column_type {
+            Some(column_type) => (
+                column_type,
+                1.0,
+                format!("Type {:?} was given by the user", column_type),
+            ),
+            None => self.infer_type(&type_votes, non_null_values.len()),
+        };
+
+        // Text made of delimited items is a list column
+        let (inferred_type, type_rationale) = match (column_type, inferred_type) {
+            (None, ColumnType::String) => match self.detect_list_delimiter(&non_null_values) {
+                Some((item_delimiter, lists)) => (
+                    ColumnType::List { item_delimiter },
+                    format!(
+                        "{} of {} values are lists of items separated by '{}'",
+                        lists,
+                        non_null_values.len(),
+                        item_delimiter
+                    ),
+                ),
+                None => (inferred_type, type_rationale),
+            },
+            _ => (inferred_type, type_rationale),
+        };
+
+        // List columns are categorical over their items, not the combinations
+        let item_counts: Option<IndexMap<&str, usize>> = inferred_type.item_delimiter().map(|_| {
+            let mut counts = IndexMap::new();
+            for value in &non_null_values {
+                for item in inferred_type.items(value) {
+                    *counts.entry(item).or_insert(0) += 1;
+                }
+            }
+            counts
+        });
+        let distinct_count = item_counts.as_ref().map_or(unique_count, IndexMap::len);
 
         // Compute statistics based on type
         let (numeric_stats, string_stats) = self.compute_statistics(&non_null_values, inferred_type);
@@ -232,16 +407,19 @@ impl StatisticalAnalyzer {
         // Determine semantic type and constraints
         let semantic_type = self.infer_semantic_type(
             inferred_type,
-            unique_count,
+            distinct_count,
             total_count,
             &value_counts,
             &numeric_stats,
         );
 
         let expected_values = if matches!(semantic_type, SemanticType::Categorical | SemanticType::Binary)
-            && unique_count <= self.categorical_threshold
+            && distinct_count <= self.categorical_threshold
         {
-            let values: Vec<String> = value_counts.keys().cloned().collect();
+            let values: Vec<String> = match &item_counts {
+                Some(items) => items.keys().map(|item| item.to_string()).collect(),
+                None => value_counts.keys().cloned().collect(),
+            };
             constraints.push(Constraint::SetMembership {
                 values: values.clone(),
                 confidence: 0.9,
@@ -277,17 +455,25 @@ impl StatisticalAnalyzer {
             .cloned()
             .collect();
 
+        // Keep the most frequent values; the sort is stable, so ties keep
+        // first-seen order
+        let top_values = if untracked.is_empty() {
+            let mut top_values = value_counts.clone();
+            top_values.sort_by(|_, a, _, b| b.cmp(a));
+            top_values.truncate(self.top_k);
+            Some(top_values)
+        } else {
+            None
+        };
+
         // Build statistics
         let statistics = ColumnStatistics {
             count: total_count,
             null_count,
+            null_token_count,
             unique_count,
             sample_values,
-            value_counts: if unique_count <= self.categorical_threshold * 2 {
-                Some(value_counts)
-            } else {
-                None
-            },
+            value_counts: top_values,
             numeric: numeric_stats,
             string: string_stats,
         };
@@ -302,48 +488,158 @@ impl StatisticalAnalyzer {
             constraints,
             statistics,
             confidence: type_confidence,
+            type_votes,
+            type_rationale,
             outliers,
             missing_patterns,
+            detected_null_tokens: null_tokens.into_iter().map(|(token, _)| token).collect(),
         }
     }
 
-    /// Infer the data type from values.
-    fn infer_type(&self, values: &[&&str]) -> (ColumnType, f64) {
-        if values.is_empty() {
-            return (ColumnType::Unknown, 0.0);
+    /// Detect a delimiter separating items in most values, as in
+    /// `antibiotic;probiotic`.
+    ///
+    /// At least [`LIST_DELIMITER_FIT`] of the values must contain the
+    /// delimiter, every item must be a short, non-numeric token, and there
+    /// must be fewer distinct items than distinct values, so items recur
+    /// across combinations. Returns the delimiter and how many values
+    /// contain it.
+    fn detect_list_delimiter(&self, values: &[&&str]) -> Option<(char, usize)> {
+        if values.len() < MIN_LIST_VALUES {
+            return None;
         }
 
-        let mut type_counts = HashMap::new();
+        // Reversed so ties go to the earlier, more usual delimiter
+        LIST_DELIMITERS
+            .iter()
+            .rev()
+            .filter_map(|&delimiter| {
+                let mut lists = 0;
+                let mut items = HashSet::new();
+                let mut distinct_values = HashSet::new();
+                for value in values {
+                    if value.contains(delimiter) {
+                        lists += 1;
+                    }
+                    distinct_values.insert(**value);
+                    for item in value.split(delimiter).map(str::trim) {
+                        if item.is_empty()
+                            || item.parse::<f64>().is_ok()
+                            || item.split_whitespace().count() > MAX_LIST_ITEM_WORDS
+                        {
+                            return None;
+                        }
+                        items.insert(item);
+                    }
+                }
+
+                let fits = lists as f64 / values.len() as f64 >= LIST_DELIMITER_FIT
+                    && items.len() < distinct_values.len();
+                fits.then_some((delimiter, lists))
+            })
+            .max_by_key(|&(_, lists)| lists)
+    }
 
+    /// Count the values detected as each type, most common first.
+    ///
+    /// Ties keep the order types are first seen in.
+    fn type_votes(&self, values: &[&&str]) -> Vec<(ColumnType, usize)> {
+        let grouped = !self.is_short_item_lists(values);
+        let mut type_counts: IndexMap<ColumnType, usize> = IndexMap::new();
         for &value in values {
-            let detected = self.detect_value_type(value);
-            *type_counts.entry(detected).or_insert(0usize) += 1;
+            *type_counts.entry(self.detect_value_type(value, grouped)).or_insert(0) += 1;
         }
 
-        // Find the most common type
-        let total = values.len() as f64;
-        let (best_type, count) = type_counts
-            .iter()
-            .max_by_key(|&(_, count)| *count)
-            .map(|(t, c)| (*t, *c))
-            .unwrap_or((ColumnType::String, 0));
+        let mut votes: Vec<(ColumnType, usize)> = type_counts.into_iter().collect();
+        votes.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        votes
+    }
 
-        let confidence = count as f64 / total;
+    /// Infer the data type from per-value type votes over `total` values,
+    /// with its confidence and the reason it was chosen.
+    fn infer_type(&self, votes: &[(ColumnType, usize)], total: usize) -> (ColumnType, f64, String) {
+        let Some(&(best_type, count)) = votes.first() else {
+            return (ColumnType::Unknown, 0.0, "No non-null values to infer a type from".to_string());
+        };
+        let votes_for = |column_type| {
+            votes
+                .iter()
+                .find(|(t, _)| *t == column_type)
+                .map_or(0, |&(_, count)| count)
+        };
+
+        let confidence = count as f64 / total as f64;
 
         // Special handling: if all integers, but some could be floats, stay integer
         // If mostly integers with some floats, promote to float
         if best_type == ColumnType::Integer {
-            let float_count = type_counts.get(&ColumnType::Float).unwrap_or(&0);
-            if *float_count > 0 {
-                return (ColumnType::Float, confidence * 0.95);
+            let float_count = votes_for(ColumnType::Float);
+            if float_count > 0 {
+                return (
+                    ColumnType::Float,
+                    confidence * 0.95,
+                    format!(
+                        "Most values are integers ({} of {}), but {} have decimals, so the column is Float",
+                        count, total, float_count
+                    ),
+                );
             }
         }
 
-        (best_type, confidence)
+        // Dates with and without a time are the same column; the more
+        // common of the two decides which
+        if matches!(best_type, ColumnType::Date | ColumnType::DateTime) {
+            let dates = votes_for(ColumnType::Date);
+            let datetimes = votes_for(ColumnType::DateTime);
+            let column_type = if datetimes >= dates {
+                ColumnType::DateTime
+            } else {
+                ColumnType::Date
+            };
+            return (
+                column_type,
+                (dates + datetimes) as f64 / total as f64,
+                format!(
+                    "{} of {} values are dates, {} of them with a time, so the column is {:?}",
+                    dates + datetimes,
+                    total,
+                    datetimes,
+                    column_type
+                ),
+            );
+        }
+
+        let rationale = match votes.get(1) {
+            Some(&(runner_up, runner_up_count)) => format!(
+                "{:?} is the most common value type ({} of {}), ahead of {:?} ({})",
+                best_type, count, total, runner_up, runner_up_count
+            ),
+            None => format!("All {} values are {:?}", total, best_type),
+        };
+        (best_type, confidence, rationale)
+    }
+
+    /// Whether every value is a comma-joined list of numbers of up to three
+    /// digits, like `101,202,303`.
+    ///
+    /// Such a column is more likely a list of codes than of numbers with
+    /// thousands separators, so commas aren't read as grouping in it. Only
+    /// applies with a decimal point, since lists aren't joined with points.
+    fn is_short_item_lists(&self, values: &[&&str]) -> bool {
+        self.decimal_mark == DecimalMark::Point
+            && !values.is_empty()
+            && values.iter().all(|value| {
+                let value = value.trim();
+                value.contains(',')
+                    && value.split(',').all(|item| {
+                        (1..=3).contains(&item.len()) && item.bytes().all(|b| b.is_ascii_digit())
+                    })
+            })
     }
 
-    /// Detect the type of a single value.
-    fn detect_value_type(&self, value: &str) -> ColumnType {
+    /// Detect the type of a single value, reading thousands separators
+    /// when `grouped` is set.
+    fn detect_value_type(&self, value: &str, grouped: bool) -> ColumnType {
         let trimmed = value.trim();
 
         // Boolean check
@@ -360,6 +656,16 @@ impl StatisticalAnalyzer {
             }
         }
 
+        // Numbers with thousands separators, checked first so that with a
+        // decimal comma `1.234` is a thousand and more
+        if let Some(number) = parse_grouped_number(trimmed, self.decimal_mark).filter(|_| grouped) {
+            return if number.parse::<i64>().is_ok() {
+                ColumnType::Integer
+            } else {
+                ColumnType::Float
+            };
+        }
+
         // Integer check
         if trimmed.parse::<i64>().is_ok() {
             return ColumnType::Integer;
@@ -372,7 +678,7 @@ impl StatisticalAnalyzer {
 
         // Date/DateTime check
         if self.looks_like_date(trimmed) {
-            if trimmed.contains(':') || trimmed.contains('T') {
+            if TIME_COMPONENT.is_match(trimmed) {
                 return ColumnType::DateTime;
             }
             return ColumnType::Date;
@@ -381,6 +687,13 @@ impl StatisticalAnalyzer {
         ColumnType::String
     }
 
+    /// Parse a number, reading thousands separators with the decimal mark.
+    fn parse_number(&self, value: &str) -> Option<f64> {
+        parse_grouped_number(value, self.decimal_mark)
+            .and_then(|number| number.parse().ok())
+            .or_else(|| value.parse().ok())
+    }
+
     /// Check if a value looks like a date.
     fn looks_like_date(&self, value: &str) -> bool {
         // Use pre-compiled static patterns
@@ -397,7 +710,7 @@ impl StatisticalAnalyzer {
             ColumnType::Integer | ColumnType::Float => {
                 let numeric_values: Vec<f64> = values
                     .iter()
-                    .filter_map(|v| v.parse::<f64>().ok())
+                    .filter_map(|v| self.parse_number(v))
                     .collect();
 
                 if numeric_values.is_empty() {
@@ -407,7 +720,7 @@ impl StatisticalAnalyzer {
                 let stats = self.compute_numeric_stats(&numeric_values);
                 (Some(stats), None)
             }
-            ColumnType::String => {
+            ColumnType::String | ColumnType::List { .. } => {
                 let lengths: Vec<usize> = values.iter().map(|v| v.len()).collect();
                 if lengths.is_empty() {
                     return (None, None);
@@ -461,7 +774,7 @@ impl StatisticalAnalyzer {
         column_type: ColumnType,
         unique_count: usize,
         total_count: usize,
-        _value_counts: &IndexMap<String, usize>,
+        value_counts: &IndexMap<String, usize>,
         numeric_stats: &Option<NumericStatistics>,
     ) -> SemanticType {
         match column_type {
@@ -489,13 +802,22 @@ impl StatisticalAnalyzer {
                 SemanticType::Continuous
             }
             ColumnType::String => {
+                // Money or percentages written as text
+                if let Some(formatted) = self.infer_formatted_number(value_counts) {
+                    return formatted;
+                }
+
                 // Binary if exactly 2 unique values
                 if unique_count == 2 {
                     return SemanticType::Binary;
                 }
 
-                // Categorical if low cardinality
-                if unique_count <= self.categorical_threshold {
+                // Categorical if low cardinality, unless nearly every value
+                // is distinct
+                let value_count: usize = value_counts.values().sum();
+                if unique_count <= self.categorical_threshold
+                    && !exceeds_distinct_ratio(unique_count, value_count, self.categorical_max_ratio)
+                {
                     return SemanticType::Categorical;
                 }
 
@@ -509,10 +831,53 @@ impl StatisticalAnalyzer {
             ColumnType::DateTime | ColumnType::Date | ColumnType::Time => {
                 SemanticType::Continuous
             }
+            // `unique_count` counts distinct items for lists
+            ColumnType::List { .. } => {
+                if unique_count <= self.categorical_threshold {
+                    SemanticType::Categorical
+                } else {
+                    SemanticType::FreeText
+                }
+            }
             ColumnType::Unknown => SemanticType::Unknown,
         }
     }
 
+    /// Detect a text column of currency amounts or percentages.
+    ///
+    /// Most values (see [`FORMATTED_NUMBER_FIT`]) must be formatted amounts or
+    /// plain numbers, and at least half must carry the symbol, so free text
+    /// with the odd `$` or `%` isn't mistaken for numbers.
+    fn infer_formatted_number(&self, value_counts: &IndexMap<String, usize>) -> Option<SemanticType> {
+        let total: usize = value_counts.values().sum();
+        if total == 0 {
+            return None;
+        }
+        let plain: usize = value_counts
+            .iter()
+            .filter(|(value, _)| value.trim().parse::<f64>().is_ok())
+            .map(|(_, count)| count)
+            .sum();
+
+        let fits = |parse: fn(&str) -> Option<String>| {
+            let formatted: usize = value_counts
+                .iter()
+                .filter(|(value, _)| parse(value).is_some())
+                .map(|(_, count)| count)
+                .sum();
+            (formatted + plain) as f64 / total as f64 >= FORMATTED_NUMBER_FIT
+                && formatted * 2 >= total
+        };
+
+        if fits(parse_currency) {
+            Some(SemanticType::Currency)
+        } else if fits(parse_percentage) {
+            Some(SemanticType::Percentage)
+        } else {
+            None
+        }
+    }
+
     /// Detect outliers using IQR and z-score methods.
     fn detect_outliers(
         &self,
@@ -530,7 +895,7 @@ impl StatisticalAnalyzer {
                 continue;
             }
 
-            if let Ok(num) = value.parse::<f64>() {
+            if let Some(num) = self.parse_number(value) {
                 // IQR method
                 if stats.is_outlier_iqr(num, self.iqr_multiplier) {
                     outliers.push(idx);
@@ -552,16 +917,9 @@ impl StatisticalAnalyzer {
         let mut patterns = Vec::new();
         let mut pattern_counts: HashMap<String, usize> = HashMap::new();
 
-        // Common missing value indicators that might not be caught by is_null_value
-        let suspicious_patterns = [
-            "missing", "unknown", "not available", "not recorded",
-            "n.a.", "n.a", "na.", "#n/a", "#null", "undefined",
-            "-999", "-9999", "999", "9999", "-1",
-        ];
-
         for &value in values {
             let lower = value.trim().to_lowercase();
-            for &pattern in &suspicious_patterns {
+            for &pattern in MISSING_VALUE_TOKENS {
                 if lower == pattern {
                     *pattern_counts.entry(pattern.to_string()).or_insert(0) += 1;
                 }
@@ -577,6 +935,42 @@ impl StatisticalAnalyzer {
 
         patterns
     }
+
+    /// Detect the spellings this column uses for missing data, most common first.
+    ///
+    /// A token must appear at least twice. Numeric sentinels such as `-999`
+    /// only count when they fall outside the range of the column's other
+    /// numbers, so a real `-1` among negative values is kept.
+    fn detect_null_tokens(&self, non_null_values: &[&&str]) -> Vec<(String, usize)> {
+        let mut token_counts: IndexMap<String, usize> = IndexMap::new();
+        for value in non_null_values {
+            let trimmed = value.trim();
+            if MISSING_VALUE_TOKENS.contains(&trimmed.to_lowercase().as_str()) {
+                *token_counts.entry(trimmed.to_string()).or_insert(0) += 1;
+            }
+        }
+        token_counts.retain(|_, count| *count >= 2);
+        if token_counts.is_empty() {
+            return Vec::new();
+        }
+
+        // Range of the numbers that aren't candidate tokens
+        let (min, max) = non_null_values
+            .iter()
+            .filter(|v| !token_counts.contains_key(v.trim()))
+            .filter_map(|v| v.trim().parse::<f64>().ok())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), n| {
+                (lo.min(n), hi.max(n))
+            });
+        token_counts.retain(|token, _| match token.parse::<f64>() {
+            Ok(n) => min > max || n < min || n > max,
+            Err(_) => true,
+        });
+
+        let mut tokens: Vec<(String, usize)> = token_counts.into_iter().collect();
+        tokens.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        tokens
+    }
 }
 
 impl Default for StatisticalAnalyzer {
@@ -623,6 +1017,30 @@ mod tests {
         assert_eq!(result.inferred_type, ColumnType::Float);
     }
 
+    #[test]
+    fn test_infer_datetime_type() {
+        let table = make_table(
+            vec!["collected_at"],
+            vec![
+                vec!["2024-01-15T09:30:00Z"],
+                vec!["2024-01-16 14:05"],
+                vec!["2024-01-17T08:00:00+02:00"],
+                vec!["2024-01-18"],
+            ],
+        );
+        let analyzer = StatisticalAnalyzer::new();
+        let result = analyzer.analyze_column(&table, 0);
+
+        assert_eq!(result.inferred_type, ColumnType::DateTime);
+        assert_eq!(result.confidence, 1.0);
+
+        let dates = make_table(
+            vec!["visit"],
+            vec![vec!["2024-01-15"], vec!["2024-01-16"], vec!["2024-01-17T10:00"]],
+        );
+        assert_eq!(analyzer.analyze_column(&dates, 0).inferred_type, ColumnType::Date);
+    }
+
     #[test]
     fn test_detect_categorical() {
         let table = make_table(
@@ -636,6 +1054,95 @@ mod tests {
         assert!(result.expected_values.is_some());
     }
 
+    #[test]
+    fn test_detect_list_column() {
+        let table = make_table(
+            vec!["medication"],
+            vec![
+                vec!["antibiotic;probiotic"],
+                vec!["steroid; antibiotic"],
+                vec!["probiotic"],
+                vec!["antibiotic;steroid"],
+                vec!["probiotic;steroid"],
+            ],
+        );
+        let result = StatisticalAnalyzer::new().analyze_column(&table, 0);
+
+        assert_eq!(result.inferred_type, ColumnType::List { item_delimiter: ';' });
+        assert_eq!(result.semantic_type, SemanticType::Categorical);
+        assert_eq!(
+            result.expected_values.unwrap(),
+            vec!["antibiotic", "probiotic", "steroid"]
+        );
+
+        // Prose and numbers with commas aren't lists
+        let table = make_table(
+            vec!["notes"],
+            vec![
+                vec!["sample arrived late, stored overnight in the fridge"],
+                vec!["ok, repeat"],
+                vec!["ok, repeat"],
+                vec!["1,250"],
+                vec!["hemolyzed"],
+            ],
+        );
+        let result = StatisticalAnalyzer::new().analyze_column(&table, 0);
+        assert_eq!(result.inferred_type, ColumnType::String);
+    }
+
+    #[test]
+    fn test_high_cardinality_is_not_categorical() {
+        let notes = [
+            "fasting", "late", "fasting", "hemolyzed", "repeat", "ok", "clotted", "cold",
+            "spilled", "relabeled", "delayed", "short",
+        ];
+        let table = make_table(vec!["notes"], notes.iter().map(|n| vec![*n]).collect());
+        let result = StatisticalAnalyzer::new().analyze_column(&table, 0);
+
+        // 11 distinct in 12 values: free text, with no expected values to check
+        assert_eq!(result.semantic_type, SemanticType::FreeText);
+        assert!(result.expected_values.is_none());
+        assert!(!result
+            .constraints
+            .iter()
+            .any(|c| matches!(c, Constraint::SetMembership { .. })));
+
+        assert!(is_high_cardinality(11, 12));
+        assert!(!is_high_cardinality(9, 9));
+        assert!(!is_high_cardinality(18, 20));
+    }
+
+    #[test]
+    fn test_top_k_value_counts() {
+        let table = make_table(
+            vec!["site"],
+            vec![
+                vec!["gut"],
+                vec!["oral"],
+                vec!["skin"],
+                vec!["oral"],
+                vec!["skin"],
+                vec!["oral"],
+                vec!["NA"],
+            ],
+        );
+
+        let result = StatisticalAnalyzer::new().with_top_k(2).analyze_column(&table, 0);
+        let counts = result.statistics.value_counts.unwrap();
+        assert_eq!(
+            counts.into_iter().collect::<Vec<_>>(),
+            vec![("oral".to_string(), 3), ("skin".to_string(), 2)]
+        );
+        assert_eq!(result.statistics.unique_count, 3);
+
+        // Past the tracking limit only distinct values are counted
+        let result = StatisticalAnalyzer::new()
+            .with_max_tracked_values(2)
+            .analyze_column(&table, 0);
+        assert!(result.statistics.value_counts.is_none());
+        assert_eq!(result.statistics.unique_count, 3);
+    }
+
     #[test]
     fn test_detect_nulls() {
         let table = make_table(
@@ -648,4 +1155,99 @@ mod tests {
         assert!(result.nullable);
         assert_eq!(result.statistics.null_count, 2);
     }
+
+    #[test]
+    fn test_detect_currency_and_percentage() {
+        let table = make_table(
+            vec!["price", "gc_content", "notes"],
+            vec![
+                vec!["$1,234.56", "45%", "costs $5 per tube"],
+                vec!["$12", "12.5 %", "about 10% lower"],
+                vec!["-$3.50", "100%", "fine"],
+                vec!["40", "0.3%", "$4"],
+                vec!["$980", "7%", "ok"],
+            ],
+        );
+        let analyzer = StatisticalAnalyzer::new();
+
+        let price = analyzer.analyze_column(&table, 0);
+        assert_eq!(price.inferred_type, ColumnType::String);
+        assert_eq!(price.semantic_type, SemanticType::Currency);
+        assert_eq!(
+            analyzer.analyze_column(&table, 1).semantic_type,
+            SemanticType::Percentage
+        );
+        // Free text mentioning money or percents isn't a number column
+        assert_ne!(analyzer.analyze_column(&table, 2).semantic_type, SemanticType::Currency);
+    }
+
+    #[test]
+    fn test_detect_grouped_numbers() {
+        let table = make_table(
+            vec!["read_count", "weight"],
+            vec![
+                vec!["1,234,567", "1.234,5"],
+                vec!["980", "12,5"],
+                vec!["12,000", "980,25"],
+                vec!["45,312", "2.000,0"],
+            ],
+        );
+
+        let analyzer = StatisticalAnalyzer::new();
+        let reads = analyzer.analyze_column(&table, 0);
+        assert_eq!(reads.inferred_type, ColumnType::Integer);
+        assert_eq!(reads.statistics.numeric.as_ref().unwrap().max, 1_234_567.0);
+        // Decimal commas aren't numbers unless the locale says so
+        assert_eq!(analyzer.analyze_column(&table, 1).inferred_type, ColumnType::String);
+
+        let analyzer = StatisticalAnalyzer::new().with_decimal_mark(DecimalMark::Comma);
+        let weight = analyzer.analyze_column(&table, 1);
+        assert_eq!(weight.inferred_type, ColumnType::Float);
+        assert_eq!(weight.statistics.numeric.as_ref().unwrap().max, 2000.0);
+    }
+
+    #[test]
+    fn test_comma_joined_codes_are_not_grouped_numbers() {
+        let table = make_table(
+            vec!["sample_ids", "reads"],
+            vec![
+                vec!["101,202,303", "1,234"],
+                vec!["404,505", "1,000,000"],
+                vec!["606,707", "980"],
+            ],
+        );
+
+        let analyzer = StatisticalAnalyzer::new();
+        assert_eq!(analyzer.analyze_column(&table, 0).inferred_type, ColumnType::String);
+        assert_eq!(analyzer.analyze_column(&table, 1).inferred_type, ColumnType::Integer);
+    }
+
+    #[test]
+    fn test_detect_null_tokens_per_column() {
+        let table = make_table(
+            vec!["age", "smoker", "change"],
+            vec![
+                vec!["34", "yes", "-1"],
+                vec!["-999", "Unknown", "2"],
+                vec!["51", "no", "-3"],
+                vec!["-999", "Unknown", "-1"],
+                vec!["-999", "no", "4"],
+            ],
+        );
+        let analyzer = StatisticalAnalyzer::new();
+
+        let age = analyzer.analyze_column(&table, 0);
+        assert_eq!(age.detected_null_tokens, vec!["-999"]);
+        assert_eq!(age.statistics.null_count, 0);
+        assert_eq!(age.statistics.null_token_count, 3);
+        assert!(age.nullable);
+
+        let smoker = analyzer.analyze_column(&table, 1);
+        assert_eq!(smoker.detected_null_tokens, vec!["Unknown"]);
+
+        // -1 sits inside the range of the other values, so it's real data
+        let change = analyzer.analyze_column(&table, 2);
+        assert!(change.detected_null_tokens.is_empty());
+        assert_eq!(change.statistics.null_token_count, 0);
+    }
 }
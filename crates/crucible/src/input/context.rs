@@ -1,9 +1,69 @@
 //! Context hints for LLM-enhanced analysis.
 
 use std::collections::HashMap;
+use std::path::Path;
 
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
+use crate::error::{CrucibleError, Result};
+use crate::schema::{ColumnType, Constraint, SemanticRole, TableSchema};
+use crate::transform::DateOrder;
+use crate::validation::{CanonicalLabel, DecimalMark, ObservationType, Severity};
+
+/// Conventions a dataset's numbers and dates are written in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Locale {
+    /// `1,234.56` and `MM/DD/YYYY`.
+    #[default]
+    EnUs,
+    /// `1.234,56` and `DD.MM.YYYY` (or `DD/MM/YYYY`).
+    EuDe,
+}
+
+impl Locale {
+    /// The decimal mark numbers are written with.
+    pub fn decimal_mark(self) -> DecimalMark {
+        match self {
+            Locale::EnUs => DecimalMark::Point,
+            Locale::EuDe => DecimalMark::Comma,
+        }
+    }
+
+    /// Which part of a numeric date like `03/04/2024` is the day.
+    pub fn date_order(self) -> DateOrder {
+        match self {
+            Locale::EnUs => DateOrder::MonthFirst,
+            Locale::EuDe => DateOrder::DayFirst,
+        }
+    }
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Locale::EnUs => write!(f, "en-US"),
+            Locale::EuDe => write!(f, "de-DE"),
+        }
+    }
+}
+
+impl std::str::FromStr for Locale {
+    type Err = CrucibleError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().replace('_', "-").as_str() {
+            "en-us" | "en" | "us" => Ok(Locale::EnUs),
+            "de-de" | "de" => Ok(Locale::EuDe),
+            _ => Err(CrucibleError::Config(format!(
+                "Unknown locale: {}. Use en-US or de-DE.",
+                s
+            ))),
+        }
+    }
+}
+
 /// User-provided and file-derived context hints for LLM enhancement.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ContextHints {
@@ -38,6 +98,65 @@ pub struct ContextHints {
     /// Source of the data (e.g., "extracted from RISK_CCFA.rds").
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data_source: Option<String>,
+
+    /// First date of the study's sample collection window.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub study_start: Option<NaiveDate>,
+
+    /// Last date of the study's sample collection window.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub study_end: Option<NaiveDate>,
+
+    /// Lab-specific codes that represent missing values (e.g. "ND", "-99").
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing_patterns: Vec<String>,
+
+    /// Intended unit for measurement columns (e.g. "weight" → "kg").
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub units: HashMap<String, String>,
+
+    /// Declared references: the key column each identifier column refers
+    /// to (e.g. "parent_sample_id" → "sample_id").
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub references: HashMap<String, String>,
+
+    /// The source encoding is known to be clean, so encoding checks are skipped.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub clean_encoding: bool,
+
+    /// Project policy overriding the severity of observation types.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub severity_overrides: HashMap<ObservationType, Severity>,
+
+    /// Validators to leave out, by detector name (e.g. "typo_validator").
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disabled_validators: Vec<String>,
+
+    /// Extra sensitive-data regexes by label (e.g. "MRN" → `H\d{7}`).
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub sensitive_patterns: HashMap<String, String>,
+
+    /// Column types to use instead of inferring them, by column name.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub column_types: HashMap<String, ColumnType>,
+
+    /// Semantic roles to use instead of inferring them, by column name.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub column_roles: HashMap<String, SemanticRole>,
+
+    /// Controlled vocabularies: allowed values by column name.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub vocabularies: HashMap<String, Vec<String>>,
+
+    /// How semantic equivalents are merged: into the group's preferred label
+    /// (the default), the most frequent variant, or the longest.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canonical_label: Option<CanonicalLabel>,
+
+    /// Locale the data was written in, for reading numbers and dates
+    /// (en-US when unset).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<Locale>,
 }
 
 impl ContextHints {
@@ -80,6 +199,261 @@ impl ContextHints {
         self
     }
 
+    /// Set the study's sample collection window.
+    pub fn with_study_period(mut self, start: NaiveDate, end: NaiveDate) -> Self {
+        self.study_start = Some(start);
+        self.study_end = Some(end);
+        self
+    }
+
+    /// Add codes that represent missing values in this dataset.
+    pub fn with_missing_patterns(mut self, patterns: Vec<String>) -> Self {
+        self.missing_patterns.extend(patterns);
+        self
+    }
+
+    /// Set the intended unit of a measurement column (e.g. "kg", "cm").
+    ///
+    /// Unit normalization proposes conversions into this unit.
+    pub fn with_unit(mut self, column: impl Into<String>, unit: impl Into<String>) -> Self {
+        self.units.insert(column.into(), unit.into());
+        self
+    }
+
+    /// Declare that values of `child` must match a value of `parent`.
+    ///
+    /// For example, `with_reference("parent_sample_id", "sample_id")` flags
+    /// parent IDs with no matching sample. Pairs following the `*_id`
+    /// naming convention are also discovered without being declared.
+    pub fn with_reference(mut self, child: impl Into<String>, parent: impl Into<String>) -> Self {
+        self.references.insert(child.into(), parent.into());
+        self
+    }
+
+    /// Set the locale the data was written in.
+    ///
+    /// The locale decides the decimal mark and the day/month order, so
+    /// `1.234,56` is read as 1234.56 and `03/04/2024` as 3 April in
+    /// [`Locale::EuDe`], while [`Locale::EnUs`] reads `1,234` as 1234 and
+    /// `03/04/2024` as 4 March.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// The decimal mark numbers are written with (a point when no locale
+    /// is set).
+    pub fn decimal_mark(&self) -> DecimalMark {
+        self.locale.unwrap_or_default().decimal_mark()
+    }
+
+    /// Which part of an ambiguous numeric date is the day (month first when
+    /// no locale is set).
+    pub fn date_order(&self) -> DateOrder {
+        self.locale.unwrap_or_default().date_order()
+    }
+
+    /// Declare the source encoding clean, skipping mojibake detection.
+    pub fn with_clean_encoding(mut self) -> Self {
+        self.clean_encoding = true;
+        self
+    }
+
+    /// Override the severity of every observation of a type.
+    ///
+    /// For example, `with_severity(ObservationType::Completeness, Severity::Info)`
+    /// treats missing data as informational for this project.
+    pub fn with_severity(mut self, observation_type: ObservationType, severity: Severity) -> Self {
+        self.severity_overrides.insert(observation_type, severity);
+        self
+    }
+
+    /// Pin a column's type instead of inferring it.
+    ///
+    /// For example, `with_column_type("zip", ColumnType::String)` keeps a
+    /// digit-only ZIP code column as text. Names are matched
+    /// case-insensitively. The column is still validated against the type.
+    pub fn with_column_type(mut self, column: impl Into<String>, column_type: ColumnType) -> Self {
+        self.column_types.insert(column.into(), column_type);
+        self
+    }
+
+    /// Use this semantic role for a column instead of inferring it.
+    ///
+    /// Answers to inference questions are pinned this way, so the column
+    /// isn't asked about again. Names are matched case-insensitively.
+    pub fn with_column_role(mut self, column: impl Into<String>, role: SemanticRole) -> Self {
+        self.column_roles.insert(column.into(), role);
+        self
+    }
+
+    /// Look for another kind of personal identifier, matched by regex.
+    ///
+    /// A label of a built-in kind ("email", "phone", "SSN", "MRN") replaces
+    /// its pattern, e.g. for a site's own medical record number format.
+    /// A pattern that isn't a valid regex is rejected.
+    pub fn with_sensitive_pattern(
+        mut self,
+        label: impl Into<String>,
+        pattern: impl Into<String>,
+    ) -> Result<Self> {
+        let label = label.into();
+        let pattern = pattern.into();
+        if let Err(e) = regex::Regex::new(&pattern) {
+            return Err(CrucibleError::Config(format!(
+                "Invalid sensitive-data pattern for '{}': {}",
+                label, e
+            )));
+        }
+        self.sensitive_patterns.insert(label, pattern);
+        Ok(self)
+    }
+
+    /// Turn off validators by detector name (e.g. "typo_validator").
+    ///
+    /// See [`ValidationEngine::validator_names`](crate::validation::ValidationEngine::validator_names)
+    /// for the valid names.
+    pub fn with_disabled_validators<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.disabled_validators.extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Restrict a column to a controlled vocabulary.
+    ///
+    /// Column names are matched case-insensitively during analysis, so this
+    /// replaces any vocabulary for the same name in another case.
+    pub fn with_vocabulary<I, S>(mut self, column: impl Into<String>, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let column = column.into();
+        self.vocabularies
+            .retain(|name, _| !name.eq_ignore_ascii_case(&column));
+        self.vocabularies
+            .insert(column, values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Load controlled vocabularies from a project dictionary.
+    ///
+    /// A `.json` file holds an object mapping column names to arrays of
+    /// values. Any other file is read as TSV: a column name followed by one
+    /// or more values per line, with blank lines and `#` comments skipped.
+    /// Lines for the same column accumulate. Column names that differ only
+    /// in case, within the file or from vocabularies already set, are
+    /// rejected.
+    pub fn with_vocabulary_file(mut self, path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|e| CrucibleError::Io {
+            path: path.to_path_buf(),
+            source: e,
+        })?;
+
+        let is_json = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+        if is_json {
+            let vocabularies: HashMap<String, Vec<String>> = serde_json::from_str(&text)
+                .map_err(|e| {
+                    CrucibleError::Config(format!(
+                        "Invalid vocabulary file '{}': {}",
+                        path.display(),
+                        e
+                    ))
+                })?;
+            self.vocabularies.extend(vocabularies);
+            self.check_vocabulary_names()?;
+            return Ok(self);
+        }
+
+        for (line_idx, line) in text.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split('\t').map(str::trim);
+            let column = fields.next().unwrap_or_default();
+            let values: Vec<String> = fields.filter(|v| !v.is_empty()).map(String::from).collect();
+            if column.is_empty() || values.is_empty() {
+                return Err(CrucibleError::Config(format!(
+                    "Invalid vocabulary file '{}': line {} needs a column name and at least one value",
+                    path.display(),
+                    line_idx + 1
+                )));
+            }
+            self.vocabularies
+                .entry(column.to_string())
+                .or_default()
+                .extend(values);
+        }
+        self.check_vocabulary_names()?;
+        Ok(self)
+    }
+
+    /// Reject vocabulary names that differ only in case.
+    fn check_vocabulary_names(&self) -> Result<()> {
+        let mut names: Vec<&String> = self.vocabularies.keys().collect();
+        names.sort();
+        for (i, name) in names.iter().enumerate() {
+            if let Some(other) = names[i + 1..].iter().find(|n| n.eq_ignore_ascii_case(name)) {
+                return Err(CrucibleError::Config(format!(
+                    "Vocabularies for '{}' and '{}' differ only in case",
+                    name, other
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply the controlled vocabularies to matching columns of a schema.
+    ///
+    /// Each matching column gets the vocabulary as its `expected_values` and as
+    /// its only set-membership constraint. Returns the vocabulary entries that
+    /// matched no column, sorted, so typos in the dictionary can be reported.
+    /// Vocabulary names that differ only in case are rejected, since either
+    /// could apply to the same column.
+    pub fn apply_vocabularies(&self, schema: &mut TableSchema) -> Result<Vec<String>> {
+        self.check_vocabulary_names()?;
+        let mut unmatched = Vec::new();
+        let mut vocabularies: Vec<(&String, &Vec<String>)> = self.vocabularies.iter().collect();
+        vocabularies.sort();
+
+        for (name, values) in vocabularies {
+            let mut matched = false;
+            for col in schema
+                .columns
+                .iter_mut()
+                .filter(|c| c.name.eq_ignore_ascii_case(name))
+            {
+                matched = true;
+                col.expected_values = Some(values.clone());
+                col.constraints
+                    .retain(|c| !matches!(c, Constraint::SetMembership { .. }));
+                col.constraints.push(Constraint::SetMembership {
+                    values: values.clone(),
+                    confidence: 1.0,
+                });
+            }
+            if !matched {
+                unmatched.push(name.clone());
+            }
+        }
+
+        unmatched.sort();
+        Ok(unmatched)
+    }
+
+    /// Choose the value that groups of semantic equivalents are merged into.
+    pub fn with_canonical_label(mut self, canonical: CanonicalLabel) -> Self {
+        self.canonical_label = Some(canonical);
+        self
+    }
+
     /// Add a custom hint.
     pub fn with_custom(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.custom.insert(key.into(), value.into());
@@ -96,6 +470,18 @@ impl ContextHints {
             && self.custom.is_empty()
             && self.related_files.is_empty()
             && self.data_source.is_none()
+            && self.study_start.is_none()
+            && self.study_end.is_none()
+            && self.missing_patterns.is_empty()
+            && self.units.is_empty()
+            && self.references.is_empty()
+            && !self.clean_encoding
+            && self.severity_overrides.is_empty()
+            && self.disabled_validators.is_empty()
+            && self.vocabularies.is_empty()
+            && self.column_roles.is_empty()
+            && self.canonical_label.is_none()
+            && self.locale.is_none()
     }
 
     /// Format hints as a string for LLM prompts.
@@ -117,6 +503,24 @@ impl ContextHints {
         if let Some(ref source) = self.data_source {
             parts.push(format!("Data source: {}", source));
         }
+        match (self.study_start, self.study_end) {
+            (Some(start), Some(end)) => parts.push(format!("Study period: {} to {}", start, end)),
+            (Some(start), None) => parts.push(format!("Study start: {}", start)),
+            (None, Some(end)) => parts.push(format!("Study end: {}", end)),
+            (None, None) => {}
+        }
+        if !self.missing_patterns.is_empty() {
+            parts.push(format!("Missing value codes: {}", self.missing_patterns.join(", ")));
+        }
+        if !self.units.is_empty() {
+            let mut units: Vec<String> = self
+                .units
+                .iter()
+                .map(|(column, unit)| format!("{} ({})", column, unit))
+                .collect();
+            units.sort();
+            parts.push(format!("Column units: {}", units.join(", ")));
+        }
 
         for (key, value) in &self.custom {
             parts.push(format!("{}: {}", key, value));
@@ -134,6 +538,23 @@ impl ContextHints {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_locale() {
+        assert_eq!("en-US".parse::<Locale>().unwrap(), Locale::EnUs);
+        assert_eq!("de_DE".parse::<Locale>().unwrap(), Locale::EuDe);
+        assert!("eu".parse::<Locale>().is_err());
+        assert!("fr-CA".parse::<Locale>().is_err());
+        assert_eq!(Locale::EuDe.to_string(), "de-DE");
+
+        let hints = ContextHints::new();
+        assert_eq!(hints.decimal_mark(), DecimalMark::Point);
+        assert_eq!(hints.date_order(), DateOrder::MonthFirst);
+
+        let hints = hints.with_locale(Locale::EuDe);
+        assert_eq!(hints.decimal_mark(), DecimalMark::Comma);
+        assert_eq!(hints.date_order(), DateOrder::DayFirst);
+    }
+
     #[test]
     fn test_context_builder() {
         let ctx = ContextHints::new()
@@ -157,4 +578,52 @@ mod tests {
         assert!(prompt.contains("Study: Test Study"));
         assert!(prompt.contains("Domain: test"));
     }
+
+    #[test]
+    fn test_vocabulary_file() {
+        use crate::schema::ColumnSchema;
+
+        let dir = tempfile::tempdir().unwrap();
+        let tsv = dir.path().join("vocab.tsv");
+        std::fs::write(
+            &tsv,
+            "# project dictionary\nDiagnosis\tCD\tUC\nDiagnosis\tIBDU\nsex\tmale\tfemale\nsmoker\tyes\tno\n",
+        )
+        .unwrap();
+        let ctx = ContextHints::new().with_vocabulary_file(&tsv).unwrap();
+        assert_eq!(ctx.vocabularies["Diagnosis"], vec!["CD", "UC", "IBDU"]);
+
+        // Names differing only in case would make the winner arbitrary
+        let clash = dir.path().join("clash.tsv");
+        std::fs::write(&clash, "Diagnosis\tCD\ndiagnosis\tIBDU\n").unwrap();
+        assert!(ContextHints::new().with_vocabulary_file(&clash).is_err());
+        let ctx = ContextHints::new()
+            .with_vocabulary("Diagnosis", ["CD"])
+            .with_vocabulary("diagnosis", ["UC"]);
+        assert_eq!(ctx.vocabularies.len(), 1);
+        assert_eq!(ctx.vocabularies["diagnosis"], vec!["UC"]);
+
+        let json = dir.path().join("vocab.json");
+        std::fs::write(&json, r#"{"Diagnosis": ["CD", "UC", "IBDU"], "sexx": ["male", "female"]}"#)
+            .unwrap();
+        let ctx = ContextHints::new().with_vocabulary_file(&json).unwrap();
+
+        let mut schema = TableSchema::with_columns(vec![
+            ColumnSchema::new("diagnosis", 0),
+            ColumnSchema::new("sex", 1),
+        ]);
+        let unmatched = ctx.apply_vocabularies(&mut schema).unwrap();
+        assert_eq!(unmatched, vec!["sexx"]);
+
+        let diagnosis = &schema.columns[0];
+        assert_eq!(diagnosis.expected_values.as_ref().unwrap().len(), 3);
+        assert!(diagnosis.constraints.iter().any(
+            |c| matches!(c, Constraint::SetMembership { confidence, .. } if *confidence == 1.0)
+        ));
+        assert!(schema.columns[1].expected_values.is_none());
+
+        let bad = dir.path().join("bad.tsv");
+        std::fs::write(&bad, "diagnosis\n").unwrap();
+        assert!(ContextHints::new().with_vocabulary_file(&bad).is_err());
+    }
 }
@@ -0,0 +1,138 @@
+//! Polars DataFrame interop.
+//!
+//! Converts a parsed table into a typed Polars DataFrame using the inferred
+//! schema, for analysis after curation.
+
+use chrono::NaiveDate;
+use polars::prelude::{Column, DataFrame, NamedFrom, Series};
+
+use crate::error::{CrucibleError, Result};
+use crate::schema::{ColumnType, TableSchema};
+
+use super::DataTable;
+
+/// A table converted to a Polars DataFrame.
+#[derive(Debug, Clone)]
+pub struct PolarsExport {
+    /// The converted data.
+    pub frame: DataFrame,
+    /// One note per typed column kept as Utf8 because a value didn't parse.
+    pub notes: Vec<String>,
+}
+
+impl DataTable {
+    /// Convert the table to a Polars DataFrame.
+    ///
+    /// Columns are typed from `schema`'s inferred types: Integer becomes
+    /// Int64, Float becomes Float64, Date (as `YYYY-MM-DD`) becomes Date, and
+    /// everything else Utf8. Null values (see [`DataTable::is_null_value`])
+    /// become nulls. A typed column with a value that doesn't parse is kept
+    /// as Utf8, with a note saying why.
+    pub fn to_polars(&self, schema: &TableSchema) -> Result<PolarsExport> {
+        let mut notes = Vec::new();
+        let columns: Vec<Column> = self
+            .headers
+            .iter()
+            .enumerate()
+            .map(|(col, name)| {
+                let values: Vec<Option<&str>> = (0..self.rows.len())
+                    .map(|row| {
+                        self.get(row, col)
+                            .filter(|v| !Self::is_null_value(v))
+                            .map(str::trim)
+                    })
+                    .collect();
+                let column_type = schema
+                    .get_column(name)
+                    .map_or(ColumnType::String, |c| c.inferred_type);
+
+                let typed = match column_type {
+                    ColumnType::Integer => parse_all(&values, |v| v.parse::<i64>().ok())
+                        .map(|v| Series::new(name.into(), v)),
+                    ColumnType::Float => parse_all(&values, |v| v.parse::<f64>().ok())
+                        .map(|v| Series::new(name.into(), v)),
+                    ColumnType::Date => {
+                        parse_all(&values, |v| NaiveDate::parse_from_str(v, "%Y-%m-%d").ok())
+                            .map(|v| Series::new(name.into(), v))
+                    }
+                    _ => Ok(Series::new(name.into(), values.clone())),
+                };
+
+                let series = typed.unwrap_or_else(|bad| {
+                    notes.push(format!(
+                        "Column '{}' kept as Utf8: '{}' is not a valid {:?}",
+                        name, bad, column_type
+                    ));
+                    Series::new(name.into(), values)
+                });
+                series.into()
+            })
+            .collect();
+
+        let frame = DataFrame::new(columns).map_err(|e| {
+            CrucibleError::Validation(format!("Failed to build DataFrame: {}", e))
+        })?;
+        Ok(PolarsExport { frame, notes })
+    }
+}
+
+/// Parse every non-null value, or return the first one that fails.
+fn parse_all<'a, T>(
+    values: &[Option<&'a str>],
+    parse: impl Fn(&str) -> Option<T>,
+) -> std::result::Result<Vec<Option<T>>, &'a str> {
+    values
+        .iter()
+        .map(|v| match v {
+            Some(v) => parse(v).map(Some).ok_or(*v),
+            None => Ok(None),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::prelude::DataType;
+
+    use super::*;
+    use crate::schema::ColumnSchema;
+
+    #[test]
+    fn test_to_polars_types_and_fallback() {
+        let table = DataTable::new(
+            vec!["id".into(), "age".into(), "weight".into(), "visit".into(), "site".into()],
+            vec![
+                vec!["1".into(), "34".into(), "70.5".into(), "2024-01-15".into(), "A".into()],
+                vec!["2".into(), "NA".into(), "".into(), "2024-02-01".into(), "B".into()],
+                vec!["3".into(), "41".into(), "heavy".into(), ".".into(), "A".into()],
+            ],
+            b'\t',
+        );
+        let typed = |name: &str, position, column_type| {
+            let mut column = ColumnSchema::new(name, position);
+            column.inferred_type = column_type;
+            column
+        };
+        let schema = TableSchema::with_columns(vec![
+            typed("id", 0, ColumnType::Integer),
+            typed("age", 1, ColumnType::Integer),
+            typed("weight", 2, ColumnType::Float),
+            typed("visit", 3, ColumnType::Date),
+            typed("site", 4, ColumnType::String),
+        ]);
+
+        let export = table.to_polars(&schema).unwrap();
+        let frame = &export.frame;
+        assert_eq!(frame.shape(), (3, 5));
+        assert_eq!(frame.column("id").unwrap().dtype(), &DataType::Int64);
+        assert_eq!(frame.column("age").unwrap().null_count(), 1);
+        assert_eq!(frame.column("visit").unwrap().dtype(), &DataType::Date);
+        assert_eq!(frame.column("visit").unwrap().null_count(), 1);
+        assert_eq!(frame.column("site").unwrap().dtype(), &DataType::String);
+
+        // "heavy" isn't a float, so the column stays text
+        assert_eq!(frame.column("weight").unwrap().dtype(), &DataType::String);
+        assert_eq!(export.notes.len(), 1);
+        assert!(export.notes[0].contains("'weight'"));
+    }
+}
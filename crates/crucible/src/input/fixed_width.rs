@@ -0,0 +1,130 @@
+//! Fixed-width text input backend.
+//!
+//! Slices each line at configured character ranges so files exported by
+//! legacy systems without a delimiter read like any other table.
+
+use crate::error::{CrucibleError, Result};
+
+/// Records read from a fixed-width file.
+pub(super) struct FixedWidthRecords {
+    /// Column names, sliced from the header line or generated.
+    pub headers: Vec<String>,
+    /// Trimmed cell values, one row per line.
+    pub rows: Vec<Vec<String>>,
+    /// Line of each row in the text, 1-based.
+    pub lines: Vec<usize>,
+    /// Total data lines in the file, including any beyond `max_rows`.
+    pub total_records: usize,
+}
+
+/// Check that every column range is non-empty.
+pub(super) fn check_specs(col_specs: &[(usize, usize)]) -> Result<()> {
+    if col_specs.is_empty() {
+        return Err(CrucibleError::Config(
+            "Fixed-width layout needs at least one column".to_string(),
+        ));
+    }
+    if let Some((start, end)) = col_specs.iter().find(|(start, end)| start >= end) {
+        return Err(CrucibleError::Config(format!(
+            "Invalid fixed-width column range {}..{}: start must be before end",
+            start, end
+        )));
+    }
+    Ok(())
+}
+
+/// Read up to `max_rows` data lines, slicing each at `col_specs`.
+///
+/// Ranges are `(start, end)` character offsets, end exclusive; a line too
+/// short for a range gives an empty cell. Blank lines are skipped. With a
+/// header, column names are sliced from the first line at the same ranges.
+pub(super) fn read_records(
+    text: &[u8],
+    col_specs: &[(usize, usize)],
+    has_header: bool,
+    max_rows: Option<usize>,
+) -> Result<FixedWidthRecords> {
+    check_specs(col_specs)?;
+    let text = std::str::from_utf8(text).map_err(|e| CrucibleError::Parse {
+        row: 0,
+        column: 0,
+        message: format!("Fixed-width input is not valid UTF-8: {}", e),
+    })?;
+
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let headers: Vec<String> = if has_header {
+        let (_, header) = lines
+            .next()
+            .ok_or_else(|| CrucibleError::EmptyData("No columns found".to_string()))?;
+        slice_line(header, col_specs)
+    } else {
+        (0..col_specs.len())
+            .map(|i| format!("column_{}", i + 1))
+            .collect()
+    };
+
+    let (line_numbers, rows): (Vec<usize>, Vec<Vec<String>>) = lines
+        .by_ref()
+        .take(max_rows.unwrap_or(usize::MAX))
+        .map(|(idx, line)| (idx + 1, slice_line(line, col_specs)))
+        .unzip();
+    let unread = lines.count();
+
+    if rows.is_empty() {
+        return Err(CrucibleError::EmptyData("No data rows found".to_string()));
+    }
+
+    Ok(FixedWidthRecords {
+        headers,
+        total_records: rows.len() + unread,
+        rows,
+        lines: line_numbers,
+    })
+}
+
+/// Cut a line into trimmed cells at character ranges.
+fn slice_line(line: &str, col_specs: &[(usize, usize)]) -> Vec<String> {
+    let chars: Vec<char> = line.chars().collect();
+    col_specs
+        .iter()
+        .map(|&(start, end)| {
+            let start = start.min(chars.len());
+            let end = end.min(chars.len());
+            chars[start..end].iter().collect::<String>().trim().to_string()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_fixed_width_records() {
+        let text = "ID   NAME      AGE\nS001 Alice     34\n\nS002 Bob\nS003 Carol     51\n";
+        let specs = [(0, 5), (5, 15), (15, 18)];
+
+        let records = read_records(text.as_bytes(), &specs, true, Some(2)).unwrap();
+        assert_eq!(records.headers, vec!["ID", "NAME", "AGE"]);
+        assert_eq!(records.rows[0], vec!["S001", "Alice", "34"]);
+        // A short line leaves the trailing cells empty
+        assert_eq!(records.rows[1], vec!["S002", "Bob", ""]);
+        assert_eq!(records.total_records, 3);
+        // Line numbers count the skipped blank line
+        assert_eq!(records.lines, vec![2, 4]);
+
+        let records = read_records(text.as_bytes(), &specs, false, None).unwrap();
+        assert_eq!(records.headers, vec!["column_1", "column_2", "column_3"]);
+        assert_eq!(records.rows.len(), 4);
+    }
+
+    #[test]
+    fn test_invalid_specs() {
+        assert!(check_specs(&[]).is_err());
+        assert!(check_specs(&[(0, 4), (6, 6)]).is_err());
+        assert!(check_specs(&[(0, 4), (4, 9)]).is_ok());
+    }
+}
@@ -0,0 +1,141 @@
+//! JSON Lines (NDJSON) input backend.
+//!
+//! Flattens one JSON object per line into rows of strings so the rest of the
+//! pipeline sees the same shape of data as a delimited file.
+
+use indexmap::{IndexMap, IndexSet};
+use serde_json::Value;
+
+use crate::error::{CrucibleError, Result};
+
+/// Records read from a JSON Lines file.
+pub(super) struct JsonLines {
+    /// Union of keys across the records read, in first-seen order.
+    pub headers: Vec<String>,
+    /// Cell values as strings, one row per record.
+    pub rows: Vec<Vec<String>>,
+    /// Line of each record in the text, 1-based.
+    pub lines: Vec<usize>,
+    /// Total records in the file, including any beyond `max_rows`.
+    pub total_records: usize,
+    /// Records read that lack at least one of the header keys.
+    pub ragged_records: usize,
+}
+
+/// Read up to `max_rows` records from JSON Lines text.
+///
+/// Blank lines are skipped. Keys missing from a record become empty cells,
+/// `null` becomes an empty cell, and nested objects and arrays are kept as
+/// compact JSON in a single cell.
+pub(super) fn read_records(text: &[u8], max_rows: Option<usize>) -> Result<JsonLines> {
+    let text = std::str::from_utf8(text).map_err(|e| CrucibleError::Parse {
+        row: 0,
+        column: 0,
+        message: format!("JSON Lines input is not valid UTF-8: {}", e),
+    })?;
+
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+
+    let mut records: Vec<IndexMap<String, Value>> = Vec::new();
+    let mut line_numbers = Vec::new();
+    for (line_idx, line) in lines.by_ref().take(max_rows.unwrap_or(usize::MAX)) {
+        let record = serde_json::from_str(line).map_err(|e| CrucibleError::Parse {
+            row: line_idx + 1,
+            column: e.column(),
+            message: format!("expected a JSON object per line: {}", e),
+        })?;
+        records.push(record);
+        line_numbers.push(line_idx + 1);
+    }
+    let unread = lines.count();
+
+    if records.is_empty() {
+        return Err(CrucibleError::EmptyData("No data rows found".to_string()));
+    }
+
+    let headers: IndexSet<&str> = records
+        .iter()
+        .flat_map(|record| record.keys().map(String::as_str))
+        .collect();
+    if headers.is_empty() {
+        return Err(CrucibleError::EmptyData("No columns found".to_string()));
+    }
+    let headers: Vec<String> = headers.into_iter().map(String::from).collect();
+
+    let ragged_records = records
+        .iter()
+        .filter(|record| record.len() < headers.len())
+        .count();
+    let total_records = records.len() + unread;
+
+    let rows = records
+        .into_iter()
+        .map(|mut record| {
+            headers
+                .iter()
+                .map(|key| record.swap_remove(key).map(cell).unwrap_or_default())
+                .collect()
+        })
+        .collect();
+
+    Ok(JsonLines {
+        headers,
+        rows,
+        lines: line_numbers,
+        total_records,
+        ragged_records,
+    })
+}
+
+/// Convert a JSON value to the text of a single cell.
+fn cell(value: Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s,
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        nested @ (Value::Array(_) | Value::Object(_)) => nested.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_records_unions_keys() {
+        let text = br#"{"id": "S1", "age": 34, "tags": ["a", "b"]}
+
+{"id": "S2", "site": {"lat": 1.5}, "smoker": true}
+{"id": "S3", "age": null, "tags": [], "site": null, "smoker": false}
+"#;
+        let records = read_records(text, None).unwrap();
+
+        assert_eq!(records.headers, vec!["id", "age", "tags", "site", "smoker"]);
+        assert_eq!(records.rows[0], vec!["S1", "34", r#"["a","b"]"#, "", ""]);
+        assert_eq!(records.rows[1], vec!["S2", "", "", r#"{"lat":1.5}"#, "true"]);
+        assert_eq!(records.rows[2], vec!["S3", "", "[]", "", "false"]);
+        assert_eq!(records.total_records, 3);
+        assert_eq!(records.ragged_records, 2);
+    }
+
+    #[test]
+    fn test_read_records_sampled() {
+        let text = b"{\"a\": 1}\n{\"a\": 2, \"b\": 3}\n{\"a\": 4}\n";
+        let records = read_records(text, Some(1)).unwrap();
+
+        // Keys are taken from the records read
+        assert_eq!(records.headers, vec!["a"]);
+        assert_eq!(records.rows.len(), 1);
+        assert_eq!(records.total_records, 3);
+    }
+
+    #[test]
+    fn test_read_records_rejects_non_objects() {
+        let err = read_records(b"{\"a\": 1}\n[1, 2]\n", None).err().unwrap();
+        assert!(matches!(err, CrucibleError::Parse { row: 2, .. }));
+    }
+}
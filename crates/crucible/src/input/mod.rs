@@ -1,9 +1,18 @@
 //! Input parsing and data source handling.
 
 mod context;
+#[cfg(feature = "polars")]
+mod dataframe;
+mod fixed_width;
+mod jsonl;
 mod parser;
 mod source;
+#[cfg(feature = "xlsx")]
+mod xlsx;
 
-pub use context::ContextHints;
+pub use context::{ContextHints, Locale};
+#[cfg(feature = "polars")]
+pub use dataframe::PolarsExport;
+pub use encoding_rs::Encoding;
 pub use parser::{Parser, ParserConfig};
-pub use source::{DataTable, SourceMetadata};
+pub use source::{DataTable, HeaderRename, SourceMetadata, SAMPLE_HASH_PREFIX};
@@ -1,37 +1,171 @@
-//! CSV/TSV parser with delimiter detection.
+//! CSV/TSV and JSON Lines parser with delimiter and character set detection.
 
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufRead, BufReader, Read};
-use std::path::Path;
+use std::io::{self, BufReader, Cursor, Read};
+use std::path::{Path, PathBuf};
 
+use encoding_rs::{Encoding, UTF_8, WINDOWS_1252};
+use flate2::read::MultiGzDecoder;
 use sha2::{Digest, Sha256};
 
 use crate::error::{CrucibleError, Result};
-use super::source::{DataTable, SourceMetadata};
+use super::source::{DataTable, SourceMetadata, SAMPLE_HASH_PREFIX};
 
-/// Delimiters to try when auto-detecting.
+/// Magic bytes at the start of every gzip stream.
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+
+/// Bytes read up front from a sampled file, for encoding and delimiter
+/// detection, before the rest is streamed.
+const STREAM_HEAD_BYTES: usize = 1 << 20;
+
+/// Delimiters to try when auto-detecting, in tie-breaking order.
 const DELIMITERS: &[u8] = &[b'\t', b',', b';', b'|'];
 
 /// Parser configuration.
 #[derive(Debug, Clone)]
 pub struct ParserConfig {
-    /// Delimiter to use (None = auto-detect).
+    /// Delimiter to use (None = auto-detect, or comma if detection is off).
     pub delimiter: Option<u8>,
+    /// Sniff the delimiter from the file when none is given.
+    pub detect_delimiter: bool,
+    /// Number of leading records to sniff when detecting the delimiter.
+    pub sniff_lines: usize,
     /// Whether the file has a header row.
     pub has_header: bool,
     /// Maximum rows to read (None = all).
     pub max_rows: Option<usize>,
-    /// Quote character.
+    /// Number of leading lines to skip before the header.
+    pub skip_rows: usize,
+    /// Skip leading lines starting with this character (after `skip_rows`).
+    pub comment_prefix: Option<char>,
+    /// Quote character. Quoted fields may contain delimiters and newlines.
     pub quote: u8,
+    /// Whether a doubled quote (`""`) inside a quoted field is a literal quote.
+    pub double_quote: bool,
+    /// Escape character for quotes inside quoted fields (e.g. `\`), if any.
+    pub escape: Option<u8>,
+    /// Drop a blank-headed, empty last column left by trailing delimiters.
+    pub drop_empty_trailing_column: bool,
+    /// Character set to decode input with (None = detect).
+    pub encoding: Option<&'static Encoding>,
+    /// Column ranges of a fixed-width file, as `(start, end)` character
+    /// offsets (end exclusive). When set, no delimiter is used.
+    pub fixed_width: Option<Vec<(usize, usize)>>,
+    /// Worksheet to read from an Excel workbook (None = the first).
+    pub sheet: Option<String>,
+}
+
+impl ParserConfig {
+    /// Read only the first `rows` data rows for analysis.
+    ///
+    /// The rest of the file is still scanned so the table records the true
+    /// total row count and the file hash covers the whole file, but those
+    /// rows are never kept in memory. Plain UTF-8 delimited files are
+    /// streamed; compressed, JSON Lines, fixed-width, and non-UTF-8 input is
+    /// read whole before sampling.
+    pub fn with_sample(mut self, rows: usize) -> Self {
+        self.max_rows = Some(rows);
+        self
+    }
+
+    /// Skip leading lines that start with `prefix` (e.g. `#` comments).
+    ///
+    /// Only lines before the header are skipped; the skipped text is kept in
+    /// [`SourceMetadata::preamble`].
+    pub fn with_comment_prefix(mut self, prefix: char) -> Self {
+        self.comment_prefix = Some(prefix);
+        self
+    }
+
+    /// Drop a fixed number of leading lines before the header.
+    ///
+    /// Comment lines are skipped after these. Row indices stay relative to
+    /// the data rows that follow the header.
+    pub fn with_skip_rows(mut self, rows: usize) -> Self {
+        self.skip_rows = rows;
+        self
+    }
+
+    /// Use a different quote character (the default is `"`).
+    pub fn with_quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Treat `escape` followed by a quote as a literal quote inside quoted
+    /// fields (e.g. `\"`), instead of doubled quotes (`""`).
+    pub fn with_escape(mut self, escape: u8) -> Self {
+        self.escape = Some(escape);
+        self.double_quote = false;
+        self
+    }
+
+    /// Decode input with a specific character set instead of detecting it.
+    ///
+    /// ```
+    /// use crucible::input::{Encoding, ParserConfig};
+    ///
+    /// let config = ParserConfig::default()
+    ///     .with_encoding(Encoding::for_label(b"latin1").unwrap());
+    /// ```
+    pub fn with_encoding(mut self, encoding: &'static Encoding) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// Read fixed-width text, slicing each line at the given column ranges.
+    pub fn with_fixed_width(mut self, col_specs: Vec<(usize, usize)>) -> Self {
+        self.fixed_width = Some(col_specs);
+        self
+    }
+
+    /// Read the named worksheet of an Excel workbook instead of the first.
+    pub fn with_sheet(mut self, name: impl Into<String>) -> Self {
+        self.sheet = Some(name.into());
+        self
+    }
+
+    /// Configuration to re-read a source the way it was first parsed.
+    ///
+    /// Carries over the settings recorded in the metadata: the lines skipped
+    /// before the header, whether there is a header, the delimiter (given or
+    /// detected), the encoding, any fixed-width layout, and the worksheet.
+    pub fn for_source(source: &SourceMetadata) -> Self {
+        Self {
+            delimiter: source
+                .delimiter
+                .or(source.detected_delimiter)
+                .and_then(|d| u8::try_from(d).ok()),
+            skip_rows: source.skip_rows,
+            has_header: source.has_header,
+            comment_prefix: source.comment_prefix,
+            encoding: Encoding::for_label(source.encoding.as_bytes()),
+            fixed_width: source.fixed_width.clone(),
+            sheet: source.sheet.clone(),
+            ..Self::default()
+        }
+    }
 }
 
 impl Default for ParserConfig {
     fn default() -> Self {
         Self {
             delimiter: None,
+            detect_delimiter: true,
+            sniff_lines: 10,
             has_header: true,
             max_rows: None,
+            skip_rows: 0,
+            comment_prefix: None,
             quote: b'"',
+            double_quote: true,
+            escape: None,
+            drop_empty_trailing_column: false,
+            encoding: None,
+            fixed_width: None,
+            sheet: None,
         }
     }
 }
@@ -55,132 +189,494 @@ impl Parser {
     }
 
     /// Parse a file and return the data table and metadata.
+    ///
+    /// Excel workbooks (`.xlsx`) are read from the configured worksheet, or
+    /// the first, when the `xlsx` feature is enabled. JSON Lines files (`.jsonl`, `.ndjson`) are
+    /// flattened into one column per key.
     pub fn parse_file(&self, path: impl AsRef<Path>) -> Result<(DataTable, SourceMetadata)> {
         let path = path.as_ref();
 
-        // Read file contents for hashing and parsing
-        let mut file = File::open(path).map_err(|e| CrucibleError::Io {
-            path: path.to_path_buf(),
-            source: e,
-        })?;
+        if is_workbook(path) {
+            return self.parse_workbook(path, self.config.sheet.as_deref());
+        }
+
+        if self.config.max_rows.is_some()
+            && let Some(parsed) = self.parse_sampled_file(path)?
+        {
+            return Ok(parsed);
+        }
 
-        let metadata = file.metadata().map_err(|e| CrucibleError::Io {
+        let (contents, hash, size_bytes) = read_file(path)?;
+        self.parse_contents(path, contents, hash, size_bytes, self.config.delimiter)
+    }
+
+    /// Parse a sample of a plain UTF-8 delimited file without reading all of
+    /// it into memory.
+    ///
+    /// Rows past the sample are counted and hashed as they stream by. Returns
+    /// `None` for input that has to be read whole: compressed, JSON Lines,
+    /// fixed-width, non-UTF-8, or small enough to fit in the head read used
+    /// for detection.
+    fn parse_sampled_file(&self, path: &Path) -> Result<Option<(DataTable, SourceMetadata)>> {
+        if self.config.fixed_width.is_some() || is_json_lines(path) {
+            return Ok(None);
+        }
+        if self.config.encoding.is_some_and(|encoding| encoding != UTF_8) {
+            return Ok(None);
+        }
+
+        let io_error = |e| CrucibleError::Io {
             path: path.to_path_buf(),
             source: e,
-        })?;
-        let size_bytes = metadata.len();
+        };
+        let file = File::open(path).map_err(io_error)?;
+        let size_bytes = file.metadata().map_err(io_error)?.len();
+        let mut input = HashingReader::new(BufReader::new(file));
+
+        let mut head = Vec::new();
+        (&mut input)
+            .take(STREAM_HEAD_BYTES as u64)
+            .read_to_end(&mut head)
+            .map_err(io_error)?;
+        if head.len() < STREAM_HEAD_BYTES || is_gzip(path, &head) {
+            return Ok(None);
+        }
+
+        // A multi-byte character may be cut off at the end of the head
+        let body = head.strip_prefix(b"\xef\xbb\xbf".as_slice()).unwrap_or(&head);
+        if Encoding::for_bom(&head).is_some_and(|(encoding, _)| encoding != UTF_8) {
+            return Ok(None);
+        }
+        if std::str::from_utf8(body).is_err_and(|e| e.error_len().is_some()) {
+            return Ok(None);
+        }
+
+        let (preamble, text) =
+            split_preamble(body, self.config.skip_rows, self.config.comment_prefix);
+        let complete_lines = match text.iter().rposition(|&b| b == b'\n') {
+            Some(end) => &text[..=end],
+            None => return Ok(None),
+        };
+
+        let detected = match self.config.delimiter {
+            None if self.config.detect_delimiter => Some(detect_delimiter(
+                complete_lines,
+                self.config.sniff_lines,
+                self.config.quote,
+                self.config.escape,
+            )?),
+            _ => None,
+        };
+        let delimiter = self.config.delimiter.or(detected).unwrap_or(b',');
+
+        let mut data_table = match self.parse_records(Cursor::new(text).chain(&mut input), delimiter) {
+            Ok(table) => table,
+            // Invalid UTF-8 past the head; decode the whole file instead
+            Err(CrucibleError::Csv(e)) if matches!(e.kind(), csv::ErrorKind::Utf8 { .. }) => {
+                return Ok(None);
+            }
+            Err(e) => return Err(e),
+        };
+        io::copy(&mut input, &mut io::sink()).map_err(io_error)?;
+        count_preamble_lines(&mut data_table, &preamble);
+
+        let mut source_metadata =
+            self.source_metadata(path, input.finish(), size_bytes, delimiter_format(delimiter), &mut data_table);
+        source_metadata.detected_delimiter = detected.map(char::from);
+        source_metadata.delimiter = self.config.delimiter.map(char::from);
+        source_metadata.preamble = preamble;
+        source_metadata.encoding = UTF_8.name().to_lowercase();
+
+        Ok(Some((data_table, source_metadata)))
+    }
+
+    /// Parse data from a reader, such as stdin or an in-memory buffer.
+    ///
+    /// `source` (see [`SourceMetadata::stream`]) carries what a path would
+    /// otherwise provide: the logical file name, whose extension selects
+    /// JSON Lines or gzip handling, and optionally the delimiter, which is
+    /// used unless the parser config sets one. Excel workbooks can't be read
+    /// from a stream.
+    pub fn parse_reader(
+        &self,
+        mut reader: impl Read,
+        source: SourceMetadata,
+    ) -> Result<(DataTable, SourceMetadata)> {
+        let name = PathBuf::from(&source.file);
+        if is_workbook(&name) {
+            return Err(CrucibleError::UnsupportedFormat(format!(
+                "Excel workbooks can't be read from a stream: {}",
+                source.file
+            )));
+        }
+
+        let delimiter = match (self.config.delimiter, source.delimiter) {
+            (Some(delimiter), _) => Some(delimiter),
+            (None, Some(delimiter)) => Some(u8::try_from(delimiter).map_err(|_| {
+                CrucibleError::InvalidDelimiter(format!("'{}' is not a single-byte character", delimiter))
+            })?),
+            (None, None) => None,
+        };
 
-        // Read entire file for hashing
         let mut contents = Vec::new();
-        file.read_to_end(&mut contents).map_err(|e| CrucibleError::Io {
-            path: path.to_path_buf(),
+        reader.read_to_end(&mut contents).map_err(|e| CrucibleError::Io {
+            path: name.clone(),
             source: e,
         })?;
+        let hash = sha256_hash(&contents);
+        let size_bytes = contents.len() as u64;
+
+        let (table, mut metadata) = self.parse_contents(&name, contents, hash, size_bytes, delimiter)?;
+        metadata.file = source.file;
+        metadata.path = PathBuf::new();
+        Ok((table, metadata))
+    }
+
+    /// Parse the raw contents of a file (or stream, named by `path`).
+    fn parse_contents(
+        &self,
+        path: &Path,
+        mut contents: Vec<u8>,
+        hash: String,
+        size_bytes: u64,
+        delimiter: Option<u8>,
+    ) -> Result<(DataTable, SourceMetadata)> {
+        // Transparently decompress gzip input
+        let compressed = is_gzip(path, &contents);
+        if compressed {
+            let mut decompressed = Vec::new();
+            MultiGzDecoder::new(contents.as_slice())
+                .read_to_end(&mut decompressed)
+                .map_err(|e| CrucibleError::Io {
+                    path: path.to_path_buf(),
+                    source: e,
+                })?;
+            contents = decompressed;
+        }
+
+        // Decode to UTF-8, dropping any byte-order mark
+        let (text, encoding) = decode_text(&contents, self.config.encoding);
+
+        // Set aside comment and metadata lines before the header
+        let (preamble, text) =
+            split_preamble(&text, self.config.skip_rows, self.config.comment_prefix);
 
-        // Compute hash
-        let mut hasher = Sha256::new();
-        hasher.update(&contents);
-        let hash = format!("sha256:{:x}", hasher.finalize());
+        let mut detected = None;
+        let mut ragged_records = None;
+        let (mut data_table, format) = if let Some(ref col_specs) = self.config.fixed_width {
+            let records = super::fixed_width::read_records(
+                text,
+                col_specs,
+                self.config.has_header,
+                self.config.max_rows,
+            )?;
 
-        // Detect delimiter if not specified
-        let delimiter = match self.config.delimiter {
-            Some(d) => d,
-            None => detect_delimiter(&contents)?,
+            // Fixed-width text has no delimiter; applied output defaults to TSV
+            let mut table = DataTable::new(records.headers, records.rows, b'\t');
+            if records.total_records > table.row_count() {
+                table.total_rows = Some(records.total_records);
+            }
+            table.row_lines = records.lines;
+            (table, "fixed-width".to_string())
+        } else if is_json_lines(path) {
+            let records = super::jsonl::read_records(text, self.config.max_rows)?;
+            ragged_records = Some(records.ragged_records);
+
+            // JSON Lines has no delimiter; applied output is written as TSV
+            let mut table = DataTable::new(records.headers, records.rows, b'\t');
+            if records.total_records > table.row_count() {
+                table.total_rows = Some(records.total_records);
+            }
+            table.row_lines = records.lines;
+            (table, "jsonl".to_string())
+        } else {
+            // Detect delimiter if not specified
+            detected = match delimiter {
+                None if self.config.detect_delimiter => {
+                    Some(detect_delimiter(text, self.config.sniff_lines, self.config.quote, self.config.escape)?)
+                }
+                _ => None,
+            };
+            let delimiter = delimiter.or(detected).unwrap_or(b',');
+
+            // Parse the CSV/TSV
+            let table = self.parse_records(text, delimiter)?;
+            (table, delimiter_format(delimiter))
         };
+        count_preamble_lines(&mut data_table, &preamble);
+
+        let mut source_metadata = self.source_metadata(path, hash, size_bytes, format, &mut data_table);
+        source_metadata.detected_delimiter = detected.map(char::from);
+        source_metadata.delimiter = delimiter.map(char::from);
+        source_metadata.ragged_records = ragged_records;
+        source_metadata.preamble = preamble;
+        source_metadata.encoding = encoding.name().to_lowercase();
+        if compressed {
+            source_metadata.compressed = true;
+            source_metadata.uncompressed_size = Some(contents.len() as u64);
+        }
 
-        // Parse the CSV/TSV
-        let data_table = self.parse_bytes(&contents, delimiter)?;
+        Ok((data_table, source_metadata))
+    }
 
-        // Determine format from delimiter
-        let format = match delimiter {
-            b'\t' => "tsv",
-            b',' => "csv",
-            b';' => "csv-semicolon",
-            b'|' => "psv",
-            _ => "delimited",
-        }.to_string();
+    /// Give every column a unique, non-empty name and describe the parsed table.
+    ///
+    /// A sampled table's content hash covers only the sampled rows, so it is
+    /// labeled with [`SAMPLE_HASH_PREFIX`].
+    fn source_metadata(
+        &self,
+        path: &Path,
+        hash: String,
+        size_bytes: u64,
+        format: String,
+        data_table: &mut DataTable,
+    ) -> SourceMetadata {
+        let header_renames = data_table.dedupe_headers();
 
-        let source_metadata = SourceMetadata::new(
+        let mut source_metadata = SourceMetadata::new(
             path.to_path_buf(),
             hash,
             size_bytes,
             format,
-            data_table.row_count(),
+            data_table.total_row_count(),
             data_table.column_count(),
         );
+        source_metadata.content_hash = data_table.content_hash();
+        if data_table.is_sampled() {
+            source_metadata.sampled_rows = Some(data_table.row_count());
+            source_metadata.content_hash =
+                format!("{}{}", SAMPLE_HASH_PREFIX, source_metadata.content_hash);
+        }
+        source_metadata.skip_rows = self.config.skip_rows;
+        source_metadata.has_header = self.config.has_header;
+        source_metadata.comment_prefix = self.config.comment_prefix;
+        source_metadata.fixed_width = self.config.fixed_width.clone();
+        source_metadata.header_renames = header_renames;
+        source_metadata
+    }
+
+    /// Parse a fixed-width text file, slicing each line at `col_specs`.
+    ///
+    /// Each spec is a `(start, end)` range of character offsets, end
+    /// exclusive. Cell values are trimmed; with a header row, column names
+    /// are sliced from it at the same ranges. The layout is recorded in
+    /// [`SourceMetadata::fixed_width`].
+    pub fn parse_fixed_width(
+        &self,
+        path: impl AsRef<Path>,
+        col_specs: &[(usize, usize)],
+    ) -> Result<(DataTable, SourceMetadata)> {
+        let config = self.config.clone().with_fixed_width(col_specs.to_vec());
+        Parser::with_config(config).parse_file(path)
+    }
+
+    /// Names of the worksheets in an Excel workbook, in workbook order.
+    #[cfg(feature = "xlsx")]
+    pub fn sheet_names(&self, path: impl AsRef<Path>) -> Result<Vec<String>> {
+        let (contents, _, _) = read_file(path.as_ref())?;
+        super::xlsx::sheet_names(&contents)
+    }
+
+    #[cfg(not(feature = "xlsx"))]
+    pub fn sheet_names(&self, path: impl AsRef<Path>) -> Result<Vec<String>> {
+        Err(CrucibleError::UnsupportedFormat(format!(
+            "'{}' is an Excel workbook; build with the 'xlsx' feature to read it",
+            path.as_ref().display()
+        )))
+    }
+
+    /// Parse a named worksheet from an Excel workbook.
+    pub fn parse_sheet(
+        &self,
+        path: impl AsRef<Path>,
+        sheet_name: &str,
+    ) -> Result<(DataTable, SourceMetadata)> {
+        self.parse_workbook(path.as_ref(), Some(sheet_name))
+    }
+
+    /// Parse a worksheet (the first one if no name is given) from a workbook.
+    #[cfg(feature = "xlsx")]
+    fn parse_workbook(
+        &self,
+        path: &Path,
+        sheet_name: Option<&str>,
+    ) -> Result<(DataTable, SourceMetadata)> {
+        let (contents, hash, size_bytes) = read_file(path)?;
+        let mut sheet = super::xlsx::read_sheet(&contents, sheet_name)?;
+        let preamble = self.split_record_preamble(&mut sheet.records);
+
+        // Workbooks have no delimiter; applied output is written as TSV
+        let mut data_table = self.table_from_records(sheet.records, b'\t')?;
+
+        let mut source_metadata =
+            self.source_metadata(path, hash, size_bytes, "xlsx".to_string(), &mut data_table);
+        source_metadata.sheet = Some(sheet.name);
+        source_metadata.preamble = preamble;
+        source_metadata.sheet_count = Some(sheet.sheet_count);
 
         Ok((data_table, source_metadata))
     }
 
-    /// Parse bytes directly.
-    fn parse_bytes(&self, bytes: &[u8], delimiter: u8) -> Result<DataTable> {
-        let mut reader = csv::ReaderBuilder::new()
+    #[cfg(not(feature = "xlsx"))]
+    fn parse_workbook(
+        &self,
+        path: &Path,
+        _sheet_name: Option<&str>,
+    ) -> Result<(DataTable, SourceMetadata)> {
+        Err(CrucibleError::UnsupportedFormat(format!(
+            "'{}' is an Excel workbook; build with the 'xlsx' feature to read it",
+            path.display()
+        )))
+    }
+
+    /// Remove leading skipped and comment records, returning them as lines of
+    /// tab-separated cells.
+    #[cfg(feature = "xlsx")]
+    fn split_record_preamble(&self, records: &mut Vec<Vec<String>>) -> Vec<String> {
+        let skip = self.config.skip_rows.min(records.len());
+        let comments = records[skip..]
+            .iter()
+            .take_while(|record| {
+                self.config.comment_prefix.is_some_and(|prefix| {
+                    record.first().is_some_and(|cell| cell.starts_with(prefix))
+                })
+            })
+            .count();
+
+        records
+            .drain(..skip + comments)
+            .map(|mut record| {
+                while record.last().is_some_and(|cell| cell.is_empty()) {
+                    record.pop();
+                }
+                record.join("\t")
+            })
+            .collect()
+    }
+
+    /// Build a table from already-split records (header row included).
+    #[cfg(feature = "xlsx")]
+    fn table_from_records(&self, records: Vec<Vec<String>>, delimiter: u8) -> Result<DataTable> {
+        let mut records = records.into_iter();
+
+        let headers: Vec<String> = if self.config.has_header {
+            records.next().unwrap_or_default()
+        } else {
+            // Generate column names from the first record's width
+            let width = records.as_slice().first().map(|r| r.len()).unwrap_or(0);
+            (0..width).map(|i| format!("column_{}", i + 1)).collect()
+        };
+
+        if headers.is_empty() {
+            return Err(CrucibleError::EmptyData("No columns found".to_string()));
+        }
+
+        let expected_cols = headers.len();
+        let available = records.len();
+        let rows: Vec<Vec<String>> = records
+            .take(self.config.max_rows.unwrap_or(usize::MAX))
+            .map(|mut row| {
+                row.resize(expected_cols, String::new());
+                row
+            })
+            .collect();
+
+        if rows.is_empty() {
+            return Err(CrucibleError::EmptyData("No data rows found".to_string()));
+        }
+
+        let mut table = DataTable::new(headers, rows, delimiter);
+        if available > table.row_count() {
+            table.total_rows = Some(available);
+        }
+        if self.config.drop_empty_trailing_column && table.has_phantom_trailing_column() {
+            table.drop_last_column();
+        }
+
+        Ok(table)
+    }
+
+    /// Build an RFC 4180 reader for the configured quoting rules.
+    ///
+    /// Quoted fields keep embedded delimiters and newlines, so a field that
+    /// spans several physical lines is read as part of one record.
+    fn reader<R: Read>(&self, input: R, delimiter: u8) -> csv::Reader<R> {
+        csv::ReaderBuilder::new()
             .delimiter(delimiter)
             .has_headers(self.config.has_header)
             .quote(self.config.quote)
+            .double_quote(self.config.double_quote)
+            .escape(self.config.escape)
             .flexible(true)
-            .from_reader(bytes);
+            .from_reader(input)
+    }
+
+    /// Parse delimited records, keeping at most `max_rows` of them.
+    ///
+    /// Records past the limit are counted but not kept.
+    fn parse_records(&self, input: impl Read, delimiter: u8) -> Result<DataTable> {
+        let mut reader = self.reader(input, delimiter);
 
         // Get headers
-        let headers: Vec<String> = if self.config.has_header {
+        let mut headers: Vec<String> = if self.config.has_header {
             reader
                 .headers()?
                 .iter()
                 .map(|s| s.to_string())
                 .collect()
         } else {
-            // Generate column names
-            let first_record = reader.records().next();
-            match first_record {
-                Some(Ok(record)) => (0..record.len())
-                    .map(|i| format!("column_{}", i + 1))
-                    .collect(),
-                Some(Err(e)) => return Err(e.into()),
+            Vec::new()
+        };
+
+        // Read rows
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        let mut row_lines = Vec::new();
+        let limit = self.config.max_rows.unwrap_or(usize::MAX);
+        let mut record = csv::StringRecord::new();
+        while rows.len() < limit && reader.read_record(&mut record)? {
+            rows.push(record.iter().map(|s| s.to_string()).collect());
+            row_lines.push(record.position().map_or(0, |pos| pos.line() as usize));
+        }
+
+        if !self.config.has_header {
+            // Generate column names from the first record's width
+            match rows.first() {
+                Some(row) => headers = (0..row.len()).map(|i| format!("column_{}", i + 1)).collect(),
                 None => return Err(CrucibleError::EmptyData("No data rows found".to_string())),
             }
-        };
+        }
 
         if headers.is_empty() {
             return Err(CrucibleError::EmptyData("No columns found".to_string()));
         }
 
-        // Read rows
-        let mut rows = Vec::new();
+        // Pad short rows and truncate long ones
         let expected_cols = headers.len();
+        for row in &mut rows {
+            row.resize(expected_cols, String::new());
+        }
 
-        // Need to re-create reader if we consumed it getting headers
-        let mut reader = csv::ReaderBuilder::new()
-            .delimiter(delimiter)
-            .has_headers(self.config.has_header)
-            .quote(self.config.quote)
-            .flexible(true)
-            .from_reader(bytes);
-
-        for (row_idx, result) in reader.records().enumerate() {
-            if let Some(max) = self.config.max_rows {
-                if row_idx >= max {
-                    break;
-                }
-            }
-
-            let record = result?;
-            let mut row: Vec<String> = record.iter().map(|s| s.to_string()).collect();
-
-            // Pad row if needed
-            while row.len() < expected_cols {
-                row.push(String::new());
-            }
-            // Truncate if too many columns
-            row.truncate(expected_cols);
-
-            rows.push(row);
+        // Count rows beyond the sample without keeping them
+        let mut unread = 0;
+        let mut skipped = csv::ByteRecord::new();
+        while rows.len() == limit && reader.read_byte_record(&mut skipped)? {
+            unread += 1;
         }
 
         if rows.is_empty() {
             return Err(CrucibleError::EmptyData("No data rows found".to_string()));
         }
 
-        Ok(DataTable::new(headers, rows, delimiter))
+        let mut table = DataTable::new(headers, rows, delimiter);
+        table.row_lines = row_lines;
+        if unread > 0 {
+            table.total_rows = Some(table.row_count() + unread);
+        }
+        if self.config.drop_empty_trailing_column && table.has_phantom_trailing_column() {
+            table.drop_last_column();
+        }
+
+        Ok(table)
     }
 }
 
@@ -190,13 +686,185 @@ impl Default for Parser {
     }
 }
 
-/// Detect the delimiter by analyzing the first few lines.
-fn detect_delimiter(bytes: &[u8]) -> Result<u8> {
-    let reader = BufReader::new(bytes);
-    let lines: Vec<String> = reader
-        .lines()
-        .take(10)
-        .filter_map(|l| l.ok())
+/// Split comment and metadata lines off the start of the text.
+///
+/// The first `skip_rows` lines are dropped, then any lines starting with
+/// `comment_prefix`. Returns the skipped lines, without line endings, and
+/// the text from the header on.
+fn split_preamble(text: &[u8], skip_rows: usize, comment_prefix: Option<char>) -> (Vec<String>, &[u8]) {
+    let mut prefix = [0; 4];
+    let prefix = comment_prefix.map(|c| c.encode_utf8(&mut prefix).as_bytes());
+
+    let mut preamble = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let skipped = preamble.len() < skip_rows || prefix.is_some_and(|p| rest.starts_with(p));
+        if !skipped {
+            break;
+        }
+        let end = rest.iter().position(|&b| b == b'\n').map_or(rest.len(), |i| i + 1);
+        let line = String::from_utf8_lossy(&rest[..end]);
+        preamble.push(line.trim_end_matches(['\r', '\n']).to_string());
+        rest = &rest[end..];
+    }
+
+    (preamble, rest)
+}
+
+/// Shift row line numbers, counted from the header, past the preamble.
+fn count_preamble_lines(table: &mut DataTable, preamble: &[String]) {
+    for line in &mut table.row_lines {
+        *line += preamble.len();
+    }
+}
+
+/// Name the format of a delimited file after its delimiter.
+fn delimiter_format(delimiter: u8) -> String {
+    match delimiter {
+        b'\t' => "tsv",
+        b',' => "csv",
+        b';' => "csv-semicolon",
+        b'|' => "psv",
+        _ => "delimited",
+    }
+    .to_string()
+}
+
+/// Reader that hashes the bytes read through it.
+struct HashingReader<R> {
+    inner: R,
+    hasher: Sha256,
+}
+
+impl<R: Read> HashingReader<R> {
+    fn new(inner: R) -> Self {
+        Self {
+            inner,
+            hasher: Sha256::new(),
+        }
+    }
+
+    /// The hash of everything read, as `sha256:<hex>`.
+    fn finish(self) -> String {
+        format!("sha256:{:x}", self.hasher.finalize())
+    }
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// Read a file, returning its contents, SHA-256 hash, and size on disk.
+fn read_file(path: &Path) -> Result<(Vec<u8>, String, u64)> {
+    let mut file = File::open(path).map_err(|e| CrucibleError::Io {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+
+    let metadata = file.metadata().map_err(|e| CrucibleError::Io {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    let size_bytes = metadata.len();
+
+    // Read entire file for hashing
+    let mut contents = Vec::new();
+    file.read_to_end(&mut contents).map_err(|e| CrucibleError::Io {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+
+    // Compute hash of the file as stored on disk
+    let hash = sha256_hash(&contents);
+
+    Ok((contents, hash, size_bytes))
+}
+
+/// Hash raw contents as `sha256:<hex>`.
+fn sha256_hash(contents: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(contents);
+    format!("sha256:{:x}", hasher.finalize())
+}
+
+/// Decode file contents to UTF-8.
+///
+/// A forced encoding is always used. Otherwise a byte-order mark selects the
+/// encoding; input without one is taken as UTF-8 when valid, and as
+/// Windows-1252 (a superset of printable Latin-1) when not. The BOM is
+/// stripped so it doesn't end up in the first header name.
+fn decode_text<'a>(
+    bytes: &'a [u8],
+    forced: Option<&'static Encoding>,
+) -> (Cow<'a, [u8]>, &'static Encoding) {
+    let (text, encoding) = match forced {
+        Some(encoding) => (encoding.decode_with_bom_removal(bytes).0, encoding),
+        None => match Encoding::for_bom(bytes) {
+            Some((encoding, bom_len)) => (
+                encoding.decode_without_bom_handling(&bytes[bom_len..]).0,
+                encoding,
+            ),
+            None => match std::str::from_utf8(bytes) {
+                Ok(text) => (Cow::Borrowed(text), UTF_8),
+                Err(_) => (
+                    WINDOWS_1252.decode_without_bom_handling(bytes).0,
+                    WINDOWS_1252,
+                ),
+            },
+        },
+    };
+
+    let text = match text {
+        Cow::Borrowed(text) => Cow::Borrowed(text.as_bytes()),
+        Cow::Owned(text) => Cow::Owned(text.into_bytes()),
+    };
+    (text, encoding)
+}
+
+/// Check whether a file is an Excel workbook, by extension.
+fn is_workbook(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("xlsx") || ext.eq_ignore_ascii_case("xlsm"))
+}
+
+/// Check whether a file holds JSON Lines, by extension (ignoring a trailing `.gz`).
+fn is_json_lines(path: &Path) -> bool {
+    let path = match path.extension() {
+        Some(ext) if ext.eq_ignore_ascii_case("gz") => Path::new(path.file_stem().unwrap_or_default()),
+        _ => path,
+    };
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("jsonl") || ext.eq_ignore_ascii_case("ndjson"))
+}
+
+/// Check whether a file is gzip-compressed, by extension or magic bytes.
+fn is_gzip(path: &Path, contents: &[u8]) -> bool {
+    let gz_extension = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("gz"));
+    gz_extension || contents.starts_with(GZIP_MAGIC)
+}
+
+/// Detect the delimiter by analyzing the first `sniff_lines` records.
+///
+/// Records are split on newlines outside quoted fields, so quoted fields
+/// with embedded delimiters or line breaks don't skew the counts. Picks the candidate that yields the most consistent column count across
+/// the sampled lines, then the one with more columns. Remaining ties go to the
+/// earlier candidate in [`DELIMITERS`], so tab wins over comma, and comma over
+/// semicolon and pipe. Falls back to comma when no candidate appears at all.
+fn detect_delimiter(
+    bytes: &[u8],
+    sniff_lines: usize,
+    quote: u8,
+    escape: Option<u8>,
+) -> Result<u8> {
+    let text = String::from_utf8_lossy(bytes);
+    let lines: Vec<&str> = split_records(&text, sniff_lines.max(1), quote, escape)
+        .into_iter()
         .filter(|l| !l.trim().is_empty())
         .collect();
 
@@ -204,45 +872,31 @@ fn detect_delimiter(bytes: &[u8]) -> Result<u8> {
         return Err(CrucibleError::EmptyData("No lines to analyze".to_string()));
     }
 
-    // Count occurrences of each delimiter in each line
     let mut best_delimiter = b',';
-    let mut best_score = 0;
+    let mut best_score = (0, 0);
 
     for &delim in DELIMITERS {
         let counts: Vec<usize> = lines
             .iter()
-            .map(|line| count_delimiter_in_line(line, delim))
+            .map(|line| count_delimiter_in_line(line, delim, quote, escape))
             .collect();
 
-        // Check if counts are consistent (same count in each line)
-        if counts.is_empty() {
-            continue;
+        // Most common per-line count and how many lines agree with it
+        let mut frequency: HashMap<usize, usize> = HashMap::new();
+        for &count in &counts {
+            *frequency.entry(count).or_insert(0) += 1;
         }
+        let (modal_count, agreeing) = frequency
+            .into_iter()
+            .max_by_key(|&(count, lines)| (lines, count))
+            .unwrap_or((0, 0));
 
-        let first_count = counts[0];
-        if first_count == 0 {
+        if modal_count == 0 {
             continue;
         }
 
-        // Calculate consistency score
-        let consistent = counts.iter().all(|&c| c == first_count);
-        let variance: f64 = if counts.len() > 1 {
-            let mean = counts.iter().sum::<usize>() as f64 / counts.len() as f64;
-            counts.iter().map(|&c| (c as f64 - mean).powi(2)).sum::<f64>() / counts.len() as f64
-        } else {
-            0.0
-        };
-
-        // Score: higher count with lower variance is better
-        // Tab delimiter gets a slight bonus as it's less common in actual data
-        let score = if consistent {
-            first_count * 1000 + (if delim == b'\t' { 100 } else { 0 })
-        } else if variance < 1.0 {
-            first_count * 100
-        } else {
-            first_count
-        };
-
+        // Strictly greater, so earlier candidates win ties
+        let score = (agreeing, modal_count);
         if score > best_score {
             best_score = score;
             best_delimiter = delim;
@@ -252,17 +906,50 @@ fn detect_delimiter(bytes: &[u8]) -> Result<u8> {
     Ok(best_delimiter)
 }
 
-/// Count delimiter occurrences in a line, respecting quotes.
-fn count_delimiter_in_line(line: &str, delimiter: u8) -> usize {
-    let delim_char = delimiter as char;
+/// Split the first `limit` records from text, on newlines outside quoted fields.
+fn split_records(text: &str, limit: usize, quote: u8, escape: Option<u8>) -> Vec<&str> {
+    let mut records = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut escaped = false;
+
+    for (i, byte) in text.bytes().enumerate() {
+        if escaped {
+            escaped = false;
+        } else if in_quotes && Some(byte) == escape {
+            escaped = true;
+        } else if byte == quote {
+            in_quotes = !in_quotes;
+        } else if byte == b'\n' && !in_quotes {
+            records.push(text[start..i].trim_end_matches('\r'));
+            start = i + 1;
+            if records.len() == limit {
+                return records;
+            }
+        }
+    }
+    if start < text.len() {
+        records.push(&text[start..]);
+    }
+
+    records
+}
+
+/// Count delimiter occurrences in a record, ignoring those inside quotes.
+fn count_delimiter_in_line(line: &str, delimiter: u8, quote: u8, escape: Option<u8>) -> usize {
     let mut count = 0;
     let mut in_quotes = false;
+    let mut escaped = false;
 
-    for ch in line.chars() {
-        match ch {
-            '"' => in_quotes = !in_quotes,
-            c if c == delim_char && !in_quotes => count += 1,
-            _ => {}
+    for byte in line.bytes() {
+        if escaped {
+            escaped = false;
+        } else if in_quotes && Some(byte) == escape {
+            escaped = true;
+        } else if byte == quote {
+            in_quotes = !in_quotes;
+        } else if byte == delimiter && !in_quotes {
+            count += 1;
         }
     }
 
@@ -276,20 +963,171 @@ mod tests {
     #[test]
     fn test_detect_delimiter_csv() {
         let data = b"a,b,c\n1,2,3\n4,5,6";
-        assert_eq!(detect_delimiter(data).unwrap(), b',');
+        assert_eq!(detect_delimiter(data, 10, b'"', None).unwrap(), b',');
     }
 
     #[test]
     fn test_detect_delimiter_tsv() {
         let data = b"a\tb\tc\n1\t2\t3\n4\t5\t6";
-        assert_eq!(detect_delimiter(data).unwrap(), b'\t');
+        assert_eq!(detect_delimiter(data, 10, b'"', None).unwrap(), b'\t');
+    }
+
+    #[test]
+    fn test_detect_delimiter_semicolon_and_pipe() {
+        // Decimal commas vary per line; the semicolons are consistent
+        let data = b"id;weight;height\nS1;70,5;1,80\nS2;82;1,75\nS3;64,2;165";
+        assert_eq!(detect_delimiter(data, 10, b'"', None).unwrap(), b';');
+
+        let data = b"id|name\nS1|alpha\nS2|beta";
+        assert_eq!(detect_delimiter(data, 10, b'"', None).unwrap(), b'|');
+    }
+
+    #[test]
+    fn test_detect_delimiter_prefers_tab_on_tie() {
+        let data = b"a\tb,c\n1\t2,3\n4\t5,6";
+        assert_eq!(detect_delimiter(data, 10, b'"', None).unwrap(), b'\t');
+    }
+
+    #[test]
+    fn test_parse_latin1_file() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        // "Montréal" and "Zürich" in Latin-1
+        std::io::Write::write_all(&mut file, b"id,city\nS1,Montr\xe9al\nS2,Z\xfcrich\n").unwrap();
+
+        let (table, source) = Parser::new().parse_file(file.path()).unwrap();
+        assert_eq!(table.get(0, 1), Some("Montréal"));
+        assert_eq!(table.get(1, 1), Some("Zürich"));
+        assert_eq!(source.encoding, "windows-1252");
+    }
+
+    #[test]
+    fn test_bom_is_stripped() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(&mut file, b"\xef\xbb\xbfid,name\nS1,alpha\n").unwrap();
+
+        let (table, source) = Parser::new().parse_file(file.path()).unwrap();
+        assert_eq!(table.headers[0], "id");
+        assert_eq!(source.encoding, "utf-8");
+
+        // UTF-16 is only recognized by its BOM
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        let utf16: Vec<u8> = "\u{feff}id,name\nS1,élan\n"
+            .encode_utf16()
+            .flat_map(|unit| unit.to_le_bytes())
+            .collect();
+        std::io::Write::write_all(&mut file, &utf16).unwrap();
+
+        let (table, source) = Parser::new().parse_file(file.path()).unwrap();
+        assert_eq!(table.headers, vec!["id", "name"]);
+        assert_eq!(table.get(0, 1), Some("élan"));
+        assert_eq!(source.encoding, "utf-16le");
+    }
+
+    #[test]
+    fn test_forced_encoding() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        // Valid UTF-8 that was meant to be read as Latin-1
+        std::io::Write::write_all(&mut file, "id,name\nS1,Ã©\n".as_bytes()).unwrap();
+
+        let (table, _) = Parser::new().parse_file(file.path()).unwrap();
+        assert_eq!(table.get(0, 1), Some("Ã©"));
+
+        let config = ParserConfig::default().with_encoding(Encoding::for_label(b"utf-8").unwrap());
+        let (table, source) = Parser::with_config(config).parse_file(file.path()).unwrap();
+        assert_eq!(table.get(0, 1), Some("Ã©"));
+        assert_eq!(source.encoding, "utf-8");
+
+        let config = ParserConfig::default().with_encoding(WINDOWS_1252);
+        let (table, source) = Parser::with_config(config).parse_file(file.path()).unwrap();
+        assert_eq!(table.get(0, 1), Some("ÃƒÂ©"));
+        assert_eq!(source.encoding, "windows-1252");
+
+        // The encoding is restored when re-reading the source
+        let (table, _) = Parser::with_config(ParserConfig::for_source(&source))
+            .parse_file(file.path())
+            .unwrap();
+        assert_eq!(table.get(0, 1), Some("ÃƒÂ©"));
+    }
+
+    #[test]
+    fn test_detected_delimiter_in_metadata() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(&mut file, b"id;name\nS1;alpha\nS2;beta\n").unwrap();
+
+        let (table, source) = Parser::new().parse_file(file.path()).unwrap();
+        assert_eq!(table.delimiter, b';');
+        assert_eq!(table.column_count(), 2);
+        assert_eq!(source.detected_delimiter, Some(';'));
+
+        let parser = Parser::with_config(ParserConfig {
+            delimiter: Some(b';'),
+            ..Default::default()
+        });
+        let (_, source) = parser.parse_file(file.path()).unwrap();
+        assert_eq!(source.detected_delimiter, None);
+        assert_eq!(source.delimiter, Some(';'));
+        assert_eq!(ParserConfig::for_source(&source).delimiter, Some(b';'));
+    }
+
+    #[test]
+    fn test_parse_json_lines_file() {
+        let mut file = tempfile::Builder::new().suffix(".ndjson").tempfile().unwrap();
+        std::io::Write::write_all(
+            &mut file,
+            b"{\"id\": \"S1\", \"depth\": 10}\n{\"id\": \"S2\", \"meta\": {\"run\": 2}}\n",
+        )
+        .unwrap();
+
+        let (table, source) = Parser::new().parse_file(file.path()).unwrap();
+        assert_eq!(table.headers, vec!["id", "depth", "meta"]);
+        assert_eq!(table.rows[1], vec!["S2", "", "{\"run\":2}"]);
+        assert_eq!(source.format, "jsonl");
+        assert_eq!(source.ragged_records, Some(2));
+        assert_eq!(source.detected_delimiter, None);
+        assert!(is_json_lines(Path::new("events.jsonl.gz")));
+    }
+
+    #[test]
+    fn test_duplicate_headers_renamed_in_metadata() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(&mut file, b"date\t\tdate\nd1\tx\td2\n").unwrap();
+
+        let (table, source) = Parser::new().parse_file(file.path()).unwrap();
+        assert_eq!(table.headers, vec!["date", "column_2", "date_2"]);
+        assert_eq!(source.header_renames.len(), 2);
+        assert_eq!(source.header_renames[1].original, "date");
+        assert_eq!(source.header_renames[1].renamed, "date_2");
+    }
+
+    #[test]
+    fn test_parse_gzip_file() {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+
+        let data = b"sample_id\tdepth\nS1\t10\nS2\t20\n";
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        let gz = encoder.finish().unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("metadata.tsv.gz");
+        std::fs::write(&path, &gz).unwrap();
+
+        let (table, source) = Parser::new().parse_file(&path).unwrap();
+        assert_eq!(table.headers, vec!["sample_id", "depth"]);
+        assert_eq!(table.row_count(), 2);
+        assert!(source.compressed);
+        assert_eq!(source.uncompressed_size, Some(data.len() as u64));
+        assert_eq!(source.size_bytes, gz.len() as u64);
+        assert_eq!(source.format, "tsv");
     }
 
     #[test]
     fn test_parse_csv() {
         let parser = Parser::new();
         let data = b"name,age,city\nAlice,30,NYC\nBob,25,LA";
-        let table = parser.parse_bytes(data, b',').unwrap();
+        let table = parser.parse_records(&data[..], b',').unwrap();
 
         assert_eq!(table.headers, vec!["name", "age", "city"]);
         assert_eq!(table.row_count(), 2);
@@ -297,6 +1135,251 @@ mod tests {
         assert_eq!(table.get(1, 1), Some("25"));
     }
 
+    #[test]
+    fn test_parse_quoted_fields() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(
+            &mut file,
+            b"id,name,notes\n\
+              1,\"Smith, John\",\"said \"\"hi\"\", then left\"\n\
+              2,\"Doe, Jane\",\"first line\nsecond, line\n\"\n\
+              3,Lee,plain\n",
+        )
+        .unwrap();
+
+        let (table, source) = Parser::new().parse_file(file.path()).unwrap();
+        assert_eq!(source.detected_delimiter, Some(','));
+        assert_eq!(table.row_count(), 3);
+        assert_eq!(table.get(0, 1), Some("Smith, John"));
+        assert_eq!(table.get(0, 2), Some("said \"hi\", then left"));
+        // The quoted field spans lines and ends with a newline
+        assert_eq!(table.get(1, 2), Some("first line\nsecond, line\n"));
+        assert_eq!(table.get(2, 0), Some("3"));
+    }
+
+    #[test]
+    fn test_parse_skips_preamble() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(
+            &mut file,
+            b"Instrument export v2\r\nRun date: 2024-03-01\r\n\
+              # operator: jd\r\n# units: mg/L\r\n\
+              sample\tvalue\r\nS1\t1.5\r\n# S2\t2.0\r\n",
+        )
+        .unwrap();
+
+        let config = ParserConfig::default()
+            .with_skip_rows(2)
+            .with_comment_prefix('#');
+        let (table, source) = Parser::with_config(config).parse_file(file.path()).unwrap();
+
+        assert_eq!(table.headers, vec!["sample", "value"]);
+        assert_eq!(source.detected_delimiter, Some('\t'));
+        assert_eq!(
+            source.preamble,
+            vec!["Instrument export v2", "Run date: 2024-03-01", "# operator: jd", "# units: mg/L"]
+        );
+        // Rows count from the header; comment-like data rows are kept
+        assert_eq!(table.row_count(), 2);
+        assert_eq!(table.get(0, 0), Some("S1"));
+        assert_eq!(table.get(1, 0), Some("# S2"));
+
+        // Re-reading with the recorded options skips the preamble again
+        let (reparsed, _) = Parser::with_config(ParserConfig::for_source(&source))
+            .parse_file(file.path())
+            .unwrap();
+        assert_eq!(reparsed.headers, vec!["sample", "value"]);
+        assert_eq!(reparsed.row_count(), 2);
+
+        // Without the options the preamble becomes part of the table
+        let (table, source) = Parser::new().parse_file(file.path()).unwrap();
+        assert!(source.preamble.is_empty());
+        assert_ne!(table.headers, vec!["sample", "value"]);
+    }
+
+    #[test]
+    fn test_for_source_keeps_sheet() {
+        let mut source = SourceMetadata::stream("study.xlsx");
+        assert_eq!(ParserConfig::for_source(&source).sheet, None);
+        source.sheet = Some("labs".to_string());
+        assert_eq!(ParserConfig::for_source(&source).sheet.as_deref(), Some("labs"));
+    }
+
+    #[test]
+    fn test_row_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ParserConfig::default().with_comment_prefix('#');
+
+        // Preamble lines and quoted fields spanning lines push rows down
+        let csv = dir.path().join("notes.csv");
+        std::fs::write(&csv, "# export\nid,note\n1,\"two\nlines\"\n2,plain\n").unwrap();
+        let (table, _) = Parser::with_config(config.clone()).parse_file(&csv).unwrap();
+        assert_eq!(table.row_lines, vec![3, 5]);
+        assert_eq!(table.row_line(1), Some(5));
+        assert_eq!(table.row_line(2), None);
+
+        // JSON Lines has no header, and blank lines are skipped
+        let jsonl = dir.path().join("notes.jsonl");
+        std::fs::write(&jsonl, "# export\n{\"id\": 1}\n\n{\"id\": 2}\n").unwrap();
+        let (table, _) = Parser::with_config(config).parse_file(&jsonl).unwrap();
+        assert_eq!(table.row_lines, vec![2, 4]);
+    }
+
+    #[test]
+    fn test_parse_escaped_quotes() {
+        let config = ParserConfig::default().with_escape(b'\\');
+        let data = b"id\tquote\nQ1\t\"she said \\\"no\\\"\"\n";
+        let table = Parser::with_config(config).parse_records(&data[..], b'\t').unwrap();
+        assert_eq!(table.get(0, 1), Some("she said \"no\""));
+
+        let config = ParserConfig::default().with_quote(b'\'');
+        let data = b"id,name\n1,'Smith, John'\n";
+        let table = Parser::with_config(config).parse_records(&data[..], b',').unwrap();
+        assert_eq!(table.get(0, 1), Some("Smith, John"));
+    }
+
+    #[test]
+    fn test_detect_delimiter_ignores_quoted_newlines() {
+        // Physical lines disagree on comma counts; logical records don't
+        let data = b"a,b,c\n1,\"x\ny,z\",3\n4,5,6\n7,8,9";
+        assert_eq!(detect_delimiter(data, 10, b'"', None).unwrap(), b',');
+        assert_eq!(split_records("a\n\"b\nc\"\nd", 2, b'"', None), vec!["a", "\"b\nc\""]);
+    }
+
+    #[test]
+    fn test_trailing_delimiter_column() {
+        let data = b"name\tage\t\nAlice\t30\t\nBob\t25\t\n";
+
+        let table = Parser::new().parse_records(&data[..], b'\t').unwrap();
+        assert_eq!(table.column_count(), 3);
+        assert!(table.has_phantom_trailing_column());
+
+        let parser = Parser::with_config(ParserConfig {
+            drop_empty_trailing_column: true,
+            ..Default::default()
+        });
+        let table = parser.parse_records(&data[..], b'\t').unwrap();
+        assert_eq!(table.headers, vec!["name", "age"]);
+        assert!(table.rows.iter().all(|r| r.len() == 2));
+        assert!(!table.has_phantom_trailing_column());
+    }
+
+    #[test]
+    fn test_sample_records_total_rows() {
+        let data = b"id,value\n1,a\n2,\"multi\nline\"\n3,c\n4,d\n5,e\n";
+
+        let parser = Parser::with_config(ParserConfig::default().with_sample(2));
+        let table = parser.parse_records(&data[..], b',').unwrap();
+        assert_eq!(table.row_count(), 2);
+        assert_eq!(table.total_row_count(), 5);
+        assert!(table.is_sampled());
+
+        let parser = Parser::with_config(ParserConfig::default().with_sample(10));
+        let table = parser.parse_records(&data[..], b',').unwrap();
+        assert_eq!(table.row_count(), 5);
+        assert_eq!(table.total_rows, None);
+        assert!(!table.is_sampled());
+    }
+
+    #[test]
+    fn test_sampled_file_is_streamed_and_hashed_whole() {
+        let mut data = b"# exported 2024-01-01\nid;label\n".to_vec();
+        let mut row = 0;
+        while data.len() <= STREAM_HEAD_BYTES {
+            data.extend_from_slice(format!("{};sample {}\n", row, row).as_bytes());
+            row += 1;
+        }
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(&mut file, &data).unwrap();
+
+        let config = ParserConfig::default().with_comment_prefix('#');
+        let (_, full) = Parser::with_config(config.clone()).parse_file(file.path()).unwrap();
+        let (table, sampled) = Parser::with_config(config.with_sample(10))
+            .parse_file(file.path())
+            .unwrap();
+
+        assert_eq!(table.row_count(), 10);
+        assert_eq!(table.total_row_count(), row);
+        assert_eq!(table.headers, vec!["id", "label"]);
+        assert_eq!(sampled.hash, full.hash);
+        assert_eq!(sampled.detected_delimiter, Some(';'));
+        assert_eq!(sampled.preamble, vec!["# exported 2024-01-01"]);
+        assert_eq!(sampled.sampled_rows, Some(10));
+        assert!(sampled.content_hash.starts_with(SAMPLE_HASH_PREFIX));
+        assert!(!full.content_hash.starts_with(SAMPLE_HASH_PREFIX));
+    }
+
+    #[test]
+    fn test_parse_fixed_width_round_trip() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(
+            &mut file,
+            b"id    name      dose\nS1    alpha     1.5\n\nS2    beta gamma2.0\n",
+        )
+        .unwrap();
+
+        let layout = vec![(0, 6), (6, 16), (16, 20)];
+        let (mut table, source) = Parser::new()
+            .parse_fixed_width(file.path(), &layout)
+            .unwrap();
+
+        assert_eq!(table.headers, vec!["id", "name", "dose"]);
+        assert_eq!(table.row_count(), 2);
+        assert_eq!(table.get(1, 1), Some("beta gamma"));
+        assert_eq!(source.format, "fixed-width");
+        assert_eq!(source.fixed_width, Some(layout.clone()));
+
+        // Writing back with the recorded layout reproduces the columns
+        table.set(0, 2, "1.25".to_string());
+        let out = tempfile::NamedTempFile::new().unwrap();
+        table.write_fixed_width(out.path(), &layout, source.has_header).unwrap();
+        let written = std::fs::read_to_string(out.path()).unwrap();
+        assert_eq!(written.lines().nth(1), Some("S1    alpha     1.25"));
+
+        let (reparsed, _) = Parser::with_config(ParserConfig::for_source(&source))
+            .parse_file(out.path())
+            .unwrap();
+        assert_eq!(reparsed.get(0, 2), Some("1.25"));
+        assert_eq!(reparsed.get(1, 1), Some("beta gamma"));
+
+        // A value wider than its column cannot be written
+        table.set(0, 0, "S1-too-long".to_string());
+        assert!(table.write_fixed_width(out.path(), &layout, true).is_err());
+        assert_eq!(std::fs::read_to_string(out.path()).unwrap(), written);
+
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("out.txt");
+        assert!(table.write_fixed_width(&missing, &layout, true).is_err());
+        assert!(!missing.exists());
+    }
+
+    #[test]
+    fn test_parse_fixed_width_without_header() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(&mut file, b"S1    alpha     1.5
+S2    beta      2.0
+").unwrap();
+
+        let layout = vec![(0, 6), (6, 16), (16, 20)];
+        let mut config = ParserConfig::default().with_fixed_width(layout.clone());
+        config.has_header = false;
+        let (table, source) = Parser::with_config(config).parse_file(file.path()).unwrap();
+        assert_eq!(table.headers, vec!["column_1", "column_2", "column_3"]);
+        assert!(!source.has_header);
+
+        // No header line is written, and re-reading keeps the first row
+        let out = tempfile::NamedTempFile::new().unwrap();
+        table.write_fixed_width(out.path(), &layout, source.has_header).unwrap();
+        let written = std::fs::read_to_string(out.path()).unwrap();
+        assert_eq!(written.lines().next(), Some("S1    alpha     1.5"));
+
+        let (reparsed, _) = Parser::with_config(ParserConfig::for_source(&source))
+            .parse_file(out.path())
+            .unwrap();
+        assert_eq!(reparsed.row_count(), 2);
+        assert_eq!(reparsed.get(0, 0), Some("S1"));
+    }
+
     #[test]
     fn test_is_null_value() {
         assert!(DataTable::is_null_value(""));
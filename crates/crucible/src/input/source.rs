@@ -1,9 +1,14 @@
 //! Data source abstraction and metadata.
 
+use std::collections::HashSet;
 use std::path::PathBuf;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Prefix of a [`SourceMetadata::content_hash`] that covers only the sampled rows.
+pub const SAMPLE_HASH_PREFIX: &str = "sample-";
 
 /// Metadata about the source data file.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,20 +19,84 @@ pub struct SourceMetadata {
     pub path: PathBuf,
     /// SHA-256 hash of the file contents.
     pub hash: String,
-    /// File size in bytes.
+    /// File size in bytes, as stored on disk.
     pub size_bytes: u64,
+    /// Whether the file was gzip-compressed.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub compressed: bool,
+    /// Decompressed size in bytes, for compressed files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uncompressed_size: Option<u64>,
     /// Detected format (csv, tsv, etc.).
     pub format: String,
     /// Detected encoding.
     pub encoding: String,
+    /// Worksheet that was read, for Excel workbooks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sheet: Option<String>,
+    /// Total number of worksheets, for Excel workbooks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sheet_count: Option<usize>,
+    /// Delimiter sniffed from the file, if it was auto-detected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detected_delimiter: Option<char>,
+    /// Delimiter given (in the parser config, or for stream input) instead
+    /// of being detected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delimiter: Option<char>,
+    /// Lines dropped before the header.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub skip_rows: usize,
+    /// Whether the first line holds column names. Without one, columns
+    /// are named `column_1`, `column_2`, and so on.
+    #[serde(default = "has_header_default", skip_serializing_if = "Clone::clone")]
+    pub has_header: bool,
+    /// Prefix of comment lines skipped before the header.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_prefix: Option<char>,
+    /// Records missing at least one column's key, for JSON Lines input.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ragged_records: Option<usize>,
+    /// Column ranges of a fixed-width file, as `(start, end)` character offsets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixed_width: Option<Vec<(usize, usize)>>,
+    /// Duplicate or empty headers renamed while parsing.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub header_renames: Vec<HeaderRename>,
+    /// Comment or metadata lines skipped before the header, as read.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub preamble: Vec<String>,
+    /// Order-independent hash of the parsed row contents.
+    ///
+    /// When the file was sampled this covers only the sampled rows, and is
+    /// labeled with [`SAMPLE_HASH_PREFIX`].
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub content_hash: String,
     /// Number of data rows (excluding header).
     pub row_count: usize,
+    /// Number of rows read for analysis, when the file was sampled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sampled_rows: Option<usize>,
     /// Number of columns.
     pub column_count: usize,
+    /// Columns analyzed, when analysis was restricted to some of them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub selected_columns: Vec<String>,
+    /// Columns requested for analysis that the file doesn't have.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing_columns: Vec<String>,
     /// When the analysis was performed.
     pub analyzed_at: DateTime<Utc>,
 }
 
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
+fn has_header_default() -> bool {
+    true
+}
+
 impl SourceMetadata {
     /// Create metadata for a file that has been analyzed.
     pub fn new(
@@ -48,13 +117,93 @@ impl SourceMetadata {
             path,
             hash,
             size_bytes,
+            compressed: false,
+            uncompressed_size: None,
             format,
             encoding: "utf-8".to_string(),
+            sheet: None,
+            sheet_count: None,
+            detected_delimiter: None,
+            delimiter: None,
+            skip_rows: 0,
+            has_header: true,
+            comment_prefix: None,
+            ragged_records: None,
+            fixed_width: None,
+            header_renames: Vec::new(),
+            preamble: Vec::new(),
+            content_hash: String::new(),
             row_count,
+            sampled_rows: None,
             column_count,
+            selected_columns: Vec::new(),
+            missing_columns: Vec::new(),
             analyzed_at: Utc::now(),
         }
     }
+
+    /// Metadata for input read from a stream rather than a file.
+    ///
+    /// `name` is the logical file name (e.g. `cohort.tsv`), which stands in
+    /// for the path when detecting the format. The path is left empty; the
+    /// rest is filled in when the stream is parsed.
+    pub fn stream(name: impl Into<String>) -> Self {
+        Self {
+            file: name.into(),
+            ..Self::new(PathBuf::new(), String::new(), 0, String::new(), 0, 0)
+        }
+    }
+
+    /// Parse stream input with this delimiter instead of detecting one.
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Whether the data was read from a stream, with no file behind it.
+    pub fn is_stream(&self) -> bool {
+        self.path.as_os_str().is_empty()
+    }
+
+    /// Compute a stable fingerprint of the dataset's shape and content.
+    ///
+    /// Combines the normalized (trimmed, lowercased, sorted) header set, the
+    /// row count, and the content hash. Two deliveries of the same data produce
+    /// the same fingerprint even if rows or columns were reordered. Falls back
+    /// to the file hash when the content hash only covers a sample, and for
+    /// metadata saved before content hashing existed.
+    pub fn fingerprint<S: AsRef<str>>(&self, headers: &[S]) -> String {
+        let mut normalized: Vec<String> = headers
+            .iter()
+            .map(|h| h.as_ref().trim().to_lowercase())
+            .collect();
+        normalized.sort();
+
+        let content = if self.content_hash.is_empty() || self.content_hash.starts_with(SAMPLE_HASH_PREFIX) {
+            &self.hash
+        } else {
+            &self.content_hash
+        };
+
+        let mut hasher = Sha256::new();
+        hasher.update(normalized.join("\u{1f}").as_bytes());
+        hasher.update(b"\n");
+        hasher.update(self.row_count.to_string().as_bytes());
+        hasher.update(b"\n");
+        hasher.update(content.as_bytes());
+        format!("sha256:{:x}", hasher.finalize())
+    }
+}
+
+/// A header renamed to make column names unique and non-empty.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HeaderRename {
+    /// Zero-based column position.
+    pub position: usize,
+    /// Header as it appeared in the file.
+    pub original: String,
+    /// Header the column was given.
+    pub renamed: String,
 }
 
 /// Represents parsed tabular data.
@@ -66,6 +215,16 @@ pub struct DataTable {
     pub rows: Vec<Vec<String>>,
     /// The delimiter used.
     pub delimiter: u8,
+    /// Total data rows in the file, when only a sample was read into `rows`.
+    ///
+    /// Rates computed over `rows` estimate rates for the whole file; use
+    /// [`DataTable::total_row_count`] when a count for the whole file is needed.
+    pub total_rows: Option<usize>,
+    /// Line of the source text where each row starts, 1-based, counting
+    /// skipped preamble lines, blank lines, and quoted fields that span
+    /// several lines. Empty when the table wasn't parsed from text, as for
+    /// workbooks.
+    pub row_lines: Vec<usize>,
 }
 
 impl DataTable {
@@ -75,9 +234,16 @@ impl DataTable {
             headers,
             rows,
             delimiter,
+            total_rows: None,
+            row_lines: Vec::new(),
         }
     }
 
+    /// Line of the source text where a row starts, 1-based, if known.
+    pub fn row_line(&self, row: usize) -> Option<usize> {
+        self.row_lines.get(row).copied()
+    }
+
     /// Get the number of columns.
     pub fn column_count(&self) -> usize {
         self.headers.len()
@@ -88,6 +254,16 @@ impl DataTable {
         self.rows.len()
     }
 
+    /// Get the number of data rows in the source file, including unsampled rows.
+    pub fn total_row_count(&self) -> usize {
+        self.total_rows.unwrap_or(self.rows.len())
+    }
+
+    /// Whether only a sample of the file's rows was read.
+    pub fn is_sampled(&self) -> bool {
+        self.total_rows.is_some_and(|total| total > self.rows.len())
+    }
+
     /// Get all values for a column by index.
     pub fn column_values(&self, index: usize) -> impl Iterator<Item = &str> {
         self.rows.iter().map(move |row| {
@@ -97,10 +273,55 @@ impl DataTable {
         })
     }
 
-    /// Get a column by name.
+    /// Get a column by name (see [`DataTable::column_index`] for matching).
     pub fn column_by_name(&self, name: &str) -> Option<Vec<&str>> {
-        let index = self.headers.iter().position(|h| h == name)?;
-        Some(self.column_values(index).collect())
+        self.column_values_by_name(name).map(Iterator::collect)
+    }
+
+    /// Get all values for a column by name (see [`DataTable::column_index`] for matching).
+    pub fn column_values_by_name(&self, name: &str) -> Option<impl Iterator<Item = &str>> {
+        self.column_index(name).map(|index| self.column_values(index))
+    }
+
+    /// Compute an order-independent hash of the row contents.
+    ///
+    /// Each row is hashed with its cells keyed by header, so neither row nor
+    /// column order affects the result.
+    pub fn content_hash(&self) -> String {
+        let mut row_hashes: Vec<[u8; 32]> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let mut cells: Vec<(String, &str)> = self
+                    .headers
+                    .iter()
+                    .enumerate()
+                    .map(|(i, h)| {
+                        (
+                            h.trim().to_lowercase(),
+                            row.get(i).map(|s| s.as_str()).unwrap_or(""),
+                        )
+                    })
+                    .collect();
+                cells.sort();
+
+                let mut hasher = Sha256::new();
+                for (header, value) in cells {
+                    hasher.update(header.as_bytes());
+                    hasher.update([0x1f]);
+                    hasher.update(value.as_bytes());
+                    hasher.update([0x1e]);
+                }
+                hasher.finalize().into()
+            })
+            .collect();
+        row_hashes.sort_unstable();
+
+        let mut hasher = Sha256::new();
+        for row_hash in &row_hashes {
+            hasher.update(row_hash);
+        }
+        format!("sha256:{:x}", hasher.finalize())
     }
 
     /// Get a specific cell value.
@@ -121,9 +342,129 @@ impl DataTable {
             || trimmed == "-"
     }
 
+    /// Check whether the last column looks like a trailing-delimiter artifact.
+    ///
+    /// A phantom column has a blank header and is empty in at least 95% of
+    /// rows, which is what a trailing tab or comma on every line produces.
+    pub fn has_phantom_trailing_column(&self) -> bool {
+        let Some(last) = self.headers.len().checked_sub(1) else {
+            return false;
+        };
+        if !self.headers[last].trim().is_empty() || self.rows.is_empty() {
+            return false;
+        }
+
+        let empty = self
+            .column_values(last)
+            .filter(|v| v.trim().is_empty())
+            .count();
+        empty as f64 / self.rows.len() as f64 >= 0.95
+    }
+
+    /// Remove the last column from the table.
+    pub fn drop_last_column(&mut self) {
+        if self.headers.pop().is_some() {
+            let width = self.headers.len();
+            for row in &mut self.rows {
+                row.truncate(width);
+            }
+        }
+    }
+
+    /// Rename empty and duplicate headers so every column has a unique name.
+    ///
+    /// Empty headers become `column_N` (1-based position); later copies of a
+    /// name get a numeric suffix (`date`, `date_2`). A phantom trailing
+    /// column keeps its blank header so it can still be recognized.
+    pub fn dedupe_headers(&mut self) -> Vec<HeaderRename> {
+        let phantom = self.has_phantom_trailing_column();
+        let last = self.headers.len().saturating_sub(1);
+
+        let mut taken: HashSet<String> = self.headers.iter().cloned().collect();
+        let mut seen: HashSet<String> = HashSet::new();
+        let mut renames = Vec::new();
+
+        for position in 0..self.headers.len() {
+            let original = self.headers[position].clone();
+            let base = if original.trim().is_empty() {
+                if phantom && position == last {
+                    continue;
+                }
+                format!("column_{}", position + 1)
+            } else if seen.insert(original.clone()) {
+                continue;
+            } else {
+                original.clone()
+            };
+
+            let mut renamed = base.clone();
+            let mut suffix = 2;
+            while taken.contains(&renamed) {
+                renamed = format!("{}_{}", base, suffix);
+                suffix += 1;
+            }
+            taken.insert(renamed.clone());
+            seen.insert(renamed.clone());
+
+            self.headers[position] = renamed.clone();
+            renames.push(HeaderRename {
+                position,
+                original,
+                renamed,
+            });
+        }
+
+        renames
+    }
+
+    /// Undo header renames, restoring the names from the file.
+    ///
+    /// Renames whose column no longer carries the renamed header are skipped.
+    pub fn restore_headers(&mut self, renames: &[HeaderRename]) {
+        for rename in renames {
+            if self.headers.get(rename.position) == Some(&rename.renamed) {
+                self.headers[rename.position] = rename.original.clone();
+            }
+        }
+    }
+
+    /// Keep only the named columns, in file order, returning the names
+    /// that match no column.
+    ///
+    /// Names are matched as in [`column_index`](Self::column_index). Values
+    /// of the kept columns are moved, not copied.
+    pub fn retain_columns<S: AsRef<str>>(&mut self, names: &[S]) -> Vec<String> {
+        let mut keep = vec![false; self.headers.len()];
+        let mut missing = Vec::new();
+        for name in names {
+            match self.column_index(name.as_ref()) {
+                Some(index) => keep[index] = true,
+                None => missing.push(name.as_ref().to_string()),
+            }
+        }
+
+        let retain = |values: &mut Vec<String>| {
+            let mut index = 0;
+            values.retain(|_| {
+                index += 1;
+                keep.get(index - 1).copied().unwrap_or(false)
+            });
+        };
+        retain(&mut self.headers);
+        self.rows.iter_mut().for_each(retain);
+
+        missing
+    }
+
     /// Get column index by name.
+    ///
+    /// An exact match is preferred; otherwise the name is matched ignoring
+    /// case. With duplicate headers the first match wins.
     pub fn column_index(&self, name: &str) -> Option<usize> {
-        self.headers.iter().position(|h| h == name)
+        self.headers.iter().position(|h| h == name).or_else(|| {
+            let lower = name.to_lowercase();
+            self.headers.iter().position(|h| h.to_lowercase() == lower)
+        })
     }
 
     /// Set a specific cell value.
@@ -145,22 +486,97 @@ impl DataTable {
         }
     }
 
+    /// Remove the column at `index`.
+    pub fn remove_column(&mut self, index: usize) {
+        if index < self.headers.len() {
+            self.headers.remove(index);
+            for row in &mut self.rows {
+                if index < row.len() {
+                    row.remove(index);
+                }
+            }
+        }
+    }
+
     /// Write the table to a file in the specified format.
     pub fn write_to_file(&self, path: &std::path::Path, delimiter: u8) -> std::io::Result<()> {
-        use std::io::Write;
-        let mut file = std::fs::File::create(path)?;
-
-        // Write header
-        let header_line = self.headers.join(&String::from_utf8(vec![delimiter]).unwrap());
-        writeln!(file, "{}", header_line)?;
+        self.write_delimited(std::fs::File::create(path)?, delimiter)
+    }
 
-        // Write rows
+    /// Write the table as delimited text to any writer.
+    ///
+    /// Values are quoted per RFC 4180 where needed, so ones containing the
+    /// delimiter, quotes, or line breaks read back unchanged.
+    pub fn write_delimited<W: std::io::Write>(&self, writer: W, delimiter: u8) -> std::io::Result<()> {
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(delimiter)
+            .flexible(true)
+            .from_writer(writer);
+        writer.write_record(&self.headers)?;
         for row in &self.rows {
-            let row_line = row.join(&String::from_utf8(vec![delimiter]).unwrap());
-            writeln!(file, "{}", row_line)?;
+            writer.write_record(row)?;
         }
+        writer.flush()
+    }
 
-        Ok(())
+    /// Write the table as fixed-width text in the given column layout.
+    ///
+    /// Each cell is left-aligned and padded to its range, with the header
+    /// written first when `has_header` is set. Columns beyond the layout
+    /// (such as added flag columns) follow, one space apart, as wide as their
+    /// longest value. Fails, without creating the file, if a value doesn't
+    /// fit its range.
+    pub fn write_fixed_width(
+        &self,
+        path: &std::path::Path,
+        layout: &[(usize, usize)],
+        has_header: bool,
+    ) -> std::io::Result<()> {
+        // Extend the layout to cover any added columns
+        let mut ranges = layout.to_vec();
+        for col in ranges.len()..self.headers.len() {
+            let start = ranges.last().map(|&(_, end)| end + 1).unwrap_or(0);
+            let width = std::iter::once(&self.headers[col])
+                .chain(self.rows.iter().filter_map(|row| row.get(col)))
+                .map(|value| value.chars().count())
+                .max()
+                .unwrap_or(0);
+            ranges.push((start, start + width.max(1)));
+        }
+
+        let format_line = |cells: &[String]| -> std::io::Result<String> {
+            let mut line = String::new();
+            for (col, &(start, end)) in ranges.iter().enumerate().take(cells.len()) {
+                let value = &cells[col];
+                let width = end - start;
+                if value.chars().count() > width {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!(
+                            "Value '{}' in column '{}' is wider than its {} character(s)",
+                            value,
+                            self.headers.get(col).map(String::as_str).unwrap_or_default(),
+                            width
+                        ),
+                    ));
+                }
+                let padding = start.saturating_sub(line.chars().count());
+                line.extend(std::iter::repeat_n(' ', padding));
+                line.push_str(&format!("{:<width$}", value, width = width));
+            }
+            Ok(line.trim_end().to_string())
+        };
+
+        // Format every line before creating the file, so a value that
+        // doesn't fit leaves no partial output behind
+        let mut output = String::new();
+        let header = has_header.then_some(&self.headers);
+        for cells in header.into_iter().chain(&self.rows) {
+            output.push_str(&format_line(cells)?);
+            output.push('\n');
+        }
+
+        std::fs::write(path, output)
     }
 
     /// Write the table to a JSON file.
@@ -211,3 +627,101 @@ impl DataTable {
         serde_json::Value::Array(rows)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_table(headers: Vec<&str>) -> DataTable {
+        let row = (0..headers.len()).map(|i| i.to_string()).collect();
+        DataTable::new(headers.into_iter().map(String::from).collect(), vec![row], b',')
+    }
+
+    #[test]
+    fn test_column_index_prefers_exact_match() {
+        let table = make_table(vec!["Age", "age", "Sex"]);
+
+        assert_eq!(table.column_index("age"), Some(1));
+        assert_eq!(table.column_index("Age"), Some(0));
+        assert_eq!(table.column_index("SEX"), Some(2));
+        assert_eq!(table.column_index("bmi"), None);
+    }
+
+    #[test]
+    fn test_column_index_duplicate_headers() {
+        let table = make_table(vec!["id", "Site", "site", "Site"]);
+
+        // Exact duplicates resolve to the first occurrence
+        assert_eq!(table.column_index("Site"), Some(1));
+        assert_eq!(table.column_index("SITE"), Some(1));
+        let values: Vec<&str> = table.column_values_by_name("SITE").unwrap().collect();
+        assert_eq!(values, vec!["1"]);
+        assert_eq!(table.column_by_name("site"), Some(vec!["2"]));
+        assert!(table.column_values_by_name("depth").is_none());
+    }
+
+    #[test]
+    fn test_retain_columns() {
+        let mut table = make_table(vec!["id", "site", "age", "bmi"]);
+
+        let missing = table.retain_columns(&["AGE", "id", "depth"]);
+
+        assert_eq!(missing, vec!["depth"]);
+        assert_eq!(table.headers, vec!["id", "age"]);
+        assert_eq!(table.rows, vec![vec!["0", "2"]]);
+    }
+
+    #[test]
+    fn test_write_delimited_quotes_values() {
+        let table = DataTable::new(
+            vec!["site".to_string(), "note".to_string()],
+            vec![vec!["Lake, north".to_string(), "said \"dry\"\nafter rain".to_string()]],
+            b',',
+        );
+
+        let mut out = Vec::new();
+        table.write_delimited(&mut out, b',').unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "site,note\n\"Lake, north\",\"said \"\"dry\"\"\nafter rain\"\n"
+        );
+
+        let mut out = Vec::new();
+        table.write_delimited(&mut out, b'\t').unwrap();
+        let mut reader = csv::ReaderBuilder::new().delimiter(b'\t').from_reader(out.as_slice());
+        let row = reader.records().next().unwrap().unwrap();
+        assert_eq!(row, csv::StringRecord::from(table.rows[0].clone()));
+    }
+
+    #[test]
+    fn test_dedupe_headers() {
+        let mut table = make_table(vec!["date", "", "date", "date_2", "date"]);
+
+        let renames = table.dedupe_headers();
+
+        assert_eq!(
+            table.headers,
+            vec!["date", "column_2", "date_3", "date_2", "date_4"]
+        );
+        let renamed: Vec<(usize, &str)> = renames
+            .iter()
+            .map(|r| (r.position, r.original.as_str()))
+            .collect();
+        assert_eq!(renamed, vec![(1, ""), (2, "date"), (4, "date")]);
+
+        table.restore_headers(&renames);
+        assert_eq!(table.headers, vec!["date", "", "date", "date_2", "date"]);
+    }
+
+    #[test]
+    fn test_dedupe_headers_keeps_phantom_trailing_column() {
+        let mut table = DataTable::new(
+            vec!["id".to_string(), "".to_string()],
+            vec![vec!["S1".to_string(), "".to_string()]],
+            b'\t',
+        );
+
+        assert!(table.dedupe_headers().is_empty());
+        assert!(table.has_phantom_trailing_column());
+    }
+}
@@ -0,0 +1,103 @@
+//! Excel workbook input backend.
+//!
+//! Reads one worksheet into rows of strings so the rest of the pipeline sees
+//! the same shape of data as a delimited file.
+
+use std::io::Cursor;
+
+use calamine::{Data, Reader, Xlsx};
+
+use crate::error::{CrucibleError, Result};
+
+/// A worksheet read from a workbook.
+pub(super) struct Sheet {
+    /// Name of the worksheet that was read.
+    pub name: String,
+    /// Total number of worksheets in the workbook.
+    pub sheet_count: usize,
+    /// Cell values as strings, header row included.
+    pub records: Vec<Vec<String>>,
+}
+
+/// Names of the worksheets in xlsx bytes, in workbook order.
+pub(super) fn sheet_names(bytes: &[u8]) -> Result<Vec<String>> {
+    let workbook: Xlsx<_> = Xlsx::new(Cursor::new(bytes))
+        .map_err(|e| CrucibleError::UnsupportedFormat(format!("Invalid xlsx workbook: {}", e)))?;
+    Ok(workbook.sheet_names())
+}
+
+/// Read a worksheet from xlsx bytes (the first one if no name is given).
+pub(super) fn read_sheet(bytes: &[u8], sheet_name: Option<&str>) -> Result<Sheet> {
+    let mut workbook: Xlsx<_> = Xlsx::new(Cursor::new(bytes))
+        .map_err(|e| CrucibleError::UnsupportedFormat(format!("Invalid xlsx workbook: {}", e)))?;
+
+    let names = workbook.sheet_names();
+    let name = match sheet_name {
+        Some(name) if names.iter().any(|n| n == name) => name.to_string(),
+        Some(name) => {
+            return Err(CrucibleError::Config(format!(
+                "Worksheet '{}' not found (available: {})",
+                name,
+                names.join(", ")
+            )));
+        }
+        None => names
+            .first()
+            .cloned()
+            .ok_or_else(|| CrucibleError::EmptyData("Workbook has no worksheets".to_string()))?,
+    };
+
+    let range = workbook.worksheet_range(&name).map_err(|e| {
+        CrucibleError::UnsupportedFormat(format!("Failed to read worksheet '{}': {}", name, e))
+    })?;
+
+    let records = range
+        .rows()
+        .map(|row| row.iter().map(cell_to_string).collect())
+        .collect();
+
+    Ok(Sheet {
+        name,
+        sheet_count: names.len(),
+        records,
+    })
+}
+
+/// Convert a cell to its string representation.
+///
+/// Date cells become ISO `YYYY-MM-DD` (with a time part only when one is
+/// set) and blank cells become empty strings.
+fn cell_to_string(cell: &Data) -> String {
+    match cell {
+        Data::Empty => String::new(),
+        Data::DateTime(dt) if dt.is_datetime() => match dt.as_datetime() {
+            Some(datetime) if datetime.time() == chrono::NaiveTime::MIN => {
+                datetime.format("%Y-%m-%d").to_string()
+            }
+            Some(datetime) => datetime.format("%Y-%m-%dT%H:%M:%S").to_string(),
+            None => dt.as_f64().to_string(),
+        },
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calamine::{ExcelDateTime, ExcelDateTimeType};
+
+    #[test]
+    fn test_cell_to_string() {
+        assert_eq!(cell_to_string(&Data::Empty), "");
+        assert_eq!(cell_to_string(&Data::Int(42)), "42");
+        assert_eq!(cell_to_string(&Data::Float(30.0)), "30");
+        assert_eq!(cell_to_string(&Data::Float(1.5)), "1.5");
+        assert_eq!(cell_to_string(&Data::String("S1".to_string())), "S1");
+
+        let date = ExcelDateTime::new(45306.0, ExcelDateTimeType::DateTime, false);
+        assert_eq!(cell_to_string(&Data::DateTime(date)), "2024-01-15");
+
+        let datetime = ExcelDateTime::new(45306.5, ExcelDateTimeType::DateTime, false);
+        assert_eq!(cell_to_string(&Data::DateTime(datetime)), "2024-01-15T12:00:00");
+    }
+}
@@ -78,6 +78,7 @@ pub mod error;
 pub mod inference;
 pub mod input;
 pub mod llm;
+pub mod progress;
 pub mod schema;
 pub mod suggestion;
 pub mod transform;
@@ -85,16 +86,28 @@ pub mod validation;
 
 mod crucible;
 
-pub use crate::crucible::{AnalysisResult, Crucible, CrucibleConfig};
-pub use curation::{CurationContext, CurationLayer, Decision, DecisionStatus};
+pub use crate::crucible::{AnalysisResult, AppendValidation, Crucible, CrucibleConfig, ObservationCounts};
+pub use curation::{
+    CarryForwardReport, CurationContext, CurationLayer, Decision, DecisionEvent, DecisionStatus,
+};
 pub use error::{CrucibleError, Result};
-pub use input::{ContextHints, DataTable, Parser, SourceMetadata};
+pub use inference::Question;
+pub use input::{ContextHints, DataTable, Locale, Parser, SourceMetadata};
+#[cfg(feature = "polars")]
+pub use input::PolarsExport;
 pub use llm::{
-    AnthropicProvider, CalibratedConfidence, ConfidenceFactor, LlmConfig, LlmProvider,
-    MockProvider, OllamaProvider, OpenAIProvider, QuestionContext, QuestionResponse,
+    AnthropicProvider, CachingProvider, CalibratedConfidence, ConfidenceFactor, LlmConfig,
+    LlmProvider, LlmUsage, MockProvider, OllamaProvider, OpenAIProvider, QuestionContext, QuestionResponse,
     SchemaEnhancement,
 };
-pub use schema::{ColumnSchema, ColumnType, Constraint, SemanticRole, TableSchema};
+pub use progress::{ProgressCallback, ProgressEvent};
+pub use schema::{
+    ColumnSchema, ColumnType, Constraint, InferenceExplanation, InferenceSettings, SemanticRole,
+    TableSchema,
+};
 pub use suggestion::{Suggestion, SuggestionAction, SuggestionEngine};
-pub use transform::{TransformEngine, TransformOperation, TransformResult};
-pub use validation::{Observation, ObservationType, Severity};
+pub use transform::{TransformAudit, TransformEngine, TransformOperation, TransformResult};
+pub use validation::{
+    CohortReport, ConfidenceHistogram, DecimalMark, Observation, ObservationType, Parallelism,
+    Profile, Severity,
+};
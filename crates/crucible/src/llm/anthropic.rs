@@ -1,5 +1,10 @@
 //! Anthropic Claude API provider implementation.
+//!
+//! Schema enhancements and suggestions are requested through a forced tool
+//! call whose input schema is the expected response, so the reply arrives as
+//! structured JSON rather than free text.
 
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 use reqwest::blocking::Client;
@@ -15,6 +20,8 @@ use crate::validation::Observation;
 
 use super::prompts;
 use super::provider::{LlmConfig, LlmProvider, SchemaEnhancement};
+use super::retry::{HttpReply, RetryPolicy};
+use super::structured::{self, SchemaEnhancementResponse, StructuredOutput, SuggestionResponse};
 
 /// Anthropic API endpoint.
 const API_URL: &str = "https://api.anthropic.com/v1/messages";
@@ -27,6 +34,8 @@ pub struct AnthropicProvider {
     client: Client,
     api_key: String,
     config: LlmConfig,
+    /// Cleared once the API rejects a tool-use request.
+    structured_supported: AtomicBool,
 }
 
 impl AnthropicProvider {
@@ -46,6 +55,7 @@ impl AnthropicProvider {
             client,
             api_key: api_key.into(),
             config,
+            structured_supported: AtomicBool::new(true),
         })
     }
 
@@ -75,9 +85,9 @@ impl AnthropicProvider {
         Ok(headers)
     }
 
-    /// Send a message to the Claude API.
-    fn send_message(&self, user_prompt: &str) -> Result<String> {
-        let body = json!({
+    /// Build a Messages API request body for a prompt.
+    fn message_body(&self, user_prompt: &str) -> Value {
+        json!({
             "model": self.config.model,
             "max_tokens": self.config.max_tokens,
             "temperature": self.config.temperature,
@@ -88,27 +98,38 @@ impl AnthropicProvider {
                     "content": user_prompt
                 }
             ]
-        });
-
-        let response = self
-            .client
-            .post(API_URL)
-            .headers(self.build_headers()?)
-            .json(&body)
-            .send()
-            .map_err(|e| CrucibleError::Config(format!("API request failed: {}", e)))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().unwrap_or_default();
+        })
+    }
+
+    /// Post a request body, retrying transient failures.
+    fn post(&self, body: &Value) -> Result<HttpReply> {
+        let headers = self.build_headers()?;
+        RetryPolicy::from_config(&self.config).run(
+            || {
+                self.client
+                    .post(API_URL)
+                    .headers(headers.clone())
+                    .json(body)
+                    .send()
+                    .map(HttpReply::from_response)
+                    .map_err(|e| CrucibleError::Config(format!("API request failed: {}", e)))
+            },
+            std::thread::sleep,
+        )
+    }
+
+    /// Send a message to the Claude API.
+    fn send_message(&self, user_prompt: &str) -> Result<String> {
+        let reply = self.post(&self.message_body(user_prompt))?;
+
+        if !reply.status.is_success() {
             return Err(CrucibleError::Config(format!(
                 "API error ({}): {}",
-                status, error_text
+                reply.status, reply.body
             )));
         }
 
-        let api_response: ApiResponse = response
-            .json()
+        let api_response: ApiResponse = serde_json::from_str(&reply.body)
             .map_err(|e| CrucibleError::Config(format!("Failed to parse API response: {}", e)))?;
 
         // Extract text from response
@@ -125,29 +146,77 @@ impl AnthropicProvider {
             .ok_or_else(|| CrucibleError::Config("No text in API response".to_string()))
     }
 
+    /// Request a response as a forced tool call.
+    ///
+    /// Returns `None` when structured output is disabled or unsupported. A
+    /// reply that matches the expected response neither as tool input nor as
+    /// text is an error rather than a reason to ask again.
+    fn send_structured<T: StructuredOutput>(&self, user_prompt: &str) -> Result<Option<T>> {
+        if !self.config.structured_output || !self.structured_supported.load(Ordering::Relaxed) {
+            return Ok(None);
+        }
+
+        let mut body = self.message_body(user_prompt);
+        body["tools"] = json!([{
+            "name": T::NAME,
+            "description": T::DESCRIPTION,
+            "input_schema": T::json_schema()
+        }]);
+        body["tool_choice"] = json!({"type": "tool", "name": T::NAME});
+
+        let reply = self.post(&body)?;
+        if !reply.status.is_success() {
+            if structured::is_unsupported(reply.status, &reply.body) {
+                self.structured_supported.store(false, Ordering::Relaxed);
+                return Ok(None);
+            }
+            return Err(CrucibleError::Config(format!(
+                "API error ({}): {}",
+                reply.status, reply.body
+            )));
+        }
+
+        let api_response: ApiResponse = serde_json::from_str(&reply.body)
+            .map_err(|e| CrucibleError::Config(format!("Failed to parse API response: {}", e)))?;
+        structured_reply(api_response).map(Some)
+    }
+
+    /// Request a JSON response, preferring structured output and falling
+    /// back to parsing the free-text reply to the prompt.
+    fn request_json<T: StructuredOutput>(&self, user_prompt: &str) -> Result<T> {
+        if let Some(parsed) = self.send_structured(user_prompt)? {
+            return Ok(parsed);
+        }
+        let response = self.send_message(user_prompt)?;
+        self.parse_json_response(&response)
+    }
+
     /// Parse JSON from LLM response, handling markdown code blocks.
     fn parse_json_response<T: for<'de> Deserialize<'de>>(&self, response: &str) -> Result<T> {
-        // Try to extract JSON from markdown code block if present
-        let json_str = if response.contains("```json") {
-            response
-                .split("```json")
-                .nth(1)
-                .and_then(|s| s.split("```").next())
-                .map(|s| s.trim())
-                .unwrap_or(response)
-        } else if response.contains("```") {
-            response
-                .split("```")
-                .nth(1)
-                .map(|s| s.trim())
-                .unwrap_or(response)
-        } else {
-            response.trim()
-        };
+        structured::parse_json(response)
+    }
+}
 
-        serde_json::from_str(json_str)
-            .map_err(|e| CrucibleError::Config(format!("Failed to parse LLM JSON response: {}", e)))
+/// Deserialize the input of the first tool call in a response, falling
+/// back to JSON in its text when the model answered without the tool.
+fn structured_reply<T: StructuredOutput>(response: ApiResponse) -> Result<T> {
+    let tool_input = response
+        .content
+        .iter()
+        .find(|block| block.content_type == "tool_use")
+        .and_then(|block| block.input.clone())
+        .and_then(|input| serde_json::from_value(input).ok());
+    if let Some(parsed) = tool_input {
+        return Ok(parsed);
     }
+
+    let text: Vec<&str> = response
+        .content
+        .iter()
+        .filter(|block| block.content_type == "text")
+        .map(|block| block.text.as_str())
+        .collect();
+    structured::parse_json(&text.join("\n"))
 }
 
 impl LlmProvider for AnthropicProvider {
@@ -167,9 +236,7 @@ impl LlmProvider for AnthropicProvider {
         }
 
         let prompt = prompts::schema_enhancement_prompt(column, samples, context);
-        let response = self.send_message(&prompt)?;
-
-        let parsed: SchemaEnhancementResponse = self.parse_json_response(&response)?;
+        let parsed: SchemaEnhancementResponse = self.request_json(&prompt)?;
 
         Ok(SchemaEnhancement {
             insight: parsed.insight,
@@ -207,9 +274,7 @@ impl LlmProvider for AnthropicProvider {
         }
 
         let prompt = prompts::suggestion_prompt(observation, column, context);
-        let response = self.send_message(&prompt)?;
-
-        let parsed: SuggestionResponse = self.parse_json_response(&response)?;
+        let parsed: SuggestionResponse = self.request_json(&prompt)?;
 
         // If no action suggested, return None
         let action = match parsed.action.as_deref() {
@@ -303,31 +368,9 @@ struct ContentBlock {
     content_type: String,
     #[serde(default)]
     text: String,
-}
-
-/// Parsed schema enhancement response.
-#[derive(Debug, Deserialize)]
-struct SchemaEnhancementResponse {
-    insight: String,
-    #[serde(default)]
-    suggested_role: Option<String>,
+    /// Tool call arguments, for `tool_use` blocks.
     #[serde(default)]
-    potential_issues: Option<String>,
-    #[serde(default)]
-    confidence: f64,
-}
-
-/// Parsed suggestion response.
-#[derive(Debug, Deserialize)]
-struct SuggestionResponse {
-    action: Option<String>,
-    rationale: String,
-    #[serde(default)]
-    parameters: Option<Value>,
-    #[serde(default)]
-    confidence: Option<f64>,
-    #[serde(default)]
-    priority: Option<u8>,
+    input: Option<Value>,
 }
 
 /// Parsed question response.
@@ -369,6 +412,7 @@ mod tests {
             client: Client::new(),
             api_key: "test".to_string(),
             config: LlmConfig::default(),
+            structured_supported: AtomicBool::new(true),
         };
 
         let response = r#"```json
@@ -389,6 +433,7 @@ mod tests {
             client: Client::new(),
             api_key: "test".to_string(),
             config: LlmConfig::default(),
+            structured_supported: AtomicBool::new(true),
         };
 
         let response = r#"{"insight": "Test", "confidence": 0.8}"#;
@@ -396,4 +441,26 @@ mod tests {
         let parsed: SchemaEnhancementResponse = provider.parse_json_response(response).unwrap();
         assert_eq!(parsed.insight, "Test");
     }
+
+    #[test]
+    fn test_structured_reply_falls_back_on_messy_reply() {
+        let reply = r#"{"content": [{"type": "tool_use", "input": {"insight": "Visit date", "confidence": 0.7}}]}"#;
+        let response: ApiResponse = serde_json::from_str(reply).unwrap();
+        let parsed: SchemaEnhancementResponse = structured_reply(response).unwrap();
+        assert_eq!(parsed.insight, "Visit date");
+
+        // Without a usable tool call, JSON in the text is used instead
+        let reply = r#"{"content": [
+            {"type": "text", "text": "Here you go:\n```json\n{\"insight\": \"Visit date\", \"confidence\": 0.6}\n```"},
+            {"type": "tool_use", "input": {"notes": "date?"}}
+        ]}"#;
+        let response: ApiResponse = serde_json::from_str(reply).unwrap();
+        let parsed: SchemaEnhancementResponse = structured_reply(response).unwrap();
+        assert_eq!(parsed.confidence, 0.6);
+
+        // and a reply with neither is an error, not a second request
+        let reply = r#"{"content": [{"type": "text", "text": "Looks like a date column."}]}"#;
+        let response: ApiResponse = serde_json::from_str(reply).unwrap();
+        assert!(structured_reply::<SchemaEnhancementResponse>(response).is_err());
+    }
 }
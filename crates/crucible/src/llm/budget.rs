@@ -0,0 +1,78 @@
+//! Token budget for LLM requests made during analysis.
+
+use serde::{Deserialize, Serialize};
+
+use super::prompts;
+use super::provider::{LlmConfig, QuestionContext};
+use crate::input::ContextHints;
+use crate::schema::ColumnSchema;
+use crate::validation::Observation;
+
+/// Rough token estimate for a prompt, at four characters per token.
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+/// How the LLM budget was spent on observations during analysis.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LlmUsage {
+    /// Configured token budget, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_budget: Option<usize>,
+    /// Estimated tokens spent on question and observation requests.
+    pub estimated_tokens: usize,
+    /// Questions sent to the LLM to answer.
+    #[serde(default)]
+    pub questions: usize,
+    /// Observations sent to the LLM.
+    pub enhanced: usize,
+    /// Observations skipped once the budget ran out (rule-based only).
+    pub skipped: usize,
+}
+
+/// Estimated tokens to explain and suggest a fix for one observation.
+///
+/// Counts the prompt of each enabled request plus the maximum response
+/// length, so the estimate errs on the side of spending too much.
+pub(crate) fn observation_cost(
+    config: &LlmConfig,
+    observation: &Observation,
+    column: Option<&ColumnSchema>,
+    context: &ContextHints,
+) -> usize {
+    let mut cost = 0;
+    if config.explain_observations {
+        let prompt = prompts::observation_explanation_prompt(observation, column, context);
+        cost += estimate_tokens(&prompt) + config.max_tokens;
+    }
+    if config.generate_suggestions {
+        let prompt = prompts::suggestion_prompt(observation, column, context);
+        cost += estimate_tokens(&prompt) + config.max_tokens;
+    }
+    cost
+}
+
+/// Estimated tokens to have the LLM answer one question.
+pub(crate) fn question_cost(config: &LlmConfig, question: &QuestionContext, context: &ContextHints) -> usize {
+    let prompt = prompts::question_prompt(
+        &question.question,
+        question.observation.as_ref(),
+        question.suggestion.as_ref(),
+        question.column.as_ref(),
+        &question.sample_values,
+        context,
+    );
+    estimate_tokens(&prompt) + config.max_tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_tokens() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("abcd"), 1);
+        assert_eq!(estimate_tokens("abcde"), 2);
+    }
+}
@@ -0,0 +1,537 @@
+//! Response caching for LLM providers.
+//!
+//! Re-analyzing the same file sends the same prompts again. [`CachingProvider`]
+//! wraps any provider and stores each response in a JSON file, keyed by a hash
+//! of the prompt text, model, and temperature, so repeated prompts are answered
+//! from disk instead of the API.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::error::{CrucibleError, Result};
+use crate::input::ContextHints;
+use crate::schema::ColumnSchema;
+use crate::suggestion::Suggestion;
+use crate::validation::Observation;
+
+use super::prompts;
+use super::provider::{
+    CalibratedConfidence, LlmConfig, LlmProvider, QuestionContext, QuestionResponse,
+    SchemaEnhancement,
+};
+
+/// A cached LLM response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedResponse {
+    /// The provider's result, serialized.
+    pub response: Value,
+    /// When the response was stored.
+    pub cached_at: DateTime<Utc>,
+}
+
+/// On-disk format of the response cache.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ResponseCacheFile {
+    #[serde(default)]
+    entries: HashMap<String, CachedResponse>,
+}
+
+/// An [`LlmProvider`] wrapper that caches responses in a JSON file.
+///
+/// On a hit the cached response is returned without calling the wrapped
+/// provider; on a miss the call is delegated and the response kept in
+/// memory. New responses are written to the cache file in one go by
+/// [`flush`](Self::flush), or when the provider is dropped.
+pub struct CachingProvider<P: LlmProvider> {
+    inner: P,
+    path: PathBuf,
+    ttl: Option<Duration>,
+    entries: Mutex<HashMap<String, CachedResponse>>,
+    /// Whether entries were added since the file was last written.
+    dirty: AtomicBool,
+}
+
+impl<P: LlmProvider> CachingProvider<P> {
+    /// Wrap a provider, loading any existing cache from `path`.
+    ///
+    /// A missing cache file starts an empty cache.
+    pub fn new(inner: P, path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let entries = Self::load(&path)?;
+
+        Ok(Self {
+            inner,
+            path,
+            ttl: None,
+            entries: Mutex::new(entries),
+            dirty: AtomicBool::new(false),
+        })
+    }
+
+    /// Treat entries older than `ttl` as stale and re-request them.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Path of the cache file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Number of cached responses.
+    pub fn len(&self) -> usize {
+        self.entries.lock().map(|e| e.len()).unwrap_or(0)
+    }
+
+    /// Whether the cache holds no responses.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Write responses added since the last flush to the cache file.
+    ///
+    /// Does nothing when no response was added.
+    pub fn flush(&self) -> Result<()> {
+        if !self.dirty.swap(false, Ordering::SeqCst) {
+            return Ok(());
+        }
+        let entries = self
+            .entries
+            .lock()
+            .map_err(|_| CrucibleError::Persistence("LLM cache lock poisoned".to_string()))?;
+        self.save(&entries).inspect_err(|_| self.dirty.store(true, Ordering::SeqCst))
+    }
+
+    fn load(path: &Path) -> Result<HashMap<String, CachedResponse>> {
+        if !path.exists() {
+            return Ok(HashMap::new());
+        }
+
+        let file = File::open(path).map_err(|e| {
+            CrucibleError::Persistence(format!(
+                "Failed to open LLM cache '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        let cache: ResponseCacheFile =
+            serde_json::from_reader(BufReader::new(file)).map_err(|e| {
+                CrucibleError::Persistence(format!(
+                    "Failed to parse LLM cache '{}': {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+
+        Ok(cache.entries)
+    }
+
+    fn save(&self, entries: &HashMap<String, CachedResponse>) -> Result<()> {
+        let missing_parent = self
+            .path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty() && !p.exists());
+        if let Some(parent) = missing_parent {
+            fs::create_dir_all(parent).map_err(|e| {
+                CrucibleError::Persistence(format!(
+                    "Failed to create directory '{}': {}",
+                    parent.display(),
+                    e
+                ))
+            })?;
+        }
+
+        let file = File::create(&self.path).map_err(|e| {
+            CrucibleError::Persistence(format!(
+                "Failed to create LLM cache '{}': {}",
+                self.path.display(),
+                e
+            ))
+        })?;
+
+        let cache = ResponseCacheFile {
+            entries: entries.clone(),
+        };
+        serde_json::to_writer_pretty(BufWriter::new(file), &cache).map_err(|e| {
+            CrucibleError::Persistence(format!("Failed to serialize LLM cache: {}", e))
+        })
+    }
+
+    /// Cache key for a prompt under the wrapped provider's model settings.
+    fn key(&self, kind: &str, prompt: &str) -> String {
+        let config = self.inner.config();
+        let mut hasher = Sha256::new();
+        hasher.update(kind.as_bytes());
+        hasher.update([0x1f]);
+        hasher.update(config.model.as_bytes());
+        hasher.update([0x1f]);
+        hasher.update(config.temperature.to_bits().to_be_bytes());
+        hasher.update([0x1f]);
+        hasher.update(prompt.as_bytes());
+        format!("sha256:{:x}", hasher.finalize())
+    }
+
+    fn is_fresh(&self, entry: &CachedResponse) -> bool {
+        match self.ttl {
+            Some(ttl) => {
+                let age = Utc::now().signed_duration_since(entry.cached_at);
+                age.to_std().map(|age| age <= ttl).unwrap_or(true)
+            }
+            None => true,
+        }
+    }
+
+    /// Return the cached response for a prompt, or call `fetch` and store it.
+    fn cached<T, F>(&self, kind: &str, prompt: &str, fetch: F) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnOnce() -> Result<T>,
+    {
+        let key = self.key(kind, prompt);
+
+        let hit = self
+            .entries
+            .lock()
+            .ok()
+            .and_then(|entries| entries.get(&key).cloned())
+            .filter(|entry| self.is_fresh(entry))
+            .and_then(|entry| serde_json::from_value(entry.response).ok());
+        if let Some(response) = hit {
+            return Ok(response);
+        }
+
+        let response = fetch()?;
+
+        let mut entries = self
+            .entries
+            .lock()
+            .map_err(|_| CrucibleError::Persistence("LLM cache lock poisoned".to_string()))?;
+        entries.insert(
+            key,
+            CachedResponse {
+                response: serde_json::to_value(&response)?,
+                cached_at: Utc::now(),
+            },
+        );
+        self.dirty.store(true, Ordering::SeqCst);
+
+        Ok(response)
+    }
+}
+
+impl<P: LlmProvider> Drop for CachingProvider<P> {
+    /// Write unflushed responses; errors are ignored, so call
+    /// [`flush`](Self::flush) to see them.
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+impl<P: LlmProvider> LlmProvider for CachingProvider<P> {
+    fn enhance_schema(
+        &self,
+        column: &ColumnSchema,
+        samples: &[String],
+        context: &ContextHints,
+    ) -> Result<SchemaEnhancement> {
+        let prompt = prompts::schema_enhancement_prompt(column, samples, context);
+        self.cached("enhance_schema", &prompt, || {
+            self.inner.enhance_schema(column, samples, context)
+        })
+    }
+
+    fn explain_observation(
+        &self,
+        observation: &Observation,
+        column: Option<&ColumnSchema>,
+        context: &ContextHints,
+    ) -> Result<String> {
+        let prompt = prompts::observation_explanation_prompt(observation, column, context);
+        self.cached("explain_observation", &prompt, || {
+            self.inner.explain_observation(observation, column, context)
+        })
+    }
+
+    fn generate_suggestion(
+        &self,
+        observation: &Observation,
+        column: Option<&ColumnSchema>,
+        context: &ContextHints,
+    ) -> Result<Option<Suggestion>> {
+        let prompt = prompts::suggestion_prompt(observation, column, context);
+        let suggestion: Option<Suggestion> = self.cached("generate_suggestion", &prompt, || {
+            self.inner.generate_suggestion(observation, column, context)
+        })?;
+
+        // A cached suggestion may have been generated for an earlier run's observation
+        Ok(suggestion.map(|mut s| {
+            s.observation_id = observation.id.clone();
+            s
+        }))
+    }
+
+    fn config(&self) -> &LlmConfig {
+        self.inner.config()
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn answer_question(
+        &self,
+        question_context: &QuestionContext,
+        hints: &ContextHints,
+    ) -> Result<QuestionResponse> {
+        let prompt = prompts::question_prompt(
+            &question_context.question,
+            question_context.observation.as_ref(),
+            question_context.suggestion.as_ref(),
+            question_context.column.as_ref(),
+            &question_context.sample_values,
+            hints,
+        );
+        self.cached("answer_question", &prompt, || {
+            self.inner.answer_question(question_context, hints)
+        })
+    }
+
+    fn calibrate_confidence(
+        &self,
+        observation: &Observation,
+        column: Option<&ColumnSchema>,
+        hints: &ContextHints,
+    ) -> Result<CalibratedConfidence> {
+        let prompt = prompts::confidence_calibration_prompt(observation, column, hints);
+        self.cached("calibrate_confidence", &prompt, || {
+            self.inner.calibrate_confidence(observation, column, hints)
+        })
+    }
+
+    /// Embeddings are cached per text, so only texts not seen before are sent.
+    fn embed(&self, texts: &[String]) -> Result<Option<Vec<Vec<f32>>>> {
+        let kind = format!(
+            "embed:{}",
+            self.inner.config().embedding_model.as_deref().unwrap_or_default()
+        );
+        let keys: Vec<String> = texts.iter().map(|text| self.key(&kind, text)).collect();
+
+        let mut embeddings: Vec<Option<Vec<f32>>> = {
+            let entries = self
+                .entries
+                .lock()
+                .map_err(|_| CrucibleError::Persistence("LLM cache lock poisoned".to_string()))?;
+            keys.iter()
+                .map(|key| {
+                    entries
+                        .get(key)
+                        .filter(|entry| self.is_fresh(entry))
+                        .and_then(|entry| serde_json::from_value(entry.response.clone()).ok())
+                })
+                .collect()
+        };
+
+        let missing: Vec<usize> = (0..texts.len()).filter(|&i| embeddings[i].is_none()).collect();
+        if !missing.is_empty() {
+            let requested: Vec<String> = missing.iter().map(|&i| texts[i].clone()).collect();
+            let Some(fetched) = self.inner.embed(&requested)? else {
+                return Ok(None);
+            };
+            if fetched.len() != requested.len() {
+                return Err(CrucibleError::Config(format!(
+                    "Expected {} embeddings, got {}",
+                    requested.len(),
+                    fetched.len()
+                )));
+            }
+
+            let mut entries = self
+                .entries
+                .lock()
+                .map_err(|_| CrucibleError::Persistence("LLM cache lock poisoned".to_string()))?;
+            for (&i, embedding) in missing.iter().zip(fetched) {
+                entries.insert(
+                    keys[i].clone(),
+                    CachedResponse {
+                        response: serde_json::to_value(&embedding)?,
+                        cached_at: Utc::now(),
+                    },
+                );
+                embeddings[i] = Some(embedding);
+            }
+            self.dirty.store(true, Ordering::SeqCst);
+        }
+
+        Ok(embeddings.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use super::*;
+    use crate::llm::MockProvider;
+
+    /// Counts calls that reach the wrapped provider.
+    struct CountingProvider {
+        inner: MockProvider,
+        calls: AtomicUsize,
+    }
+
+    impl LlmProvider for CountingProvider {
+        fn enhance_schema(
+            &self,
+            column: &ColumnSchema,
+            samples: &[String],
+            context: &ContextHints,
+        ) -> Result<SchemaEnhancement> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.inner.enhance_schema(column, samples, context)
+        }
+
+        fn explain_observation(
+            &self,
+            observation: &Observation,
+            column: Option<&ColumnSchema>,
+            context: &ContextHints,
+        ) -> Result<String> {
+            self.inner.explain_observation(observation, column, context)
+        }
+
+        fn generate_suggestion(
+            &self,
+            observation: &Observation,
+            column: Option<&ColumnSchema>,
+            context: &ContextHints,
+        ) -> Result<Option<Suggestion>> {
+            self.inner.generate_suggestion(observation, column, context)
+        }
+
+        fn config(&self) -> &LlmConfig {
+            self.inner.config()
+        }
+
+        fn name(&self) -> &str {
+            "counting"
+        }
+
+        fn answer_question(
+            &self,
+            question_context: &QuestionContext,
+            hints: &ContextHints,
+        ) -> Result<QuestionResponse> {
+            self.inner.answer_question(question_context, hints)
+        }
+
+        fn calibrate_confidence(
+            &self,
+            observation: &Observation,
+            column: Option<&ColumnSchema>,
+            hints: &ContextHints,
+        ) -> Result<CalibratedConfidence> {
+            self.inner.calibrate_confidence(observation, column, hints)
+        }
+
+        /// Counts texts sent, rather than calls.
+        fn embed(&self, texts: &[String]) -> Result<Option<Vec<Vec<f32>>>> {
+            self.calls.fetch_add(texts.len(), Ordering::SeqCst);
+            self.inner.embed(texts)
+        }
+    }
+
+    fn counting() -> CountingProvider {
+        CountingProvider {
+            inner: MockProvider::new(),
+            calls: AtomicUsize::new(0),
+        }
+    }
+
+    #[test]
+    fn test_cache_hit_skips_provider() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("llm-cache.json");
+        let column = ColumnSchema::new("age", 0);
+        let samples = vec!["30".to_string(), "40".to_string()];
+        let context = ContextHints::new();
+
+        let provider = CachingProvider::new(counting(), &path).unwrap();
+        let first = provider.enhance_schema(&column, &samples, &context).unwrap();
+        let second = provider.enhance_schema(&column, &samples, &context).unwrap();
+        assert_eq!(first.insight, second.insight);
+        assert_eq!(provider.inner.calls.load(Ordering::SeqCst), 1);
+
+        // New responses are written once, on flush, and reused by a new provider
+        assert!(!path.exists());
+        provider.flush().unwrap();
+        let provider = CachingProvider::new(counting(), &path).unwrap();
+        assert_eq!(provider.len(), 1);
+        provider.enhance_schema(&column, &samples, &context).unwrap();
+        assert_eq!(provider.inner.calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_embeddings_cached_per_text() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("llm-cache.json");
+        let texts = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+
+        let provider = CachingProvider::new(counting(), &path).unwrap();
+        let first = provider.embed(&texts(&["stool", "feces"])).unwrap().unwrap();
+        assert_eq!(provider.inner.calls.load(Ordering::SeqCst), 2);
+
+        // Only the new text is sent; cached vectors come back in order
+        let second = provider
+            .embed(&texts(&["feces", "urine", "stool"]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(provider.inner.calls.load(Ordering::SeqCst), 3);
+        assert_eq!(second[0], first[1]);
+        assert_eq!(second[2], first[0]);
+        assert_eq!(provider.len(), 3);
+    }
+
+    #[test]
+    fn test_unflushed_responses_saved_on_drop() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("llm-cache.json");
+        let column = ColumnSchema::new("age", 0);
+        let context = ContextHints::new();
+
+        let provider = CachingProvider::new(counting(), &path).unwrap();
+        provider.enhance_schema(&column, &[], &context).unwrap();
+        drop(provider);
+
+        let provider = CachingProvider::new(counting(), &path).unwrap();
+        assert_eq!(provider.len(), 1);
+    }
+
+    #[test]
+    fn test_expired_entries_are_refetched() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("llm-cache.json");
+        let column = ColumnSchema::new("age", 0);
+        let context = ContextHints::new();
+
+        let provider = CachingProvider::new(counting(), &path)
+            .unwrap()
+            .with_ttl(Duration::ZERO);
+        provider.enhance_schema(&column, &[], &context).unwrap();
+        std::thread::sleep(Duration::from_millis(5));
+        provider.enhance_schema(&column, &[], &context).unwrap();
+        assert_eq!(provider.inner.calls.load(Ordering::SeqCst), 2);
+    }
+}
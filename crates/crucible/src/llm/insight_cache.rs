@@ -0,0 +1,155 @@
+//! Persistent cache of per-column LLM insights.
+//!
+//! Re-analyzing a file shouldn't re-call the model just to repopulate
+//! `llm_insight` for columns that haven't changed. Each entry is keyed by
+//! column name and a hash of the column's value profile, so a column whose
+//! values change is treated as a cache miss.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::error::{CrucibleError, Result};
+use crate::input::DataTable;
+use crate::schema::ColumnSchema;
+
+/// A cached insight for a single column.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedInsight {
+    /// Hash of the column's value profile when the insight was generated.
+    pub profile_hash: String,
+    /// The LLM-generated insight.
+    pub insight: String,
+}
+
+/// Cache of LLM column insights, keyed by column name.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InsightCache {
+    /// Cached insights by column name.
+    #[serde(default)]
+    pub entries: HashMap<String, CachedInsight>,
+}
+
+impl InsightCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a cache from a JSON file.
+    ///
+    /// A missing file yields an empty cache, so the first run populates it.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::new());
+        }
+
+        let file = File::open(path).map_err(|e| {
+            CrucibleError::Persistence(format!(
+                "Failed to open insight cache '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        serde_json::from_reader(BufReader::new(file)).map_err(|e| {
+            CrucibleError::Persistence(format!(
+                "Failed to parse insight cache '{}': {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    /// Save the cache to a JSON file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+
+        let missing_parent = path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty() && !p.exists());
+        if let Some(parent) = missing_parent {
+            fs::create_dir_all(parent).map_err(|e| {
+                CrucibleError::Persistence(format!(
+                    "Failed to create directory '{}': {}",
+                    parent.display(),
+                    e
+                ))
+            })?;
+        }
+
+        let file = File::create(path).map_err(|e| {
+            CrucibleError::Persistence(format!(
+                "Failed to create insight cache '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        serde_json::to_writer_pretty(BufWriter::new(file), self).map_err(|e| {
+            CrucibleError::Persistence(format!("Failed to serialize insight cache: {}", e))
+        })
+    }
+
+    /// Get the cached insight for a column if its value profile is unchanged.
+    pub fn get(&self, column: &str, profile_hash: &str) -> Option<&str> {
+        self.entries
+            .get(column)
+            .filter(|entry| entry.profile_hash == profile_hash)
+            .map(|entry| entry.insight.as_str())
+    }
+
+    /// Store an insight for a column, replacing any previous entry.
+    pub fn insert(
+        &mut self,
+        column: impl Into<String>,
+        profile_hash: impl Into<String>,
+        insight: impl Into<String>,
+    ) {
+        self.entries.insert(
+            column.into(),
+            CachedInsight {
+                profile_hash: profile_hash.into(),
+                insight: insight.into(),
+            },
+        );
+    }
+
+    /// Hash a column's value profile (inferred type and all values).
+    pub fn profile_hash(column: &ColumnSchema, table: &DataTable) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(format!("{:?}", column.inferred_type).as_bytes());
+        for value in table.column_values(column.position) {
+            hasher.update([0x1e]);
+            hasher.update(value.as_bytes());
+        }
+        format!("sha256:{:x}", hasher.finalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_requires_matching_profile() {
+        let mut cache = InsightCache::new();
+        cache.insert("age", "sha256:abc", "Patient age in years");
+
+        assert_eq!(cache.get("age", "sha256:abc"), Some("Patient age in years"));
+        assert_eq!(cache.get("age", "sha256:def"), None);
+        assert_eq!(cache.get("sex", "sha256:abc"), None);
+    }
+
+    #[test]
+    fn test_load_missing_file_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InsightCache::load(dir.path().join("missing.json")).unwrap();
+        assert!(cache.entries.is_empty());
+    }
+}
@@ -8,6 +8,9 @@ use crate::validation::{Observation, ObservationType};
 
 use super::provider::{LlmConfig, LlmProvider, SchemaEnhancement};
 
+/// Dimensions of mock embeddings.
+const MOCK_EMBEDDING_DIMS: usize = 64;
+
 /// Mock LLM provider that returns predictable responses for testing.
 pub struct MockProvider {
     config: LlmConfig,
@@ -332,6 +335,29 @@ impl LlmProvider for MockProvider {
         "mock"
     }
 
+    /// Embed each text as counts of its character trigrams, hashed into a
+    /// small vector, so texts spelled alike embed alike.
+    fn embed(&self, texts: &[String]) -> Result<Option<Vec<Vec<f32>>>> {
+        let embeddings = texts
+            .iter()
+            .map(|text| {
+                let normalized: Vec<char> = format!(" {} ", text.to_lowercase())
+                    .chars()
+                    .filter(|c| c.is_alphanumeric() || *c == ' ')
+                    .collect();
+                let mut vector = vec![0.0f32; MOCK_EMBEDDING_DIMS];
+                for trigram in normalized.windows(3) {
+                    let hash = trigram
+                        .iter()
+                        .fold(17u64, |h, c| h.wrapping_mul(31).wrapping_add(*c as u64));
+                    vector[(hash % MOCK_EMBEDDING_DIMS as u64) as usize] += 1.0;
+                }
+                vector
+            })
+            .collect();
+        Ok(Some(embeddings))
+    }
+
     fn answer_question(
         &self,
         question_context: &super::provider::QuestionContext,
@@ -368,6 +394,9 @@ impl LlmProvider for MockProvider {
                 sug.affected_rows,
                 sug.confidence * 100.0
             )
+        } else if let Some(ref column) = question_context.column {
+            // Role questions ask for the role alone
+            column.semantic_role.label().to_string()
         } else {
             format!(
                 "Based on the {} domain context, here's what I can tell you about '{}': \
@@ -519,9 +548,11 @@ mod tests {
                 unique_count: 5,
                 ..Default::default()
             },
+            detected_null_tokens: Vec::new(),
             confidence: 0.9,
             inference_sources: vec!["statistical".to_string()],
             llm_insight: None,
+            explanation: None,
         }
     }
 
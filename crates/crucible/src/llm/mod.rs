@@ -28,13 +28,22 @@
 //! ```
 
 mod anthropic;
+mod budget;
+mod caching;
+mod insight_cache;
 mod mock;
 mod ollama;
 mod openai;
 mod prompts;
 mod provider;
+mod retry;
+mod structured;
 
 pub use anthropic::AnthropicProvider;
+pub use budget::{LlmUsage, estimate_tokens};
+pub(crate) use budget::{observation_cost, question_cost};
+pub use caching::{CachedResponse, CachingProvider};
+pub use insight_cache::{CachedInsight, InsightCache};
 pub use mock::MockProvider;
 pub use ollama::OllamaProvider;
 pub use openai::OpenAIProvider;
@@ -19,25 +19,29 @@ use crate::validation::Observation;
 use super::prompts;
 use super::provider::{LlmConfig, LlmProvider, SchemaEnhancement};
 
-/// Default Ollama API endpoint.
-const DEFAULT_API_URL: &str = "http://localhost:11434/api/chat";
+/// Default Ollama server address.
+const DEFAULT_HOST: &str = "http://localhost:11434";
+
+/// Embedding model used when the config names none.
+const DEFAULT_EMBEDDING_MODEL: &str = "nomic-embed-text";
+
+/// How long to wait for the Ollama daemon to accept a connection.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
 
 /// Ollama local LLM provider.
 pub struct OllamaProvider {
     client: Client,
-    api_url: String,
+    host: String,
     config: LlmConfig,
 }
 
 impl OllamaProvider {
     /// Create a new Ollama provider with default settings.
     ///
-    /// Uses llama3.2 model by default. Make sure you've pulled it:
-    /// `ollama pull llama3.2`
+    /// Uses llama3.2 model by default. Fails if Ollama isn't running or the
+    /// model hasn't been pulled (`ollama pull llama3.2`).
     pub fn new() -> Result<Self> {
-        let mut config = LlmConfig::default();
-        config.model = "llama3.2".to_string();
-        Self::with_config(config)
+        Self::with_model("llama3.2")
     }
 
     /// Create with a specific model.
@@ -47,30 +51,90 @@ impl OllamaProvider {
     /// - `llama3.1:70b` - Higher quality, slower
     /// - `mistral` - Fast, good for simple tasks
     /// - `codellama` - Good for technical/structured output
+    ///
+    /// Fails if Ollama isn't running or the model hasn't been pulled.
     pub fn with_model(model: impl Into<String>) -> Result<Self> {
-        let mut config = LlmConfig::default();
-        config.model = model.into();
-        Self::with_config(config)
+        let config = LlmConfig {
+            model: model.into(),
+            ..Default::default()
+        };
+        let provider = Self::with_config(config)?;
+        provider.check_model()?;
+        Ok(provider)
     }
 
     /// Create with custom configuration.
+    ///
+    /// Does not contact Ollama; call [`check_model`](Self::check_model) to
+    /// verify the model is available before use.
     pub fn with_config(config: LlmConfig) -> Result<Self> {
         let client = Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
             .timeout(Duration::from_secs(120)) // Local models can be slower
             .build()
             .map_err(|e| CrucibleError::Config(format!("Failed to create HTTP client: {}", e)))?;
 
-        let api_url = std::env::var("OLLAMA_HOST")
-            .map(|host| format!("{}/api/chat", host.trim_end_matches('/')))
-            .unwrap_or_else(|_| DEFAULT_API_URL.to_string());
+        let host = std::env::var("OLLAMA_HOST")
+            .map(|host| host.trim_end_matches('/').to_string())
+            .unwrap_or_else(|_| DEFAULT_HOST.to_string());
 
         Ok(Self {
             client,
-            api_url,
+            host,
             config,
         })
     }
 
+    /// Verify that the configured model has been pulled.
+    ///
+    /// Queries the `/api/tags` endpoint and returns an error naming the
+    /// `ollama pull` command to run if the model is missing.
+    pub fn check_model(&self) -> Result<()> {
+        let response = self
+            .client
+            .get(format!("{}/api/tags", self.host))
+            .send()
+            .map_err(|e| self.request_error(e))?;
+
+        if !response.status().is_success() {
+            return Err(CrucibleError::Config(format!(
+                "Failed to list Ollama models ({})",
+                response.status()
+            )));
+        }
+
+        let tags: TagsResponse = response
+            .json()
+            .map_err(|e| CrucibleError::Config(format!("Failed to parse Ollama model list: {}", e)))?;
+        let installed: Vec<String> = tags.models.into_iter().map(|m| m.name).collect();
+
+        if is_model_installed(&installed, &self.config.model) {
+            return Ok(());
+        }
+
+        let available = if installed.is_empty() {
+            "no models are installed".to_string()
+        } else {
+            format!("installed: {}", installed.join(", "))
+        };
+        Err(CrucibleError::Config(format!(
+            "Ollama model '{}' is not available ({}). Pull it with: ollama pull {}",
+            self.config.model, available, self.config.model
+        )))
+    }
+
+    /// Describe a failed request, pointing at `ollama serve` if unreachable.
+    fn request_error(&self, e: reqwest::Error) -> CrucibleError {
+        if e.is_connect() || (e.is_timeout() && e.is_request()) {
+            CrucibleError::Config(format!(
+                "Failed to connect to Ollama at {}. Is it running? Start with: ollama serve",
+                self.host
+            ))
+        } else {
+            CrucibleError::Config(format!("Ollama request failed: {}", e))
+        }
+    }
+
     /// Build headers for API requests.
     fn build_headers(&self) -> HeaderMap {
         let mut headers = HeaderMap::new();
@@ -101,20 +165,11 @@ impl OllamaProvider {
 
         let response = self
             .client
-            .post(&self.api_url)
+            .post(format!("{}/api/chat", self.host))
             .headers(self.build_headers())
             .json(&body)
             .send()
-            .map_err(|e| {
-                if e.is_connect() {
-                    CrucibleError::Config(
-                        "Failed to connect to Ollama. Is it running? Start with: ollama serve"
-                            .to_string(),
-                    )
-                } else {
-                    CrucibleError::Config(format!("Ollama request failed: {}", e))
-                }
-            })?;
+            .map_err(|e| self.request_error(e))?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -166,11 +221,29 @@ impl OllamaProvider {
 }
 
 impl Default for OllamaProvider {
+    /// A llama3.2 provider, without checking that the model is available.
     fn default() -> Self {
-        Self::new().expect("Failed to create default Ollama provider")
+        let config = LlmConfig {
+            model: "llama3.2".to_string(),
+            ..Default::default()
+        };
+        Self::with_config(config).expect("Failed to create default Ollama provider")
     }
 }
 
+/// Check a model name against the names Ollama reports as installed.
+///
+/// Ollama lists models with their tag (`llama3.2:latest`), while users often
+/// leave the tag off, which means `latest`.
+fn is_model_installed(installed: &[String], model: &str) -> bool {
+    let wanted = if model.contains(':') {
+        model.to_string()
+    } else {
+        format!("{}:latest", model)
+    };
+    installed.iter().any(|name| name == model || *name == wanted)
+}
+
 impl LlmProvider for OllamaProvider {
     fn enhance_schema(
         &self,
@@ -258,6 +331,41 @@ impl LlmProvider for OllamaProvider {
         "ollama"
     }
 
+    fn embed(&self, texts: &[String]) -> Result<Option<Vec<Vec<f32>>>> {
+        let model = self
+            .config
+            .embedding_model
+            .as_deref()
+            .unwrap_or(DEFAULT_EMBEDDING_MODEL);
+        let response = self
+            .client
+            .post(format!("{}/api/embed", self.host))
+            .headers(self.build_headers())
+            .json(&json!({"model": model, "input": texts}))
+            .send()
+            .map_err(|e| self.request_error(e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().unwrap_or_default();
+            if error_text.contains("not found") {
+                return Err(CrucibleError::Config(format!(
+                    "Embedding model '{}' not found. Pull it with: ollama pull {}",
+                    model, model
+                )));
+            }
+            return Err(CrucibleError::Config(format!(
+                "Ollama error ({}): {}",
+                status, error_text
+            )));
+        }
+
+        let api_response: EmbedResponse = response
+            .json()
+            .map_err(|e| CrucibleError::Config(format!("Failed to parse Ollama embeddings: {}", e)))?;
+        Ok(Some(api_response.embeddings))
+    }
+
     fn answer_question(
         &self,
         question_context: &super::provider::QuestionContext,
@@ -338,6 +446,18 @@ struct ConfidenceFactorParsed {
     explanation: String,
 }
 
+/// Ollama `/api/tags` response listing installed models.
+#[derive(Debug, Deserialize)]
+struct TagsResponse {
+    #[serde(default)]
+    models: Vec<TagsModel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TagsModel {
+    name: String,
+}
+
 /// Ollama API response structure.
 #[derive(Debug, Deserialize)]
 struct OllamaResponse {
@@ -349,6 +469,12 @@ struct OllamaMessage {
     content: String,
 }
 
+/// Ollama `/api/embed` response.
+#[derive(Debug, Deserialize)]
+struct EmbedResponse {
+    embeddings: Vec<Vec<f32>>,
+}
+
 /// Parsed schema enhancement response.
 #[derive(Debug, Deserialize)]
 struct SchemaEnhancementResponse {
@@ -373,3 +499,75 @@ struct SuggestionResponse {
     #[serde(default)]
     priority: Option<u8>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_model_installed() {
+        let installed = vec!["llama3.2:latest".to_string(), "llama3.1:70b".to_string()];
+
+        assert!(is_model_installed(&installed, "llama3.2"));
+        assert!(is_model_installed(&installed, "llama3.2:latest"));
+        assert!(is_model_installed(&installed, "llama3.1:70b"));
+        assert!(!is_model_installed(&installed, "llama3.1"));
+        assert!(!is_model_installed(&installed, "mistral"));
+        assert!(!is_model_installed(&[], "llama3.2"));
+    }
+
+    /// Serve one canned `/api/tags` reply from a mock daemon, returning its host.
+    fn mock_daemon(body: &'static str) -> String {
+        use std::io::{BufRead, BufReader, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let host = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                line.clear();
+            }
+            let reply = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            reader.get_mut().write_all(reply.as_bytes()).unwrap();
+        });
+        host
+    }
+
+    fn provider(model: &str, host: String) -> OllamaProvider {
+        let config = LlmConfig {
+            model: model.to_string(),
+            ..Default::default()
+        };
+        let mut provider = OllamaProvider::with_config(config).unwrap();
+        provider.host = host;
+        provider
+    }
+
+    #[test]
+    fn test_check_model() {
+        const TAGS: &str = r#"{"models": [{"name": "llama3.2:latest"}]}"#;
+
+        assert!(provider("llama3.2", mock_daemon(TAGS)).check_model().is_ok());
+
+        let err = provider("mistral", mock_daemon(TAGS)).check_model().unwrap_err();
+        assert!(err.to_string().contains("installed: llama3.2:latest"));
+        assert!(err.to_string().contains("ollama pull mistral"));
+    }
+
+    #[test]
+    fn test_stopped_daemon_fails_fast() {
+        // A port that was just released has nothing listening on it
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let host = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let err = provider("llama3.2", host).check_model().unwrap_err();
+        assert!(err.to_string().contains("ollama serve"));
+    }
+}
@@ -1,5 +1,16 @@
 //! OpenAI GPT API provider implementation.
-
+//!
+//! Also works with local servers that expose an OpenAI-compatible
+//! `/v1/chat/completions` endpoint, such as llama.cpp's `llama-server` or
+//! LM Studio. Point the provider at one with
+//! [`OpenAIProvider::local`] or [`OpenAIProvider::with_base_url`], or set
+//! `OPENAI_BASE_URL` for [`OpenAIProvider::from_env`]; no API key is needed.
+//!
+//! Schema enhancements and suggestions are requested with a
+//! `response_format: json_schema` structured output. Servers and models
+//! that reject it get the plain prompt instead.
+
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 use reqwest::blocking::Client;
@@ -15,15 +26,24 @@ use crate::validation::Observation;
 
 use super::prompts;
 use super::provider::{LlmConfig, LlmProvider, SchemaEnhancement};
+use super::retry::{HttpReply, RetryPolicy};
+use super::structured::{self, SchemaEnhancementResponse, StructuredOutput, SuggestionResponse};
+
+/// Base URL of the hosted OpenAI API.
+const DEFAULT_BASE_URL: &str = "https://api.openai.com/v1";
 
-/// OpenAI API endpoint.
-const API_URL: &str = "https://api.openai.com/v1/chat/completions";
+/// Embedding model used when the config names none.
+const DEFAULT_EMBEDDING_MODEL: &str = "text-embedding-3-small";
 
 /// OpenAI GPT provider.
 pub struct OpenAIProvider {
     client: Client,
-    api_key: String,
+    /// API key; local servers usually don't need one.
+    api_key: Option<String>,
+    base_url: String,
     config: LlmConfig,
+    /// Cleared once the server rejects a structured output request.
+    structured_supported: AtomicBool,
 }
 
 impl OpenAIProvider {
@@ -41,36 +61,79 @@ impl OpenAIProvider {
             .build()
             .map_err(|e| CrucibleError::Config(format!("Failed to create HTTP client: {}", e)))?;
 
+        let api_key: String = api_key.into();
         Ok(Self {
             client,
-            api_key: api_key.into(),
+            api_key: (!api_key.is_empty()).then_some(api_key),
+            base_url: DEFAULT_BASE_URL.to_string(),
             config,
+            structured_supported: AtomicBool::new(true),
         })
     }
 
-    /// Create from environment variable.
+    /// Create a provider for a local OpenAI-compatible server.
+    ///
+    /// `base_url` is the server's API root, e.g. `http://localhost:8080/v1`
+    /// for llama.cpp's `llama-server`. No API key is sent.
+    ///
+    /// ```
+    /// use crucible::{LlmConfig, OpenAIProvider};
+    ///
+    /// let provider = OpenAIProvider::local("http://localhost:8080/v1", LlmConfig::default())?;
+    /// # Ok::<(), crucible::CrucibleError>(())
+    /// ```
+    pub fn local(base_url: impl Into<String>, config: LlmConfig) -> Result<Self> {
+        Ok(Self::with_config("", config)?.with_base_url(base_url))
+    }
+
+    /// Send requests to a different OpenAI-compatible API root.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Use a different model, e.g. the name a local server was started with.
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.config.model = model.into();
+        self
+    }
+
+    /// Create from environment variables.
+    ///
+    /// Uses the hosted API with `OPENAI_API_KEY`. If `OPENAI_BASE_URL` is
+    /// set, requests go to that server instead and the key is optional.
     pub fn from_env() -> Result<Self> {
-        let api_key = std::env::var("OPENAI_API_KEY").map_err(|_| {
-            CrucibleError::Config("OPENAI_API_KEY environment variable not set".to_string())
-        })?;
-        Self::new(api_key)
+        let api_key = std::env::var("OPENAI_API_KEY");
+        match std::env::var("OPENAI_BASE_URL") {
+            Ok(base_url) if !base_url.is_empty() => {
+                Ok(Self::new(api_key.unwrap_or_default())?.with_base_url(base_url))
+            }
+            _ => {
+                let api_key = api_key.map_err(|_| {
+                    CrucibleError::Config("OPENAI_API_KEY environment variable not set".to_string())
+                })?;
+                Self::new(api_key)
+            }
+        }
     }
 
     /// Build headers for API requests.
     fn build_headers(&self) -> Result<HeaderMap> {
         let mut headers = HeaderMap::new();
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&format!("Bearer {}", self.api_key))
-                .map_err(|e| CrucibleError::Config(format!("Invalid API key: {}", e)))?,
-        );
+        if let Some(ref api_key) = self.api_key {
+            headers.insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", api_key))
+                    .map_err(|e| CrucibleError::Config(format!("Invalid API key: {}", e)))?,
+            );
+        }
         Ok(headers)
     }
 
-    /// Send a message to the OpenAI API.
-    fn send_message(&self, user_prompt: &str) -> Result<String> {
-        let body = json!({
+    /// Build a chat completions request body for a prompt.
+    fn message_body(&self, user_prompt: &str) -> Value {
+        json!({
             "model": self.config.model,
             "max_tokens": self.config.max_tokens,
             "temperature": self.config.temperature,
@@ -84,62 +147,107 @@ impl OpenAIProvider {
                     "content": user_prompt
                 }
             ]
+        })
+    }
+
+    /// Post a chat completions request body, retrying transient failures.
+    fn post(&self, body: &Value) -> Result<HttpReply> {
+        let headers = self.build_headers()?;
+        let url = format!("{}/chat/completions", self.base_url);
+        RetryPolicy::from_config(&self.config).run(
+            || {
+                self.client
+                    .post(&url)
+                    .headers(headers.clone())
+                    .json(body)
+                    .send()
+                    .map(HttpReply::from_response)
+                    .map_err(|e| {
+                        CrucibleError::Config(format!("API request to {} failed: {}", url, e))
+                    })
+            },
+            std::thread::sleep,
+        )
+    }
+
+    /// Send a message to the OpenAI API.
+    fn send_message(&self, user_prompt: &str) -> Result<String> {
+        let reply = self.post(&self.message_body(user_prompt))?;
+
+        if !reply.status.is_success() {
+            return Err(CrucibleError::Config(format!(
+                "OpenAI API error ({}): {}",
+                reply.status, reply.body
+            )));
+        }
+
+        message_content(&reply.body)
+    }
+
+    /// Request a response constrained to the response type's JSON Schema.
+    ///
+    /// Returns `None` when structured output is disabled or unsupported. A
+    /// reply that doesn't match the expected response is an error rather
+    /// than a reason to ask again, so each request is sent once.
+    fn send_structured<T: StructuredOutput>(&self, user_prompt: &str) -> Result<Option<T>> {
+        if !self.config.structured_output || !self.structured_supported.load(Ordering::Relaxed) {
+            return Ok(None);
+        }
+
+        let mut body = self.message_body(user_prompt);
+        body["response_format"] = json!({
+            "type": "json_schema",
+            "json_schema": {
+                "name": T::NAME,
+                "description": T::DESCRIPTION,
+                "schema": T::json_schema()
+            }
         });
 
-        let response = self
-            .client
-            .post(API_URL)
-            .headers(self.build_headers()?)
-            .json(&body)
-            .send()
-            .map_err(|e| CrucibleError::Config(format!("API request failed: {}", e)))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().unwrap_or_default();
+        let reply = self.post(&body)?;
+        if !reply.status.is_success() {
+            if structured::is_unsupported(reply.status, &reply.body) {
+                self.structured_supported.store(false, Ordering::Relaxed);
+                return Ok(None);
+            }
             return Err(CrucibleError::Config(format!(
                 "OpenAI API error ({}): {}",
-                status, error_text
+                reply.status, reply.body
             )));
         }
 
-        let api_response: OpenAIResponse = response
-            .json()
-            .map_err(|e| CrucibleError::Config(format!("Failed to parse API response: {}", e)))?;
-
-        // Extract text from response
-        api_response
-            .choices
-            .into_iter()
-            .next()
-            .map(|choice| choice.message.content)
-            .ok_or_else(|| CrucibleError::Config("No response from OpenAI".to_string()))
+        structured::parse_json(&message_content(&reply.body)?).map(Some)
+    }
+
+    /// Request a JSON response, preferring structured output and falling
+    /// back to parsing the free-text reply to the prompt.
+    fn request_json<T: StructuredOutput>(&self, user_prompt: &str) -> Result<T> {
+        if let Some(parsed) = self.send_structured(user_prompt)? {
+            return Ok(parsed);
+        }
+        let response = self.send_message(user_prompt)?;
+        self.parse_json_response(&response)
     }
 
     /// Parse JSON from LLM response, handling markdown code blocks.
     fn parse_json_response<T: for<'de> Deserialize<'de>>(&self, response: &str) -> Result<T> {
-        let json_str = if response.contains("```json") {
-            response
-                .split("```json")
-                .nth(1)
-                .and_then(|s| s.split("```").next())
-                .map(|s| s.trim())
-                .unwrap_or(response)
-        } else if response.contains("```") {
-            response
-                .split("```")
-                .nth(1)
-                .map(|s| s.trim())
-                .unwrap_or(response)
-        } else {
-            response.trim()
-        };
-
-        serde_json::from_str(json_str)
-            .map_err(|e| CrucibleError::Config(format!("Failed to parse LLM JSON response: {}", e)))
+        structured::parse_json(response)
     }
 }
 
+/// Extract the first choice's message text from a chat completions reply.
+fn message_content(body: &str) -> Result<String> {
+    let api_response: OpenAIResponse = serde_json::from_str(body)
+        .map_err(|e| CrucibleError::Config(format!("Failed to parse API response: {}", e)))?;
+
+    api_response
+        .choices
+        .into_iter()
+        .next()
+        .map(|choice| choice.message.content)
+        .ok_or_else(|| CrucibleError::Config("No response from OpenAI".to_string()))
+}
+
 impl LlmProvider for OpenAIProvider {
     fn enhance_schema(
         &self,
@@ -157,9 +265,7 @@ impl LlmProvider for OpenAIProvider {
         }
 
         let prompt = prompts::schema_enhancement_prompt(column, samples, context);
-        let response = self.send_message(&prompt)?;
-
-        let parsed: SchemaEnhancementResponse = self.parse_json_response(&response)?;
+        let parsed: SchemaEnhancementResponse = self.request_json(&prompt)?;
 
         Ok(SchemaEnhancement {
             insight: parsed.insight,
@@ -196,9 +302,7 @@ impl LlmProvider for OpenAIProvider {
         }
 
         let prompt = prompts::suggestion_prompt(observation, column, context);
-        let response = self.send_message(&prompt)?;
-
-        let parsed: SuggestionResponse = self.parse_json_response(&response)?;
+        let parsed: SuggestionResponse = self.request_json(&prompt)?;
 
         let action = match parsed.action.as_deref() {
             Some("standardize") => SuggestionAction::Standardize,
@@ -227,6 +331,44 @@ impl LlmProvider for OpenAIProvider {
         "openai"
     }
 
+    fn embed(&self, texts: &[String]) -> Result<Option<Vec<Vec<f32>>>> {
+        let model = self
+            .config
+            .embedding_model
+            .as_deref()
+            .unwrap_or(DEFAULT_EMBEDDING_MODEL);
+        let body = json!({"model": model, "input": texts});
+
+        let headers = self.build_headers()?;
+        let url = format!("{}/embeddings", self.base_url);
+        let reply = RetryPolicy::from_config(&self.config).run(
+            || {
+                self.client
+                    .post(&url)
+                    .headers(headers.clone())
+                    .json(&body)
+                    .send()
+                    .map(HttpReply::from_response)
+                    .map_err(|e| {
+                        CrucibleError::Config(format!("API request to {} failed: {}", url, e))
+                    })
+            },
+            std::thread::sleep,
+        )?;
+
+        if !reply.status.is_success() {
+            return Err(CrucibleError::Config(format!(
+                "OpenAI API error ({}): {}",
+                reply.status, reply.body
+            )));
+        }
+
+        let mut api_response: EmbeddingResponse = serde_json::from_str(&reply.body)
+            .map_err(|e| CrucibleError::Config(format!("Failed to parse embeddings: {}", e)))?;
+        api_response.data.sort_by_key(|d| d.index);
+        Ok(Some(api_response.data.into_iter().map(|d| d.embedding).collect()))
+    }
+
     fn answer_question(
         &self,
         question_context: &super::provider::QuestionContext,
@@ -323,27 +465,48 @@ struct Message {
     content: String,
 }
 
-/// Parsed schema enhancement response.
+/// OpenAI `/embeddings` response.
 #[derive(Debug, Deserialize)]
-struct SchemaEnhancementResponse {
-    insight: String,
-    #[serde(default)]
-    suggested_role: Option<String>,
-    #[serde(default)]
-    potential_issues: Option<String>,
-    #[serde(default)]
-    confidence: f64,
+struct EmbeddingResponse {
+    data: Vec<EmbeddingData>,
 }
 
-/// Parsed suggestion response.
 #[derive(Debug, Deserialize)]
-struct SuggestionResponse {
-    action: Option<String>,
-    rationale: String,
+struct EmbeddingData {
     #[serde(default)]
-    parameters: Option<Value>,
-    #[serde(default)]
-    confidence: Option<f64>,
-    #[serde(default)]
-    priority: Option<u8>,
+    index: usize,
+    embedding: Vec<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hosted_provider_sends_api_key() {
+        let provider = OpenAIProvider::new("sk-test").unwrap();
+        assert_eq!(provider.base_url, DEFAULT_BASE_URL);
+
+        let headers = provider.build_headers().unwrap();
+        assert_eq!(headers[AUTHORIZATION], "Bearer sk-test");
+    }
+
+    #[test]
+    fn test_local_provider_without_key() {
+        let provider =
+            OpenAIProvider::local("http://localhost:8080/v1/", LlmConfig::default()).unwrap();
+        assert_eq!(provider.base_url, "http://localhost:8080/v1");
+
+        let headers = provider.build_headers().unwrap();
+        assert!(!headers.contains_key(AUTHORIZATION));
+    }
+
+    #[test]
+    fn test_message_content_parses_messy_reply() {
+        let reply = r#"{"choices": [{"message": {"content": "Here you go:\n{\"action\": \"convert_na\", \"rationale\": \"Sentinel values\"}"}}]}"#;
+        let content = message_content(reply).unwrap();
+        let parsed: SuggestionResponse = structured::parse_json(&content).unwrap();
+        assert_eq!(parsed.action.as_deref(), Some("convert_na"));
+        assert_eq!(parsed.rationale, "Sentinel values");
+    }
 }
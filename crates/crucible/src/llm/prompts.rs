@@ -423,9 +423,11 @@ mod tests {
             expected_range: Some((0.0, 100.0)),
             constraints: vec![],
             statistics: ColumnStatistics::default(),
+            detected_null_tokens: Vec::new(),
             confidence: 0.9,
             inference_sources: vec!["statistical".to_string()],
             llm_insight: None,
+            explanation: None,
         };
 
         let samples = vec!["25".to_string(), "30".to_string(), "28".to_string()];
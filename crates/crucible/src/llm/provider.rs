@@ -1,5 +1,7 @@
 //! LLM provider trait and types.
 
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
 use crate::error::Result;
@@ -150,6 +152,25 @@ pub struct LlmConfig {
 
     /// Whether to generate suggestions.
     pub generate_suggestions: bool,
+
+    /// Maximum retries for rate-limited or overloaded API requests.
+    pub max_retries: u32,
+
+    /// Delay before the first retry; doubled on each subsequent retry.
+    pub initial_backoff: Duration,
+
+    /// Estimated token budget for observation requests during analysis.
+    ///
+    /// When set, observations are sent highest severity first and requests
+    /// stop once the budget would be exceeded. `None` means unlimited.
+    pub token_budget: Option<usize>,
+
+    /// Model used for embeddings; `None` uses the provider's default.
+    pub embedding_model: Option<String>,
+
+    /// Whether to request JSON-schema structured output from providers
+    /// that support it, falling back to parsing free text if rejected.
+    pub structured_output: bool,
 }
 
 impl Default for LlmConfig {
@@ -161,10 +182,47 @@ impl Default for LlmConfig {
             enhance_schema: true,
             explain_observations: true,
             generate_suggestions: true,
+            max_retries: 3,
+            initial_backoff: Duration::from_secs(1),
+            token_budget: None,
+            embedding_model: None,
+            structured_output: true,
         }
     }
 }
 
+impl LlmConfig {
+    /// Set the maximum number of retries for transient API failures.
+    pub fn with_max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Use a specific model for embeddings.
+    pub fn with_embedding_model(mut self, model: impl Into<String>) -> Self {
+        self.embedding_model = Some(model.into());
+        self
+    }
+
+    /// Set the delay before the first retry.
+    pub fn with_initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Enable or disable structured (JSON mode) output requests.
+    pub fn with_structured_output(mut self, enabled: bool) -> Self {
+        self.structured_output = enabled;
+        self
+    }
+
+    /// Cap the estimated tokens spent on observation requests.
+    pub fn with_token_budget(mut self, max_tokens: usize) -> Self {
+        self.token_budget = Some(max_tokens);
+        self
+    }
+}
+
 /// Trait for LLM providers.
 ///
 /// Implementations must be thread-safe (Send + Sync) to allow
@@ -261,4 +319,74 @@ pub trait LlmProvider: Send + Sync {
         column: Option<&ColumnSchema>,
         hints: &ContextHints,
     ) -> Result<CalibratedConfidence>;
+
+    /// Embed texts as vectors, one per text, in order.
+    ///
+    /// Used to find values with the same meaning (see
+    /// [`SemanticEquivalenceValidator::with_embeddings`](crate::validation::SemanticEquivalenceValidator::with_embeddings)).
+    /// Providers without an embedding model return `Ok(None)`, the default.
+    fn embed(&self, texts: &[String]) -> Result<Option<Vec<Vec<f32>>>> {
+        let _ = texts;
+        Ok(None)
+    }
+}
+
+/// Shared providers delegate to the provider they point to, so wrappers such
+/// as [`CachingProvider`](super::CachingProvider) can hold an `Arc<dyn LlmProvider>`.
+impl<T: LlmProvider + ?Sized> LlmProvider for std::sync::Arc<T> {
+    fn enhance_schema(
+        &self,
+        column: &ColumnSchema,
+        samples: &[String],
+        context: &ContextHints,
+    ) -> Result<SchemaEnhancement> {
+        (**self).enhance_schema(column, samples, context)
+    }
+
+    fn explain_observation(
+        &self,
+        observation: &Observation,
+        column: Option<&ColumnSchema>,
+        context: &ContextHints,
+    ) -> Result<String> {
+        (**self).explain_observation(observation, column, context)
+    }
+
+    fn generate_suggestion(
+        &self,
+        observation: &Observation,
+        column: Option<&ColumnSchema>,
+        context: &ContextHints,
+    ) -> Result<Option<Suggestion>> {
+        (**self).generate_suggestion(observation, column, context)
+    }
+
+    fn config(&self) -> &LlmConfig {
+        (**self).config()
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn answer_question(
+        &self,
+        question_context: &QuestionContext,
+        hints: &ContextHints,
+    ) -> Result<QuestionResponse> {
+        (**self).answer_question(question_context, hints)
+    }
+
+    fn calibrate_confidence(
+        &self,
+        observation: &Observation,
+        column: Option<&ColumnSchema>,
+        hints: &ContextHints,
+    ) -> Result<CalibratedConfidence> {
+        (**self).calibrate_confidence(observation, column, hints)
+    }
+
+    fn embed(&self, texts: &[String]) -> Result<Option<Vec<Vec<f32>>>> {
+        (**self).embed(texts)
+    }
 }
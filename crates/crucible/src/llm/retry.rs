@@ -0,0 +1,244 @@
+//! Retry with exponential backoff for hosted LLM APIs.
+//!
+//! Rate-limit (429) and overload (529) responses are usually transient, so
+//! requests are retried with exponential backoff and jitter, honoring any
+//! `Retry-After` header the API sends.
+
+use std::time::Duration;
+
+use reqwest::blocking::Response;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+
+use crate::error::{CrucibleError, Result};
+
+use super::provider::LlmConfig;
+
+/// Upper bound on a single backoff delay.
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Non-standard status Anthropic uses when the API is overloaded.
+const OVERLOADED: u16 = 529;
+
+/// The parts of an HTTP response the retry logic needs.
+#[derive(Debug)]
+pub(super) struct HttpReply {
+    /// Response status.
+    pub status: StatusCode,
+    /// Delay requested by the server, if any.
+    pub retry_after: Option<Duration>,
+    /// Response body.
+    pub body: String,
+}
+
+impl HttpReply {
+    /// Read a reqwest response into a reply.
+    pub fn from_response(response: Response) -> Self {
+        let status = response.status();
+        let retry_after = retry_after(response.headers());
+        let body = response.text().unwrap_or_default();
+
+        Self {
+            status,
+            retry_after,
+            body,
+        }
+    }
+
+    /// Whether the server rejected the request for rate or capacity reasons.
+    fn is_rate_limited(&self) -> bool {
+        self.status == StatusCode::TOO_MANY_REQUESTS || self.status.as_u16() == OVERLOADED
+    }
+}
+
+/// Parse a `Retry-After` header given in seconds, capped at the longest
+/// backoff.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<f64>().ok())
+        .filter(|secs| *secs >= 0.0)
+        .map(|secs| Duration::try_from_secs_f64(secs).map_or(MAX_BACKOFF, |d| d.min(MAX_BACKOFF)))
+}
+
+/// Retry settings taken from an [`LlmConfig`].
+#[derive(Debug, Clone, Copy)]
+pub(super) struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Build the policy configured for a provider.
+    pub fn from_config(config: &LlmConfig) -> Self {
+        Self {
+            max_retries: config.max_retries,
+            initial_backoff: config.initial_backoff,
+        }
+    }
+
+    /// Delay before retry number `attempt` (starting at 0).
+    ///
+    /// A server-provided `Retry-After` wins; otherwise the initial backoff is
+    /// doubled per attempt, with up to 50% random jitter added.
+    fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(delay) = retry_after {
+            return delay.min(MAX_BACKOFF);
+        }
+
+        let base = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(MAX_BACKOFF);
+        let jitter = base.mul_f64(fastrand::f64() * 0.5);
+        (base + jitter).min(MAX_BACKOFF)
+    }
+
+    /// Send a request, retrying rate-limit (429) and overload (529) replies.
+    ///
+    /// `send` performs one HTTP request and `sleep` waits between attempts.
+    /// Other errors, server errors included, are returned at once for the
+    /// caller to interpret. A request still rate limited after every retry
+    /// is reported as [`CrucibleError::RateLimited`].
+    pub fn run<F, S>(&self, mut send: F, mut sleep: S) -> Result<HttpReply>
+    where
+        F: FnMut() -> Result<HttpReply>,
+        S: FnMut(Duration),
+    {
+        let mut attempt = 0;
+        loop {
+            let reply = send()?;
+            if !reply.is_rate_limited() {
+                return Ok(reply);
+            }
+
+            if attempt == self.max_retries {
+                return Err(CrucibleError::RateLimited {
+                    retries: self.max_retries,
+                    message: format!("{}: {}", reply.status, reply.body),
+                });
+            }
+
+            sleep(self.delay(attempt, reply.retry_after));
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    fn reply(status: u16, retry_after: Option<u64>) -> HttpReply {
+        HttpReply {
+            status: StatusCode::from_u16(status).unwrap(),
+            retry_after: retry_after.map(Duration::from_secs),
+            body: format!("status {}", status),
+        }
+    }
+
+    fn policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy::from_config(
+            &LlmConfig::default()
+                .with_max_retries(max_retries)
+                .with_initial_backoff(Duration::from_millis(100)),
+        )
+    }
+
+    /// Drive the policy against scripted replies, recording requested sleeps.
+    fn drive(
+        policy: RetryPolicy,
+        replies: Vec<HttpReply>,
+    ) -> (Result<HttpReply>, usize, Vec<Duration>) {
+        let mut replies = VecDeque::from(replies);
+        let mut requests = 0;
+        let mut sleeps = Vec::new();
+        let result = policy.run(
+            || {
+                requests += 1;
+                Ok(replies.pop_front().expect("unexpected request"))
+            },
+            |delay| sleeps.push(delay),
+        );
+        (result, requests, sleeps)
+    }
+
+    #[test]
+    fn test_retries_until_success_with_backoff() {
+        let (result, requests, sleeps) = drive(
+            policy(3),
+            vec![reply(429, None), reply(529, None), reply(200, None)],
+        );
+
+        assert_eq!(result.unwrap().status, StatusCode::OK);
+        assert_eq!(requests, 3);
+        assert_eq!(sleeps.len(), 2);
+        // Exponential with at most 50% jitter
+        assert!(sleeps[0] >= Duration::from_millis(100) && sleeps[0] <= Duration::from_millis(150));
+        assert!(sleeps[1] >= Duration::from_millis(200) && sleeps[1] <= Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_retry_after_header_is_respected() {
+        let (result, _, sleeps) = drive(policy(3), vec![reply(429, Some(7)), reply(200, None)]);
+
+        assert!(result.is_ok());
+        assert_eq!(sleeps, vec![Duration::from_secs(7)]);
+    }
+
+    #[test]
+    fn test_rate_limited_after_max_retries() {
+        let (result, requests, sleeps) = drive(
+            policy(2),
+            vec![reply(429, None), reply(429, None), reply(429, None)],
+        );
+
+        assert!(matches!(
+            result,
+            Err(CrucibleError::RateLimited { retries: 2, .. })
+        ));
+        assert_eq!(requests, 3);
+        assert_eq!(sleeps.len(), 2);
+    }
+
+    #[test]
+    fn test_non_transient_errors_are_not_retried() {
+        let (result, requests, sleeps) = drive(policy(3), vec![reply(401, None)]);
+
+        assert_eq!(result.unwrap().status, StatusCode::UNAUTHORIZED);
+        assert_eq!(requests, 1);
+        assert!(sleeps.is_empty());
+
+        // Only rate limiting and overload are retried, not server errors
+        let (result, requests, _) = drive(policy(3), vec![reply(503, None)]);
+        assert_eq!(result.unwrap().status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(requests, 1);
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("12"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(12)));
+
+        // Huge delays are capped rather than overflowing
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("1e300"));
+        assert_eq!(retry_after(&headers), Some(MAX_BACKOFF));
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("-5"));
+        assert_eq!(retry_after(&headers), None);
+
+        // HTTP-date values fall back to computed backoff
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), None);
+    }
+}
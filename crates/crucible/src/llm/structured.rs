@@ -0,0 +1,293 @@
+//! Structured (JSON mode) output for hosted LLM APIs.
+//!
+//! Free-text replies sometimes wrap or mangle the JSON a prompt asks for.
+//! Providers that support it are instead asked to fill in a JSON Schema
+//! (OpenAI `response_format: json_schema`, Anthropic tool use). Each
+//! response type's schema is derived from its fields by
+//! `structured_output!`, so the two stay in sync. When an endpoint rejects
+//! the structured request, providers fall back to the prompt and
+//! [`parse_json`]; a structured reply that doesn't deserialize is parsed the
+//! same way rather than asked for again.
+
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::error::{CrucibleError, Result};
+
+/// A response type providers can request as structured output.
+pub(super) trait StructuredOutput: DeserializeOwned {
+    /// Name of the schema (OpenAI) or tool (Anthropic).
+    const NAME: &'static str;
+
+    /// What the output is for; shown to the model as the tool description.
+    const DESCRIPTION: &'static str;
+
+    /// JSON Schema the model's output must satisfy.
+    fn json_schema() -> Value;
+}
+
+/// JSON Schema of a structured response field's type.
+pub(super) trait SchemaType {
+    /// Whether the field may be null or left out.
+    const OPTIONAL: bool = false;
+
+    fn schema() -> Value;
+}
+
+impl SchemaType for String {
+    fn schema() -> Value {
+        json!({ "type": "string" })
+    }
+}
+
+impl SchemaType for f64 {
+    fn schema() -> Value {
+        json!({ "type": "number" })
+    }
+}
+
+impl SchemaType for u8 {
+    fn schema() -> Value {
+        json!({ "type": "integer", "minimum": 0, "maximum": u8::MAX })
+    }
+}
+
+impl SchemaType for Value {
+    fn schema() -> Value {
+        json!({})
+    }
+}
+
+impl<T: SchemaType> SchemaType for Option<T> {
+    const OPTIONAL: bool = true;
+
+    fn schema() -> Value {
+        let mut schema = T::schema();
+        if let Some(ty) = schema.get("type").cloned() {
+            schema["type"] = json!([ty, "null"]);
+        }
+        schema
+    }
+}
+
+/// Define a structured response and derive its JSON Schema from its fields.
+///
+/// Each field's schema comes from its [`SchemaType`], with any constraints
+/// written after `=>` laid over it. Fields that aren't `Option` are required.
+macro_rules! structured_output {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident : $ty:ty $(=> $constraints:tt)?
+            ),* $(,)?
+        }
+        name: $schema_name:literal,
+        description: $description:literal $(,)?
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                $field_vis $field: $ty,
+            )*
+        }
+
+        impl StructuredOutput for $name {
+            const NAME: &'static str = $schema_name;
+            const DESCRIPTION: &'static str = $description;
+
+            fn json_schema() -> Value {
+                let mut properties = serde_json::Map::new();
+                let mut required = Vec::new();
+                $(
+                    #[allow(unused_mut)]
+                    let mut schema = <$ty as SchemaType>::schema();
+                    $(
+                        if let (Some(schema), Value::Object(constraints)) =
+                            (schema.as_object_mut(), json!($constraints))
+                        {
+                            schema.extend(constraints);
+                        }
+                    )?
+                    if !<$ty as SchemaType>::OPTIONAL {
+                        required.push(stringify!($field));
+                    }
+                    properties.insert(stringify!($field).to_string(), schema);
+                )*
+                json!({
+                    "type": "object",
+                    "properties": properties,
+                    "required": required
+                })
+            }
+        }
+    };
+}
+
+structured_output! {
+    /// Parsed schema enhancement response.
+    #[derive(Debug, Deserialize)]
+    pub(super) struct SchemaEnhancementResponse {
+        pub insight: String,
+        #[serde(default)]
+        pub suggested_role: Option<String>
+            => { "enum": ["Identifier", "Grouping", "Covariate", "Outcome", "Metadata", null] },
+        #[serde(default)]
+        pub potential_issues: Option<String>,
+        #[serde(default)]
+        pub confidence: f64 => { "minimum": 0.0, "maximum": 1.0 },
+    }
+    name: "schema_enhancement",
+    description: "Record an insight about a column of a tabular dataset.",
+}
+
+structured_output! {
+    /// Parsed suggestion response.
+    #[derive(Debug, Deserialize)]
+    pub(super) struct SuggestionResponse {
+        pub action: Option<String>
+            => { "enum": ["standardize", "convert_na", "coerce", "flag", "remove", "merge", null] },
+        pub rationale: String,
+        #[serde(default)]
+        pub parameters: Option<Value> => { "type": ["object", "null"] },
+        #[serde(default)]
+        pub confidence: Option<f64> => { "minimum": 0.0, "maximum": 1.0 },
+        #[serde(default)]
+        pub priority: Option<u8> => { "minimum": 1, "maximum": 10 },
+    }
+    name: "suggestion",
+    description: "Record the suggested fix for a data quality issue, or a null action if none applies.",
+}
+
+/// Request fields for structured output, as named in provider error messages.
+const STRUCTURED_FIELDS: &[&str] = &["response_format", "json_schema", "tools", "tool_choice"];
+
+/// Whether a rejected structured request means the endpoint or model
+/// doesn't support structured output, rather than a failure worth reporting.
+///
+/// Only a client error whose body names one of the structured-output
+/// fields counts; other rejections (a bad key, an unknown model) are
+/// reported rather than silently turning structured output off.
+pub(super) fn is_unsupported(status: StatusCode, body: &str) -> bool {
+    let client_error = matches!(
+        status,
+        StatusCode::BAD_REQUEST | StatusCode::NOT_FOUND | StatusCode::UNPROCESSABLE_ENTITY
+    );
+    let body = body.to_lowercase();
+    client_error && STRUCTURED_FIELDS.iter().any(|field| body.contains(field))
+}
+
+/// Parse JSON from a free-text LLM response.
+///
+/// Handles markdown code blocks and prose around a single JSON object.
+pub(super) fn parse_json<T: DeserializeOwned>(response: &str) -> Result<T> {
+    let json_str = if response.contains("```json") {
+        response
+            .split("```json")
+            .nth(1)
+            .and_then(|s| s.split("```").next())
+            .map(|s| s.trim())
+            .unwrap_or(response)
+    } else if response.contains("```") {
+        response
+            .split("```")
+            .nth(1)
+            .map(|s| s.trim())
+            .unwrap_or(response)
+    } else {
+        response.trim()
+    };
+
+    serde_json::from_str(json_str)
+        .or_else(|e| match (json_str.find('{'), json_str.rfind('}')) {
+            (Some(start), Some(end)) if start < end => {
+                serde_json::from_str(&json_str[start..=end]).map_err(|_| e)
+            }
+            _ => Err(e),
+        })
+        .map_err(|e| CrucibleError::Config(format!("Failed to parse LLM JSON response: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_matches_response() {
+        let schema = SuggestionResponse::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+        assert_eq!(properties.len(), 5);
+        assert_eq!(schema["required"], json!(["rationale"]));
+        assert_eq!(properties["action"]["type"], json!(["string", "null"]));
+        assert_eq!(properties["parameters"]["type"], json!(["object", "null"]));
+        assert_eq!(
+            properties["priority"],
+            json!({"type": ["integer", "null"], "minimum": 1, "maximum": 10})
+        );
+
+        let schema = SchemaEnhancementResponse::json_schema();
+        assert_eq!(schema["required"], json!(["insight", "confidence"]));
+        assert_eq!(
+            schema["properties"]["confidence"],
+            json!({"type": "number", "minimum": 0.0, "maximum": 1.0})
+        );
+    }
+
+    #[test]
+    fn test_schema_instances_deserialize() {
+        // Only the schema's required fields
+        let minimal = json!({"rationale": "Already consistent"});
+        assert!(serde_json::from_value::<SuggestionResponse>(minimal).is_ok());
+        let minimal = json!({"insight": "Patient age in years", "confidence": 0.9});
+        assert!(serde_json::from_value::<SchemaEnhancementResponse>(minimal).is_ok());
+
+        // Every field the schema allows
+        let full = json!({
+            "action": "convert_na",
+            "rationale": "Sentinel values",
+            "parameters": {"from_values": ["-999"]},
+            "confidence": 0.8,
+            "priority": 3
+        });
+        let parsed: SuggestionResponse = serde_json::from_value(full).unwrap();
+        assert_eq!(parsed.priority, Some(3));
+        let full = json!({
+            "insight": "Patient age in years",
+            "suggested_role": "Covariate",
+            "potential_issues": null,
+            "confidence": 0.9
+        });
+        let parsed: SchemaEnhancementResponse = serde_json::from_value(full).unwrap();
+        assert_eq!(parsed.suggested_role.as_deref(), Some("Covariate"));
+    }
+
+    #[test]
+    fn test_is_unsupported_needs_structured_field_in_error() {
+        let body = r#"{"error": {"message": "Invalid parameter: 'response_format' of type 'json_schema' is not supported with this model."}}"#;
+        assert!(is_unsupported(StatusCode::BAD_REQUEST, body));
+        let body = r#"{"error": {"type": "invalid_request_error", "message": "tools: Extra inputs are not permitted"}}"#;
+        assert!(is_unsupported(StatusCode::BAD_REQUEST, body));
+
+        let body = r#"{"error": {"message": "The model `gpt-5-typo` does not exist"}}"#;
+        assert!(!is_unsupported(StatusCode::NOT_FOUND, body));
+        let body = r#"{"error": {"message": "json_schema is not supported"}}"#;
+        assert!(!is_unsupported(StatusCode::UNAUTHORIZED, body));
+    }
+
+    #[test]
+    fn test_parse_messy_response() {
+        let response = "Sure! Here is my analysis of the column:\n\
+                        {\"insight\": \"Patient age in years\", \"confidence\": 0.85}\n\
+                        Let me know if you need anything else.";
+        let parsed: SchemaEnhancementResponse = parse_json(response).unwrap();
+        assert_eq!(parsed.insight, "Patient age in years");
+        assert_eq!(parsed.confidence, 0.85);
+
+        let response = "I would suggest converting these to NA.";
+        assert!(parse_json::<SuggestionResponse>(response).is_err());
+    }
+}
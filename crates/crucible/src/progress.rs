@@ -0,0 +1,47 @@
+//! Progress reporting for long analyses.
+
+use std::sync::Arc;
+
+/// A stage of analysis reached, reported to a [`ProgressCallback`].
+///
+/// Events arrive in the order below, except that validators may run in
+/// parallel: their events can arrive in any order, and from other threads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressEvent {
+    /// Reading and parsing the input file.
+    Parsing,
+    /// Inferring column types, semantics, and roles.
+    Inference,
+    /// A validator started.
+    Validation {
+        /// The validator's detector name.
+        validator_name: &'static str,
+    },
+    /// LLM requests made so far in the current LLM pass (column insights,
+    /// then observation explanations and suggestions).
+    LlmEnhancement {
+        /// Requests completed.
+        done: usize,
+        /// Requests planned for this pass.
+        total: usize,
+    },
+}
+
+impl ProgressEvent {
+    /// Short label for status lines.
+    pub fn label(&self) -> String {
+        match self {
+            ProgressEvent::Parsing => "Parsing".to_string(),
+            ProgressEvent::Inference => "Inferring schema".to_string(),
+            ProgressEvent::Validation { validator_name } => {
+                format!("Validating ({})", validator_name)
+            }
+            ProgressEvent::LlmEnhancement { done, total } => {
+                format!("LLM enhancement {}/{}", done, total)
+            }
+        }
+    }
+}
+
+/// Callback receiving [`ProgressEvent`]s.
+pub type ProgressCallback = Arc<dyn Fn(ProgressEvent) + Send + Sync>;
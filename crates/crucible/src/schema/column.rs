@@ -2,9 +2,15 @@
 
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
 
+use super::explanation::InferenceExplanation;
 use super::types::{ColumnType, Constraint, SemanticRole, SemanticType};
 
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
 /// Statistics computed for a column.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ColumnStatistics {
@@ -12,12 +18,18 @@ pub struct ColumnStatistics {
     pub count: usize,
     /// Number of null/missing values.
     pub null_count: usize,
+    /// Number of values matching the column's detected null tokens
+    /// (not included in `null_count`).
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub null_token_count: usize,
     /// Number of unique non-null values.
     pub unique_count: usize,
     /// Sample of values for display.
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub sample_values: Vec<String>,
-    /// Value frequency counts (for categorical).
+    /// Counts of the most frequent non-null values, most frequent first.
+    /// Capped at the inference top-k; absent when the column had too many
+    /// distinct values to track.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value_counts: Option<IndexMap<String, usize>>,
     /// Numeric statistics (for numeric columns).
@@ -104,6 +116,10 @@ pub struct ColumnSchema {
     pub constraints: Vec<Constraint>,
     /// Computed statistics.
     pub statistics: ColumnStatistics,
+    /// Column-specific values that stand for missing data (e.g. `-999`),
+    /// as spelled in the data. Counted as missing alongside standard nulls.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub detected_null_tokens: Vec<String>,
     /// Confidence in the inferred schema (0.0-1.0).
     pub confidence: f64,
     /// Sources that contributed to the inference.
@@ -112,6 +128,11 @@ pub struct ColumnSchema {
     /// LLM-generated insight (when available).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub llm_insight: Option<String>,
+    /// How the type and role were inferred: the candidate types with their
+    /// votes, each source's weight and confidence, and why the final type
+    /// won. `None` for columns not built by inference.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<InferenceExplanation>,
 }
 
 impl ColumnSchema {
@@ -129,9 +150,11 @@ impl ColumnSchema {
             expected_range: None,
             constraints: Vec::new(),
             statistics: ColumnStatistics::default(),
+            detected_null_tokens: Vec::new(),
             confidence: 0.0,
             inference_sources: Vec::new(),
             llm_insight: None,
+            explanation: None,
         }
     }
 
@@ -142,12 +165,157 @@ impl ColumnSchema {
             && matches!(self.semantic_role, SemanticRole::Identifier)
     }
 
-    /// Get the null percentage.
+    /// Number of missing values, including detected null tokens.
+    pub fn missing_count(&self) -> usize {
+        self.statistics.null_count + self.statistics.null_token_count
+    }
+
+    /// Get the null percentage, including detected null tokens.
     pub fn null_percentage(&self) -> f64 {
         if self.statistics.count == 0 {
             0.0
         } else {
-            (self.statistics.null_count as f64 / self.statistics.count as f64) * 100.0
+            (self.missing_count() as f64 / self.statistics.count as f64) * 100.0
         }
     }
+
+    /// Summarize the column as a JSON profile for documentation.
+    ///
+    /// Every profile has the same keys; `numeric` and `string_length` are
+    /// null when they do not apply. Built from the statistics computed during
+    /// inference, so no data is read.
+    pub fn profile(&self) -> Value {
+        let stats = &self.statistics;
+
+        // Older schemas kept value counts in first-seen order
+        let mut top_values: Vec<(&String, &usize)> = stats.value_counts.iter().flatten().collect();
+        top_values.sort_by_key(|(_, count)| std::cmp::Reverse(**count));
+
+        let non_null = stats.count.saturating_sub(stats.null_count);
+        let top_values: Vec<Value> = top_values
+            .into_iter()
+            .map(|(value, &count)| {
+                let pct = if non_null == 0 {
+                    0.0
+                } else {
+                    count as f64 / non_null as f64 * 100.0
+                };
+                json!({"value": value, "count": count, "percentage": pct})
+            })
+            .collect();
+
+        json!({
+            "name": self.name,
+            "position": self.position,
+            "type": self.inferred_type,
+            "semantic_type": self.semantic_type,
+            "semantic_role": self.semantic_role,
+            "count": stats.count,
+            "missing_count": self.missing_count(),
+            "null_percentage": self.null_percentage(),
+            "distinct_count": stats.unique_count,
+            "top_values": top_values,
+            "numeric": stats.numeric.as_ref().map(|n| json!({
+                "min": n.min,
+                "q1": n.q1,
+                "median": n.median,
+                "q3": n.q3,
+                "max": n.max,
+                "mean": n.mean,
+                "std": n.std,
+            })),
+            "string_length": stats.string.as_ref().map(|s| json!({
+                "min": s.min_length,
+                "max": s.max_length,
+                "mean": s.avg_length,
+            })),
+        })
+    }
+
+    /// Check whether a value is one of this column's detected null tokens.
+    pub fn is_null_token(&self, value: &str) -> bool {
+        let trimmed = value.trim();
+        self.detected_null_tokens.iter().any(|t| t == trimmed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_shape() {
+        let mut column = ColumnSchema::new("diagnosis", 2);
+        column.inferred_type = ColumnType::String;
+        column.statistics = ColumnStatistics {
+            count: 10,
+            null_count: 2,
+            unique_count: 3,
+            value_counts: Some(IndexMap::from([
+                ("UC".to_string(), 2),
+                ("CD".to_string(), 5),
+                ("IBDU".to_string(), 1),
+            ])),
+            string: Some(StringStatistics {
+                min_length: 2,
+                max_length: 4,
+                avg_length: 2.25,
+            }),
+            ..Default::default()
+        };
+
+        let profile = column.profile();
+        assert_eq!(profile["type"], "string");
+        assert_eq!(profile["distinct_count"], 3);
+        assert_eq!(profile["null_percentage"], 20.0);
+        assert_eq!(profile["top_values"][0]["value"], "CD");
+        assert_eq!(profile["top_values"][0]["percentage"], 62.5);
+        assert_eq!(profile["string_length"]["max"], 4);
+        assert!(profile["numeric"].is_null());
+    }
+
+    #[test]
+    fn test_detected_null_tokens_round_trip() {
+        let mut column = ColumnSchema::new("age", 0);
+        column.detected_null_tokens = vec!["-999".to_string()];
+        column.statistics.count = 10;
+        column.statistics.null_count = 1;
+        column.statistics.null_token_count = 3;
+
+        let json = serde_json::to_string(&column).unwrap();
+        let restored: ColumnSchema = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.detected_null_tokens, vec!["-999"]);
+        assert_eq!(restored.statistics.null_token_count, 3);
+        assert_eq!(restored.missing_count(), 4);
+        assert!((restored.null_percentage() - 40.0).abs() < f64::EPSILON);
+        assert!(restored.is_null_token(" -999 "));
+    }
+
+    #[test]
+    fn test_detected_null_tokens_default_when_absent() {
+        let json = serde_json::to_string(&ColumnSchema::new("notes", 0)).unwrap();
+        assert!(!json.contains("detected_null_tokens"));
+        assert!(!json.contains("null_token_count"));
+
+        let restored: ColumnSchema = serde_json::from_str(&json).unwrap();
+        assert!(restored.detected_null_tokens.is_empty());
+        assert_eq!(restored.statistics.null_token_count, 0);
+    }
+
+    #[test]
+    fn test_explanation_round_trips() {
+        let mut column = ColumnSchema::new("age", 0);
+        column.explanation = Some(InferenceExplanation {
+            candidates: Vec::new(),
+            sources: Vec::new(),
+            rationale: "only integers".to_string(),
+        });
+        let json = serde_json::to_string(&column).unwrap();
+        let restored: ColumnSchema = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.explanation, column.explanation);
+
+        // Columns without one leave it out
+        let json = serde_json::to_string(&ColumnSchema::new("age", 0)).unwrap();
+        assert!(!json.contains("explanation"));
+    }
 }
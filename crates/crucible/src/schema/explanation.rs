@@ -0,0 +1,42 @@
+//! Explanations of how a column's schema was inferred.
+
+use serde::{Deserialize, Serialize};
+
+use super::types::ColumnType;
+
+/// Why a column was given its type and role.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InferenceExplanation {
+    /// Types the column's values parsed as, most votes first.
+    pub candidates: Vec<TypeCandidate>,
+    /// How each inference source contributed.
+    pub sources: Vec<SourceContribution>,
+    /// Why the final type was chosen over the other candidates.
+    pub rationale: String,
+}
+
+/// A type considered for a column.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TypeCandidate {
+    /// The candidate type.
+    pub column_type: ColumnType,
+    /// Non-null values detected as this type.
+    pub votes: usize,
+    /// Share of non-null values detected as this type (0.0-1.0).
+    pub share: f64,
+}
+
+/// How one inference source (statistical, semantic, user hint) contributed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceContribution {
+    /// Source name, as listed in `inference_sources` when used.
+    pub source: String,
+    /// Weight of the source in the fused confidence.
+    pub weight: f64,
+    /// The source's own confidence (0.0-1.0).
+    pub confidence: f64,
+    /// Whether the source contributed to the final schema.
+    pub used: bool,
+    /// What the source concluded.
+    pub detail: String,
+}
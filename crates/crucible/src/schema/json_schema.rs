@@ -0,0 +1,186 @@
+//! Export of inferred schemas as JSON Schema (draft 2020-12).
+//!
+//! The exported schema describes the array-of-row-objects shape produced by
+//! JSON output, so downstream tooling can validate curated data directly.
+
+use serde_json::{Map, Value, json};
+
+use super::column::ColumnSchema;
+use super::table::TableSchema;
+use super::types::{ColumnType, Constraint};
+
+/// Dialect URI for JSON Schema draft 2020-12.
+const DRAFT_2020_12: &str = "https://json-schema.org/draft/2020-12/schema";
+
+impl TableSchema {
+    /// Export the schema as a JSON Schema (draft 2020-12) document.
+    ///
+    /// Each column becomes a property of the row object. Types, ranges, set
+    /// membership, patterns, and lengths map to their JSON Schema keywords;
+    /// nullable columns also accept `null`, and unique columns are marked
+    /// with a custom `x-unique` keyword.
+    pub fn to_json_schema(&self) -> Value {
+        let properties: Map<String, Value> = self
+            .columns
+            .iter()
+            .map(|col| (col.name.clone(), col.to_json_schema_property()))
+            .collect();
+
+        json!({
+            "$schema": DRAFT_2020_12,
+            "type": "array",
+            "items": {
+                "type": "object",
+                "properties": properties,
+                "required": self.column_names(),
+            },
+        })
+    }
+}
+
+impl ColumnSchema {
+    /// Describe this column as a JSON Schema property.
+    fn to_json_schema_property(&self) -> Value {
+        let mut property = Map::new();
+
+        let (json_type, format) = match self.inferred_type {
+            ColumnType::Integer => (Some("integer"), None),
+            ColumnType::Float => (Some("number"), None),
+            ColumnType::Boolean => (Some("boolean"), None),
+            ColumnType::String => (Some("string"), None),
+            ColumnType::Date => (Some("string"), Some("date")),
+            ColumnType::DateTime => (Some("string"), Some("date-time")),
+            ColumnType::Time => (Some("string"), Some("time")),
+            ColumnType::List { .. } => (Some("string"), None),
+            ColumnType::Unknown => (None, None),
+        };
+
+        if let Some(json_type) = json_type {
+            let type_value = if self.nullable {
+                json!([json_type, "null"])
+            } else {
+                json!(json_type)
+            };
+            property.insert("type".to_string(), type_value);
+        }
+        if let Some(format) = format {
+            property.insert("format".to_string(), json!(format));
+        }
+        let item_delimiter = self.inferred_type.item_delimiter();
+        if let Some(delimiter) = item_delimiter {
+            property.insert("x-item-delimiter".to_string(), json!(delimiter.to_string()));
+        }
+
+        let is_string = json_type == Some("string");
+        for constraint in &self.constraints {
+            match constraint {
+                Constraint::Range { min, max, .. } if self.inferred_type.is_numeric() => {
+                    if let Some(min) = min {
+                        property.insert("minimum".to_string(), json!(min));
+                    }
+                    if let Some(max) = max {
+                        property.insert("maximum".to_string(), json!(max));
+                    }
+                }
+                // The vocabulary of a list column applies to each item
+                Constraint::SetMembership { values, .. } if item_delimiter.is_some() => {
+                    property.insert("x-item-enum".to_string(), json!(values));
+                }
+                Constraint::SetMembership { values, .. } => {
+                    let mut allowed: Vec<Value> =
+                        values.iter().map(|v| self.typed_json_value(v)).collect();
+                    if self.nullable {
+                        allowed.push(Value::Null);
+                    }
+                    property.insert("enum".to_string(), Value::Array(allowed));
+                }
+                Constraint::Pattern { value: pattern, .. } | Constraint::Regex { pattern }
+                    if is_string =>
+                {
+                    property.insert("pattern".to_string(), json!(pattern));
+                }
+                Constraint::Length { min, max, .. } if is_string => {
+                    if let Some(min) = min {
+                        property.insert("minLength".to_string(), json!(min));
+                    }
+                    if let Some(max) = max {
+                        property.insert("maxLength".to_string(), json!(max));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let unique = self.unique
+            || self
+                .constraints
+                .iter()
+                .any(|c| matches!(c, Constraint::Unique { .. }));
+        if unique {
+            property.insert("x-unique".to_string(), json!(true));
+        }
+
+        if let Some(ref insight) = self.llm_insight {
+            property.insert("description".to_string(), json!(insight));
+        }
+
+        Value::Object(property)
+    }
+
+    /// Convert a raw value to the JSON type this column is exported as.
+    fn typed_json_value(&self, value: &str) -> Value {
+        let trimmed = value.trim();
+        match self.inferred_type {
+            ColumnType::Integer => trimmed.parse::<i64>().map(Value::from).ok(),
+            ColumnType::Float => trimmed.parse::<f64>().ok().map(|n| json!(n)),
+            ColumnType::Boolean => trimmed.to_lowercase().parse::<bool>().map(Value::from).ok(),
+            _ => None,
+        }
+        .unwrap_or_else(|| json!(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json_schema() {
+        let mut age = ColumnSchema::new("age", 0);
+        age.inferred_type = ColumnType::Integer;
+        age.nullable = true;
+        age.constraints = vec![Constraint::Range {
+            min: Some(0.0),
+            max: Some(120.0),
+            confidence: 0.9,
+        }];
+
+        let mut sample_id = ColumnSchema::new("sample_id", 1);
+        sample_id.inferred_type = ColumnType::String;
+        sample_id.nullable = false;
+        sample_id.unique = true;
+
+        let mut visit = ColumnSchema::new("visit", 2);
+        visit.inferred_type = ColumnType::Integer;
+        visit.nullable = false;
+        visit.constraints = vec![Constraint::SetMembership {
+            values: vec!["1".to_string(), "2".to_string()],
+            confidence: 0.9,
+        }];
+
+        let schema = TableSchema::with_columns(vec![age, sample_id, visit]);
+        let json_schema = schema.to_json_schema();
+
+        assert_eq!(json_schema["$schema"], DRAFT_2020_12);
+        let items = &json_schema["items"];
+        assert_eq!(items["required"], json!(["age", "sample_id", "visit"]));
+
+        let properties = &items["properties"];
+        assert_eq!(properties["age"]["type"], json!(["integer", "null"]));
+        assert_eq!(properties["age"]["minimum"], json!(0.0));
+        assert_eq!(properties["age"]["maximum"], json!(120.0));
+        assert_eq!(properties["sample_id"]["type"], json!("string"));
+        assert_eq!(properties["sample_id"]["x-unique"], json!(true));
+        assert_eq!(properties["visit"]["enum"], json!([1, 2]));
+    }
+}
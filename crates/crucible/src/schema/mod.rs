@@ -1,9 +1,12 @@
 //! Schema types for representing inferred table structure.
 
 mod column;
+mod explanation;
+mod json_schema;
 mod table;
 mod types;
 
 pub use column::{ColumnSchema, ColumnStatistics, NumericStatistics, StringStatistics};
-pub use table::{CrossColumnRule, RowConstraint, TableSchema};
+pub use explanation::{InferenceExplanation, SourceContribution, TypeCandidate};
+pub use table::{CrossColumnRule, InferenceSettings, RowConstraint, TableSchema};
 pub use types::{ColumnType, Constraint, SemanticRole, SemanticType};
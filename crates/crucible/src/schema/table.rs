@@ -1,7 +1,14 @@
 //! Table-level schema definition.
 
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
 use serde::{Deserialize, Serialize};
 
+use crate::error::{CrucibleError, Result};
+use crate::validation::DecimalMark;
+
 use super::column::ColumnSchema;
 
 /// A constraint that spans multiple columns.
@@ -20,6 +27,16 @@ pub enum RowConstraint {
     },
 }
 
+impl RowConstraint {
+    /// The columns the constraint spans.
+    fn columns_mut(&mut self) -> &mut Vec<String> {
+        match self {
+            RowConstraint::UniqueIdentifier { columns, .. }
+            | RowConstraint::UniqueComposite { columns, .. } => columns,
+        }
+    }
+}
+
 /// A rule relating multiple columns.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrossColumnRule {
@@ -38,6 +55,18 @@ pub struct CrossColumnRule {
     pub confidence: f64,
 }
 
+/// Inference thresholds a schema was inferred with, for reproducing it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InferenceSettings {
+    /// Most distinct values a text column could have and be categorical.
+    pub categorical_max_distinct: usize,
+    /// Distinct-to-value ratio above which a text column was not categorical.
+    pub categorical_max_ratio: f64,
+    /// Decimal mark numbers were read with.
+    #[serde(default)]
+    pub decimal_mark: DecimalMark,
+}
+
 /// Schema for an entire table.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TableSchema {
@@ -49,6 +78,9 @@ pub struct TableSchema {
     /// Cross-column rules.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub cross_column_rules: Vec<CrossColumnRule>,
+    /// Thresholds the schema was inferred with, if it was inferred.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inference: Option<InferenceSettings>,
 }
 
 impl TableSchema {
@@ -58,6 +90,7 @@ impl TableSchema {
             columns: Vec::new(),
             row_constraints: Vec::new(),
             cross_column_rules: Vec::new(),
+            inference: None,
         }
     }
 
@@ -67,6 +100,7 @@ impl TableSchema {
             columns,
             row_constraints: Vec::new(),
             cross_column_rules: Vec::new(),
+            inference: None,
         }
     }
 
@@ -102,6 +136,82 @@ impl TableSchema {
     pub fn identifier_columns(&self) -> impl Iterator<Item = &ColumnSchema> {
         self.columns.iter().filter(|c| c.is_likely_identifier())
     }
+
+    /// Rename a column, keeping its type, role, and constraints.
+    ///
+    /// Row constraints naming the column are updated too. A different
+    /// column already named `to` is dropped, and later positions shift
+    /// down. Returns false when there is no column named `from`.
+    pub fn rename_column(&mut self, from: &str, to: &str) -> bool {
+        if self.get_column(from).is_none() {
+            return false;
+        }
+
+        let replaced = self.columns.iter().position(|c| c.name == to).filter(|_| from != to);
+        if let Some(idx) = replaced {
+            let removed = self.columns.remove(idx);
+            for column in &mut self.columns {
+                if column.position > removed.position {
+                    column.position -= 1;
+                }
+            }
+            for constraint in &mut self.row_constraints {
+                constraint.columns_mut().retain(|c| c != to);
+            }
+        }
+
+        for column in &mut self.columns {
+            if column.name == from {
+                column.name = to.to_string();
+            }
+        }
+        for constraint in &mut self.row_constraints {
+            for column in constraint.columns_mut() {
+                if column == from {
+                    *column = to.to_string();
+                }
+            }
+        }
+        true
+    }
+
+    /// Save the schema to a JSON file, e.g. to freeze it as a contract.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+
+        let file = File::create(path).map_err(|e| {
+            CrucibleError::Persistence(format!(
+                "Failed to create schema file '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        serde_json::to_writer_pretty(BufWriter::new(file), self).map_err(|e| {
+            CrucibleError::Persistence(format!("Failed to serialize schema: {}", e))
+        })
+    }
+
+    /// Load a schema from a JSON file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+
+        let file = File::open(path).map_err(|e| {
+            CrucibleError::Persistence(format!(
+                "Failed to open schema file '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        serde_json::from_reader(BufReader::new(file)).map_err(|e| {
+            CrucibleError::Persistence(format!(
+                "Failed to parse schema file '{}': {}",
+                path.display(),
+                e
+            ))
+        })
+    }
 }
 
 impl Default for TableSchema {
@@ -20,6 +20,11 @@ pub enum ColumnType {
     Date,
     /// Time only (no date component).
     Time,
+    /// Delimited lists of items in each cell (e.g. `antibiotic;steroid`).
+    List {
+        /// Character separating the items in a cell.
+        item_delimiter: char,
+    },
     /// Unable to determine type.
     Unknown,
 }
@@ -37,6 +42,29 @@ impl ColumnType {
             ColumnType::DateTime | ColumnType::Date | ColumnType::Time
         )
     }
+
+    /// The character separating items, for list columns.
+    pub fn item_delimiter(&self) -> Option<char> {
+        match self {
+            ColumnType::List { item_delimiter } => Some(*item_delimiter),
+            _ => None,
+        }
+    }
+
+    /// Split a value into the items it holds.
+    ///
+    /// For list columns these are the trimmed, non-empty items; for any
+    /// other type, the trimmed value itself.
+    pub fn items<'a>(&self, value: &'a str) -> Vec<&'a str> {
+        match self {
+            ColumnType::List { item_delimiter } => value
+                .split(*item_delimiter)
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .collect(),
+            _ => vec![value.trim()],
+        }
+    }
 }
 
 impl Default for ColumnType {
@@ -59,6 +87,8 @@ pub enum SemanticRole {
     Outcome,
     /// Metadata about the data (e.g., date_collected).
     Metadata,
+    /// Geographic coordinate (latitude, longitude, or a lat/lon pair).
+    Coordinate,
     /// Unable to determine role.
     Unknown,
 }
@@ -69,6 +99,21 @@ impl Default for SemanticRole {
     }
 }
 
+impl SemanticRole {
+    /// Get a lowercase label, as serialized.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SemanticRole::Identifier => "identifier",
+            SemanticRole::Grouping => "grouping",
+            SemanticRole::Covariate => "covariate",
+            SemanticRole::Outcome => "outcome",
+            SemanticRole::Metadata => "metadata",
+            SemanticRole::Coordinate => "coordinate",
+            SemanticRole::Unknown => "unknown",
+        }
+    }
+}
+
 /// Semantic type providing more specific categorization.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -87,6 +132,10 @@ pub enum SemanticType {
     Count,
     /// Proportion/percentage (0-1 or 0-100).
     Proportion,
+    /// Money amounts written with a currency symbol (e.g. `$1,234.56`).
+    Currency,
+    /// Percentages written with a percent sign (e.g. `45%`).
+    Percentage,
     /// Free text.
     FreeText,
     /// Unable to determine.
@@ -133,6 +182,13 @@ pub enum Constraint {
     NotNull {
         confidence: f64,
     },
+    /// Values must match a declared regex.
+    ///
+    /// Unlike [`Constraint::Pattern`], which is a heuristic guess, this is a
+    /// known format and is always enforced by validation.
+    Regex {
+        pattern: String,
+    },
 }
 
 impl Constraint {
@@ -145,6 +201,7 @@ impl Constraint {
             Constraint::Length { confidence, .. } => *confidence,
             Constraint::Unique { confidence } => *confidence,
             Constraint::NotNull { confidence } => *confidence,
+            Constraint::Regex { .. } => 1.0,
         }
     }
 }
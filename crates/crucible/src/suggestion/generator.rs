@@ -7,9 +7,11 @@
 use indexmap::IndexMap;
 use serde_json::{json, Value};
 
-use crate::validation::{Observation, ObservationType, Severity};
+use crate::validation::{
+    BOOLEAN_FALSE_VALUES, BOOLEAN_TRUE_VALUES, Observation, ObservationType, Severity,
+};
 
-use super::{Suggestion, SuggestionAction};
+use super::{ConvertUnitParams, Suggestion, SuggestionAction};
 
 /// Generates suggestions from observations using rule-based logic.
 pub struct SuggestionEngine;
@@ -20,7 +22,9 @@ impl SuggestionEngine {
         let mut suggestions = Vec::new();
 
         for obs in observations {
-            if let Some(suggestion) = Self::generate_for_observation(obs) {
+            if Self::is_semantic_equivalence(obs) {
+                suggestions.extend(Self::suggest_merge_equivalents(obs));
+            } else if let Some(suggestion) = Self::generate_for_observation(obs) {
                 suggestions.push(suggestion);
             }
         }
@@ -39,12 +43,31 @@ impl SuggestionEngine {
                 // Check if this is a date format inconsistency
                 if Self::is_date_format_issue(obs) {
                     Self::suggest_convert_date(obs)
+                } else if Self::is_timezone_issue(obs) {
+                    // Naive times can't be converted without knowing the
+                    // zone they were recorded in
+                    None
+                } else if Self::is_boolean_issue(obs) {
+                    Self::suggest_normalize_boolean(obs)
+                } else if Self::is_whitespace_issue(obs) {
+                    Self::suggest_trim_whitespace(obs)
+                } else if Self::is_precision_issue(obs) {
+                    Self::suggest_round_numeric(obs)
                 } else {
                     Self::suggest_standardize(obs)
                 }
             }
+            ObservationType::Outlier if Self::is_range_violation(obs) => {
+                Self::suggest_clamp_or_flag(obs)
+            }
             ObservationType::Outlier => Self::suggest_flag_outlier(obs),
             ObservationType::Duplicate => Self::suggest_handle_duplicate(obs),
+            ObservationType::TypeMismatch if Self::is_grouped_number(obs) => {
+                Self::suggest_strip_thousands(obs)
+            }
+            ObservationType::TypeMismatch if Self::is_formatted_number(obs) => {
+                Self::suggest_clean_formatted_number(obs)
+            }
             ObservationType::TypeMismatch => Self::suggest_handle_type_mismatch(obs),
             ObservationType::ConstraintViolation => Self::suggest_flag_constraint(obs),
             ObservationType::Completeness => Self::suggest_flag_completeness(obs),
@@ -52,6 +75,17 @@ impl SuggestionEngine {
             ObservationType::CrossColumn => Self::suggest_flag_cross_column(obs),
             ObservationType::PatternViolation => Self::suggest_flag_pattern(obs),
             ObservationType::CrossColumnInconsistency => Self::suggest_flag_cross_column(obs),
+            // Structural deviations (missing columns, type changes) have no rows to fix
+            ObservationType::ContractViolation if obs.evidence.sample_rows.is_empty() => None,
+            ObservationType::ContractViolation => Self::suggest_flag_constraint(obs),
+            ObservationType::SkippedCheck => None,
+            ObservationType::NonStandardUnit => Self::suggest_convert_unit(obs),
+            ObservationType::Encoding => Self::suggest_redecode(obs),
+            // Spans several files, so there is nothing to fix within one table
+            ObservationType::CohortInconsistency => None,
+            // Headers were already renamed by the parser
+            ObservationType::SchemaIssue => None,
+            ObservationType::SensitiveData => Self::suggest_redact(obs),
         }
     }
 
@@ -61,14 +95,118 @@ impl SuggestionEngine {
         desc.contains("date format") || desc.contains("Mixed date")
     }
 
+    /// Check if an observation is about datetimes mixing timezone notations.
+    fn is_timezone_issue(obs: &Observation) -> bool {
+        obs.evidence
+            .expected
+            .as_ref()
+            .is_some_and(|expected| expected.get("timezone").is_some())
+    }
+
+    /// Check if an observation groups synonyms of the same concept.
+    fn is_semantic_equivalence(obs: &Observation) -> bool {
+        obs.detector == "semantic_equivalence_validator"
+    }
+
+    /// Check if an observation is about currency or percentages stored as text.
+    fn is_formatted_number(obs: &Observation) -> bool {
+        obs.evidence
+            .expected
+            .as_ref()
+            .is_some_and(|e| e.get("format").is_some())
+    }
+
+    /// Check if an observation is about numbers written with thousands separators.
+    fn is_grouped_number(obs: &Observation) -> bool {
+        obs.evidence
+            .expected
+            .as_ref()
+            .is_some_and(|e| e.get("format").and_then(|f| f.as_str()) == Some("grouped"))
+    }
+
+    /// Check if an observation is about stray whitespace in values.
+    fn is_whitespace_issue(obs: &Observation) -> bool {
+        obs.detector == "whitespace_validator"
+    }
+
+    /// Check if an observation is about inconsistent numeric precision.
+    fn is_precision_issue(obs: &Observation) -> bool {
+        obs.detector == "precision_validator"
+    }
+
+    /// Check if an observation is about mixed boolean representations.
+    fn is_boolean_issue(obs: &Observation) -> bool {
+        obs.detector == "consistency_validator"
+            && obs
+                .evidence
+                .expected
+                .as_ref()
+                .is_some_and(|e| e.get("type").and_then(|t| t.as_str()) == Some("boolean"))
+    }
+
+    /// Generate suggestion to normalize mixed boolean spellings.
+    ///
+    /// The canonical pair is the one the most common spelling belongs to
+    /// (e.g. mostly "yes" → "yes"/"no").
+    fn suggest_normalize_boolean(obs: &Observation) -> Option<Suggestion> {
+        let counts = obs.evidence.value_counts.as_ref()?.as_object()?;
+        let family = |value: &str| {
+            let lower = value.to_lowercase();
+            BOOLEAN_TRUE_VALUES
+                .iter()
+                .position(|v| *v == lower)
+                .or_else(|| BOOLEAN_FALSE_VALUES.iter().position(|v| *v == lower))
+        };
+
+        let (_, pair) = counts
+            .iter()
+            .filter_map(|(value, count)| Some((count.as_u64()?, family(value)?)))
+            .max_by_key(|(count, _)| *count)?;
+        let (true_token, false_token) = (BOOLEAN_TRUE_VALUES[pair], BOOLEAN_FALSE_VALUES[pair]);
+
+        let affected_rows: u64 = counts
+            .iter()
+            .filter(|(value, _)| {
+                family(value).is_some() && *value != true_token && *value != false_token
+            })
+            .filter_map(|(_, count)| count.as_u64())
+            .sum();
+
+        let params = json!({
+            "column": obs.column,
+            "true_token": true_token,
+            "false_token": false_token,
+        });
+
+        Some(
+            Suggestion::new(
+                &obs.id,
+                SuggestionAction::Standardize,
+                format!(
+                    "Normalize boolean values in column '{}' to '{}'/'{}'.",
+                    obs.column, true_token, false_token
+                ),
+            )
+            .with_parameters(params)
+            .with_affected_rows(affected_rows as usize)
+            .with_confidence(obs.confidence)
+            .with_priority(2)
+            .with_suggester("rule_engine"),
+        )
+    }
+
     /// Generate suggestion to standardize date formats.
     fn suggest_convert_date(obs: &Observation) -> Option<Suggestion> {
         let occurrences = obs.evidence.occurrences.unwrap_or(0);
 
-        let params = json!({
+        let mut params = json!({
             "column": obs.column,
             "target_format": "ISO (YYYY-MM-DD)",
         });
+        // Ambiguous dates are read in the order the data's locale uses
+        if let Some(order) = obs.evidence.expected.as_ref().and_then(|e| e.get("date_order")) {
+            params["assume_order"] = order.clone();
+        }
 
         Some(
             Suggestion::new(
@@ -154,6 +292,125 @@ impl SuggestionEngine {
         )
     }
 
+    /// Generate suggestion to trim whitespace in values, collapsing internal
+    /// runs when any were found.
+    ///
+    /// Applied as a whitespace trim over the whole column, so values beyond
+    /// the sampled evidence are fixed too.
+    fn suggest_trim_whitespace(obs: &Observation) -> Option<Suggestion> {
+        let affected_rows = obs.evidence.occurrences.filter(|&n| n > 0)?;
+        let collapse_internal = obs
+            .evidence
+            .expected
+            .as_ref()
+            .and_then(|expected| expected.get("internal"))
+            .and_then(|n| n.as_u64())
+            .is_some_and(|n| n > 0);
+        let params = json!({
+            "column": obs.column,
+            "collapse_internal": collapse_internal,
+        });
+        let verb = if collapse_internal { "Trim and collapse" } else { "Trim" };
+
+        Some(
+            Suggestion::new(
+                &obs.id,
+                SuggestionAction::Standardize,
+                format!(
+                    "{} whitespace in {} value(s) of column '{}'.",
+                    verb, affected_rows, obs.column
+                ),
+            )
+            .with_parameters(params)
+            .with_affected_rows(affected_rows)
+            .with_confidence(obs.confidence)
+            .with_priority(2)
+            .with_suggester("rule_engine"),
+        )
+    }
+
+    /// Generate suggestion to round a column to its modal precision.
+    ///
+    /// Values with fewer decimal places are padded (`5` → `5.00`), so the
+    /// whole column ends up written to the same precision.
+    fn suggest_round_numeric(obs: &Observation) -> Option<Suggestion> {
+        let decimals = obs.evidence.expected.as_ref()?.get("modal_decimals")?.as_u64()?;
+        let affected_rows = obs.evidence.occurrences.unwrap_or(0);
+        let params = json!({
+            "column": obs.column,
+            "decimals": decimals,
+            "fixed": true,
+        });
+
+        Some(
+            Suggestion::new(
+                &obs.id,
+                SuggestionAction::Standardize,
+                format!(
+                    "Round {} value(s) of column '{}' to {} decimal place(s), the column's usual precision.",
+                    affected_rows, obs.column, decimals
+                ),
+            )
+            .with_parameters(params)
+            .with_affected_rows(affected_rows)
+            .with_confidence(obs.confidence)
+            .with_priority(3)
+            .with_suggester("rule_engine"),
+        )
+    }
+
+    /// Generate one suggestion per group of semantic equivalents.
+    ///
+    /// Evidence groups are keyed by their canonical value, which every other
+    /// variant in the group is mapped to.
+    fn suggest_merge_equivalents(obs: &Observation) -> Vec<Suggestion> {
+        let Some(groups) = obs.evidence.value_counts.as_ref().and_then(|v| v.as_object()) else {
+            return Vec::new();
+        };
+
+        let mut suggestions = Vec::new();
+        for (canonical, variants) in groups {
+            let Some(variants) = variants.as_object() else {
+                continue;
+            };
+
+            let mut mapping = IndexMap::new();
+            let mut affected_rows = 0;
+            for (variant, count) in variants {
+                if variant != canonical {
+                    mapping.insert(variant.clone(), canonical.clone());
+                    affected_rows += count.as_u64().unwrap_or(0) as usize;
+                }
+            }
+            if mapping.is_empty() {
+                continue;
+            }
+
+            let merged: Vec<&String> = mapping.keys().collect();
+            suggestions.push(
+                Suggestion::new(
+                    &obs.id,
+                    SuggestionAction::Standardize,
+                    format!(
+                        "Merge {:?} into '{}' in column '{}'; they name the same thing.",
+                        merged, canonical, obs.column
+                    ),
+                )
+                .with_parameters(json!({
+                    "column": obs.column,
+                    "mapping": mapping,
+                    "canonical": canonical,
+                }))
+                .with_affected_rows(affected_rows)
+                .with_confidence(obs.confidence)
+                .with_priority(2)
+                .with_suggester("rule_engine"),
+            );
+        }
+
+        suggestions
+    }
+
     /// Extract a standardization mapping from value_counts evidence.
     fn extract_standardization_mapping(value_counts: &Value, _column: &str) -> IndexMap<String, String> {
         let mut mapping = IndexMap::new();
@@ -238,6 +495,167 @@ impl SuggestionEngine {
         )
     }
 
+    /// Check if an outlier observation is a violation of a known range.
+    fn is_range_violation(obs: &Observation) -> bool {
+        obs.detector == "range_validator"
+            && obs
+                .evidence
+                .expected
+                .as_ref()
+                .is_some_and(|e| !e["min"].is_null() || !e["max"].is_null())
+    }
+
+    /// Every row the observation affects, or its sample rows when the
+    /// detector didn't list them all.
+    fn affected_rows(obs: &Observation) -> &[usize] {
+        if obs.evidence.rows.is_empty() {
+            &obs.evidence.sample_rows
+        } else {
+            &obs.evidence.rows
+        }
+    }
+
+    /// Generate suggestion to clamp a few out-of-range values to the bounds.
+    ///
+    /// Clamping rewrites data, so it is only proposed when violations are
+    /// rare (at most 5% of rows); widespread violations are flagged instead,
+    /// since they more likely mean the range itself is wrong.
+    fn suggest_clamp_or_flag(obs: &Observation) -> Option<Suggestion> {
+        if obs.evidence.percentage.unwrap_or(0.0) > 5.0 {
+            return Self::suggest_flag_outlier(obs);
+        }
+
+        let expected = obs.evidence.expected.as_ref()?;
+        let min = expected.get("min").and_then(|v| v.as_f64());
+        let max = expected.get("max").and_then(|v| v.as_f64());
+        let occurrences = obs
+            .evidence
+            .occurrences
+            .unwrap_or(obs.evidence.sample_rows.len());
+
+        // Every out-of-range value is clamped, not just the sampled rows
+        let params = json!({
+            "column": obs.column,
+            "min": min,
+            "max": max,
+            "rows": Self::affected_rows(obs),
+        });
+
+        let bound = |b: Option<f64>, open: &str| b.map_or(open.to_string(), |v| v.to_string());
+        let rationale = format!(
+            "Clamp {} value(s) in column '{}' to the expected range [{}, {}]. Reject to keep the original values for review.",
+            occurrences,
+            obs.column,
+            bound(min, "-∞"),
+            bound(max, "∞")
+        );
+
+        Some(
+            Suggestion::new(&obs.id, SuggestionAction::Clamp, rationale)
+                .with_parameters(params)
+                .with_affected_rows(occurrences)
+                .with_confidence(obs.confidence * 0.9)
+                .with_priority(3)
+                .with_suggester("rule_engine"),
+        )
+    }
+
+    /// Generate suggestion to convert a column to its intended unit.
+    fn suggest_convert_unit(obs: &Observation) -> Option<Suggestion> {
+        let expected = obs.evidence.expected.as_ref()?;
+        let params = ConvertUnitParams {
+            column: obs.column.clone(),
+            from_unit: expected.get("detected_unit")?.as_str()?.to_string(),
+            to_unit: expected.get("target_unit")?.as_str()?.to_string(),
+            factor: expected.get("factor")?.as_f64()?,
+        };
+        let occurrences = obs.evidence.occurrences.unwrap_or(0);
+
+        let rationale = format!(
+            "Convert column '{}' from {} to {} (multiply by {:.4}). Reject if the values are already in {}.",
+            params.column, params.from_unit, params.to_unit, params.factor, params.to_unit
+        );
+
+        Some(
+            Suggestion::new(&obs.id, SuggestionAction::ConvertUnit, rationale)
+                .with_parameters(serde_json::to_value(&params).ok()?)
+                .with_affected_rows(occurrences)
+                .with_confidence(obs.confidence)
+                .with_priority(4)
+                .with_suggester("rule_engine"),
+        )
+    }
+
+    /// Generate suggestion to re-decode mis-decoded text.
+    ///
+    /// Repairable mojibake becomes a standardization mapping to the re-decoded
+    /// values; values with replacement characters can only be flagged, since
+    /// the original characters must be recovered from the source file.
+    fn suggest_redecode(obs: &Observation) -> Option<Suggestion> {
+        let expected = obs.evidence.expected.as_ref()?;
+        let guessed_encoding = expected.get("guessed_encoding")?.as_str()?;
+
+        let mapping = obs
+            .evidence
+            .value_counts
+            .as_ref()
+            .map(|vc| Self::extract_standardization_mapping(vc, &obs.column))
+            .unwrap_or_default();
+
+        if mapping.is_empty() {
+            let occurrences = obs.evidence.occurrences.unwrap_or(0);
+            let params = json!({
+                "column": obs.column,
+                "rows": Self::affected_rows(obs),
+                "flag_column": format!("{}_encoding", obs.column),
+                "flag_value": "encoding_error",
+            });
+            let rationale = format!(
+                "Flag {} value(s) in column '{}' with lost characters ({}). Re-read the source file with the correct encoding to recover them.",
+                occurrences, obs.column, guessed_encoding
+            );
+
+            return Some(
+                Suggestion::new(&obs.id, SuggestionAction::Flag, rationale)
+                    .with_parameters(params)
+                    .with_affected_rows(occurrences)
+                    .with_confidence(obs.confidence * 0.8)
+                    .with_priority(3)
+                    .with_suggester("rule_engine"),
+            );
+        }
+
+        let affected_rows = obs
+            .evidence
+            .value_counts
+            .as_ref()
+            .and_then(|vc| vc.as_object())
+            .map(|vc| {
+                vc.values()
+                    .filter_map(|v| v.get("count").and_then(|c| c.as_u64()))
+                    .sum::<u64>() as usize
+            })
+            .unwrap_or(0);
+        let rationale = format!(
+            "Re-decode {} mis-decoded value(s) in column '{}' ({}).",
+            affected_rows, obs.column, guessed_encoding
+        );
+        let params = json!({
+            "column": obs.column,
+            "mapping": mapping,
+            "rows": Self::affected_rows(obs),
+        });
+
+        Some(
+            Suggestion::new(&obs.id, SuggestionAction::Standardize, rationale)
+                .with_parameters(params)
+                .with_affected_rows(affected_rows)
+                .with_confidence(obs.confidence)
+                .with_priority(2)
+                .with_suggester("rule_engine"),
+        )
+    }
+
     /// Generate suggestion to handle duplicates.
     fn suggest_handle_duplicate(obs: &Observation) -> Option<Suggestion> {
         let occurrences = obs.evidence.occurrences.unwrap_or(0);
@@ -267,6 +685,104 @@ impl SuggestionEngine {
         )
     }
 
+    /// Generate suggestion to strip thousands separators from numbers.
+    ///
+    /// The decimal mark the values were read with is passed on, so the
+    /// transform doesn't mistake a decimal comma for a separator.
+    fn suggest_strip_thousands(obs: &Observation) -> Option<Suggestion> {
+        let expected = obs.evidence.expected.as_ref()?;
+        let decimal_mark = expected.get("decimal_mark")?;
+        let target_type = expected.get("target_type")?.as_str()?;
+        let occurrences = obs.evidence.occurrences.unwrap_or(0);
+        let examples: Vec<String> = expected
+            .get("examples")
+            .and_then(|e| e.as_object())
+            .map(|e| {
+                e.iter()
+                    .filter_map(|(from, to)| Some(format!("'{}' → {}", from, to.as_str()?)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let params = json!({
+            "column": obs.column,
+            "decimal_mark": decimal_mark,
+        });
+
+        Some(
+            Suggestion::new(
+                &obs.id,
+                SuggestionAction::Standardize,
+                format!(
+                    "Strip thousands separators from {} value(s) in column '{}' so they read as {} ({}).",
+                    occurrences,
+                    obs.column,
+                    target_type,
+                    examples.join(", ")
+                ),
+            )
+            .with_parameters(params)
+            .with_affected_rows(occurrences)
+            .with_confidence(obs.confidence)
+            .with_priority(2)
+            .with_suggester("rule_engine"),
+        )
+    }
+
+    /// Generate suggestion to convert currency or percentage text to numbers.
+    ///
+    /// The whole column is coerced to `Float`; coercion strips currency
+    /// symbols and thousands separators and divides percentages by 100,
+    /// plain numbers in a percentage column included.
+    fn suggest_clean_formatted_number(obs: &Observation) -> Option<Suggestion> {
+        let expected = obs.evidence.expected.as_ref()?;
+        let format = expected.get("format")?.as_str()?;
+        let target_type = expected.get("target_type")?.as_str()?;
+        let occurrences = obs.evidence.occurrences.unwrap_or(0);
+
+        let cleaning = if format == "percentage" {
+            "removing '%' and dividing every value, plain numbers included, by 100"
+        } else {
+            "removing currency symbols and thousands separators"
+        };
+        let examples: Vec<String> = expected
+            .get("examples")
+            .and_then(|e| e.as_object())
+            .map(|e| {
+                e.iter()
+                    .filter_map(|(from, to)| Some(format!("'{}' → {}", from, to.as_str()?)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let params = json!({
+            "column": obs.column,
+            "target_type": target_type,
+            "format": format,
+            "rows": Self::affected_rows(obs),
+        });
+
+        Some(
+            Suggestion::new(
+                &obs.id,
+                SuggestionAction::Coerce,
+                format!(
+                    "Convert {} {} value(s) in column '{}' to numbers by {} ({}). Other non-numeric values will become NA.",
+                    occurrences,
+                    format,
+                    obs.column,
+                    cleaning,
+                    examples.join(", ")
+                ),
+            )
+            .with_parameters(params)
+            .with_affected_rows(occurrences)
+            .with_confidence(obs.confidence)
+            .with_priority(3)
+            .with_suggester("rule_engine"),
+        )
+    }
+
     /// Generate suggestion to handle type mismatches.
     fn suggest_handle_type_mismatch(obs: &Observation) -> Option<Suggestion> {
         let occurrences = obs.evidence.occurrences.unwrap_or(0);
@@ -418,6 +934,35 @@ impl SuggestionEngine {
         )
     }
 
+    /// Generate suggestion to redact a column holding personal identifiers.
+    ///
+    /// Redaction is proposed; switching `method` to "hash" keeps equal
+    /// values linkable instead, and "pseudonymize" (with an optional
+    /// `prefix`) does so with readable pseudonyms.
+    fn suggest_redact(obs: &Observation) -> Option<Suggestion> {
+        let occurrences = obs.evidence.occurrences.unwrap_or(0);
+        let params = json!({
+            "column": obs.column,
+            "method": "redact",
+        });
+
+        Some(
+            Suggestion::new(
+                &obs.id,
+                SuggestionAction::Redact,
+                format!(
+                    "Redact column '{}' before sharing: {} value(s) contain possible personal identifiers. Use method \"hash\" to keep equal values linkable.",
+                    obs.column, occurrences
+                ),
+            )
+            .with_parameters(params)
+            .with_affected_rows(occurrences)
+            .with_confidence(obs.confidence)
+            .with_priority(1)
+            .with_suggester("rule_engine"),
+        )
+    }
+
     /// Generate suggestion to flag pattern violations.
     fn suggest_flag_pattern(obs: &Observation) -> Option<Suggestion> {
         let occurrences = obs.evidence.occurrences.unwrap_or(0);
@@ -482,6 +1027,63 @@ mod tests {
         assert!(suggestion.rationale.contains("missing"));
     }
 
+    #[test]
+    fn test_generate_formatted_number_suggestion() {
+        let obs = Observation::new(
+            ObservationType::TypeMismatch,
+            Severity::Warning,
+            "gc_content",
+            "4 values (80.0%) are percentages stored as text",
+        )
+        .with_evidence(
+            Evidence::new()
+                .with_occurrences(4)
+                .with_percentage(80.0)
+                .with_sample_rows(vec![0, 1, 2, 3])
+                .with_rows(vec![0, 1, 2, 3, 4])
+                .with_expected(json!({
+                    "target_type": "Float",
+                    "format": "percentage",
+                    "examples": {"45%": "0.45"},
+                })),
+        )
+        .with_confidence(0.85);
+
+        let suggestion = SuggestionEngine::generate_for_observation(&obs).unwrap();
+
+        assert_eq!(suggestion.action, SuggestionAction::Coerce);
+        assert_eq!(suggestion.parameters["target_type"], "Float");
+        assert_eq!(suggestion.parameters["rows"], json!([0, 1, 2, 3, 4]));
+        assert!(suggestion.rationale.contains("'45%' → 0.45"));
+    }
+
+    #[test]
+    fn test_generate_strip_thousands_suggestion() {
+        let obs = Observation::new(
+            ObservationType::TypeMismatch,
+            Severity::Warning,
+            "read_count",
+            "2 values (66.7%) are written with thousands separators",
+        )
+        .with_evidence(
+            Evidence::new()
+                .with_occurrences(2)
+                .with_expected(json!({
+                    "target_type": "Integer",
+                    "format": "grouped",
+                    "decimal_mark": "point",
+                    "examples": {"1,234,567": "1234567"},
+                })),
+        )
+        .with_confidence(0.85);
+
+        let suggestion = SuggestionEngine::generate_for_observation(&obs).unwrap();
+
+        assert_eq!(suggestion.action, SuggestionAction::Standardize);
+        assert_eq!(suggestion.parameters["decimal_mark"], "point");
+        assert!(suggestion.rationale.contains("'1,234,567' → 1234567"));
+    }
+
     #[test]
     fn test_generate_standardize_suggestion() {
         let obs = Observation::new(
@@ -505,6 +1107,170 @@ mod tests {
         assert!(suggestion.rationale.contains("Standardize"));
     }
 
+    #[test]
+    fn test_generate_merge_equivalents_suggestions() {
+        let obs = Observation::new(
+            ObservationType::Inconsistency,
+            Severity::Warning,
+            "diagnosis",
+            "2 semantic equivalent group(s) detected",
+        )
+        .with_evidence(Evidence::new().with_value_counts(Some(json!({
+            "CD": {"Crohn's": 3, "Crohns": 2},
+            "UC": {"UC": 5, "Ulcerative Colitis": 1},
+        }))))
+        .with_detector("semantic_equivalence_validator");
+
+        let suggestions = SuggestionEngine::generate(&[obs]);
+        assert_eq!(suggestions.len(), 2);
+
+        let cd = &suggestions[0];
+        assert_eq!(cd.action, SuggestionAction::Standardize);
+        assert_eq!(cd.parameters["canonical"], "CD");
+        assert_eq!(cd.parameters["mapping"]["Crohn's"], "CD");
+        assert_eq!(cd.parameters["mapping"]["Crohns"], "CD");
+        assert_eq!(cd.affected_rows, 5);
+
+        // The canonical value itself is left out of the mapping
+        let uc = &suggestions[1];
+        assert_eq!(uc.parameters["mapping"].as_object().unwrap().len(), 1);
+        assert_eq!(uc.affected_rows, 1);
+    }
+
+    #[test]
+    fn test_generate_normalize_boolean_suggestion() {
+        let obs = Observation::new(
+            ObservationType::Inconsistency,
+            Severity::Warning,
+            "smoker",
+            "Smoker answers mix yes/no spellings",
+        )
+        .with_evidence(
+            Evidence::new()
+                .with_value_counts(Some(json!({
+                    "yes": 10, "no": 8, "Y": 2, "Yes": 1
+                })))
+                .with_expected(json!({ "type": "boolean" })),
+        )
+        .with_confidence(0.92)
+        .with_detector("consistency_validator");
+
+        let suggestion = SuggestionEngine::generate_for_observation(&obs).unwrap();
+
+        assert_eq!(suggestion.action, SuggestionAction::Standardize);
+        assert_eq!(suggestion.parameters["true_token"], "yes");
+        assert_eq!(suggestion.parameters["false_token"], "no");
+        assert_eq!(suggestion.affected_rows, 3);
+
+        // The description alone doesn't make it a boolean issue
+        let mut described = obs.clone();
+        described.evidence.expected = None;
+        described.description = "Mixed boolean representations: [\"Yes\", \"no\"]".to_string();
+        let suggestion = SuggestionEngine::generate_for_observation(&described);
+        assert!(suggestion.is_none_or(|s| s.parameters.get("true_token").is_none()));
+    }
+
+    #[test]
+    fn test_generate_trim_whitespace_suggestion() {
+        let obs = Observation::new(
+            ObservationType::Inconsistency,
+            Severity::Warning,
+            "site",
+            "3 values have leading, trailing, or repeated whitespace",
+        )
+        .with_evidence(
+            Evidence::new()
+                .with_occurrences(3)
+                .with_expected(json!({"leading_or_trailing": 2, "internal": 1})),
+        )
+        .with_detector("whitespace_validator")
+        .with_confidence(0.9);
+
+        let suggestion = SuggestionEngine::generate_for_observation(&obs).unwrap();
+
+        assert_eq!(suggestion.action, SuggestionAction::Standardize);
+        assert_eq!(
+            suggestion.parameters,
+            json!({"column": "site", "collapse_internal": true})
+        );
+        assert_eq!(suggestion.affected_rows, 3);
+    }
+
+    #[test]
+    fn test_generate_redecode_suggestion() {
+        let obs = Observation::new(
+            ObservationType::Encoding,
+            Severity::Warning,
+            "city",
+            "Encoding problems",
+        )
+        .with_evidence(
+            Evidence::new()
+                .with_occurrences(3)
+                .with_sample_rows(vec![2])
+                .with_rows(vec![2, 7, 9])
+                .with_expected(json!({ "guessed_encoding": "UTF-8 decoded as Windows-1252/Latin-1 (double-encoded)" }))
+                .with_value_counts(Some(json!({
+                    "MontrÃ©al": { "suggestion": "Montréal", "count": 3 }
+                }))),
+        )
+        .with_confidence(0.9);
+
+        let suggestion = SuggestionEngine::generate_for_observation(&obs).unwrap();
+
+        assert_eq!(suggestion.action, SuggestionAction::Standardize);
+        assert_eq!(suggestion.parameters["mapping"]["MontrÃ©al"], "Montréal");
+        assert_eq!(suggestion.parameters["rows"], json!([2, 7, 9]));
+        assert_eq!(suggestion.affected_rows, 3);
+
+        // Values that can't be repaired are flagged in every affected row
+        let mut lost = obs.clone();
+        lost.evidence.value_counts = None;
+        let suggestion = SuggestionEngine::generate_for_observation(&lost).unwrap();
+        assert_eq!(suggestion.action, SuggestionAction::Flag);
+        assert_eq!(suggestion.parameters["rows"], json!([2, 7, 9]));
+    }
+
+    #[test]
+    fn test_generate_clamp_suggestion() {
+        let obs = Observation::new(
+            ObservationType::Outlier,
+            Severity::Info,
+            "age",
+            "2 values outside expected range [0, 120]",
+        )
+        .with_evidence(
+            Evidence::new()
+                .with_occurrences(2)
+                .with_percentage(2.0)
+                .with_sample_rows(vec![4])
+                .with_rows(vec![4, 17])
+                .with_expected(json!({ "min": 0.0, "max": 120.0 })),
+        )
+        .with_confidence(0.85)
+        .with_detector("range_validator");
+
+        // Every out-of-range row is clamped, not just the sampled ones
+        let suggestion = SuggestionEngine::generate_for_observation(&obs).unwrap();
+        assert_eq!(suggestion.action, SuggestionAction::Clamp);
+        assert_eq!(suggestion.parameters["min"], json!(0.0));
+        assert_eq!(suggestion.parameters["max"], json!(120.0));
+        assert_eq!(suggestion.parameters["rows"], json!([4, 17]));
+        assert!(suggestion.rationale.contains("[0, 120]"));
+
+        // An open bound is shown as infinite
+        let mut lower_open = obs.clone();
+        lower_open.evidence.expected = Some(json!({ "min": null, "max": 120.0 }));
+        let suggestion = SuggestionEngine::generate_for_observation(&lower_open).unwrap();
+        assert!(suggestion.rationale.contains("[-∞, 120]"));
+
+        // Widespread violations are flagged rather than rewritten
+        let mut widespread = obs.clone();
+        widespread.evidence.percentage = Some(40.0);
+        let suggestion = SuggestionEngine::generate_for_observation(&widespread).unwrap();
+        assert_eq!(suggestion.action, SuggestionAction::Flag);
+    }
+
     #[test]
     fn test_generate_flag_outlier_suggestion() {
         let obs = Observation::new(
@@ -12,5 +12,6 @@ mod suggestion;
 
 pub use generator::SuggestionEngine;
 pub use suggestion::{
-    ConvertNaParams, FlagParams, StandardizeParams, Suggestion, SuggestionAction,
+    ConvertNaParams, ConvertUnitParams, FlagParams, StandardizeParams, Suggestion,
+    SuggestionAction,
 };
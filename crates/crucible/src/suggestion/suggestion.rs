@@ -20,6 +20,10 @@ pub enum SuggestionAction {
     ConvertDate,
     /// Add a flag column for human review.
     Flag,
+    /// Clamp numeric values to an expected range.
+    Clamp,
+    /// Convert measurements to another unit.
+    ConvertUnit,
     /// Remove row or column.
     Remove,
     /// Combine duplicate entries.
@@ -30,6 +34,8 @@ pub enum SuggestionAction {
     Split,
     /// Create a computed/derived column.
     Derive,
+    /// Redact or hash a column holding personal identifiers.
+    Redact,
 }
 
 impl SuggestionAction {
@@ -41,11 +47,14 @@ impl SuggestionAction {
             SuggestionAction::Coerce => "Type Coercion",
             SuggestionAction::ConvertDate => "Standardize Dates",
             SuggestionAction::Flag => "Flag for Review",
+            SuggestionAction::Clamp => "Clamp to Range",
+            SuggestionAction::ConvertUnit => "Convert Units",
             SuggestionAction::Remove => "Remove",
             SuggestionAction::Merge => "Merge Duplicates",
             SuggestionAction::Rename => "Rename",
             SuggestionAction::Split => "Split Values",
             SuggestionAction::Derive => "Derive Column",
+            SuggestionAction::Redact => "Redact Sensitive Data",
         }
     }
 }
@@ -144,6 +153,43 @@ impl Suggestion {
         self.suggester = suggester.into();
         self
     }
+
+    /// Column this suggestion changes, if its parameters name one.
+    pub fn column(&self) -> Option<&str> {
+        self.parameters.get("column").and_then(Value::as_str)
+    }
+
+    /// What the suggestion changes values to, independent of which rows it touches.
+    ///
+    /// Taken from the canonical value, target type, format or unit, or flag
+    /// column in the parameters; for a bare mapping, its distinct targets.
+    /// Used with the column and action to recognize the same suggestion
+    /// across re-analyses.
+    pub fn target(&self) -> Option<String> {
+        const TARGET_KEYS: &[&str] = &[
+            "canonical",
+            "target_type",
+            "target_format",
+            "to_unit",
+            "format",
+            "flag_column",
+        ];
+        if let Some(target) = TARGET_KEYS.iter().find_map(|key| self.parameters.get(*key)) {
+            return Some(match target {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            });
+        }
+
+        let mapping = self.parameters.get("mapping")?.as_object()?;
+        let mut targets: Vec<String> = mapping
+            .values()
+            .map(|v| v.as_str().map_or_else(|| v.to_string(), str::to_string))
+            .collect();
+        targets.sort();
+        targets.dedup();
+        Some(targets.join(","))
+    }
 }
 
 /// Generate a unique suggestion ID.
@@ -161,7 +207,23 @@ pub struct StandardizeParams {
     pub mapping: std::collections::HashMap<String, String>,
 }
 
+/// Parameters for unit conversion suggestions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertUnitParams {
+    /// Column to convert.
+    pub column: String,
+    /// Unit the values appear to be recorded in.
+    pub from_unit: String,
+    /// Unit to convert to.
+    pub to_unit: String,
+    /// Multiplier taking a value from `from_unit` to `to_unit`.
+    pub factor: f64,
+}
+
 /// Parameters for NA conversion suggestions.
+///
+/// A column's detected null tokens are reported, as spelled, by the
+/// missing-pattern validator, so each token gets its own suggestion.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConvertNaParams {
     /// Column to convert.
@@ -187,6 +249,25 @@ pub struct FlagParams {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_suggestion_target() {
+        let coerce = Suggestion::new("obs", SuggestionAction::Coerce, "")
+            .with_parameters(serde_json::json!({"column": "age", "target_type": "integer"}));
+        assert_eq!(coerce.column(), Some("age"));
+        assert_eq!(coerce.target().as_deref(), Some("integer"));
+
+        let standardize = Suggestion::new("obs", SuggestionAction::Standardize, "")
+            .with_parameters(serde_json::json!({
+                "column": "status",
+                "mapping": {"Active": "active", "ACTIVE": "active", "Done": "done"}
+            }));
+        assert_eq!(standardize.target().as_deref(), Some("active,done"));
+
+        let convert_na = Suggestion::new("obs", SuggestionAction::ConvertNa, "")
+            .with_parameters(serde_json::json!({"column": "status", "from_values": ["NA"]}));
+        assert_eq!(convert_na.target(), None);
+    }
+
     #[test]
     fn test_create_suggestion() {
         let sug = Suggestion::new("obs_001", SuggestionAction::Standardize, "Normalize case")
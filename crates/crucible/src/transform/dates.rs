@@ -0,0 +1,214 @@
+//! Date parsing for normalizing mixed date formats to ISO 8601.
+
+use chrono::NaiveDate;
+
+use super::operations::DateOrder;
+
+/// Two-digit years below this are read as 20xx, the rest as 19xx.
+pub const TWO_DIGIT_YEAR_PIVOT: i32 = 50;
+
+/// Month names and abbreviations, longest first within each month.
+const MONTHS: &[(&str, u32)] = &[
+    ("january", 1), ("jan", 1),
+    ("february", 2), ("feb", 2),
+    ("march", 3), ("mar", 3),
+    ("april", 4), ("apr", 4),
+    ("may", 5),
+    ("june", 6), ("jun", 6),
+    ("july", 7), ("jul", 7),
+    ("august", 8), ("aug", 8),
+    ("september", 9), ("sept", 9), ("sep", 9),
+    ("october", 10), ("oct", 10),
+    ("november", 11), ("nov", 11),
+    ("december", 12), ("dec", 12),
+];
+
+/// A date value read from one of the supported formats.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedDate {
+    /// The date as `YYYY-MM-DD`.
+    pub iso: String,
+    /// Day and month could each have been the other; `assume_order` decided.
+    pub ambiguous: bool,
+}
+
+/// Parse a date in any supported format.
+///
+/// Supported: year first (`2024-01-15`, `2024/1/15`), numeric day and month
+/// with a four- or two-digit year (`15/01/2024`, `1-15-24`, `15.01.2024`),
+/// and month names (`Jan 15 2024`, `January 15, 2024`, `15-Mar-24`).
+/// Dates separated by dots are always read day first, whatever `order` is.
+/// Two-digit years use [`TWO_DIGIT_YEAR_PIVOT`]. Returns `None` for
+/// anything else, including impossible dates like `2024-02-30`.
+pub fn parse_date(value: &str, order: DateOrder) -> Option<ParsedDate> {
+    let value = value.trim();
+    if value.chars().any(|c| c.is_ascii_alphabetic()) {
+        return parse_month_name(value);
+    }
+
+    let parts: Vec<&str> = value.split(['-', '/', '.']).collect();
+    if parts.len() != 3 || parts.iter().any(|p| p.is_empty() || !p.bytes().all(|b| b.is_ascii_digit())) {
+        return None;
+    }
+    let number = |s: &str| s.parse::<u32>().ok();
+
+    // Year first
+    if parts[0].len() == 4 {
+        let date = ymd(number(parts[0])? as i32, number(parts[1])?, number(parts[2])?)?;
+        return Some(ParsedDate {
+            iso: date,
+            ambiguous: false,
+        });
+    }
+
+    if parts[0].len() > 2 || parts[1].len() > 2 {
+        return None;
+    }
+    let year = year(parts[2])?;
+    let (first, second) = (number(parts[0])?, number(parts[1])?);
+    if value.contains('.') {
+        return Some(ParsedDate {
+            iso: ymd(year, second, first)?,
+            ambiguous: false,
+        });
+    }
+
+    // Only a value over 12 settles which part is the day
+    let (month, day, ambiguous) = if first > 12 {
+        (second, first, false)
+    } else if second > 12 {
+        (first, second, false)
+    } else {
+        let ambiguous = first != second;
+        match order {
+            DateOrder::DayFirst => (second, first, ambiguous),
+            DateOrder::MonthFirst => (first, second, ambiguous),
+        }
+    };
+
+    Some(ParsedDate {
+        iso: ymd(year, month, day)?,
+        ambiguous,
+    })
+}
+
+/// Parse a date with a month name, e.g. `Jan 15 2024` or `15-Mar-24`.
+fn parse_month_name(value: &str) -> Option<ParsedDate> {
+    let tokens: Vec<&str> = value
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .collect();
+    if tokens.len() != 3 {
+        return None;
+    }
+
+    let mut month = None;
+    let mut numbers = Vec::new();
+    for token in tokens {
+        if token.bytes().all(|b| b.is_ascii_digit()) {
+            numbers.push(token);
+            continue;
+        }
+        // Ordinal days like "15th"
+        let digits = token.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+        if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+            numbers.push(digits);
+            continue;
+        }
+        let lower = token.to_ascii_lowercase();
+        let number = MONTHS.iter().find(|(name, _)| *name == lower).map(|(_, n)| *n);
+        if month.is_some() || number.is_none() {
+            return None;
+        }
+        month = number;
+    }
+
+    let month = month?;
+    let [a, b] = numbers[..] else {
+        return None;
+    };
+    // A four-digit number is the year; otherwise the year comes last
+    let (day, year) = if a.len() == 4 { (b, a) } else { (a, b) };
+    if day.len() > 2 {
+        return None;
+    }
+
+    Some(ParsedDate {
+        iso: ymd(self::year(year)?, month, day.parse().ok()?)?,
+        ambiguous: false,
+    })
+}
+
+/// Read a four-digit year, or a two-digit year around the pivot.
+fn year(s: &str) -> Option<i32> {
+    let n: i32 = s.parse().ok()?;
+    match s.len() {
+        4 => Some(n),
+        2 if n < TWO_DIGIT_YEAR_PIVOT => Some(2000 + n),
+        2 => Some(1900 + n),
+        _ => None,
+    }
+}
+
+/// Format a valid calendar date as `YYYY-MM-DD`.
+fn ymd(year: i32, month: u32, day: u32) -> Option<String> {
+    NaiveDate::from_ymd_opt(year, month, day).map(|d| d.format("%Y-%m-%d").to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn iso(value: &str, order: DateOrder) -> Option<String> {
+        parse_date(value, order).map(|d| d.iso)
+    }
+
+    #[test]
+    fn test_parse_numeric_dates() {
+        let order = DateOrder::MonthFirst;
+        assert_eq!(iso("2024-01-15", order).as_deref(), Some("2024-01-15"));
+        assert_eq!(iso("2024/1/5", order).as_deref(), Some("2024-01-05"));
+        assert_eq!(iso("01/15/2024", order).as_deref(), Some("2024-01-15"));
+        assert_eq!(iso("15.01.2024", order).as_deref(), Some("2024-01-15"));
+        assert_eq!(iso("1-15-24", order).as_deref(), Some("2024-01-15"));
+        assert_eq!(iso("12/31/99", order).as_deref(), Some("1999-12-31"));
+        assert_eq!(iso("2024-02-30", order), None);
+        assert_eq!(iso("13/13/2024", order), None);
+        assert_eq!(iso("12345", order), None);
+    }
+
+    #[test]
+    fn test_assume_order_only_for_ambiguous_dates() {
+        let day_first = parse_date("03/04/2024", DateOrder::DayFirst).unwrap();
+        assert_eq!(day_first.iso, "2024-04-03");
+        assert!(day_first.ambiguous);
+
+        let month_first = parse_date("03/04/2024", DateOrder::MonthFirst).unwrap();
+        assert_eq!(month_first.iso, "2024-03-04");
+        assert!(month_first.ambiguous);
+
+        // A day over 12 decides the order regardless of the assumption
+        let clear = parse_date("25/04/2024", DateOrder::MonthFirst).unwrap();
+        assert_eq!(clear.iso, "2024-04-25");
+        assert!(!clear.ambiguous);
+        assert!(!parse_date("04/04/2024", DateOrder::DayFirst).unwrap().ambiguous);
+
+        // Dotted dates are day first in any order
+        let dotted = parse_date("03.04.2024", DateOrder::MonthFirst).unwrap();
+        assert_eq!(dotted.iso, "2024-04-03");
+        assert!(!dotted.ambiguous);
+    }
+
+    #[test]
+    fn test_parse_month_names() {
+        let order = DateOrder::DayFirst;
+        assert_eq!(iso("Jan 15 2024", order).as_deref(), Some("2024-01-15"));
+        assert_eq!(iso("January 15, 2024", order).as_deref(), Some("2024-01-15"));
+        assert_eq!(iso("15-Mar-2024", order).as_deref(), Some("2024-03-15"));
+        assert_eq!(iso("15-MAR-24", order).as_deref(), Some("2024-03-15"));
+        assert_eq!(iso("2 Sept 1987", order).as_deref(), Some("1987-09-02"));
+        assert_eq!(iso("March 3rd, 2021", order).as_deref(), Some("2021-03-03"));
+        assert_eq!(iso("March 2024", order), None);
+        assert_eq!(iso("Marching 3 2024", order), None);
+    }
+}
@@ -1,28 +1,83 @@
 //! Transformation engine that applies curation decisions to data.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use indexmap::IndexMap;
 
 use crate::curation::{CurationLayer, DecisionStatus};
 use crate::error::{CrucibleError, Result};
 use crate::input::DataTable;
-use crate::suggestion::SuggestionAction;
-use crate::validation::ObservationType;
+use crate::suggestion::{ConvertUnitParams, SuggestionAction};
+use crate::validation::{
+    DecimalMark, ObservationType, parse_boolean, parse_currency, parse_grouped_number, parse_percentage,
+};
 
-use super::operations::{RowAudit, TransformChange, TransformOperation, TransformResult};
+use crate::bio::OntologyType;
+
+use super::dates;
+use super::operations::{
+    ALL_COLUMNS, ColumnRename, DateNormalizationCounts, DateOrder, MAX_ROUND_DECIMALS, MappedTerm, OntologyMappingCounts, PseudonymMethod, RedactionMethod, RoundingCounts, RowAudit, TransformAudit, TransformChange,
+    TransformOperation, TransformResult, round_decimal,
+};
+
+/// Environment variable holding the key for HMAC pseudonyms.
+pub const PSEUDONYM_KEY_ENV: &str = "CRUCIBLE_PSEUDONYM_KEY";
 
 /// Engine for applying transformations to data based on curation decisions.
-pub struct TransformEngine;
+pub struct TransformEngine {
+    /// Let column renames replace an existing column of the same name.
+    force_renames: bool,
+    /// Key for HMAC pseudonyms, instead of the one in [`PSEUDONYM_KEY_ENV`].
+    pseudonym_key: Option<String>,
+}
 
 impl TransformEngine {
     /// Create a new transform engine.
     pub fn new() -> Self {
-        Self
+        Self {
+            force_renames: false,
+            pseudonym_key: None,
+        }
+    }
+
+    /// Derive pseudonyms with HMAC-SHA-256 under this key.
+    ///
+    /// Without one, the key is read from [`PSEUDONYM_KEY_ENV`] when decisions
+    /// are applied, and pseudonyms fall back to plain SHA-256 if it is unset.
+    /// The key is never stored with the curation or its audit.
+    pub fn with_pseudonym_key(mut self, key: impl Into<String>) -> Self {
+        self.pseudonym_key = Some(key.into());
+        self
+    }
+
+    /// The pseudonym method for decisions applied by this engine.
+    fn pseudonym_method(&self) -> Result<PseudonymMethod> {
+        match self.pseudonym_key.clone().or_else(|| std::env::var(PSEUDONYM_KEY_ENV).ok()) {
+            Some(key) => PseudonymMethod::hmac(key),
+            None => Ok(PseudonymMethod::Sha256),
+        }
+    }
+
+    /// Let a rename onto an existing column's name replace that column,
+    /// instead of failing.
+    pub fn with_force_renames(mut self, force: bool) -> Self {
+        self.force_renames = force;
+        self
     }
 
     /// Apply all accepted decisions from a curation layer to the source data.
+    ///
+    /// A multi-table layer is rejected; apply each table's view (see
+    /// [`CurationLayer::table`]) to that table's data instead.
     pub fn apply(&self, curation: &CurationLayer, data: &mut DataTable) -> Result<TransformResult> {
+        if !curation.tables.is_empty() {
+            return Err(CrucibleError::Validation(format!(
+                "Curation covers {} tables ({}); apply one table at a time",
+                curation.tables.len(),
+                curation.table_names().join(", ")
+            )));
+        }
+
         let mut result = TransformResult::new();
 
         // Get all accepted/modified decisions
@@ -59,9 +114,142 @@ impl TransformEngine {
             if let Some(op) = operation {
                 let change = self.apply_operation(&op, data)?;
                 result.add_change(change);
+                result.operations.push(op);
+            }
+        }
+
+        result.columns = data.headers.clone();
+        Ok(result)
+    }
+
+    /// Undo an applied curation using the original values in its audit.
+    ///
+    /// Cells are restored from the recorded per-row originals, latest change
+    /// first, and columns the operations added are removed. Nothing is
+    /// inferred from the operations themselves, so lossy conversions such as
+    /// case-folding are only undone where the audit kept the exact original.
+    /// A cell that no longer holds the value the transform wrote is left as
+    /// it is and logged with `transform_type` "revert_conflict", as is a
+    /// cell whose original was withheld from the audit.
+    ///
+    /// Renamed columns get their original names back. A rename that
+    /// replaced an existing column can't be undone, so its audit is rejected.
+    pub fn revert(&self, audit: &TransformAudit, data: &mut DataTable) -> Result<TransformResult> {
+        let mut result = TransformResult::new();
+        let mut by_column: IndexMap<String, Vec<RowAudit>> = IndexMap::new();
+
+        if let Some(rename) = audit.columns_renamed.iter().find(|r| r.replaced) {
+            return Err(CrucibleError::Validation(format!(
+                "Cannot revert: renaming '{}' to '{}' replaced an existing column",
+                rename.from, rename.to
+            )));
+        }
+
+        // Row audits name columns as they were when changed, so restore the
+        // original names first and map every name back to its original
+        for rename in audit.columns_renamed.iter().rev() {
+            let idx = data.headers.iter().position(|h| *h == rename.to).ok_or_else(|| {
+                CrucibleError::Validation(format!("Column '{}' not found", rename.to))
+            })?;
+            data.headers[idx] = rename.from.clone();
+            result.add_change(TransformChange {
+                columns_renamed: vec![ColumnRename {
+                    from: rename.to.clone(),
+                    to: rename.from.clone(),
+                    replaced: false,
+                }],
+                ..TransformChange::new(
+                    format!("Renamed '{}' back to '{}'", rename.to, rename.from),
+                    &rename.from,
+                )
+            });
+        }
+        let original_name = |column: &str| {
+            audit
+                .columns_renamed
+                .iter()
+                .rev()
+                .fold(column.to_string(), |name, rename| {
+                    if name == rename.to { rename.from.clone() } else { name }
+                })
+        };
+
+        for entry in audit.row_audits.iter().rev() {
+            let column = original_name(&entry.column);
+            if audit.columns_added.iter().any(|added| original_name(added) == column) {
+                continue;
+            }
+            let col_idx = target_column(data, &column).ok_or_else(|| {
+                CrucibleError::Validation(format!("Column '{}' not found", column))
+            })?;
+            let current = data.get(entry.row, col_idx).unwrap_or_default().to_string();
+
+            // Keyed pseudonyms can only be re-linked through the keyfile
+            if entry.original_withheld {
+                by_column.entry(column.clone()).or_default().push(RowAudit {
+                    row: entry.row,
+                    column: column.clone(),
+                    original_value: current.clone(),
+                    new_value: current,
+                    transform_type: "revert_conflict".to_string(),
+                    reason: format!(
+                        "Original of {} change was withheld from the audit; left as is",
+                        entry.transform_type
+                    ),
+                    original_withheld: false,
+                });
+                continue;
+            }
+
+            let restored = current == entry.new_value;
+            by_column.entry(column.clone()).or_default().push(RowAudit {
+                row: entry.row,
+                column: column.clone(),
+                original_value: current.clone(),
+                new_value: if restored {
+                    entry.original_value.clone()
+                } else {
+                    current
+                },
+                transform_type: if restored { "revert" } else { "revert_conflict" }.to_string(),
+                reason: if restored {
+                    format!("Restored original of {} change", entry.transform_type)
+                } else {
+                    format!("Value changed since {} was applied; left as is", entry.transform_type)
+                },
+                original_withheld: false,
+            });
+            if restored {
+                data.set(entry.row, col_idx, entry.original_value.clone());
+            }
+        }
+
+        for (column, row_audits) in by_column {
+            let values_changed = row_audits
+                .iter()
+                .filter(|a| a.transform_type == "revert")
+                .count();
+            result.add_change(TransformChange {
+                values_changed,
+                row_audits,
+                ..TransformChange::new(
+                    format!("Restored {} value(s) in '{}'", values_changed, column),
+                    column,
+                )
+            });
+        }
+
+        for column in audit.columns_added.iter().rev() {
+            if let Some(idx) = data.headers.iter().position(|h| h == column) {
+                data.remove_column(idx);
+                result.add_change(TransformChange::new(
+                    format!("Removed added column '{}'", column),
+                    column,
+                ));
             }
         }
 
+        result.columns = data.headers.clone();
         Ok(result)
     }
 
@@ -70,16 +258,36 @@ impl TransformEngine {
         &self,
         suggestion: &crate::suggestion::Suggestion,
         observation: &crate::validation::Observation,
-        _curation: &CurationLayer,
+        curation: &CurationLayer,
         data: &DataTable,
     ) -> Result<Option<TransformOperation>> {
         match suggestion.action {
             SuggestionAction::Standardize => {
-                self.create_standardize_operation(suggestion, observation, data)
+                let operation = self.create_standardize_operation(suggestion, observation, data)?;
+                // Lists are standardized item by item
+                Ok(operation.map(|operation| match operation {
+                    TransformOperation::Standardize { column, mapping } => {
+                        let delimiter = curation
+                            .schema
+                            .get_column(&column)
+                            .and_then(|c| c.inferred_type.item_delimiter());
+                        match delimiter {
+                            Some(delimiter) => TransformOperation::NormalizeList {
+                                column,
+                                delimiter,
+                                mapping,
+                            },
+                            None => TransformOperation::Standardize { column, mapping },
+                        }
+                    }
+                    other => other,
+                }))
             }
             SuggestionAction::Flag => self.create_flag_operation(suggestion, observation),
             SuggestionAction::ConvertNa => self.create_convert_na_operation(suggestion, observation),
             SuggestionAction::Coerce => self.create_coerce_operation(suggestion, observation, data),
+            SuggestionAction::Clamp => self.create_clamp_operation(suggestion, observation),
+            SuggestionAction::ConvertUnit => self.create_convert_unit_operation(suggestion),
             SuggestionAction::ConvertDate => {
                 self.create_convert_date_operation(suggestion, observation)
             }
@@ -89,16 +297,51 @@ impl TransformEngine {
             SuggestionAction::Merge => Ok(Some(TransformOperation::NoOp {
                 reason: "Merge operations require manual review".to_string(),
             })),
-            SuggestionAction::Rename => Ok(Some(TransformOperation::NoOp {
-                reason: "Rename operations not yet implemented".to_string(),
-            })),
-            SuggestionAction::Split => Ok(Some(TransformOperation::NoOp {
-                reason: "Split operations not yet implemented".to_string(),
-            })),
+            SuggestionAction::Rename => self.create_rename_operation(suggestion, observation),
+            SuggestionAction::Split => self.create_split_operation(suggestion, observation),
             SuggestionAction::Derive => Ok(Some(TransformOperation::NoOp {
                 reason: "Derive operations not yet implemented".to_string(),
             })),
+            SuggestionAction::Redact => self.create_redact_operation(suggestion, observation),
+        }
+    }
+
+    /// Create a redaction operation from a suggestion and observation.
+    fn create_redact_operation(
+        &self,
+        suggestion: &crate::suggestion::Suggestion,
+        observation: &crate::validation::Observation,
+    ) -> Result<Option<TransformOperation>> {
+        let column = suggestion
+            .parameters
+            .get("column")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| observation.column.clone());
+
+        // The key for keyed pseudonyms isn't stored with the curation, so it
+        // is supplied when applying
+        if suggestion.parameters.get("method").and_then(|v| v.as_str()) == Some("pseudonymize") {
+            let prefix = suggestion
+                .parameters
+                .get("prefix")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string());
+            return Ok(Some(TransformOperation::Pseudonymize {
+                column,
+                method: self.pseudonym_method()?,
+                prefix,
+            }));
         }
+
+        let method = match suggestion.parameters.get("method") {
+            Some(method) => serde_json::from_value(method.clone()).map_err(|e| {
+                CrucibleError::Validation(format!("Invalid redaction method: {}", e))
+            })?,
+            None => RedactionMethod::default(),
+        };
+
+        Ok(Some(TransformOperation::Redact { column, method }))
     }
 
     /// Create a convert date operation from a suggestion and observation.
@@ -121,6 +364,18 @@ impl TransformEngine {
             .map(|s| s.to_string())
             .unwrap_or_else(|| "ISO (YYYY-MM-DD)".to_string());
 
+        // A known day/month order normalizes every numeric date format
+        let assume_order = suggestion
+            .parameters
+            .get("assume_order")
+            .and_then(|v| serde_json::from_value::<DateOrder>(v.clone()).ok());
+        if let Some(assume_order) = assume_order {
+            return Ok(Some(TransformOperation::NormalizeDate {
+                column,
+                assume_order,
+            }));
+        }
+
         Ok(Some(TransformOperation::ConvertDate {
             column,
             target_format,
@@ -142,6 +397,57 @@ impl TransformEngine {
             .map(|s| s.to_string())
             .unwrap_or_else(|| observation.column.clone());
 
+        // Ontology mappings carry their precomputed term table
+        if let Some(ontology) = suggestion.parameters.get("ontology") {
+            return self.create_ontology_operation(suggestion, column, ontology);
+        }
+
+        // Mixed boolean spellings are normalized to a canonical pair
+        let token = |key: &str| suggestion.parameters.get(key).and_then(|v| v.as_str());
+        if let (Some(true_token), Some(false_token)) = (token("true_token"), token("false_token")) {
+            return Ok(Some(TransformOperation::NormalizeBoolean {
+                column,
+                true_token: true_token.to_string(),
+                false_token: false_token.to_string(),
+            }));
+        }
+
+        // Grouped numbers are rewritten without their thousands separators
+        if let Some(decimal_mark) = suggestion.parameters.get("decimal_mark") {
+            let decimal_mark = serde_json::from_value(decimal_mark.clone()).map_err(|e| {
+                CrucibleError::Validation(format!("Invalid decimal mark: {}", e))
+            })?;
+            return Ok(Some(TransformOperation::StripThousandsSeparators {
+                column,
+                decimal_mark,
+            }));
+        }
+
+        // Stray whitespace is trimmed, and optionally collapsed, column-wide
+        if let Some(collapse_internal) =
+            suggestion.parameters.get("collapse_internal").and_then(|v| v.as_bool())
+        {
+            return Ok(Some(TransformOperation::TrimWhitespace {
+                column,
+                collapse_internal,
+            }));
+        }
+
+        // Inconsistent precision is fixed by rounding to the column's usual places,
+        // capped so a bad parameter can't ask for millions of padded zeros
+        if let Some(decimals) = suggestion.parameters.get("decimals").and_then(|v| v.as_u64()) {
+            let fixed = suggestion
+                .parameters
+                .get("fixed")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            return Ok(Some(TransformOperation::RoundNumeric {
+                column,
+                decimals: decimals.min(MAX_ROUND_DECIMALS as u64) as usize,
+                fixed,
+            }));
+        }
+
         // Try to get mapping from suggestion parameters first
         let mut mapping = HashMap::new();
         if let Some(param_mapping) = suggestion.parameters.get("mapping") {
@@ -171,6 +477,41 @@ impl TransformEngine {
         Ok(Some(TransformOperation::Standardize { column, mapping }))
     }
 
+    /// Create an ontology mapping operation from suggestion parameters.
+    ///
+    /// Expects `ontology`, a `terms` table of value to `{id, label}`, and an
+    /// optional `keep_label` flag.
+    fn create_ontology_operation(
+        &self,
+        suggestion: &crate::suggestion::Suggestion,
+        column: String,
+        ontology: &serde_json::Value,
+    ) -> Result<Option<TransformOperation>> {
+        let ontology: OntologyType = serde_json::from_value(ontology.clone())?;
+        let keep_label = suggestion
+            .parameters
+            .get("keep_label")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let mapping: HashMap<String, MappedTerm> = match suggestion.parameters.get("terms") {
+            Some(terms) => serde_json::from_value(terms.clone())?,
+            None => HashMap::new(),
+        };
+
+        if mapping.is_empty() {
+            return Ok(Some(TransformOperation::NoOp {
+                reason: format!("No {} terms mapped for '{}'", ontology.prefix(), column),
+            }));
+        }
+
+        Ok(Some(TransformOperation::MapToOntology {
+            column,
+            ontology,
+            keep_label,
+            mapping,
+        }))
+    }
+
     /// Extract a value mapping from inconsistency observation evidence.
     fn extract_standardize_mapping(
         &self,
@@ -215,7 +556,7 @@ impl TransformEngine {
 
             if desc.contains("Case variants") || desc.contains("case variant") {
                 // Find column index
-                if let Some(col_idx) = data.column_index(&observation.column) {
+                if let Some(col_idx) = target_column(data, &observation.column) {
                     mapping = self.infer_case_variant_mapping(data, col_idx);
                 }
             } else if desc.contains("typo") {
@@ -453,7 +794,54 @@ impl TransformEngine {
             .unwrap_or_else(|| "string".to_string());
 
         // Get rows with type issues from suggestion parameters or observation evidence
-        let rows: Vec<usize> = suggestion
+        let rows = Self::suggested_rows(suggestion, observation);
+
+        if rows.is_empty() {
+            return Ok(Some(TransformOperation::NoOp {
+                reason: "No values to coerce".to_string(),
+            }));
+        }
+
+        Ok(Some(TransformOperation::Coerce {
+            column,
+            target_type,
+            rows,
+        }))
+    }
+
+    /// Create a clamp operation from a suggestion and observation.
+    fn create_clamp_operation(
+        &self,
+        suggestion: &crate::suggestion::Suggestion,
+        observation: &crate::validation::Observation,
+    ) -> Result<Option<TransformOperation>> {
+        let column = suggestion
+            .parameters
+            .get("column")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| observation.column.clone());
+
+        let min = suggestion.parameters.get("min").and_then(|v| v.as_f64());
+        let max = suggestion.parameters.get("max").and_then(|v| v.as_f64());
+
+        if min.is_none() && max.is_none() {
+            return Ok(Some(TransformOperation::NoOp {
+                reason: "No bounds to clamp to".to_string(),
+            }));
+        }
+
+        let rows = Self::suggested_rows(suggestion, observation);
+        Ok(Some(TransformOperation::Clamp { column, min, max, rows }))
+    }
+
+    /// Rows listed in a suggestion's `rows` parameter, or else every row
+    /// the observation affects (its sample rows if it lists no others).
+    fn suggested_rows(
+        suggestion: &crate::suggestion::Suggestion,
+        observation: &crate::validation::Observation,
+    ) -> Vec<usize> {
+        suggestion
             .parameters
             .get("rows")
             .and_then(|v| v.as_array())
@@ -462,18 +850,105 @@ impl TransformEngine {
                     .filter_map(|v| v.as_u64().map(|n| n as usize))
                     .collect()
             })
-            .unwrap_or_else(|| observation.evidence.sample_rows.clone());
+            .unwrap_or_else(|| {
+                if observation.evidence.rows.is_empty() {
+                    observation.evidence.sample_rows.clone()
+                } else {
+                    observation.evidence.rows.clone()
+                }
+            })
+    }
 
-        if rows.is_empty() {
+    /// Create a unit conversion operation from a suggestion.
+    fn create_convert_unit_operation(
+        &self,
+        suggestion: &crate::suggestion::Suggestion,
+    ) -> Result<Option<TransformOperation>> {
+        let params: ConvertUnitParams = serde_json::from_value(suggestion.parameters.clone())
+            .map_err(|e| {
+                CrucibleError::Validation(format!("Invalid unit conversion parameters: {}", e))
+            })?;
+
+        Ok(Some(TransformOperation::ConvertUnit {
+            column: params.column,
+            from_unit: params.from_unit,
+            to_unit: params.to_unit,
+            factor: params.factor,
+        }))
+    }
+
+    /// Create a rename operation from a suggestion and observation.
+    fn create_rename_operation(
+        &self,
+        suggestion: &crate::suggestion::Suggestion,
+        observation: &crate::validation::Observation,
+    ) -> Result<Option<TransformOperation>> {
+        let from = suggestion
+            .parameters
+            .get("column")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| observation.column.clone());
+
+        let Some(to) = suggestion
+            .parameters
+            .get("to")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+        else {
             return Ok(Some(TransformOperation::NoOp {
-                reason: "No values to coerce".to_string(),
+                reason: format!("No new name given for '{}'", from),
+            }));
+        };
+
+        Ok(Some(TransformOperation::RenameColumn {
+            from,
+            to: to.to_string(),
+        }))
+    }
+
+    /// Create a split-column operation from a suggestion and observation.
+    fn create_split_operation(
+        &self,
+        suggestion: &crate::suggestion::Suggestion,
+        observation: &crate::validation::Observation,
+    ) -> Result<Option<TransformOperation>> {
+        let source = suggestion
+            .parameters
+            .get("column")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| observation.column.clone());
+
+        let delimiter = suggestion
+            .parameters
+            .get("delimiter")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .unwrap_or_default();
+
+        let into: Vec<String> = suggestion
+            .parameters
+            .get("into")
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if into.len() < 2 {
+            return Ok(Some(TransformOperation::NoOp {
+                reason: "Split needs at least two target columns".to_string(),
             }));
         }
 
-        Ok(Some(TransformOperation::Coerce {
-            column,
-            target_type,
-            rows,
+        Ok(Some(TransformOperation::SplitColumn {
+            source,
+            delimiter,
+            into,
         }))
     }
 
@@ -501,16 +976,74 @@ impl TransformEngine {
                 target_type,
                 rows,
             } => self.apply_coerce(column, target_type, rows, data),
+            TransformOperation::Clamp {
+                column,
+                min,
+                max,
+                rows,
+            } => self.apply_clamp(column, *min, *max, rows, data),
+            TransformOperation::ConvertUnit {
+                column,
+                from_unit,
+                to_unit,
+                factor,
+            } => self.apply_convert_unit(column, from_unit, to_unit, *factor, data),
+            TransformOperation::SplitColumn {
+                source,
+                delimiter,
+                into,
+            } => self.apply_split_column(source, delimiter, into, data),
+            TransformOperation::RenameColumn { from, to } => {
+                self.apply_rename_column(from, to, data)
+            }
+            TransformOperation::NormalizeBoolean {
+                column,
+                true_token,
+                false_token,
+            } => self.apply_normalize_boolean(column, true_token, false_token, data),
+            TransformOperation::MapToOntology {
+                column,
+                ontology,
+                keep_label,
+                mapping,
+            } => self.apply_map_to_ontology(column, *ontology, *keep_label, mapping, data),
             TransformOperation::ConvertDate {
                 column,
                 target_format,
             } => self.apply_convert_date(column, target_format, data),
-            TransformOperation::NoOp { reason } => Ok(TransformChange {
-                description: format!("Skipped: {}", reason),
-                column: String::new(),
-                values_changed: 0,
-                row_audits: Vec::new(),
-            }),
+            TransformOperation::NormalizeDate {
+                column,
+                assume_order,
+            } => self.apply_normalize_date(column, *assume_order, data),
+            TransformOperation::Redact { column, method } => {
+                self.apply_redact(column, *method, data)
+            }
+            TransformOperation::Pseudonymize {
+                column,
+                method,
+                prefix,
+            } => self.apply_pseudonymize(column, method, prefix.as_deref(), data),
+            TransformOperation::TrimWhitespace {
+                column,
+                collapse_internal,
+            } => self.apply_trim_whitespace(column, *collapse_internal, data),
+            TransformOperation::RoundNumeric {
+                column,
+                decimals,
+                fixed,
+            } => self.apply_round_numeric(column, *decimals, *fixed, data),
+            TransformOperation::NormalizeList {
+                column,
+                delimiter,
+                mapping,
+            } => self.apply_normalize_list(column, *delimiter, mapping, data),
+            TransformOperation::StripThousandsSeparators {
+                column,
+                decimal_mark,
+            } => self.apply_strip_thousands(column, *decimal_mark, data),
+            TransformOperation::NoOp { reason } => {
+                Ok(TransformChange::new(format!("Skipped: {}", reason), ""))
+            }
         }
     }
 
@@ -521,7 +1054,7 @@ impl TransformEngine {
         mapping: &HashMap<String, String>,
         data: &mut DataTable,
     ) -> Result<TransformChange> {
-        let col_idx = data.column_index(column).ok_or_else(|| {
+        let col_idx = target_column(data, column).ok_or_else(|| {
             CrucibleError::Validation(format!("Column '{}' not found", column))
         })?;
 
@@ -538,6 +1071,7 @@ impl TransformEngine {
                     new_value: new_value.clone(),
                     transform_type: "standardize".to_string(),
                     reason: format!("Normalized '{}' to '{}'", value, new_value),
+                    original_withheld: false,
                 });
                 data.set(row_idx, col_idx, new_value.clone());
                 changed += 1;
@@ -551,10 +1085,12 @@ impl TransformEngine {
             .collect();
 
         Ok(TransformChange {
-            description: format!("Standardized '{}': {}", column, examples.join(", ")),
-            column: column.to_string(),
             values_changed: changed,
             row_audits,
+            ..TransformChange::new(
+                format!("Standardized '{}': {}", column, examples.join(", ")),
+                column,
+            )
         })
     }
 
@@ -568,11 +1104,13 @@ impl TransformEngine {
         data: &mut DataTable,
     ) -> Result<TransformChange> {
         // Add the flag column if it doesn't exist
-        if data.column_index(flag_column).is_none() {
+        let mut columns_added = Vec::new();
+        if target_column(data, flag_column).is_none() {
             data.add_column(flag_column.to_string(), String::new());
+            columns_added.push(flag_column.to_string());
         }
 
-        let flag_col_idx = data.column_index(flag_column).unwrap();
+        let flag_col_idx = target_column(data, flag_column).unwrap();
         let mut row_audits = Vec::new();
 
         // Set flag values for specified rows
@@ -585,21 +1123,25 @@ impl TransformEngine {
                     new_value: flag_value.to_string(),
                     transform_type: "flag".to_string(),
                     reason: format!("Flagged for review: issue in '{}'", source_column),
+                    original_withheld: false,
                 });
                 data.set(row_idx, flag_col_idx, flag_value.to_string());
             }
         }
 
         Ok(TransformChange {
-            description: format!(
-                "Flagged {} rows in '{}' → '{}'",
-                rows.len(),
-                source_column,
-                flag_column
-            ),
-            column: flag_column.to_string(),
             values_changed: rows.len(),
             row_audits,
+            columns_added,
+            ..TransformChange::new(
+                format!(
+                    "Flagged {} rows in '{}' → '{}'",
+                    rows.len(),
+                    source_column,
+                    flag_column
+                ),
+                flag_column,
+            )
         })
     }
 
@@ -610,7 +1152,7 @@ impl TransformEngine {
         values: &[String],
         data: &mut DataTable,
     ) -> Result<TransformChange> {
-        let col_idx = data.column_index(column).ok_or_else(|| {
+        let col_idx = target_column(data, column).ok_or_else(|| {
             CrucibleError::Validation(format!("Column '{}' not found", column))
         })?;
 
@@ -628,6 +1170,7 @@ impl TransformEngine {
                     new_value: String::new(),
                     transform_type: "convert_na".to_string(),
                     reason: format!("Converted '{}' to NA (missing value pattern)", value),
+                    original_withheld: false,
                 });
                 data.set(row_idx, col_idx, String::new());
                 changed += 1;
@@ -635,132 +1178,877 @@ impl TransformEngine {
         }
 
         Ok(TransformChange {
-            description: format!("Converted {:?} to NA in '{}'", values, column),
-            column: column.to_string(),
             values_changed: changed,
             row_audits,
+            ..TransformChange::new(
+                format!("Converted {:?} to NA in '{}'", values, column),
+                column,
+            )
         })
     }
 
-    /// Apply a type coercion transformation.
-    fn apply_coerce(
+    /// Apply a clamp transformation to the out-of-range numeric values in
+    /// the given rows, or in every row when none are given.
+    fn apply_clamp(
         &self,
         column: &str,
-        target_type: &str,
+        min: Option<f64>,
+        max: Option<f64>,
         rows: &[usize],
         data: &mut DataTable,
     ) -> Result<TransformChange> {
-        let col_idx = data.column_index(column).ok_or_else(|| {
+        let col_idx = target_column(data, column).ok_or_else(|| {
             CrucibleError::Validation(format!("Column '{}' not found", column))
         })?;
 
         let mut changed = 0;
         let mut row_audits = Vec::new();
 
-        for &row_idx in rows {
-            if row_idx >= data.row_count() {
+        let row_count = data.row_count();
+        let all_rows: Vec<usize>;
+        let rows = if rows.is_empty() {
+            all_rows = (0..row_count).collect();
+            &all_rows
+        } else {
+            rows
+        };
+        for &row_idx in rows.iter().filter(|&&row| row < row_count) {
+            let value = data.get(row_idx, col_idx).unwrap_or_default().to_string();
+            let Ok(num) = value.trim().parse::<f64>() else {
                 continue;
-            }
+            };
 
-            let value = data.get(row_idx, col_idx).unwrap_or_default().to_string();
-            let trimmed = value.trim();
+            let bound = match (min, max) {
+                (Some(m), _) if num < m => m,
+                (_, Some(m)) if num > m => m,
+                _ => continue,
+            };
 
-            // Skip empty/null values
-            if trimmed.is_empty() || DataTable::is_null_value(trimmed) {
-                continue;
-            }
+            let new_value = bound.to_string();
+            row_audits.push(RowAudit {
+                row: row_idx,
+                column: column.to_string(),
+                original_value: value.clone(),
+                new_value: new_value.clone(),
+                transform_type: "clamp".to_string(),
+                reason: format!("Clamped out-of-range value '{}' to {}", value, new_value),
+                original_withheld: false,
+            });
+            data.set(row_idx, col_idx, new_value);
+            changed += 1;
+        }
 
-            // Try to coerce based on target type
-            let coerced = match target_type {
-                "integer" | "Integer" => {
-                    if trimmed.parse::<i64>().is_ok() {
-                        Some(trimmed.to_string())
-                    } else if let Some(f) = trimmed.parse::<f64>().ok() {
-                        // Try to convert float to int if it's a whole number
-                        if f.fract() == 0.0 {
-                            Some(format!("{}", f as i64))
-                        } else {
-                            None // Can't cleanly convert
-                        }
-                    } else {
-                        None // Convert to NA
-                    }
-                }
-                "float" | "Float" => {
-                    if trimmed.parse::<f64>().is_ok() {
-                        Some(trimmed.to_string())
-                    } else {
+        Ok(TransformChange {
+            values_changed: changed,
+            row_audits,
+            ..TransformChange::new(
+                format!("Clamped {} values in '{}'", changed, column),
+                column,
+            )
+        })
+    }
+
+    /// Apply a unit conversion, rounding results to two decimal places.
+    fn apply_convert_unit(
+        &self,
+        column: &str,
+        from_unit: &str,
+        to_unit: &str,
+        factor: f64,
+        data: &mut DataTable,
+    ) -> Result<TransformChange> {
+        let col_idx = target_column(data, column).ok_or_else(|| {
+            CrucibleError::Validation(format!("Column '{}' not found", column))
+        })?;
+
+        let mut changed = 0;
+        let mut row_audits = Vec::new();
+
+        for row_idx in 0..data.row_count() {
+            let value = data.get(row_idx, col_idx).unwrap_or_default().to_string();
+            let Ok(num) = value.trim().parse::<f64>() else {
+                continue;
+            };
+
+            let new_value = ((num * factor * 100.0).round() / 100.0).to_string();
+            row_audits.push(RowAudit {
+                row: row_idx,
+                column: column.to_string(),
+                original_value: value.clone(),
+                new_value: new_value.clone(),
+                transform_type: "convert_unit".to_string(),
+                reason: format!("Converted {} {} to {} {}", value, from_unit, new_value, to_unit),
+                original_withheld: false,
+            });
+            data.set(row_idx, col_idx, new_value);
+            changed += 1;
+        }
+
+        Ok(TransformChange {
+            values_changed: changed,
+            row_audits,
+            ..TransformChange::new(
+                format!("Converted '{}' from {} to {}", column, from_unit, to_unit),
+                column,
+            )
+        })
+    }
+
+    /// Apply a split-column transformation.
+    ///
+    /// The source column is left untouched. Rows that don't split into
+    /// exactly one value per target column get empty targets and an audit
+    /// entry preserving the original value.
+    fn apply_split_column(
+        &self,
+        source: &str,
+        delimiter: &str,
+        into: &[String],
+        data: &mut DataTable,
+    ) -> Result<TransformChange> {
+        let src_idx = target_column(data, source).ok_or_else(|| {
+            CrucibleError::Validation(format!("Column '{}' not found", source))
+        })?;
+        if let Some(existing) = into.iter().find(|c| target_column(data, c).is_some()) {
+            return Err(CrucibleError::Validation(format!(
+                "Cannot split '{}': column '{}' already exists",
+                source, existing
+            )));
+        }
+
+        let first_new = data.column_count();
+        for column in into {
+            data.add_column(column.clone(), String::new());
+        }
+
+        let mut changed = 0;
+        let mut row_audits = Vec::new();
+
+        for row_idx in 0..data.row_count() {
+            let value = data.get(row_idx, src_idx).unwrap_or_default().to_string();
+            if DataTable::is_null_value(&value) {
+                continue;
+            }
+
+            let parts: Vec<&str> = if delimiter.trim().is_empty() {
+                value.split_whitespace().collect()
+            } else {
+                value.split(delimiter).map(str::trim).collect()
+            };
+
+            if parts.len() != into.len() {
+                row_audits.push(RowAudit {
+                    row: row_idx,
+                    column: source.to_string(),
+                    original_value: value.clone(),
+                    new_value: value.clone(),
+                    transform_type: "split_column".to_string(),
+                    reason: format!(
+                        "Could not split '{}' into {} parts (found {})",
+                        value,
+                        into.len(),
+                        parts.len()
+                    ),
+                    original_withheld: false,
+                });
+                continue;
+            }
+
+            for (offset, (column, part)) in into.iter().zip(&parts).enumerate() {
+                row_audits.push(RowAudit {
+                    row: row_idx,
+                    column: column.clone(),
+                    original_value: value.clone(),
+                    new_value: part.to_string(),
+                    transform_type: "split_column".to_string(),
+                    reason: format!("Split from '{}'", source),
+                    original_withheld: false,
+                });
+                data.set(row_idx, first_new + offset, part.to_string());
+            }
+            changed += 1;
+        }
+
+        Ok(TransformChange {
+            values_changed: changed,
+            row_audits,
+            columns_added: into.to_vec(),
+            ..TransformChange::new(
+                format!("Split '{}' into {}", source, into.join(", ")),
+                source,
+            )
+        })
+    }
+
+    /// Apply a column rename.
+    fn apply_rename_column(&self, from: &str, to: &str, data: &mut DataTable) -> Result<TransformChange> {
+        let mut idx = target_column(data, from).ok_or_else(|| {
+            CrucibleError::Validation(format!("Column '{}' not found", from))
+        })?;
+        let existing = data.headers.iter().position(|h| h == to).filter(|&i| i != idx);
+        if existing.is_some() && !self.force_renames {
+            return Err(CrucibleError::Validation(format!(
+                "Cannot rename '{}' to '{}': column '{}' already exists (force the rename to replace it)",
+                from, to, to
+            )));
+        }
+
+        let from = data.headers[idx].clone();
+        if let Some(existing) = existing {
+            data.remove_column(existing);
+            if existing < idx {
+                idx -= 1;
+            }
+        }
+        data.headers[idx] = to.to_string();
+
+        let description = if existing.is_some() {
+            format!("Rename '{}' to '{}', replacing the existing '{}'", from, to, to)
+        } else {
+            format!("Rename '{}' to '{}'", from, to)
+        };
+        Ok(TransformChange {
+            columns_renamed: vec![ColumnRename {
+                from,
+                to: to.to_string(),
+                replaced: existing.is_some(),
+            }],
+            ..TransformChange::new(description, to)
+        })
+    }
+
+    /// Apply a type coercion transformation.
+    ///
+    /// When coercing to float rows that include percentages (`45%`), plain
+    /// numbers among them are read as percentages too, so the whole column
+    /// ends up on one scale (`40` becomes `0.4`).
+    fn apply_coerce(
+        &self,
+        column: &str,
+        target_type: &str,
+        rows: &[usize],
+        data: &mut DataTable,
+    ) -> Result<TransformChange> {
+        let col_idx = target_column(data, column).ok_or_else(|| {
+            CrucibleError::Validation(format!("Column '{}' not found", column))
+        })?;
+
+        let mut changed = 0;
+        let mut row_audits = Vec::new();
+        let percentages = rows
+            .iter()
+            .any(|&row| data.get(row, col_idx).is_some_and(|v| v.trim().ends_with('%')));
+
+        for &row_idx in rows {
+            if row_idx >= data.row_count() {
+                continue;
+            }
+
+            let value = data.get(row_idx, col_idx).unwrap_or_default().to_string();
+            let trimmed = value.trim();
+
+            // Skip empty/null values
+            if trimmed.is_empty() || DataTable::is_null_value(trimmed) {
+                continue;
+            }
+
+            // Try to coerce based on target type
+            let coerced = match target_type {
+                "integer" | "Integer" => {
+                    if trimmed.parse::<i64>().is_ok() {
+                        Some(trimmed.to_string())
+                    } else if let Some(f) = trimmed.parse::<f64>().ok() {
+                        // Try to convert float to int if it's a whole number
+                        if f.fract() == 0.0 {
+                            Some(format!("{}", f as i64))
+                        } else {
+                            None // Can't cleanly convert
+                        }
+                    } else {
                         None // Convert to NA
                     }
                 }
-                "boolean" | "Boolean" => {
-                    let lower = trimmed.to_lowercase();
-                    if matches!(
-                        lower.as_str(),
-                        "true" | "false" | "yes" | "no" | "1" | "0" | "t" | "f" | "y" | "n"
-                    ) {
-                        // Standardize to true/false
-                        let is_true = matches!(lower.as_str(), "true" | "yes" | "1" | "t" | "y");
-                        Some(if is_true { "true" } else { "false" }.to_string())
+                "float" | "Float" => {
+                    if percentages && trimmed.parse::<f64>().is_ok() {
+                        parse_percentage(&format!("{}%", trimmed))
+                    } else if trimmed.parse::<f64>().is_ok() {
+                        Some(trimmed.to_string())
                     } else {
-                        None
+                        // Clean formatted amounts; anything else becomes NA
+                        parse_currency(trimmed).or_else(|| parse_percentage(trimmed))
                     }
                 }
+                "boolean" | "Boolean" => {
+                    // Standardize to true/false
+                    parse_boolean(trimmed).map(|b| b.to_string())
+                }
                 _ => Some(trimmed.to_string()), // String type - keep as is
             };
 
-            match coerced {
-                Some(ref new_value) if new_value != trimmed => {
-                    row_audits.push(RowAudit {
-                        row: row_idx,
-                        column: column.to_string(),
-                        original_value: trimmed.to_string(),
-                        new_value: new_value.clone(),
-                        transform_type: "coerce".to_string(),
-                        reason: format!("Coerced '{}' to {} type", trimmed, target_type),
-                    });
-                    data.set(row_idx, col_idx, new_value.clone());
-                    changed += 1;
+            match coerced {
+                Some(ref new_value) if new_value != trimmed => {
+                    row_audits.push(RowAudit {
+                        row: row_idx,
+                        column: column.to_string(),
+                        original_value: trimmed.to_string(),
+                        new_value: new_value.clone(),
+                        transform_type: "coerce".to_string(),
+                        reason: format!("Coerced '{}' to {} type", trimmed, target_type),
+                        original_withheld: false,
+                    });
+                    data.set(row_idx, col_idx, new_value.clone());
+                    changed += 1;
+                }
+                None => {
+                    row_audits.push(RowAudit {
+                        row: row_idx,
+                        column: column.to_string(),
+                        original_value: trimmed.to_string(),
+                        new_value: String::new(),
+                        transform_type: "coerce".to_string(),
+                        reason: format!(
+                            "Converted '{}' to NA (could not coerce to {})",
+                            trimmed, target_type
+                        ),
+                        original_withheld: false,
+                    });
+                    // Convert non-coercible values to NA
+                    data.set(row_idx, col_idx, String::new());
+                    changed += 1;
+                }
+                _ => {} // Value unchanged
+            }
+        }
+
+        Ok(TransformChange {
+            values_changed: changed,
+            row_audits,
+            ..TransformChange::new(
+                format!(
+                    "Coerced {} value(s) in '{}' to {}",
+                    changed, column, target_type
+                ),
+                column,
+            )
+        })
+    }
+
+    /// Apply a rounding to a number of decimal places.
+    ///
+    /// Null values are skipped; other non-numeric values are kept and
+    /// logged in the audit.
+    fn apply_round_numeric(
+        &self,
+        column: &str,
+        decimals: usize,
+        fixed: bool,
+        data: &mut DataTable,
+    ) -> Result<TransformChange> {
+        let col_idx = target_column(data, column).ok_or_else(|| {
+            CrucibleError::Validation(format!("Column '{}' not found", column))
+        })?;
+
+        let mut counts = RoundingCounts::default();
+        let mut row_audits = Vec::new();
+
+        for row_idx in 0..data.row_count() {
+            let value = data.get(row_idx, col_idx).unwrap_or_default().to_string();
+            if value.trim().is_empty() || DataTable::is_null_value(value.trim()) {
+                continue;
+            }
+
+            let Some(new_value) = round_decimal(&value, decimals, fixed) else {
+                counts.non_numeric += 1;
+                row_audits.push(RowAudit {
+                    row: row_idx,
+                    column: column.to_string(),
+                    original_value: value.clone(),
+                    new_value: value.clone(),
+                    transform_type: "round_numeric".to_string(),
+                    reason: format!("Kept '{}' (not a number)", value),
+                    original_withheld: false,
+                });
+                continue;
+            };
+            if new_value == value {
+                counts.unchanged += 1;
+                continue;
+            }
+
+            row_audits.push(RowAudit {
+                row: row_idx,
+                column: column.to_string(),
+                original_value: value.clone(),
+                new_value: new_value.clone(),
+                transform_type: "round_numeric".to_string(),
+                reason: format!("Rounded '{}' to {} decimal place(s)", value, decimals),
+                original_withheld: false,
+            });
+            data.set(row_idx, col_idx, new_value);
+            counts.rounded += 1;
+        }
+
+        Ok(TransformChange {
+            values_changed: counts.rounded,
+            row_audits,
+            rounding_counts: Some(counts),
+            ..TransformChange::new(
+                format!(
+                    "Rounded {} value(s) in '{}' to {} decimal place(s) ({} already at that precision)",
+                    counts.rounded, column, decimals, counts.unchanged
+                ),
+                column,
+            )
+        })
+    }
+
+    /// Apply a boolean normalization.
+    ///
+    /// Recognized spellings become `true_token`/`false_token`; null and
+    /// unrecognized values are kept, with unrecognized ones logged in the audit.
+    fn apply_normalize_boolean(
+        &self,
+        column: &str,
+        true_token: &str,
+        false_token: &str,
+        data: &mut DataTable,
+    ) -> Result<TransformChange> {
+        let col_idx = target_column(data, column).ok_or_else(|| {
+            CrucibleError::Validation(format!("Column '{}' not found", column))
+        })?;
+
+        let mut changed = 0;
+        let mut row_audits = Vec::new();
+
+        for row_idx in 0..data.row_count() {
+            let value = data.get(row_idx, col_idx).unwrap_or_default().to_string();
+            if value.trim().is_empty() || DataTable::is_null_value(value.trim()) {
+                continue;
+            }
+
+            match parse_boolean(&value) {
+                Some(b) => {
+                    let new_value = if b { true_token } else { false_token };
+                    if value != new_value {
+                        row_audits.push(RowAudit {
+                            row: row_idx,
+                            column: column.to_string(),
+                            original_value: value.clone(),
+                            new_value: new_value.to_string(),
+                            transform_type: "normalize_boolean".to_string(),
+                            reason: format!("Normalized '{}' to '{}'", value, new_value),
+                            original_withheld: false,
+                        });
+                        data.set(row_idx, col_idx, new_value.to_string());
+                        changed += 1;
+                    }
+                }
+                None => {
+                    row_audits.push(RowAudit {
+                        row: row_idx,
+                        column: column.to_string(),
+                        original_value: value.clone(),
+                        new_value: value.clone(),
+                        transform_type: "normalize_boolean".to_string(),
+                        reason: format!("Kept '{}' (not a recognized boolean value)", value),
+                        original_withheld: false,
+                    });
+                }
+            }
+        }
+
+        Ok(TransformChange {
+            values_changed: changed,
+            row_audits,
+            ..TransformChange::new(
+                format!(
+                    "Normalized {} boolean value(s) in '{}' to '{}'/'{}'",
+                    changed, column, true_token, false_token
+                ),
+                column,
+            )
+        })
+    }
+
+    /// Apply an ontology mapping.
+    ///
+    /// Null values and values that are already IDs of the ontology are
+    /// skipped. Free text without a mapping is kept and logged in the audit.
+    fn apply_map_to_ontology(
+        &self,
+        column: &str,
+        ontology: OntologyType,
+        keep_label: bool,
+        mapping: &HashMap<String, MappedTerm>,
+        data: &mut DataTable,
+    ) -> Result<TransformChange> {
+        let col_idx = target_column(data, column).ok_or_else(|| {
+            CrucibleError::Validation(format!("Column '{}' not found", column))
+        })?;
+
+        let mut counts = OntologyMappingCounts::default();
+        let mut row_audits = Vec::new();
+
+        for row_idx in 0..data.row_count() {
+            let value = data.get(row_idx, col_idx).unwrap_or_default().to_string();
+            let trimmed = value.trim();
+            if DataTable::is_null_value(trimmed) {
+                continue;
+            }
+            let id = trimmed.split('|').next().unwrap_or_default();
+            if OntologyType::from_id(id) == Some(ontology) {
+                continue;
+            }
+
+            match mapping.get(&value).or_else(|| mapping.get(trimmed)) {
+                Some(term) => {
+                    let new_value = term.value(keep_label);
+                    row_audits.push(RowAudit {
+                        row: row_idx,
+                        column: column.to_string(),
+                        original_value: value.clone(),
+                        new_value: new_value.clone(),
+                        transform_type: "map_to_ontology".to_string(),
+                        reason: format!("Mapped '{}' to {} ({})", value, term.id, term.label),
+                        original_withheld: false,
+                    });
+                    data.set(row_idx, col_idx, new_value);
+                    counts.mapped += 1;
+                }
+                None => {
+                    row_audits.push(RowAudit {
+                        row: row_idx,
+                        column: column.to_string(),
+                        original_value: value.clone(),
+                        new_value: value.clone(),
+                        transform_type: "map_to_ontology".to_string(),
+                        reason: format!("Kept '{}' (no {} term mapped)", value, ontology.prefix()),
+                        original_withheld: false,
+                    });
+                    counts.unmapped += 1;
+                }
+            }
+        }
+
+        Ok(TransformChange {
+            values_changed: counts.mapped,
+            row_audits,
+            ontology_counts: Some(counts),
+            ..TransformChange::new(
+                format!(
+                    "Mapped {} value(s) in '{}' to {} terms ({} unmapped)",
+                    counts.mapped,
+                    column,
+                    ontology.prefix(),
+                    counts.unmapped
+                ),
+                column,
+            )
+        })
+    }
+
+    /// Apply a date format conversion.
+    fn apply_convert_date(
+        &self,
+        column: &str,
+        _target_format: &str,
+        data: &mut DataTable,
+    ) -> Result<TransformChange> {
+        let col_idx = target_column(data, column).ok_or_else(|| {
+            CrucibleError::Validation(format!("Column '{}' not found", column))
+        })?;
+
+        let mut changed = 0;
+        let mut row_audits = Vec::new();
+
+        for row_idx in 0..data.row_count() {
+            let value = data.get(row_idx, col_idx).unwrap_or_default().to_string();
+            let trimmed = value.trim();
+
+            // Skip empty/null values
+            if trimmed.is_empty() || DataTable::is_null_value(trimmed) {
+                continue;
+            }
+
+            // Try to parse and convert to ISO format
+            if let Some(iso_date) = Self::parse_date_to_iso(trimmed) {
+                if iso_date != trimmed {
+                    row_audits.push(RowAudit {
+                        row: row_idx,
+                        column: column.to_string(),
+                        original_value: trimmed.to_string(),
+                        new_value: iso_date.clone(),
+                        transform_type: "convert_date".to_string(),
+                        reason: format!("Converted '{}' to ISO format '{}'", trimmed, iso_date),
+                        original_withheld: false,
+                    });
+                    data.set(row_idx, col_idx, iso_date);
+                    changed += 1;
+                }
+            }
+        }
+
+        Ok(TransformChange {
+            values_changed: changed,
+            row_audits,
+            ..TransformChange::new(
+                format!(
+                    "Standardized {} date(s) in '{}' to ISO format",
+                    changed, column
+                ),
+                column,
+            )
+        })
+    }
+
+    /// Apply a date normalization to ISO 8601.
+    ///
+    /// Null values are skipped. Unparseable values are kept and logged in
+    /// the audit, as are ambiguous dates resolved by `assume_order`.
+    fn apply_normalize_date(
+        &self,
+        column: &str,
+        assume_order: DateOrder,
+        data: &mut DataTable,
+    ) -> Result<TransformChange> {
+        let col_idx = target_column(data, column).ok_or_else(|| {
+            CrucibleError::Validation(format!("Column '{}' not found", column))
+        })?;
+
+        let mut counts = DateNormalizationCounts::default();
+        let mut row_audits = Vec::new();
+
+        for row_idx in 0..data.row_count() {
+            let value = data.get(row_idx, col_idx).unwrap_or_default().to_string();
+            if value.trim().is_empty() || DataTable::is_null_value(value.trim()) {
+                continue;
+            }
+
+            let Some(date) = dates::parse_date(&value, assume_order) else {
+                row_audits.push(RowAudit {
+                    row: row_idx,
+                    column: column.to_string(),
+                    original_value: value.clone(),
+                    new_value: value.clone(),
+                    transform_type: "normalize_date".to_string(),
+                    reason: format!("Kept '{}' (not a recognized date)", value),
+                    original_withheld: false,
+                });
+                counts.unparseable += 1;
+                continue;
+            };
+
+            if date.ambiguous {
+                counts.ambiguous += 1;
+            }
+            if date.iso == value {
+                continue;
+            }
+            let reason = if date.ambiguous {
+                format!(
+                    "Normalized '{}' to '{}' (ambiguous, read {})",
+                    value,
+                    date.iso,
+                    assume_order.label()
+                )
+            } else {
+                format!("Normalized '{}' to '{}'", value, date.iso)
+            };
+            row_audits.push(RowAudit {
+                row: row_idx,
+                column: column.to_string(),
+                original_value: value.clone(),
+                new_value: date.iso.clone(),
+                transform_type: "normalize_date".to_string(),
+                reason,
+                original_withheld: false,
+            });
+            data.set(row_idx, col_idx, date.iso);
+            counts.reformatted += 1;
+        }
+
+        Ok(TransformChange {
+            values_changed: counts.reformatted,
+            row_audits,
+            date_counts: Some(counts),
+            ..TransformChange::new(
+                format!(
+                    "Normalized {} date(s) in '{}' to YYYY-MM-DD ({} ambiguous, {} unparseable)",
+                    counts.reformatted, column, counts.ambiguous, counts.unparseable
+                ),
+                column,
+            )
+        })
+    }
+
+    /// Apply a redaction to every non-null value in a column.
+    ///
+    /// No row audits are recorded, so the removed values don't end up in
+    /// the audit file.
+    fn apply_redact(
+        &self,
+        column: &str,
+        method: RedactionMethod,
+        data: &mut DataTable,
+    ) -> Result<TransformChange> {
+        let col_idx = target_column(data, column).ok_or_else(|| {
+            CrucibleError::Validation(format!("Column '{}' not found", column))
+        })?;
+
+        let mut changed = 0;
+        for row_idx in 0..data.row_count() {
+            let value = data.get(row_idx, col_idx).unwrap_or_default().to_string();
+            if value.trim().is_empty() || DataTable::is_null_value(value.trim()) {
+                continue;
+            }
+            data.set(row_idx, col_idx, method.apply(&value));
+            changed += 1;
+        }
+
+        Ok(TransformChange {
+            values_changed: changed,
+            ..TransformChange::new(
+                format!("{} {} value(s) in '{}'", method.label(), changed, column),
+                column,
+            )
+        })
+    }
+
+    /// Apply a pseudonymization to every non-null value in a column.
+    ///
+    /// Two distinct values hashing to the same pseudonym is an error, since
+    /// applying it would merge them.
+    fn apply_pseudonymize(
+        &self,
+        column: &str,
+        method: &PseudonymMethod,
+        prefix: Option<&str>,
+        data: &mut DataTable,
+    ) -> Result<TransformChange> {
+        if matches!(method, PseudonymMethod::Hmac { key } if key.is_empty()) {
+            return Err(CrucibleError::Config(format!(
+                "HMAC pseudonyms need a key: set {}",
+                PSEUDONYM_KEY_ENV
+            )));
+        }
+        let col_idx = target_column(data, column).ok_or_else(|| {
+            CrucibleError::Validation(format!("Column '{}' not found", column))
+        })?;
+
+        let keyed = method.is_keyed();
+        let mut pseudonyms: BTreeMap<String, String> = BTreeMap::new();
+        let mut originals: HashMap<String, String> = HashMap::new();
+        let mut row_audits = Vec::new();
+
+        for row_idx in 0..data.row_count() {
+            let value = data.get(row_idx, col_idx).unwrap_or_default().to_string();
+            if value.trim().is_empty() || DataTable::is_null_value(value.trim()) {
+                continue;
+            }
+
+            let pseudonym = method.pseudonym(&value, prefix);
+            match originals.get(&pseudonym) {
+                Some(original) if *original != value => {
+                    return Err(CrucibleError::Validation(format!(
+                        "Two distinct values in '{}' have the same pseudonym",
+                        column
+                    )));
                 }
+                Some(_) => {}
                 None => {
-                    row_audits.push(RowAudit {
-                        row: row_idx,
-                        column: column.to_string(),
-                        original_value: trimmed.to_string(),
-                        new_value: String::new(),
-                        transform_type: "coerce".to_string(),
-                        reason: format!(
-                            "Converted '{}' to NA (could not coerce to {})",
-                            trimmed, target_type
-                        ),
-                    });
-                    // Convert non-coercible values to NA
-                    data.set(row_idx, col_idx, String::new());
-                    changed += 1;
+                    originals.insert(pseudonym.clone(), value.clone());
                 }
-                _ => {} // Value unchanged
             }
+            pseudonyms.insert(value.clone(), pseudonym.clone());
+
+            row_audits.push(RowAudit {
+                row: row_idx,
+                column: column.to_string(),
+                original_value: if keyed { String::new() } else { value.clone() },
+                new_value: pseudonym.clone(),
+                transform_type: "pseudonymize".to_string(),
+                reason: format!("Pseudonymized with {}", method.label()),
+                original_withheld: keyed,
+            });
+            data.set(row_idx, col_idx, pseudonym);
+        }
+
+        let description = format!(
+            "Pseudonymized {} value(s) in '{}' ({} distinct, {})",
+            row_audits.len(),
+            column,
+            pseudonyms.len(),
+            method.label()
+        );
+        Ok(TransformChange {
+            values_changed: row_audits.len(),
+            row_audits,
+            pseudonyms: Some(pseudonyms),
+            ..TransformChange::new(description, column)
+        })
+    }
+
+    /// Normalize the items of a delimited-list column.
+    fn apply_normalize_list(
+        &self,
+        column: &str,
+        delimiter: char,
+        mapping: &HashMap<String, String>,
+        data: &mut DataTable,
+    ) -> Result<TransformChange> {
+        let col_idx = target_column(data, column).ok_or_else(|| {
+            CrucibleError::Validation(format!("Column '{}' not found", column))
+        })?;
+
+        let mut changed = 0;
+        let mut row_audits = Vec::new();
+
+        for row_idx in 0..data.row_count() {
+            let value = data.get(row_idx, col_idx).unwrap_or_default().to_string();
+            if DataTable::is_null_value(&value) {
+                continue;
+            }
+
+            let mut items: Vec<&str> = Vec::new();
+            for item in value.split(delimiter).map(str::trim) {
+                let item = mapping.get(item).map_or(item, String::as_str);
+                if !item.is_empty() && !items.contains(&item) {
+                    items.push(item);
+                }
+            }
+            let new_value = items.join(&delimiter.to_string());
+            if new_value == value {
+                continue;
+            }
+
+            row_audits.push(RowAudit {
+                row: row_idx,
+                column: column.to_string(),
+                original_value: value.clone(),
+                new_value: new_value.clone(),
+                transform_type: "normalize_list".to_string(),
+                reason: format!("Normalized list items of '{}'", value),
+                original_withheld: false,
+            });
+            data.set(row_idx, col_idx, new_value);
+            changed += 1;
         }
 
         Ok(TransformChange {
-            description: format!(
-                "Coerced {} value(s) in '{}' to {}",
-                changed, column, target_type
-            ),
-            column: column.to_string(),
             values_changed: changed,
             row_audits,
+            ..TransformChange::new(format!("Normalized list items in '{}'", column), column)
         })
     }
 
-    /// Apply a date format conversion.
-    fn apply_convert_date(
+    /// Remove thousands separators from the numbers in a column.
+    fn apply_strip_thousands(
         &self,
         column: &str,
-        _target_format: &str,
+        decimal_mark: DecimalMark,
         data: &mut DataTable,
     ) -> Result<TransformChange> {
-        let col_idx = data.column_index(column).ok_or_else(|| {
+        let col_idx = target_column(data, column).ok_or_else(|| {
             CrucibleError::Validation(format!("Column '{}' not found", column))
         })?;
 
@@ -769,38 +2057,112 @@ impl TransformEngine {
 
         for row_idx in 0..data.row_count() {
             let value = data.get(row_idx, col_idx).unwrap_or_default().to_string();
-            let trimmed = value.trim();
+            if DataTable::is_null_value(&value) {
+                continue;
+            }
 
-            // Skip empty/null values
-            if trimmed.is_empty() || DataTable::is_null_value(trimmed) {
+            let Some(new_value) = parse_grouped_number(&value, decimal_mark) else {
+                continue;
+            };
+            if new_value == value {
                 continue;
             }
 
-            // Try to parse and convert to ISO format
-            if let Some(iso_date) = Self::parse_date_to_iso(trimmed) {
-                if iso_date != trimmed {
-                    row_audits.push(RowAudit {
-                        row: row_idx,
-                        column: column.to_string(),
-                        original_value: trimmed.to_string(),
-                        new_value: iso_date.clone(),
-                        transform_type: "convert_date".to_string(),
-                        reason: format!("Converted '{}' to ISO format '{}'", trimmed, iso_date),
-                    });
-                    data.set(row_idx, col_idx, iso_date);
-                    changed += 1;
+            row_audits.push(RowAudit {
+                row: row_idx,
+                column: column.to_string(),
+                original_value: value.clone(),
+                new_value: new_value.clone(),
+                transform_type: "strip_thousands_separators".to_string(),
+                reason: format!("Removed thousands separators from '{}'", value),
+                original_withheld: false,
+            });
+            data.set(row_idx, col_idx, new_value);
+            changed += 1;
+        }
+
+        Ok(TransformChange {
+            values_changed: changed,
+            row_audits,
+            ..TransformChange::new(
+                format!(
+                    "Stripped thousands separators from {} value(s) in '{}'",
+                    changed, column
+                ),
+                column,
+            )
+        })
+    }
+
+    /// Trim whitespace in one column, or in every column for [`ALL_COLUMNS`].
+    ///
+    /// Unicode whitespace counts, so tabs and non-breaking spaces are
+    /// stripped as well as ASCII spaces.
+    fn apply_trim_whitespace(
+        &self,
+        column: &str,
+        collapse_internal: bool,
+        data: &mut DataTable,
+    ) -> Result<TransformChange> {
+        let columns: Vec<usize> = if column == ALL_COLUMNS {
+            (0..data.column_count()).collect()
+        } else {
+            vec![target_column(data, column).ok_or_else(|| {
+                CrucibleError::Validation(format!("Column '{}' not found", column))
+            })?]
+        };
+
+        let reason = if collapse_internal {
+            "Trimmed and collapsed whitespace"
+        } else {
+            "Trimmed whitespace"
+        };
+        let mut column_counts = BTreeMap::new();
+        let mut row_audits = Vec::new();
+
+        for col_idx in columns {
+            let name = data.headers[col_idx].clone();
+            let mut changed = 0;
+            for row_idx in 0..data.row_count() {
+                let value = data.get(row_idx, col_idx).unwrap_or_default().to_string();
+                let new_value = if collapse_internal {
+                    value.split_whitespace().collect::<Vec<_>>().join(" ")
+                } else {
+                    value.trim().to_string()
+                };
+                if new_value == value {
+                    continue;
                 }
+
+                row_audits.push(RowAudit {
+                    row: row_idx,
+                    column: name.clone(),
+                    original_value: value,
+                    new_value: new_value.clone(),
+                    transform_type: "trim_whitespace".to_string(),
+                    reason: reason.to_string(),
+                    original_withheld: false,
+                });
+                data.set(row_idx, col_idx, new_value);
+                changed += 1;
+            }
+            if changed > 0 {
+                column_counts.insert(name, changed);
             }
         }
 
+        let changed: usize = column_counts.values().sum();
+        let description = format!(
+            "{} in {} value(s) across {} column(s)",
+            reason,
+            changed,
+            column_counts.len()
+        );
         Ok(TransformChange {
-            description: format!(
-                "Standardized {} date(s) in '{}' to ISO format",
-                changed, column
-            ),
-            column: column.to_string(),
             values_changed: changed,
             row_audits,
+            column_counts: Some(column_counts),
+            ..TransformChange::new(description, column)
         })
     }
 
@@ -970,3 +2332,855 @@ impl Default for TransformEngine {
         Self::new()
     }
 }
+
+/// Position of a column a transform reads or rewrites.
+///
+/// Unlike [`DataTable::column_index`], only an exact name matches, so a
+/// decision naming `sample` never rewrites a `Sample` column.
+fn target_column(data: &DataTable, name: &str) -> Option<usize> {
+    data.headers.iter().position(|h| h == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_audit_key() {
+        use crate::schema::{ColumnSchema, TableSchema};
+
+        let run = |pairs: &[(&str, &str)]| {
+            let mut data = DataTable::new(
+                vec!["diagnosis".to_string()],
+                vec![vec!["cd".to_string()], vec!["uc".to_string()], vec!["CD".to_string()]],
+                b'\t',
+            );
+            let op = TransformOperation::Standardize {
+                column: "diagnosis".to_string(),
+                mapping: pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            };
+            let mut result = TransformResult::new();
+            result.add_change(TransformEngine::new().apply_operation(&op, &mut data).unwrap());
+            result.operations.push(op);
+            result
+        };
+        let schema = TableSchema::with_columns(vec![ColumnSchema::new("diagnosis", 0)]);
+
+        let first = TransformAudit::new(&schema, &run(&[("cd", "CD"), ("uc", "UC")]));
+        assert_eq!(first.changes_by_column.get("diagnosis"), Some(&2));
+        assert_eq!(first.operations.len(), 1);
+
+        // Mapping order doesn't change the key, and the key survives a round trip
+        let again = TransformAudit::new(&schema, &run(&[("uc", "UC"), ("cd", "CD")]));
+        assert!(first.same_operations(&again));
+        let json = serde_json::to_string(&first).unwrap();
+        let loaded: TransformAudit = serde_json::from_str(&json).unwrap();
+        assert!(loaded.same_operations(&again));
+
+        let other = TransformAudit::new(&schema, &run(&[("cd", "Crohn's disease")]));
+        assert!(!first.same_operations(&other));
+
+        let renamed = TableSchema::with_columns(vec![ColumnSchema::new("dx", 0)]);
+        let moved = TransformAudit::new(&renamed, &run(&[("cd", "CD"), ("uc", "UC")]));
+        assert_ne!(first.schema_hash, moved.schema_hash);
+        assert!(!first.same_operations(&moved));
+    }
+
+    #[test]
+    fn test_transforms_match_column_case_exactly() {
+        let mut data = DataTable::new(
+            vec!["Sample".to_string()],
+            vec![vec!["cd".to_string()]],
+            b'\t',
+        );
+        let op = TransformOperation::Standardize {
+            column: "sample".to_string(),
+            mapping: [("cd".to_string(), "CD".to_string())].into_iter().collect(),
+        };
+        assert!(TransformEngine::new().apply_operation(&op, &mut data).is_err());
+        assert_eq!(data.get(0, 0), Some("cd"));
+    }
+
+    #[test]
+    fn test_revert_restores_audited_originals() {
+        use crate::schema::{ColumnSchema, TableSchema};
+
+        let source = vec![
+            vec!["cd".to_string(), "1".to_string()],
+            vec!["Cd".to_string(), "2".to_string()],
+            vec!["CD".to_string(), "3".to_string()],
+            vec!["uc".to_string(), "4".to_string()],
+        ];
+        let mut data = DataTable::new(
+            vec!["diagnosis".to_string(), "visit".to_string()],
+            source.clone(),
+            b'\t',
+        );
+        let engine = TransformEngine::new();
+        let mut result = TransformResult::new();
+        let ops = [
+            // Case-folding: three spellings collapse to one
+            TransformOperation::Standardize {
+                column: "diagnosis".to_string(),
+                mapping: [("cd", "CD"), ("Cd", "CD"), ("uc", "UC")]
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            },
+            TransformOperation::Flag {
+                source_column: "visit".to_string(),
+                flag_column: "visit_flagged".to_string(),
+                rows: vec![1],
+                flag_value: "check".to_string(),
+            },
+        ];
+        for op in ops {
+            result.add_change(engine.apply_operation(&op, &mut data).unwrap());
+            result.operations.push(op);
+        }
+        let schema = TableSchema::with_columns(vec![
+            ColumnSchema::new("diagnosis", 0),
+            ColumnSchema::new("visit", 1),
+        ]);
+        let audit = TransformAudit::new(&schema, &result);
+
+        let mut reverted = data.clone();
+        let undo = engine.revert(&audit, &mut reverted).unwrap();
+        assert_eq!(reverted.headers, vec!["diagnosis", "visit"]);
+        assert_eq!(reverted.rows, source);
+        assert_eq!(undo.rows_modified, 3);
+
+        // A cell edited after applying is left alone and reported
+        data.set(3, 0, "IBD-U".to_string());
+        let undo = engine.revert(&audit, &mut data).unwrap();
+        assert_eq!(data.get(3, 0), Some("IBD-U"));
+        assert_eq!(data.get(1, 0), Some("Cd"));
+        let conflicts: Vec<_> = undo
+            .changes
+            .iter()
+            .flat_map(|c| &c.row_audits)
+            .filter(|a| a.transform_type == "revert_conflict")
+            .collect();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].row, 3);
+    }
+
+    #[test]
+    fn test_coerce_float_cleans_formatted_numbers() {
+        let values = ["$1,234.56", "€45", "7.5", "", "TBD"];
+        let mut data = DataTable::new(
+            vec!["amount".to_string()],
+            values.iter().map(|v| vec![v.to_string()]).collect(),
+            b'\t',
+        );
+        let op = TransformOperation::Coerce {
+            column: "amount".to_string(),
+            target_type: "Float".to_string(),
+            rows: (0..values.len()).collect(),
+        };
+
+        let change = TransformEngine::new().apply_operation(&op, &mut data).unwrap();
+
+        let column: Vec<&str> = data.column_values(0).collect();
+        assert_eq!(column, vec!["1234.56", "45", "7.5", "", ""]);
+        assert_eq!(change.values_changed, 3);
+    }
+
+    #[test]
+    fn test_coerce_percentages_to_one_scale() {
+        let values = ["45%", "40", "12.5 %", "0.5", "NA"];
+        let mut data = DataTable::new(
+            vec!["gc_content".to_string()],
+            values.iter().map(|v| vec![v.to_string()]).collect(),
+            b'\t',
+        );
+        let op = TransformOperation::Coerce {
+            column: "gc_content".to_string(),
+            target_type: "Float".to_string(),
+            rows: (0..values.len()).collect(),
+        };
+
+        let change = TransformEngine::new().apply_operation(&op, &mut data).unwrap();
+
+        // Plain numbers are percentages missing their sign
+        let column: Vec<&str> = data.column_values(0).collect();
+        assert_eq!(column, vec!["0.45", "0.4", "0.125", "0.005", "NA"]);
+        assert_eq!(change.values_changed, 4);
+    }
+
+    #[test]
+    fn test_clamp_listed_rows() {
+        let values = ["-5", "30", "150", "200"];
+        let mut data = DataTable::new(
+            vec!["age".to_string()],
+            values.iter().map(|v| vec![v.to_string()]).collect(),
+            b'\t',
+        );
+        let op = TransformOperation::Clamp {
+            column: "age".to_string(),
+            min: Some(0.0),
+            max: Some(120.0),
+            rows: vec![0, 2],
+        };
+
+        let change = TransformEngine::new().apply_operation(&op, &mut data).unwrap();
+
+        let column: Vec<&str> = data.column_values(0).collect();
+        assert_eq!(column, vec!["0", "30", "120", "200"]);
+        let audited: Vec<usize> = change.row_audits.iter().map(|a| a.row).collect();
+        assert_eq!(audited, vec![0, 2]);
+        assert_eq!(op.description(), "Clamp 2 values in 'age' to [0, 120]");
+    }
+
+    #[test]
+    fn test_normalize_boolean() {
+        let values = ["yes", "Y", "1", "no", "N", "maybe", "", "true"];
+        let mut data = DataTable::new(
+            vec!["smoker".to_string()],
+            values.iter().map(|v| vec![v.to_string()]).collect(),
+            b'\t',
+        );
+        let op = TransformOperation::NormalizeBoolean {
+            column: "smoker".to_string(),
+            true_token: "yes".to_string(),
+            false_token: "no".to_string(),
+        };
+
+        let change = TransformEngine::new().apply_operation(&op, &mut data).unwrap();
+
+        let column: Vec<&str> = data.column_values(0).collect();
+        assert_eq!(column, vec!["yes", "yes", "yes", "no", "no", "maybe", "", "yes"]);
+        assert_eq!(change.values_changed, 4);
+
+        // The unrecognized value is kept and logged
+        let kept = change.row_audits.iter().find(|a| a.row == 5).unwrap();
+        assert_eq!(kept.new_value, "maybe");
+
+        let mut result = TransformResult::new();
+        result.add_change(change);
+        assert_eq!(result.rows_modified, 4);
+    }
+
+    #[test]
+    fn test_normalize_date() {
+        let values = ["2024-01-15", "03/04/2024", "25/12/2023", "Mar 5 2024", "7-Jun-99", "soon", "NA"];
+        let mut data = DataTable::new(
+            vec!["collection_date".to_string()],
+            values.iter().map(|v| vec![v.to_string()]).collect(),
+            b'\t',
+        );
+        let op = TransformOperation::NormalizeDate {
+            column: "collection_date".to_string(),
+            assume_order: DateOrder::DayFirst,
+        };
+
+        let change = TransformEngine::new().apply_operation(&op, &mut data).unwrap();
+
+        let column: Vec<&str> = data.column_values(0).collect();
+        assert_eq!(
+            column,
+            vec!["2024-01-15", "2024-04-03", "2023-12-25", "2024-03-05", "1999-06-07", "soon", "NA"]
+        );
+        let counts = change.date_counts.unwrap();
+        assert_eq!(counts.reformatted, 4);
+        assert_eq!(counts.ambiguous, 1);
+        assert_eq!(counts.unparseable, 1);
+
+        // The unparseable value is kept and logged
+        let kept = change.row_audits.iter().find(|a| a.row == 5).unwrap();
+        assert_eq!(kept.new_value, "soon");
+
+        let mut result = TransformResult::new();
+        result.add_change(change);
+        assert_eq!(result.rows_modified, 4);
+        assert_eq!(result.date_counts["collection_date"], counts);
+    }
+
+    #[test]
+    fn test_redact_column() {
+        let values = ["jane@example.org", "", "jane@example.org", "NA", "bob@example.org"];
+        let table = || {
+            DataTable::new(
+                vec!["contact".to_string()],
+                values.iter().map(|v| vec![v.to_string()]).collect(),
+                b'\t',
+            )
+        };
+
+        let mut data = table();
+        let op = TransformOperation::Redact {
+            column: "contact".to_string(),
+            method: RedactionMethod::Redact,
+        };
+        let change = TransformEngine::new().apply_operation(&op, &mut data).unwrap();
+        let column: Vec<&str> = data.column_values(0).collect();
+        assert_eq!(column, vec!["[REDACTED]", "", "[REDACTED]", "NA", "[REDACTED]"]);
+        assert_eq!(change.values_changed, 3);
+        // Removed values stay out of the audit
+        assert!(change.row_audits.is_empty());
+
+        // Hashing keeps equal values linkable
+        let mut data = table();
+        let op = TransformOperation::Redact {
+            column: "contact".to_string(),
+            method: RedactionMethod::Hash,
+        };
+        TransformEngine::new().apply_operation(&op, &mut data).unwrap();
+        let column: Vec<&str> = data.column_values(0).collect();
+        assert_eq!(column[0], column[2]);
+        assert_ne!(column[0], column[4]);
+        assert_eq!(column[0].len(), 16);
+        assert!(!column.contains(&"jane@example.org"));
+    }
+
+    #[test]
+    fn test_normalize_list() {
+        let mut data = DataTable::new(
+            vec!["medication".to_string()],
+            vec![
+                vec!["Antibiotic; probiotic".to_string()],
+                vec!["antibiotic;antibiotic;steroid".to_string()],
+                vec!["steroid".to_string()],
+                vec!["NA".to_string()],
+            ],
+            b'\t',
+        );
+        let op = TransformOperation::NormalizeList {
+            column: "medication".to_string(),
+            delimiter: ';',
+            mapping: HashMap::from([("Antibiotic".to_string(), "antibiotic".to_string())]),
+        };
+
+        let change = TransformEngine::new().apply_operation(&op, &mut data).unwrap();
+        let column: Vec<&str> = data.column_values(0).collect();
+        assert_eq!(column, vec!["antibiotic;probiotic", "antibiotic;steroid", "steroid", "NA"]);
+        assert_eq!(change.values_changed, 2);
+    }
+
+    #[test]
+    fn test_strip_thousands_separators() {
+        let values = ["1,234,567", "980", "12,5", "NA", "2,000.5"];
+        let mut data = DataTable::new(
+            vec!["read_count".to_string()],
+            values.iter().map(|v| vec![v.to_string()]).collect(),
+            b'\t',
+        );
+        let op = TransformOperation::StripThousandsSeparators {
+            column: "read_count".to_string(),
+            decimal_mark: DecimalMark::Point,
+        };
+
+        let change = TransformEngine::new().apply_operation(&op, &mut data).unwrap();
+        let column: Vec<&str> = data.column_values(0).collect();
+        // "12,5" isn't grouped in threes, so it's left alone
+        assert_eq!(column, vec!["1234567", "980", "12,5", "NA", "2000.5"]);
+        assert_eq!(change.values_changed, 2);
+
+        // With a decimal comma the point groups thousands
+        let mut data = DataTable::new(
+            vec!["weight".to_string()],
+            vec![vec!["1.234,56".to_string()], vec!["12,5".to_string()]],
+            b'\t',
+        );
+        let op = TransformOperation::StripThousandsSeparators {
+            column: "weight".to_string(),
+            decimal_mark: DecimalMark::Comma,
+        };
+        TransformEngine::new().apply_operation(&op, &mut data).unwrap();
+        let column: Vec<&str> = data.column_values(0).collect();
+        assert_eq!(column, vec!["1234.56", "12.5"]);
+    }
+
+    #[test]
+    fn test_trim_whitespace() {
+        let table = || {
+            DataTable::new(
+                vec!["sample_type".to_string(), "site".to_string()],
+                vec![
+                    vec!["stool ".to_string(), "\tgut".to_string()],
+                    vec!["fecal  swab".to_string(), "oral\u{00A0}".to_string()],
+                    vec!["stool".to_string(), "gut".to_string()],
+                ],
+                b'\t',
+            )
+        };
+
+        let mut data = table();
+        let op = TransformOperation::TrimWhitespace {
+            column: "sample_type".to_string(),
+            collapse_internal: false,
+        };
+        let change = TransformEngine::new().apply_operation(&op, &mut data).unwrap();
+        let column: Vec<&str> = data.column_values(0).collect();
+        assert_eq!(column, vec!["stool", "fecal  swab", "stool"]);
+        assert_eq!(change.values_changed, 1);
+        assert_eq!(change.row_audits[0].original_value, "stool ");
+
+        // Every column, with internal runs collapsed
+        let mut data = table();
+        let op = TransformOperation::TrimWhitespace {
+            column: ALL_COLUMNS.to_string(),
+            collapse_internal: true,
+        };
+        let change = TransformEngine::new().apply_operation(&op, &mut data).unwrap();
+        assert_eq!(data.column_values(0).collect::<Vec<_>>(), vec!["stool", "fecal swab", "stool"]);
+        assert_eq!(data.column_values(1).collect::<Vec<_>>(), vec!["gut", "oral", "gut"]);
+        assert_eq!(change.values_changed, 4);
+        let counts = change.column_counts.unwrap();
+        assert_eq!(counts["sample_type"], 2);
+        assert_eq!(counts["site"], 2);
+        assert_eq!(change.row_audits.len(), 4);
+    }
+
+    #[test]
+    fn test_round_numeric() {
+        let mut data = DataTable::new(
+            vec!["weight".to_string()],
+            ["5", "5.0", "4.99999999", "5.25", "NA", "1e3", "2.675", "n/a ml", "-0.001"]
+                .iter()
+                .map(|v| vec![v.to_string()])
+                .collect(),
+            b'\t',
+        );
+
+        let op = TransformOperation::RoundNumeric {
+            column: "weight".to_string(),
+            decimals: 2,
+            fixed: true,
+        };
+        assert_eq!(op.description(), "Round 'weight' to exactly 2 decimal place(s)");
+        let change = TransformEngine::new().apply_operation(&op, &mut data).unwrap();
+        let column: Vec<&str> = data.column_values(0).collect();
+        assert_eq!(
+            column,
+            vec!["5.00", "5.00", "5.00", "5.25", "NA", "1000.00", "2.68", "n/a ml", "0.00"]
+        );
+        let counts = change.rounding_counts.unwrap();
+        assert_eq!(counts.rounded, 6);
+        assert_eq!(counts.unchanged, 1);
+        assert_eq!(counts.non_numeric, 1);
+        assert_eq!(change.values_changed, 6);
+        assert_eq!(change.row_audits[2].original_value, "4.99999999");
+        assert_eq!(change.row_audits[2].transform_type, "round_numeric");
+        let kept = change.row_audits.iter().find(|a| a.original_value == "n/a ml").unwrap();
+        assert_eq!(kept.new_value, "n/a ml");
+
+        // Without the fixed flag, trailing zeros are dropped
+        let mut result = TransformResult::new();
+        let op = TransformOperation::RoundNumeric {
+            column: "weight".to_string(),
+            decimals: 1,
+            fixed: false,
+        };
+        result.add_change(TransformEngine::new().apply_operation(&op, &mut data).unwrap());
+        let column: Vec<&str> = data.column_values(0).collect();
+        assert_eq!(column, vec!["5", "5", "5", "5.3", "NA", "1000", "2.7", "n/a ml", "0"]);
+        assert_eq!(result.rounding_counts["weight"].rounded, 7);
+
+        assert_eq!(round_decimal("9.995", 2, false).as_deref(), Some("10"));
+        assert_eq!(round_decimal("-1.25e-1", 2, true).as_deref(), Some("-0.13"));
+        assert_eq!(
+            round_decimal("123456789012345678901.5", 0, false).as_deref(),
+            Some("123456789012345678902")
+        );
+        assert_eq!(round_decimal("1e5000", 2, false), None);
+        assert_eq!(round_decimal(".", 2, false), None);
+    }
+
+    #[test]
+    fn test_pseudonymize_column() {
+        let values = ["P001", "P002", "P001", "", "P003"];
+        let table = || {
+            DataTable::new(
+                vec!["patient_id".to_string()],
+                values.iter().map(|v| vec![v.to_string()]).collect(),
+                b'\t',
+            )
+        };
+
+        // RFC 4231 test case 2
+        let method = PseudonymMethod::Hmac { key: "Jefe".to_string() };
+        assert_eq!(method.pseudonym("what do ya want for nothing?", None), "5bdcc146bf60");
+
+        let mut data = table();
+        let op = TransformOperation::Pseudonymize {
+            column: "patient_id".to_string(),
+            method: PseudonymMethod::Sha256,
+            prefix: Some("SUBJ_".to_string()),
+        };
+        let change = TransformEngine::new().apply_operation(&op, &mut data).unwrap();
+        let column: Vec<&str> = data.column_values(0).collect();
+        assert_eq!(column[0], column[2]);
+        assert_ne!(column[0], column[1]);
+        assert!(column[0].starts_with("SUBJ_") && column[0].len() == 17);
+        assert_eq!(column[3], "");
+        assert_eq!(change.values_changed, 4);
+        assert_eq!(change.row_audits[0].original_value, "P001");
+
+        // A keyed method keeps originals out of the audit but in the keyfile map
+        let mut data = table();
+        let op = TransformOperation::Pseudonymize {
+            column: "patient_id".to_string(),
+            method: PseudonymMethod::Hmac { key: "secret".to_string() },
+            prefix: None,
+        };
+        let engine = TransformEngine::new();
+        let change = engine.apply_operation(&op, &mut data).unwrap();
+        assert!(change
+            .row_audits
+            .iter()
+            .all(|a| a.original_withheld && a.original_value.is_empty()));
+
+        let mut result = TransformResult::new();
+        result.add_change(change);
+        result.operations.push(op);
+        let keys = &result.pseudonyms["patient_id"];
+        assert_eq!(keys.len(), 3);
+        assert_eq!(keys["P002"], data.get(1, 0).unwrap());
+
+        // Neither the key nor the mapping is serialized
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(!json.contains("secret"));
+        assert!(!json.contains("P002"));
+
+        // Withheld originals can't be restored from the audit
+        let schema = crate::schema::TableSchema::with_columns(vec![
+            crate::schema::ColumnSchema::new("patient_id", 0),
+        ]);
+        let audit = TransformAudit::new(&schema, &result);
+        let json = serde_json::to_string(&audit).unwrap();
+        assert!(!json.contains("secret") && !json.contains("P002"));
+        let reverted = engine.revert(&audit, &mut data).unwrap();
+        assert_eq!(reverted.rows_modified, 0);
+        assert_eq!(data.get(1, 0), Some(keys["P002"].as_str()));
+
+        // A keyed method is never applied without its key
+        assert!(PseudonymMethod::hmac("").is_err());
+        let deserialized: PseudonymMethod = serde_json::from_str(r#"{"hmac": {}}"#).unwrap();
+        let op = TransformOperation::Pseudonymize {
+            column: "patient_id".to_string(),
+            method: deserialized,
+            prefix: None,
+        };
+        let mut data = table();
+        assert!(engine.apply_operation(&op, &mut data).is_err());
+        assert_eq!(data.get(0, 0), Some("P001"));
+
+        // The engine's key selects HMAC for pseudonymize suggestions
+        let keyed = TransformEngine::new().with_pseudonym_key("secret");
+        assert_eq!(
+            keyed.pseudonym_method().unwrap(),
+            PseudonymMethod::Hmac { key: "secret".to_string() }
+        );
+        assert!(TransformEngine::new().with_pseudonym_key("").pseudonym_method().is_err());
+    }
+
+    #[test]
+    fn test_map_to_ontology() {
+        let values = ["forest", "Prairie", "moon base", "forest", "ENVO:00000447", ""];
+        let mut data = DataTable::new(
+            vec!["biome".to_string()],
+            values.iter().map(|v| vec![v.to_string()]).collect(),
+            b'\t',
+        );
+        let validator = crate::bio::OntologyValidator::new();
+        let op = TransformOperation::map_to_ontology(
+            "biome",
+            OntologyType::Envo,
+            true,
+            values.iter().copied(),
+            &validator,
+        );
+
+        // Matching happens when the operation is built
+        let TransformOperation::MapToOntology { ref mapping, .. } = op else {
+            panic!("expected MapToOntology");
+        };
+        assert_eq!(mapping["forest"].id, "ENVO:01000174");
+        assert!(!mapping.contains_key("moon base"));
+
+        let change = TransformEngine::new().apply_operation(&op, &mut data).unwrap();
+
+        let column: Vec<&str> = data.column_values(0).collect();
+        assert_eq!(column[0], "ENVO:01000174|forest biome");
+        assert_eq!(column[1], "ENVO:01000177|grassland biome");
+        assert_eq!(column[2], "moon base");
+        assert_eq!(column[4], "ENVO:00000447");
+        assert_eq!(change.values_changed, 3);
+
+        // The unmapped value is kept and logged
+        let kept = change.row_audits.iter().find(|a| a.row == 2).unwrap();
+        assert_eq!(kept.new_value, "moon base");
+
+        let mut result = TransformResult::new();
+        result.add_change(change);
+        assert_eq!(
+            result.ontology_counts["biome"],
+            OntologyMappingCounts {
+                mapped: 3,
+                unmapped: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_map_to_ontology_from_suggestion_parameters() {
+        let suggestion = crate::suggestion::Suggestion::new(
+            "obs_001",
+            SuggestionAction::Standardize,
+            "Map biomes to ENVO",
+        )
+        .with_parameters(serde_json::json!({
+            "column": "biome",
+            "ontology": "Envo",
+            "terms": {"forest": {"id": "ENVO:01000174", "label": "forest biome"}},
+        }));
+        let observation = crate::validation::Observation::new(
+            ObservationType::Inconsistency,
+            crate::validation::Severity::Warning,
+            "biome",
+            "Free-text values could be mapped to ENVO",
+        );
+        let data = DataTable::new(vec!["biome".to_string()], vec![], b'\t');
+
+        let op = TransformEngine::new()
+            .create_standardize_operation(&suggestion, &observation, &data)
+            .unwrap()
+            .unwrap();
+        let TransformOperation::MapToOntology {
+            keep_label,
+            mapping,
+            ..
+        } = op
+        else {
+            panic!("expected MapToOntology");
+        };
+        assert!(!keep_label);
+        assert_eq!(mapping["forest"].value(keep_label), "ENVO:01000174");
+    }
+
+    #[test]
+    fn test_trim_whitespace_from_suggestion_parameters() {
+        let suggestion = crate::suggestion::Suggestion::new(
+            "obs_001",
+            SuggestionAction::Standardize,
+            "Trim and collapse whitespace",
+        )
+        .with_parameters(serde_json::json!({"column": "site", "collapse_internal": true}));
+        let observation = crate::validation::Observation::new(
+            ObservationType::Inconsistency,
+            crate::validation::Severity::Warning,
+            "site",
+            "Values have stray whitespace",
+        );
+        let data = DataTable::new(vec!["site".to_string()], vec![], b'\t');
+
+        let op = TransformEngine::new()
+            .create_standardize_operation(&suggestion, &observation, &data)
+            .unwrap()
+            .unwrap();
+        let TransformOperation::TrimWhitespace {
+            column,
+            collapse_internal,
+        } = op
+        else {
+            panic!("expected TrimWhitespace");
+        };
+        assert_eq!(column, "site");
+        assert!(collapse_internal);
+    }
+
+    #[test]
+    fn test_round_numeric_from_suggestion_caps_decimals() {
+        let suggestion = crate::suggestion::Suggestion::new(
+            "obs_001",
+            SuggestionAction::Standardize,
+            "Round to a consistent precision",
+        )
+        .with_parameters(serde_json::json!({"column": "weight", "decimals": 1_000_000_000u64, "fixed": true}));
+        let observation = crate::validation::Observation::new(
+            ObservationType::Inconsistency,
+            crate::validation::Severity::Info,
+            "weight",
+            "Values use inconsistent precision",
+        );
+        let data = DataTable::new(vec!["weight".to_string()], vec![], b'\t');
+
+        let op = TransformEngine::new()
+            .create_standardize_operation(&suggestion, &observation, &data)
+            .unwrap()
+            .unwrap();
+        let TransformOperation::RoundNumeric { decimals, fixed, .. } = op else {
+            panic!("expected RoundNumeric");
+        };
+        assert_eq!(decimals, MAX_ROUND_DECIMALS);
+        assert!(fixed);
+    }
+
+    #[test]
+    fn test_merge_semantic_equivalents() {
+        use crate::schema::ColumnSchema;
+        use crate::validation::{SemanticEquivalenceValidator, Validator};
+
+        let mut data = DataTable::new(
+            vec!["diagnosis".to_string()],
+            vec![
+                vec!["Crohn's".to_string()],
+                vec!["CD".to_string()],
+                vec!["Crohns".to_string()],
+                vec!["UC".to_string()],
+            ],
+            b'\t',
+        );
+        let mut column = ColumnSchema::new("diagnosis", 0);
+        column.inferred_type = crate::schema::ColumnType::String;
+        let schema = crate::schema::TableSchema::with_columns(vec![column]);
+
+        let observation = SemanticEquivalenceValidator::default()
+            .validate(&data, &schema)
+            .remove(0);
+        let suggestion = crate::suggestion::SuggestionEngine::generate(std::slice::from_ref(&observation))
+            .remove(0);
+
+        let engine = TransformEngine::new();
+        let op = engine
+            .create_standardize_operation(&suggestion, &observation, &data)
+            .unwrap()
+            .unwrap();
+        let change = engine.apply_operation(&op, &mut data).unwrap();
+
+        assert_eq!(change.values_changed, 2);
+        assert_eq!(data.column_values(0).collect::<Vec<_>>(), vec!["CD", "CD", "CD", "UC"]);
+    }
+
+    #[test]
+    fn test_split_column() {
+        let mut data = DataTable::new(
+            vec!["site".to_string(), "lat_lon".to_string()],
+            vec![
+                vec!["A".to_string(), "38.98 -77.11".to_string()],
+                vec!["B".to_string(), "38.98".to_string()],
+                vec!["C".to_string(), "".to_string()],
+            ],
+            b'\t',
+        );
+        let op = TransformOperation::SplitColumn {
+            source: "lat_lon".to_string(),
+            delimiter: " ".to_string(),
+            into: vec!["latitude".to_string(), "longitude".to_string()],
+        };
+
+        let change = TransformEngine::new().apply_operation(&op, &mut data).unwrap();
+
+        assert_eq!(data.headers, vec!["site", "lat_lon", "latitude", "longitude"]);
+        assert_eq!(data.rows[0], vec!["A", "38.98 -77.11", "38.98", "-77.11"]);
+        assert_eq!(data.rows[1], vec!["B", "38.98", "", ""]);
+        assert_eq!(change.values_changed, 1);
+        assert_eq!(change.columns_added, vec!["latitude", "longitude"]);
+
+        // The row that failed to split is audited with its original value
+        let failed: Vec<_> = change.row_audits.iter().filter(|a| a.row == 1).collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].original_value, "38.98");
+    }
+
+    #[test]
+    fn test_rename_column() {
+        use crate::schema::{ColumnSchema, Constraint, SemanticRole, TableSchema};
+
+        let source = vec![
+            vec!["S1".to_string(), "2024-01-05".to_string(), "old".to_string()],
+            vec!["S2".to_string(), "5 Jan 2024".to_string(), "old".to_string()],
+        ];
+        let mut data = DataTable::new(
+            vec!["sample".to_string(), "Collected".to_string(), "collection_date".to_string()],
+            source.clone(),
+            b'\t',
+        );
+        let rename = TransformOperation::RenameColumn {
+            from: "Collected".to_string(),
+            to: "collection_date".to_string(),
+        };
+
+        // Renaming onto an existing column needs force
+        let err = TransformEngine::new().apply_operation(&rename, &mut data).unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+        assert_eq!(data.headers, vec!["sample", "Collected", "collection_date"]);
+
+        let engine = TransformEngine::new().with_force_renames(true);
+        let mut result = TransformResult::new();
+        let ops = [
+            rename,
+            TransformOperation::NormalizeDate {
+                column: "collection_date".to_string(),
+                assume_order: DateOrder::DayFirst,
+            },
+        ];
+        for op in ops {
+            result.add_change(engine.apply_operation(&op, &mut data).unwrap());
+            result.operations.push(op);
+        }
+        assert_eq!(data.headers, vec!["sample", "collection_date"]);
+        assert_eq!(data.rows[1], vec!["S2", "2024-01-05"]);
+        assert_eq!(result.rows_modified, 1);
+        assert_eq!(
+            result.columns_renamed,
+            vec![ColumnRename {
+                from: "Collected".to_string(),
+                to: "collection_date".to_string(),
+                replaced: true,
+            }]
+        );
+
+        // Role and constraints follow the column into the output schema
+        let mut collected = ColumnSchema::new("Collected", 1);
+        collected.semantic_role = SemanticRole::Metadata;
+        collected.constraints.push(Constraint::NotNull { confidence: 1.0 });
+        let schema = TableSchema::with_columns(vec![
+            ColumnSchema::new("sample", 0),
+            collected,
+            ColumnSchema::new("collection_date", 2),
+        ]);
+        let output = result.output_schema(&schema);
+        assert_eq!(output.column_names(), vec!["sample", "collection_date"]);
+        let renamed = output.get_column("collection_date").unwrap();
+        assert_eq!(renamed.position, 1);
+        assert_eq!(renamed.semantic_role, SemanticRole::Metadata);
+        assert_eq!(renamed.constraints.len(), 1);
+
+        // A rename that dropped a column can't be reverted
+        let audit = TransformAudit::new(&schema, &result);
+        assert!(engine.revert(&audit, &mut data.clone()).is_err());
+
+        // A plain rename is, along with changes made under either name
+        let mut data = DataTable::new(
+            vec!["sample".to_string(), "Collected".to_string()],
+            source.iter().map(|row| row[..2].to_vec()).collect(),
+            b'\t',
+        );
+        let mut result = TransformResult::new();
+        let ops = [
+            TransformOperation::Standardize {
+                column: "Collected".to_string(),
+                mapping: [("2024-01-05".to_string(), "2024-01-06".to_string())].into(),
+            },
+            TransformOperation::RenameColumn {
+                from: "Collected".to_string(),
+                to: "collection_date".to_string(),
+            },
+            TransformOperation::NormalizeDate {
+                column: "collection_date".to_string(),
+                assume_order: DateOrder::DayFirst,
+            },
+        ];
+        for op in ops {
+            result.add_change(engine.apply_operation(&op, &mut data).unwrap());
+            result.operations.push(op);
+        }
+        let audit = TransformAudit::new(&schema, &result);
+        let undo = engine.revert(&audit, &mut data).unwrap();
+        assert_eq!(data.headers, vec!["sample", "Collected"]);
+        assert_eq!(data.rows, source.iter().map(|row| row[..2].to_vec()).collect::<Vec<_>>());
+        assert_eq!(undo.columns_renamed.len(), 1);
+    }
+}
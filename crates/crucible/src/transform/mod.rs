@@ -1,7 +1,12 @@
 //! Data transformation module for applying curation decisions.
 
+mod dates;
 mod engine;
 mod operations;
 
-pub use engine::TransformEngine;
-pub use operations::{RowAudit, TransformChange, TransformOperation, TransformResult};
+pub(crate) use dates::parse_date;
+pub use engine::{PSEUDONYM_KEY_ENV, TransformEngine};
+pub use operations::{
+    ALL_COLUMNS, ColumnRename, DateNormalizationCounts, DateOrder, MAX_ROUND_DECIMALS, MappedTerm, OntologyMappingCounts, PseudonymMethod, REDACTED_VALUE, RedactionMethod, RoundingCounts, RowAudit, TransformAudit, TransformChange,
+    TransformOperation, TransformResult, round_decimal,
+};
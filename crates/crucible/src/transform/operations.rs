@@ -1,8 +1,19 @@
 //! Transformation operations that can be applied to data.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::bio::{OntologyType, OntologyValidator};
+use crate::curation::CRUCIBLE_VERSION;
+use crate::error::{CrucibleError, Result};
+use crate::schema::TableSchema;
+use crate::validation::DecimalMark;
+
+/// Column target that applies an operation to every column, where supported.
+pub const ALL_COLUMNS: &str = "*";
 
 /// A transformation operation to apply to data.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,12 +45,150 @@ pub enum TransformOperation {
         rows: Vec<usize>,
     },
 
+    /// Clamp numeric values to a range.
+    ///
+    /// Only the listed rows are clamped; with no rows, every row is.
+    Clamp {
+        column: String,
+        min: Option<f64>,
+        max: Option<f64>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        rows: Vec<usize>,
+    },
+
+    /// Multiply numeric values by a factor to change their unit.
+    ConvertUnit {
+        column: String,
+        from_unit: String,
+        to_unit: String,
+        factor: f64,
+    },
+
+    /// Split a combined column into several new columns.
+    ///
+    /// The source column is kept. A blank delimiter splits on runs of
+    /// whitespace.
+    SplitColumn {
+        source: String,
+        delimiter: String,
+        into: Vec<String>,
+    },
+
+    /// Rename a column, keeping its values.
+    ///
+    /// Renaming onto another column's name is rejected unless the engine
+    /// allows it with [`TransformEngine::with_force_renames`](super::TransformEngine::with_force_renames),
+    /// in which case that column is replaced.
+    RenameColumn {
+        from: String,
+        to: String,
+    },
+
+    /// Map recognized boolean spellings (yes/Y/1/true, ...) to canonical tokens.
+    ///
+    /// Values that aren't a recognized spelling are left as they are.
+    NormalizeBoolean {
+        column: String,
+        true_token: String,
+        false_token: String,
+    },
+
+    /// Replace free-text values with ontology term IDs (`ID` or `ID|label`).
+    ///
+    /// The mapping is precomputed (see [`TransformOperation::map_to_ontology`])
+    /// so applying it is deterministic. Values without a mapping are left as
+    /// they are.
+    MapToOntology {
+        column: String,
+        ontology: OntologyType,
+        keep_label: bool,
+        mapping: HashMap<String, MappedTerm>,
+    },
+
     /// Standardize date formats to ISO (YYYY-MM-DD).
     ConvertDate {
         column: String,
         target_format: String,
     },
 
+    /// Rewrite dates in any recognized format to ISO 8601 (`YYYY-MM-DD`).
+    ///
+    /// `assume_order` only decides numeric dates whose day and month could
+    /// be swapped (`03/04/2024`); month names and two-digit years are
+    /// handled too. Unparseable values are kept and logged in the audit.
+    NormalizeDate {
+        column: String,
+        assume_order: DateOrder,
+    },
+
+    /// Replace every non-null value in a column, to remove personal identifiers.
+    ///
+    /// The original values are not written to the audit, so a redaction
+    /// can't be reverted.
+    Redact {
+        column: String,
+        method: RedactionMethod,
+    },
+
+    /// Replace each value with a stable pseudonym (e.g. `SUBJ_3f2a9c01b7e4`).
+    ///
+    /// Equal values get equal pseudonyms. The original-to-pseudonym mapping
+    /// is kept in [`TransformResult::pseudonyms`] for a separate keyfile;
+    /// with a keyed method the originals are also left out of the row audits.
+    Pseudonymize {
+        column: String,
+        method: PseudonymMethod,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        prefix: Option<String>,
+    },
+
+    /// Strip leading and trailing whitespace (tabs and non-breaking spaces
+    /// included), optionally collapsing internal runs to one space.
+    ///
+    /// A `column` of [`ALL_COLUMNS`] trims every column.
+    TrimWhitespace {
+        column: String,
+        #[serde(default)]
+        collapse_internal: bool,
+    },
+
+    /// Round numeric values to at most `decimals` decimal places.
+    ///
+    /// Values are rewritten as plain decimals (scientific notation is
+    /// expanded) without trailing zeros, or with exactly `decimals` places
+    /// when `fixed` is set. Rounding works on the written digits, so it
+    /// is exact for any magnitude. Non-numeric values are kept and logged
+    /// in the audit.
+    RoundNumeric {
+        column: String,
+        decimals: usize,
+        #[serde(default)]
+        fixed: bool,
+    },
+
+    /// Normalize the items of a delimited-list column.
+    ///
+    /// Each value is split on `delimiter`; items are trimmed, replaced when
+    /// found in `mapping`, and rejoined with `delimiter`, dropping empty
+    /// and repeated items.
+    NormalizeList {
+        column: String,
+        delimiter: char,
+        #[serde(default)]
+        mapping: HashMap<String, String>,
+    },
+
+    /// Remove thousands separators from numbers (`1,234,567` → `1234567`).
+    ///
+    /// Numbers are read with `decimal_mark` and written with a `.` decimal
+    /// point, so with a decimal comma `1.234,5` becomes `1234.5`. Values
+    /// that aren't numbers are left as they are.
+    StripThousandsSeparators {
+        column: String,
+        #[serde(default)]
+        decimal_mark: DecimalMark,
+    },
+
     /// No operation - just a marker that the suggestion was acknowledged.
     NoOp {
         reason: String,
@@ -86,12 +235,131 @@ impl TransformOperation {
                     target_type
                 )
             }
+            TransformOperation::Clamp { column, min, max, rows } => {
+                let scope = if rows.is_empty() {
+                    String::new()
+                } else {
+                    format!("{} values in ", rows.len())
+                };
+                format!(
+                    "Clamp {}'{}' to [{}, {}]",
+                    scope,
+                    column,
+                    min.map(|v| v.to_string()).unwrap_or("∞".to_string()),
+                    max.map(|v| v.to_string()).unwrap_or("∞".to_string())
+                )
+            }
+            TransformOperation::ConvertUnit {
+                column,
+                from_unit,
+                to_unit,
+                ..
+            } => {
+                format!("Convert '{}' from {} to {}", column, from_unit, to_unit)
+            }
+            TransformOperation::SplitColumn { source, into, .. } => {
+                format!("Split '{}' into {}", source, into.join(", "))
+            }
+            TransformOperation::RenameColumn { from, to } => {
+                format!("Rename '{}' to '{}'", from, to)
+            }
+            TransformOperation::NormalizeBoolean {
+                column,
+                true_token,
+                false_token,
+            } => {
+                format!(
+                    "Normalize booleans in '{}' to '{}'/'{}'",
+                    column, true_token, false_token
+                )
+            }
+            TransformOperation::MapToOntology {
+                column,
+                ontology,
+                mapping,
+                ..
+            } => {
+                format!(
+                    "Map {} value(s) in '{}' to {} terms",
+                    mapping.len(),
+                    column,
+                    ontology.prefix()
+                )
+            }
             TransformOperation::ConvertDate {
                 column,
                 target_format,
             } => {
                 format!("Standardize dates in '{}' to {}", column, target_format)
             }
+            TransformOperation::NormalizeDate {
+                column,
+                assume_order,
+            } => {
+                format!(
+                    "Normalize dates in '{}' to YYYY-MM-DD ({} when ambiguous)",
+                    column,
+                    assume_order.label()
+                )
+            }
+            TransformOperation::Redact { column, method } => {
+                format!("{} values in '{}'", method.label(), column)
+            }
+            TransformOperation::Pseudonymize { column, method, .. } => {
+                format!("Pseudonymize '{}' ({})", column, method.label())
+            }
+            TransformOperation::TrimWhitespace {
+                column,
+                collapse_internal,
+            } => {
+                let target = if column == ALL_COLUMNS {
+                    "all columns".to_string()
+                } else {
+                    format!("'{}'", column)
+                };
+                if *collapse_internal {
+                    format!("Trim and collapse whitespace in {}", target)
+                } else {
+                    format!("Trim whitespace in {}", target)
+                }
+            }
+            TransformOperation::RoundNumeric {
+                column,
+                decimals,
+                fixed,
+            } => {
+                if *fixed {
+                    format!("Round '{}' to exactly {} decimal place(s)", column, decimals)
+                } else {
+                    format!("Round '{}' to {} decimal place(s)", column, decimals)
+                }
+            }
+            TransformOperation::NormalizeList {
+                column,
+                delimiter,
+                mapping,
+            } => {
+                if mapping.is_empty() {
+                    format!("Normalize '{}'-separated items in '{}'", delimiter, column)
+                } else {
+                    format!(
+                        "Normalize '{}'-separated items in '{}', mapping {} item(s)",
+                        delimiter,
+                        column,
+                        mapping.len()
+                    )
+                }
+            }
+            TransformOperation::StripThousandsSeparators {
+                column,
+                decimal_mark,
+            } => match decimal_mark {
+                DecimalMark::Point => format!("Strip thousands separators in '{}'", column),
+                DecimalMark::Comma => format!(
+                    "Strip thousands separators in '{}' and write decimal commas as points",
+                    column
+                ),
+            },
             TransformOperation::NoOp { reason } => {
                 format!("No action: {}", reason)
             }
@@ -99,6 +367,352 @@ impl TransformOperation {
     }
 }
 
+impl TransformOperation {
+    /// Build a [`TransformOperation::MapToOntology`] by looking up the best
+    /// term for each distinct value.
+    ///
+    /// Matching happens here, once, so the operation can be stored and
+    /// applied later without re-running fuzzy matching. Values with no
+    /// match in the ontology are left out of the mapping.
+    pub fn map_to_ontology<'a>(
+        column: impl Into<String>,
+        ontology: OntologyType,
+        keep_label: bool,
+        values: impl IntoIterator<Item = &'a str>,
+        validator: &OntologyValidator,
+    ) -> Self {
+        let mut mapping = HashMap::new();
+        for value in values {
+            if mapping.contains_key(value) {
+                continue;
+            }
+            // Highest confidence wins; ties go to the lowest ID so the
+            // result doesn't depend on index order
+            let best = validator
+                .suggest_mappings(value, Some(ontology))
+                .into_iter()
+                .max_by(|a, b| {
+                    a.confidence
+                        .total_cmp(&b.confidence)
+                        .then_with(|| b.term_id.cmp(&a.term_id))
+                });
+            if let Some(best) = best {
+                mapping.insert(
+                    value.to_string(),
+                    MappedTerm {
+                        id: best.term_id,
+                        label: best.term_label,
+                    },
+                );
+            }
+        }
+
+        TransformOperation::MapToOntology {
+            column: column.into(),
+            ontology,
+            keep_label,
+            mapping,
+        }
+    }
+}
+
+/// An ontology term chosen for a free-text value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MappedTerm {
+    /// Term ID (e.g., "ENVO:00001998").
+    pub id: String,
+    /// Term label (e.g., "soil").
+    pub label: String,
+}
+
+impl MappedTerm {
+    /// The replacement value: the ID, or `ID|label` when keeping the label.
+    pub fn value(&self, keep_label: bool) -> String {
+        if keep_label {
+            format!("{}|{}", self.id, self.label)
+        } else {
+            self.id.clone()
+        }
+    }
+}
+
+/// Mapped and unmapped value counts for an ontology mapping.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OntologyMappingCounts {
+    /// Values replaced with an ontology term.
+    pub mapped: usize,
+    /// Free-text values left as they were.
+    pub unmapped: usize,
+}
+
+/// Which part of an ambiguous numeric date is the day.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DateOrder {
+    /// Day before month, as in `DD/MM/YYYY`.
+    DayFirst,
+    /// Month before day, as in `MM/DD/YYYY`.
+    #[default]
+    MonthFirst,
+}
+
+impl DateOrder {
+    /// Get a human-readable label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            DateOrder::DayFirst => "day first",
+            DateOrder::MonthFirst => "month first",
+        }
+    }
+}
+
+/// How a column of sensitive values is redacted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RedactionMethod {
+    /// Replace each value with [`REDACTED_VALUE`].
+    #[default]
+    Redact,
+    /// Replace each value with a truncated SHA-256 hash, so equal values
+    /// stay linkable. Small value spaces (such as SSNs) can be brute-forced,
+    /// so this is not anonymization.
+    Hash,
+}
+
+impl RedactionMethod {
+    /// Get a human-readable label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            RedactionMethod::Redact => "Redact",
+            RedactionMethod::Hash => "Hash",
+        }
+    }
+
+    /// The replacement for a value.
+    pub fn apply(&self, value: &str) -> String {
+        match self {
+            RedactionMethod::Redact => REDACTED_VALUE.to_string(),
+            RedactionMethod::Hash => {
+                let digest = format!("{:x}", Sha256::digest(value.as_bytes()));
+                digest[..16].to_string()
+            }
+        }
+    }
+}
+
+/// Placeholder written over redacted values.
+pub const REDACTED_VALUE: &str = "[REDACTED]";
+
+/// Hex digits of the digest kept in a pseudonym.
+const PSEUDONYM_HEX_LEN: usize = 12;
+
+/// How pseudonyms are derived from values.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PseudonymMethod {
+    /// Plain SHA-256. Anyone can recompute it, so small value spaces (such
+    /// as record numbers) can be re-identified by brute force.
+    Sha256,
+    /// HMAC-SHA-256 with a secret key. The key is never serialized, so it
+    /// doesn't end up in audit files, and a deserialized method has an empty
+    /// key that can't be applied. Build one with [`PseudonymMethod::hmac`].
+    Hmac {
+        #[serde(default, skip_serializing)]
+        key: String,
+    },
+}
+
+impl PseudonymMethod {
+    /// HMAC-SHA-256 under a key, which must not be empty.
+    pub fn hmac(key: impl Into<String>) -> Result<Self> {
+        let key = key.into();
+        if key.is_empty() {
+            return Err(CrucibleError::Config(
+                "HMAC pseudonyms need a non-empty key".to_string(),
+            ));
+        }
+        Ok(PseudonymMethod::Hmac { key })
+    }
+
+    /// Get a human-readable label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            PseudonymMethod::Sha256 => "SHA-256",
+            PseudonymMethod::Hmac { .. } => "HMAC-SHA-256",
+        }
+    }
+
+    /// Whether the method needs a secret key.
+    pub fn is_keyed(&self) -> bool {
+        matches!(self, PseudonymMethod::Hmac { .. })
+    }
+
+    /// The pseudonym for a value: the prefix followed by the start of the
+    /// hex digest.
+    pub fn pseudonym(&self, value: &str, prefix: Option<&str>) -> String {
+        let digest = match self {
+            PseudonymMethod::Sha256 => Sha256::digest(value.as_bytes()).to_vec(),
+            PseudonymMethod::Hmac { key } => hmac_sha256(key.as_bytes(), value.as_bytes()).to_vec(),
+        };
+        let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+        format!("{}{}", prefix.unwrap_or_default(), &hex[..PSEUDONYM_HEX_LEN])
+    }
+}
+
+/// HMAC-SHA-256 (RFC 2104).
+fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    const BLOCK_SIZE: usize = 64;
+
+    let mut block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let pad = |byte: u8| block.iter().map(|b| b ^ byte).collect::<Vec<u8>>();
+    let inner = Sha256::new()
+        .chain_update(pad(0x36))
+        .chain_update(message)
+        .finalize();
+    Sha256::new()
+        .chain_update(pad(0x5c))
+        .chain_update(inner)
+        .finalize()
+        .into()
+}
+
+/// Outcome counts for a date normalization.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DateNormalizationCounts {
+    /// Values rewritten to `YYYY-MM-DD`.
+    pub reformatted: usize,
+    /// Values whose day and month were decided by the assumed order.
+    pub ambiguous: usize,
+    /// Values that couldn't be parsed and were kept as they were.
+    pub unparseable: usize,
+}
+
+/// Outcome counts for a numeric rounding.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundingCounts {
+    /// Values rewritten to the target precision.
+    pub rounded: usize,
+    /// Numeric values already written at the target precision.
+    pub unchanged: usize,
+    /// Values that aren't numbers and were kept as they were.
+    pub non_numeric: usize,
+}
+
+/// Largest exponent expanded by [`round_decimal`]; beyond it a value is
+/// treated as non-numeric rather than written out in full.
+const MAX_EXPONENT: i64 = 1000;
+
+/// Most decimal places a [`TransformOperation::RoundNumeric`] built from a
+/// suggestion keeps; an `f64` carries no more than this.
+pub const MAX_ROUND_DECIMALS: usize = 15;
+
+/// Round a decimal number, written plainly or in scientific notation, to
+/// at most `decimals` places (exactly `decimals` when `fixed`).
+///
+/// Works on the digits as written, rounding half away from zero, so
+/// `2.675` becomes `2.68` and large values keep every digit. Returns `None`
+/// for text that isn't a number.
+///
+/// ```
+/// use crucible::transform::round_decimal;
+///
+/// assert_eq!(round_decimal("4.99999999", 2, false).as_deref(), Some("5"));
+/// assert_eq!(round_decimal("5.1", 2, true).as_deref(), Some("5.10"));
+/// assert_eq!(round_decimal("1.2345e3", 1, false).as_deref(), Some("1234.5"));
+/// assert_eq!(round_decimal("n/a", 2, false), None);
+/// ```
+pub fn round_decimal(value: &str, decimals: usize, fixed: bool) -> Option<String> {
+    let value = value.trim();
+    let (negative, unsigned) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i64>().ok()?),
+        None => (unsigned, 0),
+    };
+    if exponent.abs() > MAX_EXPONENT {
+        return None;
+    }
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if int_part.is_empty() && frac_part.is_empty()
+        || !int_part.bytes().chain(frac_part.bytes()).all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    // All digits, with the decimal point `point` digits from the left
+    let mut digits: Vec<u8> = int_part.bytes().chain(frac_part.bytes()).map(|b| b - b'0').collect();
+    let mut point = int_part.len() as i64 + exponent;
+    if point < 0 {
+        digits.splice(0..0, std::iter::repeat_n(0, (-point) as usize));
+        point = 0;
+    }
+    let point = point as usize;
+    if digits.len() < point {
+        digits.resize(point, 0);
+    }
+
+    // Round half away from zero at the last kept place
+    let keep = point + decimals;
+    if digits.len() > keep {
+        let round_up = digits[keep] >= 5;
+        digits.truncate(keep);
+        if round_up {
+            let mut i = keep;
+            loop {
+                if i == 0 {
+                    digits.insert(0, 1);
+                    return Some(format_digits(negative, &digits, point + 1, decimals, fixed));
+                }
+                i -= 1;
+                if digits[i] == 9 {
+                    digits[i] = 0;
+                } else {
+                    digits[i] += 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    Some(format_digits(negative, &digits, point, decimals, fixed))
+}
+
+/// Write digits with a decimal point as a plain decimal string.
+fn format_digits(negative: bool, digits: &[u8], point: usize, decimals: usize, fixed: bool) -> String {
+    let to_str = |d: &[u8]| d.iter().map(|d| (b'0' + d) as char).collect::<String>();
+    let int_part = to_str(&digits[..point]);
+    let int_part = match int_part.trim_start_matches('0') {
+        "" => "0",
+        trimmed => trimmed,
+    };
+    let mut frac_part = to_str(&digits[point..]);
+    if fixed {
+        frac_part.extend(std::iter::repeat_n('0', decimals - frac_part.len()));
+    } else {
+        frac_part.truncate(frac_part.trim_end_matches('0').len());
+    }
+
+    let is_zero = digits.iter().all(|d| *d == 0);
+    let mut out = String::new();
+    if negative && !is_zero {
+        out.push('-');
+    }
+    out.push_str(int_part);
+    if !frac_part.is_empty() {
+        out.push('.');
+        out.push_str(&frac_part);
+    }
+    out
+}
+
 /// Result of applying transformations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransformResult {
@@ -111,12 +725,41 @@ pub struct TransformResult {
     /// Number of columns added.
     pub columns_added: usize,
 
+    /// Column names of the transformed table.
+    #[serde(default)]
+    pub columns: Vec<String>,
+
+    /// Columns renamed, in the order applied.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns_renamed: Vec<ColumnRename>,
+
+    /// Ontology mapping counts by column.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub ontology_counts: BTreeMap<String, OntologyMappingCounts>,
+
+    /// Date normalization counts by column.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub date_counts: BTreeMap<String, DateNormalizationCounts>,
+
+    /// Rounding counts by column.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rounding_counts: BTreeMap<String, RoundingCounts>,
+
+    /// Original value → pseudonym by column, for the keyfile. Never
+    /// serialized with the result.
+    #[serde(skip)]
+    pub pseudonyms: BTreeMap<String, BTreeMap<String, String>>,
+
+    /// Operations applied, in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub operations: Vec<TransformOperation>,
+
     /// Detailed changes for each operation.
     pub changes: Vec<TransformChange>,
 }
 
 /// A single change made during transformation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TransformChange {
     /// Description of the change.
     pub description: String,
@@ -129,6 +772,60 @@ pub struct TransformChange {
 
     /// Per-row audit information.
     pub row_audits: Vec<RowAudit>,
+
+    /// Columns added to the table by this change.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns_added: Vec<String>,
+
+    /// Mapped and unmapped counts, for ontology mappings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ontology_counts: Option<OntologyMappingCounts>,
+
+    /// Reformatted, ambiguous, and unparseable counts, for date normalizations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_counts: Option<DateNormalizationCounts>,
+
+    /// Original value → pseudonym, for pseudonymizations. Never serialized.
+    #[serde(skip)]
+    pub pseudonyms: Option<BTreeMap<String, String>>,
+
+    /// Values changed by column, for changes that can span several columns.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column_counts: Option<BTreeMap<String, usize>>,
+
+    /// Rounded, unchanged, and non-numeric counts, for numeric roundings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rounding_counts: Option<RoundingCounts>,
+
+    /// Columns renamed by this change.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns_renamed: Vec<ColumnRename>,
+}
+
+impl TransformChange {
+    /// Create a change to a column that touched no values; set the other
+    /// fields with struct update syntax.
+    pub fn new(description: impl Into<String>, column: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            column: column.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// A column header changed by a transform.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnRename {
+    /// Name before the rename.
+    pub from: String,
+
+    /// Name after the rename.
+    pub to: String,
+
+    /// A column already named `to` was dropped to make room.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub replaced: bool,
 }
 
 /// Audit information for a single row change.
@@ -146,6 +843,11 @@ pub struct RowAudit {
     /// New value after transformation.
     pub new_value: String,
 
+    /// The original was left out of the audit (keyed pseudonymization), so
+    /// `original_value` is empty and the change can't be reverted from it.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub original_withheld: bool,
+
     /// Type of transformation applied.
     pub transform_type: String,
 
@@ -160,6 +862,13 @@ impl TransformResult {
             operations_applied: 0,
             rows_modified: 0,
             columns_added: 0,
+            columns: Vec::new(),
+            columns_renamed: Vec::new(),
+            ontology_counts: BTreeMap::new(),
+            date_counts: BTreeMap::new(),
+            rounding_counts: BTreeMap::new(),
+            pseudonyms: BTreeMap::new(),
+            operations: Vec::new(),
             changes: Vec::new(),
         }
     }
@@ -168,8 +877,44 @@ impl TransformResult {
     pub fn add_change(&mut self, change: TransformChange) {
         self.operations_applied += 1;
         self.rows_modified += change.values_changed;
+        self.columns_added += change.columns_added.len();
+        self.columns_renamed.extend(change.columns_renamed.iter().cloned());
+        if let Some(counts) = change.ontology_counts {
+            let total = self.ontology_counts.entry(change.column.clone()).or_default();
+            total.mapped += counts.mapped;
+            total.unmapped += counts.unmapped;
+        }
+        if let Some(counts) = change.date_counts {
+            let total = self.date_counts.entry(change.column.clone()).or_default();
+            total.reformatted += counts.reformatted;
+            total.ambiguous += counts.ambiguous;
+            total.unparseable += counts.unparseable;
+        }
+        if let Some(counts) = change.rounding_counts {
+            let total = self.rounding_counts.entry(change.column.clone()).or_default();
+            total.rounded += counts.rounded;
+            total.unchanged += counts.unchanged;
+            total.non_numeric += counts.non_numeric;
+        }
+        if let Some(pseudonyms) = &change.pseudonyms {
+            self.pseudonyms
+                .entry(change.column.clone())
+                .or_default()
+                .extend(pseudonyms.clone());
+        }
         self.changes.push(change);
     }
+
+    /// The schema of the transformed table: `schema` with this result's
+    /// renames applied, so roles, constraints, and row constraints follow
+    /// renamed columns. Columns replaced by a forced rename are dropped.
+    pub fn output_schema(&self, schema: &TableSchema) -> TableSchema {
+        let mut schema = schema.clone();
+        for rename in &self.columns_renamed {
+            schema.rename_column(&rename.from, &rename.to);
+        }
+        schema
+    }
 }
 
 impl Default for TransformResult {
@@ -177,3 +922,109 @@ impl Default for TransformResult {
         Self::new()
     }
 }
+
+/// Audit trail written alongside applied data.
+///
+/// Records what was applied and to which schema so a transform run can be
+/// reviewed and reproduced. Two audits with the same [`key`](Self::key)
+/// applied the same operations to the same schema.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransformAudit {
+    /// Hash of the schema's column names and types.
+    pub schema_hash: String,
+
+    /// Hash of the schema hash and the applied operations.
+    pub key: String,
+
+    /// Operations applied, in order, with their parameters.
+    pub operations: Vec<TransformOperation>,
+
+    /// Cells changed per column, from the row audits.
+    pub changes_by_column: BTreeMap<String, usize>,
+
+    /// Original and new value of every changed cell, in the order applied.
+    #[serde(default)]
+    pub row_audits: Vec<RowAudit>,
+
+    /// Columns the operations added to the table.
+    #[serde(default)]
+    pub columns_added: Vec<String>,
+
+    /// Columns the operations renamed, in the order applied.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns_renamed: Vec<ColumnRename>,
+
+    /// Version of Crucible that applied the operations.
+    pub crucible_version: String,
+
+    /// When the operations were applied.
+    pub created_at: DateTime<Utc>,
+}
+
+impl TransformAudit {
+    /// Build the audit for a transform run against `schema`.
+    pub fn new(schema: &TableSchema, result: &TransformResult) -> Self {
+        let schema_hash = Self::schema_hash(schema);
+        let key = Self::operations_key(&schema_hash, &result.operations);
+
+        let row_audits: Vec<RowAudit> = result
+            .changes
+            .iter()
+            .flat_map(|c| &c.row_audits)
+            .filter(|audit| audit.original_value != audit.new_value)
+            .cloned()
+            .collect();
+
+        let mut changes_by_column = BTreeMap::new();
+        for audit in &row_audits {
+            *changes_by_column.entry(audit.column.clone()).or_default() += 1;
+        }
+
+        Self {
+            schema_hash,
+            key,
+            operations: result.operations.clone(),
+            changes_by_column,
+            row_audits,
+            columns_added: result
+                .changes
+                .iter()
+                .flat_map(|c| c.columns_added.iter().cloned())
+                .collect(),
+            columns_renamed: result.columns_renamed.clone(),
+            crucible_version: CRUCIBLE_VERSION.to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Whether `other` applied the same operations to the same schema.
+    pub fn same_operations(&self, other: &TransformAudit) -> bool {
+        self.key == other.key
+    }
+
+    /// Hash the schema's column names and inferred types, in order.
+    fn schema_hash(schema: &TableSchema) -> String {
+        let mut hasher = Sha256::new();
+        for column in &schema.columns {
+            hasher.update(column.name.as_bytes());
+            hasher.update([0x1f]);
+            hasher.update(format!("{:?}", column.inferred_type).as_bytes());
+            hasher.update([0x1e]);
+        }
+        format!("sha256:{:x}", hasher.finalize())
+    }
+
+    /// Hash the operations along with the schema they were applied to.
+    fn operations_key(schema_hash: &str, operations: &[TransformOperation]) -> String {
+        // `Value` objects keep keys sorted, so mapping order doesn't matter
+        let operations = serde_json::to_value(operations)
+            .map(|v| v.to_string())
+            .unwrap_or_default();
+
+        let mut hasher = Sha256::new();
+        hasher.update(schema_hash.as_bytes());
+        hasher.update(b"\n");
+        hasher.update(operations.as_bytes());
+        format!("sha256:{:x}", hasher.finalize())
+    }
+}
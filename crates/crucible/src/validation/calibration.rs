@@ -0,0 +1,133 @@
+//! Confidence calibration reporting across observations.
+//!
+//! Bucketing observation confidences per type makes it easy to spot detectors
+//! that are systematically over- or under-confident.
+
+use serde::{Deserialize, Serialize};
+
+use super::observation::{Observation, ObservationType};
+
+/// Number of confidence buckets (deciles).
+pub const CONFIDENCE_BUCKETS: usize = 10;
+
+/// Confidence distribution for one observation type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeConfidence {
+    /// The observation type.
+    pub observation_type: ObservationType,
+    /// Number of observations whose confidence falls in each decile.
+    pub counts: [usize; CONFIDENCE_BUCKETS],
+    /// Total observations of this type.
+    pub total: usize,
+    /// Mean confidence of observations of this type.
+    pub mean_confidence: f64,
+}
+
+/// Histogram of observation confidences, bucketed into deciles.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConfidenceHistogram {
+    /// Counts across all observations, per decile.
+    pub counts: [usize; CONFIDENCE_BUCKETS],
+    /// Total observations.
+    pub total: usize,
+    /// Per-type distributions, most frequent type first.
+    pub by_type: Vec<TypeConfidence>,
+}
+
+impl ConfidenceHistogram {
+    /// Bucket the confidences of a set of observations.
+    pub fn from_observations(observations: &[Observation]) -> Self {
+        let mut histogram = Self::default();
+
+        for obs in observations {
+            let bucket = Self::bucket(obs.confidence);
+            histogram.counts[bucket] += 1;
+            histogram.total += 1;
+
+            let index = match histogram
+                .by_type
+                .iter()
+                .position(|t| t.observation_type == obs.observation_type)
+            {
+                Some(index) => index,
+                None => {
+                    histogram.by_type.push(TypeConfidence {
+                        observation_type: obs.observation_type,
+                        counts: [0; CONFIDENCE_BUCKETS],
+                        total: 0,
+                        mean_confidence: 0.0,
+                    });
+                    histogram.by_type.len() - 1
+                }
+            };
+
+            let entry = &mut histogram.by_type[index];
+            entry.counts[bucket] += 1;
+            entry.total += 1;
+            // Accumulate the sum; converted to a mean below
+            entry.mean_confidence += obs.confidence;
+        }
+
+        for entry in &mut histogram.by_type {
+            entry.mean_confidence /= entry.total as f64;
+        }
+        histogram.by_type.sort_by(|a, b| {
+            b.total
+                .cmp(&a.total)
+                .then_with(|| a.observation_type.label().cmp(b.observation_type.label()))
+        });
+
+        histogram
+    }
+
+    /// Decile bucket for a confidence; 1.0 falls in the top bucket.
+    pub fn bucket(confidence: f64) -> usize {
+        let bucket = (confidence.clamp(0.0, 1.0) * CONFIDENCE_BUCKETS as f64) as usize;
+        bucket.min(CONFIDENCE_BUCKETS - 1)
+    }
+
+    /// Label for a bucket, e.g. "0.9-1.0".
+    pub fn bucket_label(bucket: usize) -> String {
+        format!(
+            "{:.1}-{:.1}",
+            bucket as f64 / CONFIDENCE_BUCKETS as f64,
+            (bucket + 1) as f64 / CONFIDENCE_BUCKETS as f64
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::Severity;
+
+    fn observation(observation_type: ObservationType, confidence: f64) -> Observation {
+        Observation::new(observation_type, Severity::Warning, "col", "test")
+            .with_confidence(confidence)
+    }
+
+    #[test]
+    fn test_confidence_histogram() {
+        let observations = vec![
+            observation(ObservationType::Completeness, 1.0),
+            observation(ObservationType::Completeness, 0.95),
+            observation(ObservationType::Outlier, 0.55),
+            observation(ObservationType::Completeness, 0.0),
+        ];
+
+        let histogram = ConfidenceHistogram::from_observations(&observations);
+        assert_eq!(histogram.total, 4);
+        assert_eq!(histogram.counts[9], 2);
+        assert_eq!(histogram.counts[5], 1);
+        assert_eq!(histogram.counts[0], 1);
+
+        assert_eq!(histogram.by_type.len(), 2);
+        let completeness = &histogram.by_type[0];
+        assert_eq!(completeness.observation_type, ObservationType::Completeness);
+        assert_eq!(completeness.total, 3);
+        assert_eq!(completeness.counts[9], 2);
+        assert!((completeness.mean_confidence - 0.65).abs() < 1e-9);
+
+        assert_eq!(ConfidenceHistogram::bucket_label(9), "0.9-1.0");
+    }
+}
@@ -0,0 +1,424 @@
+//! Cross-file consistency checks for longitudinal datasets.
+//!
+//! Multi-timepoint studies deliver one table per visit that should share
+//! subject identifiers, column types, and categorical value sets. Comparing
+//! the per-file schemas and tables reports where the timepoints disagree as
+//! [`ObservationType::CohortInconsistency`] observations.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::input::DataTable;
+use crate::schema::{ColumnType, SemanticRole, SemanticType, TableSchema};
+
+use super::observation::{Evidence, Observation, ObservationType, Severity};
+
+/// Synthetic column that cohort observations are scoped to.
+pub const COHORT_COLUMN: &str = "_cohort";
+
+/// Maximum example values listed in an observation description.
+const MAX_EXAMPLES: usize = 5;
+
+/// One timepoint of a cohort: a parsed table and its inferred schema.
+pub struct CohortMember<'a> {
+    /// Label identifying the timepoint (usually the file name).
+    pub label: String,
+    /// Parsed data.
+    pub table: &'a DataTable,
+    /// Inferred schema.
+    pub schema: &'a TableSchema,
+}
+
+/// Subjects absent from one timepoint but present in others.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissingSubjects {
+    /// The timepoint the subjects are missing from.
+    pub timepoint: String,
+    /// Subject identifiers seen in other timepoints only.
+    pub subjects: Vec<String>,
+}
+
+/// A column whose inferred type differs between timepoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeDrift {
+    /// Column name.
+    pub column: String,
+    /// Inferred type in each timepoint that has the column.
+    pub types: BTreeMap<String, ColumnType>,
+}
+
+/// A categorical column whose values differ between timepoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValueDrift {
+    /// Column name.
+    pub column: String,
+    /// Values missing from some timepoints, with the timepoints they appear in.
+    pub values: BTreeMap<String, Vec<String>>,
+}
+
+/// Result of comparing the timepoints of a cohort.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CohortReport {
+    /// Timepoint labels, in the order given.
+    pub timepoints: Vec<String>,
+    /// Identifier column used to match subjects, if one is shared by every timepoint.
+    pub subject_column: Option<String>,
+    /// Subjects missing from some timepoints.
+    pub missing_subjects: Vec<MissingSubjects>,
+    /// Columns whose inferred type drifts between timepoints.
+    pub type_drift: Vec<TypeDrift>,
+    /// Categorical columns whose value sets drift between timepoints.
+    pub value_drift: Vec<ValueDrift>,
+    /// The findings above, as observations on the [`COHORT_COLUMN`].
+    pub observations: Vec<Observation>,
+}
+
+impl CohortReport {
+    /// Compare the timepoints of a cohort.
+    pub fn compare(members: &[CohortMember<'_>]) -> Self {
+        let mut report = Self {
+            timepoints: members.iter().map(|m| m.label.clone()).collect(),
+            ..Self::default()
+        };
+        if members.len() < 2 {
+            return report;
+        }
+
+        report.subject_column = subject_column(members);
+        if let Some(ref column) = report.subject_column {
+            report.missing_subjects = missing_subjects(members, column);
+        }
+        report.type_drift = type_drift(members);
+        report.value_drift = value_drift(members);
+
+        report.observations = report.to_observations();
+        report
+    }
+
+    /// Check if the timepoints are consistent.
+    pub fn is_consistent(&self) -> bool {
+        self.missing_subjects.is_empty()
+            && self.type_drift.is_empty()
+            && self.value_drift.is_empty()
+    }
+
+    fn to_observations(&self) -> Vec<Observation> {
+        let mut observations = Vec::new();
+        let subject_column = self.subject_column.as_deref().unwrap_or_default();
+
+        for missing in &self.missing_subjects {
+            observations.push(
+                cohort_observation(
+                    Severity::Warning,
+                    format!(
+                        "{} subjects ('{}') from other timepoints are missing in {}: {}",
+                        missing.subjects.len(),
+                        subject_column,
+                        missing.timepoint,
+                        examples(&missing.subjects)
+                    ),
+                )
+                .with_evidence(
+                    Evidence::new()
+                        .with_occurrences(missing.subjects.len())
+                        .with_value(json!(missing.timepoint))
+                        .with_expected(json!(missing.subjects)),
+                ),
+            );
+        }
+
+        for drift in &self.type_drift {
+            let types: Vec<String> = drift
+                .types
+                .iter()
+                .map(|(timepoint, ty)| format!("{:?} in {}", ty, timepoint))
+                .collect();
+            observations.push(
+                cohort_observation(
+                    Severity::Warning,
+                    format!(
+                        "Column '{}' changes type between timepoints: {}",
+                        drift.column,
+                        types.join(", ")
+                    ),
+                )
+                .with_evidence(
+                    Evidence::new()
+                        .with_value(json!(drift.column))
+                        .with_expected(json!(drift.types)),
+                ),
+            );
+        }
+
+        for drift in &self.value_drift {
+            let values: Vec<String> = drift.values.keys().cloned().collect();
+            observations.push(
+                cohort_observation(
+                    Severity::Info,
+                    format!(
+                        "Column '{}' has {} values that appear in only some timepoints: {}",
+                        drift.column,
+                        values.len(),
+                        examples(&values)
+                    ),
+                )
+                .with_evidence(
+                    Evidence::new()
+                        .with_value(json!(drift.column))
+                        .with_occurrences(values.len())
+                        .with_value_counts(Some(json!(drift.values))),
+                ),
+            );
+        }
+
+        observations
+    }
+}
+
+fn cohort_observation(severity: Severity, description: String) -> Observation {
+    Observation::new(
+        ObservationType::CohortInconsistency,
+        severity,
+        COHORT_COLUMN,
+        description,
+    )
+    .with_confidence(0.9)
+    .with_detector("cohort_validator")
+}
+
+/// Quote the first few values, noting how many were left out.
+fn examples(values: &[String]) -> String {
+    let mut listed: Vec<String> = values
+        .iter()
+        .take(MAX_EXAMPLES)
+        .map(|v| format!("'{}'", v))
+        .collect();
+    if values.len() > MAX_EXAMPLES {
+        listed.push(format!("and {} more", values.len() - MAX_EXAMPLES));
+    }
+    listed.join(", ")
+}
+
+/// The first identifier column, in the first timepoint's order, shared by all timepoints.
+fn subject_column(members: &[CohortMember<'_>]) -> Option<String> {
+    members[0]
+        .schema
+        .columns
+        .iter()
+        .filter(|col| col.semantic_role == SemanticRole::Identifier)
+        .map(|col| &col.name)
+        .find(|name| {
+            members[1..].iter().all(|m| {
+                m.schema
+                    .get_column(name)
+                    .is_some_and(|col| col.semantic_role == SemanticRole::Identifier)
+            })
+        })
+        .cloned()
+}
+
+/// Distinct non-null values of a column, trimmed.
+fn distinct_values(table: &DataTable, column: &str) -> BTreeSet<String> {
+    let Some(index) = table.column_index(column) else {
+        return BTreeSet::new();
+    };
+    table
+        .column_values(index)
+        .map(str::trim)
+        .filter(|v| !DataTable::is_null_value(v))
+        .map(str::to_string)
+        .collect()
+}
+
+fn missing_subjects(members: &[CohortMember<'_>], column: &str) -> Vec<MissingSubjects> {
+    let subjects: Vec<BTreeSet<String>> = members
+        .iter()
+        .map(|m| distinct_values(m.table, column))
+        .collect();
+    let all: BTreeSet<&String> = subjects.iter().flatten().collect();
+
+    members
+        .iter()
+        .zip(&subjects)
+        .filter_map(|(member, present)| {
+            let missing: Vec<String> = all
+                .iter()
+                .filter(|s| !present.contains(s.as_str()))
+                .map(|s| s.to_string())
+                .collect();
+            (!missing.is_empty()).then(|| MissingSubjects {
+                timepoint: member.label.clone(),
+                subjects: missing,
+            })
+        })
+        .collect()
+}
+
+/// Column names in first-seen order across all timepoints.
+fn all_columns<'a>(members: &[CohortMember<'a>]) -> Vec<&'a str> {
+    let mut columns: Vec<&str> = Vec::new();
+    for member in members {
+        for col in &member.schema.columns {
+            if !columns.contains(&col.name.as_str()) {
+                columns.push(&col.name);
+            }
+        }
+    }
+    columns
+}
+
+fn type_drift(members: &[CohortMember<'_>]) -> Vec<TypeDrift> {
+    all_columns(members)
+        .into_iter()
+        .filter_map(|name| {
+            // All-null columns infer as Unknown and say nothing about drift
+            let types: BTreeMap<String, ColumnType> = members
+                .iter()
+                .filter_map(|m| Some((m.label.clone(), m.schema.get_column(name)?.inferred_type)))
+                .filter(|(_, ty)| *ty != ColumnType::Unknown)
+                .collect();
+            let first = *types.values().next()?;
+            types.values().any(|ty| *ty != first).then(|| TypeDrift {
+                column: name.to_string(),
+                types,
+            })
+        })
+        .collect()
+}
+
+fn value_drift(members: &[CohortMember<'_>]) -> Vec<ValueDrift> {
+    all_columns(members)
+        .into_iter()
+        .filter_map(|name| {
+            let having: Vec<&CohortMember<'_>> = members
+                .iter()
+                .filter(|m| m.schema.get_column(name).is_some())
+                .collect();
+            let categorical = having.iter().any(|m| {
+                m.schema.get_column(name).is_some_and(|col| {
+                    matches!(
+                        col.semantic_type,
+                        SemanticType::Categorical | SemanticType::Binary
+                    )
+                })
+            });
+            if having.len() < 2 || !categorical {
+                return None;
+            }
+
+            let mut seen_in: BTreeMap<String, Vec<String>> = BTreeMap::new();
+            for member in &having {
+                for value in distinct_values(member.table, name) {
+                    seen_in.entry(value).or_default().push(member.label.clone());
+                }
+            }
+            seen_in.retain(|_, timepoints| timepoints.len() < having.len());
+
+            (!seen_in.is_empty()).then(|| ValueDrift {
+                column: name.to_string(),
+                values: seen_in,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inference::InferenceFusion;
+
+    fn table(headers: &[&str], rows: &[&[&str]]) -> DataTable {
+        DataTable::new(
+            headers.iter().map(|h| h.to_string()).collect(),
+            rows.iter()
+                .map(|r| r.iter().map(|v| v.to_string()).collect())
+                .collect(),
+            b'\t',
+        )
+    }
+
+    #[test]
+    fn test_cohort_drift() {
+        let visit1 = table(
+            &["subject_id", "treatment", "age"],
+            &[
+                &["S1", "placebo", "34"],
+                &["S2", "drug", "51"],
+                &["S3", "placebo", "47"],
+                &["S4", "drug", "29"],
+            ],
+        );
+        let visit2 = table(
+            &["subject_id", "treatment", "age"],
+            &[
+                &["S1", "placebo", "34 years"],
+                &["S2", "drug", "51 years"],
+                &["S4", "Drug", "29 years"],
+                &["S5", "drug", "38 years"],
+            ],
+        );
+
+        let fusion = InferenceFusion::new();
+        let (schema1, schema2) = (fusion.analyze_table(&visit1), fusion.analyze_table(&visit2));
+        let report = CohortReport::compare(&[
+            CohortMember {
+                label: "visit1.tsv".into(),
+                table: &visit1,
+                schema: &schema1,
+            },
+            CohortMember {
+                label: "visit2.tsv".into(),
+                table: &visit2,
+                schema: &schema2,
+            },
+        ]);
+
+        assert_eq!(report.subject_column.as_deref(), Some("subject_id"));
+        assert_eq!(report.missing_subjects.len(), 2);
+        assert_eq!(report.missing_subjects[0].timepoint, "visit1.tsv");
+        assert_eq!(report.missing_subjects[0].subjects, vec!["S5"]);
+        assert_eq!(report.missing_subjects[1].subjects, vec!["S3"]);
+
+        assert_eq!(report.type_drift.len(), 1);
+        assert_eq!(report.type_drift[0].column, "age");
+
+        let treatment = report
+            .value_drift
+            .iter()
+            .find(|d| d.column == "treatment")
+            .unwrap();
+        assert_eq!(treatment.values["Drug"], vec!["visit2.tsv"]);
+        assert!(!treatment.values.contains_key("placebo"));
+
+        assert!(!report.is_consistent());
+        assert!(report.observations.iter().all(|o| {
+            o.observation_type == ObservationType::CohortInconsistency && o.column == COHORT_COLUMN
+        }));
+    }
+
+    #[test]
+    fn test_consistent_cohort() {
+        let visit = table(
+            &["subject_id", "sex"],
+            &[&["S1", "F"], &["S2", "M"], &["S3", "F"]],
+        );
+        let schema = InferenceFusion::new().analyze_table(&visit);
+        let report = CohortReport::compare(&[
+            CohortMember {
+                label: "visit1.tsv".into(),
+                table: &visit,
+                schema: &schema,
+            },
+            CohortMember {
+                label: "visit2.tsv".into(),
+                table: &visit,
+                schema: &schema,
+            },
+        ]);
+
+        assert!(report.is_consistent());
+        assert!(report.observations.is_empty());
+    }
+}
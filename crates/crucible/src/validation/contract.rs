@@ -0,0 +1,193 @@
+//! Validation against a frozen schema contract.
+//!
+//! Once a schema has been reviewed and approved it can be saved and used as
+//! the contract for future deliveries. Every deviation from the contract is
+//! reported as an [`ObservationType::ContractViolation`].
+
+use serde_json::json;
+
+use crate::inference::ConstraintReport;
+use crate::input::DataTable;
+use crate::schema::{SemanticRole, TableSchema};
+
+use super::observation::{Evidence, Observation, ObservationType, Severity};
+use super::validators::Validator;
+
+/// Validates data and its inferred schema against a frozen contract schema.
+pub struct ContractValidator {
+    contract: TableSchema,
+}
+
+impl ContractValidator {
+    /// Create a validator for the given contract.
+    pub fn new(contract: TableSchema) -> Self {
+        Self { contract }
+    }
+
+    /// The contract being enforced.
+    pub fn contract(&self) -> &TableSchema {
+        &self.contract
+    }
+
+    fn violation(
+        column: &str,
+        severity: Severity,
+        description: String,
+        evidence: Evidence,
+    ) -> Observation {
+        Observation::new(ObservationType::ContractViolation, severity, column, description)
+            .with_evidence(evidence)
+            .with_confidence(1.0)
+            .with_detector("contract_validator")
+    }
+}
+
+impl Validator for ContractValidator {
+    fn name(&self) -> &'static str {
+        "contract_validator"
+    }
+
+    fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
+        let mut observations = Vec::new();
+
+        // Columns present in the data but not in the contract
+        for col in &schema.columns {
+            if self.contract.get_column(&col.name).is_none() {
+                observations.push(Self::violation(
+                    &col.name,
+                    Severity::Warning,
+                    format!("Column '{}' is not part of the schema contract", col.name),
+                    Evidence::new().with_expected(json!(self.contract.column_names())),
+                ));
+            }
+        }
+
+        // Contract columns, re-pointed at their position in this table
+        let mut located = TableSchema::new();
+        for expected in &self.contract.columns {
+            let Some(actual) = schema.get_column(&expected.name) else {
+                observations.push(Self::violation(
+                    &expected.name,
+                    Severity::Error,
+                    format!("Required column '{}' is missing", expected.name),
+                    Evidence::new().with_expected(json!(expected.name)),
+                ));
+                continue;
+            };
+
+            if actual.inferred_type != expected.inferred_type {
+                observations.push(Self::violation(
+                    &expected.name,
+                    Severity::Error,
+                    format!(
+                        "Type changed from {:?} to {:?}",
+                        expected.inferred_type, actual.inferred_type
+                    ),
+                    Evidence::new()
+                        .with_value(format!("{:?}", actual.inferred_type))
+                        .with_expected(format!("{:?}", expected.inferred_type)),
+                ));
+            }
+
+            if expected.semantic_role != SemanticRole::Unknown
+                && actual.semantic_role != expected.semantic_role
+            {
+                observations.push(Self::violation(
+                    &expected.name,
+                    Severity::Warning,
+                    format!(
+                        "Semantic role changed from {:?} to {:?}",
+                        expected.semantic_role, actual.semantic_role
+                    ),
+                    Evidence::new()
+                        .with_value(format!("{:?}", actual.semantic_role))
+                        .with_expected(format!("{:?}", expected.semantic_role)),
+                ));
+            }
+
+            let mut col = expected.clone();
+            col.position = actual.position;
+            located.columns.push(col);
+        }
+
+        // Contract constraints checked against the actual values
+        for report in ConstraintReport::for_table(table, &located) {
+            if !report.is_violated() {
+                continue;
+            }
+
+            observations.push(Self::violation(
+                &report.column,
+                Severity::Error,
+                format!(
+                    "{} values ({:.1}%) violate contract constraint {:?}",
+                    report.violations,
+                    report.violation_rate * 100.0,
+                    report.constraint
+                ),
+                Evidence::new()
+                    .with_occurrences(report.violations)
+                    .with_percentage(report.violation_rate * 100.0)
+                    .with_sample_rows(report.sample_rows)
+                    .with_expected(json!(report.constraint)),
+            ));
+        }
+
+        observations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{ColumnSchema, ColumnType, Constraint};
+
+    fn column(name: &str, position: usize, typ: ColumnType) -> ColumnSchema {
+        let mut col = ColumnSchema::new(name, position);
+        col.inferred_type = typ;
+        col
+    }
+
+    #[test]
+    fn test_contract_deviations() {
+        let table = DataTable::new(
+            vec!["age".to_string(), "sample_id".to_string(), "extra".to_string()],
+            vec![
+                vec!["30".to_string(), "S1".to_string(), "x".to_string()],
+                vec!["300".to_string(), "S2".to_string(), "y".to_string()],
+            ],
+            b',',
+        );
+        let schema = TableSchema::with_columns(vec![
+            column("age", 0, ColumnType::Integer),
+            column("sample_id", 1, ColumnType::String),
+            column("extra", 2, ColumnType::String),
+        ]);
+
+        let mut age = column("age", 1, ColumnType::Integer);
+        age.constraints = vec![Constraint::Range {
+            min: Some(0.0),
+            max: Some(120.0),
+            confidence: 0.9,
+        }];
+        let contract = TableSchema::with_columns(vec![
+            column("sample_id", 0, ColumnType::String),
+            age,
+            column("group", 2, ColumnType::String),
+        ]);
+
+        let observations = ContractValidator::new(contract).validate(&table, &schema);
+        let by_column = |name: &str| observations.iter().filter(|o| o.column == name).count();
+
+        assert!(observations
+            .iter()
+            .all(|o| o.observation_type == ObservationType::ContractViolation));
+        assert_eq!(by_column("extra"), 1);
+        assert_eq!(by_column("group"), 1);
+        assert_eq!(by_column("sample_id"), 0);
+
+        let age_obs: Vec<_> = observations.iter().filter(|o| o.column == "age").collect();
+        assert_eq!(age_obs.len(), 1);
+        assert_eq!(age_obs[0].evidence.sample_rows, vec![1]);
+    }
+}
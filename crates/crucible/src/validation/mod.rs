@@ -1,10 +1,23 @@
 //! Validation engine for detecting data quality issues.
 
+mod calibration;
+mod cohort;
+mod contract;
 mod observation;
+mod profile;
 mod validators;
 
-pub use observation::{Evidence, Observation, ObservationType, Severity};
+pub use calibration::{CONFIDENCE_BUCKETS, ConfidenceHistogram, TypeConfidence};
+pub use cohort::{
+    COHORT_COLUMN, CohortMember, CohortReport, MissingSubjects, TypeDrift, ValueDrift,
+};
+pub use contract::ContractValidator;
+pub use observation::{Evidence, MergedFinding, Observation, ObservationType, Severity};
+pub use profile::Profile;
 pub use validators::{
-    CompletenessValidator, ConsistencyValidator, MissingPatternValidator, RangeValidator,
-    SetValidator, TypeValidator, UniquenessValidator, ValidationEngine, Validator,
+    CanonicalLabel, CompletenessValidator, ConsistencyValidator, ConstantColumnValidator, CrossColumnValidator, DecimalMark, EncodingValidator, FormattedNumberValidator, HighCardinalityValidator, IdentifierNullValidator, MissingPatternValidator,
+    OutlierMethod, Parallelism, PiiValidator, PrecisionValidator, RangeValidator, ReferentialIntegrityValidator, SemanticEquivalenceValidator, SetValidator, StatisticalOutlierValidator, TypeValidator, UniquenessValidator, UnitNormalizationValidator,
+    ValidationEngine, ValidationEngineBuilder, Validator, WhitespaceValidator, BOOLEAN_FALSE_VALUES, BOOLEAN_TRUE_VALUES, CURRENCY_SYMBOLS, parse_boolean, parse_currency, parse_grouped_number, parse_percentage,
+    header_rename_observations, unmatched_vocabulary_observations,
 };
+pub(crate) use validators::levenshtein_distance;
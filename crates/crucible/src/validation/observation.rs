@@ -30,6 +30,20 @@ pub enum ObservationType {
     PatternViolation,
     /// Logical inconsistency between related columns.
     CrossColumnInconsistency,
+    /// Deviation from a frozen schema contract.
+    ContractViolation,
+    /// A check that was not run (e.g. on a sampled table).
+    SkippedCheck,
+    /// Measurements recorded in a different unit than intended.
+    NonStandardUnit,
+    /// Mis-decoded text (mojibake or replacement characters).
+    Encoding,
+    /// Disagreement between the files of a multi-timepoint cohort.
+    CohortInconsistency,
+    /// Structural problem with the table itself (e.g. duplicate or empty headers).
+    SchemaIssue,
+    /// Personal identifiers (emails, phone numbers, SSNs, MRNs) in the data.
+    SensitiveData,
 }
 
 impl ObservationType {
@@ -47,6 +61,13 @@ impl ObservationType {
             ObservationType::CrossColumn => "Cross-Column Issue",
             ObservationType::PatternViolation => "Pattern Violation",
             ObservationType::CrossColumnInconsistency => "Cross-Column Inconsistency",
+            ObservationType::ContractViolation => "Contract Violation",
+            ObservationType::SkippedCheck => "Skipped Check",
+            ObservationType::NonStandardUnit => "Non-standard Unit",
+            ObservationType::Encoding => "Encoding Issue",
+            ObservationType::CohortInconsistency => "Cohort Inconsistency",
+            ObservationType::SchemaIssue => "Schema Issue",
+            ObservationType::SensitiveData => "Sensitive Data",
         }
     }
 }
@@ -92,6 +113,9 @@ pub struct Evidence {
     /// Sample row indices.
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub sample_rows: Vec<usize>,
+    /// Every affected row index, for issues whose fix rewrites them all.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub rows: Vec<usize>,
     /// Expected value or range.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expected: Option<Value>,
@@ -101,6 +125,20 @@ pub struct Evidence {
     /// Z-score for outliers.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub z_score: Option<f64>,
+    /// Findings combined into this one, when duplicates were merged.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub merged_from: Vec<MergedFinding>,
+}
+
+/// One detector's finding folded into a merged observation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MergedFinding {
+    /// Detector that reported the finding.
+    pub detector: String,
+    /// Its description.
+    pub description: String,
+    /// Its confidence.
+    pub confidence: f64,
 }
 
 impl Evidence {
@@ -112,9 +150,11 @@ impl Evidence {
             occurrences: None,
             percentage: None,
             sample_rows: Vec::new(),
+            rows: Vec::new(),
             expected: None,
             value_counts: None,
             z_score: None,
+            merged_from: Vec::new(),
         }
     }
 
@@ -148,6 +188,12 @@ impl Evidence {
         self
     }
 
+    /// Set every affected row.
+    pub fn with_rows(mut self, rows: Vec<usize>) -> Self {
+        self.rows = rows;
+        self
+    }
+
     /// Set expected value.
     pub fn with_expected(mut self, expected: impl Into<Value>) -> Self {
         self.expected = Some(expected.into());
@@ -195,6 +241,9 @@ pub struct Observation {
     pub detected_at: DateTime<Utc>,
     /// What detected this issue.
     pub detector: String,
+    /// Inferred and policy severity, when a severity policy changed it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity_override: Option<(Severity, Severity)>,
     /// LLM-generated explanation (when available).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub llm_explanation: Option<String>,
@@ -218,6 +267,7 @@ impl Observation {
             confidence: 0.0,
             detected_at: Utc::now(),
             detector: String::new(),
+            severity_override: None,
             llm_explanation: None,
         }
     }
@@ -242,7 +292,7 @@ impl Observation {
 }
 
 /// Generate a unique observation ID.
-fn generate_observation_id() -> String {
+pub(super) fn generate_observation_id() -> String {
     use std::sync::atomic::{AtomicU64, Ordering};
     static COUNTER: AtomicU64 = AtomicU64::new(1);
     format!("obs_{:03}", COUNTER.fetch_add(1, Ordering::SeqCst))
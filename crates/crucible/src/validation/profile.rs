@@ -0,0 +1,101 @@
+//! Strictness presets for validation.
+
+use serde::{Deserialize, Serialize};
+
+/// A preset of validator thresholds and checks.
+///
+/// `Lenient` suits exploratory data, `Strict` submission-ready datasets.
+/// The profile used is recorded with the analysis and the curation layer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Profile {
+    /// Higher thresholds and fewer stylistic checks.
+    Lenient,
+    /// The default thresholds.
+    #[default]
+    Standard,
+    /// Lower thresholds, warnings scored as errors, and MIxS findings blocking.
+    Strict,
+}
+
+impl Profile {
+    /// Get a human-readable label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Profile::Lenient => "lenient",
+            Profile::Standard => "standard",
+            Profile::Strict => "strict",
+        }
+    }
+
+    /// Percentages of missing values at which a column gets a completeness
+    /// warning and error.
+    pub fn completeness_thresholds(&self) -> (f64, f64) {
+        match self {
+            Profile::Lenient => (20.0, 50.0),
+            Profile::Standard => (5.0, 20.0),
+            Profile::Strict => (1.0, 5.0),
+        }
+    }
+
+    /// IQR multiplier for statistical outliers.
+    pub fn iqr_multiplier(&self) -> f64 {
+        match self {
+            Profile::Lenient => 3.0,
+            Profile::Standard => 1.5,
+            Profile::Strict => 1.0,
+        }
+    }
+
+    /// Maximum edit distance between a value and the one it's a typo of.
+    ///
+    /// `Lenient` leaves the typo validator out, so only `Strict` differs
+    /// from the default.
+    pub fn typo_distance(&self) -> usize {
+        match self {
+            Profile::Lenient | Profile::Standard => 2,
+            Profile::Strict => 3,
+        }
+    }
+
+    /// Validators left out, by detector name.
+    pub fn disabled_validators(&self) -> &'static [&'static str] {
+        match self {
+            Profile::Lenient => &["title_case_validator", "typo_validator"],
+            Profile::Standard | Profile::Strict => &[],
+        }
+    }
+
+    /// Whether warnings are scored as errors.
+    pub fn scores_warnings_as_errors(&self) -> bool {
+        *self == Profile::Strict
+    }
+
+    /// Whether every MIxS finding (missing or malformed fields) blocks
+    /// submission, and so is reported as an error.
+    pub fn mixs_blocking(&self) -> bool {
+        *self == Profile::Strict
+    }
+}
+
+impl std::fmt::Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+impl std::str::FromStr for Profile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "lenient" => Ok(Profile::Lenient),
+            "standard" => Ok(Profile::Standard),
+            "strict" => Ok(Profile::Strict),
+            _ => Err(format!(
+                "Unknown profile: {}. Use lenient, standard, or strict.",
+                s
+            )),
+        }
+    }
+}
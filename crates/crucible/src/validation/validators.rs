@@ -1,27 +1,219 @@
 //! Validators for checking data against inferred schema.
 
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
 use indexmap::IndexMap;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 
-use crate::input::DataTable;
-use crate::schema::{ColumnSchema, ColumnType, Constraint, SemanticRole, TableSchema};
+use crate::error::{CrucibleError, Result};
+use crate::inference::{DEFAULT_CATEGORICAL_MAX_RATIO, DEFAULT_CATEGORICAL_THRESHOLD, exceeds_distinct_ratio};
+use crate::input::{ContextHints, DataTable, HeaderRename};
+use crate::llm::LlmProvider;
+use crate::progress::{ProgressCallback, ProgressEvent};
+use crate::schema::{ColumnSchema, ColumnType, Constraint, SemanticRole, SemanticType, TableSchema};
+use crate::transform::DateOrder;
+
+use super::observation::{
+    Evidence, MergedFinding, Observation, ObservationType, Severity, generate_observation_id,
+};
+use super::profile::Profile;
+
+/// Lowercase spellings accepted as boolean true.
+pub const BOOLEAN_TRUE_VALUES: &[&str] = &["true", "yes", "t", "y", "1"];
+
+/// Lowercase spellings accepted as boolean false.
+pub const BOOLEAN_FALSE_VALUES: &[&str] = &["false", "no", "f", "n", "0"];
+
+/// Parse a boolean spelling (case-insensitive, surrounding whitespace ignored).
+pub fn parse_boolean(value: &str) -> Option<bool> {
+    let lower = value.trim().to_lowercase();
+    if BOOLEAN_TRUE_VALUES.contains(&lower.as_str()) {
+        Some(true)
+    } else if BOOLEAN_FALSE_VALUES.contains(&lower.as_str()) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Currency symbols recognized by [`parse_currency`].
+pub const CURRENCY_SYMBOLS: &[char] = &['$', '€', '£', '¥'];
+
+/// Parse a money amount such as `$1,234.56`, `-$5` or `12.50 €` into plain
+/// number text (`1234.56`, `-5`, `12.50`).
+///
+/// A currency symbol is required. Thousands separators must group digits in
+/// threes, so `1,5` is not read as a number.
+pub fn parse_currency(value: &str) -> Option<String> {
+    let trimmed = value.trim();
+    let (negative, rest) = match trimmed.strip_prefix('-') {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, trimmed),
+    };
+    let rest = rest
+        .strip_prefix(CURRENCY_SYMBOLS)
+        .or_else(|| rest.strip_suffix(CURRENCY_SYMBOLS))?
+        .trim();
+    let (negative, rest) = match rest.strip_prefix('-') {
+        Some(rest) if !negative => (true, rest),
+        _ => (negative, rest),
+    };
+
+    let number = parse_grouped_number(rest, DecimalMark::Point).filter(|n| !n.starts_with('-'))?;
+    Some(if negative { format!("-{}", number) } else { number })
+}
+
+/// Parse a percentage such as `45%` or `12.5 %` into a fraction (`0.45`,
+/// `0.125`).
+pub fn parse_percentage(value: &str) -> Option<String> {
+    let rest = value.trim().strip_suffix('%')?.trim_end();
+    let (negative, rest) = match rest.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, rest),
+    };
+
+    let number = parse_grouped_number(rest, DecimalMark::Point).filter(|n| !n.starts_with('-'))?;
+    let percent: f64 = number.parse().ok()?;
+    // Dividing by 100 adds two decimal places; keep exactly those
+    let decimals = number.split_once('.').map_or(0, |(_, frac)| frac.len()) + 2;
+    let fraction = format!("{:.*}", decimals, percent / 100.0);
+    let fraction = fraction.trim_end_matches('0').trim_end_matches('.');
+
+    Some(if negative && fraction != "0" {
+        format!("-{}", fraction)
+    } else {
+        fraction.to_string()
+    })
+}
+
+/// The character a dataset uses to mark decimals in numbers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecimalMark {
+    /// `1,234.56`: a decimal point, with commas or spaces grouping thousands.
+    #[default]
+    Point,
+    /// `1.234,56`: a decimal comma, with points or spaces grouping thousands.
+    Comma,
+}
+
+impl DecimalMark {
+    /// The character that separates the whole and fractional parts.
+    pub fn as_char(self) -> char {
+        match self {
+            DecimalMark::Point => '.',
+            DecimalMark::Comma => ',',
+        }
+    }
+}
+
+/// Characters grouping thousands besides the mark's own separator: plain,
+/// non-breaking and narrow non-breaking spaces.
+const THOUSANDS_SPACES: &[char] = &[' ', '\u{a0}', '\u{202f}'];
+
+/// Parse a number written with thousands separators, such as `1,234,567`,
+/// `1 234.5` or (with a decimal comma) `1.234,56`, into plain number text
+/// (`1234567`, `1234.5`, `1234.56`).
+///
+/// Groups must be exactly three digits, so `1,5` is not read as a number
+/// with a decimal point. Numbers without separators are accepted too and
+/// returned with a `.` decimal point; a plain `12.5` is unchanged, while
+/// with a decimal comma `12,5` becomes `12.5`.
+pub fn parse_grouped_number(value: &str, decimal_mark: DecimalMark) -> Option<String> {
+    let trimmed = value.trim();
+    let (negative, rest) = match trimmed.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, trimmed),
+    };
+    let (whole, fraction) = match rest.split_once(decimal_mark.as_char()) {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (rest, None),
+    };
+
+    let digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    let group_separator = match decimal_mark {
+        DecimalMark::Point => ',',
+        DecimalMark::Comma => '.',
+    };
+    // One separator throughout, whichever appears first
+    let separator = whole
+        .chars()
+        .find(|&c| c == group_separator || THOUSANDS_SPACES.contains(&c));
+    let whole = match separator {
+        Some(separator) => {
+            let mut groups = whole.split(separator);
+            let first = groups.next()?;
+            if !digits(first) || first.len() > 3 || !groups.all(|g| g.len() == 3 && digits(g)) {
+                return None;
+            }
+            whole.replace(separator, "")
+        }
+        None if digits(whole) => whole.to_string(),
+        None => return None,
+    };
+    if fraction.is_some_and(|f| !digits(f)) {
+        return None;
+    }
 
-use super::observation::{Evidence, Observation, ObservationType, Severity};
+    let mut number = if negative { format!("-{}", whole) } else { whole };
+    if let Some(fraction) = fraction {
+        number.push('.');
+        number.push_str(fraction);
+    }
+    Some(number)
+}
 
 /// Trait for validators.
-pub trait Validator {
+///
+/// Validators are read-only over the table and schema, so the engine may
+/// run them on several threads at once. That is why the trait requires
+/// `Send + Sync`; validators holding non-thread-safe state (such as
+/// `Rc` or `RefCell`) must switch to `Arc` or `Mutex`.
+pub trait Validator: Send + Sync {
+    /// Detector name, used to enable or disable this validator.
+    ///
+    /// By convention this matches the `detector` of the observations it
+    /// produces, e.g. `"typo_validator"`. Defaults to the type name, so
+    /// validators written before names existed keep compiling.
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
     /// Run validation and return observations.
     fn validate(
         &self,
         table: &DataTable,
         schema: &TableSchema,
     ) -> Vec<Observation>;
+
+    /// Name of the whole-table check this validator performs, if any.
+    ///
+    /// Such checks are skipped on sampled tables, since a sample can't show
+    /// that values are unique or rows aren't duplicated.
+    fn full_table_check(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 /// Validates that values match their inferred type.
-pub struct TypeValidator;
+///
+/// Numbers with thousands separators (see [`parse_grouped_number`]) match
+/// numeric types; the [`FormattedNumberValidator`] reports them instead.
+#[derive(Debug, Clone, Default)]
+pub struct TypeValidator {
+    /// Decimal mark numbers are written with.
+    decimal_mark: DecimalMark,
+}
 
 impl Validator for TypeValidator {
+    fn name(&self) -> &'static str {
+        "type_validator"
+    }
+
     fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
@@ -59,6 +251,12 @@ impl Validator for TypeValidator {
 }
 
 impl TypeValidator {
+    /// Read numbers as written with this decimal mark.
+    pub fn with_decimal_mark(mut self, decimal_mark: DecimalMark) -> Self {
+        self.decimal_mark = decimal_mark;
+        self
+    }
+
     fn find_type_mismatches(&self, table: &DataTable, col_schema: &ColumnSchema) -> Vec<usize> {
         let mut mismatches = Vec::new();
 
@@ -67,13 +265,14 @@ impl TypeValidator {
                 continue;
             }
 
+            let grouped = || parse_grouped_number(value, self.decimal_mark);
             let matches = match col_schema.inferred_type {
-                ColumnType::Integer => value.trim().parse::<i64>().is_ok(),
-                ColumnType::Float => value.trim().parse::<f64>().is_ok(),
-                ColumnType::Boolean => matches!(
-                    value.trim().to_lowercase().as_str(),
-                    "true" | "false" | "yes" | "no" | "t" | "f" | "y" | "n" | "1" | "0"
-                ),
+                ColumnType::Integer => {
+                    value.trim().parse::<i64>().is_ok()
+                        || grouped().is_some_and(|n| n.parse::<i64>().is_ok())
+                }
+                ColumnType::Float => value.trim().parse::<f64>().is_ok() || grouped().is_some(),
+                ColumnType::Boolean => parse_boolean(value).is_some(),
                 ColumnType::String => true, // Strings always match
                 _ => true,
             };
@@ -87,10 +286,177 @@ impl TypeValidator {
     }
 }
 
+/// Flags currency and percentage columns stored as text, and numbers
+/// written with thousands separators.
+///
+/// Columns inferred as [`SemanticType::Currency`] or
+/// [`SemanticType::Percentage`] are reported as type mismatches against
+/// `Float`, with a few before/after examples so the values can be cleaned
+/// and coerced. Integer and Float columns with values like `1,234,567` are
+/// reported the same way, so the separators can be stripped.
+#[derive(Debug, Clone, Default)]
+pub struct FormattedNumberValidator {
+    /// Decimal mark numbers are written with.
+    decimal_mark: DecimalMark,
+}
+
+impl Validator for FormattedNumberValidator {
+    fn name(&self) -> &'static str {
+        "formatted_number_validator"
+    }
+
+    fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
+        schema
+            .columns
+            .iter()
+            .filter_map(|col| match col.inferred_type {
+                ColumnType::String => self.check_column(table, col),
+                ColumnType::Integer | ColumnType::Float => self.check_grouped_column(table, col),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl FormattedNumberValidator {
+    /// Read numbers as written with this decimal mark, so `1.234` is a
+    /// thousand and more with [`DecimalMark::Comma`].
+    pub fn with_decimal_mark(mut self, decimal_mark: DecimalMark) -> Self {
+        self.decimal_mark = decimal_mark;
+        self
+    }
+
+    /// Report numeric values written with thousands separators (or, with a
+    /// decimal comma, a comma for the decimal point).
+    fn check_grouped_column(&self, table: &DataTable, col_schema: &ColumnSchema) -> Option<Observation> {
+        let mut rows = Vec::new();
+        let mut examples = IndexMap::new();
+        for (row_idx, value) in table.column_values(col_schema.position).enumerate() {
+            match parse_grouped_number(value, self.decimal_mark) {
+                Some(cleaned) if cleaned != value.trim() => {
+                    rows.push(row_idx);
+                    if examples.len() < 3 {
+                        examples.insert(value.trim().to_string(), cleaned);
+                    }
+                }
+                _ => {}
+            }
+        }
+        if rows.is_empty() {
+            return None;
+        }
+
+        let count = rows.len();
+        let pct = (count as f64 / table.row_count() as f64) * 100.0;
+        let notation = match self.decimal_mark {
+            DecimalMark::Point => "thousands separators",
+            DecimalMark::Comma => "thousands separators or a decimal comma",
+        };
+
+        Some(
+            Observation::new(
+                ObservationType::TypeMismatch,
+                Severity::Warning,
+                &col_schema.name,
+                format!(
+                    "{} values ({:.1}%) are written with {}, e.g. {:?}; strip them so the column reads as {:?}",
+                    count,
+                    pct,
+                    notation,
+                    examples.keys().collect::<Vec<_>>(),
+                    col_schema.inferred_type
+                ),
+            )
+            .with_evidence(
+                Evidence::new()
+                    .with_occurrences(count)
+                    .with_percentage(pct)
+                    .with_sample_rows(rows.into_iter().take(5).collect())
+                    .with_expected(json!({
+                        "target_type": format!("{:?}", col_schema.inferred_type),
+                        "format": "grouped",
+                        "decimal_mark": self.decimal_mark,
+                        "examples": examples,
+                    })),
+            )
+            .with_confidence(0.85)
+            .with_detector("formatted_number_validator"),
+        )
+    }
+
+    fn check_column(&self, table: &DataTable, col_schema: &ColumnSchema) -> Option<Observation> {
+        let (parse, format, label): (fn(&str) -> Option<String>, _, _) =
+            match col_schema.semantic_type {
+                SemanticType::Currency => (parse_currency, "currency", "currency amounts"),
+                SemanticType::Percentage => (parse_percentage, "percentage", "percentages"),
+                _ => return None,
+            };
+
+        let mut rows = Vec::new();
+        // Rows coercion rewrites: the formatted values, text that isn't a
+        // number (it becomes NA), and in a percentage column plain numbers,
+        // which are put on the same scale
+        let mut affected = Vec::new();
+        let mut examples = IndexMap::new();
+        for (row_idx, value) in table.column_values(col_schema.position).enumerate() {
+            if let Some(cleaned) = parse(value) {
+                rows.push(row_idx);
+                affected.push(row_idx);
+                if examples.len() < 3 {
+                    examples.insert(value.trim().to_string(), cleaned);
+                }
+            } else if !DataTable::is_null_value(value.trim())
+                && (format == "percentage" || value.trim().parse::<f64>().is_err())
+            {
+                affected.push(row_idx);
+            }
+        }
+        if rows.is_empty() {
+            return None;
+        }
+
+        let count = rows.len();
+        let pct = (count as f64 / table.row_count() as f64) * 100.0;
+
+        Some(
+            Observation::new(
+                ObservationType::TypeMismatch,
+                Severity::Warning,
+                &col_schema.name,
+                format!(
+                    "{} values ({:.1}%) are {} stored as text, e.g. {:?}",
+                    count,
+                    pct,
+                    label,
+                    examples.keys().collect::<Vec<_>>()
+                ),
+            )
+            .with_evidence(
+                Evidence::new()
+                    .with_occurrences(count)
+                    .with_percentage(pct)
+                    .with_sample_rows(rows.into_iter().take(5).collect())
+                    .with_rows(affected)
+                    .with_expected(json!({
+                        "target_type": "Float",
+                        "format": format,
+                        "examples": examples,
+                    })),
+            )
+            .with_confidence(0.85)
+            .with_detector("formatted_number_validator"),
+        )
+    }
+}
+
 /// Validates that numeric values are within expected range.
 pub struct RangeValidator;
 
 impl Validator for RangeValidator {
+    fn name(&self) -> &'static str {
+        "range_validator"
+    }
+
     fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
@@ -129,7 +495,8 @@ impl Validator for RangeValidator {
                         Evidence::new()
                             .with_occurrences(count)
                             .with_percentage(pct)
-                            .with_sample_rows(out_of_range.into_iter().take(5).collect())
+                            .with_sample_rows(out_of_range.iter().take(5).copied().collect())
+                            .with_rows(out_of_range)
                             .with_expected(json!({
                                 "min": min,
                                 "max": max
@@ -176,9 +543,15 @@ impl RangeValidator {
 }
 
 /// Validates that categorical values are in the expected set.
+///
+/// Each item of a list column is checked on its own.
 pub struct SetValidator;
 
 impl Validator for SetValidator {
+    fn name(&self) -> &'static str {
+        "set_validator"
+    }
+
     fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
@@ -242,9 +615,10 @@ impl SetValidator {
                 continue;
             }
 
-            let trimmed = value.trim();
-            if !expected.iter().any(|e| e == trimmed) {
-                unexpected.push((row_idx, trimmed.to_string()));
+            for item in col_schema.inferred_type.items(value) {
+                if !expected.iter().any(|e| e == item) {
+                    unexpected.push((row_idx, item.to_string()));
+                }
             }
         }
 
@@ -256,6 +630,10 @@ impl SetValidator {
 pub struct UniquenessValidator;
 
 impl Validator for UniquenessValidator {
+    fn name(&self) -> &'static str {
+        "uniqueness_validator"
+    }
+
     fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
@@ -303,6 +681,10 @@ impl Validator for UniquenessValidator {
 
         observations
     }
+
+    fn full_table_check(&self) -> Option<&'static str> {
+        Some("uniqueness")
+    }
 }
 
 impl UniquenessValidator {
@@ -347,7 +729,20 @@ impl Default for CompletenessValidator {
     }
 }
 
+impl CompletenessValidator {
+    /// Set the percentages of missing values that raise a warning and an error.
+    pub fn with_thresholds(mut self, warning: f64, error: f64) -> Self {
+        self.warning_threshold = warning;
+        self.error_threshold = error;
+        self
+    }
+}
+
 impl Validator for CompletenessValidator {
+    fn name(&self) -> &'static str {
+        "completeness_validator"
+    }
+
     fn validate(&self, _table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
@@ -370,7 +765,7 @@ impl Validator for CompletenessValidator {
                 )
                 .with_evidence(
                     Evidence::new()
-                        .with_occurrences(col_schema.statistics.null_count)
+                        .with_occurrences(col_schema.missing_count())
                         .with_percentage(null_pct),
                 )
                 .with_confidence(0.95)
@@ -388,13 +783,54 @@ impl Validator for CompletenessValidator {
 }
 
 /// Validates for inconsistencies (case variations, format variations).
+///
+/// In list columns, casing is compared item by item.
 pub struct ConsistencyValidator;
 
 impl Validator for ConsistencyValidator {
+    fn name(&self) -> &'static str {
+        "consistency_validator"
+    }
+
     fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
         for col_schema in &schema.columns {
+            // Check for items of a list spelled in different cases
+            if col_schema.inferred_type.item_delimiter().is_some() {
+                let groups = self.find_item_case_variants(table, col_schema);
+                if !groups.is_empty() {
+                    let count = self.count_rows_with_items(table, col_schema, &groups);
+                    let pct = (count as f64 / table.row_count() as f64) * 100.0;
+                    let examples: Vec<String> = groups
+                        .values()
+                        .take(3)
+                        .map(|variants| format!("{:?}", variants.keys().collect::<Vec<_>>()))
+                        .collect();
+
+                    let obs = Observation::new(
+                        ObservationType::Inconsistency,
+                        Severity::Warning,
+                        &col_schema.name,
+                        format!(
+                            "Case variants detected in list items: {}",
+                            examples.join(", ")
+                        ),
+                    )
+                    .with_evidence(
+                        Evidence::new()
+                            .with_occurrences(count)
+                            .with_percentage(pct)
+                            .with_value_counts(Some(json!(groups))),
+                    )
+                    .with_confidence(0.88)
+                    .with_detector("consistency_validator");
+
+                    observations.push(obs);
+                }
+                continue;
+            }
+
             // Check for case inconsistencies in categorical columns
             if let Some(ref expected) = col_schema.expected_values {
                 let case_issues = self.find_case_inconsistencies(table, col_schema, expected);
@@ -439,7 +875,8 @@ impl Validator for ConsistencyValidator {
                     )
                     .with_evidence(
                         Evidence::new()
-                            .with_value_counts(Some(json!(bool_variants))),
+                            .with_value_counts(Some(json!(bool_variants)))
+                            .with_expected(json!({ "type": "boolean" })),
                     )
                     .with_confidence(0.92)
                     .with_detector("consistency_validator");
@@ -480,6 +917,52 @@ impl ConsistencyValidator {
         variants
     }
 
+    /// Group a list column's items by lowercase form, keeping groups with
+    /// more than one spelling.
+    fn find_item_case_variants(
+        &self,
+        table: &DataTable,
+        col_schema: &ColumnSchema,
+    ) -> IndexMap<String, IndexMap<String, usize>> {
+        let mut groups: IndexMap<String, IndexMap<String, usize>> = IndexMap::new();
+
+        for value in table.column_values(col_schema.position) {
+            if DataTable::is_null_value(value) {
+                continue;
+            }
+            for item in col_schema.inferred_type.items(value) {
+                *groups
+                    .entry(item.to_lowercase())
+                    .or_default()
+                    .entry(item.to_string())
+                    .or_insert(0) += 1;
+            }
+        }
+
+        groups.retain(|_, variants| variants.len() > 1);
+        groups
+    }
+
+    /// Count the rows of a list column with an item in any of the groups.
+    fn count_rows_with_items(
+        &self,
+        table: &DataTable,
+        col_schema: &ColumnSchema,
+        groups: &IndexMap<String, IndexMap<String, usize>>,
+    ) -> usize {
+        table
+            .column_values(col_schema.position)
+            .filter(|value| !DataTable::is_null_value(value))
+            .filter(|value| {
+                col_schema
+                    .inferred_type
+                    .items(value)
+                    .iter()
+                    .any(|item| groups.contains_key(&item.to_lowercase()))
+            })
+            .count()
+    }
+
     fn find_boolean_variants(
         &self,
         table: &DataTable,
@@ -500,23 +983,33 @@ impl ConsistencyValidator {
 
 /// Validates for non-standard missing value patterns.
 pub struct MissingPatternValidator {
-    /// Common patterns that represent missing values.
-    patterns: Vec<&'static str>,
+    /// Patterns (lowercase) that represent missing values.
+    patterns: Vec<String>,
+    /// Minimum occurrences before a pattern is reported.
+    min_occurrences: usize,
 }
 
 impl Default for MissingPatternValidator {
     fn default() -> Self {
         Self {
-            patterns: vec![
+            patterns: [
                 "missing", "unknown", "not available", "not recorded",
                 "n.a.", "n.a", "na.", "#n/a", "#null", "undefined",
                 "-999", "-9999", "999", "9999",
-            ],
+            ]
+            .iter()
+            .map(|p| p.to_string())
+            .collect(),
+            min_occurrences: 2,
         }
     }
 }
 
 impl Validator for MissingPatternValidator {
+    fn name(&self) -> &'static str {
+        "missing_pattern_validator"
+    }
+
     fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
@@ -553,6 +1046,31 @@ impl Validator for MissingPatternValidator {
 }
 
 impl MissingPatternValidator {
+    /// Replace the default patterns with a custom set.
+    ///
+    /// Patterns are matched case-insensitively against trimmed values.
+    pub fn with_patterns(mut self, patterns: Vec<String>) -> Self {
+        self.patterns = Vec::new();
+        self.with_additional_patterns(patterns)
+    }
+
+    /// Add patterns on top of the current set (e.g. lab sentinels like `ND`).
+    pub fn with_additional_patterns(mut self, patterns: Vec<String>) -> Self {
+        for pattern in patterns {
+            let pattern = pattern.trim().to_lowercase();
+            if !pattern.is_empty() && !self.patterns.contains(&pattern) {
+                self.patterns.push(pattern);
+            }
+        }
+        self
+    }
+
+    /// Set how many times a pattern must appear before it is reported.
+    pub fn with_min_occurrences(mut self, min_occurrences: usize) -> Self {
+        self.min_occurrences = min_occurrences.max(1);
+        self
+    }
+
     fn find_missing_patterns(
         &self,
         table: &DataTable,
@@ -561,18 +1079,24 @@ impl MissingPatternValidator {
         let mut pattern_counts: IndexMap<String, usize> = IndexMap::new();
 
         for value in table.column_values(col_schema.position) {
+            // Tokens detected for this column are reported as spelled
+            if col_schema.is_null_token(value) {
+                *pattern_counts.entry(value.trim().to_string()).or_insert(0) += 1;
+                continue;
+            }
+
             let lower = value.trim().to_lowercase();
-            for &pattern in &self.patterns {
-                if lower == pattern {
-                    *pattern_counts.entry(pattern.to_string()).or_insert(0) += 1;
+            for pattern in &self.patterns {
+                if lower == *pattern {
+                    *pattern_counts.entry(pattern.clone()).or_insert(0) += 1;
                 }
             }
         }
 
-        // Only report patterns that appear multiple times
+        // Only report patterns that appear often enough
         pattern_counts
             .into_iter()
-            .filter(|(_, count)| *count >= 2)
+            .filter(|(_, count)| *count >= self.min_occurrences)
             .collect()
     }
 }
@@ -582,6 +1106,10 @@ impl MissingPatternValidator {
 pub struct IdentifierDuplicateValidator;
 
 impl Validator for IdentifierDuplicateValidator {
+    fn name(&self) -> &'static str {
+        "identifier_duplicate_validator"
+    }
+
     fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         use crate::schema::SemanticRole;
         let mut observations = Vec::new();
@@ -629,6 +1157,10 @@ impl Validator for IdentifierDuplicateValidator {
 
         observations
     }
+
+    fn full_table_check(&self) -> Option<&'static str> {
+        Some("identifier duplicates")
+    }
 }
 
 impl IdentifierDuplicateValidator {
@@ -656,91 +1188,499 @@ impl IdentifierDuplicateValidator {
     }
 }
 
-/// Validates for statistical outliers using IQR method and domain knowledge.
-pub struct StatisticalOutlierValidator {
-    /// IQR multiplier for outlier detection (typically 1.5 for mild, 3.0 for extreme).
-    iqr_multiplier: f64,
-}
+/// Validates that identifier columns have no missing values.
+/// A record without an ID can't be referenced, so any null is an error.
+pub struct IdentifierNullValidator;
 
-impl Default for StatisticalOutlierValidator {
-    fn default() -> Self {
-        Self {
-            iqr_multiplier: 1.5,
-        }
+impl Validator for IdentifierNullValidator {
+    fn name(&self) -> &'static str {
+        "identifier_null_validator"
     }
-}
 
-impl Validator for StatisticalOutlierValidator {
     fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
         for col_schema in &schema.columns {
-            if !col_schema.inferred_type.is_numeric() {
+            if col_schema.semantic_role != SemanticRole::Identifier {
                 continue;
             }
 
-            // Check for statistical outliers using IQR
-            if let Some(ref numeric_stats) = col_schema.statistics.numeric {
-                let outliers = self.find_iqr_outliers(table, col_schema, numeric_stats);
-                if !outliers.is_empty() {
-                    let count = outliers.len();
-                    let pct = (count as f64 / table.row_count() as f64) * 100.0;
+            let null_rows: Vec<usize> = table
+                .column_values(col_schema.position)
+                .enumerate()
+                .filter(|(_, value)| DataTable::is_null_value(value))
+                .map(|(row_idx, _)| row_idx)
+                .collect();
 
-                    // Get actual outlier values for display
-                    let outlier_values: Vec<f64> = outliers
-                        .iter()
-                        .take(5)
-                        .filter_map(|(_, v)| v.parse::<f64>().ok())
-                        .collect();
+            if null_rows.is_empty() {
+                continue;
+            }
 
-                    let obs = Observation::new(
-                        ObservationType::Outlier,
-                        if count > 1 { Severity::Warning } else { Severity::Info },
-                        &col_schema.name,
-                        format!(
-                            "{} statistical outlier(s) detected (IQR method): {:?}",
-                            count, outlier_values
-                        ),
-                    )
-                    .with_evidence(
-                        Evidence::new()
-                            .with_occurrences(count)
-                            .with_percentage(pct)
-                            .with_sample_rows(outliers.iter().map(|(r, _)| *r).take(5).collect())
-                            .with_expected(json!({
-                                "q1": numeric_stats.q1,
-                                "q3": numeric_stats.q3,
-                                "iqr": numeric_stats.iqr(),
-                                "lower_bound": numeric_stats.q1 - self.iqr_multiplier * numeric_stats.iqr(),
-                                "upper_bound": numeric_stats.q3 + self.iqr_multiplier * numeric_stats.iqr()
-                            })),
-                    )
-                    .with_confidence(0.85)
-                    .with_detector("statistical_outlier_validator");
+            let count = null_rows.len();
+            let pct = (count as f64 / table.row_count() as f64) * 100.0;
 
-                    observations.push(obs);
-                }
-            }
+            let obs = Observation::new(
+                ObservationType::Completeness,
+                Severity::Error,
+                &col_schema.name,
+                format!(
+                    "Identifier column has {} missing values; records without an ID can't be referenced",
+                    count
+                ),
+            )
+            .with_evidence(
+                Evidence::new()
+                    .with_occurrences(count)
+                    .with_percentage(pct)
+                    .with_sample_rows(null_rows),
+            )
+            .with_confidence(0.95)
+            .with_detector("identifier_null_validator");
 
-            // Check for domain-specific invalid values (negative ages, weights, counts)
-            let negative_issues = self.find_invalid_negative_values(table, col_schema);
-            if !negative_issues.is_empty() {
-                let count = negative_issues.len();
-                let pct = (count as f64 / table.row_count() as f64) * 100.0;
+            observations.push(obs);
+        }
 
-                let obs = Observation::new(
-                    ObservationType::Outlier,
-                    Severity::Error,
-                    &col_schema.name,
-                    format!(
-                        "{} impossible negative value(s) in column '{}' (should be non-negative)",
-                        count, col_schema.name
-                    ),
-                )
-                .with_evidence(
-                    Evidence::new()
-                        .with_occurrences(count)
-                        .with_percentage(pct)
+        observations
+    }
+}
+
+/// Flags a blank-headed, empty last column produced by trailing delimiters.
+pub struct TrailingEmptyColumnValidator;
+
+impl Validator for TrailingEmptyColumnValidator {
+    fn name(&self) -> &'static str {
+        "trailing_empty_column_validator"
+    }
+
+    fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
+        if !table.has_phantom_trailing_column() {
+            return Vec::new();
+        }
+
+        let position = table.column_count() - 1;
+        let column = schema
+            .get_column_by_position(position)
+            .map(|c| c.name.clone())
+            .unwrap_or_default();
+        let empty = table
+            .column_values(position)
+            .filter(|v| v.trim().is_empty())
+            .count();
+        let pct = (empty as f64 / table.row_count() as f64) * 100.0;
+
+        vec![Observation::new(
+            ObservationType::Completeness,
+            Severity::Warning,
+            column,
+            format!(
+                "Last column has a blank header and is empty in {:.1}% of rows; \
+                 likely a trailing delimiter export artifact",
+                pct
+            ),
+        )
+        .with_evidence(
+            Evidence::new()
+                .with_occurrences(empty)
+                .with_percentage(pct),
+        )
+        .with_confidence(0.9)
+        .with_detector("trailing_empty_column_validator")]
+    }
+}
+
+/// Fewest non-null values for a column to be reported as constant.
+const MIN_CONSTANT_VALUES: usize = 3;
+
+/// Name parts of columns expected to differ between rows.
+const VARYING_NAME_PARTS: &[&str] = &[
+    "id", "uuid", "sample", "subject", "patient", "participant", "specimen", "barcode",
+    "accession", "batch", "run", "lane", "well", "date", "time", "timestamp",
+];
+
+/// Flags columns holding a single distinct value in every non-null row.
+///
+/// Such a column carries no information, and is often an export bug (a
+/// `batch` column filled down with one value). It is a warning when the
+/// column is an identifier or its name suggests it should vary, and info
+/// otherwise.
+pub struct ConstantColumnValidator;
+
+impl ConstantColumnValidator {
+    /// Whether a column name suggests its values should differ between rows.
+    ///
+    /// The name is split into parts at punctuation and lower-to-upper case
+    /// changes, so `sample_id`, `SampleID`, and `batch.no` all match.
+    pub fn expects_variation(name: &str) -> bool {
+        let mut parts = Vec::new();
+        let mut part = String::new();
+        let mut prev_lower = false;
+        for c in name.chars() {
+            if !c.is_alphanumeric() || (c.is_uppercase() && prev_lower) {
+                parts.push(std::mem::take(&mut part));
+            }
+            if c.is_alphanumeric() {
+                part.extend(c.to_lowercase());
+            }
+            prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        }
+        parts.push(part);
+
+        parts
+            .iter()
+            .any(|part| VARYING_NAME_PARTS.contains(&part.as_str()))
+    }
+}
+
+impl Validator for ConstantColumnValidator {
+    fn name(&self) -> &'static str {
+        "constant_column_validator"
+    }
+
+    fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
+        let mut observations = Vec::new();
+
+        for col_schema in &schema.columns {
+            let mut constant: Option<&str> = None;
+            let mut count = 0;
+            let mut varies = false;
+            for value in table.column_values(col_schema.position) {
+                if DataTable::is_null_value(value) || col_schema.is_null_token(value) {
+                    continue;
+                }
+                match constant {
+                    Some(seen) if seen != value => {
+                        varies = true;
+                        break;
+                    }
+                    Some(_) => {}
+                    None => constant = Some(value),
+                }
+                count += 1;
+            }
+            let Some(value) = constant else {
+                continue;
+            };
+            if varies || count < MIN_CONSTANT_VALUES {
+                continue;
+            }
+
+            let missing = table.row_count() - count;
+            let should_vary = col_schema.semantic_role == SemanticRole::Identifier
+                || Self::expects_variation(&col_schema.name);
+            let mut description = format!(
+                "Column has a single value '{}' in all {} non-null rows",
+                value, count
+            );
+            if missing > 0 {
+                description.push_str(&format!(" ({} row(s) missing)", missing));
+            }
+            if should_vary {
+                description.push_str("; its name suggests it should vary, so it may have been filled by mistake");
+            }
+
+            let obs = Observation::new(
+                ObservationType::Cardinality,
+                if should_vary { Severity::Warning } else { Severity::Info },
+                &col_schema.name,
+                description,
+            )
+            .with_evidence(
+                Evidence::new()
+                    .with_value(value)
+                    .with_occurrences(count)
+                    .with_percentage(count as f64 / table.row_count() as f64 * 100.0)
+                    .with_value_counts(Some(json!({ value: count }))),
+            )
+            .with_confidence(if should_vary { 0.8 } else { 0.9 })
+            .with_detector("constant_column_validator");
+
+            observations.push(obs);
+        }
+
+        observations
+    }
+}
+
+/// Flags text columns that look categorical but have nearly as many
+/// distinct values as rows.
+///
+/// Such a column is probably free text or an identifier. Inference doesn't
+/// give it a set of expected values, so it's reported instead of having
+/// every value flagged as unexpected. Columns with few enough distinct
+/// values to be categorical, and grouping columns of any size, are checked;
+/// identifiers are skipped. Both thresholds are those the schema was
+/// inferred with, so a column inference kept as categorical isn't reported.
+pub struct HighCardinalityValidator;
+
+impl Validator for HighCardinalityValidator {
+    fn name(&self) -> &'static str {
+        "high_cardinality_validator"
+    }
+
+    fn validate(&self, _table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
+        let mut observations = Vec::new();
+        let (max_distinct, max_ratio) = schema.inference.as_ref().map_or(
+            (DEFAULT_CATEGORICAL_THRESHOLD, DEFAULT_CATEGORICAL_MAX_RATIO),
+            |settings| (settings.categorical_max_distinct, settings.categorical_max_ratio),
+        );
+
+        for col_schema in &schema.columns {
+            if col_schema.inferred_type != ColumnType::String
+                || col_schema.semantic_role == SemanticRole::Identifier
+            {
+                continue;
+            }
+
+            let stats = &col_schema.statistics;
+            let value_count = stats.count - stats.null_count;
+            if !exceeds_distinct_ratio(stats.unique_count, value_count, max_ratio)
+                || (stats.unique_count > max_distinct
+                    && col_schema.semantic_role != SemanticRole::Grouping)
+            {
+                continue;
+            }
+
+            let pct = stats.unique_count as f64 / value_count as f64 * 100.0;
+            let obs = Observation::new(
+                ObservationType::Cardinality,
+                Severity::Warning,
+                &col_schema.name,
+                format!(
+                    "{} distinct values in {} non-null rows ({:.0}%); likely free text or an identifier rather than categories",
+                    stats.unique_count, value_count, pct
+                ),
+            )
+            .with_evidence(
+                Evidence::new()
+                    .with_occurrences(stats.unique_count)
+                    .with_percentage(pct),
+            )
+            .with_confidence(0.75)
+            .with_detector("high_cardinality_validator");
+
+            observations.push(obs);
+        }
+
+        observations
+    }
+}
+
+/// Report headers the parser renamed because they were duplicated or empty.
+///
+/// Duplicates are reported once per name, with the positions of every copy.
+pub fn header_rename_observations(table: &DataTable, renames: &[HeaderRename]) -> Vec<Observation> {
+    let mut observations = Vec::new();
+
+    let mut duplicates: IndexMap<&str, Vec<&HeaderRename>> = IndexMap::new();
+    for rename in renames {
+        if rename.original.trim().is_empty() {
+            observations.push(
+                Observation::new(
+                    ObservationType::SchemaIssue,
+                    Severity::Warning,
+                    &rename.renamed,
+                    format!(
+                        "Column {} has an empty header; renamed to '{}'",
+                        rename.position + 1,
+                        rename.renamed
+                    ),
+                )
+                .with_evidence(Evidence::new().with_value(json!({
+                    "original": rename.original,
+                    "renamed": rename.renamed,
+                    "positions": [rename.position],
+                })))
+                .with_confidence(1.0)
+                .with_detector("header_validator"),
+            );
+        } else {
+            duplicates.entry(&rename.original).or_default().push(rename);
+        }
+    }
+
+    for (name, copies) in duplicates {
+        // The first occurrence keeps its name
+        let positions: Vec<usize> = table
+            .headers
+            .iter()
+            .position(|h| h == name)
+            .into_iter()
+            .chain(copies.iter().map(|r| r.position))
+            .collect();
+        let renamed: Vec<&str> = copies.iter().map(|r| r.renamed.as_str()).collect();
+
+        observations.push(
+            Observation::new(
+                ObservationType::SchemaIssue,
+                Severity::Warning,
+                name,
+                format!(
+                    "Header '{}' appears {} times (columns {}); later copies renamed to {}",
+                    name,
+                    positions.len(),
+                    positions
+                        .iter()
+                        .map(|p| (p + 1).to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    renamed
+                        .iter()
+                        .map(|r| format!("'{}'", r))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )
+            .with_evidence(
+                Evidence::new()
+                    .with_occurrences(positions.len())
+                    .with_value(json!({
+                        "original": name,
+                        "renamed": renamed,
+                        "positions": positions,
+                    })),
+            )
+            .with_confidence(1.0)
+            .with_detector("header_validator"),
+        );
+    }
+
+    observations
+}
+
+/// Report controlled-vocabulary entries that matched no column.
+///
+/// These are usually typos or renamed columns in the project dictionary.
+pub fn unmatched_vocabulary_observations(table: &DataTable, unmatched: &[String]) -> Vec<Observation> {
+    unmatched
+        .iter()
+        .map(|name| {
+            Observation::new(
+                ObservationType::SchemaIssue,
+                Severity::Warning,
+                name,
+                format!("Vocabulary entry '{}' does not match any column", name),
+            )
+            .with_evidence(Evidence::new().with_expected(json!(table.headers)))
+            .with_confidence(1.0)
+            .with_detector("vocabulary")
+        })
+        .collect()
+}
+
+/// Method used to flag statistical outliers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutlierMethod {
+    /// Values beyond the quartiles by a multiple of the interquartile range.
+    #[default]
+    Iqr,
+    /// Values whose modified Z-score (based on the median absolute
+    /// deviation) exceeds 3.5. More robust for skewed distributions.
+    ModifiedZScore,
+}
+
+impl OutlierMethod {
+    /// Human-readable name, as shown in observation descriptions.
+    pub fn label(&self) -> &'static str {
+        match self {
+            OutlierMethod::Iqr => "IQR",
+            OutlierMethod::ModifiedZScore => "modified Z-score",
+        }
+    }
+}
+
+/// Validates for statistical outliers using IQR or modified Z-score and domain knowledge.
+pub struct StatisticalOutlierValidator {
+    /// Method used to flag outliers.
+    method: OutlierMethod,
+    /// IQR multiplier for outlier detection (typically 1.5 for mild, 3.0 for extreme).
+    iqr_multiplier: f64,
+}
+
+impl Default for StatisticalOutlierValidator {
+    fn default() -> Self {
+        Self {
+            method: OutlierMethod::default(),
+            iqr_multiplier: 1.5,
+        }
+    }
+}
+
+impl Validator for StatisticalOutlierValidator {
+    fn name(&self) -> &'static str {
+        "statistical_outlier_validator"
+    }
+
+    fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
+        let mut observations = Vec::new();
+
+        for col_schema in &schema.columns {
+            if !col_schema.inferred_type.is_numeric() {
+                continue;
+            }
+
+            // Check for statistical outliers using the configured method
+            let found = match self.method {
+                OutlierMethod::Iqr => col_schema
+                    .statistics
+                    .numeric
+                    .as_ref()
+                    .map(|stats| self.find_iqr_outliers(table, col_schema, stats)),
+                OutlierMethod::ModifiedZScore => self.find_modified_z_outliers(table, col_schema),
+            };
+            if let Some((outliers, expected)) = found.filter(|(outliers, _)| !outliers.is_empty()) {
+                let count = outliers.len();
+                let pct = (count as f64 / table.row_count() as f64) * 100.0;
+
+                // Get actual outlier values for display
+                let outlier_values: Vec<f64> = outliers
+                    .iter()
+                    .take(5)
+                    .filter_map(|(_, v)| v.parse::<f64>().ok())
+                    .collect();
+
+                let obs = Observation::new(
+                    ObservationType::Outlier,
+                    if count > 1 { Severity::Warning } else { Severity::Info },
+                    &col_schema.name,
+                    format!(
+                        "{} statistical outlier(s) detected ({} method): {:?}",
+                        count,
+                        self.method.label(),
+                        outlier_values
+                    ),
+                )
+                .with_evidence(
+                    Evidence::new()
+                        .with_occurrences(count)
+                        .with_percentage(pct)
+                        .with_sample_rows(outliers.iter().map(|(r, _)| *r).take(5).collect())
+                        .with_expected(expected),
+                )
+                .with_confidence(0.85)
+                .with_detector("statistical_outlier_validator");
+
+                observations.push(obs);
+            }
+
+            // Check for domain-specific invalid values (negative ages, weights, counts)
+            let negative_issues = self.find_invalid_negative_values(table, col_schema);
+            if !negative_issues.is_empty() {
+                let count = negative_issues.len();
+                let pct = (count as f64 / table.row_count() as f64) * 100.0;
+
+                let obs = Observation::new(
+                    ObservationType::Outlier,
+                    Severity::Error,
+                    &col_schema.name,
+                    format!(
+                        "{} impossible negative value(s) in column '{}' (should be non-negative)",
+                        count, col_schema.name
+                    ),
+                )
+                .with_evidence(
+                    Evidence::new()
+                        .with_occurrences(count)
+                        .with_percentage(pct)
                         .with_sample_rows(negative_issues.iter().map(|(r, _)| *r).take(5).collect())
                         .with_expected(json!({"min": 0})),
                 )
@@ -755,13 +1695,54 @@ impl Validator for StatisticalOutlierValidator {
     }
 }
 
+/// Median of the values, or `None` when there are none.
+fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.total_cmp(b));
+    let mid = values.len() / 2;
+    Some(if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    })
+}
+
 impl StatisticalOutlierValidator {
+    /// Modified Z-score above which a value is an outlier (Iglewicz and Hoaglin).
+    const MODIFIED_Z_THRESHOLD: f64 = 3.5;
+
+    /// Scales the median absolute deviation to the standard deviation of a normal distribution.
+    const MAD_SCALE: f64 = 0.6745;
+
+    /// Use the given method to flag outliers.
+    pub fn with_method(mut self, method: OutlierMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Set the IQR multiplier (1.5 flags mild outliers, 3.0 extreme ones).
+    pub fn with_iqr_multiplier(mut self, multiplier: f64) -> Self {
+        self.iqr_multiplier = multiplier;
+        self
+    }
+
+    /// Find IQR outliers, with the quartiles and bounds used as evidence.
     fn find_iqr_outliers(
         &self,
         table: &DataTable,
         col_schema: &ColumnSchema,
         stats: &crate::schema::NumericStatistics,
-    ) -> Vec<(usize, String)> {
+    ) -> (Vec<(usize, String)>, serde_json::Value) {
+        let expected = json!({
+            "method": "iqr",
+            "q1": stats.q1,
+            "q3": stats.q3,
+            "iqr": stats.iqr(),
+            "lower_bound": stats.q1 - self.iqr_multiplier * stats.iqr(),
+            "upper_bound": stats.q3 + self.iqr_multiplier * stats.iqr()
+        });
         let mut outliers = Vec::new();
 
         for (row_idx, value) in table.column_values(col_schema.position).enumerate() {
@@ -776,7 +1757,62 @@ impl StatisticalOutlierValidator {
             }
         }
 
-        outliers
+        (outliers, expected)
+    }
+
+    /// Find modified Z-score outliers, with the median, MAD and bounds used as evidence.
+    ///
+    /// Returns `None` when the median absolute deviation is zero (more than
+    /// half the values are identical), since the score is undefined.
+    fn find_modified_z_outliers(
+        &self,
+        table: &DataTable,
+        col_schema: &ColumnSchema,
+    ) -> Option<(Vec<(usize, String)>, serde_json::Value)> {
+        let values: Vec<(usize, &str, f64)> = table
+            .column_values(col_schema.position)
+            .enumerate()
+            .filter(|(_, value)| !DataTable::is_null_value(value))
+            .filter_map(|(row_idx, value)| {
+                value
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|num| num.is_finite())
+                    .map(|num| (row_idx, value, num))
+            })
+            .collect();
+
+        let center = median(values.iter().map(|(_, _, num)| *num).collect())?;
+        let mad = median(
+            values
+                .iter()
+                .map(|(_, _, num)| (num - center).abs())
+                .collect(),
+        )?;
+        if mad == 0.0 {
+            return None;
+        }
+
+        let spread = Self::MODIFIED_Z_THRESHOLD * mad / Self::MAD_SCALE;
+        let expected = json!({
+            "method": "modified_z_score",
+            "median": center,
+            "mad": mad,
+            "threshold": Self::MODIFIED_Z_THRESHOLD,
+            "lower_bound": center - spread,
+            "upper_bound": center + spread
+        });
+
+        let outliers = values
+            .into_iter()
+            .filter(|(_, _, num)| {
+                (Self::MAD_SCALE * (num - center) / mad).abs() > Self::MODIFIED_Z_THRESHOLD
+            })
+            .map(|(row_idx, value, _)| (row_idx, value.to_string()))
+            .collect();
+
+        Some((outliers, expected))
     }
 
     fn find_invalid_negative_values(
@@ -823,6 +1859,10 @@ impl StatisticalOutlierValidator {
 pub struct CaseVariantValidator;
 
 impl Validator for CaseVariantValidator {
+    fn name(&self) -> &'static str {
+        "case_variant_validator"
+    }
+
     fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
@@ -894,6 +1934,9 @@ impl Validator for CaseVariantValidator {
 
 impl CaseVariantValidator {
     /// Groups values by their lowercase form to find case variants.
+    ///
+    /// Reuses the value counts from inference when they cover every distinct
+    /// value of this table; otherwise the column is scanned.
     fn find_case_variant_groups(
         &self,
         table: &DataTable,
@@ -901,25 +1944,34 @@ impl CaseVariantValidator {
     ) -> IndexMap<String, IndexMap<String, usize>> {
         // Map from lowercase -> (original_case -> count)
         let mut groups: IndexMap<String, IndexMap<String, usize>> = IndexMap::new();
-
-        for value in table.column_values(col_schema.position) {
-            if DataTable::is_null_value(value) {
-                continue;
-            }
-
+        let mut add = |value: &str, count: usize| {
             let trimmed = value.trim();
             if trimmed.is_empty() {
-                continue;
+                return;
             }
-
-            let lowercase = trimmed.to_lowercase();
-
-            groups
-                .entry(lowercase)
+            *groups
+                .entry(trimmed.to_lowercase())
                 .or_default()
                 .entry(trimmed.to_string())
-                .and_modify(|c| *c += 1)
-                .or_insert(1);
+                .or_insert(0) += count;
+        };
+
+        let stats = &col_schema.statistics;
+        match &stats.value_counts {
+            Some(counts)
+                if counts.len() == stats.unique_count && stats.count == table.row_count() =>
+            {
+                for (value, count) in counts {
+                    add(value, *count);
+                }
+            }
+            _ => {
+                for value in table.column_values(col_schema.position) {
+                    if !DataTable::is_null_value(value) {
+                        add(value, 1);
+                    }
+                }
+            }
         }
 
         groups
@@ -928,6 +1980,9 @@ impl CaseVariantValidator {
 
 /// Validates for potential typos using edit distance.
 /// Detects when values are very similar to other values (e.g., "stoool" vs "stool").
+///
+/// When a column has `expected_values`, they are used as the canonical
+/// vocabulary; otherwise rare values are compared against frequent ones.
 pub struct TypoValidator {
     /// Maximum edit distance to consider as a typo (default: 2).
     max_distance: usize,
@@ -945,6 +2000,10 @@ impl Default for TypoValidator {
 }
 
 impl Validator for TypoValidator {
+    fn name(&self) -> &'static str {
+        "typo_validator"
+    }
+
     fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
@@ -962,14 +2021,14 @@ impl Validator for TypoValidator {
             let potential_typos = self.find_potential_typos(table, col_schema);
 
             if !potential_typos.is_empty() {
-                let count: usize = potential_typos.values().map(|(_, c)| c).sum();
+                let count: usize = potential_typos.values().map(|m| m.count).sum();
                 let pct = (count as f64 / table.row_count() as f64) * 100.0;
 
                 // Format typo suggestions
                 let typo_examples: Vec<String> = potential_typos
                     .iter()
                     .take(3)
-                    .map(|(typo, (suggestion, _))| format!("'{}' → '{}'", typo, suggestion))
+                    .map(|(typo, m)| format!("'{}' → '{}'", typo, m.suggestion))
                     .collect();
 
                 let obs = Observation::new(
@@ -989,8 +2048,15 @@ impl Validator for TypoValidator {
                         .with_value_counts(Some(json!(
                             potential_typos
                                 .iter()
-                                .map(|(typo, (suggestion, count))| {
-                                    (typo.clone(), json!({"suggestion": suggestion, "count": count}))
+                                .map(|(typo, m)| {
+                                    (
+                                        typo.clone(),
+                                        json!({
+                                            "suggestion": m.suggestion,
+                                            "count": m.count,
+                                            "source": m.source,
+                                        }),
+                                    )
                                 })
                                 .collect::<IndexMap<_, _>>()
                         ))),
@@ -1006,13 +2072,34 @@ impl Validator for TypoValidator {
     }
 }
 
+/// A suspected typo and the value it likely should have been.
+#[derive(Debug, Clone, PartialEq)]
+struct TypoMatch {
+    /// The canonical value to suggest.
+    suggestion: String,
+    /// Number of occurrences of the typo.
+    count: usize,
+    /// Where the canonical value came from ("vocabulary" or "frequency").
+    source: &'static str,
+}
+
 impl TypoValidator {
-    /// Find values that appear to be typos of more common values.
+    /// Set the maximum edit distance for a value to count as a typo.
+    pub fn with_max_distance(mut self, distance: usize) -> Self {
+        self.max_distance = distance;
+        self
+    }
+
+    /// Find values that appear to be typos of canonical values.
+    ///
+    /// Values outside the column's `expected_values` are matched against that
+    /// vocabulary first; values with no vocabulary match fall back to being
+    /// compared against more common values in the column.
     fn find_potential_typos(
         &self,
         table: &DataTable,
         col_schema: &ColumnSchema,
-    ) -> IndexMap<String, (String, usize)> {
+    ) -> IndexMap<String, TypoMatch> {
         // Count all values
         let mut value_counts: IndexMap<String, usize> = IndexMap::new();
         for value in table.column_values(col_schema.position) {
@@ -1025,8 +2112,7 @@ impl TypoValidator {
             }
         }
 
-        // Find rare values that are close to common values
-        let mut potential_typos: IndexMap<String, (String, usize)> = IndexMap::new();
+        let vocabulary: &[String] = col_schema.expected_values.as_deref().unwrap_or_default();
 
         // Sort by count descending to identify "canonical" values
         let mut sorted_values: Vec<_> = value_counts.iter().collect();
@@ -1039,40 +2125,96 @@ impl TypoValidator {
             .map(|(val, _)| *val)
             .collect();
 
-        // Check rare values (count == 1) against common values
-        for (rare_value, count) in &value_counts {
-            if *count > 1 || rare_value.len() < self.min_length {
+        let mut potential_typos: IndexMap<String, TypoMatch> = IndexMap::new();
+
+        for (value, count) in &value_counts {
+            if value.chars().count() < self.min_length {
                 continue;
             }
 
-            for common_value in &common_values {
-                if common_value.len() < self.min_length {
-                    continue;
-                }
-
-                let distance = levenshtein_distance(rare_value, common_value);
+            // Prefer the controlled vocabulary, whatever the value's frequency
+            if let Some(canonical) = self.closest_match(value, vocabulary.iter()) {
+                potential_typos.insert(
+                    value.clone(),
+                    TypoMatch {
+                        suggestion: canonical.clone(),
+                        count: *count,
+                        source: "vocabulary",
+                    },
+                );
+                continue;
+            }
 
-                // Only flag if distance is small relative to string length
-                // and the strings are reasonably similar
-                if distance > 0
-                    && distance <= self.max_distance
-                    && distance < rare_value.len() / 2
-                {
-                    potential_typos.insert(
-                        rare_value.clone(),
-                        ((*common_value).clone(), *count),
-                    );
-                    break; // Only suggest one correction per typo
-                }
+            // Otherwise check rare values (count == 1) against common values
+            if *count > 1 {
+                continue;
+            }
+            if let Some(canonical) = self.first_match(value, common_values.iter().copied()) {
+                potential_typos.insert(
+                    value.clone(),
+                    TypoMatch {
+                        suggestion: canonical.clone(),
+                        count: *count,
+                        source: "frequency",
+                    },
+                );
             }
         }
 
         potential_typos
     }
+
+    /// Whether `candidate` is close enough to `value` to be its correction.
+    ///
+    /// Returns the edit distance when it is small relative to the string
+    /// length and the strings are reasonably similar.
+    fn typo_distance(&self, value: &str, candidate: &str) -> Option<usize> {
+        if candidate.chars().count() < self.min_length {
+            return None;
+        }
+
+        let distance = levenshtein_distance(value, candidate);
+        (distance > 0 && distance <= self.max_distance && distance < value.len() / 2)
+            .then_some(distance)
+    }
+
+    /// The closest vocabulary entry to a value outside the vocabulary.
+    fn closest_match<'a>(
+        &self,
+        value: &str,
+        vocabulary: impl Iterator<Item = &'a String> + Clone,
+    ) -> Option<&'a String> {
+        // Values already in the vocabulary (ignoring case) are not typos
+        if vocabulary.clone().any(|v| v.eq_ignore_ascii_case(value)) {
+            return None;
+        }
+
+        vocabulary
+            .filter_map(|v| self.typo_distance(value, v).map(|d| (d, v)))
+            .min_by_key(|(d, _)| *d)
+            .map(|(_, v)| v)
+    }
+
+    /// The first (most common) value that a value appears to be a typo of.
+    fn first_match<'a>(
+        &self,
+        value: &str,
+        mut candidates: impl Iterator<Item = &'a String>,
+    ) -> Option<&'a String> {
+        candidates.find(|c| self.typo_distance(value, c).is_some())
+    }
+}
+
+/// Cosine similarity of two vectors; 0 when either is all zeros.
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| *x as f64 * *y as f64).sum();
+    let norm = |v: &[f32]| v.iter().map(|x| (*x as f64).powi(2)).sum::<f64>().sqrt();
+    let norms = norm(a) * norm(b);
+    if norms == 0.0 { 0.0 } else { dot / norms }
 }
 
 /// Calculate Levenshtein (edit) distance between two strings.
-fn levenshtein_distance(s1: &str, s2: &str) -> usize {
+pub(crate) fn levenshtein_distance(s1: &str, s2: &str) -> usize {
     let s1_chars: Vec<char> = s1.chars().collect();
     let s2_chars: Vec<char> = s2.chars().collect();
     let len1 = s1_chars.len();
@@ -1117,16 +2259,44 @@ fn levenshtein_distance(s1: &str, s2: &str) -> usize {
     matrix[len1][len2]
 }
 
+/// How the canonical value of a group of semantic equivalents is chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CanonicalLabel {
+    /// The preferred label of the synonym group, even if absent from the data.
+    #[default]
+    First,
+    /// The variant that occurs most often in the column.
+    MostFrequent,
+    /// The longest variant in the column, e.g. "Crohn's Disease" over "CD".
+    Longest,
+}
+
+/// Cosine similarity at which embedded values are taken as equivalent.
+const DEFAULT_EMBEDDING_THRESHOLD: f64 = 0.9;
+
+/// Most distinct values of a column sent for embedding.
+const MAX_EMBEDDED_VALUES: usize = 200;
+
 /// Validates for semantic equivalents (synonyms).
 /// Detects when values represent the same concept with different names.
 pub struct SemanticEquivalenceValidator {
     /// Known synonym groups for biomedical terms.
     synonym_groups: Vec<Vec<&'static str>>,
+    /// How the value a group should be merged into is chosen.
+    canonical: CanonicalLabel,
+    /// Provider embedding values that no synonym group matches.
+    embedder: Option<Arc<dyn LlmProvider>>,
+    /// Cosine similarity at which embedded values are equivalent.
+    embedding_threshold: f64,
 }
 
 impl Default for SemanticEquivalenceValidator {
     fn default() -> Self {
         Self {
+            canonical: CanonicalLabel::default(),
+            embedder: None,
+            embedding_threshold: DEFAULT_EMBEDDING_THRESHOLD,
             synonym_groups: vec![
                 // Disease names
                 vec!["CD", "Crohn's", "Crohns", "Crohn's Disease", "Crohn Disease"],
@@ -1192,6 +2362,10 @@ impl Default for SemanticEquivalenceValidator {
 }
 
 impl Validator for SemanticEquivalenceValidator {
+    fn name(&self) -> &'static str {
+        "semantic_equivalence_validator"
+    }
+
     fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
@@ -1234,15 +2408,7 @@ impl Validator for SemanticEquivalenceValidator {
                     Evidence::new()
                         .with_occurrences(total_affected)
                         .with_percentage(pct)
-                        .with_value_counts(Some(json!(
-                            equivalent_groups
-                                .iter()
-                                .take(5)
-                                .map(|(canonical, variants)| {
-                                    (canonical.clone(), variants.clone())
-                                })
-                                .collect::<IndexMap<_, _>>()
-                        ))),
+                        .with_value_counts(Some(json!(equivalent_groups))),
                 )
                 .with_confidence(0.85)
                 .with_detector("semantic_equivalence_validator");
@@ -1256,7 +2422,94 @@ impl Validator for SemanticEquivalenceValidator {
 }
 
 impl SemanticEquivalenceValidator {
+    /// Choose how each group's canonical value is picked.
+    pub fn with_canonical(mut self, canonical: CanonicalLabel) -> Self {
+        self.canonical = canonical;
+        self
+    }
+
+    /// Also group values with the same meaning by their embeddings.
+    ///
+    /// Distinct values of a column that no synonym group matches are
+    /// embedded by `provider` and clustered: values at least as similar as
+    /// the threshold (cosine, 0.9 by default) form a group. Groups differing
+    /// only in case are left to the [`CaseVariantValidator`], and columns
+    /// with more than 200 distinct values aren't embedded. When the provider
+    /// can't embed, or the request fails, only the synonym table is used.
+    pub fn with_embeddings(mut self, provider: Arc<dyn LlmProvider>) -> Self {
+        self.embedder = Some(provider);
+        self
+    }
+
+    /// Set the cosine similarity at which embedded values are equivalent.
+    pub fn with_embedding_threshold(mut self, threshold: f64) -> Self {
+        self.embedding_threshold = threshold;
+        self
+    }
+
+    /// Cluster values whose embeddings are similar enough, in order of
+    /// first appearance. Empty when the values can't be embedded.
+    fn embedding_clusters<'a>(&self, embedder: &dyn LlmProvider, values: &[&'a String]) -> Vec<Vec<&'a String>> {
+        if values.len() < 2 || values.len() > MAX_EMBEDDED_VALUES {
+            return Vec::new();
+        }
+        let texts: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+        let vectors = match embedder.embed(&texts) {
+            Ok(Some(vectors)) if vectors.len() == values.len() => vectors,
+            _ => return Vec::new(),
+        };
+
+        // Single-linkage clustering by union-find
+        let mut parent: Vec<usize> = (0..values.len()).collect();
+        fn root(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+        for i in 0..values.len() {
+            for j in 0..i {
+                if cosine_similarity(&vectors[i], &vectors[j]) >= self.embedding_threshold {
+                    let (ri, rj) = (root(&mut parent, i), root(&mut parent, j));
+                    parent[ri.max(rj)] = ri.min(rj);
+                }
+            }
+        }
+
+        let mut clusters: IndexMap<usize, Vec<&String>> = IndexMap::new();
+        for (i, value) in values.iter().enumerate() {
+            clusters.entry(root(&mut parent, i)).or_default().push(value);
+        }
+        clusters
+            .into_values()
+            .filter(|members| {
+                let first = members[0].to_lowercase();
+                members.len() > 1 && members.iter().any(|m| m.to_lowercase() != first)
+            })
+            .collect()
+    }
+
+    /// Pick the canonical value for a group of observed variants.
+    ///
+    /// Ties between variants go to the more frequent one, then the first seen.
+    fn canonical_value(&self, preferred: &str, variants: &IndexMap<String, usize>) -> String {
+        // Reversed so that max_by_key, which keeps the last maximum, keeps the first seen
+        let best = match self.canonical {
+            CanonicalLabel::First => None,
+            CanonicalLabel::MostFrequent => variants.iter().rev().max_by_key(|(_, count)| **count),
+            CanonicalLabel::Longest => variants
+                .iter()
+                .rev()
+                .max_by_key(|(value, count)| (value.chars().count(), **count)),
+        };
+        best.map(|(value, _)| value.clone())
+            .unwrap_or_else(|| preferred.to_string())
+    }
+
     /// Find values that are semantic equivalents (synonyms).
+    ///
+    /// Groups are keyed by their canonical value.
     fn find_semantic_equivalents(
         &self,
         table: &DataTable,
@@ -1307,7 +2560,32 @@ impl SemanticEquivalenceValidator {
         // Only keep groups with multiple variants
         groups.retain(|_, variants| variants.len() > 1);
 
+        // Values outside the synonym table are grouped by embeddings, with
+        // the most frequent member as the preferred label
+        if let Some(ref embedder) = self.embedder {
+            let unmatched: Vec<&String> = value_counts
+                .keys()
+                .filter(|value| !canonical_map.contains_key(*value))
+                .collect();
+            for cluster in self.embedding_clusters(embedder.as_ref(), &unmatched) {
+                let variants: IndexMap<String, usize> = cluster
+                    .into_iter()
+                    .map(|value| (value.clone(), value_counts[value]))
+                    .collect();
+                let preferred = variants
+                    .iter()
+                    .rev()
+                    .max_by_key(|(_, count)| **count)
+                    .map(|(value, _)| value.clone())
+                    .unwrap_or_default();
+                groups.insert(preferred, variants);
+            }
+        }
+
         groups
+            .into_iter()
+            .map(|(preferred, variants)| (self.canonical_value(&preferred, &variants), variants))
+            .collect()
     }
 }
 
@@ -1315,7 +2593,61 @@ impl SemanticEquivalenceValidator {
 ///
 /// Detects when dates in the same column use different formats
 /// (e.g., "2024-01-15" vs "01/15/2024" vs "Jan 15 2024").
-pub struct DateFormatValidator;
+///
+/// Datetime columns are compared on their date part, so times don't count
+/// as a different format. Their times are also checked for mixed timezone
+/// notation: UTC (`Z`), an offset (`+02:00`), or none at all.
+///
+/// Numeric dates like `03/04/2024` are US dates unless a day-first date
+/// order is set, which makes them EU dates. A date order that was set is
+/// reported in the evidence, so suggestions read ambiguous dates that way.
+#[derive(Debug, Clone, Default)]
+pub struct DateFormatValidator {
+    /// Which part of a numeric date is the day, if known.
+    date_order: Option<DateOrder>,
+}
+
+/// How a datetime value records its timezone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum TimezoneNotation {
+    /// Trailing `Z` (or `UTC`/`GMT`): 2024-01-15T09:30:00Z
+    Utc,
+    /// Numeric offset or zone name: 2024-01-15T09:30:00+02:00
+    Offset,
+    /// No timezone: 2024-01-15 09:30:00
+    Naive,
+}
+
+impl TimezoneNotation {
+    /// Classify the time part of a datetime.
+    ///
+    /// A 12-hour clock's AM/PM marker is not a timezone, so it is set aside
+    /// first: `09:30 PM` has no timezone, `09:30 PM EST` has one.
+    fn of(time: &str) -> Self {
+        let upper = time.trim().to_uppercase();
+        let time = ["AM", "PM", "A.M.", "P.M."]
+            .iter()
+            .find_map(|marker| upper.strip_suffix(marker))
+            .filter(|rest| rest.ends_with(|c: char| c.is_ascii_digit() || c.is_whitespace()))
+            .unwrap_or(&upper)
+            .trim_end();
+        if time.ends_with('Z') || time.ends_with("UTC") || time.ends_with("GMT") {
+            TimezoneNotation::Utc
+        } else if time.contains(['+', '-']) || time.ends_with(|c: char| c.is_ascii_alphabetic()) {
+            TimezoneNotation::Offset
+        } else {
+            TimezoneNotation::Naive
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            TimezoneNotation::Utc => "UTC (Z)",
+            TimezoneNotation::Offset => "UTC offset (+hh:mm)",
+            TimezoneNotation::Naive => "no timezone",
+        }
+    }
+}
 
 /// Known date format patterns with their regex and description.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1331,6 +2663,8 @@ enum DateFormat {
     EuSlash,
     /// European format with dashes: 15-01-2024
     EuDash,
+    /// European format with dots: 15.01.2024
+    EuDot,
     /// Month name format: Jan 15 2024 or January 15, 2024
     MonthName,
     /// Year/month/day with slashes: 2024/01/15
@@ -1347,6 +2681,7 @@ impl DateFormat {
             DateFormat::UsDash => "US (MM-DD-YYYY)",
             DateFormat::EuSlash => "EU (DD/MM/YYYY)",
             DateFormat::EuDash => "EU (DD-MM-YYYY)",
+            DateFormat::EuDot => "EU (DD.MM.YYYY)",
             DateFormat::MonthName => "Month name (Mon DD YYYY)",
             DateFormat::YearSlash => "Year first (YYYY/MM/DD)",
             DateFormat::Unknown => "Unknown",
@@ -1355,8 +2690,94 @@ impl DateFormat {
 }
 
 impl DateFormatValidator {
+    /// Read numeric dates in this day/month order.
+    pub fn with_date_order(mut self, date_order: DateOrder) -> Self {
+        self.date_order = Some(date_order);
+        self
+    }
+
+    /// Split a value into its date and, if present, time of day.
+    ///
+    /// The time starts at a `T` or space followed by `hh:mm`, so month
+    /// names with spaces ("Jan 15 2024") stay whole.
+    fn split_time(value: &str) -> (&str, Option<&str>) {
+        let trimmed = value.trim();
+        for (idx, c) in trimmed.char_indices().skip(1) {
+            if c != 'T' && c != ' ' {
+                continue;
+            }
+            let rest = trimmed[idx + 1..].trim_start();
+            let hour_digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+            if (1..=2).contains(&hour_digits) && rest[hour_digits..].starts_with(':') {
+                return (trimmed[..idx].trim_end(), Some(rest));
+            }
+        }
+        (trimmed, None)
+    }
+
+    /// Report datetimes that mix timezone notations.
+    fn timezone_observation(
+        column: &str,
+        notations: &IndexMap<TimezoneNotation, (Vec<usize>, Vec<String>)>,
+    ) -> Option<Observation> {
+        if notations.len() < 2 {
+            return None;
+        }
+        let (dominant, _) = notations
+            .iter()
+            .max_by_key(|(_, (rows, _))| rows.len())?;
+        let total: usize = notations.values().map(|(rows, _)| rows.len()).sum();
+        let others: Vec<usize> = notations
+            .iter()
+            .filter(|(notation, _)| *notation != dominant)
+            .flat_map(|(_, (rows, _))| rows.iter().copied())
+            .collect();
+        let pct = others.len() as f64 / total as f64 * 100.0;
+
+        let counts: Vec<String> = notations
+            .iter()
+            .map(|(notation, (rows, _))| format!("{}: {} values", notation.description(), rows.len()))
+            .collect();
+
+        let mut sample_rows: Vec<usize> = others.iter().take(5).copied().collect();
+        sample_rows.sort_unstable();
+
+        Some(
+            Observation::new(
+                ObservationType::Inconsistency,
+                Severity::Warning,
+                column,
+                format!(
+                    "Mixed timezone notation in datetimes ({}). Times without a timezone are ambiguous; record all times as {}",
+                    counts.join("; "),
+                    dominant.description()
+                ),
+            )
+            .with_evidence(
+                Evidence::new()
+                    .with_occurrences(others.len())
+                    .with_percentage(pct)
+                    .with_sample_rows(sample_rows)
+                    .with_expected(json!({"timezone": dominant.description()}))
+                    .with_value_counts(Some(json!(
+                        notations
+                            .iter()
+                            .map(|(notation, (rows, examples))| {
+                                (
+                                    notation.description().to_string(),
+                                    json!({"count": rows.len(), "examples": examples})
+                                )
+                            })
+                            .collect::<IndexMap<_, _>>()
+                    ))),
+            )
+            .with_confidence(0.85)
+            .with_detector("date_format_validator"),
+        )
+    }
+
     /// Detect the date format of a string value.
-    fn detect_format(value: &str) -> Option<DateFormat> {
+    fn detect_format(&self, value: &str) -> Option<DateFormat> {
         let trimmed = value.trim();
 
         // ISO format: 2024-01-15
@@ -1369,14 +2790,21 @@ impl DateFormatValidator {
             return Some(DateFormat::YearSlash);
         }
 
-        // US format with slashes: 01/15/2024 or 1/15/2024
+        let day_first = self.date_order == Some(DateOrder::DayFirst);
+
+        // Slashes: 01/15/2024 or 1/15/2024, or 15/01/2024 day first
         if Self::matches_us_slash(trimmed) {
-            return Some(DateFormat::UsSlash);
+            return Some(if day_first { DateFormat::EuSlash } else { DateFormat::UsSlash });
         }
 
-        // US format with dashes: 01-15-2024
+        // Dashes: 01-15-2024, or 15-01-2024 day first
         if Self::matches_us_dash(trimmed) {
-            return Some(DateFormat::UsDash);
+            return Some(if day_first { DateFormat::EuDash } else { DateFormat::UsDash });
+        }
+
+        // Dots are always day first: 15.01.2024
+        if Self::matches_eu_dot(trimmed) {
+            return Some(DateFormat::EuDot);
         }
 
         // Month name format: Jan 15 2024, January 15, 2024, etc.
@@ -1443,6 +2871,20 @@ impl DateFormatValidator {
             && parts[2].chars().all(|c| c.is_ascii_digit())
     }
 
+    fn matches_eu_dot(s: &str) -> bool {
+        // DD.MM.YYYY
+        let parts: Vec<&str> = s.split('.').collect();
+        if parts.len() != 3 {
+            return false;
+        }
+        parts[0].len() <= 2
+            && parts[0].chars().all(|c| c.is_ascii_digit())
+            && parts[1].len() <= 2
+            && parts[1].chars().all(|c| c.is_ascii_digit())
+            && parts[2].len() == 4
+            && parts[2].chars().all(|c| c.is_ascii_digit())
+    }
+
     fn matches_month_name(s: &str) -> bool {
         let months = [
             "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
@@ -1470,25 +2912,40 @@ impl DateFormatValidator {
 }
 
 impl Validator for DateFormatValidator {
+    fn name(&self) -> &'static str {
+        "date_format_validator"
+    }
+
     fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
         for col_schema in &schema.columns {
-            // Only check date columns
-            if col_schema.inferred_type != ColumnType::Date {
+            // Only check date and datetime columns
+            if !matches!(col_schema.inferred_type, ColumnType::Date | ColumnType::DateTime) {
                 continue;
             }
 
             // Count occurrences of each format
             let mut format_counts: IndexMap<DateFormat, (usize, Vec<String>)> = IndexMap::new();
             let mut rows_with_format: IndexMap<DateFormat, Vec<usize>> = IndexMap::new();
+            let mut notations: IndexMap<TimezoneNotation, (Vec<usize>, Vec<String>)> =
+                IndexMap::new();
 
             for (row_idx, value) in table.column_values(col_schema.position).enumerate() {
                 if DataTable::is_null_value(value) {
                     continue;
                 }
 
-                if let Some(format) = Self::detect_format(value) {
+                let (date, time) = Self::split_time(value);
+                if let Some(time) = time {
+                    let entry = notations.entry(TimezoneNotation::of(time)).or_default();
+                    entry.0.push(row_idx);
+                    if entry.1.len() < 3 {
+                        entry.1.push(value.to_string());
+                    }
+                }
+
+                if let Some(format) = self.detect_format(date) {
                     let entry = format_counts.entry(format.clone()).or_insert((0, Vec::new()));
                     entry.0 += 1;
                     if entry.1.len() < 3 {
@@ -1539,7 +2996,7 @@ impl Validator for DateFormatValidator {
                     .take(5)
                     .collect();
 
-                let obs = Observation::new(
+                let mut obs = Observation::new(
                     ObservationType::Inconsistency,
                     Severity::Warning,
                     &col_schema.name,
@@ -1568,9 +3025,14 @@ impl Validator for DateFormatValidator {
                 )
                 .with_confidence(0.90)
                 .with_detector("date_format_validator");
+                if let Some(order) = self.date_order {
+                    obs.evidence.expected = Some(json!({"date_order": order}));
+                }
 
                 observations.push(obs);
             }
+
+            observations.extend(Self::timezone_observation(&col_schema.name, &notations));
         }
 
         observations
@@ -1636,13 +3098,31 @@ impl PatternType {
 }
 
 /// Validates values against expected patterns (email, URL, identifiers, etc.).
+///
+/// A [`Constraint::Regex`] declared on a column takes precedence over the
+/// formats guessed from the column name.
 pub struct RegexPatternValidator;
 
 impl Validator for RegexPatternValidator {
+    fn name(&self) -> &'static str {
+        "regex_pattern_validator"
+    }
+
     fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
         for col_schema in &schema.columns {
+            let declared = col_schema.constraints.iter().find_map(|c| match c {
+                Constraint::Regex { pattern } => Some(pattern),
+                _ => None,
+            });
+            if let Some(pattern) = declared {
+                if let Some(obs) = self.check_declared_pattern(table, col_schema, pattern) {
+                    observations.push(obs);
+                }
+                continue;
+            }
+
             // Skip non-string columns
             if col_schema.inferred_type != ColumnType::String {
                 continue;
@@ -1677,32 +3157,7 @@ impl RegexPatternValidator {
         pattern_type: PatternType,
     ) -> Option<Observation> {
         let pattern = regex::Regex::new(pattern_type.pattern()).ok()?;
-        let mut invalid_rows = Vec::new();
-        let mut invalid_examples = Vec::new();
-
-        for (row_idx, value) in table.column_values(col_schema.position).enumerate() {
-            let trimmed = value.trim();
-            if DataTable::is_null_value(trimmed) || trimmed.is_empty() {
-                continue;
-            }
-
-            if !pattern.is_match(trimmed) {
-                invalid_rows.push(row_idx);
-                if invalid_examples.len() < 3 {
-                    invalid_examples.push(trimmed.to_string());
-                }
-            }
-        }
-
-        if invalid_rows.is_empty() {
-            return None;
-        }
-
-        let total_non_null: usize = table
-            .column_values(col_schema.position)
-            .filter(|v| !DataTable::is_null_value(v.trim()) && !v.trim().is_empty())
-            .count();
-        let pct = (invalid_rows.len() as f64 / total_non_null as f64) * 100.0;
+        let (invalid_rows, invalid_examples, pct) = Self::mismatches(table, col_schema, &pattern)?;
 
         // Only report if < 50% match (otherwise, pattern might not be applicable)
         if pct > 50.0 {
@@ -1738,6 +3193,78 @@ impl RegexPatternValidator {
         )
     }
 
+    /// Check values against a regex declared as a column constraint.
+    ///
+    /// The regex is compiled once per column, and every mismatch is reported
+    /// since the format is known rather than guessed.
+    fn check_declared_pattern(
+        &self,
+        table: &DataTable,
+        col_schema: &ColumnSchema,
+        pattern: &str,
+    ) -> Option<Observation> {
+        let regex = regex::Regex::new(pattern).ok()?;
+        let (invalid_rows, invalid_examples, pct) = Self::mismatches(table, col_schema, &regex)?;
+
+        Some(
+            Observation::new(
+                ObservationType::PatternViolation,
+                Severity::Warning,
+                &col_schema.name,
+                format!(
+                    "{} value(s) ({:.1}%) don't match declared pattern {}: {:?}",
+                    invalid_rows.len(),
+                    pct,
+                    pattern,
+                    invalid_examples
+                ),
+            )
+            .with_evidence(
+                Evidence::new()
+                    .with_occurrences(invalid_rows.len())
+                    .with_percentage(pct)
+                    .with_sample_rows(invalid_rows.into_iter().take(5).collect())
+                    .with_pattern(pattern.to_string()),
+            )
+            .with_confidence(0.95)
+            .with_detector("regex_pattern_validator"),
+        )
+    }
+
+    /// Rows whose non-null value doesn't match `pattern`, up to three example
+    /// values, and the mismatch percentage. `None` when every value matches.
+    fn mismatches(
+        table: &DataTable,
+        col_schema: &ColumnSchema,
+        pattern: &regex::Regex,
+    ) -> Option<(Vec<usize>, Vec<String>, f64)> {
+        let mut invalid_rows = Vec::new();
+        let mut invalid_examples = Vec::new();
+        let mut total_non_null = 0;
+
+        for (row_idx, value) in table.column_values(col_schema.position).enumerate() {
+            let trimmed = value.trim();
+            if DataTable::is_null_value(trimmed) || trimmed.is_empty() {
+                continue;
+            }
+            total_non_null += 1;
+
+            if !pattern.is_match(trimmed) {
+                invalid_rows.push(row_idx);
+                if invalid_examples.len() < 3 {
+                    invalid_examples.push(trimmed.to_string());
+                }
+            }
+        }
+
+        if invalid_rows.is_empty() {
+            return None;
+        }
+
+        let pct = (invalid_rows.len() as f64 / total_non_null as f64) * 100.0;
+        Some((invalid_rows, invalid_examples, pct))
+    }
+
     /// Check identifier columns for inconsistent formats.
     fn check_identifier_consistency(
         &self,
@@ -1876,14 +3403,290 @@ impl RegexPatternValidator {
 }
 
 // ============================================================================
-// Cross-Column Validator
+// Sensitive Data Validator
 // ============================================================================
 
-/// Validates logical relationships between columns.
-pub struct CrossColumnValidator;
+/// US Social Security numbers.
+const SSN_PATTERN: &str = r"\b\d{3}-\d{2}-\d{4}\b";
 
-impl Validator for CrossColumnValidator {
-    fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
+/// Medical record numbers written with an `MRN` prefix.
+const MRN_PATTERN: &str = r"(?i)\bMRN[\s:#-]*\d{6,10}\b";
+
+/// A kind of sensitive value and the regex that finds it in text.
+struct SensitivePattern {
+    label: String,
+    regex: regex::Regex,
+}
+
+/// Flags string columns whose values contain personal identifiers.
+///
+/// Every value is searched for email addresses and phone numbers (the
+/// [`RegexPatternValidator`] formats, unanchored), US SSNs and medical record
+/// numbers. More kinds can be added by label with
+/// [`ContextHints::with_sensitive_pattern`]; a pattern with the label of a
+/// built-in one replaces it. Samples are masked and never contain a full
+/// value.
+///
+/// Identifier columns are skipped, as are columns of codes that all share
+/// one shape of letters and digits, such as run accessions.
+pub struct PiiValidator {
+    patterns: Vec<SensitivePattern>,
+}
+
+impl Default for PiiValidator {
+    fn default() -> Self {
+        let unanchored = |p: PatternType| {
+            p.pattern()
+                .trim_start_matches('^')
+                .trim_end_matches('$')
+                .to_string()
+        };
+        Self::with_patterns([
+            ("email".to_string(), unanchored(PatternType::Email)),
+            ("phone".to_string(), unanchored(PatternType::Phone)),
+            ("SSN".to_string(), SSN_PATTERN.to_string()),
+            ("MRN".to_string(), MRN_PATTERN.to_string()),
+        ])
+    }
+}
+
+impl PiiValidator {
+    /// Create a validator with the built-in patterns.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add patterns by label, replacing a built-in pattern of the same label
+    /// (case-insensitive). Patterns that don't compile are skipped; those
+    /// from [`ContextHints::with_sensitive_pattern`] are checked there.
+    pub fn with_additional_patterns<I>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        for (label, pattern) in patterns {
+            let Ok(regex) = regex::Regex::new(&pattern) else {
+                continue;
+            };
+            self.patterns.retain(|p| !p.label.eq_ignore_ascii_case(&label));
+            self.patterns.push(SensitivePattern { label, regex });
+        }
+        self
+    }
+
+    fn with_patterns<I>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        Self { patterns: Vec::new() }.with_additional_patterns(patterns)
+    }
+
+    /// Phone matches need 10-15 digits, must not be a date or an SSN, and
+    /// must not run into letters or digits on either side (as the digits of
+    /// an accession like `SRR1234567890` do).
+    fn is_plausible(label: &str, value: &str, found: regex::Match<'_>) -> bool {
+        if label != "phone" {
+            return true;
+        }
+        let text = found.as_str();
+        let start = found.start() + (text.len() - text.trim_start().len());
+        let end = found.end() - (text.len() - text.trim_end().len());
+        let before = value[..start].chars().next_back();
+        let after = value[end..].chars().next();
+        if before.is_some_and(char::is_alphanumeric) || after.is_some_and(char::is_alphanumeric) {
+            return false;
+        }
+        let found = text.trim();
+        let digits = found.chars().filter(char::is_ascii_digit).count();
+        let bytes = found.as_bytes();
+        let is_date = bytes.len() >= 10 && bytes[4] == b'-' && bytes[7] == b'-';
+        (10..=15).contains(&digits) && !is_date
+    }
+
+    /// Whether every value has the same mix of letters and digits in the same
+    /// places, like accessions or sample codes (`SRR1234567890`).
+    fn is_uniform_code(values: &[&str]) -> bool {
+        let shape = |value: &str| -> String {
+            value
+                .chars()
+                .map(|c| match c {
+                    c if c.is_alphabetic() => 'A',
+                    c if c.is_ascii_digit() => '9',
+                    c => c,
+                })
+                .collect()
+        };
+        let Some(first) = values.first().map(|v| shape(v)) else {
+            return false;
+        };
+        first.contains('A') && first.contains('9') && values.iter().all(|v| shape(v) == first)
+    }
+
+    /// Hide every letter and digit but the first, keeping punctuation so the
+    /// kind of value stays recognizable. The first is hidden too unless the
+    /// value has at least four, so no more than a quarter is ever shown.
+    fn mask(found: &str) -> String {
+        let alphanumeric = found.chars().filter(|c| c.is_alphanumeric()).count();
+        let mut shown = usize::from(alphanumeric >= 4);
+        found
+            .chars()
+            .map(|c| {
+                if !c.is_alphanumeric() {
+                    c
+                } else if shown > 0 {
+                    shown -= 1;
+                    c
+                } else {
+                    '*'
+                }
+            })
+            .collect()
+    }
+}
+
+impl Validator for PiiValidator {
+    fn name(&self) -> &'static str {
+        "pii_validator"
+    }
+
+    fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
+        let mut observations = Vec::new();
+
+        for col_schema in &schema.columns {
+            if col_schema.inferred_type != ColumnType::String
+                || col_schema.semantic_role == SemanticRole::Identifier
+            {
+                continue;
+            }
+            let values: Vec<&str> = table
+                .column_values(col_schema.position)
+                .map(str::trim)
+                .filter(|v| !v.is_empty() && !DataTable::is_null_value(v))
+                .collect();
+            if Self::is_uniform_code(&values) {
+                continue;
+            }
+
+            let mut counts: IndexMap<&str, usize> = IndexMap::new();
+            let mut rows = Vec::new();
+            let mut samples = Vec::new();
+            let mut total_non_null = 0;
+
+            for (row_idx, value) in table.column_values(col_schema.position).enumerate() {
+                let trimmed = value.trim();
+                if DataTable::is_null_value(trimmed) || trimmed.is_empty() {
+                    continue;
+                }
+                total_non_null += 1;
+
+                let mut flagged = false;
+                for pattern in &self.patterns {
+                    let found = pattern
+                        .regex
+                        .find_iter(trimmed)
+                        .find(|&m| Self::is_plausible(&pattern.label, trimmed, m))
+                        .map(|m| m.as_str().trim());
+                    if let Some(found) = found {
+                        *counts.entry(pattern.label.as_str()).or_default() += 1;
+                        if samples.len() < 3 {
+                            samples.push(Self::mask(found));
+                        }
+                        flagged = true;
+                    }
+                }
+                if flagged {
+                    rows.push(row_idx);
+                }
+            }
+
+            if rows.is_empty() {
+                continue;
+            }
+
+            // Report kinds in pattern order
+            counts.sort_by_cached_key(|label, _| {
+                self.patterns.iter().position(|p| p.label == *label)
+            });
+            let pct = (rows.len() as f64 / total_non_null as f64) * 100.0;
+            let kinds: Vec<String> = counts
+                .iter()
+                .map(|(label, count)| format!("{} {}", count, label))
+                .collect();
+
+            observations.push(
+                Observation::new(
+                    ObservationType::SensitiveData,
+                    Severity::Error,
+                    &col_schema.name,
+                    format!(
+                        "{} value(s) ({:.1}%) contain possible personal identifiers ({}): {:?}",
+                        rows.len(),
+                        pct,
+                        kinds.join(", "),
+                        samples
+                    ),
+                )
+                .with_evidence(
+                    Evidence::new()
+                        .with_occurrences(rows.len())
+                        .with_percentage(pct)
+                        .with_sample_rows(rows.into_iter().take(5).collect())
+                        .with_value_counts(Some(json!(counts))),
+                )
+                .with_confidence(0.85)
+                .with_detector("pii_validator"),
+            );
+        }
+
+        observations
+    }
+}
+
+// ============================================================================
+// Cross-Column Validator
+// ============================================================================
+
+/// Date pairs that should be in order, as column name patterns.
+const DATE_PAIRS: [(&[&str], &[&str], &str); 4] = [
+    (
+        &["start_date", "start", "begin", "enrollment"],
+        &["end_date", "end", "finish", "completion"],
+        "start date should be before end date",
+    ),
+    (
+        &["birth", "dob", "date_of_birth"],
+        &["death", "dod", "date_of_death"],
+        "birth date should be before death date",
+    ),
+    (
+        &["admission", "admit"],
+        &["discharge"],
+        "admission should be before discharge",
+    ),
+    (
+        &["diagnosis_date", "dx_date"],
+        &["treatment_date", "tx_date"],
+        "diagnosis should typically precede treatment",
+    ),
+];
+
+/// Name patterns of the BMI, weight and height columns.
+const BMI_COLUMNS: [&[&str]; 3] = [&["bmi"], &["weight", "wt"], &["height", "ht"]];
+
+/// Name patterns of the sex and pregnancy columns.
+const SEX_PREGNANCY_COLUMNS: [&[&str]; 2] = [&["sex", "gender"], &["pregnant", "pregnancy"]];
+
+/// Name patterns of the age and diagnosis columns.
+const AGE_DIAGNOSIS_COLUMNS: [&[&str]; 2] = [&["age"], &["diagnosis", "dx", "condition"]];
+
+/// Validates logical relationships between columns.
+pub struct CrossColumnValidator;
+
+impl Validator for CrossColumnValidator {
+    fn name(&self) -> &'static str {
+        "cross_column_validator"
+    }
+
+    fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
         // Check date relationships
@@ -1903,20 +3706,54 @@ impl Validator for CrossColumnValidator {
 }
 
 impl CrossColumnValidator {
+    /// Columns that a check relates to any of the `selected` ones.
+    ///
+    /// When analysis is restricted to some columns, these are kept too so
+    /// the checks on the selected columns can still run.
+    pub fn related_columns<S: AsRef<str>>(table: &DataTable, selected: &[S]) -> Vec<String> {
+        let is_selected =
+            |index: usize| selected.iter().any(|name| table.column_index(name.as_ref()) == Some(index));
+        let checks = DATE_PAIRS
+            .iter()
+            .map(|(start, end, _)| vec![*start, *end])
+            .chain([BMI_COLUMNS.to_vec(), SEX_PREGNANCY_COLUMNS.to_vec(), AGE_DIAGNOSIS_COLUMNS.to_vec()]);
+
+        let mut related: Vec<String> = Vec::new();
+        for check in checks {
+            let positions: Option<Vec<usize>> =
+                check.iter().map(|patterns| Self::find_position(table, patterns)).collect();
+            if let Some(positions) = positions
+                && positions.iter().any(|&index| is_selected(index))
+            {
+                for index in positions {
+                    let name = &table.headers[index];
+                    if !is_selected(index) && !related.contains(name) {
+                        related.push(name.clone());
+                    }
+                }
+            }
+        }
+        related
+    }
+
+    /// Find a column's position by name pattern (case-insensitive).
+    fn find_position(table: &DataTable, patterns: &[&str]) -> Option<usize> {
+        // A column named exactly after a pattern beats a partial match
+        patterns.iter().find_map(|pattern| table.column_index(pattern)).or_else(|| {
+            table.headers.iter().position(|header| {
+                let lower = header.to_lowercase();
+                patterns.iter().any(|pattern| lower.contains(pattern))
+            })
+        })
+    }
+
     /// Find column by name pattern (case-insensitive).
     fn find_column<'a>(
+        table: &DataTable,
         schema: &'a TableSchema,
         patterns: &[&str],
     ) -> Option<&'a ColumnSchema> {
-        for col in &schema.columns {
-            let lower = col.name.to_lowercase();
-            for pattern in patterns {
-                if lower.contains(pattern) {
-                    return Some(col);
-                }
-            }
-        }
-        None
+        Self::find_position(table, patterns).and_then(|index| schema.get_column_by_position(index))
     }
 
     /// Check date column relationships (start < end, birth < death, etc.).
@@ -1927,33 +3764,9 @@ impl CrossColumnValidator {
     ) -> Vec<Observation> {
         let mut observations = Vec::new();
 
-        // Common date pairs to check
-        let date_pairs = [
-            (
-                &["start_date", "start", "begin", "enrollment"][..],
-                &["end_date", "end", "finish", "completion"][..],
-                "start date should be before end date",
-            ),
-            (
-                &["birth", "dob", "date_of_birth"][..],
-                &["death", "dod", "date_of_death"][..],
-                "birth date should be before death date",
-            ),
-            (
-                &["admission", "admit"][..],
-                &["discharge"][..],
-                "admission should be before discharge",
-            ),
-            (
-                &["diagnosis_date", "dx_date"][..],
-                &["treatment_date", "tx_date"][..],
-                "diagnosis should typically precede treatment",
-            ),
-        ];
-
-        for (start_patterns, end_patterns, description) in &date_pairs {
-            let start_col = Self::find_column(schema, start_patterns);
-            let end_col = Self::find_column(schema, end_patterns);
+        for (start_patterns, end_patterns, description) in &DATE_PAIRS {
+            let start_col = Self::find_column(table, schema, start_patterns);
+            let end_col = Self::find_column(table, schema, end_patterns);
 
             if let (Some(start), Some(end)) = (start_col, end_col) {
                 let issues = self.compare_date_columns(table, start, end);
@@ -2011,40 +3824,51 @@ impl CrossColumnValidator {
             }
 
             // Try to parse dates and compare
-            if let (Some(start_date), Some(end_date)) =
-                (self.parse_date(start_val), self.parse_date(end_val))
-            {
-                if start_date > end_date {
-                    issues.push((row_idx, start_val.to_string(), end_val.to_string()));
-                }
+            let out_of_order = match (self.parse_date(start_val), self.parse_date(end_val)) {
+                (Some((start, true)), Some((end, true))) => start > end,
+                // A date without a time covers the whole day
+                (Some((start, _)), Some((end, _))) => start.date() > end.date(),
+                // Unrecognized formats fall back to string comparison
+                (None, None) => start_val.trim() > end_val.trim(),
+                _ => false,
+            };
+            if out_of_order {
+                issues.push((row_idx, start_val.to_string(), end_val.to_string()));
             }
         }
 
         issues
     }
 
-    /// Simple date parser that returns a comparable string (YYYY-MM-DD format).
-    fn parse_date(&self, value: &str) -> Option<String> {
+    /// Parse a date or datetime into a comparable timestamp, and whether it
+    /// has a time of day.
+    ///
+    /// Times in UTC (`Z`) or with an offset are converted to UTC; times
+    /// without a timezone are taken as written. Slashed dates are read as
+    /// MM/DD/YYYY.
+    fn parse_date(&self, value: &str) -> Option<(NaiveDateTime, bool)> {
         let trimmed = value.trim();
 
-        // ISO format: YYYY-MM-DD
-        if trimmed.len() == 10 && trimmed.chars().nth(4) == Some('-') {
-            return Some(trimmed.to_string());
+        if let Ok(datetime) = DateTime::parse_from_rfc3339(trimmed) {
+            return Some((datetime.naive_utc(), true));
+        }
+        for format in ["%Y-%m-%dT%H:%M:%S%.f%z", "%Y-%m-%d %H:%M:%S%.f%z", "%Y-%m-%dT%H:%M%z", "%Y-%m-%d %H:%M%z"] {
+            if let Ok(datetime) = DateTime::parse_from_str(trimmed, format) {
+                return Some((datetime.naive_utc(), true));
+            }
         }
 
-        // Try other common formats
-        // MM/DD/YYYY or DD/MM/YYYY - assume MM/DD/YYYY for US
-        if let Some(parts) = trimmed.split('/').collect::<Vec<_>>().get(0..3) {
-            if parts.len() == 3 {
-                let (p1, p2, p3) = (parts[0], parts[1], parts[2]);
-                if p3.len() == 4 {
-                    return Some(format!("{}-{:0>2}-{:0>2}", p3, p1, p2));
-                }
+        let naive = trimmed.strip_suffix(['Z', 'z']).unwrap_or(trimmed);
+        for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M"] {
+            if let Ok(datetime) = NaiveDateTime::parse_from_str(naive, format) {
+                return Some((datetime, true));
             }
         }
 
-        // Just use string comparison as fallback
-        Some(trimmed.to_string())
+        ["%Y-%m-%d", "%m/%d/%Y"]
+            .iter()
+            .find_map(|format| NaiveDate::parse_from_str(trimmed, format).ok())
+            .map(|date| (date.and_time(chrono::NaiveTime::MIN), false))
     }
 
     /// Check BMI vs weight/height consistency.
@@ -2055,9 +3879,10 @@ impl CrossColumnValidator {
     ) -> Vec<Observation> {
         let mut observations = Vec::new();
 
-        let bmi_col = Self::find_column(schema, &["bmi"]);
-        let weight_col = Self::find_column(schema, &["weight", "wt"]);
-        let height_col = Self::find_column(schema, &["height", "ht"]);
+        let [bmi, weight, height] = BMI_COLUMNS;
+        let bmi_col = Self::find_column(table, schema, bmi);
+        let weight_col = Self::find_column(table, schema, weight);
+        let height_col = Self::find_column(table, schema, height);
 
         if let (Some(bmi), Some(weight), Some(height)) = (bmi_col, weight_col, height_col) {
             let issues = self.check_bmi_calculation(table, bmi, weight, height);
@@ -2144,8 +3969,9 @@ impl CrossColumnValidator {
     ) -> Vec<Observation> {
         let mut observations = Vec::new();
 
-        let sex_col = Self::find_column(schema, &["sex", "gender"]);
-        let pregnant_col = Self::find_column(schema, &["pregnant", "pregnancy"]);
+        let [sex, pregnant] = SEX_PREGNANCY_COLUMNS;
+        let sex_col = Self::find_column(table, schema, sex);
+        let pregnant_col = Self::find_column(table, schema, pregnant);
 
         if let (Some(sex), Some(pregnant)) = (sex_col, pregnant_col) {
             let mut issues = Vec::new();
@@ -2205,11 +4031,12 @@ impl CrossColumnValidator {
     ) -> Vec<Observation> {
         let mut observations = Vec::new();
 
-        let age_col = Self::find_column(schema, &["age"]);
+        let [age, diagnosis] = AGE_DIAGNOSIS_COLUMNS;
+        let age_col = Self::find_column(table, schema, age);
 
         if let Some(age) = age_col {
             // Check for pediatric-only or adult-only conditions
-            let diagnosis_col = Self::find_column(schema, &["diagnosis", "dx", "condition"]);
+            let diagnosis_col = Self::find_column(table, schema, diagnosis);
 
             if let Some(dx) = diagnosis_col {
                 // Conditions that are unusual in certain age groups
@@ -2317,6 +4144,10 @@ impl TitleCaseValidator {
 }
 
 impl Validator for TitleCaseValidator {
+    fn name(&self) -> &'static str {
+        "title_case_validator"
+    }
+
     fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
@@ -2329,13 +4160,20 @@ impl Validator for TitleCaseValidator {
             let mut lowercase_values: IndexMap<String, (usize, Vec<usize>)> = IndexMap::new();
 
             for (row_idx, value) in table.column_values(col_schema.position).enumerate() {
-                if Self::needs_title_case(value) {
-                    let entry = lowercase_values
-                        .entry(value.to_string())
-                        .or_insert((0, Vec::new()));
-                    entry.0 += 1;
-                    if entry.1.len() < 5 {
-                        entry.1.push(row_idx + 1); // 1-indexed
+                // Items of a list are checked one by one
+                let items = match col_schema.inferred_type.item_delimiter() {
+                    Some(_) => col_schema.inferred_type.items(value),
+                    None => vec![value],
+                };
+                for item in items {
+                    if Self::needs_title_case(item) {
+                        let entry = lowercase_values
+                            .entry(item.to_string())
+                            .or_insert((0, Vec::new()));
+                        entry.0 += 1;
+                        if entry.1.len() < 5 {
+                            entry.1.push(row_idx + 1); // 1-indexed
+                        }
                     }
                 }
             }
@@ -2402,6 +4240,170 @@ impl Validator for TitleCaseValidator {
     }
 }
 
+// ============================================================================
+// Referential Integrity Validator
+// ============================================================================
+
+/// Detects identifier values that reference a key missing from the table
+/// (e.g. a `parent_sample_id` with no matching `sample_id`).
+///
+/// Reference pairs are declared with [`ContextHints::with_reference`] or
+/// discovered by name: a column ending in `_id` refers to another column
+/// whose name is a suffix of its own of at least two words
+/// (`parent_sample_id` → `sample_id`, but never a bare `id`). Orphans are
+/// errors either way; those in discovered pairs, which are only a guess,
+/// get a lower confidence. Keys come from the same table, so a row may
+/// reference itself or any other row.
+pub struct ReferentialIntegrityValidator {
+    /// Referenced (parent) column by referencing (child) column.
+    references: HashMap<String, String>,
+    /// Whether to discover reference pairs from column names.
+    discover: bool,
+}
+
+impl Default for ReferentialIntegrityValidator {
+    fn default() -> Self {
+        Self {
+            references: HashMap::new(),
+            discover: true,
+        }
+    }
+}
+
+impl ReferentialIntegrityValidator {
+    /// Create a validator that discovers reference pairs from column names.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also check the references declared in context hints.
+    pub fn with_references(mut self, references: HashMap<String, String>) -> Self {
+        self.references = references;
+        self
+    }
+
+    /// Only check declared references.
+    pub fn without_discovery(mut self) -> Self {
+        self.discover = false;
+        self
+    }
+
+    /// Find the column a `*_id` column refers to by name, if any.
+    fn discover_parent(table: &DataTable, child: &str) -> Option<usize> {
+        let lower = child.to_lowercase();
+        let tokens: Vec<&str> = lower.split('_').collect();
+        if tokens.len() < 3 || tokens.last() != Some(&"id") {
+            return None;
+        }
+
+        // Prefer the longest matching suffix; a bare `id` is too generic
+        (1..tokens.len() - 1).find_map(|start| table.column_index(&tokens[start..].join("_")))
+    }
+
+    /// Resolve (child, parent, declared) column positions to check.
+    fn reference_pairs(&self, table: &DataTable) -> Vec<(usize, usize, bool)> {
+        let mut declared: Vec<(&String, &String)> = self.references.iter().collect();
+        declared.sort();
+
+        let mut pairs: Vec<(usize, usize, bool)> = declared
+            .into_iter()
+            .filter_map(|(child, parent)| {
+                Some((table.column_index(child)?, table.column_index(parent)?, true))
+            })
+            .collect();
+
+        if self.discover {
+            for (child, name) in table.headers.iter().enumerate() {
+                if pairs.iter().any(|(c, _, _)| *c == child) {
+                    continue;
+                }
+                if let Some(parent) = Self::discover_parent(table, name).filter(|p| *p != child) {
+                    pairs.push((child, parent, false));
+                }
+            }
+        }
+
+        pairs
+    }
+}
+
+impl Validator for ReferentialIntegrityValidator {
+    fn name(&self) -> &'static str {
+        "referential_integrity_validator"
+    }
+
+    fn validate(&self, table: &DataTable, _schema: &TableSchema) -> Vec<Observation> {
+        let mut observations = Vec::new();
+
+        for (child, parent, declared) in self.reference_pairs(table) {
+            let keys: HashSet<&str> = table
+                .column_values(parent)
+                .filter(|v| !DataTable::is_null_value(v))
+                .map(str::trim)
+                .collect();
+
+            let mut orphans: IndexMap<&str, Vec<usize>> = IndexMap::new();
+            for (row_idx, value) in table.column_values(child).enumerate() {
+                if DataTable::is_null_value(value) || keys.contains(value.trim()) {
+                    continue;
+                }
+                orphans.entry(value.trim()).or_default().push(row_idx);
+            }
+
+            let count: usize = orphans.values().map(Vec::len).sum();
+            if count == 0 {
+                continue;
+            }
+
+            let child_name = &table.headers[child];
+            let parent_name = &table.headers[parent];
+            let mut rows: Vec<usize> = orphans.values().flatten().copied().collect();
+            rows.sort_unstable();
+            let examples: Vec<String> = orphans
+                .iter()
+                .take(3)
+                .map(|(value, rows)| format!("{:?} (row {})", value, rows[0] + 1))
+                .collect();
+            let pct = (count as f64 / table.row_count() as f64) * 100.0;
+
+            observations.push(
+                Observation::new(
+                    ObservationType::CrossColumnInconsistency,
+                    Severity::Error,
+                    child_name,
+                    format!(
+                        "{} value(s) ({:.1}%) reference a missing '{}': {}",
+                        count,
+                        pct,
+                        parent_name,
+                        examples.join(", ")
+                    ),
+                )
+                .with_evidence(
+                    Evidence::new()
+                        .with_occurrences(count)
+                        .with_percentage(pct)
+                        .with_sample_rows(rows.into_iter().take(5).collect())
+                        .with_expected(json!({ "references": parent_name }))
+                        .with_value_counts(Some(json!(orphans
+                            .iter()
+                            .take(10)
+                            .map(|(value, rows)| (value.to_string(), rows.clone()))
+                            .collect::<IndexMap<_, _>>()))),
+                )
+                .with_confidence(if declared { 0.95 } else { 0.8 })
+                .with_detector("referential_integrity_validator"),
+            );
+        }
+
+        observations
+    }
+
+    fn full_table_check(&self) -> Option<&'static str> {
+        Some("referential integrity")
+    }
+}
+
 // ============================================================================
 // Coordinate Validator
 // ============================================================================
@@ -2461,6 +4463,35 @@ impl CoordinateValidator {
         None
     }
 
+    /// Determine how to validate a column as coordinates, if at all.
+    ///
+    /// Columns whose name identifies them are validated by name. Other columns
+    /// inferred as [`SemanticRole::Coordinate`] are validated as lat/lon pairs
+    /// when most values are pairs, and otherwise as single values within the
+    /// longitude range (the wider of the two).
+    fn coordinate_type(table: &DataTable, col_schema: &ColumnSchema) -> Option<CoordinateType> {
+        if let Some(coord_type) = Self::is_coordinate_column(&col_schema.name) {
+            return Some(coord_type);
+        }
+        if col_schema.semantic_role != SemanticRole::Coordinate {
+            return None;
+        }
+
+        let (pairs, total) = table
+            .column_values(col_schema.position)
+            .filter(|v| !DataTable::is_null_value(v.trim()))
+            .fold((0, 0), |(pairs, total), v| {
+                let is_pair = Self::parse_combined(v).is_some();
+                (pairs + usize::from(is_pair), total + 1)
+            });
+
+        if total > 0 && pairs * 2 >= total {
+            Some(CoordinateType::Combined)
+        } else {
+            Some(CoordinateType::Longitude)
+        }
+    }
+
     /// Parse a coordinate value and return (value, format).
     fn parse_coordinate(value: &str) -> Option<(f64, CoordinateFormat)> {
         let trimmed = value.trim();
@@ -2624,11 +4655,15 @@ impl CoordinateFormat {
 }
 
 impl Validator for CoordinateValidator {
+    fn name(&self) -> &'static str {
+        "coordinate_validator"
+    }
+
     fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
         for col_schema in &schema.columns {
-            let coord_type = match Self::is_coordinate_column(&col_schema.name) {
+            let coord_type = match Self::coordinate_type(table, col_schema) {
                 Some(ct) => ct,
                 None => continue,
             };
@@ -2915,285 +4950,985 @@ impl CoordinateValidator {
 }
 
 // ============================================================================
-// Duplicate Row Validator
+// Unit Normalization Validator
 // ============================================================================
 
-/// Validates for duplicate or near-duplicate rows.
+/// A measured quantity whose unit can be told apart by value range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Quantity {
+    Weight,
+    Height,
+}
+
+/// A unit of measurement and the values typical for people measured in it.
+struct UnitSpec {
+    /// Canonical unit name.
+    name: &'static str,
+    /// Spellings accepted in column names and unit hints.
+    aliases: &'static [&'static str],
+    quantity: Quantity,
+    /// Multiplier to the quantity's base unit (kg or cm).
+    to_base: f64,
+    /// Range of typical values for adults.
+    typical: (f64, f64),
+}
+
+/// Known units. Typical ranges overlap only where the unit is ambiguous.
+const UNITS: &[UnitSpec] = &[
+    UnitSpec {
+        name: "kg",
+        aliases: &["kg", "kgs", "kilogram", "kilograms"],
+        quantity: Quantity::Weight,
+        to_base: 1.0,
+        typical: (30.0, 130.0),
+    },
+    UnitSpec {
+        name: "lb",
+        aliases: &["lb", "lbs", "pound", "pounds"],
+        quantity: Quantity::Weight,
+        to_base: 0.453_592_37,
+        typical: (100.0, 330.0),
+    },
+    UnitSpec {
+        name: "cm",
+        aliases: &["cm", "centimeter", "centimeters", "centimetre", "centimetres"],
+        quantity: Quantity::Height,
+        to_base: 1.0,
+        typical: (120.0, 210.0),
+    },
+    UnitSpec {
+        name: "m",
+        aliases: &["m", "meter", "meters", "metre", "metres"],
+        quantity: Quantity::Height,
+        to_base: 100.0,
+        typical: (1.2, 2.1),
+    },
+    UnitSpec {
+        name: "in",
+        aliases: &["in", "inch", "inches"],
+        quantity: Quantity::Height,
+        to_base: 2.54,
+        typical: (50.0, 80.0),
+    },
+];
+
+/// Detects weight and height columns recorded in a non-standard unit.
 ///
-/// Detects:
-/// - Exact duplicate rows (all values identical)
-/// - Near-duplicates (same values except for identifier column)
-/// - Conflicting duplicates (same ID but different values)
-pub struct DuplicateRowValidator {
-    /// Columns to exclude when checking for duplicates (typically identifiers).
-    exclude_patterns: Vec<&'static str>,
+/// The unit is inferred from the value distribution (e.g. weights of
+/// 100–300 suggest pounds, heights of 50–80 suggest inches). To stay
+/// conservative, a unit is only reported when nearly all values fall in
+/// its typical range and in no other unit's. Targets default to kg and cm
+/// and can be overridden per column with [`ContextHints::with_unit`].
+pub struct UnitNormalizationValidator {
+    /// Intended unit by column name.
+    target_units: HashMap<String, String>,
+    /// Minimum fraction of values that must fit the detected unit.
+    min_fit: f64,
+    /// Minimum number of numeric values needed to judge.
+    min_values: usize,
 }
 
-impl Default for DuplicateRowValidator {
+impl Default for UnitNormalizationValidator {
     fn default() -> Self {
         Self {
-            exclude_patterns: vec![
-                "id", "sample_id", "patient_id", "subject_id", "row_id",
-                "index", "uuid", "guid", "key", "record_id",
-            ],
+            target_units: HashMap::new(),
+            min_fit: 0.95,
+            min_values: 5,
         }
     }
 }
 
-impl DuplicateRowValidator {
-    /// Check if a column should be excluded from duplicate detection.
-    fn is_excluded_column(&self, name: &str) -> bool {
-        let lower = name.to_lowercase();
-        self.exclude_patterns.iter().any(|p| lower.contains(p))
+impl UnitNormalizationValidator {
+    /// Create a validator with default targets (kg, cm).
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    /// Get a hash key for a row (excluding identifier columns).
-    fn row_key(&self, table: &DataTable, schema: &TableSchema, row_idx: usize) -> String {
-        let mut parts = Vec::new();
-        for col in &schema.columns {
-            if !self.is_excluded_column(&col.name) {
-                let value = table.get(row_idx, col.position).unwrap_or_default();
-                parts.push(value.trim().to_lowercase());
-            }
-        }
-        parts.join("\0")
+    /// Use the intended units given in context hints.
+    pub fn with_target_units(mut self, units: HashMap<String, String>) -> Self {
+        self.target_units = units;
+        self
     }
 
-    /// Get a full row key (including all columns).
-    fn full_row_key(&self, table: &DataTable, row_idx: usize) -> String {
-        let mut parts = Vec::new();
-        for col_idx in 0..table.column_count() {
-            let value = table.get(row_idx, col_idx).unwrap_or_default();
-            parts.push(value.trim().to_lowercase());
-        }
-        parts.join("\0")
+    /// Look up a unit by name or alias.
+    fn unit(name: &str) -> Option<&'static UnitSpec> {
+        let lower = name.trim().to_lowercase();
+        UNITS.iter().find(|u| u.aliases.contains(&lower.as_str()))
     }
 
-    /// Find the primary identifier column.
-    fn find_id_column<'a>(&self, schema: &'a TableSchema) -> Option<&'a ColumnSchema> {
-        // Prefer columns explicitly marked as identifiers
-        if let Some(col) = schema.columns.iter().find(|c| c.semantic_role == SemanticRole::Identifier) {
-            return Some(col);
-        }
+    /// The default unit for a quantity.
+    fn default_unit(quantity: Quantity) -> &'static UnitSpec {
+        let name = match quantity {
+            Quantity::Weight => "kg",
+            Quantity::Height => "cm",
+        };
+        UNITS.iter().find(|u| u.name == name).unwrap()
+    }
 
-        // Fall back to name-based detection
-        for col in &schema.columns {
-            let lower = col.name.to_lowercase();
-            if lower == "sample_id"
-                || lower == "id"
-                || lower == "patient_id"
-                || lower == "subject_id"
-            {
-                return Some(col);
-            }
+    /// Split a column name into lowercase word tokens.
+    fn name_tokens(name: &str) -> Vec<String> {
+        name.split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|t| !t.is_empty())
+            .map(|t| t.to_lowercase())
+            .collect()
+    }
+
+    /// Infer the measured quantity from a column name.
+    fn quantity_from_name(tokens: &[String]) -> Option<Quantity> {
+        if tokens.iter().any(|t| matches!(t.as_str(), "weight" | "wt" | "mass")) {
+            Some(Quantity::Weight)
+        } else if tokens.iter().any(|t| matches!(t.as_str(), "height" | "ht" | "stature")) {
+            Some(Quantity::Height)
+        } else {
+            None
         }
+    }
 
-        // Use first column if it looks like an ID
-        if let Some(first) = schema.columns.first() {
-            let lower = first.name.to_lowercase();
-            if lower.contains("id") || lower.contains("sample") || lower.contains("subject") {
-                return Some(first);
-            }
+    /// Find the single unit whose typical range fits the values.
+    fn detect_unit(&self, values: &[f64], quantity: Quantity) -> Option<(&'static UnitSpec, f64)> {
+        if values.len() < self.min_values {
+            return None;
         }
 
-        None
+        let mut fitting = UNITS.iter().filter(|u| u.quantity == quantity).filter_map(|u| {
+            let (lo, hi) = u.typical;
+            let inside = values.iter().filter(|v| (lo..=hi).contains(*v)).count();
+            let fit = inside as f64 / values.len() as f64;
+            (fit >= self.min_fit).then_some((u, fit))
+        });
+
+        match (fitting.next(), fitting.next()) {
+            (Some(only), None) => Some(only),
+            _ => None,
+        }
     }
 }
 
-impl Validator for DuplicateRowValidator {
+impl Validator for UnitNormalizationValidator {
+    fn name(&self) -> &'static str {
+        "unit_normalization_validator"
+    }
+
     fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
         let mut observations = Vec::new();
 
-        if table.row_count() < 2 || schema.columns.is_empty() {
-            return observations;
-        }
-
-        // Find exact duplicates
-        let mut full_row_map: IndexMap<String, Vec<usize>> = IndexMap::new();
-        for row_idx in 0..table.row_count() {
-            let key = self.full_row_key(table, row_idx);
-            full_row_map.entry(key).or_default().push(row_idx);
-        }
+        for col_schema in &schema.columns {
+            if !col_schema.inferred_type.is_numeric() {
+                continue;
+            }
 
-        let exact_duplicates: Vec<_> = full_row_map
-            .values()
-            .filter(|rows| rows.len() > 1)
-            .cloned()
-            .collect();
+            let tokens = Self::name_tokens(&col_schema.name);
+            let hinted = self
+                .target_units
+                .get(&col_schema.name)
+                .and_then(|unit| Self::unit(unit));
 
-        if !exact_duplicates.is_empty() {
-            let total_dup_rows: usize = exact_duplicates.iter().map(|g| g.len() - 1).sum();
-            let pct = (total_dup_rows as f64 / table.row_count() as f64) * 100.0;
+            let (quantity, target) = match hinted {
+                Some(unit) => (unit.quantity, unit),
+                None => {
+                    // A unit written into the name is deliberate; leave it alone
+                    if tokens.iter().any(|t| Self::unit(t).is_some()) {
+                        continue;
+                    }
+                    let Some(quantity) = Self::quantity_from_name(&tokens) else {
+                        continue;
+                    };
+                    (quantity, Self::default_unit(quantity))
+                }
+            };
 
-            let sample_groups: Vec<String> = exact_duplicates
-                .iter()
-                .take(3)
-                .map(|rows| {
-                    format!(
-                        "rows {}",
-                        rows.iter()
-                            .map(|r| (r + 1).to_string())
-                            .collect::<Vec<_>>()
-                            .join(", ")
-                    )
-                })
+            let values: Vec<f64> = table
+                .column_values(col_schema.position)
+                .filter_map(|v| v.trim().parse::<f64>().ok())
                 .collect();
 
+            let Some((detected, fit)) = self.detect_unit(&values, quantity) else {
+                continue;
+            };
+            if detected.name == target.name {
+                continue;
+            }
+
+            let factor = detected.to_base / target.to_base;
+            let (lo, hi) = detected.typical;
+
             observations.push(
                 Observation::new(
-                    ObservationType::Duplicate,
-                    Severity::Error,
-                    "_table",
+                    ObservationType::NonStandardUnit,
+                    Severity::Info,
+                    &col_schema.name,
                     format!(
-                        "{} exact duplicate row(s) found in {} group(s): {}",
-                        total_dup_rows,
-                        exact_duplicates.len(),
-                        sample_groups.join("; ")
+                        "Values look like {} ({:.0}% within {}–{}), not {}",
+                        detected.name,
+                        fit * 100.0,
+                        lo,
+                        hi,
+                        target.name
                     ),
                 )
                 .with_evidence(
                     Evidence::new()
-                        .with_occurrences(total_dup_rows)
-                        .with_percentage(pct)
-                        .with_sample_rows(
-                            exact_duplicates
-                                .iter()
-                                .flat_map(|g| g.iter().skip(1))
-                                .take(5)
-                                .copied()
-                                .collect(),
-                        ),
+                        .with_occurrences(values.len())
+                        .with_percentage(fit * 100.0)
+                        .with_expected(json!({
+                            "detected_unit": detected.name,
+                            "target_unit": target.name,
+                            "factor": factor,
+                        })),
                 )
-                .with_confidence(0.98)
-                .with_detector("duplicate_row_validator"),
+                .with_confidence(0.9 * fit)
+                .with_detector("unit_normalization_validator"),
             );
         }
 
-        // Find near-duplicates (same content except ID column)
-        let mut content_map: IndexMap<String, Vec<usize>> = IndexMap::new();
-        for row_idx in 0..table.row_count() {
-            let key = self.row_key(table, schema, row_idx);
-            content_map.entry(key).or_default().push(row_idx);
-        }
-
-        let near_duplicates: Vec<_> = content_map
-            .values()
-            .filter(|rows| rows.len() > 1)
-            .cloned()
-            .collect();
+        observations
+    }
+}
 
-        // Only report near-duplicates that aren't also exact duplicates
-        let near_only: Vec<_> = near_duplicates
-            .into_iter()
-            .filter(|rows| {
-                // Check if these rows have different IDs
-                let id_col = self.find_id_column(schema);
-                if let Some(id) = id_col {
-                    let ids: std::collections::HashSet<_> = rows
-                        .iter()
-                        .map(|r| table.get(*r, id.position).unwrap_or_default().to_lowercase())
-                        .collect();
-                    ids.len() > 1 // Different IDs but same content
-                } else {
-                    false
-                }
-            })
-            .collect();
+// ============================================================================
+// Encoding Validator
+// ============================================================================
 
-        if !near_only.is_empty() {
-            let total_near: usize = near_only.iter().map(|g| g.len()).sum();
-            let pct = (total_near as f64 / table.row_count() as f64) * 100.0;
+/// Unicode replacement character, left behind when invalid bytes are decoded.
+const REPLACEMENT_CHAR: char = '\u{FFFD}';
+
+/// Characters Windows-1252 places at bytes 0x80–0x9F (the rest match Latin-1).
+const CP1252_HIGH: [(char, u8); 27] = [
+    ('€', 0x80),
+    ('‚', 0x82),
+    ('ƒ', 0x83),
+    ('„', 0x84),
+    ('…', 0x85),
+    ('†', 0x86),
+    ('‡', 0x87),
+    ('ˆ', 0x88),
+    ('‰', 0x89),
+    ('Š', 0x8A),
+    ('‹', 0x8B),
+    ('Œ', 0x8C),
+    ('Ž', 0x8E),
+    ('‘', 0x91),
+    ('’', 0x92),
+    ('“', 0x93),
+    ('”', 0x94),
+    ('•', 0x95),
+    ('–', 0x96),
+    ('—', 0x97),
+    ('˜', 0x98),
+    ('™', 0x99),
+    ('š', 0x9A),
+    ('›', 0x9B),
+    ('œ', 0x9C),
+    ('ž', 0x9E),
+    ('Ÿ', 0x9F),
+];
+
+/// Detects mis-decoded text in string columns.
+///
+/// Two kinds of damage are reported:
+/// - Mojibake such as `Ã©` for `é`, where UTF-8 bytes were read as
+///   Windows-1252/Latin-1. These values can be repaired by re-decoding.
+/// - Replacement characters (U+FFFD), where Latin-1 bytes were read as
+///   UTF-8. The original characters are lost and must be recovered from the
+///   source file.
+///
+/// Skipped when [`ContextHints::with_clean_encoding`] declares the source
+/// encoding already clean.
+pub struct EncodingValidator;
 
-            let id_col_name = self
-                .find_id_column(schema)
-                .map(|c| c.name.as_str())
-                .unwrap_or("ID");
+impl Validator for EncodingValidator {
+    fn name(&self) -> &'static str {
+        "encoding_validator"
+    }
 
-            observations.push(
-                Observation::new(
-                    ObservationType::Duplicate,
-                    Severity::Warning,
-                    "_table",
-                    format!(
-                        "{} row(s) have identical content but different {} values (potential data entry duplicates)",
-                        total_near,
-                        id_col_name
-                    ),
-                )
-                .with_evidence(
-                    Evidence::new()
-                        .with_occurrences(near_only.len())
-                        .with_percentage(pct)
-                        .with_sample_rows(
-                            near_only
-                                .iter()
-                                .flat_map(|g| g.iter())
-                                .take(5)
-                                .copied()
-                                .collect(),
-                        ),
-                )
-                .with_confidence(0.85)
-                .with_detector("duplicate_row_validator"),
-            );
-        }
+    fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
+        let mut observations = Vec::new();
 
-        // Find ID conflicts (same ID but different content)
-        if let Some(id_col) = self.find_id_column(schema) {
-            let mut id_map: IndexMap<String, Vec<usize>> = IndexMap::new();
-            for row_idx in 0..table.row_count() {
-                let id = table.get(row_idx, id_col.position).unwrap_or_default();
-                if !DataTable::is_null_value(id) && !id.is_empty() {
-                    id_map.entry(id.to_lowercase()).or_default().push(row_idx);
-                }
+        for col_schema in &schema.columns {
+            if col_schema.inferred_type != ColumnType::String {
+                continue;
             }
 
-            let mut conflicts: Vec<(String, Vec<usize>)> = Vec::new();
-            for (id, rows) in &id_map {
-                if rows.len() > 1 {
-                    // Check if content differs
-                    let contents: std::collections::HashSet<_> = rows
-                        .iter()
-                        .map(|r| self.row_key(table, schema, *r))
-                        .collect();
+            let mut affected_rows = Vec::new();
+            let mut repairs: IndexMap<String, (String, usize)> = IndexMap::new();
+            let mut replacement_count = 0;
 
-                    if contents.len() > 1 {
-                        conflicts.push((id.clone(), rows.clone()));
-                    }
+            for (row_idx, value) in table.column_values(col_schema.position).enumerate() {
+                if let Some(repaired) = Self::repair_mojibake(value) {
+                    repairs
+                        .entry(value.to_string())
+                        .or_insert((repaired, 0))
+                        .1 += 1;
+                    affected_rows.push(row_idx);
+                } else if value.contains(REPLACEMENT_CHAR) {
+                    replacement_count += 1;
+                    affected_rows.push(row_idx);
                 }
             }
 
-            if !conflicts.is_empty() {
-                let total_conflicts: usize = conflicts.iter().map(|(_, r)| r.len()).sum();
-                let pct = (total_conflicts as f64 / table.row_count() as f64) * 100.0;
+            if affected_rows.is_empty() {
+                continue;
+            }
 
-                let sample_ids: Vec<String> = conflicts
+            let mojibake_count = affected_rows.len() - replacement_count;
+            let guessed_encoding = if mojibake_count > 0 {
+                "UTF-8 decoded as Windows-1252/Latin-1 (double-encoded)"
+            } else {
+                "Latin-1 decoded as UTF-8"
+            };
+
+            let mut issues = Vec::new();
+            if mojibake_count > 0 {
+                let examples: Vec<String> = repairs
                     .iter()
                     .take(3)
-                    .map(|(id, rows)| format!("'{}' (rows {})", id, rows.len()))
+                    .map(|(garbled, (repaired, _))| format!("'{}' → '{}'", garbled, repaired))
                     .collect();
+                issues.push(format!(
+                    "{} mis-decoded value(s) ({})",
+                    mojibake_count,
+                    examples.join(", ")
+                ));
+            }
+            if replacement_count > 0 {
+                issues.push(format!(
+                    "{} value(s) with replacement characters (�)",
+                    replacement_count
+                ));
+            }
 
-                observations.push(
-                    Observation::new(
-                        ObservationType::Inconsistency,
-                        Severity::Error,
-                        &id_col.name,
-                        format!(
-                            "{} {} value(s) appear multiple times with different data: {}",
-                            conflicts.len(),
-                            id_col.name,
-                            sample_ids.join(", ")
-                        ),
-                    )
-                    .with_evidence(
-                        Evidence::new()
-                            .with_occurrences(total_conflicts)
-                            .with_percentage(pct)
-                            .with_sample_rows(
-                                conflicts
-                                    .iter()
-                                    .flat_map(|(_, r)| r.iter())
-                                    .take(5)
+            let pct = (affected_rows.len() as f64 / table.row_count() as f64) * 100.0;
+            let obs = Observation::new(
+                ObservationType::Encoding,
+                Severity::Warning,
+                &col_schema.name,
+                format!(
+                    "Encoding problems: {}; likely {}",
+                    issues.join(" and "),
+                    guessed_encoding
+                ),
+            )
+            .with_evidence(
+                Evidence::new()
+                    .with_occurrences(affected_rows.len())
+                    .with_percentage(pct)
+                    .with_sample_rows(affected_rows.iter().take(5).copied().collect())
+                    .with_rows(affected_rows)
+                    .with_expected(json!({
+                        "guessed_encoding": guessed_encoding,
+                        "repairable": mojibake_count,
+                        "unrecoverable": replacement_count,
+                    }))
+                    .with_value_counts(Some(json!(
+                        repairs
+                            .iter()
+                            .map(|(garbled, (repaired, count))| {
+                                (
+                                    garbled.clone(),
+                                    json!({"suggestion": repaired, "count": count}),
+                                )
+                            })
+                            .collect::<IndexMap<_, _>>()
+                    ))),
+            )
+            .with_confidence(if mojibake_count > 0 { 0.9 } else { 0.8 })
+            .with_detector("encoding_validator");
+
+            observations.push(obs);
+        }
+
+        observations
+    }
+}
+
+impl EncodingValidator {
+    /// Undo UTF-8 text having been decoded as Windows-1252 or Latin-1.
+    ///
+    /// Returns the repaired value when every character maps back to a
+    /// single byte and those bytes form valid UTF-8 with at least one
+    /// multi-byte character; plain Latin-1 text like "café" is left alone.
+    pub fn repair_mojibake(value: &str) -> Option<String> {
+        if value.is_ascii() {
+            return None;
+        }
+
+        let bytes = value
+            .chars()
+            .map(|c| match u8::try_from(c) {
+                Ok(byte) => Some(byte),
+                Err(_) => CP1252_HIGH
+                    .iter()
+                    .find(|(ch, _)| *ch == c)
+                    .map(|(_, byte)| *byte),
+            })
+            .collect::<Option<Vec<u8>>>()?;
+
+        let repaired = String::from_utf8(bytes).ok()?;
+        (repaired != value).then_some(repaired)
+    }
+}
+
+// ============================================================================
+// Whitespace Validator
+// ============================================================================
+
+/// Detects leading, trailing, or repeated internal whitespace in categorical
+/// values (e.g., "stool " alongside "stool").
+///
+/// Only columns with `expected_values` or a categorical/binary semantic type
+/// are checked, so free-text columns where spacing may be meaningful are left
+/// alone. Each affected value is mapped to its trimmed and collapsed form.
+pub struct WhitespaceValidator;
+
+impl Validator for WhitespaceValidator {
+    fn name(&self) -> &'static str {
+        "whitespace_validator"
+    }
+
+    fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
+        let mut observations = Vec::new();
+
+        for col_schema in &schema.columns {
+            if col_schema.inferred_type != ColumnType::String {
+                continue;
+            }
+            let categorical = matches!(
+                col_schema.semantic_type,
+                SemanticType::Categorical | SemanticType::Binary
+            );
+            if !categorical && col_schema.expected_values.is_none() {
+                continue;
+            }
+
+            let mut affected_rows = Vec::new();
+            let mut fixes: IndexMap<String, (String, usize)> = IndexMap::new();
+            let (mut edge_count, mut internal_count) = (0, 0);
+
+            for (row_idx, value) in table.column_values(col_schema.position).enumerate() {
+                if DataTable::is_null_value(value) {
+                    continue;
+                }
+                let Some(normalized) = Self::normalize(value) else {
+                    continue;
+                };
+
+                if value.trim() != value {
+                    edge_count += 1;
+                }
+                if value.trim() != normalized {
+                    internal_count += 1;
+                }
+                fixes
+                    .entry(value.to_string())
+                    .or_insert((normalized, 0))
+                    .1 += 1;
+                affected_rows.push(row_idx);
+            }
+
+            if affected_rows.is_empty() {
+                continue;
+            }
+
+            let examples: Vec<String> = fixes
+                .iter()
+                .take(3)
+                .map(|(raw, (normalized, _))| format!("{:?} → {:?}", raw, normalized))
+                .collect();
+            let pct = (affected_rows.len() as f64 / table.row_count() as f64) * 100.0;
+
+            let obs = Observation::new(
+                ObservationType::Inconsistency,
+                Severity::Warning,
+                &col_schema.name,
+                format!(
+                    "Extra whitespace in {} value(s): {}",
+                    affected_rows.len(),
+                    examples.join(", ")
+                ),
+            )
+            .with_evidence(
+                Evidence::new()
+                    .with_occurrences(affected_rows.len())
+                    .with_percentage(pct)
+                    .with_sample_rows(affected_rows.into_iter().take(5).collect())
+                    .with_expected(json!({
+                        "leading_or_trailing": edge_count,
+                        "internal": internal_count,
+                    }))
+                    .with_value_counts(Some(json!(
+                        fixes
+                            .iter()
+                            .map(|(raw, (normalized, count))| {
+                                (
+                                    raw.clone(),
+                                    json!({"suggestion": normalized, "count": count}),
+                                )
+                            })
+                            .collect::<IndexMap<_, _>>()
+                    ))),
+            )
+            .with_confidence(0.95)
+            .with_detector("whitespace_validator");
+
+            observations.push(obs);
+        }
+
+        observations
+    }
+}
+
+impl WhitespaceValidator {
+    /// Trim a value and collapse runs of internal whitespace to one space.
+    ///
+    /// Returns `None` when the value is already clean.
+    pub fn normalize(value: &str) -> Option<String> {
+        let normalized = value.split_whitespace().collect::<Vec<_>>().join(" ");
+        (normalized != value).then_some(normalized)
+    }
+}
+
+// ============================================================================
+// Precision Validator
+// ============================================================================
+
+/// Detects inconsistent decimal precision in numeric columns (`5`, `5.0`,
+/// and `5.00` mixed) and values far more precise than the rest of the
+/// column (`4.99999999` among two-decimal values).
+///
+/// The modal number of decimal places is taken as the column's precision.
+/// Only reported when most values share it, so columns without a
+/// convention (e.g. computed ratios) are left alone.
+pub struct PrecisionValidator {
+    /// Minimum share of values at the modal precision.
+    min_modal_share: f64,
+    /// Decimal places beyond the mode that make a value excessively precise.
+    excess_places: usize,
+    /// Minimum number of numeric values needed to judge.
+    min_values: usize,
+}
+
+impl Default for PrecisionValidator {
+    fn default() -> Self {
+        Self {
+            min_modal_share: 0.6,
+            excess_places: 3,
+            min_values: 5,
+        }
+    }
+}
+
+impl PrecisionValidator {
+    /// Number of decimal places written in a numeric value, if it is a
+    /// plain decimal number (not scientific notation).
+    pub fn decimal_places(value: &str) -> Option<usize> {
+        let value = value.trim();
+        if value.contains(['e', 'E']) || value.parse::<f64>().is_err() {
+            return None;
+        }
+        Some(value.split_once('.').map_or(0, |(_, fraction)| fraction.len()))
+    }
+}
+
+impl Validator for PrecisionValidator {
+    fn name(&self) -> &'static str {
+        "precision_validator"
+    }
+
+    fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
+        let mut observations = Vec::new();
+
+        for col_schema in &schema.columns {
+            if col_schema.inferred_type != ColumnType::Float
+                || col_schema.semantic_role == SemanticRole::Identifier
+            {
+                continue;
+            }
+
+            let places: Vec<(usize, &str, usize)> = table
+                .column_values(col_schema.position)
+                .enumerate()
+                .filter(|(_, value)| !DataTable::is_null_value(value))
+                .filter_map(|(row, value)| Some((row, value, Self::decimal_places(value)?)))
+                .collect();
+            if places.len() < self.min_values {
+                continue;
+            }
+
+            let mut counts: BTreeMap<usize, usize> = BTreeMap::new();
+            for (_, _, n) in &places {
+                *counts.entry(*n).or_insert(0) += 1;
+            }
+            // Ties go to the lower precision
+            let (modal, modal_count) = counts
+                .iter()
+                .max_by_key(|(n, count)| (**count, std::cmp::Reverse(**n)))
+                .map(|(n, count)| (*n, *count))
+                .unwrap();
+            if counts.len() < 2 || (modal_count as f64) < self.min_modal_share * places.len() as f64
+            {
+                continue;
+            }
+
+            let is_excessive = |n: usize| n > modal + self.excess_places;
+            let off_mode: Vec<&(usize, &str, usize)> =
+                places.iter().filter(|(_, _, n)| *n != modal).collect();
+            let excessive: Vec<(usize, &str)> = off_mode
+                .iter()
+                .filter(|(_, _, n)| is_excessive(*n))
+                .map(|(row, value, _)| (*row, *value))
+                .collect();
+
+            let pct = (off_mode.len() as f64 / table.row_count() as f64) * 100.0;
+            let mut description = format!(
+                "Inconsistent precision: {} value(s) ({:.1}%) don't have the usual {} decimal place(s)",
+                off_mode.len(),
+                pct,
+                modal
+            );
+            if !excessive.is_empty() {
+                let examples: Vec<String> = excessive
+                    .iter()
+                    .take(3)
+                    .map(|(row, value)| format!("{:?} (row {})", value, row + 1))
+                    .collect();
+                description.push_str(&format!(
+                    "; {} are far more precise: {}",
+                    excessive.len(),
+                    examples.join(", ")
+                ));
+            }
+
+            // Overly precise values are the likelier errors, so show them first
+            let sample_rows: Vec<usize> = excessive
+                .iter()
+                .map(|(row, _)| *row)
+                .chain(
+                    off_mode
+                        .iter()
+                        .filter(|(_, _, n)| !is_excessive(*n))
+                        .map(|(row, _, _)| *row),
+                )
+                .take(5)
+                .collect();
+
+            observations.push(
+                Observation::new(
+                    ObservationType::Inconsistency,
+                    if excessive.is_empty() {
+                        Severity::Info
+                    } else {
+                        Severity::Warning
+                    },
+                    &col_schema.name,
+                    description,
+                )
+                .with_evidence(
+                    Evidence::new()
+                        .with_occurrences(off_mode.len())
+                        .with_percentage(pct)
+                        .with_sample_rows(sample_rows)
+                        .with_expected(json!({
+                            "modal_decimals": modal,
+                            "excessive": excessive.len(),
+                        }))
+                        .with_value_counts(Some(json!(counts
+                            .iter()
+                            .map(|(n, count)| (n.to_string(), *count))
+                            .collect::<BTreeMap<_, _>>()))),
+                )
+                .with_confidence(0.8)
+                .with_detector("precision_validator"),
+            );
+        }
+
+        observations
+    }
+}
+
+// ============================================================================
+// Duplicate Row Validator
+// ============================================================================
+
+/// Validates for duplicate or near-duplicate rows.
+///
+/// Detects:
+/// - Exact duplicate rows (all values identical)
+/// - Near-duplicates (same values except for identifier column)
+/// - Conflicting duplicates (same ID but different values)
+pub struct DuplicateRowValidator {
+    /// Columns to exclude when checking for duplicates (typically identifiers).
+    exclude_patterns: Vec<&'static str>,
+}
+
+impl Default for DuplicateRowValidator {
+    fn default() -> Self {
+        Self {
+            exclude_patterns: vec![
+                "id", "sample_id", "patient_id", "subject_id", "row_id",
+                "index", "uuid", "guid", "key", "record_id",
+            ],
+        }
+    }
+}
+
+impl DuplicateRowValidator {
+    /// Check if a column should be excluded from duplicate detection.
+    fn is_excluded_column(&self, name: &str) -> bool {
+        let lower = name.to_lowercase();
+        self.exclude_patterns.iter().any(|p| lower.contains(p))
+    }
+
+    /// Get a hash key for a row (excluding identifier columns).
+    fn row_key(&self, table: &DataTable, schema: &TableSchema, row_idx: usize) -> String {
+        let mut parts = Vec::new();
+        for col in &schema.columns {
+            if !self.is_excluded_column(&col.name) {
+                let value = table.get(row_idx, col.position).unwrap_or_default();
+                parts.push(value.trim().to_lowercase());
+            }
+        }
+        parts.join("\0")
+    }
+
+    /// Get a full row key (including all columns).
+    fn full_row_key(&self, table: &DataTable, row_idx: usize) -> String {
+        let mut parts = Vec::new();
+        for col_idx in 0..table.column_count() {
+            let value = table.get(row_idx, col_idx).unwrap_or_default();
+            parts.push(value.trim().to_lowercase());
+        }
+        parts.join("\0")
+    }
+
+    /// Find the primary identifier column.
+    fn find_id_column<'a>(&self, schema: &'a TableSchema) -> Option<&'a ColumnSchema> {
+        // Prefer columns explicitly marked as identifiers
+        if let Some(col) = schema.columns.iter().find(|c| c.semantic_role == SemanticRole::Identifier) {
+            return Some(col);
+        }
+
+        // Fall back to name-based detection
+        for col in &schema.columns {
+            let lower = col.name.to_lowercase();
+            if lower == "sample_id"
+                || lower == "id"
+                || lower == "patient_id"
+                || lower == "subject_id"
+            {
+                return Some(col);
+            }
+        }
+
+        // Use first column if it looks like an ID
+        if let Some(first) = schema.columns.first() {
+            let lower = first.name.to_lowercase();
+            if lower.contains("id") || lower.contains("sample") || lower.contains("subject") {
+                return Some(first);
+            }
+        }
+
+        None
+    }
+}
+
+impl Validator for DuplicateRowValidator {
+    fn name(&self) -> &'static str {
+        "duplicate_row_validator"
+    }
+
+    fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
+        let mut observations = Vec::new();
+
+        if table.row_count() < 2 || schema.columns.is_empty() {
+            return observations;
+        }
+
+        // Find exact duplicates
+        let mut full_row_map: IndexMap<String, Vec<usize>> = IndexMap::new();
+        for row_idx in 0..table.row_count() {
+            let key = self.full_row_key(table, row_idx);
+            full_row_map.entry(key).or_default().push(row_idx);
+        }
+
+        let exact_duplicates: Vec<_> = full_row_map
+            .values()
+            .filter(|rows| rows.len() > 1)
+            .cloned()
+            .collect();
+
+        if !exact_duplicates.is_empty() {
+            let total_dup_rows: usize = exact_duplicates.iter().map(|g| g.len() - 1).sum();
+            let pct = (total_dup_rows as f64 / table.row_count() as f64) * 100.0;
+
+            let sample_groups: Vec<String> = exact_duplicates
+                .iter()
+                .take(3)
+                .map(|rows| {
+                    format!(
+                        "rows {}",
+                        rows.iter()
+                            .map(|r| (r + 1).to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                })
+                .collect();
+
+            observations.push(
+                Observation::new(
+                    ObservationType::Duplicate,
+                    Severity::Error,
+                    "_table",
+                    format!(
+                        "{} exact duplicate row(s) found in {} group(s): {}",
+                        total_dup_rows,
+                        exact_duplicates.len(),
+                        sample_groups.join("; ")
+                    ),
+                )
+                .with_evidence(
+                    Evidence::new()
+                        .with_occurrences(total_dup_rows)
+                        .with_percentage(pct)
+                        .with_sample_rows(
+                            exact_duplicates
+                                .iter()
+                                .flat_map(|g| g.iter().skip(1))
+                                .take(5)
+                                .copied()
+                                .collect(),
+                        ),
+                )
+                .with_confidence(0.98)
+                .with_detector("duplicate_row_validator"),
+            );
+        }
+
+        // Find near-duplicates (same content except ID column)
+        let mut content_map: IndexMap<String, Vec<usize>> = IndexMap::new();
+        for row_idx in 0..table.row_count() {
+            let key = self.row_key(table, schema, row_idx);
+            content_map.entry(key).or_default().push(row_idx);
+        }
+
+        let near_duplicates: Vec<_> = content_map
+            .values()
+            .filter(|rows| rows.len() > 1)
+            .cloned()
+            .collect();
+
+        // Only report near-duplicates that aren't also exact duplicates
+        let near_only: Vec<_> = near_duplicates
+            .into_iter()
+            .filter(|rows| {
+                // Check if these rows have different IDs
+                let id_col = self.find_id_column(schema);
+                if let Some(id) = id_col {
+                    let ids: std::collections::HashSet<_> = rows
+                        .iter()
+                        .map(|r| table.get(*r, id.position).unwrap_or_default().to_lowercase())
+                        .collect();
+                    ids.len() > 1 // Different IDs but same content
+                } else {
+                    false
+                }
+            })
+            .collect();
+
+        if !near_only.is_empty() {
+            let total_near: usize = near_only.iter().map(|g| g.len()).sum();
+            let pct = (total_near as f64 / table.row_count() as f64) * 100.0;
+
+            let id_col_name = self
+                .find_id_column(schema)
+                .map(|c| c.name.as_str())
+                .unwrap_or("ID");
+
+            observations.push(
+                Observation::new(
+                    ObservationType::Duplicate,
+                    Severity::Warning,
+                    "_table",
+                    format!(
+                        "{} row(s) have identical content but different {} values (potential data entry duplicates)",
+                        total_near,
+                        id_col_name
+                    ),
+                )
+                .with_evidence(
+                    Evidence::new()
+                        .with_occurrences(near_only.len())
+                        .with_percentage(pct)
+                        .with_sample_rows(
+                            near_only
+                                .iter()
+                                .flat_map(|g| g.iter())
+                                .take(5)
+                                .copied()
+                                .collect(),
+                        ),
+                )
+                .with_confidence(0.85)
+                .with_detector("duplicate_row_validator"),
+            );
+        }
+
+        // Find ID conflicts (same ID but different content)
+        if let Some(id_col) = self.find_id_column(schema) {
+            let mut id_map: IndexMap<String, Vec<usize>> = IndexMap::new();
+            for row_idx in 0..table.row_count() {
+                let id = table.get(row_idx, id_col.position).unwrap_or_default();
+                if !DataTable::is_null_value(id) && !id.is_empty() {
+                    id_map.entry(id.to_lowercase()).or_default().push(row_idx);
+                }
+            }
+
+            let mut conflicts: Vec<(String, Vec<usize>)> = Vec::new();
+            for (id, rows) in &id_map {
+                if rows.len() > 1 {
+                    // Check if content differs
+                    let contents: std::collections::HashSet<_> = rows
+                        .iter()
+                        .map(|r| self.row_key(table, schema, *r))
+                        .collect();
+
+                    if contents.len() > 1 {
+                        conflicts.push((id.clone(), rows.clone()));
+                    }
+                }
+            }
+
+            if !conflicts.is_empty() {
+                let total_conflicts: usize = conflicts.iter().map(|(_, r)| r.len()).sum();
+                let pct = (total_conflicts as f64 / table.row_count() as f64) * 100.0;
+
+                let sample_ids: Vec<String> = conflicts
+                    .iter()
+                    .take(3)
+                    .map(|(id, rows)| format!("'{}' (rows {})", id, rows.len()))
+                    .collect();
+
+                observations.push(
+                    Observation::new(
+                        ObservationType::Inconsistency,
+                        Severity::Error,
+                        &id_col.name,
+                        format!(
+                            "{} {} value(s) appear multiple times with different data: {}",
+                            conflicts.len(),
+                            id_col.name,
+                            sample_ids.join(", ")
+                        ),
+                    )
+                    .with_evidence(
+                        Evidence::new()
+                            .with_occurrences(total_conflicts)
+                            .with_percentage(pct)
+                            .with_sample_rows(
+                                conflicts
+                                    .iter()
+                                    .flat_map(|(_, r)| r.iter())
+                                    .take(5)
                                     .copied()
                                     .collect(),
                             )
@@ -3211,138 +5946,1445 @@ impl Validator for DuplicateRowValidator {
             }
         }
 
-        observations
+        observations
+    }
+
+    fn full_table_check(&self) -> Option<&'static str> {
+        Some("duplicate rows")
+    }
+}
+
+/// How many threads the validation engine runs validators on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Parallelism {
+    /// One validator at a time on the calling thread.
+    Sequential,
+    /// A dedicated pool of this many threads.
+    Threads(usize),
+    /// The global thread pool, sized to the available cores.
+    #[default]
+    Auto,
+}
+
+/// Composite validator that runs all validators.
+pub struct ValidationEngine {
+    validators: Vec<Box<dyn Validator>>,
+    /// Severity to report for each overridden observation type.
+    severity_overrides: HashMap<ObservationType, Severity>,
+    /// Threads to run validators on.
+    parallelism: Parallelism,
+    /// Whether to run [`Self::merge_duplicate_observations`] after validating.
+    merge_duplicates: bool,
+    /// Told as each validator starts.
+    progress: Option<ProgressCallback>,
+}
+
+impl ValidationEngine {
+    /// Create a new validation engine with all default validators.
+    pub fn new() -> Self {
+        Self::with_context(&ContextHints::default())
+    }
+
+    /// Create an engine with all default validators, configured from context.
+    ///
+    /// Missing-value codes from the context extend the default patterns of
+    /// the [`MissingPatternValidator`], sensitive-data patterns extend the
+    /// [`PiiValidator`], and its severity overrides are
+    /// applied to the observations produced. The [`EncodingValidator`] is
+    /// left out when the context declares the encoding clean, as is any
+    /// validator named in its disabled validators.
+    pub fn with_context(context: &ContextHints) -> Self {
+        Self::with_profile(context, Profile::Standard)
+    }
+
+    /// Create an engine configured from context, with the thresholds and
+    /// validators of a strictness profile.
+    pub fn with_profile(context: &ContextHints, profile: Profile) -> Self {
+        let (completeness_warning, completeness_error) = profile.completeness_thresholds();
+        let missing_patterns = MissingPatternValidator::default()
+            .with_additional_patterns(context.missing_patterns.clone());
+        let unit_normalization =
+            UnitNormalizationValidator::new().with_target_units(context.units.clone());
+        // Only a locale the user chose decides ambiguous dates
+        let mut date_format = DateFormatValidator::default();
+        if context.locale.is_some() {
+            date_format = date_format.with_date_order(context.date_order());
+        }
+        let mut sensitive_patterns: Vec<(String, String)> = context
+            .sensitive_patterns
+            .iter()
+            .map(|(label, pattern)| (label.clone(), pattern.clone()))
+            .collect();
+        sensitive_patterns.sort();
+
+        let mut validators: Vec<Box<dyn Validator>> = vec![
+            Box::new(TypeValidator::default().with_decimal_mark(context.decimal_mark())),
+            Box::new(FormattedNumberValidator::default().with_decimal_mark(context.decimal_mark())),
+            Box::new(RangeValidator),
+            Box::new(SetValidator),
+            Box::new(UniquenessValidator),
+            Box::new(IdentifierDuplicateValidator),
+            Box::new(IdentifierNullValidator),
+            Box::new(StatisticalOutlierValidator::default()
+                .with_iqr_multiplier(profile.iqr_multiplier())),
+            Box::new(CompletenessValidator::default()
+                .with_thresholds(completeness_warning, completeness_error)),
+            Box::new(TrailingEmptyColumnValidator),
+            Box::new(ConstantColumnValidator),
+            Box::new(HighCardinalityValidator),
+            Box::new(ConsistencyValidator),
+            Box::new(CaseVariantValidator),
+            Box::new(WhitespaceValidator),
+            Box::new(TypoValidator::default().with_max_distance(profile.typo_distance())),
+            Box::new(SemanticEquivalenceValidator::default()
+                .with_canonical(context.canonical_label.unwrap_or_default())),
+            Box::new(date_format),
+            Box::new(missing_patterns),
+            Box::new(RegexPatternValidator),
+            Box::new(PiiValidator::new().with_additional_patterns(sensitive_patterns)),
+            Box::new(CrossColumnValidator),
+            Box::new(
+                ReferentialIntegrityValidator::new().with_references(context.references.clone()),
+            ),
+            Box::new(TitleCaseValidator),
+            Box::new(CoordinateValidator),
+            Box::new(unit_normalization),
+            Box::new(PrecisionValidator::default()),
+            Box::new(DuplicateRowValidator::default()),
+        ];
+        if !context.clean_encoding {
+            validators.push(Box::new(EncodingValidator));
+        }
+
+        Self {
+            validators,
+            severity_overrides: context.severity_overrides.clone(),
+            parallelism: Parallelism::default(),
+            merge_duplicates: false,
+            progress: None,
+        }
+        .without_validators(profile.disabled_validators())
+        .without_validators(&context.disabled_validators)
+    }
+
+    /// Back the semantic equivalence check with embeddings from `provider`.
+    ///
+    /// Has no effect when that check is disabled. See
+    /// [`SemanticEquivalenceValidator::with_embeddings`].
+    pub fn with_semantic_embeddings(
+        mut self,
+        context: &ContextHints,
+        provider: Arc<dyn LlmProvider>,
+    ) -> Self {
+        for validator in &mut self.validators {
+            if validator.name() == "semantic_equivalence_validator" {
+                *validator = Box::new(
+                    SemanticEquivalenceValidator::default()
+                        .with_canonical(context.canonical_label.unwrap_or_default())
+                        .with_embeddings(Arc::clone(&provider)),
+                );
+            }
+        }
+        self
+    }
+
+    /// Add a validator to run alongside the others.
+    pub fn with_validator(mut self, validator: Box<dyn Validator>) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Drop validators by detector name. Unknown names are ignored.
+    pub fn without_validators<S: AsRef<str>>(mut self, names: &[S]) -> Self {
+        self.validators
+            .retain(|v| !names.iter().any(|name| name.as_ref() == v.name()));
+        self
+    }
+
+    /// Create an engine that runs exactly the given validators.
+    pub fn with_validators(validators: Vec<Box<dyn Validator>>) -> Self {
+        Self {
+            validators,
+            severity_overrides: HashMap::new(),
+            parallelism: Parallelism::default(),
+            merge_duplicates: false,
+            progress: None,
+        }
+    }
+
+    /// Start building an engine from the default validators.
+    ///
+    /// ```
+    /// use crucible::validation::ValidationEngine;
+    ///
+    /// let engine = ValidationEngine::builder()
+    ///     .disable("typo_validator")
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(!engine.names().contains(&"typo_validator"));
+    ///
+    /// assert!(ValidationEngine::builder().disable("typos").build().is_err());
+    /// ```
+    pub fn builder() -> ValidationEngineBuilder {
+        ValidationEngineBuilder::default()
+    }
+
+    /// Detector names of all default validators.
+    pub fn validator_names() -> Vec<&'static str> {
+        Self::new().names()
+    }
+
+    /// Check that every name is a default validator's detector name.
+    ///
+    /// The error lists the valid names.
+    pub fn check_validator_names<S: AsRef<str>>(names: &[S]) -> Result<()> {
+        let valid = Self::validator_names();
+        let unknown: Vec<&str> = names
+            .iter()
+            .map(AsRef::as_ref)
+            .filter(|name| !valid.contains(name))
+            .collect();
+        if unknown.is_empty() {
+            return Ok(());
+        }
+
+        Err(CrucibleError::Config(format!(
+            "Unknown validator{} {}. Valid names: {}",
+            if unknown.len() == 1 { "" } else { "s" },
+            unknown
+                .iter()
+                .map(|name| format!("'{}'", name))
+                .collect::<Vec<_>>()
+                .join(", "),
+            valid.join(", ")
+        )))
+    }
+
+    /// Detector names of the validators this engine runs, in order.
+    pub fn names(&self) -> Vec<&'static str> {
+        self.validators.iter().map(|v| v.name()).collect()
+    }
+
+    /// Create an engine with only the validators that can check new rows in
+    /// isolation against a previously inferred schema.
+    ///
+    /// Type, range, set membership, format, and missing-identifier checks only
+    /// look at one value at a time. Checks that need the whole table
+    /// (uniqueness, duplicate rows, variant and typo detection) are excluded.
+    /// Numbers are read with the given decimal mark.
+    pub fn incremental(decimal_mark: DecimalMark) -> Self {
+        Self {
+            validators: vec![
+                Box::new(TypeValidator::default().with_decimal_mark(decimal_mark)),
+                Box::new(RangeValidator),
+                Box::new(SetValidator),
+                Box::new(RegexPatternValidator),
+                Box::new(IdentifierNullValidator),
+            ],
+            severity_overrides: HashMap::new(),
+            parallelism: Parallelism::default(),
+            merge_duplicates: false,
+            progress: None,
+        }
+    }
+
+    /// Report observations of the given types at a fixed severity.
+    pub fn with_severity_overrides(
+        mut self,
+        overrides: HashMap<ObservationType, Severity>,
+    ) -> Self {
+        self.severity_overrides = overrides;
+        self
+    }
+
+    /// Run validators on the given number of threads.
+    ///
+    /// The observations returned are the same in every mode, and their IDs
+    /// are assigned in the order they're returned.
+    pub fn with_parallelism(mut self, parallelism: Parallelism) -> Self {
+        self.parallelism = parallelism;
+        self
+    }
+
+    /// Merge duplicate observations after validating.
+    ///
+    /// Off by default. See [`merge_duplicate_observations`](Self::merge_duplicate_observations).
+    pub fn with_merged_duplicates(mut self, merge: bool) -> Self {
+        self.merge_duplicates = merge;
+        self
+    }
+
+    /// Report a [`ProgressEvent::Validation`] as each validator starts.
+    pub fn with_progress(mut self, progress: ProgressCallback) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Run all validators and collect observations.
+    ///
+    /// Observations are sorted by severity (errors first), then detector and
+    /// column; ties keep the order the validator reported them in. IDs are
+    /// then renumbered in that order, since validators running in parallel
+    /// take theirs in whatever order they finish.
+    pub fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
+        let run = |validator: &dyn Validator| {
+            if let Some(ref progress) = self.progress {
+                progress(ProgressEvent::Validation {
+                    validator_name: validator.name(),
+                });
+            }
+            match validator.full_table_check().filter(|_| table.is_sampled()) {
+                Some(check) => vec![Self::skipped_check(check, table)],
+                None => validator.validate(table, schema),
+            }
+        };
+
+        let per_validator: Vec<Vec<Observation>> = match self.parallelism {
+            Parallelism::Sequential => self.validators.iter().map(|v| run(v.as_ref())).collect(),
+            Parallelism::Auto => self.validators.par_iter().map(|v| run(v.as_ref())).collect(),
+            Parallelism::Threads(threads) => {
+                match rayon::ThreadPoolBuilder::new().num_threads(threads).build() {
+                    Ok(pool) => pool.install(|| self.validators.par_iter().map(|v| run(v.as_ref())).collect()),
+                    Err(_) => self.validators.iter().map(|v| run(v.as_ref())).collect(),
+                }
+            }
+        };
+        let mut all_observations: Vec<Observation> = per_validator.into_iter().flatten().collect();
+
+        for obs in &mut all_observations {
+            self.apply_severity_override(obs);
+        }
+        if self.merge_duplicates {
+            all_observations = Self::merge_duplicate_observations(all_observations);
+        }
+
+        all_observations.sort_by(|a, b| {
+            b.severity
+                .cmp(&a.severity)
+                .then_with(|| a.detector.cmp(&b.detector))
+                .then_with(|| a.column.cmp(&b.column))
+        });
+        for obs in &mut all_observations {
+            obs.id = generate_observation_id();
+        }
+
+        all_observations
+    }
+}
+
+impl ValidationEngine {
+    /// Merge observations that report the same issue on the same column.
+    ///
+    /// Observations cluster when they share a column and type and name at
+    /// least one common value (case-insensitively) in their evidence, e.g.
+    /// case-variant, semantic-equivalence, and typo findings on one set of
+    /// spellings. Each cluster becomes its most confident observation with
+    /// the highest severity, a combined confidence of `1 - Π(1 - c)`, the
+    /// union of sample rows and value counts, and every member's detector listed in
+    /// [`Evidence::merged_from`]. Observations without values are kept.
+    pub fn merge_duplicate_observations(observations: Vec<Observation>) -> Vec<Observation> {
+        let values: Vec<HashSet<String>> = observations.iter().map(Self::issue_values).collect();
+
+        // Union-find over observations of the same issue
+        let mut parent: Vec<usize> = (0..observations.len()).collect();
+        fn root(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+        for i in 0..observations.len() {
+            for j in 0..i {
+                let (a, b) = (&observations[i], &observations[j]);
+                if a.column == b.column
+                    && a.observation_type == b.observation_type
+                    && !values[i].is_disjoint(&values[j])
+                {
+                    let (ri, rj) = (root(&mut parent, i), root(&mut parent, j));
+                    parent[ri.max(rj)] = ri.min(rj);
+                }
+            }
+        }
+
+        let mut clusters: IndexMap<usize, Vec<Observation>> = IndexMap::new();
+        for (i, obs) in observations.into_iter().enumerate() {
+            clusters.entry(root(&mut parent, i)).or_default().push(obs);
+        }
+
+        clusters
+            .into_values()
+            .map(|mut members| match members.len() {
+                1 => members.remove(0),
+                _ => Self::merge_cluster(members),
+            })
+            .collect()
+    }
+
+    /// Lowercased values an observation's evidence is about.
+    fn issue_values(obs: &Observation) -> HashSet<String> {
+        let mut values = HashSet::new();
+        let mut add = |s: &str| {
+            let s = s.trim().to_lowercase();
+            if !s.is_empty() {
+                values.insert(s);
+            }
+        };
+
+        if let Some(counts) = obs.evidence.value_counts.as_ref().and_then(|v| v.as_object()) {
+            for (value, detail) in counts {
+                add(value);
+                // Nested count maps list the variants of a group
+                if let Some(nested) = detail.as_object().filter(|m| m.values().all(|v| v.is_number())) {
+                    nested.keys().for_each(|k| add(k));
+                }
+            }
+        }
+        match &obs.evidence.value {
+            Some(serde_json::Value::String(s)) => add(s),
+            Some(serde_json::Value::Array(items)) => {
+                items.iter().filter_map(|v| v.as_str()).for_each(&mut add);
+            }
+            _ => {}
+        }
+        values
+    }
+
+    /// Fold a cluster of observations into its most confident member.
+    fn merge_cluster(members: Vec<Observation>) -> Observation {
+        let primary = members
+            .iter()
+            .enumerate()
+            .max_by(|(i, a), (j, b)| a.confidence.total_cmp(&b.confidence).then(j.cmp(i)))
+            .map(|(i, _)| i)
+            .unwrap_or_default();
+
+        let mut merged = members[primary].clone();
+        merged.severity = members.iter().map(|o| o.severity).max().unwrap_or(merged.severity);
+        merged.confidence = 1.0
+            - members
+                .iter()
+                .map(|o| 1.0 - o.confidence.clamp(0.0, 1.0))
+                .product::<f64>();
+        merged.evidence.occurrences = members.iter().filter_map(|o| o.evidence.occurrences).max();
+
+        let mut rows: Vec<usize> = members
+            .iter()
+            .flat_map(|o| o.evidence.sample_rows.iter().copied())
+            .collect();
+        rows.sort_unstable();
+        rows.dedup();
+        merged.evidence.sample_rows = rows;
+
+        // Union of the value counts, the primary's entry winning for a shared value
+        let mut counts = serde_json::Map::new();
+        let primary_first = std::iter::once(&members[primary])
+            .chain(members.iter().enumerate().filter(|(i, _)| *i != primary).map(|(_, o)| o));
+        for obs in primary_first {
+            if let Some(member_counts) = obs.evidence.value_counts.as_ref().and_then(|v| v.as_object()) {
+                for (value, detail) in member_counts {
+                    counts.entry(value.clone()).or_insert_with(|| detail.clone());
+                }
+            }
+        }
+        if !counts.is_empty() {
+            merged.evidence.value_counts = Some(serde_json::Value::Object(counts));
+        }
+
+        let others: Vec<&str> = members
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != primary)
+            .map(|(_, o)| o.detector.as_str())
+            .collect();
+        merged.description = format!(
+            "{} (also flagged by {})",
+            merged.description,
+            others.join(", ")
+        );
+        merged.evidence.merged_from = members
+            .iter()
+            .map(|o| MergedFinding {
+                detector: o.detector.clone(),
+                description: o.description.clone(),
+                confidence: o.confidence,
+            })
+            .collect();
+
+        merged
+    }
+
+    /// Apply the configured severity policy to an observation.
+    ///
+    /// The inferred severity is kept in `severity_override` so it is clear
+    /// the severity was set by policy.
+    fn apply_severity_override(&self, obs: &mut Observation) {
+        let Some(&severity) = self.severity_overrides.get(&obs.observation_type) else {
+            return;
+        };
+        if severity == obs.severity {
+            return;
+        }
+
+        obs.severity_override = Some((obs.severity, severity));
+        obs.severity = severity;
+    }
+
+    /// Note that a whole-table check was not run on a sampled table.
+    fn skipped_check(check: &str, table: &DataTable) -> Observation {
+        Observation::new(
+            ObservationType::SkippedCheck,
+            Severity::Info,
+            "_table",
+            format!(
+                "Check for {} was not run: only {} of {} rows were sampled",
+                check,
+                table.row_count(),
+                table.total_row_count()
+            ),
+        )
+        .with_evidence(
+            Evidence::new()
+                .with_occurrences(table.row_count())
+                .with_percentage(table.row_count() as f64 / table.total_row_count() as f64 * 100.0),
+        )
+        .with_confidence(1.0)
+        .with_detector("validation_engine")
+    }
+}
+
+impl Default for ValidationEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builder for a [`ValidationEngine`] with validators turned on or off by name.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationEngineBuilder {
+    context: ContextHints,
+    disabled: Vec<String>,
+    enabled: Vec<String>,
+}
+
+impl ValidationEngineBuilder {
+    /// Configure validators from context hints (see [`ValidationEngine::with_context`]).
+    pub fn context(mut self, context: ContextHints) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Turn off a validator by detector name.
+    pub fn disable(mut self, name: impl Into<String>) -> Self {
+        self.disabled.push(name.into());
+        self
+    }
+
+    /// Turn a validator back on, overriding the context's disabled validators.
+    pub fn enable(mut self, name: impl Into<String>) -> Self {
+        self.enabled.push(name.into());
+        self
+    }
+
+    /// Build the engine, failing on names that aren't default validators.
+    pub fn build(self) -> Result<ValidationEngine> {
+        let mut context = self.context;
+        ValidationEngine::check_validator_names(&context.disabled_validators)?;
+        ValidationEngine::check_validator_names(&self.disabled)?;
+        ValidationEngine::check_validator_names(&self.enabled)?;
+
+        context.disabled_validators.extend(self.disabled);
+        context
+            .disabled_validators
+            .retain(|name| !self.enabled.contains(name));
+        Ok(ValidationEngine::with_context(&context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::schema::{ColumnStatistics, NumericStatistics, SemanticRole, SemanticType};
+
+    fn make_table(headers: Vec<&str>, rows: Vec<Vec<&str>>) -> DataTable {
+        DataTable::new(
+            headers.into_iter().map(String::from).collect(),
+            rows.into_iter()
+                .map(|r| r.into_iter().map(String::from).collect())
+                .collect(),
+            b',',
+        )
+    }
+
+    fn make_simple_schema(columns: Vec<(&str, ColumnType)>) -> TableSchema {
+        TableSchema::with_columns(
+            columns
+                .into_iter()
+                .enumerate()
+                .map(|(i, (name, typ))| ColumnSchema {
+                    name: name.to_string(),
+                    position: i,
+                    inferred_type: typ,
+                    semantic_type: SemanticType::Unknown,
+                    semantic_role: SemanticRole::Unknown,
+                    nullable: false,
+                    unique: false,
+                    expected_values: None,
+                    expected_range: None,
+                    constraints: Vec::new(),
+                    statistics: ColumnStatistics::default(),
+                    detected_null_tokens: Vec::new(),
+                    confidence: 0.9,
+                    inference_sources: vec!["test".to_string()],
+                    llm_insight: None,
+                    explanation: None,
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_merge_duplicate_observations() {
+        let values = [
+            "active", "Active", "active", "ACTIVE", "inactive", "active", "actve", "inactive",
+            "active", "Inactive",
+        ];
+        let table = make_table(vec!["status"], values.iter().map(|v| vec![*v]).collect());
+        let schema = make_simple_schema(vec![("status", ColumnType::String)]);
+        let detectors = |observations: &[Observation]| {
+            observations
+                .iter()
+                .filter(|o| o.column == "status")
+                .map(|o| o.detector.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let separate = ValidationEngine::new().validate(&table, &schema);
+        let separate_detectors = detectors(&separate);
+        assert!(separate_detectors.len() >= 3);
+
+        let merged = ValidationEngine::new()
+            .with_merged_duplicates(true)
+            .validate(&table, &schema);
+        assert_eq!(detectors(&merged).len(), 1);
+
+        let obs = merged.iter().find(|o| o.column == "status").unwrap();
+        let mut provenance: Vec<String> = obs
+            .evidence
+            .merged_from
+            .iter()
+            .map(|f| f.detector.clone())
+            .collect();
+        provenance.sort();
+        let mut expected = separate_detectors;
+        expected.sort();
+        assert_eq!(provenance, expected);
+
+        // Combined confidence is at least that of the most confident member
+        let max = separate
+            .iter()
+            .filter(|o| o.column == "status")
+            .map(|o| o.confidence)
+            .fold(0.0, f64::max);
+        assert!(obs.confidence >= max && obs.confidence <= 1.0);
+        assert!(obs.description.contains("also flagged by"));
+    }
+
+    #[test]
+    fn test_merge_unions_value_counts() {
+        let case_issue = Observation::new(ObservationType::Inconsistency, Severity::Warning, "site", "Case variants")
+            .with_evidence(Evidence::new().with_value_counts(Some(serde_json::json!({"gut": {"Gut": 3, "GUT": 1}}))))
+            .with_confidence(0.9);
+        let typo_issue = Observation::new(ObservationType::Inconsistency, Severity::Info, "site", "Possible typo")
+            .with_evidence(Evidence::new().with_value_counts(Some(serde_json::json!({"gut": 4, "gutt": 1}))))
+            .with_confidence(0.6);
+
+        let merged = ValidationEngine::merge_duplicate_observations(vec![case_issue, typo_issue]);
+        assert_eq!(merged.len(), 1);
+        let counts = merged[0].evidence.value_counts.as_ref().unwrap();
+        assert_eq!(counts["gut"], serde_json::json!({"Gut": 3, "GUT": 1}));
+        assert_eq!(counts["gutt"], 1);
+    }
+
+    #[test]
+    fn test_merge_keeps_unrelated_observations() {
+        let date_issue = Observation::new(ObservationType::Inconsistency, Severity::Warning, "date", "Mixed formats")
+            .with_evidence(Evidence::new().with_value_counts(Some(serde_json::json!({"ISO": 3, "US": 2}))))
+            .with_confidence(0.8);
+        let case_issue = Observation::new(ObservationType::Inconsistency, Severity::Info, "date", "Case variants")
+            .with_evidence(Evidence::new().with_value_counts(Some(serde_json::json!({"jan": {"Jan": 1}}))))
+            .with_confidence(0.5);
+        let no_values = Observation::new(ObservationType::Outlier, Severity::Warning, "age", "Outlier");
+
+        let merged = ValidationEngine::merge_duplicate_observations(vec![
+            date_issue,
+            case_issue,
+            no_values.clone(),
+            no_values,
+        ]);
+        assert_eq!(merged.len(), 4);
+        assert!(merged.iter().all(|o| o.evidence.merged_from.is_empty()));
+    }
+
+    #[test]
+    fn test_parallel_validation_is_deterministic() {
+        let table = make_table(
+            vec!["id", "age", "status"],
+            vec![
+                vec!["1", "25", "active"],
+                vec!["1", "invalid", "Active"],
+                vec!["3", "28", "missing"],
+                vec!["4", "900", "actve"],
+            ],
+        );
+        let schema = make_simple_schema(vec![
+            ("id", ColumnType::Integer),
+            ("age", ColumnType::Integer),
+            ("status", ColumnType::String),
+        ]);
+        let summarize = |parallelism| {
+            ValidationEngine::new()
+                .with_parallelism(parallelism)
+                .validate(&table, &schema)
+                .into_iter()
+                .map(|o| (o.severity, o.detector, o.column, o.description))
+                .collect::<Vec<_>>()
+        };
+
+        let sequential = summarize(Parallelism::Sequential);
+        assert!(sequential.len() > 1);
+        assert_eq!(summarize(Parallelism::Auto), sequential);
+        assert_eq!(summarize(Parallelism::Threads(2)), sequential);
+
+        // IDs follow the returned order in every mode
+        for parallelism in [Parallelism::Sequential, Parallelism::Auto, Parallelism::Threads(4)] {
+            let ids: Vec<u64> = ValidationEngine::new()
+                .with_parallelism(parallelism)
+                .validate(&table, &schema)
+                .iter()
+                .map(|o| o.id.trim_start_matches("obs_").parse().unwrap())
+                .collect();
+            assert!(ids.windows(2).all(|w| w[0] < w[1]), "{:?}", ids);
+        }
+    }
+
+    #[test]
+    fn test_type_validator() {
+        let table = make_table(
+            vec!["age"],
+            vec![vec!["25"], vec!["30"], vec!["invalid"], vec!["28"]],
+        );
+        let schema = make_simple_schema(vec![("age", ColumnType::Integer)]);
+
+        let validator = TypeValidator::default();
+        let observations = validator.validate(&table, &schema);
+
+        assert_eq!(observations.len(), 1);
+        assert_eq!(observations[0].observation_type, ObservationType::TypeMismatch);
+    }
+
+    #[test]
+    fn test_typo_validator_uses_vocabulary() {
+        // "stool" never appears, but the vocabulary knows it
+        let table = make_table(
+            vec!["sample_type"],
+            vec![
+                vec!["stoool"],
+                vec!["stoool"],
+                vec!["saliva"],
+                vec!["saliva"],
+                vec!["salvia"],
+            ],
+        );
+        let mut schema = make_simple_schema(vec![("sample_type", ColumnType::String)]);
+        schema.columns[0].expected_values = Some(vec!["stool".to_string(), "saliva".to_string()]);
+
+        let observations = TypoValidator::default().validate(&table, &schema);
+        assert_eq!(observations.len(), 1);
+
+        let counts = observations[0].evidence.value_counts.as_ref().unwrap();
+        assert_eq!(counts["stoool"]["suggestion"], "stool");
+        assert_eq!(counts["stoool"]["count"], 2);
+        assert_eq!(counts["stoool"]["source"], "vocabulary");
+        assert_eq!(counts["salvia"]["suggestion"], "saliva");
+    }
+
+    #[test]
+    fn test_semantic_equivalence_canonical_label() {
+        let table = make_table(
+            vec!["diagnosis"],
+            vec![
+                vec!["Crohns"],
+                vec!["Crohn's Disease"],
+                vec!["Crohns"],
+                vec!["Crohns"],
+                vec!["UC"],
+            ],
+        );
+        let schema = make_simple_schema(vec![("diagnosis", ColumnType::String)]);
+
+        let canonicals = |canonical: CanonicalLabel| -> Vec<String> {
+            let observations = SemanticEquivalenceValidator::default()
+                .with_canonical(canonical)
+                .validate(&table, &schema);
+            let counts = observations[0].evidence.value_counts.as_ref().unwrap();
+            counts.as_object().unwrap().keys().cloned().collect()
+        };
+
+        assert_eq!(canonicals(CanonicalLabel::First), vec!["CD"]);
+        assert_eq!(canonicals(CanonicalLabel::MostFrequent), vec!["Crohns"]);
+        assert_eq!(canonicals(CanonicalLabel::Longest), vec!["Crohn's Disease"]);
+    }
+
+    #[test]
+    fn test_typo_validator_falls_back_to_frequency() {
+        let table = make_table(
+            vec!["sample_type"],
+            vec![vec!["stool"], vec!["stool"], vec!["stoool"], vec!["blood"]],
+        );
+        let schema = make_simple_schema(vec![("sample_type", ColumnType::String)]);
+
+        let observations = TypoValidator::default().validate(&table, &schema);
+        assert_eq!(observations.len(), 1);
+
+        let counts = observations[0].evidence.value_counts.as_ref().unwrap();
+        assert_eq!(counts["stoool"]["suggestion"], "stool");
+        assert_eq!(counts["stoool"]["source"], "frequency");
+        assert!(counts.get("blood").is_none());
+    }
+
+    #[test]
+    fn test_missing_pattern_validator() {
+        let table = make_table(
+            vec!["status"],
+            vec![
+                vec!["active"],
+                vec!["missing"],
+                vec!["inactive"],
+                vec!["missing"],
+            ],
+        );
+        let schema = make_simple_schema(vec![("status", ColumnType::String)]);
+
+        let validator = MissingPatternValidator::default();
+        let observations = validator.validate(&table, &schema);
+
+        assert_eq!(observations.len(), 1);
+        assert_eq!(observations[0].observation_type, ObservationType::MissingPattern);
+        assert!(observations[0].description.contains("missing"));
+    }
+
+    #[test]
+    fn test_missing_pattern_custom_patterns() {
+        let table = make_table(
+            vec!["result"],
+            vec![vec!["12.1"], vec!["ND"], vec!["not done"], vec!["missing"], vec!["missing"]],
+        );
+        let schema = make_simple_schema(vec![("result", ColumnType::String)]);
+
+        let validator = MissingPatternValidator::default()
+            .with_additional_patterns(vec!["ND".to_string(), "Not Done".to_string()])
+            .with_min_occurrences(1);
+        let observations = validator.validate(&table, &schema);
+        assert_eq!(observations.len(), 3);
+
+        let validator = MissingPatternValidator::default()
+            .with_patterns(vec!["nd".to_string()])
+            .with_min_occurrences(1);
+        let observations = validator.validate(&table, &schema);
+        assert_eq!(observations.len(), 1);
+        assert_eq!(observations[0].evidence.pattern.as_deref(), Some("nd"));
+    }
+
+    #[test]
+    fn test_detected_null_tokens_get_convert_na_suggestions() {
+        let table = make_table(
+            vec!["result"],
+            vec![vec!["12.1"], vec!["BLQ"], vec!["8.4"], vec!["BLQ"]],
+        );
+        let mut schema = make_simple_schema(vec![("result", ColumnType::Float)]);
+        schema.columns[0].detected_null_tokens = vec!["BLQ".to_string()];
+
+        let observations = MissingPatternValidator::default().validate(&table, &schema);
+        let suggestions = crate::suggestion::SuggestionEngine::generate(&observations);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].action, crate::suggestion::SuggestionAction::ConvertNa);
+        assert_eq!(suggestions[0].parameters["from_values"], serde_json::json!(["BLQ"]));
+    }
+
+    #[test]
+    fn test_sampled_table_skips_full_table_checks() {
+        let mut table = make_table(
+            vec!["sample_id", "value"],
+            vec![vec!["S1", "1"], vec!["S1", "1"]],
+        );
+        let mut schema = make_simple_schema(vec![
+            ("sample_id", ColumnType::String),
+            ("value", ColumnType::Integer),
+        ]);
+        schema.columns[0].semantic_role = SemanticRole::Identifier;
+
+        let engine = ValidationEngine::new();
+        let observations = engine.validate(&table, &schema);
+        assert!(observations.iter().any(|o| o.observation_type == ObservationType::Duplicate));
+
+        table.total_rows = Some(1000);
+        let observations = engine.validate(&table, &schema);
+        assert!(!observations.iter().any(|o| o.observation_type == ObservationType::Duplicate));
+
+        let skipped: Vec<_> = observations
+            .iter()
+            .filter(|o| o.observation_type == ObservationType::SkippedCheck)
+            .collect();
+        assert_eq!(skipped.len(), 4);
+        assert!(skipped.iter().all(|o| o.severity == Severity::Info));
+        assert!(skipped[0].description.contains("2 of 1000"));
+    }
+
+    #[test]
+    fn test_validation_engine_severity_overrides() {
+        let table = make_table(
+            vec!["notes"],
+            vec![vec!["a"], vec![""], vec!["b"], vec![""]],
+        );
+        let mut schema = make_simple_schema(vec![("notes", ColumnType::String)]);
+        schema.columns[0].statistics.count = 4;
+        schema.columns[0].statistics.null_count = 2;
+
+        let completeness = |observations: &[Observation]| {
+            observations
+                .iter()
+                .find(|o| o.observation_type == ObservationType::Completeness)
+                .cloned()
+                .unwrap()
+        };
+
+        let default = completeness(&ValidationEngine::new().validate(&table, &schema));
+        assert_eq!(default.severity, Severity::Error);
+
+        let context =
+            ContextHints::new().with_severity(ObservationType::Completeness, Severity::Info);
+        let adjusted =
+            completeness(&ValidationEngine::with_context(&context).validate(&table, &schema));
+        assert_eq!(adjusted.severity, Severity::Info);
+        assert_eq!(adjusted.detector, default.detector);
+        assert_eq!(adjusted.severity_override, Some((Severity::Error, Severity::Info)));
+    }
+
+    #[test]
+    fn test_validator_name_defaults_to_type_name() {
+        struct Unnamed;
+        impl Validator for Unnamed {
+            fn validate(&self, _table: &DataTable, _schema: &TableSchema) -> Vec<Observation> {
+                Vec::new()
+            }
+        }
+
+        assert!(Unnamed.name().ends_with("Unnamed"));
+    }
+
+    #[test]
+    fn test_validation_engine_builder_disables_validators() {
+        let table = make_table(
+            vec!["site"],
+            vec![
+                vec!["Boston"],
+                vec!["Boston"],
+                vec!["Boston"],
+                vec!["Bostn"],
+            ],
+        );
+        let schema = make_simple_schema(vec![("site", ColumnType::String)]);
+
+        let engine = ValidationEngine::builder()
+            .disable("typo_validator")
+            .build()
+            .unwrap();
+        assert!(!engine.names().contains(&"typo_validator"));
+        assert_eq!(engine.names().len(), ValidationEngine::validator_names().len() - 1);
+        assert!(
+            engine
+                .validate(&table, &schema)
+                .iter()
+                .all(|o| o.detector != "typo_validator")
+        );
+
+        // Enabling overrides a validator disabled through context
+        let context = ContextHints::new().with_disabled_validators(["typo_validator"]);
+        let engine = ValidationEngine::builder()
+            .context(context)
+            .enable("typo_validator")
+            .build()
+            .unwrap();
+        assert!(engine.names().contains(&"typo_validator"));
+    }
+
+    #[test]
+    fn test_validation_engine_unknown_validator_name() {
+        let err = ValidationEngine::builder()
+            .disable("typos")
+            .build()
+            .err()
+            .expect("unknown name should fail")
+            .to_string();
+        assert!(err.contains("Unknown validator 'typos'"));
+        assert!(err.contains("typo_validator"));
+
+        assert!(ValidationEngine::check_validator_names(&["range_validator"]).is_ok());
+    }
+
+    #[test]
+    fn test_validator_names_are_unique() {
+        let names = ValidationEngine::validator_names();
+        let unique: HashSet<_> = names.iter().collect();
+        assert_eq!(unique.len(), names.len());
+    }
+
+    #[test]
+    fn test_outlier_methods_on_skewed_sample() {
+        // Right-skewed: most values sit at the minimum with a long upper tail
+        let mut values = vec!["10"; 8];
+        values.extend([
+            "11", "12", "14", "16", "18", "20", "22", "24", "26", "28", "30", "36",
+        ]);
+        let table = make_table(vec!["value"], values.iter().map(|v| vec![*v]).collect());
+        let mut schema = make_simple_schema(vec![("value", ColumnType::Integer)]);
+        schema.columns[0].statistics.numeric = Some(NumericStatistics {
+            min: 10.0,
+            max: 36.0,
+            mean: 17.5,
+            std: 7.9,
+            median: 13.0,
+            q1: 10.0,
+            q3: 22.0,
+        });
+
+        // IQR upper bound is 22 + 1.5 * 12 = 40, above every value
+        let iqr = StatisticalOutlierValidator::default().validate(&table, &schema);
+        assert!(iqr.is_empty());
+
+        // Median 13, MAD 3: bounds are 13 +/- 3.5 * 3 / 0.6745 (about 28.6)
+        let mz = StatisticalOutlierValidator::default()
+            .with_method(OutlierMethod::ModifiedZScore)
+            .validate(&table, &schema);
+        assert_eq!(mz.len(), 1);
+        assert!(mz[0].description.contains("modified Z-score method"));
+        assert_eq!(mz[0].evidence.occurrences, Some(2));
+        assert_eq!(mz[0].evidence.sample_rows, vec![18, 19]);
+
+        let expected = mz[0].evidence.expected.as_ref().unwrap();
+        assert_eq!(expected["method"], "modified_z_score");
+        assert_eq!(expected["median"], 13.0);
+        assert_eq!(expected["mad"], 3.0);
+        assert!((expected["upper_bound"].as_f64().unwrap() - 28.567).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_modified_z_score_skips_zero_mad() {
+        let table = make_table(
+            vec!["value"],
+            vec![vec!["5"], vec!["5"], vec!["5"], vec!["5"], vec!["100"]],
+        );
+        let schema = make_simple_schema(vec![("value", ColumnType::Integer)]);
+
+        let observations = StatisticalOutlierValidator::default()
+            .with_method(OutlierMethod::ModifiedZScore)
+            .validate(&table, &schema);
+        assert!(observations.is_empty());
+    }
+
+    #[test]
+    fn test_identifier_null_validator() {
+        let table = make_table(
+            vec!["sample_id", "notes"],
+            vec![
+                vec!["S001", ""],
+                vec!["", "no id"],
+                vec!["S003", ""],
+                vec!["NA", "also no id"],
+            ],
+        );
+        let mut schema = make_simple_schema(vec![
+            ("sample_id", ColumnType::String),
+            ("notes", ColumnType::String),
+        ]);
+        schema.columns[0].semantic_role = SemanticRole::Identifier;
+
+        let validator = IdentifierNullValidator;
+        let observations = validator.validate(&table, &schema);
+
+        assert_eq!(observations.len(), 1);
+        assert_eq!(observations[0].column, "sample_id");
+        assert_eq!(observations[0].severity, Severity::Error);
+        assert_eq!(observations[0].evidence.sample_rows, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_coordinate_validator_uses_semantic_role() {
+        let table = make_table(
+            vec!["site_location"],
+            vec![vec!["38.98 -77.11"], vec!["95.00 -77.11"], vec!["39.01 -76.95"]],
+        );
+        let mut schema = make_simple_schema(vec![("site_location", ColumnType::String)]);
+
+        // Not recognizable by name alone
+        assert!(CoordinateValidator.validate(&table, &schema).is_empty());
+
+        schema.columns[0].semantic_role = SemanticRole::Coordinate;
+        let observations = CoordinateValidator.validate(&table, &schema);
+        assert!(observations
+            .iter()
+            .any(|o| o.severity == Severity::Error && o.evidence.sample_rows == vec![1]));
+    }
+
+    #[test]
+    fn test_unit_normalization_validator() {
+        let table = make_table(
+            vec!["weight", "height"],
+            vec![
+                vec!["154", "170"],
+                vec!["187", "182"],
+                vec!["210", "165"],
+                vec!["132", "158"],
+                vec!["176", "175"],
+            ],
+        );
+        let schema = make_simple_schema(vec![
+            ("weight", ColumnType::Integer),
+            ("height", ColumnType::Integer),
+        ]);
+
+        // Weights look like pounds; heights are already in centimeters
+        let observations = UnitNormalizationValidator::new().validate(&table, &schema);
+        assert_eq!(observations.len(), 1);
+        assert_eq!(observations[0].column, "weight");
+        let expected = observations[0].evidence.expected.as_ref().unwrap();
+        assert_eq!(expected["detected_unit"], "lb");
+        assert_eq!(expected["target_unit"], "kg");
+
+        // A target-unit hint changes the proposed conversion
+        let mut units = HashMap::new();
+        units.insert("height".to_string(), "in".to_string());
+        let observations = UnitNormalizationValidator::new()
+            .with_target_units(units)
+            .validate(&table, &schema);
+        let height = observations.iter().find(|o| o.column == "height").unwrap();
+        let expected = height.evidence.expected.as_ref().unwrap();
+        assert_eq!(expected["detected_unit"], "cm");
+        assert_eq!(expected["target_unit"], "in");
     }
-}
 
-/// Composite validator that runs all validators.
-pub struct ValidationEngine {
-    validators: Vec<Box<dyn Validator>>,
-}
+    #[test]
+    fn test_unit_normalization_is_conservative() {
+        // Weights between 100 and 130 fit both kg and lb
+        let table = make_table(
+            vec!["weight"],
+            vec![vec!["105"], vec!["112"], vec!["120"], vec!["118"], vec!["125"]],
+        );
+        let schema = make_simple_schema(vec![("weight", ColumnType::Integer)]);
 
-impl ValidationEngine {
-    /// Create a new validation engine with all default validators.
-    pub fn new() -> Self {
-        Self {
-            validators: vec![
-                Box::new(TypeValidator),
-                Box::new(RangeValidator),
-                Box::new(SetValidator),
-                Box::new(UniquenessValidator),
-                Box::new(IdentifierDuplicateValidator),
-                Box::new(StatisticalOutlierValidator::default()),
-                Box::new(CompletenessValidator::default()),
-                Box::new(ConsistencyValidator),
-                Box::new(CaseVariantValidator),
-                Box::new(TypoValidator::default()),
-                Box::new(SemanticEquivalenceValidator::default()),
-                Box::new(DateFormatValidator),
-                Box::new(MissingPatternValidator::default()),
-                Box::new(RegexPatternValidator),
-                Box::new(CrossColumnValidator),
-                Box::new(TitleCaseValidator),
-                Box::new(CoordinateValidator),
-                Box::new(DuplicateRowValidator::default()),
-            ],
-        }
+        assert!(UnitNormalizationValidator::new().validate(&table, &schema).is_empty());
     }
 
-    /// Run all validators and collect observations.
-    pub fn validate(&self, table: &DataTable, schema: &TableSchema) -> Vec<Observation> {
-        let mut all_observations = Vec::new();
+    #[test]
+    fn test_repair_mojibake() {
+        assert_eq!(EncodingValidator::repair_mojibake("CafÃ©"), Some("Café".to_string()));
+        assert_eq!(
+            EncodingValidator::repair_mojibake("donâ€™t"),
+            Some("don’t".to_string())
+        );
+        // Correct text is left alone
+        assert_eq!(EncodingValidator::repair_mojibake("Café"), None);
+        assert_eq!(EncodingValidator::repair_mojibake("plain"), None);
+        assert_eq!(EncodingValidator::repair_mojibake("東京"), None);
+    }
 
-        for validator in &self.validators {
-            let observations = validator.validate(table, schema);
-            all_observations.extend(observations);
-        }
+    #[test]
+    fn test_encoding_validator() {
+        let table = make_table(
+            vec!["city", "site"],
+            vec![
+                vec!["MontrÃ©al", "Z\u{FFFD}rich"],
+                vec!["Toronto", "Basel"],
+                vec!["MontrÃ©al", "Gen\u{FFFD}ve"],
+                vec!["Québec", "Bern"],
+            ],
+        );
+        let schema = make_simple_schema(vec![
+            ("city", ColumnType::String),
+            ("site", ColumnType::String),
+        ]);
 
-        // Sort by severity (errors first)
-        all_observations.sort_by(|a, b| b.severity.cmp(&a.severity));
+        let observations = EncodingValidator.validate(&table, &schema);
+        assert_eq!(observations.len(), 2);
+
+        let city = &observations[0];
+        assert_eq!(city.observation_type, ObservationType::Encoding);
+        assert_eq!(city.evidence.sample_rows, vec![0, 2]);
+        assert_eq!(city.evidence.rows, vec![0, 2]);
+        let counts = city.evidence.value_counts.as_ref().unwrap();
+        assert_eq!(counts["MontrÃ©al"]["suggestion"], "Montréal");
+        assert_eq!(counts["MontrÃ©al"]["count"], 2);
+
+        let site = &observations[1];
+        assert_eq!(site.evidence.sample_rows, vec![0, 2]);
+        assert_eq!(
+            site.evidence.expected.as_ref().unwrap()["guessed_encoding"],
+            "Latin-1 decoded as UTF-8"
+        );
 
-        all_observations
+        // Declaring the encoding clean skips the check
+        let context = ContextHints::new().with_clean_encoding();
+        let observations = ValidationEngine::with_context(&context).validate(&table, &schema);
+        assert!(!observations
+            .iter()
+            .any(|o| o.observation_type == ObservationType::Encoding));
     }
-}
 
-impl Default for ValidationEngine {
-    fn default() -> Self {
-        Self::new()
+    #[test]
+    fn test_whitespace_validator() {
+        let table = make_table(
+            vec!["sample_type", "site", "notes"],
+            vec![
+                vec!["stool ", "gut", "collected  at  home"],
+                vec!["stool", " oral", "ok"],
+                vec!["fecal  swab", "gut", "ok "],
+                vec!["stool ", "gut", "ok"],
+            ],
+        );
+        let mut schema = make_simple_schema(vec![
+            ("sample_type", ColumnType::String),
+            ("site", ColumnType::String),
+            ("notes", ColumnType::String),
+        ]);
+        schema.columns[0].semantic_type = SemanticType::Categorical;
+        schema.columns[1].expected_values = Some(vec!["gut".to_string(), "oral".to_string()]);
+        schema.columns[2].semantic_type = SemanticType::FreeText;
+
+        let observations = WhitespaceValidator.validate(&table, &schema);
+        assert_eq!(observations.len(), 2);
+
+        let sample_type = &observations[0];
+        assert_eq!(sample_type.column, "sample_type");
+        assert_eq!(sample_type.observation_type, ObservationType::Inconsistency);
+        assert_eq!(sample_type.evidence.occurrences, Some(3));
+        assert_eq!(sample_type.evidence.sample_rows, vec![0, 2, 3]);
+        let counts = sample_type.evidence.value_counts.as_ref().unwrap();
+        assert_eq!(counts["stool "]["suggestion"], "stool");
+        assert_eq!(counts["stool "]["count"], 2);
+        assert_eq!(counts["fecal  swab"]["suggestion"], "fecal swab");
+        let expected = sample_type.evidence.expected.as_ref().unwrap();
+        assert_eq!(expected["leading_or_trailing"], 2);
+        assert_eq!(expected["internal"], 1);
+
+        assert_eq!(observations[1].column, "site");
+        assert_eq!(observations[1].evidence.sample_rows, vec![1]);
+
+        let suggestions = crate::suggestion::SuggestionEngine::generate(&observations);
+        let trim = suggestions
+            .iter()
+            .find(|s| s.rationale.contains("'sample_type'"))
+            .unwrap();
+        assert_eq!(trim.action, crate::suggestion::SuggestionAction::Standardize);
+        assert_eq!(trim.parameters["collapse_internal"], true);
+        let trim = suggestions.iter().find(|s| s.rationale.contains("'site'")).unwrap();
+        assert_eq!(trim.parameters["collapse_internal"], false);
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::schema::{ColumnStatistics, SemanticRole, SemanticType};
 
-    fn make_table(headers: Vec<&str>, rows: Vec<Vec<&str>>) -> DataTable {
-        DataTable::new(
-            headers.into_iter().map(String::from).collect(),
-            rows.into_iter()
-                .map(|r| r.into_iter().map(String::from).collect())
+    #[test]
+    fn test_precision_validator() {
+        let values = ["5.10", "5.25", "5", "4.99999999", "6.00", "5.75", "5.5", "6.10"];
+        let table = make_table(
+            vec!["weight", "ratio"],
+            values
+                .iter()
+                .zip(["0.1", "0.25", "0.333", "0.5", "1", "0.75", "0.125", "0.2"])
+                .map(|(w, r)| vec![*w, r])
                 .collect(),
-            b',',
-        )
-    }
+        );
+        let schema = make_simple_schema(vec![
+            ("weight", ColumnType::Float),
+            ("ratio", ColumnType::Float),
+        ]);
 
-    fn make_simple_schema(columns: Vec<(&str, ColumnType)>) -> TableSchema {
-        TableSchema::with_columns(
-            columns
-                .into_iter()
-                .enumerate()
-                .map(|(i, (name, typ))| ColumnSchema {
-                    name: name.to_string(),
-                    position: i,
-                    inferred_type: typ,
-                    semantic_type: SemanticType::Unknown,
-                    semantic_role: SemanticRole::Unknown,
-                    nullable: false,
-                    unique: false,
-                    expected_values: None,
-                    expected_range: None,
-                    constraints: Vec::new(),
-                    statistics: ColumnStatistics::default(),
-                    confidence: 0.9,
-                    inference_sources: vec!["test".to_string()],
-                    llm_insight: None,
-                })
-                .collect(),
-        )
+        // The ratio column has no usual precision, so only weight is reported
+        let observations = PrecisionValidator::default().validate(&table, &schema);
+        assert_eq!(observations.len(), 1);
+        let obs = &observations[0];
+        assert_eq!(obs.column, "weight");
+        assert_eq!(obs.observation_type, ObservationType::Inconsistency);
+        assert_eq!(obs.severity, Severity::Warning);
+        assert_eq!(obs.evidence.occurrences, Some(3));
+        assert_eq!(obs.evidence.sample_rows, vec![3, 2, 6]);
+        assert_eq!(obs.evidence.expected.as_ref().unwrap()["modal_decimals"], 2);
+        assert_eq!(obs.evidence.value_counts.as_ref().unwrap()["8"], 1);
+        assert!(obs.description.contains("\"4.99999999\" (row 4)"));
+
+        assert_eq!(PrecisionValidator::decimal_places(" 5.00 "), Some(2));
+        assert_eq!(PrecisionValidator::decimal_places("1e-3"), None);
+
+        // The suggestion rounds the column to two places
+        let suggestions = crate::suggestion::SuggestionEngine::generate(&observations);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].action, crate::suggestion::SuggestionAction::Standardize);
+        assert_eq!(suggestions[0].parameters["decimals"], 2);
     }
 
     #[test]
-    fn test_type_validator() {
+    fn test_referential_integrity_validator() {
         let table = make_table(
-            vec!["age"],
-            vec![vec!["25"], vec!["30"], vec!["invalid"], vec!["28"]],
+            vec!["sample_id", "parent_sample_id", "subject", "donor"],
+            vec![
+                vec!["S1", "", "P1", "P1"],
+                vec!["S2", "S1", "P1", "P2"],
+                vec!["S3", "S3", "P2", "P1"],
+                vec!["S4", "S9", "P2", "P4"],
+                vec!["S5", "S9 ", "P3", "P2"],
+            ],
         );
-        let schema = make_simple_schema(vec![("age", ColumnType::Integer)]);
+        let schema = make_simple_schema(vec![
+            ("sample_id", ColumnType::String),
+            ("parent_sample_id", ColumnType::String),
+            ("subject", ColumnType::String),
+            ("donor", ColumnType::String),
+        ]);
 
-        let validator = TypeValidator;
-        let observations = validator.validate(&table, &schema);
+        // Discovered by name; a row referencing itself is fine
+        let observations = ReferentialIntegrityValidator::new().validate(&table, &schema);
+        assert_eq!(observations.len(), 1);
+        let obs = &observations[0];
+        assert_eq!(obs.column, "parent_sample_id");
+        assert_eq!(obs.observation_type, ObservationType::CrossColumnInconsistency);
+        assert_eq!(obs.severity, Severity::Error);
+        assert_eq!(obs.evidence.occurrences, Some(2));
+        assert_eq!(obs.evidence.sample_rows, vec![3, 4]);
+        assert_eq!(obs.evidence.expected.as_ref().unwrap()["references"], "sample_id");
+        assert_eq!(obs.evidence.value_counts.as_ref().unwrap()["S9"], json!([3, 4]));
+
+        // Declared references are checked too
+        let mut references = HashMap::new();
+        references.insert("donor".to_string(), "subject".to_string());
+        let observations = ReferentialIntegrityValidator::new()
+            .with_references(references)
+            .without_discovery()
+            .validate(&table, &schema);
+        assert_eq!(observations.len(), 1);
+        assert_eq!(observations[0].column, "donor");
+        assert_eq!(observations[0].evidence.sample_rows, vec![3]);
+        assert!(observations[0].description.contains("\"P4\" (row 4)"));
+
+        let context = ContextHints::new().with_reference("donor", "subject");
+        let observations = ValidationEngine::with_context(&context).validate(&table, &schema);
+        let orphaned: Vec<&str> = observations
+            .iter()
+            .filter(|o| o.detector == "referential_integrity_validator")
+            .map(|o| o.column.as_str())
+            .collect();
+        assert_eq!(orphaned, vec!["donor", "parent_sample_id"]);
+
+        // `subject_id` is not a reference to a bare `id`
+        let table = make_table(
+            vec!["id", "subject_id"],
+            vec![vec!["1", "P01"], vec!["2", "P02"]],
+        );
+        let schema =
+            make_simple_schema(vec![("id", ColumnType::Integer), ("subject_id", ColumnType::String)]);
+        assert!(ReferentialIntegrityValidator::new().validate(&table, &schema).is_empty());
+    }
+
+    #[test]
+    fn test_trailing_empty_column_validator() {
+        let table = make_table(
+            vec!["name", "age", ""],
+            vec![vec!["Alice", "30", ""], vec!["Bob", "25", ""]],
+        );
+        let schema = make_simple_schema(vec![
+            ("name", ColumnType::String),
+            ("age", ColumnType::Integer),
+            ("", ColumnType::Unknown),
+        ]);
 
+        let observations = TrailingEmptyColumnValidator.validate(&table, &schema);
         assert_eq!(observations.len(), 1);
-        assert_eq!(observations[0].observation_type, ObservationType::TypeMismatch);
+        assert!(observations[0].description.contains("trailing delimiter"));
+
+        let clean = make_table(vec!["name", "notes"], vec![vec!["Alice", ""], vec!["Bob", ""]]);
+        let schema = make_simple_schema(vec![
+            ("name", ColumnType::String),
+            ("notes", ColumnType::String),
+        ]);
+        assert!(TrailingEmptyColumnValidator.validate(&clean, &schema).is_empty());
     }
 
     #[test]
-    fn test_missing_pattern_validator() {
+    fn test_constant_column_validator() {
         let table = make_table(
-            vec!["status"],
+            vec!["SampleID", "batch", "country", "age"],
             vec![
-                vec!["active"],
-                vec!["missing"],
-                vec!["inactive"],
-                vec!["missing"],
+                vec!["B1", "2024-03", "USA", "30"],
+                vec!["B1", "2024-03", "USA", "25"],
+                vec!["B1", "", "USA", "41"],
+                vec!["B1", "2024-03", "USA", "30"],
             ],
         );
-        let schema = make_simple_schema(vec![("status", ColumnType::String)]);
+        let schema = make_simple_schema(vec![
+            ("SampleID", ColumnType::String),
+            ("batch", ColumnType::String),
+            ("country", ColumnType::String),
+            ("age", ColumnType::Integer),
+        ]);
 
-        let validator = MissingPatternValidator::default();
-        let observations = validator.validate(&table, &schema);
+        let observations = ConstantColumnValidator.validate(&table, &schema);
+        assert_eq!(observations.len(), 3);
+
+        // Names suggesting variation make it a warning
+        let sample_id = observations.iter().find(|o| o.column == "SampleID").unwrap();
+        assert_eq!(sample_id.severity, Severity::Warning);
+        assert_eq!(sample_id.evidence.value, Some(json!("B1")));
+
+        let batch = observations.iter().find(|o| o.column == "batch").unwrap();
+        assert_eq!(batch.severity, Severity::Warning);
+        assert!(batch.description.contains("all 3 non-null rows (1 row(s) missing)"));
+
+        let country = observations.iter().find(|o| o.column == "country").unwrap();
+        assert_eq!(country.severity, Severity::Info);
+        assert_eq!(country.observation_type, ObservationType::Cardinality);
+
+        assert!(ConstantColumnValidator::expects_variation("subjectNo"));
+        assert!(ConstantColumnValidator::expects_variation("run.id"));
+        assert!(!ConstantColumnValidator::expects_variation("paid"));
+    }
 
+    #[test]
+    fn test_high_cardinality_validator() {
+        let table = make_table(vec!["notes", "site", "sample_id"], vec![]);
+        let mut schema = make_simple_schema(vec![
+            ("notes", ColumnType::String),
+            ("site", ColumnType::String),
+            ("sample_id", ColumnType::String),
+        ]);
+        for (column, unique_count) in schema.columns.iter_mut().zip([19, 4, 20]) {
+            column.statistics.count = 20;
+            column.statistics.unique_count = unique_count;
+        }
+        schema.columns[2].semantic_role = SemanticRole::Identifier;
+
+        let observations = HighCardinalityValidator.validate(&table, &schema);
         assert_eq!(observations.len(), 1);
-        assert_eq!(observations[0].observation_type, ObservationType::MissingPattern);
-        assert!(observations[0].description.contains("missing"));
+        assert_eq!(observations[0].column, "notes");
+        assert_eq!(observations[0].observation_type, ObservationType::Cardinality);
+        assert!(observations[0].description.starts_with("19 distinct values in 20 non-null rows (95%)"));
+
+        // Thresholds come from the settings the schema was inferred with
+        schema.inference = Some(crate::schema::InferenceSettings {
+            categorical_max_distinct: 20,
+            categorical_max_ratio: 0.99,
+            decimal_mark: DecimalMark::default(),
+        });
+        assert!(HighCardinalityValidator.validate(&table, &schema).is_empty());
     }
 
     #[test]
@@ -3366,6 +7408,193 @@ mod tests {
         assert!(observations[0].description.contains("email"));
     }
 
+    #[test]
+    fn test_pii_validator() {
+        let table = make_table(
+            vec!["notes", "visit_date"],
+            vec![
+                vec!["call back at (555) 123-4567", "2024-03-01 10:15"],
+                vec!["SSN 123-45-6789 on file", "2024-03-02"],
+                vec!["contact jane.doe@example.org", "2024-03-03"],
+                vec!["chart MRN: 00482913", "2024-03-04"],
+                vec!["routine follow-up", "2024-03-05"],
+                vec!["NA", "2024-03-06"],
+            ],
+        );
+        let schema = make_simple_schema(vec![
+            ("notes", ColumnType::String),
+            ("visit_date", ColumnType::String),
+        ]);
+
+        let observations = PiiValidator::new().validate(&table, &schema);
+        assert_eq!(observations.len(), 1);
+        let obs = &observations[0];
+        assert_eq!(obs.observation_type, ObservationType::SensitiveData);
+        assert_eq!(obs.severity, Severity::Error);
+        assert_eq!(obs.column, "notes");
+        assert_eq!(obs.evidence.occurrences, Some(4));
+        assert_eq!(obs.evidence.sample_rows, vec![0, 1, 2, 3]);
+        assert!(obs.description.contains("1 email, 1 phone, 1 SSN, 1 MRN"));
+        assert!(obs.description.contains("1**-**-****"));
+
+        // No full value is echoed
+        for value in ["(555) 123-4567", "123-45-6789", "jane.doe@example.org", "00482913"] {
+            assert!(!obs.description.contains(value), "{}", value);
+        }
+
+        // A site-specific record number format replaces the built-in one
+        let table = make_table(vec!["notes"], vec![vec!["seen as H1234567"], vec!["ok"]]);
+        let schema = make_simple_schema(vec![("notes", ColumnType::String)]);
+        assert!(PiiValidator::new().validate(&table, &schema).is_empty());
+        let context = ContextHints::new()
+            .with_sensitive_pattern("mrn", r"\bH\d{7}\b")
+            .unwrap();
+        let observations = ValidationEngine::with_context(&context).validate(&table, &schema);
+        let obs = observations
+            .iter()
+            .find(|o| o.observation_type == ObservationType::SensitiveData)
+            .unwrap();
+        assert!(obs.description.contains("1 mrn"));
+        assert!(obs.description.contains("H*******"));
+
+        // At most a quarter of a value is revealed
+        assert_eq!(PiiValidator::mask("a@b.c"), "*@*.*");
+        assert_eq!(PiiValidator::mask("ab12"), "a***");
+        assert_eq!(PiiValidator::mask("jane.doe@example.org"), "j***.***@*******.***");
+
+        // Digits inside accessions and codes aren't phone numbers
+        let table = make_table(
+            vec!["run_accession", "notes"],
+            vec![
+                vec!["SRR1234567890", "lot AB1234567890"],
+                vec!["SRR1234567891", "ok"],
+                vec!["SRR1234567892", "ok"],
+            ],
+        );
+        let mut schema = make_simple_schema(vec![
+            ("run_accession", ColumnType::String),
+            ("notes", ColumnType::String),
+        ]);
+        assert!(PiiValidator::new().validate(&table, &schema).is_empty());
+        // Nor are values of an identifier column
+        let phones = make_table(vec!["patient"], vec![vec!["(555) 123-4567"], vec!["call 555-987-6543"]]);
+        schema = make_simple_schema(vec![("patient", ColumnType::String)]);
+        assert_eq!(PiiValidator::new().validate(&phones, &schema).len(), 1);
+        schema.columns[0].semantic_role = SemanticRole::Identifier;
+        assert!(PiiValidator::new().validate(&phones, &schema).is_empty());
+
+        // A pattern that doesn't compile is a configuration error
+        let err = ContextHints::new().with_sensitive_pattern("badge", "B(\\d+").unwrap_err();
+        assert!(err.to_string().contains("badge"));
+    }
+
+    #[test]
+    fn test_parse_formatted_numbers() {
+        assert_eq!(parse_currency("$1,234.56").as_deref(), Some("1234.56"));
+        assert_eq!(parse_currency("-$5").as_deref(), Some("-5"));
+        assert_eq!(parse_currency("$-5").as_deref(), Some("-5"));
+        assert_eq!(parse_currency("12.50 €").as_deref(), Some("12.50"));
+        assert_eq!(parse_currency("1,234"), None); // No symbol
+        assert_eq!(parse_currency("$1,23"), None); // Bad grouping
+        assert_eq!(parse_currency("$ten"), None);
+
+        assert_eq!(parse_percentage("45%").as_deref(), Some("0.45"));
+        assert_eq!(parse_percentage("12.5 %").as_deref(), Some("0.125"));
+        assert_eq!(parse_percentage("33.3%").as_deref(), Some("0.333"));
+        assert_eq!(parse_percentage("100%").as_deref(), Some("1"));
+        assert_eq!(parse_percentage("-2%").as_deref(), Some("-0.02"));
+        assert_eq!(parse_percentage("45"), None);
+        assert_eq!(parse_percentage("%"), None);
+    }
+
+    #[test]
+    fn test_parse_grouped_number() {
+        let point = DecimalMark::Point;
+        assert_eq!(parse_grouped_number("1,234,567", point).as_deref(), Some("1234567"));
+        assert_eq!(parse_grouped_number("-1 234.5", point).as_deref(), Some("-1234.5"));
+        assert_eq!(parse_grouped_number("12.5", point).as_deref(), Some("12.5"));
+        assert_eq!(parse_grouped_number("1,5", point), None); // Bad grouping
+        assert_eq!(parse_grouped_number("1,234 567", point), None); // Mixed separators
+        assert_eq!(parse_grouped_number("n/a", point), None);
+
+        let comma = DecimalMark::Comma;
+        assert_eq!(parse_grouped_number("1.234,56", comma).as_deref(), Some("1234.56"));
+        assert_eq!(parse_grouped_number("1.234", comma).as_deref(), Some("1234"));
+        assert_eq!(parse_grouped_number("12,5", comma).as_deref(), Some("12.5"));
+        assert_eq!(parse_grouped_number("1,234.56", comma), None);
+    }
+
+    #[test]
+    fn test_formatted_number_validator() {
+        let table = make_table(
+            vec!["price"],
+            vec![vec!["$1,234.56"], vec!["$12"], vec!["40"], vec!["NA"]],
+        );
+        let mut schema = make_simple_schema(vec![("price", ColumnType::String)]);
+
+        // Only columns inferred as currency or percentages are checked
+        assert!(FormattedNumberValidator::default().validate(&table, &schema).is_empty());
+
+        schema.columns[0].semantic_type = SemanticType::Currency;
+        let observations = FormattedNumberValidator::default().validate(&table, &schema);
+        assert_eq!(observations.len(), 1);
+        assert_eq!(observations[0].observation_type, ObservationType::TypeMismatch);
+        assert_eq!(observations[0].evidence.occurrences, Some(2));
+        let expected = observations[0].evidence.expected.as_ref().unwrap();
+        assert_eq!(expected["format"], "currency");
+        assert_eq!(expected["examples"]["$1,234.56"], "1234.56");
+
+        // Grouped numbers in numeric columns are also reported, and match
+        // the column's type
+        let table = make_table(
+            vec!["read_count"],
+            vec![vec!["1,234,567"], vec!["980"], vec!["2,001"]],
+        );
+        let schema = make_simple_schema(vec![("read_count", ColumnType::Integer)]);
+        assert!(TypeValidator::default().validate(&table, &schema).is_empty());
+        let observations = FormattedNumberValidator::default().validate(&table, &schema);
+        assert_eq!(observations.len(), 1);
+        assert_eq!(observations[0].evidence.occurrences, Some(2));
+        let expected = observations[0].evidence.expected.as_ref().unwrap();
+        assert_eq!(expected["format"], "grouped");
+        assert_eq!(expected["decimal_mark"], "point");
+    }
+
+    #[test]
+    fn test_regex_pattern_validator_declared_regex() {
+        let table = make_table(
+            vec!["sample_id"],
+            vec![
+                vec!["IBD0001"],
+                vec!["IBD0002"],
+                vec!["IBE0003"],
+                vec!["IBD0004"],
+            ],
+        );
+        let mut schema = make_simple_schema(vec![("sample_id", ColumnType::String)]);
+        schema.columns[0].semantic_role = SemanticRole::Identifier;
+
+        // Every id has the same shape, so the consistency heuristic is silent
+        let validator = RegexPatternValidator;
+        assert!(validator.validate(&table, &schema).is_empty());
+
+        let constraint: Constraint =
+            serde_json::from_str(r#"{"type": "regex", "pattern": "^IBD\\d{4}$"}"#).unwrap();
+        assert_eq!(
+            constraint,
+            Constraint::Regex {
+                pattern: r"^IBD\d{4}$".to_string()
+            }
+        );
+        schema.columns[0].constraints.push(constraint);
+
+        let observations = validator.validate(&table, &schema);
+        assert_eq!(observations.len(), 1);
+        assert_eq!(observations[0].observation_type, ObservationType::PatternViolation);
+        assert_eq!(observations[0].evidence.sample_rows, vec![2]);
+        assert!(observations[0].description.contains("IBE0003"));
+    }
+
     #[test]
     fn test_cross_column_validator_dates() {
         let table = make_table(
@@ -3390,9 +7619,11 @@ mod tests {
                 expected_range: None,
                 constraints: Vec::new(),
                 statistics: ColumnStatistics::default(),
+                detected_null_tokens: Vec::new(),
                 confidence: 0.9,
                 inference_sources: vec!["test".to_string()],
                 llm_insight: None,
+                explanation: None,
             },
             ColumnSchema {
                 name: "end_date".to_string(),
@@ -3406,9 +7637,11 @@ mod tests {
                 expected_range: None,
                 constraints: Vec::new(),
                 statistics: ColumnStatistics::default(),
+                detected_null_tokens: Vec::new(),
                 confidence: 0.9,
                 inference_sources: vec!["test".to_string()],
                 llm_insight: None,
+                explanation: None,
             },
         ]);
 
@@ -3419,4 +7652,191 @@ mod tests {
         assert_eq!(observations[0].observation_type, ObservationType::CrossColumnInconsistency);
         assert!(observations[0].description.contains("start date"));
     }
+
+    #[test]
+    fn test_cross_column_validator_datetimes() {
+        let table = make_table(
+            vec!["admission", "discharge"],
+            vec![
+                // 04:00 UTC on the 16th, after a discharge at 02:00 UTC
+                vec!["2024-01-15T23:00:00-05:00", "2024-01-16T02:00:00Z"],
+                vec!["2024-01-15T08:00:00Z", "2024-01-15T10:30:00+01:00"],
+                // Same day, discharge recorded without a time
+                vec!["2024-01-20 14:00", "2024-01-20"],
+                vec!["2024-01-22 09:00", "2024-01-21"],
+            ],
+        );
+        let schema = make_simple_schema(vec![
+            ("admission", ColumnType::DateTime),
+            ("discharge", ColumnType::DateTime),
+        ]);
+
+        let observations = CrossColumnValidator.validate(&table, &schema);
+
+        assert_eq!(observations.len(), 1);
+        assert_eq!(observations[0].evidence.occurrences, Some(2));
+        assert_eq!(observations[0].evidence.sample_rows, vec![0, 3]);
+    }
+
+    #[test]
+    fn test_date_format_validator_datetimes() {
+        let schema = make_simple_schema(vec![("collected_at", ColumnType::DateTime)]);
+
+        let uniform = make_table(
+            vec!["collected_at"],
+            vec![
+                vec!["2024-01-15T09:30:00Z"],
+                vec!["2024-01-16T14:05:12Z"],
+                vec!["2024-01-17"],
+                vec!["NA"],
+            ],
+        );
+        assert!(DateFormatValidator::default().validate(&uniform, &schema).is_empty());
+
+        let mixed = make_table(
+            vec!["collected_at"],
+            vec![
+                vec!["2024-01-15T09:30:00Z"],
+                vec!["2024-01-16T14:05:12Z"],
+                vec!["2024-01-17T08:00:00+02:00"],
+                vec!["2024-01-18 10:00:00"],
+                vec!["2024-01-19T11:15:00Z"],
+            ],
+        );
+        let observations = DateFormatValidator::default().validate(&mixed, &schema);
+        assert_eq!(observations.len(), 1);
+        let obs = &observations[0];
+        assert!(obs.description.contains("Mixed timezone notation"));
+        assert_eq!(obs.evidence.occurrences, Some(2));
+        assert_eq!(obs.evidence.sample_rows, vec![2, 3]);
+        assert_eq!(obs.evidence.expected, Some(json!({"timezone": "UTC (Z)"})));
+    }
+
+    #[test]
+    fn test_timezone_notation_ignores_meridiem() {
+        assert_eq!(TimezoneNotation::of("09:30 PM"), TimezoneNotation::Naive);
+        assert_eq!(TimezoneNotation::of("9:30am"), TimezoneNotation::Naive);
+        assert_eq!(TimezoneNotation::of("9:30 p.m."), TimezoneNotation::Naive);
+        assert_eq!(TimezoneNotation::of("09:30 PM EST"), TimezoneNotation::Offset);
+        assert_eq!(TimezoneNotation::of("09:30 PM UTC"), TimezoneNotation::Utc);
+        assert_eq!(TimezoneNotation::of("09:30:00Z"), TimezoneNotation::Utc);
+        assert_eq!(TimezoneNotation::of("09:30:00"), TimezoneNotation::Naive);
+
+        // A 12-hour column without zones is uniform, not mixed
+        let schema = make_simple_schema(vec![("collected_at", ColumnType::DateTime)]);
+        let table = make_table(
+            vec!["collected_at"],
+            vec![
+                vec!["2024-01-15 09:30 AM"],
+                vec!["2024-01-16 02:05 PM"],
+                vec!["2024-01-17 11:00:00"],
+            ],
+        );
+        let observations = DateFormatValidator::default().validate(&table, &schema);
+        assert!(observations.iter().all(|o| !o.description.contains("timezone")));
+    }
+
+    #[test]
+    fn test_date_format_validator_day_first() {
+        let schema = make_simple_schema(vec![("visit", ColumnType::Date)]);
+        let table = make_table(
+            vec!["visit"],
+            vec![
+                vec!["03/04/2024"],
+                vec!["15/04/2024"],
+                vec!["16.04.2024"],
+                vec!["2024-04-17"],
+            ],
+        );
+
+        let validator = DateFormatValidator::default().with_date_order(DateOrder::DayFirst);
+        let observations = validator.validate(&table, &schema);
+        assert_eq!(observations.len(), 1);
+        let obs = &observations[0];
+        assert!(obs.description.contains("EU (DD/MM/YYYY)"));
+        assert!(obs.description.contains("EU (DD.MM.YYYY)"));
+        assert!(!obs.description.contains("US (MM/DD/YYYY)"));
+        assert_eq!(obs.evidence.expected, Some(json!({"date_order": "day_first"})));
+
+        // Month first, the same slashed dates are US dates
+        let observations = DateFormatValidator::default().validate(&table, &schema);
+        assert!(observations[0].description.contains("US (MM/DD/YYYY)"));
+        // Without a date order set, none is passed on to suggestions
+        assert_eq!(observations[0].evidence.expected, None);
+    }
+
+    #[test]
+    fn test_semantic_equivalence_with_embeddings() {
+        use crate::llm::MockProvider;
+
+        let values = [
+            "colorectal cancer", "colorectal cancer", "colorectal cancers", "breast cancer",
+            "breast cancer", "lung cancer", "Lung Cancer", "stool", "feces",
+        ];
+        let table = make_table(vec!["diagnosis"], values.iter().map(|v| vec![*v]).collect());
+        let schema = make_simple_schema(vec![("diagnosis", ColumnType::String)]);
+
+        let rules_only = SemanticEquivalenceValidator::default().validate(&table, &schema);
+        assert_eq!(rules_only[0].evidence.value_counts, Some(json!({"stool": {"stool": 1, "feces": 1}})));
+
+        let observations = SemanticEquivalenceValidator::default()
+            .with_embeddings(Arc::new(MockProvider::new()))
+            .validate(&table, &schema);
+        assert_eq!(observations.len(), 1);
+        let obs = &observations[0];
+        assert_eq!(obs.observation_type, ObservationType::Inconsistency);
+        assert!(obs.description.starts_with("2 semantic equivalent group(s)"));
+        // Case variants are left to the case variant validator
+        assert_eq!(
+            obs.evidence.value_counts,
+            Some(json!({
+                "stool": {"stool": 1, "feces": 1},
+                "colorectal cancer": {"colorectal cancer": 2, "colorectal cancers": 1},
+            }))
+        );
+    }
+
+    #[test]
+    fn test_header_rename_observations() {
+        let mut table = make_table(
+            vec!["date", "", "date", "date"],
+            vec![vec!["d1", "x", "d2", "d3"]],
+        );
+        let renames = table.dedupe_headers();
+
+        let observations = header_rename_observations(&table, &renames);
+
+        assert_eq!(observations.len(), 2);
+        assert!(observations
+            .iter()
+            .all(|o| o.observation_type == ObservationType::SchemaIssue));
+        assert_eq!(observations[0].column, "column_2");
+        assert_eq!(observations[1].column, "date");
+        assert!(observations[1].description.contains("columns 1, 3, 4"));
+        assert_eq!(
+            observations[1].evidence.value.as_ref().unwrap()["positions"],
+            json!([0, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_cross_column_prefers_exact_column_name() {
+        let table = make_table(
+            vec!["restart_count", "Start", "End"],
+            vec![
+                vec!["1", "2024-03-01", "2024-02-01"],
+                vec!["2", "2024-01-01", "2024-02-01"],
+            ],
+        );
+        let schema = make_simple_schema(vec![
+            ("restart_count", ColumnType::Integer),
+            ("Start", ColumnType::Date),
+            ("End", ColumnType::Date),
+        ]);
+
+        let observations = CrossColumnValidator.validate(&table, &schema);
+
+        assert_eq!(observations.len(), 1);
+        assert_eq!(observations[0].column, "Start vs End");
+    }
 }
@@ -50,6 +50,127 @@ fn test_create_curation_layer_from_analysis() {
     assert!(!curation.observations.is_empty());
 }
 
+#[test]
+fn test_multi_table_curation_layer() {
+    use crucible::curation::TABLE_ID_SEPARATOR;
+    use indexmap::IndexMap;
+
+    let visits = create_test_file(
+        "visit_id,sample_id,outcome\n\
+         V1,S001,improved\n\
+         V2,S002,NA\n\
+         V3,S003,improved\n\
+         V4,S004,stable\n",
+    );
+    let crucible = Crucible::new().with_llm(MockProvider::new());
+    let mut results = IndexMap::new();
+    results.insert("samples".to_string(), create_analysis_with_suggestions());
+    results.insert(
+        "visits".to_string(),
+        crucible.analyze(visits.path()).expect("Analysis failed"),
+    );
+    let total_columns: usize = results.values().map(|r| r.summary.total_columns).sum();
+
+    let mut curation = CurationLayer::from_analyses(results, CurationContext::new()).unwrap();
+    assert_eq!(curation.table_names(), vec!["samples", "visits"]);
+    assert_eq!(curation.summary.total_columns, total_columns);
+    assert!(curation.suggestions.iter().all(
+        |s| s.id.contains(TABLE_ID_SEPARATOR) && s.observation_id.contains(TABLE_ID_SEPARATOR)
+    ));
+
+    let suggestion_id = curation
+        .suggestions
+        .iter()
+        .find(|s| s.id.starts_with("visits::"))
+        .map(|s| s.id.clone())
+        .expect("visits table has a suggestion");
+    assert_eq!(
+        CurationLayer::split_table_id(&suggestion_id).0,
+        Some("visits")
+    );
+    assert_eq!(CurationLayer::split_table_id("sug_001"), (None, "sug_001"));
+    curation.accept(&suggestion_id).unwrap();
+
+    // Tables and namespaced decisions survive a save/load round trip
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let save_path = temp_dir.path().join("workbook.curation.json");
+    curation.save(&save_path).expect("Save failed");
+    let loaded = CurationLayer::load(&save_path).expect("Load failed");
+    assert_eq!(loaded.table_names(), vec!["samples", "visits"]);
+    assert_eq!(
+        loaded.decision_for(&suggestion_id).map(|d| d.status.clone()),
+        Some(DecisionStatus::Accepted)
+    );
+
+    // A table view only holds that table's items
+    let view = loaded.table("visits").unwrap();
+    assert!(view.tables.is_empty());
+    assert!(view.schema.get_column("visit_id").is_some());
+    assert!(
+        view.suggestions
+            .iter()
+            .all(|s| s.id.starts_with("visits::"))
+    );
+    assert_eq!(view.decisions.len(), 1);
+    assert_eq!(view.summary.suggestions_by_status.accepted, 1);
+    let samples = loaded.table("samples").unwrap();
+    assert!(samples.decisions.is_empty());
+    assert!(loaded.table("missing").is_none());
+
+    // Single-table layers don't serialize a tables map
+    let single =
+        CurationLayer::from_analysis(create_analysis_with_suggestions(), CurationContext::new());
+    assert!(
+        !serde_json::to_string(&single)
+            .unwrap()
+            .contains("\"tables\"")
+    );
+}
+
+#[test]
+fn test_multi_table_curation_layer_keeps_tables_apart() {
+    use crucible::{DataTable, Question, SemanticRole, TransformEngine};
+    use indexmap::IndexMap;
+
+    let with_role = |role| {
+        let mut result = create_analysis_with_suggestions();
+        let mut question =
+            Question::role("status", vec![SemanticRole::Grouping, SemanticRole::Outcome]);
+        question.answer = Some(role);
+        result.questions.push(question);
+        result
+    };
+    let mut results = IndexMap::new();
+    results.insert("baseline".to_string(), with_role(SemanticRole::Grouping));
+    results.insert("followup".to_string(), with_role(SemanticRole::Outcome));
+    let curation = CurationLayer::from_analyses(results, CurationContext::new()).unwrap();
+
+    // Roles answered per sheet don't collide on a shared column name
+    let roles = &curation.context.hints.column_roles;
+    assert_eq!(roles["baseline::status"], SemanticRole::Grouping);
+    assert_eq!(roles["followup::status"], SemanticRole::Outcome);
+    let view = curation.table("followup").unwrap();
+    assert_eq!(view.context.hints.column_roles.len(), 1);
+    assert_eq!(view.context.hints.column_roles["status"], SemanticRole::Outcome);
+
+    // Decisions are applied one table at a time
+    let mut data = DataTable::new(vec!["status".into()], vec![vec!["active".into()]], b',');
+    assert!(TransformEngine::new().apply(&curation, &mut data).is_err());
+    assert!(TransformEngine::new().apply(&view, &mut data).is_ok());
+}
+
+#[test]
+fn test_multi_table_curation_layer_rejects_bad_names() {
+    use indexmap::IndexMap;
+
+    let empty = IndexMap::new();
+    assert!(CurationLayer::from_analyses(empty, CurationContext::new()).is_err());
+
+    let mut results = IndexMap::new();
+    results.insert("sheet::1".to_string(), create_analysis_with_suggestions());
+    assert!(CurationLayer::from_analyses(results, CurationContext::new()).is_err());
+}
+
 #[test]
 fn test_curation_context_builder() {
     use crucible::curation::InferenceConfig;
@@ -233,6 +354,100 @@ fn test_rejected_decisions() {
     assert_eq!(curation.rejected_decisions().len(), 1);
 }
 
+#[test]
+fn test_defer_suggestion() {
+    let result = create_analysis_with_suggestions();
+    let context = CurationContext::new();
+    let mut curation = CurationLayer::from_analysis(result, context);
+
+    let initial_pending = curation.pending_suggestions().len();
+    let suggestion_id = curation.suggestions[0].id.clone();
+    curation
+        .defer_until(&suggestion_id, "Waiting on lab confirmation", "2024-07-01")
+        .unwrap();
+
+    // Deferred suggestions are not pending, and block completion
+    assert_eq!(curation.pending_suggestions().len(), initial_pending - 1);
+    assert_eq!(curation.deferred_suggestions().len(), 1);
+    assert_eq!(curation.summary.suggestions_by_status.deferred, 1);
+    assert_eq!(curation.summary.suggestions_by_status.decided(), 0);
+
+    // The deferral survives a save/load round trip
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let save_path = temp_dir.path().join("test.curation.json");
+    curation.save(&save_path).expect("Save failed");
+    let mut loaded = CurationLayer::load(&save_path).expect("Load failed");
+
+    let decision = loaded.decision_for(&suggestion_id).unwrap();
+    assert_eq!(
+        decision.status,
+        DecisionStatus::Deferred {
+            reason: "Waiting on lab confirmation".to_string(),
+            until: Some("2024-07-01".to_string()),
+        }
+    );
+    assert_eq!(loaded.summary.suggestions_by_status.deferred, 1);
+
+    // A deferred suggestion can still be decided
+    loaded.accept(&suggestion_id).unwrap();
+    assert!(loaded.deferred_suggestions().is_empty());
+    assert_eq!(loaded.decisions.len(), 1);
+    assert_eq!(loaded.summary.suggestions_by_status.deferred, 0);
+    assert_eq!(loaded.summary.suggestions_by_status.accepted, 1);
+}
+
+#[test]
+fn test_decision_history() {
+    let result = create_analysis_with_suggestions();
+    let context = CurationContext::new();
+    let mut curation = CurationLayer::from_analysis(result, context);
+
+    let suggestion_id = curation.suggestions[0].id.clone();
+    assert!(curation.decision_history(&suggestion_id).is_empty());
+
+    curation
+        .defer(&suggestion_id, "Waiting on lab confirmation")
+        .unwrap();
+    curation.accept_by(&suggestion_id, "alice").unwrap();
+
+    // A reversal keeps the earlier events
+    curation.reset(&suggestion_id).unwrap();
+    assert_eq!(curation.decision_history(&suggestion_id).len(), 3);
+    curation
+        .reject_by(&suggestion_id, "bob", "Codes are intentional")
+        .unwrap();
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let save_path = temp_dir.path().join("test.curation.json");
+    curation.save(&save_path).expect("Save failed");
+    let loaded = CurationLayer::load(&save_path).expect("Load failed");
+
+    let history = loaded.decision_history(&suggestion_id);
+    let transitions: Vec<_> = history
+        .iter()
+        .map(|e| (e.from_status.clone(), e.to_status.clone()))
+        .collect();
+    let deferred = DecisionStatus::Deferred {
+        reason: "Waiting on lab confirmation".to_string(),
+        until: None,
+    };
+    assert_eq!(
+        transitions,
+        vec![
+            (DecisionStatus::Pending, deferred.clone()),
+            (deferred, DecisionStatus::Accepted),
+            (DecisionStatus::Accepted, DecisionStatus::Pending),
+            (DecisionStatus::Pending, DecisionStatus::Rejected),
+        ]
+    );
+    assert_eq!(history[0].reason.as_deref(), Some("Waiting on lab confirmation"));
+    assert_eq!(history[1].user.as_deref(), Some("alice"));
+    assert_eq!(history[3].user.as_deref(), Some("bob"));
+    assert_eq!(history[3].reason.as_deref(), Some("Codes are intentional"));
+    assert!(history.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+    assert!(loaded.reset_history.is_empty());
+}
+
 #[test]
 fn test_decision_for_suggestion() {
     let result = create_analysis_with_suggestions();
@@ -461,6 +676,58 @@ fn test_suggestion_counts() {
     assert_eq!(counts.approved(), counts.accepted + counts.modified + counts.applied);
 }
 
+// =============================================================================
+// Merge Tests
+// =============================================================================
+
+#[test]
+fn test_merge_parallel_reviews() {
+    let result = create_analysis_with_suggestions();
+    let base = CurationLayer::from_analysis(result, CurationContext::new());
+    assert!(base.suggestions.len() >= 3);
+    let id1 = base.suggestions[0].id.clone();
+    let id2 = base.suggestions[1].id.clone();
+    let id3 = base.suggestions[2].id.clone();
+
+    let mut ours = base.clone();
+    let mut theirs = base.clone();
+
+    ours.accept(&id1).unwrap();
+    ours.reject(&id2, "Not needed").unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    theirs.accept(&id1).unwrap();
+    theirs.accept(&id2).unwrap();
+    theirs.reject(&id3, "Out of scope").unwrap();
+
+    let report = ours.merge(&theirs).unwrap();
+
+    assert_eq!(report.added, vec![id3.clone()]);
+    assert_eq!(report.unchanged, vec![id1.clone()]);
+    assert!(report.has_conflicts());
+    assert_eq!(report.conflicts.len(), 1);
+    assert_eq!(report.conflicts[0].suggestion_id, id2);
+    assert_eq!(report.conflicts[0].ours.status, DecisionStatus::Rejected);
+    assert!(report.conflicts[0].kept_theirs);
+
+    // The more recent decision wins and the summary is updated
+    assert_eq!(ours.decisions.len(), 3);
+    assert_eq!(ours.decision_for(&id2).unwrap().status, DecisionStatus::Accepted);
+    assert_eq!(ours.decision_for(&id3).unwrap().status, DecisionStatus::Rejected);
+    assert_eq!(ours.summary.suggestions_by_status.accepted, 2);
+    assert_eq!(ours.summary.suggestions_by_status.rejected, 1);
+}
+
+#[test]
+fn test_merge_rejects_different_data() {
+    let result = create_analysis_with_suggestions();
+    let mut ours = CurationLayer::from_analysis(result, CurationContext::new());
+    let mut theirs = ours.clone();
+    theirs.source.hash = "sha256:different".to_string();
+    theirs.source.content_hash = "sha256:different".to_string();
+
+    assert!(ours.merge(&theirs).is_err());
+}
+
 // =============================================================================
 // curation_path Helper Tests
 // =============================================================================
@@ -484,6 +751,79 @@ fn test_crucible_curation_path_helper() {
     assert_eq!(path.to_string_lossy(), "data/.crucible/metadata.curation.json");
 }
 
+// =============================================================================
+// Review Sheet Tests
+// =============================================================================
+
+#[test]
+fn test_ingest_review_sheet() {
+    let result = create_analysis_with_suggestions();
+    let mut curation = CurationLayer::from_analysis(result, CurationContext::new());
+    assert!(curation.suggestions.len() >= 3);
+
+    let first = curation.suggestions[0].clone();
+    let second = curation.suggestions[1].id.clone();
+    let third = curation.suggestions[2].id.clone();
+    let sheet = format!(
+        "id,column,decision,reason,reviewer\n\
+         {},x,Accept,,alice\n\
+         {},x,reject,\"Not an issue, keep\",bob\n\
+         {},x,defer,Ask the lab,\n\
+         {},x,,,\n\
+         sug_999,x,accept,,\n",
+        first.observation_id, second, third, first.id
+    );
+    let report = curation.ingest_decisions(sheet.as_bytes()).unwrap();
+
+    // An observation ID decides every suggestion made for it
+    let for_first_obs = curation
+        .suggestions
+        .iter()
+        .filter(|s| s.observation_id == first.observation_id)
+        .count();
+    assert_eq!(report.recorded.len(), for_first_obs + 2);
+    assert_eq!(report.unmatched, vec!["sug_999".to_string()]);
+    let decision = curation.decision_for(&first.id).unwrap();
+    assert_eq!(decision.status, DecisionStatus::Accepted);
+    assert_eq!(decision.decided_by.as_deref(), Some("alice"));
+    let decision = curation.decision_for(&second).unwrap();
+    assert_eq!(decision.status, DecisionStatus::Rejected);
+    assert_eq!(decision.notes.as_deref(), Some("Not an issue, keep"));
+    assert!(curation.decision_for(&third).unwrap().status.is_deferred());
+
+    // Ingesting the same sheet again changes nothing
+    let again = curation.ingest_decisions(sheet.as_bytes()).unwrap();
+    assert!(again.recorded.is_empty());
+    assert_eq!(again.unchanged.len(), report.recorded.len());
+
+    // A changed decision replaces the earlier one
+    let sheet = format!("id,decision\n{},accept\n", second);
+    let report = curation.ingest_decisions(sheet.as_bytes()).unwrap();
+    assert_eq!(report.recorded, vec![second.clone()]);
+    assert_eq!(
+        curation.decision_for(&second).map(|d| d.status.clone()),
+        Some(DecisionStatus::Accepted)
+    );
+}
+
+#[test]
+fn test_ingest_review_sheet_rejects_malformed_rows() {
+    let result = create_analysis_with_suggestions();
+    let mut curation = CurationLayer::from_analysis(result, CurationContext::new());
+    let id = curation.suggestions[0].id.clone();
+
+    let sheet = format!("id,decision\n{},accept\n{},maybe\n,reject\n", id, id);
+    let err = curation.ingest_decisions(sheet.as_bytes()).unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("2 malformed row(s)"));
+    assert!(message.contains("line 3: unknown decision 'maybe'"));
+    assert!(message.contains("line 4: decision without an id"));
+    // Nothing is recorded from a malformed sheet
+    assert!(curation.decisions.is_empty());
+
+    assert!(curation.ingest_decisions("id,status\nsug_001,accept\n".as_bytes()).is_err());
+}
+
 // =============================================================================
 // Updated At Timestamp Tests
 // =============================================================================
@@ -583,3 +923,100 @@ fn test_complete_curation_workflow() {
     assert_eq!(loaded.context.hints.study_name.as_deref(), Some("IBD Cohort Study"));
     assert_eq!(loaded.decisions.len(), curation.suggestions.len());
 }
+
+#[test]
+fn test_analyze_with_prior_carries_decisions() {
+    use crucible::SuggestionAction;
+
+    let file = create_test_data();
+    let crucible = Crucible::new();
+    let mut prior =
+        CurationLayer::from_analysis(crucible.analyze(file.path()).unwrap(), CurationContext::new());
+    let convert_na = prior
+        .suggestions
+        .iter()
+        .find(|s| s.action == SuggestionAction::ConvertNa)
+        .unwrap()
+        .id
+        .clone();
+    let flag = prior
+        .suggestions
+        .iter()
+        .find(|s| s.action == SuggestionAction::Flag && s.column() == Some("status"))
+        .unwrap()
+        .id
+        .clone();
+    prior.accept(&convert_na).unwrap();
+    prior.reject(&flag, "Expected").unwrap();
+
+    // Appended rows keep both issues and add case variants
+    let appended = create_test_file(
+        "sample_id,diagnosis,status\n\
+         S001,CD,active\n\
+         S002,UC,missing\n\
+         S003,CD,active\n\
+         S004,Control,missing\n\
+         S005,UC,inactive\n\
+         S006,cd,active\n\
+         S007,UC,Active\n",
+    );
+    let (layer, report) = crucible.analyze_with_prior(appended.path(), &prior).unwrap();
+    assert_eq!(report.carried_over.len(), 2);
+    assert!(report.invalidated.is_empty());
+    assert!(!report.added.is_empty());
+    assert_eq!(layer.created_at, prior.created_at);
+
+    let status_of = |action| {
+        let suggestion = layer.suggestions.iter().find(|s| s.action == action).unwrap();
+        layer.decision_for(&suggestion.id).map(|d| d.status.clone())
+    };
+    assert_eq!(status_of(SuggestionAction::ConvertNa), Some(DecisionStatus::Accepted));
+    assert!(report.added.iter().all(|id| layer.decision_for(id).is_none()));
+    assert_eq!(layer.summary.suggestions_by_status.accepted, 1);
+    assert_eq!(layer.summary.suggestions_by_status.rejected, 1);
+
+    // Once the missing values are fixed, their decisions no longer apply
+    let fixed = create_test_file(
+        "sample_id,diagnosis,status\n\
+         S001,CD,active\n\
+         S002,UC,active\n\
+         S003,CD,active\n\
+         S004,Control,inactive\n\
+         S005,UC,inactive\n",
+    );
+    let (layer, report) = crucible.analyze_with_prior(fixed.path(), &prior).unwrap();
+    assert!(report.carried_over.is_empty());
+    let mut invalidated = vec![convert_na, flag];
+    invalidated.sort();
+    assert_eq!(report.invalidated, invalidated);
+    assert!(layer.decisions.is_empty());
+}
+
+#[test]
+fn test_coerce_percentages_to_one_scale() {
+    use crucible::{Parser, SuggestionAction, TransformEngine};
+
+    let file = create_test_file(
+        "sample_id,gc_content\n\
+         S001,45%\n\
+         S002,40\n\
+         S003,38%\n\
+         S004,52%\n\
+         S005,47%\n",
+    );
+    let result = Crucible::new().analyze(file.path()).unwrap();
+    let mut curation = CurationLayer::from_analysis(result, CurationContext::new());
+    let coerce = curation
+        .suggestions
+        .iter()
+        .find(|s| s.action == SuggestionAction::Coerce)
+        .unwrap()
+        .id
+        .clone();
+    curation.accept(&coerce).unwrap();
+
+    let (mut data, _) = Parser::new().parse_file(file.path()).unwrap();
+    TransformEngine::new().apply(&curation, &mut data).unwrap();
+    let column: Vec<&str> = data.column_values(1).collect();
+    assert_eq!(column, vec!["0.45", "0.4", "0.38", "0.52", "0.47"]);
+}
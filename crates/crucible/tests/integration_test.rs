@@ -3,7 +3,7 @@
 use std::io::Write;
 use tempfile::NamedTempFile;
 
-use crucible::{ColumnType, Crucible, ObservationType, SemanticRole};
+use crucible::{ColumnType, Crucible, Locale, ObservationType, SemanticRole, SourceMetadata};
 
 /// Helper to create a temporary file with given content.
 fn create_test_file(content: &str) -> NamedTempFile {
@@ -49,6 +49,26 @@ fn test_analyze_tsv_auto_detect() {
     assert_eq!(result.schema.columns.len(), 3);
 }
 
+#[test]
+fn test_analyze_reader() {
+    let content = "sample_id;site;age\n\
+                   S001;A;25\n\
+                   S002;B;30\n\
+                   S003;A;28\n";
+
+    let crucible = Crucible::new();
+    let source = SourceMetadata::stream("cohort.csv").with_delimiter(';');
+    let result = crucible
+        .analyze_reader(content.as_bytes(), source)
+        .expect("Analysis failed");
+
+    assert!(result.source.is_stream());
+    assert_eq!(result.source.file, "cohort.csv");
+    assert_eq!(result.source.delimiter, Some(';'));
+    assert_eq!(result.source.row_count, 3);
+    assert_eq!(result.schema.columns.len(), 3);
+}
+
 // =============================================================================
 // Type Inference Tests
 // =============================================================================
@@ -152,6 +172,27 @@ fn test_infer_covariate_role() {
     assert_eq!(result.schema.columns[0].semantic_role, SemanticRole::Covariate);
 }
 
+#[test]
+fn test_infer_coordinate_role_from_values() {
+    let content = "site_location,depth\n\
+                   38.98 -77.11,10\n\
+                   39.01 -76.95,12\n\
+                   38.90 -77.04,15\n";
+    let file = create_test_file(content);
+
+    let crucible = Crucible::new();
+    let result = crucible.analyze(file.path()).expect("Analysis failed");
+
+    let column = &result.schema.columns[0];
+    assert_eq!(column.semantic_role, SemanticRole::Coordinate);
+
+    // The role survives a serde round-trip
+    let json = serde_json::to_string(column).expect("Serialization failed");
+    assert!(json.contains("\"semantic_role\":\"coordinate\""));
+    let restored: crucible::ColumnSchema = serde_json::from_str(&json).expect("Deserialization failed");
+    assert_eq!(restored.semantic_role, SemanticRole::Coordinate);
+}
+
 // =============================================================================
 // Missing Value Detection Tests
 // =============================================================================
@@ -230,6 +271,111 @@ fn test_detect_categorical_values() {
     assert!(expected.contains(&"Control".to_string()));
 }
 
+#[test]
+fn test_list_column_items() {
+    let content = "sample_id\tmedication\n\
+                   S1\tantibiotic;probiotic\n\
+                   S2\tAntibiotic;steroid\n\
+                   S3\tprobiotic\n\
+                   S4\tantibiotic;steroid\n\
+                   S5\tprobiotic;steroid\n\
+                   S6\tantibiotic;probiotic\n";
+    let file = create_test_file(content);
+
+    let result = Crucible::new().analyze(file.path()).expect("Analysis failed");
+    let col = result.schema.get_column("medication").unwrap();
+    assert_eq!(col.inferred_type, ColumnType::List { item_delimiter: ';' });
+
+    // Casing is compared item by item, not per combination
+    let case_variants = result.observations.iter().find(|o| {
+        o.column == "medication" && o.observation_type == ObservationType::Inconsistency
+    });
+    assert!(case_variants.unwrap().description.contains("list items"));
+}
+
+#[test]
+fn test_list_case_variants_count_rows() {
+    // Two groups of variants in every row: 12 items, but only 6 rows
+    let content = "sample_id\tmedication\n\
+                   S1\tantibiotic;steroid\n\
+                   S2\tAntibiotic;Steroid\n\
+                   S3\tsteroid;antibiotic\n\
+                   S4\tantibiotic;Steroid\n\
+                   S5\tAntibiotic;steroid\n\
+                   S6\tsteroid;Antibiotic\n";
+    let file = create_test_file(content);
+
+    let result = Crucible::new().analyze(file.path()).expect("Analysis failed");
+    let col = result.schema.get_column("medication").unwrap();
+    assert_eq!(col.inferred_type, ColumnType::List { item_delimiter: ';' });
+
+    let obs = result
+        .observations
+        .iter()
+        .find(|o| o.column == "medication" && o.observation_type == ObservationType::Inconsistency)
+        .unwrap();
+    assert!(obs.description.contains("list items"));
+    assert_eq!(obs.evidence.occurrences, Some(6));
+    assert_eq!(obs.evidence.percentage, Some(100.0));
+}
+
+#[test]
+fn test_grouped_numbers_with_locale() {
+    let content = "sample_id\tread_count\tvisit\n\
+                   S1\t1.234.567\t03.04.2024\n\
+                   S2\t980\t15/04/2024\n\
+                   S3\t12.000\t2024-04-20\n\
+                   S4\t45.312\t21.04.2024\n";
+    let file = create_test_file(content);
+
+    let crucible = Crucible::new().with_context(ContextHints::new().with_locale(Locale::EuDe));
+    let result = crucible.analyze(file.path()).expect("Analysis failed");
+    assert_eq!(result.locale, Locale::EuDe);
+    let col = result.schema.get_column("read_count").unwrap();
+    assert_eq!(col.inferred_type, ColumnType::Integer);
+
+    let grouped = result
+        .observations
+        .iter()
+        .find(|o| o.column == "read_count" && o.observation_type == ObservationType::TypeMismatch)
+        .unwrap();
+    assert_eq!(grouped.evidence.occurrences, Some(3));
+    assert!(result.suggestions.iter().any(|s| s.observation_id == grouped.id));
+
+    // Numeric dates are read day first
+    let col = result.schema.get_column("visit").unwrap();
+    assert_eq!(col.inferred_type, ColumnType::Date);
+    let mixed_dates = result
+        .observations
+        .iter()
+        .find(|o| o.column == "visit" && o.observation_type == ObservationType::Inconsistency)
+        .unwrap();
+    assert!(mixed_dates.description.contains("EU (DD.MM.YYYY)"));
+    let convert = result
+        .suggestions
+        .iter()
+        .find(|s| s.observation_id == mixed_dates.id)
+        .unwrap();
+    assert_eq!(convert.parameters["assume_order"], "day_first");
+}
+
+#[test]
+fn test_comma_joined_ids_are_not_stripped() {
+    let content = "sample_id\tlinked_ids\n\
+                   S1\t101,202,303\n\
+                   S2\t404,505\n\
+                   S3\t606,707,808\n";
+    let file = create_test_file(content);
+
+    let result = Crucible::new().analyze(file.path()).expect("Analysis failed");
+    let col = result.schema.get_column("linked_ids").unwrap();
+    assert_eq!(col.inferred_type, ColumnType::String);
+    assert!(!result
+        .observations
+        .iter()
+        .any(|o| o.column == "linked_ids" && o.detector == "formatted_number_validator"));
+}
+
 // =============================================================================
 // Uniqueness Detection Tests
 // =============================================================================
@@ -598,3 +744,36 @@ fn test_suggestion_fields() {
         assert!(suggestion.priority <= 10);
     }
 }
+
+// =============================================================================
+// Cohort Tests
+// =============================================================================
+
+#[test]
+fn test_analyze_cohort() {
+    let visit1 = create_test_file(
+        "subject_id\tsex\tweight\n\
+         P001\tF\t61.2\n\
+         P002\tM\t80.5\n\
+         P003\tF\t58.0\n",
+    );
+    let visit2 = create_test_file(
+        "subject_id\tsex\tweight\n\
+         P001\tF\t62.0\n\
+         P003\tF\t57.4\n",
+    );
+
+    let report = Crucible::new()
+        .analyze_cohort(&[visit1.path(), visit2.path()])
+        .expect("Cohort analysis failed");
+
+    assert_eq!(report.timepoints.len(), 2);
+    assert_eq!(report.subject_column.as_deref(), Some("subject_id"));
+    assert_eq!(report.missing_subjects.len(), 1);
+    assert_eq!(report.missing_subjects[0].subjects, vec!["P002"]);
+    assert!(report.type_drift.is_empty());
+    assert!(report
+        .observations
+        .iter()
+        .all(|o| o.observation_type == ObservationType::CohortInconsistency));
+}
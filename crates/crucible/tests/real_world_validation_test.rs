@@ -383,6 +383,8 @@ fn test_biosample_validator_on_microbiome_data() {
             vec!["S002".to_string(), "Homo sapiens".to_string(), "2023-01-01".to_string()], // Duplicate
         ],
         delimiter: b'\t',
+        total_rows: None,
+        row_lines: Vec::new(),
     };
 
     let mut col_organism = ColumnSchema::new("organism", 1);